@@ -0,0 +1,62 @@
+//! Benchmarks du calcul des générations du Game of Life.
+//!
+//! Lancer avec `cargo bench --bench gameoflife` pour comparer le calcul
+//! séquentiel et le calcul parallèle (feature `parallel`) sur chaque taille de grille.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+#[allow(dead_code)]
+#[path = "../src/core/life.rs"]
+mod life;
+
+use life::CellState;
+
+// Mêmes tailles que les presets F1-F4 du jeu
+const SIZES: [(&str, usize, usize); 4] = [
+    ("small", 40, 20),
+    ("medium", 60, 30),
+    ("large", 80, 40),
+    ("huge", 120, 60),
+];
+
+/// Grille pseudo-aléatoire déterministe (~30% de cellules vivantes comme `randomize_grid`)
+fn seeded_grid(width: usize, height: usize) -> Vec<CellState> {
+    let mut state: u32 = 0x2545_f491;
+    (0..width * height)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            if state % 10 < 3 {
+                CellState::Alive
+            } else {
+                CellState::Dead
+            }
+        })
+        .collect()
+}
+
+fn bench_generations(c: &mut Criterion) {
+    let mut group = c.benchmark_group("gameoflife_generation");
+
+    for (name, width, height) in SIZES {
+        let src = seeded_grid(width, height);
+        let mut dst = vec![CellState::Dead; width * height];
+
+        group.bench_with_input(BenchmarkId::new("serial", name), &src, |b, src| {
+            b.iter(|| life::next_generation_serial(black_box(src), &mut dst, width, width, height))
+        });
+
+        #[cfg(feature = "parallel")]
+        group.bench_with_input(BenchmarkId::new("parallel", name), &src, |b, src| {
+            b.iter(|| {
+                life::next_generation_parallel(black_box(src), &mut dst, width, width, height)
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_generations);
+criterion_main!(benches);
@@ -0,0 +1,10 @@
+//! Un `config.json` corrompu doit produire une erreur, jamais un panic.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use termplay::config;
+
+fuzz_target!(|data: &str| {
+    let _ = config::parse_config(data);
+});
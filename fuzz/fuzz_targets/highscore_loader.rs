@@ -0,0 +1,16 @@
+//! Un `highscores.json` tronqué ou corrompu doit produire une erreur, jamais
+//! un panic, et les scores lus doivent pouvoir être affichés.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use termplay::highscores;
+
+fuzz_target!(|data: &str| {
+    if let Ok(scores) = highscores::parse_scores(data) {
+        for score in scores.games.values().flatten() {
+            let _ = score.format_duration();
+            let _ = score.format_date();
+        }
+    }
+});
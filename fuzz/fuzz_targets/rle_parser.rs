@@ -0,0 +1,20 @@
+//! Un fichier RLE quelconque doit être refusé proprement, jamais faire
+//! paniquer `termplay gol run` ni l'import de motifs.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use termplay::stamps;
+
+fuzz_target!(|data: &str| {
+    if let Ok(cells) = stamps::parse_rle_file(data) {
+        // Un motif lu doit pouvoir être réécrit puis relu à l'identique
+        let rle = stamps::format_rle_file(&cells);
+        let mut reread = stamps::parse_rle_file(&rle).expect("formatted RLE must parse");
+        let mut cells = cells;
+        cells.sort_unstable();
+        cells.dedup();
+        reread.sort_unstable();
+        assert_eq!(cells, reread);
+    }
+});
@@ -1,197 +1,806 @@
-use crate::core::{Game, GameAction, GameResult};
-use crate::games::GameRegistry;
-use crate::menu::MainMenu;
-use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyEventKind},
-    execute,
-    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+use crate::attract;
+use crate::audio::{AudioManager, SoundEffect};
+use crate::audio_recovery::AudioRecovery;
+use crate::challenge;
+use crate::config::{self, AudioConfig, ConfigHandle, GameConfig};
+use crate::core::clock::Instant;
+use crate::core::{clock::GameClock, DangerLevel, Game, GameAction, GameResult, GameStatus};
+use crate::crash::{self, CrashReport};
+use crate::event::{Event, KeyCode, KeyEventKind};
+use crate::frontend::{CrosstermFrontend, Frontend};
+use crate::games::{arrange, GameRegistry};
+use crate::highscores;
+use crate::history::{self, SessionRecord};
+use crate::jobs;
+use crate::menu::{MainMenu, StartupData};
+use crate::mutators;
+use crate::recording::CastRecorder;
+use crate::replay::ReplayHeader;
+use crate::saves;
+use crate::shutdown;
+use crate::splash;
+use crate::surprise;
+use crate::targets::TargetWatch;
+use crate::time_format;
+use crate::ui::{
+    animation, celebration::Celebration, debug_overlay::DebugOverlay, focus, numbers, split_panel,
+    HoldToConfirm, PauseAction, PauseMenu, RecordingBadge, VolumeOsd, Watchdog,
 };
-use ratatui::{
-    backend::{Backend, CrosstermBackend},
-    Terminal,
-};
-use std::io::{self, Stdout, Write};
-use std::time::{Duration, Instant};
+use ratatui::Frame;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Once};
+use std::time::Duration;
+
+/// Durée du fondu de la musique lors d'un arrêt par Ctrl+C ou par signal
+const SHUTDOWN_FADE: Duration = Duration::from_millis(400);
+
+/// Intervalle de rafraîchissement des écrans d'erreur en attendant une touche
+const ERROR_SCREEN_POLL: Duration = Duration::from_millis(250);
+
+/// Pas du volume général pour un appui sur '+' ou '-' pendant une partie
+const VOLUME_STEP: f32 = 0.1;
+/// Cadence de la boucle quand la partie est en pause ou terminée
+const IDLE_TICK_RATE: Duration = Duration::from_millis(250);
 
 pub struct App {
     registry: GameRegistry,
+    /// Réglages partagés avec le menu et les parties, ou l'erreur de lecture
+    /// de `config.json`
+    config: Result<ConfigHandle, String>,
+    /// "Quit" choisi dans le menu de pause : ne termine que cette
+    /// application, là où Ctrl+C et les signaux arrêtent tout le processus
+    /// (et chaque session d'un serveur)
+    quit: AtomicBool,
+    /// Session d'un joueur distant : rien n'est écrit dans les réglages ni
+    /// dans les fichiers de l'hôte en dehors des scores et de l'historique
+    guest: bool,
 }
 
 impl App {
     pub fn new() -> Self {
+        Self::with_registry(GameRegistry::new())
+    }
+
+    /// Application sur les jeux de `registry`, par exemple
+    /// [`GameRegistry::silent`] pour les tests
+    pub fn with_registry(registry: GameRegistry) -> Self {
         Self {
-            registry: GameRegistry::new(),
+            registry,
+            config: config::handle().map_err(|e| e.to_string()),
+            quit: AtomicBool::new(false),
+            guest: false,
         }
     }
 
-    pub fn run_game(&mut self, game_name: &str) -> GameResult {
-        if let Some(mut game) = self.registry.get_game(game_name) {
-            let mut terminal = self.setup_terminal()?;
+    /// Session invitée sur les jeux de `registry`, pour un joueur du serveur
+    /// SSH : ses réglages ne valent que pour elle, et l'onglet Data,
+    /// l'enregistrement avec F9 et la reprise des parties ne sont pas proposés
+    pub fn guest(registry: GameRegistry) -> Self {
+        let mut app = Self::with_registry(registry);
+        app.config = app.config.map(|config| config.detached());
+        app.guest = true;
+        app
+    }
+
+    /// Installe, une seule fois pour tout le processus, le hook qui change
+    /// les panics des parties en écran d'erreur et l'arrêt propre sur
+    /// SIGINT, SIGTERM et SIGHUP. `run_game` et `run_menu` s'en chargent ;
+    /// à appeler avant `run_game_on` ou `run_menu_on` sur un autre frontend
+    pub fn install_hooks() {
+        static INSTALLED: Once = Once::new();
+        INSTALLED.call_once(|| {
+            crash::install_hook();
+            shutdown::install_signal_handlers();
+        });
+    }
 
-            // Installer un hook de panic pour nettoyer le terminal
-            let original_hook = std::panic::take_hook();
-            std::panic::set_hook(Box::new(move |panic_info| {
-                let _ = disable_raw_mode();
-                let _ = execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture);
-                let _ = io::stdout().flush();
-                original_hook(panic_info);
-            }));
+    /// Lance un jeu dans le terminal de l'utilisateur
+    pub fn run_game(&mut self, name: &str) -> GameResult {
+        if !self.has_game(name) {
+            eprintln!("Game '{name}' not found!");
+            return Ok(());
+        }
 
-            let result = self.run_game_loop(&mut game, &mut terminal);
+        Self::install_hooks();
+        let mut frontend = CrosstermFrontend::new(self.title_enabled())?;
+        let result = self.run_game_on(name, &mut frontend);
+        frontend.restore()?;
+        result
+    }
 
-            // Restaurer le hook de panic original
-            let _ = std::panic::take_hook();
+    /// Lance un jeu sur `frontend`, jusqu'à la fin de la partie
+    pub fn run_game_on<F: Frontend>(&mut self, name: &str, frontend: &mut F) -> GameResult {
+        let (Some(game_id), Some(game)) =
+            (self.registry.find_id(name), self.registry.get_game(name))
+        else {
+            return Err(format!("Game '{name}' not found").into());
+        };
 
-            self.restore_terminal(&mut terminal)?;
-            result
-        } else {
-            eprintln!("Game '{game_name}' not found!");
-            Ok(())
+        *self.quit.get_mut() = false;
+        let result = self.play(game_id, game, frontend, true);
+
+        if self.stopping() {
+            AudioManager::fade_out_music(SHUTDOWN_FADE);
+        }
+
+        match result? {
+            Some(report) => Err(format!("{name} crashed: {}", report.message).into()),
+            None => Ok(()),
         }
     }
 
+    /// Lance le menu principal dans le terminal de l'utilisateur
     pub fn run_menu(&mut self) -> GameResult {
-        let mut terminal = self.setup_terminal()?;
-
-        // Installer un hook de panic pour nettoyer le terminal
-        let original_hook = std::panic::take_hook();
-        std::panic::set_hook(Box::new(move |panic_info| {
-            let _ = disable_raw_mode();
-            let _ = execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture);
-            let _ = io::stdout().flush();
-            original_hook(panic_info);
-        }));
-
-        let mut menu = MainMenu::new(self.registry.list_games())
-            .map_err(|e| format!("Failed to initialize menu: {e}"))?;
+        Self::install_hooks();
+        let mut frontend = CrosstermFrontend::new(self.title_enabled())?;
+        let result = self.run_menu_on(&mut frontend);
+        frontend.restore()?;
+        result
+    }
+
+    /// Lance le menu principal sur `frontend`, jusqu'à ce que le joueur quitte
+    pub fn run_menu_on<F: Frontend>(&mut self, frontend: &mut F) -> GameResult {
+        *self.quit.get_mut() = false;
+
+        let config = match &self.config {
+            Ok(config) => config.clone(),
+            Err(error) => {
+                // Réglages illisibles : l'expliquer avant de rendre la main
+                wait_for_key(frontend, |f| crash::draw_load_error_screen(f, error))?;
+                return Err(format!("Failed to initialize menu: {error}").into());
+            }
+        };
+        let display = &config.snapshot().display;
+        let startup = if display.splash_screen {
+            splash::load_startup(frontend)?
+        } else {
+            StartupData::load()
+        };
+        if display.title_screen {
+            attract::run(frontend, &self.registry, &startup.highscore_manager)?;
+        }
+
+        let mut menu = MainMenu::new(
+            self.registry.list_games(),
+            startup,
+            self.registry.audio_sink(),
+            config,
+        )
+        .with_guest_mode(self.guest);
+        // Une sauvegarde restée sur le disque vient d'une session interrompue
+        menu.offer_resume();
         let mut last_tick = Instant::now();
+        let mut audio_recovery = AudioRecovery::new();
 
         loop {
-            terminal.draw(|f| menu.draw(f))?;
+            if self.stopping() {
+                break;
+            }
 
-            let timeout = Duration::from_millis(100)
+            if let Some(notice) = audio_recovery.poll() {
+                menu.show_toast(notice);
+            }
+
+            // Aperçu du jeu sélectionné dans Games, créé à sa première sélection
+            if let Some(game_id) = menu.preview_needed() {
+                let game = self.registry.get_game(&game_id);
+                menu.add_preview(game_id, game);
+            }
+
+            frontend.set_title("TermPlay");
+            frontend.terminal().draw(|f| menu.draw(f))?;
+
+            let mut timeout = Duration::from_millis(100)
                 .checked_sub(last_tick.elapsed())
                 .unwrap_or_else(|| Duration::from_secs(0));
+            if menu.animating() {
+                timeout = timeout.min(animation::FRAME_INTERVAL);
+            }
 
-            if event::poll(timeout)? {
-                if let Event::Key(key) = event::read()? {
+            if let Some(event) = frontend.poll_event(timeout)? {
+                match event {
                     // Ne traiter que les événements de pression de touche pour éviter les répétitions
-                    if key.kind == KeyEventKind::Press {
+                    Event::Key(key) if shutdown::is_interrupt(&key) => shutdown::request(),
+                    Event::Key(key) if key.kind == KeyEventKind::Press => {
                         match menu.handle_key(key) {
                             GameAction::Quit => break,
                             GameAction::Continue => continue,
                             GameAction::GameOver => {
-                                if let Some(selected_game) = menu.get_selected_game() {
+                                // La partie reprend le canal de musique
+                                menu.stop_music_player();
+                                // Un code de partage désigne lui-même son jeu
+                                let share_code = menu.take_share_code();
+                                let random_game = menu.take_random_game();
+                                let selected_game = share_code
+                                    .map(|code| code.game.id())
+                                    .or(random_game.as_deref())
+                                    .or_else(|| menu.get_selected_game());
+                                // Le défi et les modificateurs s'appliquent dès la
+                                // création du jeu
+                                match menu.selected_challenge().filter(|_| share_code.is_none()) {
+                                    Some(weekly) => challenge::start(weekly),
+                                    None => mutators::set(&menu.selected_mutators()),
+                                }
+                                if let Some(selected_game) = selected_game {
                                     if let Some(mut game) = self.registry.get_game(selected_game) {
-                                        self.run_game_loop(&mut game, &mut terminal)?;
+                                        if let Some(code) = &share_code {
+                                            game.play_share_code(code);
+                                        }
+                                        // Un code de partage impose sa partie : pas de reprise
+                                        let resume = share_code.is_none();
+                                        let crashed =
+                                            self.play(selected_game, game, frontend, resume);
+                                        challenge::stop();
+                                        let crashed = crashed?.is_some();
+                                        // Le son a pu être réglé pendant la partie
+                                        menu.reload_audio_config();
+                                        menu.show_new_scores(highscores::take_new_scores());
+                                        menu.refresh_interrupted();
                                         // Ne pas recréer le menu - la pile de navigation est préservée
-                                        // Le menu reviendra automatiquement au menu Games grâce à la pile
+                                        // Le menu reviendra automatiquement au menu Games grâce à la pile,
+                                        // sauf après un crash où l'on repart du menu principal
+                                        if crashed {
+                                            menu.return_to_main();
+                                        }
                                     }
                                 }
                             }
                         }
                     }
+                    Event::Mouse(mouse) => menu.handle_mouse(mouse),
+                    Event::FocusLost if menu.pause_on_focus_loss() => AudioManager::pause_music(),
+                    Event::FocusGained => AudioManager::resume_music(),
+                    Event::Paste(text) => menu.handle_paste(&text),
+                    _ => {}
                 }
             }
 
-            // Update du menu pour gérer la musique
+            // Redessiner régulièrement pour suivre la position de la musique
             if last_tick.elapsed() >= Duration::from_millis(100) {
-                menu.update();
                 last_tick = Instant::now();
             }
         }
 
-        // Restaurer le hook de panic original
-        let _ = std::panic::take_hook();
-
         // IMPORTANT: Nettoyer l'audio AVANT de restaurer le terminal
+        if self.stopping() {
+            AudioManager::fade_out_music(SHUTDOWN_FADE);
+        }
         menu.cleanup_audio();
 
-        self.restore_terminal(&mut terminal)?;
         Ok(())
     }
 
-    pub fn list_games(&self) {
-        println!("Available games:");
-        for game_info in self.registry.list_games() {
-            println!("  {} - {}", game_info.name, game_info.description);
+    /// Affiche les jeux dans l'ordre du menu Games, avec les jeux masqués
+    /// seulement si `all` est demandé, et leur présentation détaillée et
+    /// leurs touches avec `verbose`
+    pub fn list_games(&self, all: bool, verbose: bool) {
+        let settings = self.settings();
+        let list = &settings.game_list;
+        let games = self.registry.list_games();
+        let shown = arrange(&games, list, all);
+
+        println!("Available games (launch with 'termplay game <id>'):");
+        for game_info in &shown {
+            let hidden = if list.hidden.contains(&game_info.id) {
+                " (hidden)"
+            } else {
+                ""
+            };
+            println!(
+                "  {:<14} {} - {}{hidden}",
+                game_info.id, game_info.name, game_info.description
+            );
+            if !game_info.aliases.is_empty() {
+                println!("  {:<14} aliases: {}", "", game_info.aliases.join(", "));
+            }
+            if verbose {
+                println!("  {:<14} {}", "", game_info.details());
+                for (key, action) in game_info.controls {
+                    println!("  {:<14}   {key:<16} {action}", "");
+                }
+                println!();
+            }
+        }
+
+        let hidden_count = games.len() - shown.len();
+        if hidden_count > 0 {
+            println!("{hidden_count} hidden game(s), use 'termplay list --all' to show them.");
         }
     }
 
+    /// Jeu tiré au hasard parmi ceux du menu Games, en évitant les derniers
+    /// joués ; `None` si tous les jeux sont masqués
+    pub fn random_game(&self) -> Option<String> {
+        let games = self.registry.list_games();
+        let candidates: Vec<&str> = arrange(&games, &self.settings().game_list, false)
+            .into_iter()
+            .map(|game| game.id.as_str())
+            .collect();
+        surprise::pick(&candidates, &history::load(), &mut rand::rng()).map(str::to_string)
+    }
+
     pub fn has_game(&self, name: &str) -> bool {
         self.registry.has_game(name)
     }
 
-    fn setup_terminal(
-        &self,
-    ) -> Result<Terminal<CrosstermBackend<Stdout>>, Box<dyn std::error::Error>> {
-        enable_raw_mode()?;
-        let mut stdout = io::stdout();
-        execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
-        let backend = CrosstermBackend::new(stdout);
-        Ok(Terminal::new(backend)?)
+    /// Identifiants et alias de tous les jeux, pour la complétion
+    pub fn game_names(&self) -> Vec<String> {
+        self.registry
+            .list_games()
+            .into_iter()
+            .flat_map(|info| std::iter::once(&info.id).chain(&info.aliases))
+            .cloned()
+            .collect()
     }
 
-    fn restore_terminal(&self, terminal: &mut Terminal<CrosstermBackend<Stdout>>) -> GameResult {
-        // Forcer l'affichage du curseur avant tout
-        let _ = terminal.show_cursor();
+    /// Noms de jeux proches de `name`, pour un nom inconnu
+    pub fn suggest_games(&self, name: &str) -> Vec<&str> {
+        self.registry.suggest(name)
+    }
+
+    /// Lance une partie en interceptant les panics : en cas de crash, affiche
+    /// un écran d'excuse et retourne le rapport au lieu de quitter. Avec
+    /// `resume`, une partie interrompue de ce jeu reprend là où elle en était
+    fn play<F: Frontend>(
+        &self,
+        game_id: &str,
+        mut game: Box<dyn Game>,
+        frontend: &mut F,
+        resume: bool,
+    ) -> Result<Option<CrashReport>, Box<dyn std::error::Error>> {
+        match crash::run_guarded(|| self.run_game_loop(game_id, &mut game, frontend, resume)) {
+            Ok(result) => result.map(|_| None),
+            Err(report) => {
+                // Libérer le jeu (et son audio) avant d'afficher l'écran d'erreur
+                drop(game);
+                self.show_crash_screen(self.display_name(game_id), &report, frontend)?;
+                Ok(Some(report))
+            }
+        }
+    }
 
-        // Désactiver le mode raw
-        let _ = disable_raw_mode();
+    /// Nom affiché du jeu, ou son identifiant s'il est inconnu
+    fn display_name<'a>(&'a self, game_id: &'a str) -> &'a str {
+        self.registry
+            .get_info(game_id)
+            .map_or(game_id, |info| info.name.as_str())
+    }
 
-        // Nettoyer l'écran et restaurer le terminal
-        let _ = execute!(
-            terminal.backend_mut(),
-            crossterm::terminal::Clear(crossterm::terminal::ClearType::All),
-            LeaveAlternateScreen,
-            DisableMouseCapture
-        );
+    fn show_crash_screen<F: Frontend>(
+        &self,
+        game_name: &str,
+        report: &CrashReport,
+        frontend: &mut F,
+    ) -> GameResult {
+        // Le panic a pu survenir au milieu d'un rendu
+        frontend.terminal().clear()?;
+        wait_for_key(frontend, |f| crash::draw_crash_screen(f, game_name, report))
+    }
 
-        // Forcer un flush final
-        let _ = io::stdout().flush();
+    /// Réglages en vigueur, ceux par défaut si `config.json` est illisible
+    fn settings(&self) -> Arc<GameConfig> {
+        self.config
+            .as_ref()
+            .map_or_else(|_| Arc::default(), ConfigHandle::snapshot)
+    }
 
-        // Petite pause pour s'assurer que tout est nettoyé
-        std::thread::sleep(Duration::from_millis(50));
+    /// Arrêt demandé, pour cette application ou pour tout le processus
+    fn stopping(&self) -> bool {
+        self.quit.load(Ordering::SeqCst) || shutdown::requested()
+    }
 
-        Ok(())
+    /// Mise à jour du titre du terminal, selon les préférences d'affichage
+    fn title_enabled(&self) -> bool {
+        self.settings().display.terminal_title
     }
 
-    fn run_game_loop<B: Backend>(
+    fn run_game_loop<F: Frontend>(
         &self,
+        game_id: &str,
         game: &mut Box<dyn Game>,
-        terminal: &mut Terminal<B>,
+        frontend: &mut F,
+        resume: bool,
     ) -> GameResult {
+        let config = self.config.as_ref().ok();
+        let settings = self.settings();
+        let show_progress = settings.display.title_progress;
+        let pause_on_focus_loss = settings.gameplay.pause_on_focus_loss;
+        let show_splits = settings.gameplay.split_timer;
+        let frame_skip = settings.display.frame_skip;
+        // Les parties du défi de la semaine ne se reprennent pas, ni celles
+        // des invités, qui partageraient la sauvegarde de chaque jeu
+        let saving = !self.guest && challenge::active().is_none();
+        let autosave_period = settings.gameplay.autosave.period().filter(|_| saving);
+        restore_game_audio(config, game_id, game.as_ref());
         let mut last_tick = Instant::now();
+        // Partie figée tant que le terminal n'a pas retrouvé le focus
+        let mut focus_lost = false;
+        // Menu de pause ouvert avec Échap ; la partie est figée aussi
+        let mut pause_menu: Option<PauseMenu> = None;
+        let mut volume_osd = VolumeOsd::new();
+        // R tenu pour recommencer une partie en cours
+        let mut restart_hold = HoldToConfirm::new();
+        // Temps de jeu de la session, pour l'historique
+        let mut session_clock = GameClock::new();
+        // Enregistrement de la partie, démarré et arrêté avec F9
+        let mut recording: Option<CastRecorder> = None;
+        let mut recording_badge = RecordingBadge::new();
+        let mut last_autosave = Instant::now();
+        let mut audio_recovery = AudioRecovery::new();
+        // Objectif de score personnel, fêté quand la partie le franchit
+        let mut target = TargetWatch::for_game(game_id);
+        let mut celebration = Celebration::new();
+        // Relevé de cadence, affiché avec F12
+        let mut debug_overlay = DebugOverlay::new();
+        // Ticks trop lents d'affilée : la partie est figée et le joueur
+        // peut revenir au menu
+        let mut watchdog = Watchdog::new();
+        // Ticks passés sans dessiner ; une touche ou un changement d'état de
+        // la partie fait dessiner l'image suivante sans attendre son tour
+        let mut skipped_frames = 0;
+        let mut force_draw = true;
+        let mut last_status = game.status();
+
+        if resume && saving {
+            if let Some(saved) = saves::load(game_id) {
+                if game.restore_state(saved.state) {
+                    // Objectif déjà fêté avant l'interruption
+                    target.check(game.session_score());
+                    recording_badge.show_notice(format!(
+                        "Resumed game saved at {}",
+                        time_format::format_time(saved.saved_at.to_utc())
+                    ));
+                }
+            }
+        }
 
         loop {
-            terminal.draw(|f| game.draw(f))?;
+            if self.stopping() {
+                game.on_shutdown();
+                // Gardée pour le prochain lancement
+                if let Some(state) = game.save_state().filter(|_| saving) {
+                    saves::save(game_id, save_summary(game.as_ref()), state);
+                }
+                break;
+            }
+
+            if let Some(notice) = audio_recovery.poll() {
+                recording_badge.show_notice(notice.to_string());
+            }
+
+            let title = game_title(self.display_name(game_id), game.as_ref(), show_progress);
+            frontend.set_title(&title);
+            // Avec Frame Skip, la partie avance à chaque tick mais n'est
+            // dessinée qu'une fois sur N, sauf à l'arrêt où rien ne bouge
+            let idle = focus_lost
+                || pause_menu.is_some()
+                || watchdog.is_warning()
+                || game.status() != GameStatus::Running;
+            // Temps passé à dessiner et à faire avancer la partie pendant ce
+            // tour de boucle, pour le watchdog
+            let mut work: Option<Duration> = None;
+            if force_draw || idle || skipped_frames + 1 >= frame_skip.interval() {
+                force_draw = false;
+                skipped_frames = 0;
+                let started = Instant::now();
+                let mut capture_error = None;
+                frontend.terminal().draw(|f| {
+                    game.draw(f, f.area());
+                    if let Some((timer, elapsed)) = game.splits().filter(|_| show_splits) {
+                        split_panel::draw_split_panel(f, timer, elapsed);
+                    }
+                    if let Some(menu) = &mut pause_menu {
+                        let (music, effects) = game.audio().map_or((false, false), |audio| {
+                            (audio.is_music_enabled(), audio.is_enabled())
+                        });
+                        menu.draw(f, music, effects);
+                    }
+                    volume_osd.draw(f);
+                    celebration.draw(f);
+                    restart_hold.draw(f, "Hold R to restart");
+                    watchdog.draw(f);
+                    if focus_lost {
+                        focus::draw_focus_lost(f);
+                    }
+                    // L'image est enregistrée telle que le joueur la voit, sans
+                    // le voyant d'enregistrement
+                    if let Some(recorder) = &mut recording {
+                        capture_error = recorder.capture(f.buffer_mut()).err();
+                    }
+                    recording_badge.draw(f, recording.as_ref().map(CastRecorder::elapsed));
+                    debug_overlay.draw(f, frame_skip);
+                })?;
+                let draw_time = started.elapsed();
+                debug_overlay.record_frame(Instant::now(), draw_time);
+                work = Some(draw_time);
+                if let Some(e) = capture_error {
+                    recording = None;
+                    recording_badge.show_notice(format!("Recording stopped: {e}"));
+                }
+            } else {
+                skipped_frames += 1;
+            }
 
-            let tick_rate = game.tick_rate(); // Obtenir le tick rate dynamique
-            let timeout = tick_rate
+            let mut tick_rate = game.tick_rate(); // Obtenir le tick rate dynamique
+            if idle {
+                // Rien ne bouge : inutile de redessiner (et de relancer la
+                // musique dans update) à pleine cadence
+                tick_rate = tick_rate.max(IDLE_TICK_RATE);
+            }
+            let mut timeout = tick_rate
                 .checked_sub(last_tick.elapsed())
                 .unwrap_or_else(|| Duration::from_secs(0));
+            if pause_menu.as_ref().is_some_and(PauseMenu::animating)
+                || restart_hold.is_holding()
+                || celebration.is_visible()
+            {
+                timeout = timeout.min(animation::FRAME_INTERVAL);
+            } else if pause_menu.is_none()
+                && game.status() == GameStatus::Running
+                && game.danger() != DangerLevel::Calm
+                && !animation::reduce_motion()
+            {
+                // La teinte de danger pulse même quand le jeu tourne lentement
+                timeout = timeout.min(animation::DANGER_FRAME_INTERVAL);
+            }
 
-            if crossterm::event::poll(timeout)? {
-                if let Event::Key(key) = event::read()? {
+            if let Some(event) = frontend.poll_event(timeout)? {
+                // La réponse à une touche s'affiche sans attendre
+                force_draw = true;
+                match event {
+                    Event::Key(key) if shutdown::is_interrupt(&key) => {
+                        // Traité au début de la prochaine itération
+                        shutdown::request();
+                        continue;
+                    }
                     // Ne traiter que les événements de pression de touche
-                    if key.kind == KeyEventKind::Press {
-                        match game.handle_key(key) {
+                    Event::Key(key) if key.kind == KeyEventKind::Press => {
+                        // Une touche vaut retour du focus (terminaux sans FocusGained)
+                        if focus_lost {
+                            focus_lost = false;
+                            if pause_menu.is_none() && !watchdog.is_warning() {
+                                game.on_resume();
+                                session_clock.unsuspend();
+                                AudioManager::resume_music();
+                            }
+                        }
+
+                        if watchdog.is_warning() {
+                            if matches!(key.code, KeyCode::Char('q' | 'Q')) {
+                                game.on_shutdown();
+                                AudioManager::resume_music();
+                                break;
+                            }
+                            watchdog.dismiss();
+                            game.on_resume();
+                            session_clock.unsuspend();
+                            AudioManager::resume_music();
+                            continue;
+                        }
+
+                        if key.code == KeyCode::F(12) {
+                            debug_overlay.toggle();
+                            continue;
+                        }
+
+                        if key.code == KeyCode::F(9) && self.guest {
+                            recording_badge
+                                .show_notice("Recording is not available here".to_string());
+                            continue;
+                        }
+                        if key.code == KeyCode::F(9) {
+                            toggle_recording(
+                                &mut recording,
+                                &mut recording_badge,
+                                game_id,
+                                game.as_ref(),
+                                &title,
+                                frontend.terminal().get_frame().area(),
+                            );
+                            continue;
+                        }
+
+                        // Les jeux qui saisissent du texte ou utilisent '+'/'-' gardent
+                        // ces touches ; F10 n'est utilisée par aucun jeu
+                        let volume_keys = key.code == KeyCode::F(10)
+                            || !(game.captures_esc() || game.captures_volume_keys());
+                        if volume_keys {
+                            if let Some((volume, muted)) =
+                                adjust_volume(config, game.as_ref(), key.code)
+                            {
+                                volume_osd.show(volume, muted);
+                                continue;
+                            }
+                        }
+
+                        if let Some(menu) = &mut pause_menu {
+                            match menu.handle_key(key) {
+                                Some(PauseAction::Resume) => {
+                                    pause_menu = None;
+                                    game.on_resume();
+                                    session_clock.unsuspend();
+                                    AudioManager::resume_music();
+                                }
+                                Some(PauseAction::Restart) => {
+                                    game.on_shutdown();
+                                    saves::discard(game_id);
+                                    record_session(
+                                        game_id,
+                                        game.as_ref(),
+                                        &session_clock,
+                                        target.reached(),
+                                    );
+                                    session_clock.start();
+                                    // Le défi rejoue les mêmes tirages
+                                    if let Some(weekly) = challenge::active() {
+                                        challenge::start(weekly);
+                                    }
+                                    if let Some(new_game) = self.registry.get_game(game_id) {
+                                        *game = new_game;
+                                        restore_game_audio(config, game_id, game.as_ref());
+                                    }
+                                    pause_menu = None;
+                                    AudioManager::resume_music();
+                                }
+                                Some(PauseAction::ToggleMusic) => {
+                                    toggle_game_audio(config, game_id, game.as_ref(), true)
+                                }
+                                Some(PauseAction::ToggleEffects) => {
+                                    toggle_game_audio(config, game_id, game.as_ref(), false)
+                                }
+                                Some(PauseAction::MainMenu) => {
+                                    game.on_shutdown();
+                                    AudioManager::resume_music();
+                                    break;
+                                }
+                                // La sauvegarde se fait au début de la prochaine itération
+                                Some(PauseAction::Quit) => self.quit.store(true, Ordering::SeqCst),
+                                None => {}
+                            }
+                            continue;
+                        }
+
+                        // Recommencer en pleine partie demande de tenir R
+                        let guarded_restart = key.code == KeyCode::Char('r')
+                            && matches!(game.status(), GameStatus::Running | GameStatus::Paused)
+                            && !(game.instant_restart() || game.captures_esc());
+                        if !guarded_restart {
+                            restart_hold.cancel();
+                        } else if !restart_hold.press(Instant::now()) {
+                            continue;
+                        }
+
+                        if key.code == KeyCode::Esc && !game.captures_esc() {
+                            let controls = self
+                                .registry
+                                .get_info(game_id)
+                                .map_or(&[][..], |info| info.controls);
+                            pause_menu = Some(PauseMenu::new().with_controls(controls));
+                            game.on_pause();
+                            session_clock.suspend();
+                            AudioManager::pause_music();
+                            continue;
+                        }
+
+                        if let Some(recorder) = &mut recording {
+                            recorder.record_key(key);
+                        }
+                        let audio_before = audio_state(game.as_ref());
+                        let action = game.handle_key(mutators::map_key(key));
+                        remember_game_audio(config, game_id, game.as_ref(), audio_before);
+                        match action {
                             GameAction::Quit => break,
                             GameAction::GameOver => break,
                             GameAction::Continue => {}
                         }
                     }
+                    Event::FocusLost if pause_on_focus_loss && !focus_lost => {
+                        focus_lost = true;
+                        if pause_menu.is_none() && !watchdog.is_warning() {
+                            game.on_pause();
+                            session_clock.suspend();
+                            AudioManager::pause_music();
+                        }
+                    }
+                    Event::Paste(text) if pause_menu.is_none() && !watchdog.is_warning() => {
+                        game.handle_paste(&text)
+                    }
+                    Event::Mouse(mouse)
+                        if pause_menu.is_none() && !focus_lost && !watchdog.is_warning() =>
+                    {
+                        game.handle_mouse(mouse)
+                    }
+                    Event::Key(key)
+                        if key.kind == KeyEventKind::Release && key.code == KeyCode::Char('r') =>
+                    {
+                        restart_hold.cancel();
+                    }
+                    Event::FocusGained if focus_lost => {
+                        focus_lost = false;
+                        if pause_menu.is_none() && !watchdog.is_warning() {
+                            game.on_resume();
+                            session_clock.unsuspend();
+                            AudioManager::resume_music();
+                        }
+                    }
+                    _ => {}
                 }
             }
 
-            if last_tick.elapsed() >= tick_rate {
-                match game.update() {
+            if focus_lost || pause_menu.is_some() || watchdog.is_warning() {
+                // Ne pas accumuler de retard pendant la pause
+                last_tick = Instant::now();
+            } else if last_tick.elapsed() >= tick_rate {
+                let started = Instant::now();
+                let action = game.update();
+                work = Some(work.unwrap_or_default() + started.elapsed());
+                match action {
                     GameAction::Quit => break,
                     GameAction::GameOver => break,
                     GameAction::Continue => {}
                 }
                 last_tick = Instant::now();
             }
+
+            // Seuls les tours d'une partie en cours comptent : un écran de
+            // pause ou de fin n'a rien à surveiller
+            let running = !idle && game.status() == GameStatus::Running;
+            if let Some(slowest) = work
+                .filter(|_| running)
+                .and_then(|work| watchdog.record(work))
+            {
+                crash::log_stall(game_id, slowest);
+                game.on_pause();
+                session_clock.suspend();
+                AudioManager::pause_music();
+                force_draw = true;
+            }
+
+            if game.status() != last_status {
+                // Hors du jeu lui-même (pause, écran de fin…), les
+                // percussions s'effacent ; le jeu les relance en reprenant
+                if last_status == GameStatus::Running {
+                    if let Some(audio) = game.audio() {
+                        audio.set_music_intensity(0.0);
+                    }
+                }
+                last_status = game.status();
+                force_draw = true;
+            }
+
+            if let Some(reached) = target.check(game.session_score()) {
+                force_draw = true;
+                celebration.show(format!("Target reached: {} pts!", numbers::score(reached)));
+                if let Some(audio) = game.audio() {
+                    audio.play_sound(SoundEffect::TargetReached);
+                }
+            }
+
+            if let Some(period) = autosave_period {
+                if last_autosave.elapsed() >= period && !focus_lost && pause_menu.is_none() {
+                    last_autosave = Instant::now();
+                    // Rien à reprendre d'une partie finie : la sauvegarde
+                    // précédente ne doit pas la ressusciter
+                    match game.save_state() {
+                        Some(state) => {
+                            let summary = save_summary(game.as_ref());
+                            jobs::spawn("Autosaving", saves::prepare(game_id, summary, state));
+                        }
+                        None => saves::discard(game_id),
+                    }
+                }
+            }
+        }
+
+        // Partie terminée ou quittée : plus rien à reprendre
+        if !self.stopping() && saving {
+            saves::discard(game_id);
+        }
+        record_session(game_id, game.as_ref(), &session_clock, target.reached());
+        if let Some(recorder) = recording {
+            if let Err(e) = recorder.finish() {
+                eprintln!("Erreur lors de l'enregistrement de la partie: {e}");
+            }
         }
 
         // Les ressources du jeu seront nettoyées automatiquement par Drop
@@ -199,3 +808,236 @@ impl App {
         Ok(())
     }
 }
+
+impl Default for App {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Affiche un écran d'erreur jusqu'à l'appui sur une touche
+fn wait_for_key<F: Frontend>(frontend: &mut F, draw: impl Fn(&mut Frame)) -> GameResult {
+    loop {
+        frontend.terminal().draw(|f| draw(f))?;
+
+        if let Some(Event::Key(key)) = frontend.poll_event(ERROR_SCREEN_POLL)? {
+            if key.kind == KeyEventKind::Press {
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// Démarre l'enregistrement de la partie, ou l'arrête et annonce le fichier
+/// enregistré
+fn toggle_recording(
+    recording: &mut Option<CastRecorder>,
+    badge: &mut RecordingBadge,
+    game_id: &str,
+    game: &dyn Game,
+    title: &str,
+    area: ratatui::layout::Rect,
+) {
+    let notice = match recording.take() {
+        Some(recorder) => match recorder.finish() {
+            Ok(path) => {
+                let name = path.file_name().unwrap_or_default().to_string_lossy();
+                format!("Saved recordings/{name}")
+            }
+            Err(e) => format!("Recording failed: {e}"),
+        },
+        None => match CastRecorder::start(game_id, title, area, &replay_header(game_id, game)) {
+            Ok(recorder) => {
+                *recording = Some(recorder);
+                return;
+            }
+            Err(e) => format!("Cannot record: {e}"),
+        },
+    };
+    badge.show_notice(notice);
+}
+
+/// En-tête du journal des touches : la graine et le code de partage des jeux
+/// qui les communiquent, et les modificateurs actifs
+fn replay_header(game_id: &str, game: &dyn Game) -> ReplayHeader {
+    let shared = game.shared_game();
+    let mut options = Vec::new();
+    if let Some(code) = shared {
+        options.push(("code".to_string(), code.encode()));
+    }
+    let modifiers = mutators::active();
+    if !modifiers.is_empty() {
+        let names: Vec<&str> = modifiers.iter().map(|mutator| mutator.name()).collect();
+        options.push(("modifiers".to_string(), names.join(",")));
+    }
+    ReplayHeader {
+        game_id: game_id.to_string(),
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        seed: shared.map_or(0, |code| code.seed),
+        options,
+    }
+}
+
+/// Ajoute la session terminée à l'historique des parties
+fn record_session(game_id: &str, game: &dyn Game, clock: &GameClock, target_reached: Option<u32>) {
+    let played = clock.elapsed();
+    if played < history::MIN_SESSION_DURATION {
+        return;
+    }
+
+    let record = SessionRecord {
+        game: game_id.to_string(),
+        mode: game.session_mode(),
+        score: game.session_score(),
+        target_reached,
+        won: game.status() == GameStatus::Victory,
+        duration_seconds: played.as_secs(),
+        timestamp: chrono::Utc::now(),
+    };
+    if let Err(e) = history::record(&record) {
+        eprintln!("Erreur lors de l'enregistrement de l'historique: {e}");
+    }
+}
+
+/// Touches de volume valables dans tous les jeux : '+'/'-' règlent le volume
+/// général et rétablissent le son, F10 le coupe ou le rétablit. Le réglage
+/// est enregistré ; renvoie le nouveau volume et l'état coupé, ou `None` si
+/// la touche ne règle pas le volume
+fn adjust_volume(
+    config: Option<&ConfigHandle>,
+    game: &dyn Game,
+    key: KeyCode,
+) -> Option<(f32, bool)> {
+    let (mut volume, mut muted) = match (game.audio(), config) {
+        (Some(audio), _) => (audio.get_master_volume(), audio.is_muted()),
+        (None, Some(config)) => {
+            let audio = &config.snapshot().audio;
+            (audio.master_volume, audio.muted)
+        }
+        (None, None) => (AudioConfig::default().master_volume, false),
+    };
+
+    // Arrondi au dixième pour retomber exactement sur 0 et 100 %
+    match key {
+        KeyCode::Char('+' | '=') => {
+            volume = ((volume + VOLUME_STEP) * 10.0).round() / 10.0;
+            muted = false;
+        }
+        KeyCode::Char('-') => {
+            volume = ((volume - VOLUME_STEP) * 10.0).round() / 10.0;
+            muted = false;
+        }
+        KeyCode::F(10) => muted = !muted,
+        _ => return None,
+    }
+    let volume = volume.clamp(0.0, 1.0);
+
+    if let Some(audio) = game.audio() {
+        audio.set_master_volume(volume);
+        audio.set_muted(muted);
+        audio.refresh_music_volume();
+    }
+    if let Some(config) = config {
+        if let Err(e) = config.update(|config| {
+            config.audio.master_volume = volume;
+            config.audio.muted = muted;
+        }) {
+            eprintln!("Erreur lors de la sauvegarde de la configuration audio: {e}");
+        }
+    }
+    Some((volume, muted))
+}
+
+/// Coupe ou rétablit la musique (`music`) ou les effets du jeu depuis le
+/// menu de pause, et enregistre le choix pour ce jeu
+fn toggle_game_audio(config: Option<&ConfigHandle>, game_id: &str, game: &dyn Game, music: bool) {
+    let Some(audio) = game.audio() else {
+        return;
+    };
+
+    let before = audio_state(game);
+    if music {
+        audio.toggle_music();
+    } else {
+        audio.toggle_enabled();
+    }
+    remember_game_audio(config, game_id, game, before);
+}
+
+/// Musique et effets activés dans le jeu
+fn audio_state(game: &dyn Game) -> Option<(bool, bool)> {
+    game.audio()
+        .map(|audio| (audio.is_music_enabled(), audio.is_enabled()))
+}
+
+/// Enregistre pour ce jeu seulement la musique ou les effets changés depuis
+/// `before` (touches M et N des jeux, menu de pause)
+fn remember_game_audio(
+    config: Option<&ConfigHandle>,
+    game_id: &str,
+    game: &dyn Game,
+    before: Option<(bool, bool)>,
+) {
+    let (Some(config), Some((music_before, effects_before)), Some((music, effects))) =
+        (config, before, audio_state(game))
+    else {
+        return;
+    };
+    if (music, effects) == (music_before, effects_before) {
+        return;
+    }
+
+    if let Err(e) = config.update(|config| {
+        let preference = config.game_audio.entry(game_id.to_string()).or_default();
+        if music != music_before {
+            preference.music_enabled = Some(music);
+        }
+        if effects != effects_before {
+            preference.effects_enabled = Some(effects);
+        }
+    }) {
+        eprintln!("Erreur lors de la sauvegarde de la configuration audio: {e}");
+    }
+}
+
+/// Rétablit le son choisi pour ce jeu lors des parties précédentes
+fn restore_game_audio(config: Option<&ConfigHandle>, game_id: &str, game: &dyn Game) {
+    let (Some(audio), Some(config)) = (game.audio(), config) else {
+        return;
+    };
+    let preference = config
+        .snapshot()
+        .game_audio
+        .get(game_id)
+        .copied()
+        .unwrap_or_default();
+    if let Some(enabled) = preference.music_enabled {
+        audio.set_music_enabled(enabled);
+    }
+    if let Some(enabled) = preference.effects_enabled {
+        audio.set_enabled(enabled);
+    }
+}
+
+/// Statut d'une partie sauvegardée, montré par le menu de reprise
+fn save_summary(game: &dyn Game) -> Option<String> {
+    game.title_status().or_else(|| game.title_progress())
+}
+
+/// Construit le titre "TermPlay – <jeu> (<statut>)" d'une partie en cours
+fn game_title(game_name: &str, game: &dyn Game, show_progress: bool) -> String {
+    let details: Vec<String> = game
+        .status()
+        .label()
+        .map(str::to_string)
+        .into_iter()
+        .chain(game.title_status())
+        .chain(game.title_progress().filter(|_| show_progress))
+        .collect();
+
+    if details.is_empty() {
+        format!("TermPlay – {game_name}")
+    } else {
+        format!("TermPlay – {game_name} ({})", details.join(", "))
+    }
+}
@@ -0,0 +1,352 @@
+//! Écran titre façon borne d'arcade, affiché avant le menu quand le réglage
+//! Title Screen est actif.
+//!
+//! L'écran tourne en boucle comme le mode attraction d'une borne : le logo
+//! avec "INSERT COIN", quelques classements des high scores, puis une démo
+//! jouée par les bots (Pong en IA contre IA, l'autoplay de 2048), sans son.
+//! Une touche ouvre le menu.
+
+use crate::audio::AudioManager;
+use crate::core::clock::Instant;
+use crate::core::{Game, GameAction};
+use crate::event::{Event, KeyEventKind};
+use crate::frontend::Frontend;
+use crate::games::{pong::PongGame, GameRegistry, _2048::Game2048};
+use crate::highscores::HighScoreManager;
+use crate::shutdown;
+use crate::splash;
+use crate::ui::bigtext::{self, Font};
+use crate::ui::{animation, numbers, style};
+use ratatui::{
+    layout::{Alignment, Constraint, Flex, Layout, Rect},
+    style::{Style, Stylize},
+    text::Line,
+    widgets::{Clear, Paragraph},
+    Frame,
+};
+use std::io;
+use std::time::Duration;
+
+/// Intervalle entre deux images hors démo
+const FRAME_INTERVAL: Duration = Duration::from_millis(50);
+/// Durée de chaque page
+const LOGO_DURATION: Duration = Duration::from_secs(6);
+const SCORES_DURATION: Duration = Duration::from_secs(4);
+const DEMO_DURATION: Duration = Duration::from_secs(20);
+/// Demi-période du clignotement de "PRESS ANY KEY"
+const BLINK: Duration = Duration::from_millis(500);
+/// Classements montrés entre le logo et la démo ; les suivants attendent le
+/// tour suivant
+const BOARDS_PER_CYCLE: usize = 3;
+/// Lignes de chaque classement
+const TABLE_ROWS: usize = 5;
+const RANKS: [&str; TABLE_ROWS] = ["1ST", "2ND", "3RD", "4TH", "5TH"];
+
+/// Démos jouées à tour de rôle
+const DEMOS: [fn(AudioManager) -> Box<dyn Game>; 2] = [
+    |audio| Box::new(PongGame::demo(audio)),
+    |audio| Box::new(Game2048::autoplay(audio)),
+];
+
+/// Meilleurs scores d'un jeu
+struct Leaderboard {
+    name: String,
+    entries: Vec<(String, u32)>,
+}
+
+enum Page {
+    Logo,
+    /// Classement `next_board`, le n-ième de ce tour
+    Scores(usize),
+    Demo(Box<dyn Game>),
+}
+
+struct TitleScreen {
+    page: Page,
+    started: Instant,
+    leaderboards: Vec<Leaderboard>,
+    next_board: usize,
+    next_demo: usize,
+}
+
+impl TitleScreen {
+    fn new(registry: &GameRegistry, scores: &HighScoreManager) -> Self {
+        let leaderboards = scores
+            .get_games_with_scores()
+            .into_iter()
+            .map(|id| Leaderboard {
+                name: registry
+                    .get_info(&id)
+                    .map_or_else(|| id.clone(), |info| info.name.clone()),
+                entries: scores
+                    .get_scores(&id)
+                    .into_iter()
+                    .take(TABLE_ROWS)
+                    .map(|score| (score.player_name.clone(), score.score))
+                    .collect(),
+            })
+            .filter(|board| !board.entries.is_empty())
+            .collect();
+        Self {
+            page: Page::Logo,
+            started: Instant::now(),
+            leaderboards,
+            next_board: 0,
+            next_demo: 0,
+        }
+    }
+
+    fn duration(&self) -> Duration {
+        match self.page {
+            Page::Logo => LOGO_DURATION,
+            Page::Scores(_) => SCORES_DURATION,
+            Page::Demo(_) => DEMO_DURATION,
+        }
+    }
+
+    /// Page suivante : le logo, quelques classements, une démo, puis de
+    /// nouveau le logo
+    fn advance(&mut self, now: Instant) {
+        let boards = self.leaderboards.len().min(BOARDS_PER_CYCLE);
+        self.page = match std::mem::replace(&mut self.page, Page::Logo) {
+            Page::Logo if boards > 0 => Page::Scores(0),
+            Page::Scores(shown) => {
+                self.next_board = (self.next_board + 1) % self.leaderboards.len();
+                if shown + 1 < boards {
+                    Page::Scores(shown + 1)
+                } else {
+                    self.start_demo()
+                }
+            }
+            Page::Logo => self.start_demo(),
+            Page::Demo(_) => Page::Logo,
+        };
+        self.started = now;
+    }
+
+    fn start_demo(&mut self) -> Page {
+        let demo = DEMOS[self.next_demo % DEMOS.len()](AudioManager::silent());
+        self.next_demo += 1;
+        Page::Demo(demo)
+    }
+
+    /// "PRESS ANY KEY" visible ; fixe avec Reduce Motion
+    fn blink_on(&self) -> bool {
+        animation::reduce_motion()
+            || (self.started.elapsed().as_millis() / BLINK.as_millis()).is_multiple_of(2)
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let blink_on = self.blink_on();
+        match &mut self.page {
+            Page::Logo => draw_logo(frame, blink_on),
+            Page::Scores(_) => draw_scores(frame, &self.leaderboards[self.next_board], blink_on),
+            Page::Demo(game) => {
+                game.draw(frame, frame.area());
+                draw_demo_banner(frame, blink_on);
+            }
+        }
+    }
+}
+
+/// Affiche l'écran titre jusqu'à l'appui sur une touche
+pub fn run<F: Frontend>(
+    frontend: &mut F,
+    registry: &GameRegistry,
+    scores: &HighScoreManager,
+) -> io::Result<()> {
+    let mut screen = TitleScreen::new(registry, scores);
+    let mut last_tick = Instant::now();
+    loop {
+        if shutdown::requested() {
+            return Ok(());
+        }
+
+        let now = Instant::now();
+        if now.duration_since(screen.started) >= screen.duration() {
+            screen.advance(now);
+        }
+        let mut timeout = FRAME_INTERVAL;
+        if let Page::Demo(game) = &mut screen.page {
+            let tick_rate = game.tick_rate();
+            if last_tick.elapsed() >= tick_rate {
+                last_tick = Instant::now();
+                // Une démo qui s'arrête d'elle-même laisse la place au logo
+                if game.update() != GameAction::Continue {
+                    screen.advance(last_tick);
+                }
+            }
+            timeout = timeout.min(tick_rate.saturating_sub(last_tick.elapsed()));
+        }
+
+        frontend.terminal().draw(|f| screen.draw(f))?;
+
+        match frontend.poll_event(timeout)? {
+            Some(Event::Key(key)) if shutdown::is_interrupt(&key) => shutdown::request(),
+            Some(Event::Key(key)) if key.kind == KeyEventKind::Press => return Ok(()),
+            _ => {}
+        }
+    }
+}
+
+/// Ligne "PRESS ANY KEY", vide pendant la moitié éteinte du clignotement
+fn press_any_key(blink_on: bool) -> Line<'static> {
+    if blink_on {
+        Line::from("PRESS ANY KEY".yellow().bold())
+    } else {
+        Line::from("")
+    }
+}
+
+fn draw_logo(frame: &mut Frame, blink_on: bool) {
+    style::fill_background(frame, style::background());
+    let font = splash::logo_font(frame);
+    let [logo_area, coin_area] =
+        Layout::vertical([Constraint::Length(font.height() + 2), Constraint::Length(3)])
+            .flex(Flex::Center)
+            .areas(frame.area());
+
+    let mut logo = bigtext::banner(
+        "TERMPLAY",
+        font,
+        Style::new().cyan().bold(),
+        frame.area().width,
+    )
+    .unwrap_or_else(|| vec![Line::from("TERMPLAY".cyan().bold())]);
+    logo.push(Line::from(""));
+    logo.push(Line::from("Terminal Mini-Games Collection".magenta()));
+    frame.render_widget(Paragraph::new(logo).alignment(Alignment::Center), logo_area);
+
+    let coin = vec![
+        Line::from("INSERT COIN".fg(style::text()).bold()),
+        Line::from(""),
+        press_any_key(blink_on),
+    ];
+    frame.render_widget(Paragraph::new(coin).alignment(Alignment::Center), coin_area);
+}
+
+fn draw_scores(frame: &mut Frame, board: &Leaderboard, blink_on: bool) {
+    style::fill_background(frame, style::background());
+    let [title_area, name_area, table_area, hint_area] = Layout::vertical([
+        Constraint::Length(Font::Compact.height() + 1),
+        Constraint::Length(2),
+        Constraint::Length(TABLE_ROWS as u16 + 1),
+        Constraint::Length(1),
+    ])
+    .flex(Flex::Center)
+    .areas(frame.area());
+
+    let title = bigtext::headline(
+        "HIGH SCORES",
+        Style::new().yellow().bold(),
+        frame.area().width,
+        Line::from("HIGH SCORES".yellow().bold()),
+    );
+    frame.render_widget(
+        Paragraph::new(title).alignment(Alignment::Center),
+        title_area,
+    );
+    frame.render_widget(
+        Paragraph::new(board.name.to_uppercase().cyan().bold()).alignment(Alignment::Center),
+        name_area,
+    );
+
+    let rows: Vec<Line> = board
+        .entries
+        .iter()
+        .zip(RANKS)
+        .map(|((name, score), rank)| {
+            let row = format!("{rank}  {name:<16} {:>10}", numbers::score(*score));
+            if rank == RANKS[0] {
+                Line::from(row.yellow().bold())
+            } else {
+                Line::from(row.fg(style::text()))
+            }
+        })
+        .collect();
+    frame.render_widget(
+        Paragraph::new(rows).alignment(Alignment::Center),
+        table_area,
+    );
+    frame.render_widget(
+        Paragraph::new(press_any_key(blink_on)).alignment(Alignment::Center),
+        hint_area,
+    );
+}
+
+/// Bandeau "DEMO" sur la dernière ligne, par-dessus la partie des bots
+fn draw_demo_banner(frame: &mut Frame, blink_on: bool) {
+    let area = frame.area();
+    if area.height == 0 {
+        return;
+    }
+    let line = Rect {
+        y: area.bottom() - 1,
+        height: 1,
+        ..area
+    };
+    let mut text = vec!["DEMO".black().on_yellow().bold()];
+    if blink_on {
+        text.push("  PRESS ANY KEY".yellow().bold());
+    }
+    frame.render_widget(Clear, line);
+    frame.render_widget(
+        Paragraph::new(Line::from(text))
+            .alignment(Alignment::Center)
+            .style(Style::default().bg(style::surface())),
+        line,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::isolate_data_dir;
+
+    fn screen_with_boards(count: usize) -> TitleScreen {
+        isolate_data_dir();
+        let mut screen = TitleScreen::new(&GameRegistry::silent(), &HighScoreManager::default());
+        screen.leaderboards = (0..count)
+            .map(|i| Leaderboard {
+                name: format!("Game {i}"),
+                entries: vec![("AAA".to_string(), 100)],
+            })
+            .collect();
+        screen
+    }
+
+    fn page_name(screen: &TitleScreen) -> String {
+        match screen.page {
+            Page::Logo => "logo".to_string(),
+            Page::Scores(_) => format!("scores {}", screen.next_board),
+            Page::Demo(_) => "demo".to_string(),
+        }
+    }
+
+    #[test]
+    fn cycles_through_logo_scores_and_demos() {
+        let mut screen = screen_with_boards(4);
+        let now = Instant::now();
+        let mut pages = vec![page_name(&screen)];
+        for _ in 0..10 {
+            screen.advance(now);
+            pages.push(page_name(&screen));
+        }
+        // Trois classements par tour ; le quatrième ouvre le tour suivant
+        assert_eq!(
+            pages,
+            [
+                "logo", "scores 0", "scores 1", "scores 2", "demo", "logo", "scores 3", "scores 0",
+                "scores 1", "demo", "logo"
+            ]
+        );
+        assert_eq!(screen.next_demo, 2);
+
+        // Sans score enregistré, le logo et les démos alternent
+        let mut screen = screen_with_boards(0);
+        screen.advance(now);
+        assert_eq!(page_name(&screen), "demo");
+        screen.advance(now);
+        assert_eq!(page_name(&screen), "logo");
+    }
+}
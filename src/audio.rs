@@ -1,16 +1,29 @@
-use crate::config::AudioConfig;
+use crate::config::{AudioConfig, EffectOverride, SoundTheme};
+use crate::music::director::{MusicDirector, MusicPosition};
+use crate::music::stingers::Stinger;
 use crate::music::{
     breakout::BREAKOUT_MUSIC, gameoflife::GAMEOFLIFE_MUSIC, minesweeper::MINESWEEPER_MUSIC,
-    pong::PONG_MUSIC, snake::SNAKE_MUSIC, tetris::TETRIS_MUSIC, GameMusic, _2048::GAME2048_MUSIC,
-};
-use rodio::{
-    source::{SineWave, Source, SquareWave},
-    OutputStream, OutputStreamBuilder, Sink,
+    pong::PONG_MUSIC, rhythm::RHYTHM_MUSIC, snake::SNAKE_MUSIC, tetris::TETRIS_MUSIC, GameMusic,
+    MusicVariant, _2048::GAME2048_MUSIC,
 };
+#[cfg(feature = "native")]
+use rodio::cpal::{self, traits::HostTrait};
+use rodio::source::{SineWave, Source, SquareWave};
+#[cfg(feature = "native")]
+use rodio::{OutputStream, OutputStreamBuilder, Sink};
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "native")]
+use std::cell::Cell;
+use std::collections::BTreeMap;
+#[cfg(feature = "native")]
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
-#[derive(Debug, Clone, Copy)]
+/// Durée de la baisse de la musique pour un effet de durée inconnue
+const DEFAULT_DUCK_DURATION: Duration = Duration::from_millis(500);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum SoundEffect {
     // Snake
     SnakeEat,
@@ -58,6 +71,101 @@ pub enum SoundEffect {
     MenuSelect,
     MenuConfirm,
     MenuBack,
+
+    // Objectifs de score
+    TargetReached,
+}
+
+impl SoundEffect {
+    /// Tous les effets, dans l'ordre d'affichage des Audio Settings
+    pub const ALL: [SoundEffect; 32] = [
+        SoundEffect::SnakeEat,
+        SoundEffect::SnakeGameOver,
+        SoundEffect::TetrisLineClear,
+        SoundEffect::TetrisPieceDrop,
+        SoundEffect::TetrisGameOver,
+        SoundEffect::TetrisRotate,
+        SoundEffect::TetrisMove,
+        SoundEffect::TetrisHardDrop,
+        SoundEffect::TetrisTetris,
+        SoundEffect::PongPaddleHit,
+        SoundEffect::PongWallHit,
+        SoundEffect::PongScore,
+        SoundEffect::BreakoutPaddleHit,
+        SoundEffect::BreakoutBrickHit,
+        SoundEffect::BreakoutGameOver,
+        SoundEffect::Game2048Move,
+        SoundEffect::Game2048Merge,
+        SoundEffect::Game2048GameOver,
+        SoundEffect::Game2048Victory,
+        SoundEffect::MinesweeperReveal,
+        SoundEffect::MinesweeperFlag,
+        SoundEffect::MinesweeperUnflag,
+        SoundEffect::MinesweeperMineHit,
+        SoundEffect::MinesweeperVictory,
+        SoundEffect::GameOfLifeStep,
+        SoundEffect::GameOfLifeCellToggle,
+        SoundEffect::GameOfLifePatternPlace,
+        SoundEffect::GameOfLifeStateChange,
+        SoundEffect::MenuSelect,
+        SoundEffect::MenuConfirm,
+        SoundEffect::MenuBack,
+        SoundEffect::TargetReached,
+    ];
+
+    /// Nom affiché dans les Audio Settings
+    pub fn label(self) -> &'static str {
+        match self {
+            SoundEffect::SnakeEat => "Snake – Eat",
+            SoundEffect::SnakeGameOver => "Snake – Game Over",
+            SoundEffect::TetrisLineClear => "Tetris – Line Clear",
+            SoundEffect::TetrisPieceDrop => "Tetris – Piece Drop",
+            SoundEffect::TetrisGameOver => "Tetris – Game Over",
+            SoundEffect::TetrisRotate => "Tetris – Rotate",
+            SoundEffect::TetrisMove => "Tetris – Move",
+            SoundEffect::TetrisHardDrop => "Tetris – Hard Drop",
+            SoundEffect::TetrisTetris => "Tetris – Tetris!",
+            SoundEffect::PongPaddleHit => "Pong – Paddle Hit",
+            SoundEffect::PongWallHit => "Pong – Wall Hit",
+            SoundEffect::PongScore => "Pong – Score",
+            SoundEffect::BreakoutPaddleHit => "Breakout – Paddle Hit",
+            SoundEffect::BreakoutBrickHit => "Breakout – Brick Hit",
+            SoundEffect::BreakoutGameOver => "Breakout – Game Over",
+            SoundEffect::Game2048Move => "2048 – Move",
+            SoundEffect::Game2048Merge => "2048 – Merge",
+            SoundEffect::Game2048GameOver => "2048 – Game Over",
+            SoundEffect::Game2048Victory => "2048 – Victory",
+            SoundEffect::MinesweeperReveal => "Minesweeper – Reveal",
+            SoundEffect::MinesweeperFlag => "Minesweeper – Flag",
+            SoundEffect::MinesweeperUnflag => "Minesweeper – Unflag",
+            SoundEffect::MinesweeperMineHit => "Minesweeper – Mine Hit",
+            SoundEffect::MinesweeperVictory => "Minesweeper – Victory",
+            SoundEffect::GameOfLifeStep => "Game of Life – Step",
+            SoundEffect::GameOfLifeCellToggle => "Game of Life – Cell Toggle",
+            SoundEffect::GameOfLifePatternPlace => "Game of Life – Pattern Place",
+            SoundEffect::GameOfLifeStateChange => "Game of Life – State Change",
+            SoundEffect::MenuSelect => "Menu – Select",
+            SoundEffect::MenuConfirm => "Menu – Confirm",
+            SoundEffect::MenuBack => "Menu – Back",
+            SoundEffect::TargetReached => "Score Target Reached",
+        }
+    }
+
+    /// Effets marquants pendant lesquels la musique est baissée
+    pub fn ducks_music(self) -> bool {
+        matches!(
+            self,
+            SoundEffect::SnakeGameOver
+                | SoundEffect::TetrisGameOver
+                | SoundEffect::TetrisTetris
+                | SoundEffect::BreakoutGameOver
+                | SoundEffect::Game2048GameOver
+                | SoundEffect::Game2048Victory
+                | SoundEffect::MinesweeperMineHit
+                | SoundEffect::MinesweeperVictory
+                | SoundEffect::TargetReached
+        )
+    }
 }
 
 // Notes musicales en Hz (pour référence future)
@@ -91,34 +199,187 @@ pub enum Note {
     Rest = 0,
 }
 
+/// Thème "Mellow" : sinusoïdes douces avec fondus, game over graves et
+/// sans onde carrée
+fn generate_mellow_sound(effect: SoundEffect) -> Box<dyn Source<Item = f32> + Send> {
+    let (frequency, duration_ms) = match effect {
+        SoundEffect::SnakeEat => (660.0, 120),
+        SoundEffect::SnakeGameOver => (196.0, 600),
+        SoundEffect::TetrisLineClear => (523.0, 300),
+        SoundEffect::TetrisPieceDrop => (262.0, 90),
+        SoundEffect::TetrisGameOver => (175.0, 800),
+        SoundEffect::TetrisRotate => (587.0, 60),
+        SoundEffect::TetrisMove => (392.0, 40),
+        SoundEffect::TetrisHardDrop => (196.0, 150),
+        SoundEffect::TetrisTetris => (784.0, 600),
+        SoundEffect::PongPaddleHit => (494.0, 90),
+        SoundEffect::PongWallHit => (330.0, 70),
+        SoundEffect::PongScore => (880.0, 300),
+        SoundEffect::BreakoutPaddleHit => (440.0, 80),
+        SoundEffect::BreakoutBrickHit => (587.0, 110),
+        SoundEffect::BreakoutGameOver => (165.0, 700),
+        SoundEffect::Game2048Move => (349.0, 90),
+        SoundEffect::Game2048Merge => (523.0, 140),
+        SoundEffect::Game2048GameOver => (196.0, 700),
+        SoundEffect::Game2048Victory => (1047.0, 500),
+        SoundEffect::MinesweeperReveal => (494.0, 80),
+        SoundEffect::MinesweeperFlag => (659.0, 70),
+        SoundEffect::MinesweeperUnflag => (523.0, 60),
+        SoundEffect::MinesweeperMineHit => (131.0, 800),
+        SoundEffect::MinesweeperVictory => (988.0, 450),
+        SoundEffect::GameOfLifeStep => (262.0, 40),
+        SoundEffect::GameOfLifeCellToggle => (392.0, 80),
+        SoundEffect::GameOfLifePatternPlace => (523.0, 200),
+        SoundEffect::GameOfLifeStateChange => (440.0, 150),
+        SoundEffect::MenuSelect => (587.0, 40),
+        SoundEffect::MenuConfirm => (784.0, 90),
+        SoundEffect::MenuBack => (392.0, 70),
+        SoundEffect::TargetReached => (1319.0, 500),
+    };
+
+    Box::new(
+        SineWave::new(frequency)
+            .take_duration(Duration::from_millis(duration_ms))
+            .fade_in(Duration::from_millis(duration_ms / 5))
+            .fade_out(Duration::from_millis(duration_ms / 2))
+            .amplify(0.8),
+    )
+}
+
+/// Thème "Mechanical" : clics brefs et secs, comme des touches ou des relais
+fn generate_mechanical_sound(effect: SoundEffect) -> Box<dyn Source<Item = f32> + Send> {
+    let (frequency, duration_ms) = match effect {
+        SoundEffect::SnakeEat => (1200.0, 25),
+        SoundEffect::SnakeGameOver => (90.0, 180),
+        SoundEffect::TetrisLineClear => (1500.0, 60),
+        SoundEffect::TetrisPieceDrop => (140.0, 30),
+        SoundEffect::TetrisGameOver => (70.0, 250),
+        SoundEffect::TetrisRotate => (2200.0, 15),
+        SoundEffect::TetrisMove => (1800.0, 10),
+        SoundEffect::TetrisHardDrop => (100.0, 50),
+        SoundEffect::TetrisTetris => (2000.0, 120),
+        SoundEffect::PongPaddleHit => (900.0, 20),
+        SoundEffect::PongWallHit => (600.0, 15),
+        SoundEffect::PongScore => (1600.0, 80),
+        SoundEffect::BreakoutPaddleHit => (800.0, 20),
+        SoundEffect::BreakoutBrickHit => (1300.0, 30),
+        SoundEffect::BreakoutGameOver => (80.0, 220),
+        SoundEffect::Game2048Move => (700.0, 15),
+        SoundEffect::Game2048Merge => (1100.0, 30),
+        SoundEffect::Game2048GameOver => (90.0, 220),
+        SoundEffect::Game2048Victory => (2400.0, 120),
+        SoundEffect::MinesweeperReveal => (1000.0, 15),
+        SoundEffect::MinesweeperFlag => (1400.0, 20),
+        SoundEffect::MinesweeperUnflag => (1100.0, 15),
+        SoundEffect::MinesweeperMineHit => (60.0, 300),
+        SoundEffect::MinesweeperVictory => (2200.0, 120),
+        SoundEffect::GameOfLifeStep => (500.0, 8),
+        SoundEffect::GameOfLifeCellToggle => (1200.0, 15),
+        SoundEffect::GameOfLifePatternPlace => (900.0, 40),
+        SoundEffect::GameOfLifeStateChange => (700.0, 30),
+        SoundEffect::MenuSelect => (1500.0, 10),
+        SoundEffect::MenuConfirm => (1900.0, 20),
+        SoundEffect::MenuBack => (1200.0, 15),
+        SoundEffect::TargetReached => (2600.0, 100),
+    };
+
+    Box::new(
+        SquareWave::new(frequency)
+            .take_duration(Duration::from_millis(duration_ms))
+            .fade_out(Duration::from_millis(duration_ms))
+            .amplify(0.5),
+    )
+}
+
+#[cfg(feature = "native")]
 // Gestionnaire audio global - reste en vie pendant toute l'exécution
 struct GlobalAudioManager {
     _stream: OutputStream, // CRUCIAL : doit rester en vie !
     effects_sink: Sink,
-    music_sink: Sink,
+    music: MusicDirector,
 }
 
-// Variable thread-locale pour éviter les problèmes de Send/Sync sur macOS
+#[cfg(feature = "native")]
+// Variable thread-locale pour éviter les problèmes de Send/Sync sur macOS.
+// Le périphérique n'est ouvert qu'au premier gestionnaire branché dessus : les
+// tests, qui n'utilisent que des `SilentSink`, ne l'ouvrent jamais
 thread_local! {
-    static GLOBAL_AUDIO: std::cell::RefCell<Option<GlobalAudioManager>> = {
-        match OutputStreamBuilder::open_default_stream() {
-            Ok(stream_handle) => {
-                let effects_sink = Sink::connect_new(stream_handle.mixer());
-                let music_sink = Sink::connect_new(stream_handle.mixer());
-
-                std::cell::RefCell::new(Some(GlobalAudioManager {
-                    _stream: stream_handle, // Garde le stream en vie !
-                    effects_sink,
-                    music_sink,
-                }))
-            }
-            Err(_) => std::cell::RefCell::new(None), // Fallback silencieux si pas d'audio
-        }
+    static GLOBAL_AUDIO: std::cell::RefCell<Option<GlobalAudioManager>> =
+        const { std::cell::RefCell::new(None) }; // None : fallback silencieux si pas d'audio
+    static DEVICE_OPENED: Cell<bool> = const { Cell::new(false) };
+}
+
+#[cfg(feature = "native")]
+/// Levé par le thread audio quand le périphérique disparaît (casque USB
+/// débranché, redémarrage de PipeWire…) : les sinks ne jouent plus rien
+/// jusqu'à la reconstruction du stream par [`AudioManager::reconnect`]
+static OUTPUT_LOST: AtomicBool = AtomicBool::new(false);
+
+#[cfg(feature = "native")]
+// Remplace le callback par défaut de Rodio, qui écrit sur stderr par-dessus
+// l'interface
+fn on_stream_error(_error: cpal::StreamError) {
+    OUTPUT_LOST.store(true, Ordering::Relaxed);
+}
+
+#[cfg(feature = "native")]
+/// Ouvre le périphérique par défaut, ou à défaut le premier qui accepte un
+/// stream, comme `OutputStreamBuilder::open_default_stream`
+fn open_output_stream() -> Option<OutputStream> {
+    let open = |builder: OutputStreamBuilder| {
+        builder
+            .with_error_callback(on_stream_error as fn(cpal::StreamError))
+            .open_stream_or_fallback()
+            .ok()
     };
+    let mut stream = OutputStreamBuilder::from_default_device()
+        .ok()
+        .and_then(open)
+        .or_else(|| {
+            cpal::default_host()
+                .output_devices()
+                .ok()?
+                .find_map(|device| OutputStreamBuilder::from_device(device).ok().and_then(open))
+        })?;
+    // Un stream remplacé après une reconnexion ne doit rien écrire non plus
+    stream.log_on_drop(false);
+    Some(stream)
+}
+
+#[cfg(feature = "native")]
+fn open_global_audio() -> Option<GlobalAudioManager> {
+    let stream_handle = open_output_stream()?;
+    let effects_sink = Sink::connect_new(stream_handle.mixer());
+    let music = MusicDirector::new(stream_handle.mixer());
+    OUTPUT_LOST.store(false, Ordering::Relaxed);
+
+    Some(GlobalAudioManager {
+        _stream: stream_handle, // Garde le stream en vie !
+        effects_sink,
+        music,
+    })
 }
 
-// Exécute une fonction avec l'audio global si disponible
+#[cfg(feature = "native")]
+// Exécute une fonction avec l'audio global si disponible, en ouvrant le
+// périphérique la première fois
 fn with_global_audio<F, R>(f: F) -> Option<R>
+where
+    F: FnOnce(&GlobalAudioManager) -> R,
+{
+    if !DEVICE_OPENED.replace(true) {
+        GLOBAL_AUDIO.with(|audio| {
+            if let Ok(mut audio_ref) = audio.try_borrow_mut() {
+                *audio_ref = open_global_audio();
+            }
+        });
+    }
+    with_open_audio(f)
+}
+
+#[cfg(feature = "native")]
+// Comme `with_global_audio`, sans ouvrir le périphérique s'il ne l'est pas
+fn with_open_audio<F, R>(f: F) -> Option<R>
 where
     F: FnOnce(&GlobalAudioManager) -> R,
 {
@@ -131,59 +392,320 @@ where
     })
 }
 
+#[cfg(feature = "native")]
+// Arrête les sons et ferme le périphérique
+fn close_global_audio() {
+    // Arrêter la musique et les effets ; un périphérique fermé ne sera pas
+    // rouvert avant `AudioManager::reconnect`
+    DEVICE_OPENED.set(true);
+    with_open_audio(|global_audio| {
+        global_audio.effects_sink.clear();
+        global_audio.music.stop();
+    });
+
+    // IMPORTANT: Nettoyer le GlobalAudioManager pour éviter le message de Rodio
+    // On remplace l'Option<GlobalAudioManager> par None, ce qui drop proprement l'OutputStream
+    // On utilise un bloc pour capturer temporairement toute sortie de Rodio
+
+    // Sauvegarder stderr actuel et le rediriger vers /dev/null temporairement
+    // Cela empêche le message "Dropping OutputStream..." d'apparaître
+    #[cfg(unix)]
+    {
+        use std::os::unix::io::AsRawFd;
+        let stderr_fd = std::io::stderr().as_raw_fd();
+        let old_stderr = unsafe { libc::dup(stderr_fd) };
+
+        if old_stderr >= 0 {
+            let dev_null = std::fs::OpenOptions::new().write(true).open("/dev/null");
+
+            if let Ok(dev_null) = dev_null {
+                unsafe {
+                    libc::dup2(dev_null.as_raw_fd(), stderr_fd);
+                }
+            }
+
+            // Nettoyer l'audio global
+            GLOBAL_AUDIO.with(|audio| {
+                if let Ok(mut audio_ref) = audio.try_borrow_mut() {
+                    *audio_ref = None;
+                }
+            });
+
+            // Restaurer stderr
+            unsafe {
+                libc::dup2(old_stderr, stderr_fd);
+                libc::close(old_stderr);
+            }
+        } else {
+            // Fallback si dup échoue
+            GLOBAL_AUDIO.with(|audio| {
+                if let Ok(mut audio_ref) = audio.try_borrow_mut() {
+                    *audio_ref = None;
+                }
+            });
+        }
+    }
+
+    // Sur Windows, on ne peut pas facilement rediriger stderr, donc on accepte le message
+    #[cfg(not(unix))]
+    {
+        GLOBAL_AUDIO.with(|audio| {
+            if let Ok(mut audio_ref) = audio.try_borrow_mut() {
+                *audio_ref = None;
+            }
+        });
+    }
+
+    // Petit délai pour s'assurer que tout est nettoyé
+    std::thread::sleep(std::time::Duration::from_millis(10));
+}
+
+/// Sortie des sons d'un [`AudioManager`]. Les jeux reçoivent leur
+/// gestionnaire à la construction : l'application le branche sur le
+/// périphérique audio ([`DeviceSink`]), les tests sur un [`SilentSink`]
+pub trait AudioSink: Send + Sync {
+    /// Faux si aucun son ne peut sortir : effets et musique sont alors
+    /// désactivés
+    fn is_available(&self) -> bool;
+
+    /// Joue un effet ; `duck` baisse la musique à ce niveau pendant cette durée
+    fn play_effect(
+        &self,
+        source: Box<dyn Source<Item = f32> + Send>,
+        duck: Option<(f32, Duration)>,
+    );
+
+    /// Coupe les effets en cours
+    fn clear_effects(&self);
+
+    /// Appelle `f` avec la musique, s'il y en a une
+    fn with_music(&self, f: &mut dyn FnMut(&MusicDirector));
+
+    /// Arrête les sons et libère la sortie
+    fn shutdown(&self);
+}
+
+#[cfg(feature = "native")]
+/// Périphérique de sortie par défaut, ouvert au premier usage et partagé par
+/// tous les gestionnaires du thread
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DeviceSink;
+
+#[cfg(feature = "native")]
+impl AudioSink for DeviceSink {
+    fn is_available(&self) -> bool {
+        with_global_audio(|_| true).is_some()
+    }
+
+    fn play_effect(
+        &self,
+        source: Box<dyn Source<Item = f32> + Send>,
+        duck: Option<(f32, Duration)>,
+    ) {
+        with_global_audio(|global_audio| {
+            if let Some((level, duration)) = duck {
+                global_audio.music.duck(level, duration);
+            }
+            global_audio.effects_sink.append(source);
+        });
+    }
+
+    fn clear_effects(&self) {
+        with_global_audio(|global_audio| global_audio.effects_sink.clear());
+    }
+
+    fn with_music(&self, f: &mut dyn FnMut(&MusicDirector)) {
+        with_global_audio(|global_audio| f(&global_audio.music));
+    }
+
+    fn shutdown(&self) {
+        close_global_audio();
+    }
+}
+
+/// Sortie muette des tests et des benchmarks : ne touche jamais au
+/// périphérique, et le gestionnaire se comporte comme sur une machine sans
+/// carte son
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SilentSink;
+
+impl AudioSink for SilentSink {
+    fn is_available(&self) -> bool {
+        false
+    }
+
+    fn play_effect(
+        &self,
+        _source: Box<dyn Source<Item = f32> + Send>,
+        _duck: Option<(f32, Duration)>,
+    ) {
+    }
+
+    fn clear_effects(&self) {}
+
+    fn with_music(&self, _f: &mut dyn FnMut(&MusicDirector)) {}
+
+    fn shutdown(&self) {}
+}
+
 pub struct AudioManager {
+    sink: Arc<dyn AudioSink>,
     master_volume: Arc<Mutex<f32>>,
     volume: Arc<Mutex<f32>>,
     music_volume: Arc<Mutex<f32>>,
     enabled: Arc<Mutex<bool>>,
     music_enabled: Arc<Mutex<bool>>,
+    sound_theme: Arc<Mutex<SoundTheme>>,
+    effect_overrides: Arc<Mutex<BTreeMap<SoundEffect, EffectOverride>>>,
+    music_ducking: Arc<Mutex<f32>>,
+    muted: Arc<Mutex<bool>>,
+    background_music: Arc<Mutex<bool>>,
 }
 
 impl AudioManager {
+    #[cfg(feature = "native")]
     pub fn new_with_config(config: &AudioConfig) -> Result<Self, Box<dyn std::error::Error>> {
         // Utilise le gestionnaire audio global - l'OutputStream reste en vie !
-        let has_audio = with_global_audio(|_| true).is_some();
+        Ok(Self::with_sink(config, Arc::new(DeviceSink)))
+    }
 
-        Ok(Self {
+    /// Gestionnaire branché sur `sink`, avec la configuration enregistrée
+    pub fn new(sink: Arc<dyn AudioSink>) -> Self {
+        // Essayer de charger la configuration depuis le fichier, sinon utiliser les valeurs par défaut
+        let config = crate::config::ConfigManager::new()
+            .map(|config_manager| config_manager.get_audio_config().clone())
+            .unwrap_or_else(|_| AudioConfig::default());
+        Self::with_sink(&config, sink)
+    }
+
+    pub fn with_sink(config: &AudioConfig, sink: Arc<dyn AudioSink>) -> Self {
+        let has_audio = sink.is_available();
+
+        Self {
+            sink,
             master_volume: Arc::new(Mutex::new(config.master_volume)),
             volume: Arc::new(Mutex::new(config.effects_volume)),
             music_volume: Arc::new(Mutex::new(config.music_volume)),
             enabled: Arc::new(Mutex::new(has_audio && config.audio_enabled)),
             music_enabled: Arc::new(Mutex::new(has_audio && config.music_enabled)),
-        })
+            sound_theme: Arc::new(Mutex::new(config.sound_theme)),
+            effect_overrides: Arc::new(Mutex::new(config.effect_overrides.clone())),
+            music_ducking: Arc::new(Mutex::new(config.music_ducking)),
+            muted: Arc::new(Mutex::new(config.muted)),
+            background_music: Arc::new(Mutex::new(config.background_music)),
+        }
+    }
+
+    /// Gestionnaire muet, avec la configuration par défaut, pour les tests
+    pub fn silent() -> Self {
+        Self::with_sink(&AudioConfig::default(), Arc::new(SilentSink))
+    }
+
+    /// Nouveau gestionnaire sur la même sortie, avec la configuration
+    /// enregistrée : pour recommencer une partie ou lancer une manche
+    pub fn sibling(&self) -> Self {
+        Self::new(Arc::clone(&self.sink))
     }
 
     pub fn play_sound(&self, effect: SoundEffect) {
+        self.play_sound_pitched(effect, 1.0);
+    }
+
+    /// Joue un effet plus aigu (`pitch` > 1) ou plus grave, et d'autant plus
+    /// court ou plus long
+    pub fn play_sound_pitched(&self, effect: SoundEffect, pitch: f32) {
+        let effect_override = self.effect_override(effect);
+        if effect_override.enabled {
+            self.play_effect(effect, effect_override.volume, pitch);
+        }
+    }
+
+    /// Joue un effet pour l'écouter depuis les Audio Settings, même s'il est
+    /// désactivé individuellement
+    pub fn audition_sound(&self, effect: SoundEffect) {
+        self.clear_effects();
+        self.play_effect(effect, self.effect_override(effect).volume, 1.0);
+    }
+
+    /// Réglage propre à un effet (activé, volume relatif)
+    pub fn effect_override(&self, effect: SoundEffect) -> EffectOverride {
+        self.effect_overrides
+            .lock()
+            .unwrap()
+            .get(&effect)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn set_effect_override(&self, effect: SoundEffect, effect_override: EffectOverride) {
+        let mut overrides = self.effect_overrides.lock().unwrap();
+        // Ne garder dans la configuration que les effets modifiés
+        if effect_override == EffectOverride::default() {
+            overrides.remove(&effect);
+        } else {
+            overrides.insert(effect, effect_override);
+        }
+    }
+
+    fn play_effect(&self, effect: SoundEffect, relative_volume: f32, pitch: f32) {
         if !*self.enabled.lock().unwrap() {
             return;
         }
 
-        with_global_audio(|global_audio| {
-            let master_volume = *self.master_volume.lock().unwrap();
-            let effects_volume = *self.volume.lock().unwrap();
-            let source = self.generate_sound(effect);
-
-            if let Some(source) = source {
-                // Volume spécial pour certains effets
-                let base_volume = match effect {
-                    SoundEffect::TetrisGameOver
-                    | SoundEffect::SnakeGameOver
-                    | SoundEffect::BreakoutGameOver
-                    | SoundEffect::Game2048GameOver => effects_volume.max(0.4),
-                    SoundEffect::TetrisTetris => effects_volume * 1.2, // Plus fort pour Tetris!
-                    _ => effects_volume,
-                };
-
-                // Appliquer le master volume
-                let final_volume = base_volume * master_volume;
-                global_audio
-                    .effects_sink
-                    .append(source.amplify(final_volume));
-            }
+        let master_volume = self.effective_master_volume();
+        let effects_volume = *self.volume.lock().unwrap();
+        let Some(source) = self.generate_sound(effect) else {
+            return;
+        };
+
+        // Volume spécial pour certains effets
+        let base_volume = match effect {
+            SoundEffect::TetrisGameOver
+            | SoundEffect::SnakeGameOver
+            | SoundEffect::BreakoutGameOver
+            | SoundEffect::Game2048GameOver => effects_volume.max(0.4),
+            SoundEffect::TetrisTetris => effects_volume * 1.2, // Plus fort pour Tetris!
+            _ => effects_volume,
+        };
+
+        // Baisser la musique le temps de l'effet
+        let ducking = *self.music_ducking.lock().unwrap();
+        let duck = (effect.ducks_music() && ducking > 0.0).then(|| {
+            let duration = source.total_duration().unwrap_or(DEFAULT_DUCK_DURATION);
+            (1.0 - ducking, duration)
         });
+
+        // Appliquer le master volume et le volume propre à l'effet
+        let final_volume = base_volume * master_volume * relative_volume;
+        self.sink
+            .play_effect(Box::new(source.speed(pitch).amplify(final_volume)), duck);
     }
 
     fn generate_sound(&self, effect: SoundEffect) -> Option<Box<dyn Source<Item = f32> + Send>> {
+        match *self.sound_theme.lock().unwrap() {
+            SoundTheme::Chiptune => self.generate_chiptune_sound(effect),
+            SoundTheme::Mellow => Some(generate_mellow_sound(effect)),
+            SoundTheme::Mechanical => Some(generate_mechanical_sound(effect)),
+        }
+    }
+
+    /// Joue un échantillon du thème de sons actuel
+    pub fn preview_sound_theme(&self) {
+        self.clear_effects();
+        for effect in [
+            SoundEffect::MenuConfirm,
+            SoundEffect::SnakeEat,
+            SoundEffect::TetrisLineClear,
+            SoundEffect::TetrisGameOver,
+        ] {
+            self.play_sound(effect);
+        }
+    }
+
+    fn generate_chiptune_sound(
+        &self,
+        effect: SoundEffect,
+    ) -> Option<Box<dyn Source<Item = f32> + Send>> {
         match effect {
             // Snake sounds
             SoundEffect::SnakeEat => Some(Box::new(
@@ -359,352 +881,186 @@ impl AudioManager {
                         .fade_out(Duration::from_millis(30)),
                 ))
             }
+
+            SoundEffect::TargetReached => {
+                // Accord majeur lumineux, comme une petite fanfare
+                Some(Box::new(
+                    SineWave::new(1047.0) // C6
+                        .mix(SineWave::new(1319.0)) // E6
+                        .mix(SineWave::new(1568.0)) // G6
+                        .take_duration(Duration::from_millis(500))
+                        .fade_out(Duration::from_millis(200)),
+                ))
+            }
         }
     }
 
     // Jouer la musique de Tetris (version normale)
     pub fn play_tetris_music(&self) {
-        if !*self.music_enabled.lock().unwrap() {
-            return;
-        }
-
-        with_global_audio(|global_audio| {
-            let sink = &global_audio.music_sink;
-            let master_volume = *self.master_volume.lock().unwrap();
-            let music_volume = *self.music_volume.lock().unwrap();
-            let final_volume = master_volume * music_volume;
-            TETRIS_MUSIC.play_normal(sink, final_volume);
-            // Forcer le démarrage de la lecture dans Rodio 0.21
-            sink.play();
-        });
+        self.play_music(&TETRIS_MUSIC, MusicVariant::Normal);
     }
 
     // Version alternative plus courte pour les niveaux rapides
     pub fn play_tetris_music_fast(&self) {
-        if !*self.music_enabled.lock().unwrap() {
-            return;
-        }
-
-        with_global_audio(|global_audio| {
-            let sink = &global_audio.music_sink;
-            let master_volume = *self.master_volume.lock().unwrap();
-            let music_volume = *self.music_volume.lock().unwrap();
-            let final_volume = master_volume * music_volume;
-            TETRIS_MUSIC.play_fast(sink, final_volume);
-            // Forcer le démarrage de la lecture dans Rodio 0.21
-            sink.play();
-        });
+        self.play_music(&TETRIS_MUSIC, MusicVariant::Fast);
     }
 
     // Version avec harmonies pour les moments spéciaux (Tetris!)
     pub fn play_tetris_music_harmony(&self) {
-        if !*self.music_enabled.lock().unwrap() {
-            return;
-        }
-
-        with_global_audio(|global_audio| {
-            let sink = &global_audio.music_sink;
-            let master_volume = *self.master_volume.lock().unwrap();
-            let music_volume = *self.music_volume.lock().unwrap();
-            let final_volume = master_volume * music_volume;
-            TETRIS_MUSIC.play_celebration(sink, final_volume);
-            // Forcer le démarrage de la lecture dans Rodio 0.21
-            sink.play();
-        });
+        self.play_music(&TETRIS_MUSIC, MusicVariant::Celebration);
     }
 
     // Jouer la musique de Snake (version normale)
     pub fn play_snake_music(&self) {
-        if !*self.music_enabled.lock().unwrap() {
-            return;
-        }
-
-        with_global_audio(|global_audio| {
-            let master_volume = *self.master_volume.lock().unwrap();
-            let music_volume = *self.music_volume.lock().unwrap();
-            let final_volume = master_volume * music_volume;
-            SNAKE_MUSIC.play_normal(&global_audio.music_sink, final_volume);
-            // Forcer le démarrage de la lecture dans Rodio 0.21
-            global_audio.music_sink.play();
-        });
+        self.play_music(&SNAKE_MUSIC, MusicVariant::Normal);
     }
 
     // Version rapide pour Snake (quand le serpent est très long)
     pub fn play_snake_music_fast(&self) {
-        if !*self.music_enabled.lock().unwrap() {
-            return;
-        }
-
-        with_global_audio(|global_audio| {
-            let sink = &global_audio.music_sink;
-            let master_volume = *self.master_volume.lock().unwrap();
-            let music_volume = *self.music_volume.lock().unwrap();
-            let final_volume = master_volume * music_volume;
-            SNAKE_MUSIC.play_fast(sink, final_volume);
-            // Forcer le démarrage de la lecture dans Rodio 0.21
-            sink.play();
-        });
+        self.play_music(&SNAKE_MUSIC, MusicVariant::Fast);
     }
 
     // Jouer la musique de Pong (version normale)
     pub fn play_pong_music(&self) {
-        if !*self.music_enabled.lock().unwrap() {
-            return;
-        }
-
-        with_global_audio(|global_audio| {
-            let sink = &global_audio.music_sink;
-            let master_volume = *self.master_volume.lock().unwrap();
-            let music_volume = *self.music_volume.lock().unwrap();
-            let final_volume = master_volume * music_volume;
-            PONG_MUSIC.play_normal(sink, final_volume);
-            // Forcer le démarrage de la lecture dans Rodio 0.21
-            sink.play();
-        });
+        self.play_music(&PONG_MUSIC, MusicVariant::Normal);
     }
 
     // Version rapide pour Pong (quand la balle va très vite)
     pub fn play_pong_music_fast(&self) {
-        if !*self.music_enabled.lock().unwrap() {
-            return;
-        }
-
-        with_global_audio(|global_audio| {
-            let sink = &global_audio.music_sink;
-            let master_volume = *self.master_volume.lock().unwrap();
-            let music_volume = *self.music_volume.lock().unwrap();
-            let final_volume = master_volume * music_volume;
-            PONG_MUSIC.play_fast(sink, final_volume);
-            // Forcer le démarrage de la lecture dans Rodio 0.21
-            sink.play();
-        });
+        self.play_music(&PONG_MUSIC, MusicVariant::Fast);
     }
 
     // Musique de célébration pour Pong
     pub fn play_pong_music_celebration(&self) {
-        if !*self.music_enabled.lock().unwrap() {
-            return;
-        }
-
-        with_global_audio(|global_audio| {
-            let sink = &global_audio.music_sink;
-            let master_volume = *self.master_volume.lock().unwrap();
-            let music_volume = *self.music_volume.lock().unwrap();
-            let final_volume = master_volume * music_volume;
-            PONG_MUSIC.play_celebration(sink, final_volume);
-            // Forcer le démarrage de la lecture dans Rodio 0.21
-            sink.play();
-        });
+        self.play_music(&PONG_MUSIC, MusicVariant::Celebration);
     }
 
     // Jouer la musique de 2048 (version normale)
     pub fn play_2048_music(&self) {
-        if !*self.music_enabled.lock().unwrap() {
-            return;
-        }
-
-        with_global_audio(|global_audio| {
-            let sink = &global_audio.music_sink;
-            let master_volume = *self.master_volume.lock().unwrap();
-            let music_volume = *self.music_volume.lock().unwrap();
-            let final_volume = master_volume * music_volume;
-            GAME2048_MUSIC.play_normal(sink, final_volume);
-            // Forcer le démarrage de la lecture dans Rodio 0.21
-            sink.play();
-        });
+        self.play_music(&GAME2048_MUSIC, MusicVariant::Normal);
     }
 
     // Version énergique pour 2048 (scores élevés/combos)
     pub fn play_2048_music_fast(&self) {
-        if !*self.music_enabled.lock().unwrap() {
-            return;
-        }
-
-        with_global_audio(|global_audio| {
-            let sink = &global_audio.music_sink;
-            let master_volume = *self.master_volume.lock().unwrap();
-            let music_volume = *self.music_volume.lock().unwrap();
-            let final_volume = master_volume * music_volume;
-            GAME2048_MUSIC.play_fast(sink, final_volume);
-            // Forcer le démarrage de la lecture dans Rodio 0.21
-            sink.play();
-        });
+        self.play_music(&GAME2048_MUSIC, MusicVariant::Fast);
     }
 
     // Musique de célébration pour 2048 (victoire!)
     pub fn play_2048_music_celebration(&self) {
-        if !*self.music_enabled.lock().unwrap() {
-            return;
-        }
-
-        with_global_audio(|global_audio| {
-            let sink = &global_audio.music_sink;
-            let master_volume = *self.master_volume.lock().unwrap();
-            let music_volume = *self.music_volume.lock().unwrap();
-            let final_volume = master_volume * music_volume;
-            GAME2048_MUSIC.play_celebration(sink, final_volume);
-            // Forcer le démarrage de la lecture dans Rodio 0.21
-            sink.play();
-        });
+        self.play_music(&GAME2048_MUSIC, MusicVariant::Celebration);
     }
 
     // Jouer la musique de Minesweeper (version normale)
     pub fn play_minesweeper_music(&self) {
-        if !*self.music_enabled.lock().unwrap() {
-            return;
-        }
-
-        with_global_audio(|global_audio| {
-            let sink = &global_audio.music_sink;
-            let master_volume = *self.master_volume.lock().unwrap();
-            let music_volume = *self.music_volume.lock().unwrap();
-            let final_volume = master_volume * music_volume;
-            MINESWEEPER_MUSIC.play_normal(sink, final_volume);
-            // Forcer le démarrage de la lecture dans Rodio 0.21
-            sink.play();
-        });
+        self.play_music(&MINESWEEPER_MUSIC, MusicVariant::Normal);
     }
 
     // Version tendue pour Minesweeper (moments critiques)
     pub fn play_minesweeper_music_fast(&self) {
-        if !*self.music_enabled.lock().unwrap() {
-            return;
-        }
-
-        with_global_audio(|global_audio| {
-            let sink = &global_audio.music_sink;
-            let master_volume = *self.master_volume.lock().unwrap();
-            let music_volume = *self.music_volume.lock().unwrap();
-            let final_volume = master_volume * music_volume;
-            MINESWEEPER_MUSIC.play_fast(sink, final_volume);
-            // Forcer le démarrage de la lecture dans Rodio 0.21
-            sink.play();
-        });
+        self.play_music(&MINESWEEPER_MUSIC, MusicVariant::Fast);
     }
 
     // Musique de célébration pour Minesweeper (victoire!)
     pub fn play_minesweeper_music_celebration(&self) {
-        if !*self.music_enabled.lock().unwrap() {
-            return;
-        }
-
-        with_global_audio(|global_audio| {
-            let sink = &global_audio.music_sink;
-            let master_volume = *self.master_volume.lock().unwrap();
-            let music_volume = *self.music_volume.lock().unwrap();
-            let final_volume = master_volume * music_volume;
-            MINESWEEPER_MUSIC.play_celebration(sink, final_volume);
-            // Forcer le démarrage de la lecture dans Rodio 0.21
-            sink.play();
-        });
+        self.play_music(&MINESWEEPER_MUSIC, MusicVariant::Celebration);
     }
 
     // Jouer la musique de Breakout (version normale)
     pub fn play_breakout_music(&self) {
-        if !*self.music_enabled.lock().unwrap() {
-            return;
-        }
-
-        with_global_audio(|global_audio| {
-            let sink = &global_audio.music_sink;
-            let master_volume = *self.master_volume.lock().unwrap();
-            let music_volume = *self.music_volume.lock().unwrap();
-            let final_volume = master_volume * music_volume;
-            BREAKOUT_MUSIC.play_normal(sink, final_volume);
-            // Forcer le démarrage de la lecture dans Rodio 0.21
-            sink.play();
-        });
+        self.play_music(&BREAKOUT_MUSIC, MusicVariant::Normal);
     }
 
     // Version intense pour Breakout (peu de briques restantes)
     pub fn play_breakout_music_fast(&self) {
-        if !*self.music_enabled.lock().unwrap() {
-            return;
-        }
-
-        with_global_audio(|global_audio| {
-            let sink = &global_audio.music_sink;
-            let master_volume = *self.master_volume.lock().unwrap();
-            let music_volume = *self.music_volume.lock().unwrap();
-            let final_volume = master_volume * music_volume;
-            BREAKOUT_MUSIC.play_fast(sink, final_volume);
-            // Forcer le démarrage de la lecture dans Rodio 0.21
-            sink.play();
-        });
+        self.play_music(&BREAKOUT_MUSIC, MusicVariant::Fast);
     }
 
     // Musique de célébration pour Breakout (victoire!)
     pub fn play_breakout_music_celebration(&self) {
-        if !*self.music_enabled.lock().unwrap() {
-            return;
-        }
-
-        with_global_audio(|global_audio| {
-            let sink = &global_audio.music_sink;
-            let master_volume = *self.master_volume.lock().unwrap();
-            let music_volume = *self.music_volume.lock().unwrap();
-            let final_volume = master_volume * music_volume;
-            BREAKOUT_MUSIC.play_celebration(sink, final_volume);
-            // Forcer le démarrage de la lecture dans Rodio 0.21
-            sink.play();
-        });
+        self.play_music(&BREAKOUT_MUSIC, MusicVariant::Celebration);
     }
 
     // Jouer la musique de Game of Life (version normale - contemplative)
     pub fn play_gameoflife_music(&self) {
-        if !*self.music_enabled.lock().unwrap() {
-            return;
-        }
-
-        with_global_audio(|global_audio| {
-            let sink = &global_audio.music_sink;
-            let master_volume = *self.master_volume.lock().unwrap();
-            let music_volume = *self.music_volume.lock().unwrap();
-            let final_volume = master_volume * music_volume;
-            GAMEOFLIFE_MUSIC.play_normal(sink, final_volume);
-            // Forcer le démarrage de la lecture dans Rodio 0.21
-            sink.play();
-        });
+        self.play_music(&GAMEOFLIFE_MUSIC, MusicVariant::Normal);
     }
 
     // Version dynamique pour Game of Life (simulations rapides)
     pub fn play_gameoflife_music_fast(&self) {
-        if !*self.music_enabled.lock().unwrap() {
-            return;
-        }
-
-        with_global_audio(|global_audio| {
-            let sink = &global_audio.music_sink;
-            let master_volume = *self.master_volume.lock().unwrap();
-            let music_volume = *self.music_volume.lock().unwrap();
-            let final_volume = master_volume * music_volume;
-            GAMEOFLIFE_MUSIC.play_fast(sink, final_volume);
-            // Forcer le démarrage de la lecture dans Rodio 0.21
-            sink.play();
-        });
+        self.play_music(&GAMEOFLIFE_MUSIC, MusicVariant::Fast);
     }
 
     // Musique d'émerveillement pour Game of Life (patterns complexes)
     pub fn play_gameoflife_music_celebration(&self) {
+        self.play_music(&GAMEOFLIFE_MUSIC, MusicVariant::Celebration);
+    }
+
+    // Morceau du jeu de rythme, au tempo de la variante choisie (Normal ou Fast)
+    pub fn play_rhythm_music(&self, variant: MusicVariant) {
+        self.play_music(&RHYTHM_MUSIC, variant);
+    }
+
+    /// Boucle une variante de musique ; si une autre joue déjà, elle prend le
+    /// relais à la fin du tour en cours
+    fn play_music(&self, music: &'static (dyn GameMusic + Sync), variant: MusicVariant) {
         if !*self.music_enabled.lock().unwrap() {
             return;
         }
 
-        with_global_audio(|global_audio| {
-            let sink = &global_audio.music_sink;
-            let master_volume = *self.master_volume.lock().unwrap();
-            let music_volume = *self.music_volume.lock().unwrap();
-            let final_volume = master_volume * music_volume;
-            GAMEOFLIFE_MUSIC.play_celebration(sink, final_volume);
-            // Forcer le démarrage de la lecture dans Rodio 0.21
-            sink.play();
-        });
+        let volume = self.final_music_volume();
+        self.music(|director| director.play(music, variant, volume));
+    }
+
+    /// Jingle de fin de partie, joué une fois par la musique qui s'arrête
+    /// ensuite (voir [`MusicDirector::play_stinger`]). Musique coupée, l'effet
+    /// `fallback` le remplace
+    pub fn play_stinger(&self, stinger: &'static Stinger, fallback: Option<SoundEffect>) {
+        if *self.music_enabled.lock().unwrap() {
+            let volume = self.final_music_volume();
+            self.music(|director| director.play_stinger(stinger, volume));
+        } else if let Some(effect) = fallback {
+            self.play_sound(effect);
+        }
+    }
+
+    /// Volume général appliqué aux sons, nul quand le son est coupé
+    fn effective_master_volume(&self) -> f32 {
+        if *self.muted.lock().unwrap() {
+            0.0
+        } else {
+            *self.master_volume.lock().unwrap()
+        }
+    }
+
+    fn final_music_volume(&self) -> f32 {
+        self.effective_master_volume() * *self.music_volume.lock().unwrap()
+    }
+
+    /// Applique tout de suite un changement de volume à la musique en cours
+    pub fn refresh_music_volume(&self) {
+        let volume = self.final_music_volume();
+        self.music(|director| director.set_loop_volume(volume));
+    }
+
+    /// Laisse la boucle en cours se terminer, puis la musique s'arrête
+    pub fn finish_music_loop(&self) {
+        self.music(|director| director.finish_loop());
     }
 
     pub fn stop_music(&self) {
-        with_global_audio(|global_audio| {
-            global_audio.music_sink.clear();
-        });
+        self.music(|director| director.stop());
+    }
+
+    /// Règle l'intensité de la musique entre 0.0 et 1.0 : la couche de
+    /// percussions apparaît en fondu à mesure qu'elle augmente
+    pub fn set_music_intensity(&self, intensity: f32) {
+        self.music(|director| director.set_intensity(intensity));
+    }
+
+    /// Règle la vitesse de la musique entre 0.0 (tempo d'origine) et 1.0
+    /// (le plus rapide), qui suit la vitesse du jeu en douceur
+    pub fn set_music_speed(&self, speed: f32) {
+        self.music(|director| director.set_speed(speed));
     }
 
     pub fn set_master_volume(&self, volume: f32) {
@@ -731,6 +1087,30 @@ impl AudioManager {
         *self.music_volume.lock().unwrap()
     }
 
+    pub fn set_music_ducking(&self, ducking: f32) {
+        *self.music_ducking.lock().unwrap() = ducking.clamp(0.0, 1.0);
+    }
+
+    pub fn get_music_ducking(&self) -> f32 {
+        *self.music_ducking.lock().unwrap()
+    }
+
+    pub fn set_background_music(&self, background_music: bool) {
+        *self.background_music.lock().unwrap() = background_music;
+    }
+
+    pub fn is_background_music(&self) -> bool {
+        *self.background_music.lock().unwrap()
+    }
+
+    pub fn set_muted(&self, muted: bool) {
+        *self.muted.lock().unwrap() = muted;
+    }
+
+    pub fn is_muted(&self) -> bool {
+        *self.muted.lock().unwrap()
+    }
+
     pub fn toggle_enabled(&self) {
         let mut enabled = self.enabled.lock().unwrap();
         *enabled = !*enabled;
@@ -764,15 +1144,84 @@ impl AudioManager {
         *self.music_enabled.lock().unwrap()
     }
 
+    pub fn set_sound_theme(&self, theme: SoundTheme) {
+        *self.sound_theme.lock().unwrap() = theme;
+    }
+
+    pub fn get_sound_theme(&self) -> SoundTheme {
+        *self.sound_theme.lock().unwrap()
+    }
+
     pub fn clear_effects(&self) {
-        with_global_audio(|global_audio| {
-            let sink = &global_audio.effects_sink;
-            sink.clear();
+        self.sink.clear_effects();
+    }
+
+    /// Appelle `f` avec la musique de la sortie, s'il y en a une
+    fn music<R>(&self, f: impl FnOnce(&MusicDirector) -> R) -> Option<R> {
+        let mut f = Some(f);
+        let mut result = None;
+        self.sink.with_music(&mut |director| {
+            result = f.take().map(|f| f(director));
+        });
+        result
+    }
+
+    #[cfg(feature = "native")]
+    /// Suspend la musique en cours (perte du focus du terminal)
+    pub fn pause_music() {
+        with_open_audio(|global_audio| global_audio.music.pause());
+    }
+
+    #[cfg(feature = "native")]
+    /// Reprend la musique suspendue par `pause_music`
+    pub fn resume_music() {
+        with_open_audio(|global_audio| global_audio.music.resume());
+    }
+
+    #[cfg(feature = "native")]
+    /// Baisse progressivement la musique en cours jusqu'au silence puis l'arrête
+    pub fn fade_out_music(duration: std::time::Duration) {
+        const STEPS: u32 = 10;
+
+        let Some(start_volume) = with_open_audio(|global_audio| {
+            (!global_audio.music.is_empty()).then(|| global_audio.music.volume())
+        })
+        .flatten() else {
+            return;
+        };
+
+        for step in (0..STEPS).rev() {
+            with_open_audio(|global_audio| {
+                global_audio
+                    .music
+                    .set_volume(start_volume * step as f32 / STEPS as f32);
+            });
+            std::thread::sleep(duration / STEPS);
+        }
+
+        with_open_audio(|global_audio| {
+            global_audio.music.stop();
+            global_audio.music.set_volume(start_volume);
         });
     }
 
-    pub fn is_music_empty(&self) -> bool {
-        with_global_audio(|global_audio| global_audio.music_sink.empty()).unwrap_or(true)
+    /// Position de lecture du morceau en cours, si de la musique joue
+    pub fn music_position(&self) -> Option<MusicPosition> {
+        self.music(|director| director.position()).flatten()
+    }
+
+    /// Applique une configuration complète (après une remise à zéro)
+    pub fn apply_config(&self, config: &AudioConfig) {
+        self.set_master_volume(config.master_volume);
+        self.set_volume(config.effects_volume);
+        self.set_music_volume(config.music_volume);
+        self.set_enabled(config.audio_enabled);
+        self.set_music_enabled(config.music_enabled);
+        self.set_sound_theme(config.sound_theme);
+        *self.effect_overrides.lock().unwrap() = config.effect_overrides.clone();
+        self.set_music_ducking(config.music_ducking);
+        self.set_muted(config.muted);
+        self.set_background_music(config.background_music);
     }
 
     pub fn get_current_config(&self) -> AudioConfig {
@@ -782,26 +1231,50 @@ impl AudioManager {
             music_volume: *self.music_volume.lock().unwrap(),
             audio_enabled: *self.enabled.lock().unwrap(),
             music_enabled: *self.music_enabled.lock().unwrap(),
+            sound_theme: *self.sound_theme.lock().unwrap(),
+            effect_overrides: self.effect_overrides.lock().unwrap().clone(),
+            music_ducking: *self.music_ducking.lock().unwrap(),
+            muted: *self.muted.lock().unwrap(),
+            background_music: *self.background_music.lock().unwrap(),
         }
     }
 }
 
+#[cfg(feature = "native")]
 impl Default for AudioManager {
+    /// Gestionnaire branché sur le périphérique audio
     fn default() -> Self {
-        // Essayer de charger la configuration depuis le fichier, sinon utiliser les valeurs par défaut
-        let config = crate::config::ConfigManager::new()
-            .map(|config_manager| config_manager.get_audio_config().clone())
-            .unwrap_or_else(|_| AudioConfig::default());
+        Self::new(Arc::new(DeviceSink))
+    }
+}
 
-        Self::new_with_config(&config).unwrap_or_else(|_| {
-            // Fallback silencieux si l'audio n'est pas disponible
-            Self {
-                master_volume: Arc::new(Mutex::new(config.master_volume)),
-                volume: Arc::new(Mutex::new(config.effects_volume)),
-                music_volume: Arc::new(Mutex::new(config.music_volume)),
-                enabled: Arc::new(Mutex::new(false)), // Disable si pas d'audio hardware
-                music_enabled: Arc::new(Mutex::new(false)), // Disable si pas d'audio hardware
-            }
+#[cfg(feature = "native")]
+impl AudioManager {
+    /// Vrai depuis la perte du périphérique de sortie, jusqu'à la reconnexion
+    pub fn output_lost() -> bool {
+        OUTPUT_LOST.load(Ordering::Relaxed)
+    }
+
+    /// Un périphérique de sortie est-il branché ? Ne fait qu'interroger le
+    /// système, sans ouvrir de stream : peut tourner en tâche de fond
+    pub fn output_available() -> bool {
+        cpal::default_host().default_output_device().is_some()
+    }
+
+    /// Reconstruit le stream de sortie et ses sinks sur le périphérique par
+    /// défaut actuel ; la musique en cours s'arrête. Rend faux si aucun
+    /// périphérique n'a pu être ouvert
+    pub fn reconnect() -> bool {
+        GLOBAL_AUDIO.with(|audio| {
+            let Ok(mut audio_ref) = audio.try_borrow_mut() else {
+                return false;
+            };
+            // Libérer l'ancien stream avant d'ouvrir le nouveau, certains
+            // pilotes n'acceptant qu'un stream à la fois
+            DEVICE_OPENED.set(true);
+            *audio_ref = None;
+            *audio_ref = open_global_audio();
+            audio_ref.is_some()
         })
     }
 }
@@ -809,67 +1282,7 @@ impl Default for AudioManager {
 impl AudioManager {
     /// Nettoyage propre des ressources audio
     pub fn shutdown(&mut self) {
-        // Arrêter la musique et les effets
-        with_global_audio(|global_audio| {
-            global_audio.effects_sink.clear();
-            global_audio.music_sink.clear();
-        });
-
-        // IMPORTANT: Nettoyer le GlobalAudioManager pour éviter le message de Rodio
-        // On remplace l'Option<GlobalAudioManager> par None, ce qui drop proprement l'OutputStream
-        // On utilise un bloc pour capturer temporairement toute sortie de Rodio
-
-        // Sauvegarder stderr actuel et le rediriger vers /dev/null temporairement
-        // Cela empêche le message "Dropping OutputStream..." d'apparaître
-        #[cfg(unix)]
-        {
-            use std::os::unix::io::AsRawFd;
-            let stderr_fd = std::io::stderr().as_raw_fd();
-            let old_stderr = unsafe { libc::dup(stderr_fd) };
-
-            if old_stderr >= 0 {
-                let dev_null = std::fs::OpenOptions::new().write(true).open("/dev/null");
-
-                if let Ok(dev_null) = dev_null {
-                    unsafe {
-                        libc::dup2(dev_null.as_raw_fd(), stderr_fd);
-                    }
-                }
-
-                // Nettoyer l'audio global
-                GLOBAL_AUDIO.with(|audio| {
-                    if let Ok(mut audio_ref) = audio.try_borrow_mut() {
-                        *audio_ref = None;
-                    }
-                });
-
-                // Restaurer stderr
-                unsafe {
-                    libc::dup2(old_stderr, stderr_fd);
-                    libc::close(old_stderr);
-                }
-            } else {
-                // Fallback si dup échoue
-                GLOBAL_AUDIO.with(|audio| {
-                    if let Ok(mut audio_ref) = audio.try_borrow_mut() {
-                        *audio_ref = None;
-                    }
-                });
-            }
-        }
-
-        // Sur Windows, on ne peut pas facilement rediriger stderr, donc on accepte le message
-        #[cfg(not(unix))]
-        {
-            GLOBAL_AUDIO.with(|audio| {
-                if let Ok(mut audio_ref) = audio.try_borrow_mut() {
-                    *audio_ref = None;
-                }
-            });
-        }
-
-        // Petit délai pour s'assurer que tout est nettoyé
-        std::thread::sleep(std::time::Duration::from_millis(10));
+        self.sink.shutdown();
     }
 }
 
@@ -878,3 +1291,25 @@ impl Drop for AudioManager {
         self.shutdown();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn silent_managers_never_open_the_device() {
+        let audio = AudioManager::silent();
+        assert!(!audio.is_enabled() && !audio.is_music_enabled());
+
+        // Même réactivés, effets et musique ne vont nulle part
+        audio.set_enabled(true);
+        audio.set_music_enabled(true);
+        audio.play_sound(SoundEffect::TetrisTetris);
+        audio.play_rhythm_music(MusicVariant::Normal);
+        assert!(audio.music_position().is_none());
+        drop(audio);
+
+        #[cfg(feature = "native")]
+        assert!(!DEVICE_OPENED.get());
+    }
+}
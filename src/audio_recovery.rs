@@ -0,0 +1,119 @@
+//! Reprise de la sortie audio après la perte du périphérique (casque USB
+//! débranché, redémarrage de PipeWire…).
+//!
+//! Le stream de Rodio ne revient pas tout seul : [`AudioRecovery`] guette la
+//! perte signalée par le thread audio, cherche en tâche de fond un
+//! périphérique de remplacement, puis reconstruit le stream sur le thread de
+//! l'interface, seul propriétaire de l'audio global.
+
+use crate::audio::AudioManager;
+use crate::core::clock::Instant;
+use crate::jobs::{self, Job, JobPoll};
+use std::time::Duration;
+
+/// Délai entre deux recherches de périphérique
+const RETRY_INTERVAL: Duration = Duration::from_secs(2);
+
+pub struct AudioRecovery {
+    lost: fn() -> bool,
+    probe: fn() -> bool,
+    rebuild: fn() -> bool,
+    job: Option<Job<bool>>,
+    /// Début de la panne en cours, ou dernière recherche sans résultat
+    last_attempt: Option<Instant>,
+}
+
+impl AudioRecovery {
+    pub fn new() -> Self {
+        Self {
+            lost: AudioManager::output_lost,
+            probe: AudioManager::output_available,
+            rebuild: AudioManager::reconnect,
+            job: None,
+            last_attempt: None,
+        }
+    }
+
+    /// À appeler à chaque image : rend l'annonce à afficher quand la sortie
+    /// vient d'être perdue ou retrouvée
+    pub fn poll(&mut self) -> Option<&'static str> {
+        if !(self.lost)() {
+            self.last_attempt = None;
+            return None;
+        }
+        let Some(last_attempt) = self.last_attempt else {
+            self.last_attempt = Some(Instant::now());
+            return Some("Audio device lost – reconnecting in the background");
+        };
+
+        if let Some(job) = &self.job {
+            match job.poll() {
+                JobPoll::Running => return None,
+                JobPoll::Finished(true) => {
+                    self.job = None;
+                    if (self.rebuild)() {
+                        self.last_attempt = None;
+                        return Some("Audio reconnected");
+                    }
+                }
+                JobPoll::Finished(false) | JobPoll::Lost => self.job = None,
+            }
+            self.last_attempt = Some(Instant::now());
+        } else if last_attempt.elapsed() >= RETRY_INTERVAL {
+            self.job = Some(jobs::spawn("Reconnecting audio", self.probe));
+        }
+        None
+    }
+}
+
+impl Default for AudioRecovery {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    static LOST: AtomicBool = AtomicBool::new(true);
+
+    fn poll_until(recovery: &mut AudioRecovery, deadline: Duration) -> Option<&'static str> {
+        let start = Instant::now();
+        while start.elapsed() < deadline {
+            if let Some(notice) = recovery.poll() {
+                return Some(notice);
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        None
+    }
+
+    #[test]
+    fn a_lost_output_is_announced_then_rebuilt_once_a_device_answers() {
+        let mut recovery = AudioRecovery {
+            lost: || LOST.load(Ordering::Relaxed),
+            probe: || true,
+            rebuild: || {
+                LOST.store(false, Ordering::Relaxed);
+                true
+            },
+            job: None,
+            last_attempt: None,
+        };
+
+        assert_eq!(
+            recovery.poll(),
+            Some("Audio device lost – reconnecting in the background")
+        );
+        // La première recherche attend un intervalle complet
+        assert_eq!(recovery.poll(), None);
+        recovery.last_attempt = Some(Instant::now() - RETRY_INTERVAL);
+        assert_eq!(
+            poll_until(&mut recovery, Duration::from_secs(5)),
+            Some("Audio reconnected")
+        );
+        assert_eq!(recovery.poll(), None);
+    }
+}
@@ -0,0 +1,336 @@
+//! Commandes `termplay backup` et `termplay restore` : toutes les données du
+//! joueur dans une seule archive, pour passer d'une machine à l'autre.
+//!
+//! L'archive est un `.tar.gz` ordinaire, lisible avec `tar -xzf` : les
+//! fichiers du dossier de données (voir [`STORED_FILES`], caches exclus),
+//! les enregistrements du dossier `recordings` et un `manifest.json` qui
+//! donne la version de TermPlay et du format. La restauration lit aussi une
+//! archive refaite avec `tar -czf`, ou un `.tar` non compressé.
+
+use crate::config;
+use crate::storage::{self, StoredFile, STORED_FILES};
+use crate::writer;
+use chrono::{DateTime, Utc};
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Version du format de l'archive, à changer si son contenu change
+pub const BACKUP_FORMAT: u32 = 1;
+const MANIFEST_FILE: &str = "manifest.json";
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Description de l'archive, enregistrée en premier fichier
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Manifest {
+    pub format: u32,
+    /// Version de TermPlay qui a créé l'archive
+    pub version: String,
+    pub created: DateTime<Utc>,
+    pub files: Vec<String>,
+}
+
+/// Fichiers sauvegardés : tout sauf les caches
+fn backed_up_files() -> impl Iterator<Item = &'static StoredFile> {
+    STORED_FILES.iter().filter(|file| !file.cache)
+}
+
+/// Nom proposé pour une sauvegarde faite depuis le menu, dans le dossier
+/// personnel
+pub fn default_backup_path() -> Option<PathBuf> {
+    let name = format!(
+        "termplay-backup-{}.tar.gz",
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    );
+    home_dir().map(|dir| dir.join(name))
+}
+
+#[cfg(feature = "native")]
+fn home_dir() -> Option<PathBuf> {
+    dirs::home_dir()
+}
+
+#[cfg(not(feature = "native"))]
+fn home_dir() -> Option<PathBuf> {
+    None
+}
+
+/// Écrit l'archive de toutes les données dans `path`
+pub fn backup(path: &Path) -> Result<Manifest, Box<dyn std::error::Error>> {
+    // Les dernières sauvegardes en file doivent être sur le disque
+    writer::flush();
+
+    let mut entries = Vec::new();
+    for file in backed_up_files() {
+        let Some(source) = file.path() else {
+            continue;
+        };
+        let read = if file.directory {
+            read_directory(&source, file.file_name, &mut entries)
+        } else {
+            fs::read(&source).map(|contents| entries.push((file.file_name.to_string(), contents)))
+        };
+        match read {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => {
+                return Err(format!("cannot read {}: {e}", source.display()).into())
+            }
+            _ => {}
+        }
+    }
+
+    let manifest = Manifest {
+        format: BACKUP_FORMAT,
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        created: Utc::now(),
+        files: entries.iter().map(|(name, _)| name.clone()).collect(),
+    };
+    let manifest_json = serde_json::to_string_pretty(&manifest)? + "\n";
+    entries.insert(0, (MANIFEST_FILE.to_string(), manifest_json.into_bytes()));
+
+    let archive = archive(&entries, manifest.created.timestamp())?;
+    fs::write(path, archive).map_err(|e| format!("cannot write {}: {e}", path.display()))?;
+    Ok(manifest)
+}
+
+/// Remplace les données par celles de l'archive `path`. Une archive d'une
+/// version plus récente de TermPlay est refusée sans `force` ; les fichiers
+/// absents de l'archive sont laissés tels quels
+pub fn restore(path: &Path, force: bool) -> Result<Manifest, Box<dyn std::error::Error>> {
+    let bytes = fs::read(path).map_err(|e| format!("cannot read {}: {e}", path.display()))?;
+    let entries = read_archive(&bytes)?;
+
+    let manifest: Manifest = entries
+        .iter()
+        .find(|(name, _)| name == MANIFEST_FILE)
+        .ok_or("not a TermPlay backup (manifest.json is missing)")
+        .and_then(|(_, contents)| {
+            serde_json::from_slice(contents).map_err(|_| "unreadable manifest.json")
+        })?;
+    check_version(&manifest, env!("CARGO_PKG_VERSION"), force)?;
+
+    let dir = storage::data_dir().ok_or("no config directory")?;
+    fs::create_dir_all(&dir)?;
+    // Ne pas laisser une sauvegarde en file écraser les fichiers restaurés
+    writer::flush();
+    for (name, contents) in &entries {
+        if let Some(target) = restore_path(name) {
+            let target = dir.join(target);
+            if let Some(parent) = target.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(target, contents)?;
+        }
+    }
+    config::reload()?;
+    Ok(manifest)
+}
+
+/// Refuse un format inconnu, et une archive d'une version plus récente de
+/// TermPlay dont les fichiers pourraient ne pas être lus correctement
+fn check_version(manifest: &Manifest, current: &str, force: bool) -> Result<(), String> {
+    if manifest.format > BACKUP_FORMAT {
+        return Err(format!(
+            "this backup uses format {} from TermPlay {}; update TermPlay to restore it",
+            manifest.format, manifest.version
+        ));
+    }
+    if !force && parse_version(&manifest.version) > parse_version(current) {
+        return Err(format!(
+            "this backup was made with TermPlay {}, newer than {current}; update TermPlay or pass --force",
+            manifest.version
+        ));
+    }
+    Ok(())
+}
+
+/// "1.7.1" → [1, 7, 1] ; les parties illisibles comptent pour 0
+fn parse_version(version: &str) -> [u32; 3] {
+    let mut parts = version
+        .split(['.', '-', '+'])
+        .map(|part| part.parse().unwrap_or(0));
+    std::array::from_fn(|_| parts.next().unwrap_or(0))
+}
+
+/// Ajoute à `entries` les fichiers du dossier `dir`, nommés
+/// `name/fichier` dans l'archive
+fn read_directory(dir: &Path, name: &str, entries: &mut Vec<(String, Vec<u8>)>) -> io::Result<()> {
+    let mut files: Vec<_> = fs::read_dir(dir)?
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_ok_and(|kind| kind.is_file()))
+        .collect();
+    files.sort_by_key(|entry| entry.file_name());
+    for entry in files {
+        let contents = fs::read(entry.path())?;
+        entries.push((
+            format!("{name}/{}", entry.file_name().to_string_lossy()),
+            contents,
+        ));
+    }
+    Ok(())
+}
+
+/// Chemin, relatif au dossier de données, où restaurer le fichier `name` de
+/// l'archive. Seuls les fichiers connus et ceux posés directement dans un
+/// dossier connu sont écrits, jamais un chemin quelconque de l'archive
+fn restore_path(name: &str) -> Option<PathBuf> {
+    let (stored_name, inner) = match name.split_once('/') {
+        Some((dir, inner)) => (dir, Some(inner)),
+        None => (name, None),
+    };
+    let stored = backed_up_files().find(|file| file.file_name == stored_name)?;
+    match inner {
+        None if !stored.directory => Some(PathBuf::from(name)),
+        Some(inner)
+            if stored.directory
+                && !inner.is_empty()
+                && inner != "."
+                && inner != ".."
+                && !inner.contains(['/', '\\']) =>
+        {
+            Some(Path::new(stored_name).join(inner))
+        }
+        _ => None,
+    }
+}
+
+/// Archive `.tar.gz` des fichiers `entries`
+fn archive(entries: &[(String, Vec<u8>)], mtime: i64) -> io::Result<Vec<u8>> {
+    let mut builder = tar::Builder::new(GzEncoder::new(Vec::new(), Compression::default()));
+    for (name, contents) in entries {
+        let mut header = tar::Header::new_ustar();
+        header.set_entry_type(tar::EntryType::Regular);
+        header.set_size(contents.len() as u64);
+        header.set_mode(0o644);
+        header.set_mtime(mtime.max(0) as u64);
+        builder.append_data(&mut header, name, contents.as_slice())?;
+    }
+    builder.into_inner()?.finish()
+}
+
+/// Fichiers ordinaires d'une archive `.tar.gz` ou `.tar`
+fn read_archive(bytes: &[u8]) -> Result<Vec<(String, Vec<u8>)>, String> {
+    if bytes.starts_with(&GZIP_MAGIC) {
+        read_tar(GzDecoder::new(bytes))
+    } else {
+        read_tar(bytes)
+    }
+}
+
+fn read_tar(reader: impl Read) -> Result<Vec<(String, Vec<u8>)>, String> {
+    let invalid = |e: io::Error| format!("not a valid archive ({e})");
+    let mut archive = tar::Archive::new(reader);
+    let mut entries = Vec::new();
+    for entry in archive.entries().map_err(invalid)? {
+        let mut entry = entry.map_err(invalid)?;
+        // Dossiers et liens sont ignorés
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        let name = String::from_utf8_lossy(&entry.path_bytes()).into_owned();
+        let name = name.strip_prefix("./").unwrap_or(&name).to_string();
+        let mut contents = Vec::new();
+        entry.read_to_end(&mut contents).map_err(invalid)?;
+        entries.push((name, contents));
+    }
+    // Lire jusqu'au bout : la somme de contrôle gzip est à la fin du flux
+    io::copy(&mut archive.into_inner(), &mut io::sink()).map_err(invalid)?;
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entries() -> Vec<(String, Vec<u8>)> {
+        vec![
+            ("config.json".to_string(), b"{}\n".to_vec()),
+            ("highscores.json".to_string(), vec![b'x'; 70_000]),
+            ("empty.json".to_string(), Vec::new()),
+            ("recordings/snake-1.cast".to_string(), b"{}\n".to_vec()),
+        ]
+    }
+
+    #[test]
+    fn archives_round_trip_through_tar_and_gzip() {
+        let archive = archive(&entries(), 0).unwrap();
+        assert!(archive.starts_with(&GZIP_MAGIC));
+        assert_eq!(read_archive(&archive).unwrap(), entries());
+
+        // Un .tar non compressé se lit aussi
+        let mut builder = tar::Builder::new(Vec::new());
+        let mut header = tar::Header::new_gnu();
+        header.set_size(3);
+        builder
+            .append_data(&mut header, "./config.json", &b"{}\n"[..])
+            .unwrap();
+        let plain = builder.into_inner().unwrap();
+        assert_eq!(
+            read_archive(&plain).unwrap(),
+            vec![("config.json".to_string(), b"{}\n".to_vec())]
+        );
+    }
+
+    #[test]
+    fn corrupted_and_truncated_archives_are_errors() {
+        let archive = archive(&entries(), 0).unwrap();
+        for len in [3, 10, 18, 40, archive.len() / 2, archive.len() - 4] {
+            assert!(read_archive(&archive[..len]).is_err(), "truncated at {len}");
+        }
+        let mut corrupted = archive.clone();
+        let last = corrupted.len() - 5;
+        corrupted[last] ^= 1;
+        assert!(read_archive(&corrupted).is_err());
+
+        // En-tête gzip dont le champ FEXTRA dépasse la fin du fichier
+        let mut extra = vec![0x1f, 0x8b, 8, 0x0c, 0, 0, 0, 0, 0, 0xff];
+        extra.extend_from_slice(&[0xff; 10]);
+        assert!(read_archive(&extra).is_err());
+    }
+
+    #[test]
+    fn only_known_files_are_restored() {
+        assert_eq!(
+            restore_path("config.json"),
+            Some(PathBuf::from("config.json"))
+        );
+        assert_eq!(
+            restore_path("recordings/snake-1.cast"),
+            Some(Path::new("recordings").join("snake-1.cast"))
+        );
+        for name in [
+            "crash.log",
+            "unknown.json",
+            "../config.json",
+            "recordings",
+            "recordings/",
+            "recordings/..",
+            "recordings/sub/x.cast",
+            "config.json/x",
+        ] {
+            assert_eq!(restore_path(name), None, "{name}");
+        }
+    }
+
+    #[test]
+    fn refuses_backups_from_newer_versions() {
+        let manifest = Manifest {
+            format: BACKUP_FORMAT,
+            version: "1.8.0".to_string(),
+            created: Utc::now(),
+            files: Vec::new(),
+        };
+        assert!(check_version(&manifest, "1.8.0", false).is_ok());
+        assert!(check_version(&manifest, "1.10.2", false).is_ok());
+        assert!(check_version(&manifest, "1.7.1", false).is_err());
+        assert!(check_version(&manifest, "1.7.1", true).is_ok());
+
+        let future = Manifest {
+            format: BACKUP_FORMAT + 1,
+            ..manifest
+        };
+        assert!(check_version(&future, "9.0.0", true).is_err());
+    }
+}
@@ -0,0 +1,145 @@
+//! Défi de la semaine.
+//!
+//! Chaque semaine ISO, un jeu et un modificateur (vitesse doublée, pièce
+//! suivante cachée…) sont tirés d'une liste à partir du numéro de la
+//! semaine : tous les joueurs ont le même défi, et les mêmes tirages grâce à
+//! la graine de la semaine. Une partie du défi active son modificateur (voir
+//! [`crate::mutators`]), et ses scores vont dans le classement de la
+//! semaine (voir [`leaderboard_id`]) plutôt que dans le classement habituel.
+//! Comme les modificateurs et la graine, le défi en cours est propre au
+//! thread qui joue.
+
+use crate::core::rng;
+use crate::mutators::{self, Mutator};
+use chrono::{Datelike, Local, NaiveDate, Weekday};
+use std::borrow::Cow;
+use std::cell::Cell;
+
+/// Défis possibles, un par semaine
+const ROTATION: [(&str, Mutator); 3] = [
+    ("snake", Mutator::DoubleSpeed),
+    ("tetris", Mutator::HiddenNext),
+    ("minesweeper", Mutator::ExtraMines),
+];
+
+/// Préfixe des classements hebdomadaires dans `highscores.json`
+const LEADERBOARD_PREFIX: &str = "challenge:";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Challenge {
+    /// Année et numéro de la semaine ISO
+    pub year: i32,
+    pub week: u32,
+    pub game_id: &'static str,
+    pub modifier: Mutator,
+    /// Graine des tirages de la semaine
+    pub seed: u64,
+}
+
+impl Challenge {
+    /// Défi de la semaine qui contient `date`
+    pub fn for_date(date: NaiveDate) -> Self {
+        let iso = date.iso_week();
+        let seed = mix((iso.year() as u64) << 8 | iso.week() as u64);
+        let (game_id, modifier) = ROTATION[(seed % ROTATION.len() as u64) as usize];
+        Self {
+            year: iso.year(),
+            week: iso.week(),
+            game_id,
+            modifier,
+            seed,
+        }
+    }
+
+    /// Défi de cette semaine
+    pub fn current() -> Self {
+        Self::for_date(Local::now().date_naive())
+    }
+
+    /// Identifiant de la semaine, par exemple `2026-W42`
+    pub fn week_label(&self) -> String {
+        format!("{}-W{:02}", self.year, self.week)
+    }
+
+    /// Clé du classement de la semaine dans les high scores
+    pub fn leaderboard(&self) -> String {
+        format!("{LEADERBOARD_PREFIX}{}", self.week_label())
+    }
+
+    /// Jours restants avant le défi suivant, aujourd'hui compris
+    pub fn days_left(&self, today: NaiveDate) -> u32 {
+        let sunday = NaiveDate::from_isoywd_opt(self.year, self.week, Weekday::Sun);
+        sunday.map_or(0, |sunday| (sunday - today).num_days().max(0) as u32 + 1)
+    }
+}
+
+/// Mélange des bits (splitmix64), pour que deux semaines voisines n'aient
+/// pas des graines voisines
+fn mix(value: u64) -> u64 {
+    let mut z = value.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+thread_local! {
+    /// Défi en cours de partie, `None` en dehors d'un défi
+    static ACTIVE: Cell<Option<Challenge>> = const { Cell::new(None) };
+}
+
+/// Commence une partie du défi : à appeler avant de créer le jeu
+pub fn start(challenge: Challenge) {
+    rng::seed(challenge.seed);
+    mutators::set(&[challenge.modifier]);
+    ACTIVE.set(Some(challenge));
+}
+
+/// Termine la partie du défi ou modifiée et rend au jeu ses règles
+/// habituelles
+pub fn stop() {
+    rng::unseed();
+    mutators::clear();
+    ACTIVE.set(None);
+}
+
+/// Défi en cours de partie
+pub fn active() -> Option<Challenge> {
+    ACTIVE.get()
+}
+
+/// Classement où enregistrer un score de `game_id` : celui de la semaine
+/// pendant une partie du défi, sinon celui du jeu
+pub fn leaderboard_id(game_id: &str) -> Cow<'_, str> {
+    match active() {
+        Some(challenge) if challenge.game_id == game_id => Cow::Owned(challenge.leaderboard()),
+        _ => Cow::Borrowed(game_id),
+    }
+}
+
+/// Indique si une clé des high scores est un classement hebdomadaire
+pub fn is_leaderboard(key: &str) -> bool {
+    key.starts_with(LEADERBOARD_PREFIX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_challenge_is_the_same_all_week_and_rotates() {
+        let monday = NaiveDate::from_ymd_opt(2026, 10, 12).unwrap();
+        let sunday = NaiveDate::from_ymd_opt(2026, 10, 18).unwrap();
+        let challenge = Challenge::for_date(monday);
+        assert_eq!(challenge, Challenge::for_date(sunday));
+        assert_eq!(challenge.week_label(), "2026-W42");
+        assert_eq!(challenge.leaderboard(), "challenge:2026-W42");
+        assert_eq!(challenge.days_left(monday), 7);
+        assert_eq!(challenge.days_left(sunday), 1);
+
+        // Sur quelques mois, chaque défi revient
+        let games: std::collections::HashSet<_> = (0..20)
+            .map(|week| Challenge::for_date(monday + chrono::Duration::weeks(week)).game_id)
+            .collect();
+        assert_eq!(games.len(), ROTATION.len());
+    }
+}
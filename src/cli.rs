@@ -1,4 +1,8 @@
-use clap::{Parser, Subcommand};
+use clap::builder::PossibleValue;
+use clap::{CommandFactory, Parser, Subcommand};
+use clap_complete::Shell;
+use std::io;
+use std::path::PathBuf;
 
 #[derive(Parser)]
 #[command(name = "termplay")]
@@ -13,14 +17,123 @@ pub struct Cli {
 pub enum Commands {
     #[command(about = "Launch a specific game directly")]
     Game {
-        #[arg(help = "Name of the game to launch")]
+        #[arg(help = "ID or name of the game to launch, or 'random' for a surprise")]
         name: String,
     },
     #[command(about = "List all available games")]
-    List,
+    List {
+        #[arg(long, help = "Include games hidden from the menu")]
+        all: bool,
+        #[arg(short, long, help = "Show each game's full description and controls")]
+        verbose: bool,
+    },
+    #[command(about = "Run Conway's Game of Life without the interface")]
+    Gol {
+        #[command(subcommand)]
+        command: GolCommand,
+    },
+    #[command(about = "Work with your local play statistics")]
+    Stats {
+        #[command(subcommand)]
+        command: StatsCommand,
+    },
+    #[command(about = "Work with the key logs recorded next to game recordings")]
+    Replay {
+        #[command(subcommand)]
+        command: ReplayCommand,
+    },
+    #[command(about = "Save settings, scores, statistics and progress to an archive")]
+    Backup {
+        #[arg(help = "Archive to write (.tar.gz)")]
+        file: PathBuf,
+    },
+    #[command(about = "Replace your data with the contents of a backup archive")]
+    Restore {
+        #[arg(help = "Archive written by 'termplay backup'")]
+        file: PathBuf,
+        #[arg(long, help = "Restore a backup made by a newer version of TermPlay")]
+        force: bool,
+    },
+    #[command(about = "Print shell completions (bash, zsh, fish, powershell, elvish)")]
+    Completions {
+        #[arg(help = "Shell to generate completions for")]
+        shell: Shell,
+    },
+    #[command(about = "Host TermPlay for remote players over SSH")]
+    Serve {
+        #[arg(
+            long,
+            value_name = "ADDRESS",
+            help = "Address to listen on: ':2222' for this machine only, '0.0.0.0:2222' for every interface"
+        )]
+        ssh: String,
+    },
     #[command(about = "Check for updates and install the latest version")]
     Update {
         #[arg(long, help = "Only check for updates without installing")]
         check_only: bool,
     },
 }
+
+#[derive(Subcommand)]
+pub enum GolCommand {
+    #[command(about = "Simulate an RLE pattern and write the resulting pattern")]
+    Run {
+        #[arg(long, help = "RLE file of the starting pattern")]
+        pattern: PathBuf,
+        #[arg(
+            long,
+            default_value_t = 100,
+            help = "Number of generations to simulate"
+        )]
+        generations: u64,
+        #[arg(
+            long,
+            help = "RLE file to write the result to (default: standard output)"
+        )]
+        out: Option<PathBuf>,
+        #[arg(long, help = "Print population statistics")]
+        stats: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum StatsCommand {
+    #[command(about = "Export an anonymized statistics summary to share in bug reports")]
+    Export {
+        #[arg(long, help = "JSON file to write (default: standard output)")]
+        out: Option<PathBuf>,
+        #[arg(long, help = "Confirm the export without asking")]
+        yes: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum ReplayCommand {
+    #[command(about = "Check a key log's checksums and print what it contains")]
+    Verify {
+        #[arg(help = "Key log to check (.tpr)")]
+        file: PathBuf,
+    },
+}
+
+/// Écrit sur la sortie standard le script de complétion de `shell`.
+///
+/// Les noms de jeux sont ajoutés comme valeurs possibles de `game` sur une
+/// copie de la commande : le script les propose, mais la ligne de commande
+/// réelle continue d'accepter les noms affichés et de suggérer les noms
+/// proches en cas d'erreur.
+pub fn print_completions(shell: Shell, game_names: Vec<String>) {
+    let mut command = Cli::command().mut_subcommand("game", |game| {
+        game.mut_arg("name", |name| {
+            name.value_parser(
+                game_names
+                    .into_iter()
+                    .map(PossibleValue::new)
+                    .collect::<Vec<_>>(),
+            )
+        })
+    });
+
+    clap_complete::generate(shell, &mut command, "termplay", &mut io::stdout());
+}
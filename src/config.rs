@@ -1,6 +1,10 @@
+use crate::audio::SoundEffect;
+use crate::storage;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AudioConfig {
@@ -9,6 +13,89 @@ pub struct AudioConfig {
     pub music_volume: f32,
     pub audio_enabled: bool,
     pub music_enabled: bool,
+    #[serde(default)]
+    pub sound_theme: SoundTheme,
+    /// Réglages propres à certains effets ; les effets absents gardent les
+    /// valeurs par défaut
+    #[serde(default)]
+    pub effect_overrides: BTreeMap<SoundEffect, EffectOverride>,
+    /// Baisse de la musique pendant les effets marquants, de 0.0 (désactivée)
+    /// à 1.0 (musique coupée)
+    #[serde(default = "default_music_ducking")]
+    pub music_ducking: f32,
+    /// Son coupé avec F10 pendant une partie, sans toucher aux volumes
+    #[serde(default)]
+    pub muted: bool,
+    /// La musique lancée dans le Music Player continue dans les autres
+    /// écrans du menu au lieu de s'arrêter en le quittant
+    #[serde(default)]
+    pub background_music: bool,
+}
+
+fn default_music_ducking() -> f32 {
+    0.5
+}
+
+/// Réglage d'un effet sonore particulier
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct EffectOverride {
+    pub enabled: bool,
+    /// Volume relatif au volume des effets, de 0.0 à 2.0
+    pub volume: f32,
+}
+
+impl Default for EffectOverride {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            volume: 1.0,
+        }
+    }
+}
+
+/// Jeu de sons utilisé pour les effets
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SoundTheme {
+    /// Ondes carrées façon console 8 bits (sons d'origine)
+    #[default]
+    Chiptune,
+    /// Ondes sinusoïdales douces avec fondus
+    Mellow,
+    /// Clics brefs et secs
+    Mechanical,
+}
+
+impl SoundTheme {
+    pub const ALL: [SoundTheme; 3] = [
+        SoundTheme::Chiptune,
+        SoundTheme::Mellow,
+        SoundTheme::Mechanical,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            SoundTheme::Chiptune => "Chiptune",
+            SoundTheme::Mellow => "Mellow",
+            SoundTheme::Mechanical => "Mechanical",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let index = Self::ALL
+            .iter()
+            .position(|&theme| theme == self)
+            .unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    pub fn previous(self) -> Self {
+        let index = Self::ALL
+            .iter()
+            .position(|&theme| theme == self)
+            .unwrap_or(0);
+        Self::ALL[(index + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
 }
 
 impl Default for AudioConfig {
@@ -19,6 +106,830 @@ impl Default for AudioConfig {
             music_volume: 0.3,
             audio_enabled: true,
             music_enabled: true,
+            sound_theme: SoundTheme::default(),
+            effect_overrides: BTreeMap::new(),
+            music_ducking: default_music_ducking(),
+            muted: false,
+            background_music: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DisplayConfig {
+    /// Afficher le jeu et le score dans le titre du terminal
+    pub terminal_title: bool,
+    /// Ajouter la progression des longues simulations (Game of Life) au titre
+    pub title_progress: bool,
+    /// Ignorer les parties jouées avec des aides dans le meilleur score des
+    /// High Scores
+    #[serde(default)]
+    pub best_excludes_assisted: bool,
+    /// Couleurs des menus, à accorder au fond du terminal
+    #[serde(default)]
+    pub color_scheme: ColorScheme,
+    /// Laisser le fond du terminal visible au lieu de peindre l'écran
+    #[serde(default)]
+    pub transparent_background: bool,
+    /// Symboles des en-têtes et des menus
+    #[serde(default)]
+    pub glyph_set: GlyphSet,
+    /// Couper les transitions animées des menus
+    #[serde(default)]
+    pub reduce_motion: bool,
+    /// Afficher le logo pendant le chargement au lancement du menu
+    #[serde(default = "default_splash_screen")]
+    pub splash_screen: bool,
+    /// Écran titre façon borne d'arcade avant le menu
+    #[serde(default)]
+    pub title_screen: bool,
+    /// Animation derrière les panneaux du menu
+    #[serde(default)]
+    pub menu_background: MenuBackground,
+    /// Couleurs que le terminal sait afficher
+    #[serde(default)]
+    pub color_depth: ColorDepth,
+    /// Traînée derrière les balles de Pong et de Breakout
+    #[serde(default)]
+    pub ball_trail: BallTrail,
+    /// Images dessinées pendant une partie, pour les terminaux lents
+    #[serde(default)]
+    pub frame_skip: FrameSkip,
+    /// Heures sur 12 ou 24 heures dans les classements et l'historique
+    #[serde(default)]
+    pub clock_format: ClockFormat,
+    /// Ordre du jour, du mois et de l'année dans les dates affichées
+    #[serde(default)]
+    pub date_order: DateOrder,
+    /// Dates affichées à l'heure locale ou en UTC
+    #[serde(default)]
+    pub timezone: Timezone,
+}
+
+fn default_splash_screen() -> bool {
+    true
+}
+
+/// Couleurs des menus et de la sélection
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ColorScheme {
+    /// Panneaux sombres (couleurs d'origine)
+    #[default]
+    Dark,
+    /// Fond du terminal conservé et texte sombre, pour les terminaux clairs
+    Light,
+    /// Couleurs du terminal uniquement, sélection en vidéo inverse
+    HighContrast,
+}
+
+impl ColorScheme {
+    pub const ALL: [ColorScheme; 3] = [
+        ColorScheme::Dark,
+        ColorScheme::Light,
+        ColorScheme::HighContrast,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ColorScheme::Dark => "Dark",
+            ColorScheme::Light => "Light",
+            ColorScheme::HighContrast => "High Contrast",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let index = Self::ALL
+            .iter()
+            .position(|&scheme| scheme == self)
+            .unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+/// Animation discrète dessinée derrière les panneaux du menu
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum MenuBackground {
+    #[default]
+    Off,
+    /// Soupe du Jeu de la Vie, relancée quand elle s'éteint ou se fige
+    Life,
+    /// Pièces de Tetris qui tombent
+    Tetris,
+}
+
+impl MenuBackground {
+    pub const ALL: [MenuBackground; 3] = [
+        MenuBackground::Off,
+        MenuBackground::Life,
+        MenuBackground::Tetris,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            MenuBackground::Off => "Off",
+            MenuBackground::Life => "Game of Life",
+            MenuBackground::Tetris => "Falling Pieces",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let index = Self::ALL
+            .iter()
+            .position(|&background| background == self)
+            .unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+/// Longueur de la traînée des balles, pour suivre une balle rapide sur un
+/// terminal lent
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BallTrail {
+    #[default]
+    Off,
+    Short,
+    Long,
+}
+
+impl BallTrail {
+    pub const ALL: [BallTrail; 3] = [BallTrail::Off, BallTrail::Short, BallTrail::Long];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            BallTrail::Off => "Off",
+            BallTrail::Short => "Short",
+            BallTrail::Long => "Long",
+        }
+    }
+
+    /// Nombre de positions passées dessinées derrière la balle
+    pub fn length(self) -> usize {
+        match self {
+            BallTrail::Off => 0,
+            BallTrail::Short => 3,
+            BallTrail::Long => 6,
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let index = Self::ALL
+            .iter()
+            .position(|&trail| trail == self)
+            .unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+/// Cadence de dessin des parties : une image sur N seulement, pour les
+/// terminaux lents et les connexions SSH. La partie avance toujours à chaque
+/// tick ; seul l'affichage est espacé
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FrameSkip {
+    #[default]
+    Off,
+    Two,
+    Three,
+    Four,
+}
+
+impl FrameSkip {
+    pub const ALL: [FrameSkip; 4] = [
+        FrameSkip::Off,
+        FrameSkip::Two,
+        FrameSkip::Three,
+        FrameSkip::Four,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            FrameSkip::Off => "Off",
+            FrameSkip::Two => "1 in 2",
+            FrameSkip::Three => "1 in 3",
+            FrameSkip::Four => "1 in 4",
+        }
+    }
+
+    /// Nombre de ticks entre deux images dessinées
+    pub fn interval(self) -> u32 {
+        match self {
+            FrameSkip::Off => 1,
+            FrameSkip::Two => 2,
+            FrameSkip::Three => 3,
+            FrameSkip::Four => 4,
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|&skip| skip == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+/// Heures sur 12 heures (avec AM/PM) ou sur 24 heures
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ClockFormat {
+    /// Choisi d'après la locale
+    #[default]
+    Auto,
+    #[serde(rename = "24h")]
+    TwentyFourHour,
+    #[serde(rename = "12h")]
+    TwelveHour,
+}
+
+impl ClockFormat {
+    pub const ALL: [ClockFormat; 3] = [
+        ClockFormat::Auto,
+        ClockFormat::TwentyFourHour,
+        ClockFormat::TwelveHour,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ClockFormat::Auto => "Auto",
+            ClockFormat::TwentyFourHour => "24-hour",
+            ClockFormat::TwelveHour => "12-hour",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let index = Self::ALL
+            .iter()
+            .position(|&format| format == self)
+            .unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+/// Ordre des éléments d'une date
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DateOrder {
+    /// Choisi d'après la locale
+    #[default]
+    Auto,
+    /// 2026-10-16
+    Iso,
+    /// 16/10/2026
+    DayMonthYear,
+    /// 10/16/2026
+    MonthDayYear,
+}
+
+impl DateOrder {
+    pub const ALL: [DateOrder; 4] = [
+        DateOrder::Auto,
+        DateOrder::Iso,
+        DateOrder::DayMonthYear,
+        DateOrder::MonthDayYear,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            DateOrder::Auto => "Auto",
+            DateOrder::Iso => "YYYY-MM-DD",
+            DateOrder::DayMonthYear => "DD/MM/YYYY",
+            DateOrder::MonthDayYear => "MM/DD/YYYY",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let index = Self::ALL
+            .iter()
+            .position(|&order| order == self)
+            .unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+/// Fuseau horaire des dates affichées. Les dates sont toujours enregistrées
+/// en UTC
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Timezone {
+    #[default]
+    Local,
+    Utc,
+}
+
+impl Timezone {
+    pub fn name(self) -> &'static str {
+        match self {
+            Timezone::Local => "Local",
+            Timezone::Utc => "UTC",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            Timezone::Local => Timezone::Utc,
+            Timezone::Utc => Timezone::Local,
+        }
+    }
+}
+
+/// Nombre de couleurs du terminal ; en dessous du truecolor, les couleurs
+/// RGB sont ramenées à la palette la plus proche
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ColorDepth {
+    /// Détectée d'après `COLORTERM` et `TERM`
+    #[default]
+    #[serde(rename = "auto")]
+    Auto,
+    #[serde(rename = "truecolor")]
+    TrueColor,
+    #[serde(rename = "256")]
+    Palette256,
+    #[serde(rename = "16")]
+    Palette16,
+}
+
+impl ColorDepth {
+    pub const ALL: [ColorDepth; 4] = [
+        ColorDepth::Auto,
+        ColorDepth::TrueColor,
+        ColorDepth::Palette256,
+        ColorDepth::Palette16,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ColorDepth::Auto => "Auto",
+            ColorDepth::TrueColor => "True Color",
+            ColorDepth::Palette256 => "256 Colors",
+            ColorDepth::Palette16 => "16 Colors",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let index = Self::ALL
+            .iter()
+            .position(|&depth| depth == self)
+            .unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+/// Symboles des en-têtes et des menus, pour les polices sans emoji
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum GlyphSet {
+    /// Choisi d'après la locale et le terminal
+    #[default]
+    Auto,
+    Emoji,
+    /// Symboles Unicode d'une colonne
+    Unicode,
+    Ascii,
+}
+
+impl GlyphSet {
+    pub const ALL: [GlyphSet; 4] = [
+        GlyphSet::Auto,
+        GlyphSet::Emoji,
+        GlyphSet::Unicode,
+        GlyphSet::Ascii,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            GlyphSet::Auto => "Auto",
+            GlyphSet::Emoji => "Emoji",
+            GlyphSet::Unicode => "Unicode",
+            GlyphSet::Ascii => "ASCII",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|&set| set == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+impl Default for DisplayConfig {
+    fn default() -> Self {
+        Self {
+            terminal_title: true,
+            title_progress: true,
+            best_excludes_assisted: false,
+            color_scheme: ColorScheme::default(),
+            transparent_background: false,
+            glyph_set: GlyphSet::default(),
+            reduce_motion: false,
+            splash_screen: true,
+            title_screen: false,
+            menu_background: MenuBackground::default(),
+            color_depth: ColorDepth::default(),
+            ball_trail: BallTrail::default(),
+            frame_skip: FrameSkip::default(),
+            clock_format: ClockFormat::default(),
+            date_order: DateOrder::default(),
+            timezone: Timezone::default(),
+        }
+    }
+}
+
+/// Nom enregistré avec les scores tant que le joueur n'en a pas choisi
+pub const DEFAULT_PLAYER_NAME: &str = "Anonymous";
+/// Longueur maximale du nom du joueur, en caractères
+pub const PLAYER_NAME_MAX_LEN: usize = 16;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameplayConfig {
+    /// Nom enregistré avec les high scores
+    pub player_name: String,
+    /// Le clic droit du Démineur passe par une marque '?' après le drapeau
+    pub minesweeper_question_marks: bool,
+    /// Mettre les parties et la musique en pause quand le terminal perd le focus
+    pub pause_on_focus_loss: bool,
+    /// Orientation du terrain de Pong
+    pub pong_layout: PongLayout,
+    /// Niveau de l'IA de Pong en partie solo
+    pub pong_difficulty: PongDifficulty,
+    /// Panneau de splits (Tetris, Snake, Breakout) comparés au meilleur temps
+    pub split_timer: bool,
+    /// Corrections des événements clavier propres à la plateforme
+    pub platform_profile: PlatformProfile,
+    /// Fréquence des sauvegardes des parties en cours
+    pub autosave: AutosaveInterval,
+    /// Objectif de score personnel par identifiant de jeu
+    pub score_targets: BTreeMap<String, u32>,
+    /// Couleurs des joueurs 1 et 2 dans les modes à deux (Pong, Tetris Doubles)
+    pub player_colors: [PlayerColor; 2],
+}
+
+/// Fréquence des sauvegardes automatiques des parties en cours
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AutosaveInterval {
+    Off,
+    #[serde(rename = "1min")]
+    OneMinute,
+    #[serde(rename = "2min")]
+    TwoMinutes,
+    #[default]
+    #[serde(rename = "5min")]
+    FiveMinutes,
+    #[serde(rename = "10min")]
+    TenMinutes,
+}
+
+impl AutosaveInterval {
+    pub const ALL: [AutosaveInterval; 5] = [
+        AutosaveInterval::Off,
+        AutosaveInterval::OneMinute,
+        AutosaveInterval::TwoMinutes,
+        AutosaveInterval::FiveMinutes,
+        AutosaveInterval::TenMinutes,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            AutosaveInterval::Off => "Off",
+            AutosaveInterval::OneMinute => "Every minute",
+            AutosaveInterval::TwoMinutes => "Every 2 minutes",
+            AutosaveInterval::FiveMinutes => "Every 5 minutes",
+            AutosaveInterval::TenMinutes => "Every 10 minutes",
+        }
+    }
+
+    /// Temps entre deux sauvegardes, `None` si elles sont désactivées
+    pub fn period(self) -> Option<std::time::Duration> {
+        let minutes = match self {
+            AutosaveInterval::Off => return None,
+            AutosaveInterval::OneMinute => 1,
+            AutosaveInterval::TwoMinutes => 2,
+            AutosaveInterval::FiveMinutes => 5,
+            AutosaveInterval::TenMinutes => 10,
+        };
+        Some(std::time::Duration::from_secs(minutes * 60))
+    }
+
+    pub fn next(self) -> Self {
+        let index = Self::ALL
+            .iter()
+            .position(|&interval| interval == self)
+            .unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+/// Couleur d'un joueur dans les modes à deux : raquette, cadre et score
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PlayerColor {
+    Blue,
+    Red,
+    Yellow,
+    Purple,
+    Cyan,
+    Green,
+    Orange,
+}
+
+impl PlayerColor {
+    pub const ALL: [PlayerColor; 7] = [
+        PlayerColor::Blue,
+        PlayerColor::Red,
+        PlayerColor::Yellow,
+        PlayerColor::Purple,
+        PlayerColor::Cyan,
+        PlayerColor::Green,
+        PlayerColor::Orange,
+    ];
+
+    /// Joueur 1 en bleu, joueur 2 en rouge, comme avant le choix des couleurs
+    pub const DEFAULT_PAIR: [PlayerColor; 2] = [PlayerColor::Blue, PlayerColor::Red];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            PlayerColor::Blue => "Blue",
+            PlayerColor::Red => "Red",
+            PlayerColor::Yellow => "Yellow",
+            PlayerColor::Purple => "Purple",
+            PlayerColor::Cyan => "Cyan",
+            PlayerColor::Green => "Green",
+            PlayerColor::Orange => "Orange",
+        }
+    }
+
+    pub fn rgb(self) -> (u8, u8, u8) {
+        match self {
+            PlayerColor::Blue => (90, 150, 255),
+            PlayerColor::Red => (255, 90, 90),
+            PlayerColor::Yellow => (255, 220, 60),
+            PlayerColor::Purple => (190, 110, 255),
+            PlayerColor::Cyan => (60, 220, 220),
+            PlayerColor::Green => (90, 220, 90),
+            PlayerColor::Orange => (255, 150, 40),
+        }
+    }
+
+    /// Famille de teintes. Rouge, orange et vert se confondent pour les
+    /// daltoniens les plus nombreux (rouge-vert), bleu et violet pour les
+    /// protanopes : deux joueurs ne prennent jamais la même famille
+    fn family(self) -> u8 {
+        match self {
+            PlayerColor::Red | PlayerColor::Orange | PlayerColor::Green => 0,
+            PlayerColor::Blue | PlayerColor::Purple => 1,
+            PlayerColor::Yellow => 2,
+            PlayerColor::Cyan => 3,
+        }
+    }
+
+    /// Indique si la couleur se distingue de celle de l'autre joueur
+    pub fn distinct_from(self, other: PlayerColor) -> bool {
+        self.family() != other.family()
+    }
+
+    /// Couleur suivante qui se distingue de `other`, celle de l'autre joueur
+    pub fn next_distinct_from(self, other: PlayerColor) -> Self {
+        let index = Self::ALL
+            .iter()
+            .position(|&color| color == self)
+            .unwrap_or(0);
+        (1..=Self::ALL.len())
+            .map(|step| Self::ALL[(index + step) % Self::ALL.len()])
+            .find(|color| color.distinct_from(other))
+            .unwrap_or(self)
+    }
+
+    /// Couleurs des deux joueurs, celle du joueur 2 changée si elle se
+    /// confond avec celle du joueur 1 (configuration modifiée à la main)
+    pub fn pair([first, second]: [PlayerColor; 2]) -> [PlayerColor; 2] {
+        if second.distinct_from(first) {
+            [first, second]
+        } else {
+            [first, second.next_distinct_from(first)]
+        }
+    }
+}
+
+/// Corrections des événements du terminal (voir `input`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PlatformProfile {
+    /// D'après le système de compilation
+    #[default]
+    Auto,
+    Standard,
+    /// Relâchements de touches ignorés, écran effacé après redimensionnement
+    Windows,
+}
+
+impl PlatformProfile {
+    pub const ALL: [PlatformProfile; 3] = [
+        PlatformProfile::Auto,
+        PlatformProfile::Standard,
+        PlatformProfile::Windows,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            PlatformProfile::Auto => "Auto",
+            PlatformProfile::Standard => "Standard",
+            PlatformProfile::Windows => "Windows",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let index = Self::ALL
+            .iter()
+            .position(|&profile| profile == self)
+            .unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+/// Orientation du terrain de Pong
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PongLayout {
+    /// Raquettes en haut et en bas quand le terminal est plus haut que large
+    #[default]
+    Auto,
+    /// Raquettes à gauche et à droite
+    Horizontal,
+    /// Raquettes en haut et en bas
+    Vertical,
+}
+
+impl PongLayout {
+    pub const ALL: [PongLayout; 3] = [
+        PongLayout::Auto,
+        PongLayout::Horizontal,
+        PongLayout::Vertical,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            PongLayout::Auto => "Auto",
+            PongLayout::Horizontal => "Horizontal",
+            PongLayout::Vertical => "Vertical",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let index = Self::ALL
+            .iter()
+            .position(|&layout| layout == self)
+            .unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+/// Niveau de l'IA de Pong en partie solo
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PongDifficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+    /// L'IA s'adapte à l'écart de score pour garder le match serré
+    Dynamic,
+}
+
+impl PongDifficulty {
+    pub const ALL: [PongDifficulty; 4] = [
+        PongDifficulty::Easy,
+        PongDifficulty::Normal,
+        PongDifficulty::Hard,
+        PongDifficulty::Dynamic,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            PongDifficulty::Easy => "Easy",
+            PongDifficulty::Normal => "Normal",
+            PongDifficulty::Hard => "Hard",
+            PongDifficulty::Dynamic => "Dynamic",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let index = Self::ALL
+            .iter()
+            .position(|&difficulty| difficulty == self)
+            .unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+impl Default for GameplayConfig {
+    fn default() -> Self {
+        Self {
+            player_name: DEFAULT_PLAYER_NAME.to_string(),
+            minesweeper_question_marks: true,
+            pause_on_focus_loss: true,
+            pong_layout: PongLayout::default(),
+            pong_difficulty: PongDifficulty::default(),
+            split_timer: false,
+            platform_profile: PlatformProfile::default(),
+            autosave: AutosaveInterval::default(),
+            score_targets: BTreeMap::new(),
+            player_colors: PlayerColor::DEFAULT_PAIR,
+        }
+    }
+}
+
+/// Liste des jeux du menu Games, par identifiant de jeu
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct GameListConfig {
+    /// Ordre choisi par le joueur ; les jeux absents suivent dans l'ordre par
+    /// défaut
+    pub order: Vec<String>,
+    /// Jeux masqués du menu et de `termplay list`
+    pub hidden: Vec<String>,
+}
+
+/// Musique ou effets coupés (ou rétablis) pendant une partie d'un jeu, avec
+/// M, N ou le menu de pause ; `None` suit les réglages audio généraux
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct GameAudioPreference {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub music_enabled: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub effects_enabled: Option<bool>,
+}
+
+/// Écran du menu atteint par une suite de touches
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ChordAction {
+    MainMenu,
+    Games,
+    HighScores,
+    Statistics,
+    History,
+    MusicPlayer,
+    Settings,
+    About,
+}
+
+impl ChordAction {
+    pub fn name(self) -> &'static str {
+        match self {
+            ChordAction::MainMenu => "Main Menu",
+            ChordAction::Games => "Games",
+            ChordAction::HighScores => "High Scores",
+            ChordAction::Statistics => "Statistics",
+            ChordAction::History => "History",
+            ChordAction::MusicPlayer => "Music Player",
+            ChordAction::Settings => "Settings",
+            ChordAction::About => "About",
+        }
+    }
+}
+
+/// Suite de touches, par exemple `"g s"` (les espaces sont ignorés)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChordBinding {
+    pub keys: String,
+    pub action: ChordAction,
+}
+
+impl ChordBinding {
+    fn new(keys: &str, action: ChordAction) -> Self {
+        Self {
+            keys: keys.to_string(),
+            action,
+        }
+    }
+
+    /// Touches de la suite, sans les espaces
+    pub fn sequence(&self) -> Vec<char> {
+        self.keys.chars().filter(|c| !c.is_whitespace()).collect()
+    }
+}
+
+/// Raccourcis du menu à plusieurs touches, façon vim
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KeymapConfig {
+    pub chords: Vec<ChordBinding>,
+}
+
+impl Default for KeymapConfig {
+    fn default() -> Self {
+        Self {
+            chords: vec![
+                ChordBinding::new("g m", ChordAction::MainMenu),
+                ChordBinding::new("g g", ChordAction::Games),
+                ChordBinding::new("g h", ChordAction::HighScores),
+                ChordBinding::new("g t", ChordAction::Statistics),
+                ChordBinding::new("g y", ChordAction::History),
+                ChordBinding::new("g p", ChordAction::MusicPlayer),
+                ChordBinding::new("g s", ChordAction::Settings),
+                ChordBinding::new("g a", ChordAction::About),
+            ],
         }
     }
 }
@@ -26,41 +937,137 @@ impl Default for AudioConfig {
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct GameConfig {
     pub audio: AudioConfig,
+    #[serde(default)]
+    pub display: DisplayConfig,
+    #[serde(default)]
+    pub gameplay: GameplayConfig,
+    #[serde(default)]
+    pub game_list: GameListConfig,
+    /// Son choisi pendant les parties, par identifiant de jeu
+    #[serde(default)]
+    pub game_audio: BTreeMap<String, GameAudioPreference>,
+    #[serde(default)]
+    pub keymap: KeymapConfig,
     // Ici on pourra ajouter plus tard : high_scores, game_settings, etc.
 }
 
-pub struct ConfigManager {
-    config_path: PathBuf,
-    config: GameConfig,
+/// Réglages du processus, partagés par le menu, l'audio, l'interface et les
+/// jeux : tous les détenteurs d'un handle lisent la même copie. Une
+/// modification est enregistrée, remplace la copie pour tous et prévient les
+/// abonnés (voir [`on_change`])
+#[derive(Clone)]
+pub struct ConfigHandle {
+    shared: Arc<SharedConfig>,
 }
 
-impl ConfigManager {
-    pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
-        let config_path = Self::get_config_path()?;
-        let config = Self::load_config(&config_path)?;
+struct SharedConfig {
+    /// `None` pour une copie détachée (voir [`ConfigHandle::detached`])
+    path: Option<PathBuf>,
+    config: RwLock<Arc<GameConfig>>,
+}
+
+/// Handle du processus, créé à la première lecture de `config.json`
+static HANDLE: Mutex<Option<ConfigHandle>> = Mutex::new(None);
+/// Fonctions appelées après chaque modification des réglages
+static LISTENERS: Mutex<Vec<fn(&GameConfig)>> = Mutex::new(Vec::new());
+
+/// Handle des réglages du processus ; `config.json` n'est lu qu'au premier
+/// appel
+pub fn handle() -> Result<ConfigHandle, Box<dyn std::error::Error>> {
+    let path = ConfigHandle::config_path()?;
+    let mut current = HANDLE.lock().unwrap_or_else(PoisonError::into_inner);
+    // Le dossier de configuration peut changer (tests)
+    if let Some(handle) = current
+        .as_ref()
+        .filter(|handle| handle.shared.path.as_ref() == Some(&path))
+    {
+        return Ok(handle.clone());
+    }
+    let handle = ConfigHandle::load(path)?;
+    *current = Some(handle.clone());
+    Ok(handle)
+}
+
+/// Réglages en vigueur, ceux par défaut si `config.json` est illisible
+pub fn current() -> Arc<GameConfig> {
+    handle().map_or_else(|_| Arc::default(), |handle| handle.snapshot())
+}
+
+/// Appelle `listener` avec les nouveaux réglages après chaque modification,
+/// et tout de suite avec ceux en vigueur
+pub fn on_change(listener: fn(&GameConfig)) {
+    listener(&current());
+    LISTENERS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .push(listener);
+}
+
+fn notify(config: &GameConfig) {
+    let listeners = LISTENERS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone();
+    for listener in listeners {
+        listener(config);
+    }
+}
+
+/// Relit `config.json` après l'avoir écrit sans passer par les handles
+/// (restauration d'une sauvegarde)
+pub fn reload() -> Result<(), Box<dyn std::error::Error>> {
+    let handle = handle()?;
+    let path = handle
+        .shared
+        .path
+        .as_deref()
+        .ok_or("settings are not stored")?;
+    let config = Arc::new(ConfigHandle::load_config(path)?);
+    *handle.write() = config.clone();
+    notify(&config);
+    Ok(())
+}
+
+/// Lit le contenu de `config.json` ; les réglages absents prennent leur
+/// valeur par défaut
+pub fn parse_config(contents: &str) -> Result<GameConfig, serde_json::Error> {
+    serde_json::from_str(contents)
+}
 
+impl ConfigHandle {
+    fn load(path: PathBuf) -> Result<Self, Box<dyn std::error::Error>> {
+        let config = Self::load_config(&path)?;
         Ok(Self {
-            config_path,
-            config,
+            shared: Arc::new(SharedConfig {
+                path: Some(path),
+                config: RwLock::new(Arc::new(config)),
+            }),
         })
     }
 
-    fn get_config_path() -> Result<PathBuf, Box<dyn std::error::Error>> {
-        let config_dir = dirs::config_dir()
-            .ok_or("Could not find config directory")?
-            .join("termplay");
-
-        // Créer le répertoire s'il n'existe pas
-        fs::create_dir_all(&config_dir)?;
+    /// Copie des réglages en vigueur, modifiable pour elle seule : ses
+    /// modifications ne sont jamais enregistrées et ne préviennent pas les
+    /// abonnés. Les sessions invitées du serveur SSH règlent ainsi leur
+    /// partie sans toucher à celles des autres
+    pub fn detached(&self) -> Self {
+        Self {
+            shared: Arc::new(SharedConfig {
+                path: None,
+                config: RwLock::new(self.snapshot()),
+            }),
+        }
+    }
 
+    fn config_path() -> Result<PathBuf, Box<dyn std::error::Error>> {
+        let config_dir = storage::data_dir().ok_or("Could not find config directory")?;
         Ok(config_dir.join("config.json"))
     }
 
-    fn load_config(path: &PathBuf) -> Result<GameConfig, Box<dyn std::error::Error>> {
+    fn load_config(path: &Path) -> Result<GameConfig, Box<dyn std::error::Error>> {
         if path.exists() {
             let contents = fs::read_to_string(path)?;
-            let config: GameConfig = serde_json::from_str(&contents)?;
-            Ok(config)
+            parse_config(&contents)
+                .map_err(|e| format!("invalid settings file {}: {e}", path.display()).into())
         } else {
             // Créer la config par défaut si le fichier n'existe pas
             let default_config = GameConfig::default();
@@ -71,27 +1078,289 @@ impl ConfigManager {
 
     fn save_config_to_file(
         config: &GameConfig,
-        path: &PathBuf,
+        path: &Path,
     ) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
         let json = serde_json::to_string_pretty(config)?;
         fs::write(path, json)?;
         Ok(())
     }
 
-    pub fn save_config(&self) -> Result<(), Box<dyn std::error::Error>> {
-        Self::save_config_to_file(&self.config, &self.config_path)
+    fn write(&self) -> std::sync::RwLockWriteGuard<'_, Arc<GameConfig>> {
+        self.shared
+            .config
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Réglages en vigueur ; la copie ne suit pas les modifications suivantes
+    pub fn snapshot(&self) -> Arc<GameConfig> {
+        self.shared
+            .config
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Partie des réglages en vigueur
+    pub fn get<T>(&self, part: fn(&GameConfig) -> &T) -> ConfigRef<T> {
+        ConfigRef {
+            config: self.snapshot(),
+            part,
+        }
+    }
+
+    /// Modifie les réglages, les enregistre puis prévient les abonnés. Les
+    /// modifications sont faites l'une après l'autre : aucune n'en écrase
+    /// une autre
+    pub fn update(
+        &self,
+        updater: impl FnOnce(&mut GameConfig),
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut current = self.write();
+        let mut config = GameConfig::clone(&current);
+        updater(&mut config);
+        if let Some(path) = &self.shared.path {
+            Self::save_config_to_file(&config, path)?;
+        }
+        let config = Arc::new(config);
+        *current = config.clone();
+        drop(current);
+        if self.shared.path.is_some() {
+            notify(&config);
+        }
+        Ok(())
+    }
+}
+
+/// Partie des réglages en vigueur au moment de la lecture, sans copie
+pub struct ConfigRef<T> {
+    config: Arc<GameConfig>,
+    part: fn(&GameConfig) -> &T,
+}
+
+impl<T> std::ops::Deref for ConfigRef<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        (self.part)(&self.config)
+    }
+}
+
+/// Accès aux réglages à travers un [`ConfigHandle`] : les lectures voient
+/// toujours les derniers réglages enregistrés, même sur un manager ouvert
+/// depuis longtemps
+pub struct ConfigManager {
+    handle: ConfigHandle,
+}
+
+impl ConfigManager {
+    pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
+        Ok(Self::from_handle(handle()?))
+    }
+
+    pub fn from_handle(handle: ConfigHandle) -> Self {
+        Self { handle }
+    }
+
+    pub fn handle(&self) -> &ConfigHandle {
+        &self.handle
+    }
+
+    /// Remet tous les réglages à leurs valeurs par défaut
+    pub fn reset(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        self.handle.update(|config| *config = GameConfig::default())
+    }
+
+    pub fn get_audio_config(&self) -> ConfigRef<AudioConfig> {
+        self.handle.get(|config| &config.audio)
+    }
+
+    pub fn get_display_config(&self) -> ConfigRef<DisplayConfig> {
+        self.handle.get(|config| &config.display)
+    }
+
+    pub fn get_gameplay_config(&self) -> ConfigRef<GameplayConfig> {
+        self.handle.get(|config| &config.gameplay)
     }
 
-    pub fn get_audio_config(&self) -> &AudioConfig {
-        &self.config.audio
+    pub fn get_game_list_config(&self) -> ConfigRef<GameListConfig> {
+        self.handle.get(|config| &config.game_list)
+    }
+
+    pub fn get_keymap_config(&self) -> ConfigRef<KeymapConfig> {
+        self.handle.get(|config| &config.keymap)
+    }
+
+    pub fn update_game_list_config<F>(
+        &mut self,
+        updater: F,
+    ) -> Result<(), Box<dyn std::error::Error>>
+    where
+        F: FnOnce(&mut GameListConfig),
+    {
+        self.handle.update(|config| updater(&mut config.game_list))
+    }
+
+    pub fn get_game_audio(&self, game_id: &str) -> GameAudioPreference {
+        self.handle
+            .snapshot()
+            .game_audio
+            .get(game_id)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn update_game_audio<F>(
+        &mut self,
+        game_id: &str,
+        updater: F,
+    ) -> Result<(), Box<dyn std::error::Error>>
+    where
+        F: FnOnce(&mut GameAudioPreference),
+    {
+        self.handle
+            .update(|config| updater(config.game_audio.entry(game_id.to_string()).or_default()))
+    }
+
+    pub fn update_gameplay_config<F>(
+        &mut self,
+        updater: F,
+    ) -> Result<(), Box<dyn std::error::Error>>
+    where
+        F: FnOnce(&mut GameplayConfig),
+    {
+        self.handle.update(|config| updater(&mut config.gameplay))
+    }
+
+    pub fn update_display_config<F>(&mut self, updater: F) -> Result<(), Box<dyn std::error::Error>>
+    where
+        F: FnOnce(&mut DisplayConfig),
+    {
+        self.handle.update(|config| updater(&mut config.display))
     }
 
     pub fn update_audio_config<F>(&mut self, updater: F) -> Result<(), Box<dyn std::error::Error>>
     where
         F: FnOnce(&mut AudioConfig),
     {
-        updater(&mut self.config.audio);
-        self.save_config()?;
-        Ok(())
+        self.handle.update(|config| updater(&mut config.audio))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::isolate_data_dir;
+
+    #[test]
+    #[cfg_attr(not(feature = "native"), ignore = "no data folder without `native`")]
+    fn managers_share_saved_settings_without_overwriting_each_other() {
+        isolate_data_dir();
+        let mut menu = ConfigManager::new().unwrap();
+        let mut game = ConfigManager::new().unwrap();
+
+        game.update_game_audio("shared-a", |preference| {
+            preference.music_enabled = Some(false)
+        })
+        .unwrap();
+        // Ouvert avant la modification, `menu` la garde en enregistrant la sienne
+        menu.update_game_audio("shared-b", |preference| {
+            preference.effects_enabled = Some(false)
+        })
+        .unwrap();
+
+        let reopened = ConfigManager::new().unwrap();
+        assert_eq!(
+            reopened.get_game_audio("shared-a").music_enabled,
+            Some(false)
+        );
+        assert_eq!(
+            reopened.get_game_audio("shared-b").effects_enabled,
+            Some(false)
+        );
+
+        // Le fichier est bien écrit, et relu après une restauration
+        reload().unwrap();
+        let from_disk = ConfigManager::new().unwrap();
+        assert_eq!(
+            from_disk.get_game_audio("shared-a").music_enabled,
+            Some(false)
+        );
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "native"), ignore = "no data folder without `native`")]
+    fn changes_reach_open_managers_and_listeners() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+        static SEEN: AtomicU32 = AtomicU32::new(0);
+
+        isolate_data_dir();
+        let open = ConfigManager::new().unwrap();
+        on_change(|config| {
+            let target = config.gameplay.score_targets.get("notified").copied();
+            SEEN.store(target.unwrap_or_default(), Ordering::Relaxed);
+        });
+
+        ConfigManager::new()
+            .unwrap()
+            .update_gameplay_config(|gameplay| {
+                gameplay
+                    .score_targets
+                    .insert("notified".to_string(), 50_000);
+            })
+            .unwrap();
+        assert_eq!(SEEN.load(Ordering::Relaxed), 50_000);
+        assert_eq!(
+            open.get_gameplay_config().score_targets.get("notified"),
+            Some(&50_000)
+        );
+        assert_eq!(crate::targets::target_for("notified"), Some(50_000));
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "native"), ignore = "no data folder without `native`")]
+    fn a_detached_copy_keeps_its_changes_to_itself() {
+        isolate_data_dir();
+        let shared = handle().unwrap();
+        let guest = shared.detached();
+
+        ConfigManager::from_handle(guest.clone())
+            .update_gameplay_config(|gameplay| {
+                gameplay.score_targets.insert("detached".to_string(), 7);
+            })
+            .unwrap();
+        assert_eq!(
+            guest.snapshot().gameplay.score_targets.get("detached"),
+            Some(&7)
+        );
+        assert_eq!(
+            shared.snapshot().gameplay.score_targets.get("detached"),
+            None
+        );
+        reload().unwrap();
+        assert_eq!(current().gameplay.score_targets.get("detached"), None);
+    }
+
+    #[test]
+    fn players_never_get_colors_a_colorblind_player_would_confuse() {
+        for first in PlayerColor::ALL {
+            let mut second = first;
+            for _ in 0..PlayerColor::ALL.len() {
+                second = second.next_distinct_from(first);
+                assert!(second.distinct_from(first), "{first:?} / {second:?}");
+            }
+        }
+        assert!(!PlayerColor::Red.distinct_from(PlayerColor::Green));
+        assert_eq!(
+            PlayerColor::pair([PlayerColor::Blue, PlayerColor::Purple]),
+            [PlayerColor::Blue, PlayerColor::Cyan]
+        );
+        assert_eq!(
+            PlayerColor::pair(PlayerColor::DEFAULT_PAIR),
+            PlayerColor::DEFAULT_PAIR
+        );
     }
 }
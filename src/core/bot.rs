@@ -0,0 +1,176 @@
+//! Pilote automatique de raquette : adversaire de Pong, démo IA contre IA et
+//! assistance de Breakout.
+//!
+//! Le bot ne raisonne que sur l'axe de déplacement de la raquette : il compare
+//! son centre à une cible (en général la balle) et indique dans quel sens
+//! bouger. Chaque jeu applique ensuite ce sens avec ses propres mouvements.
+//!
+//! Pour les jeux sur grille (le serpent adverse du mode course de Snake), le
+//! module fournit aussi une recherche de chemin en largeur, et pour les jeux
+//! solo où le hasard joue entre deux coups (l'autoplay de 2048) une recherche
+//! expectimax.
+
+use super::rng;
+use rand::Rng;
+use std::collections::VecDeque;
+
+/// Sens de déplacement choisi par le bot sur l'axe de la raquette
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Steer {
+    /// Vers les coordonnées décroissantes (haut ou gauche)
+    Backward,
+    Stay,
+    /// Vers les coordonnées croissantes (bas ou droite)
+    Forward,
+}
+
+#[derive(Debug, Clone)]
+pub struct PaddleBot {
+    /// Précision entre 0.0 et 1.0 : plus elle est basse, plus le bot vise à côté
+    skill: f32,
+    /// Nombre de frames entre deux décisions, pour éviter les mouvements
+    /// épileptiques
+    reaction_frames: u32,
+    /// Écart toléré avant de bouger
+    dead_zone: f32,
+    frames_waited: u32,
+}
+
+impl PaddleBot {
+    pub fn new(skill: f32, reaction_frames: u32, dead_zone: f32) -> Self {
+        Self {
+            skill: skill.clamp(0.0, 1.0),
+            reaction_frames: reaction_frames.max(1),
+            dead_zone,
+            frames_waited: 0,
+        }
+    }
+
+    /// Sens dans lequel déplacer la raquette pour amener son centre sur `target`
+    pub fn steer(&mut self, paddle_center: f32, target: f32) -> Steer {
+        self.frames_waited += 1;
+        if self.frames_waited < self.reaction_frames {
+            return Steer::Stay;
+        }
+        self.frames_waited = 0;
+
+        // Un peu d'imprécision, d'autant plus que le bot est faible
+        let error = rng::rng().random_range(-0.3..0.3) * (1.0 - self.skill);
+        let diff = target - paddle_center + error;
+
+        if diff > self.dead_zone {
+            Steer::Forward
+        } else if diff < -self.dead_zone {
+            Steer::Backward
+        } else {
+            Steer::Stay
+        }
+    }
+}
+
+/// Cases voisines (haut, bas, gauche, droite) de `cell` dans une grille
+/// `width` x `height`
+pub fn grid_neighbours(
+    width: u16,
+    height: u16,
+    cell: (u16, u16),
+) -> impl Iterator<Item = (u16, u16)> {
+    let (x, y) = cell;
+    [
+        (y > 0).then(|| (x, y - 1)),
+        (y + 1 < height).then_some((x, y + 1)),
+        (x > 0).then(|| (x - 1, y)),
+        (x + 1 < width).then_some((x + 1, y)),
+    ]
+    .into_iter()
+    .flatten()
+}
+
+/// Premier pas du plus court chemin de `start` à `goal` en évitant les cases
+/// `blocked` (parcours en largeur), `None` si le but est inaccessible
+pub fn bfs_first_step(
+    width: u16,
+    height: u16,
+    start: (u16, u16),
+    goal: (u16, u16),
+    blocked: impl Fn((u16, u16)) -> bool,
+) -> Option<(u16, u16)> {
+    let index = |(x, y): (u16, u16)| y as usize * width as usize + x as usize;
+
+    // Case d'où l'on vient pour chaque case visitée
+    let mut came_from: Vec<Option<(u16, u16)>> = vec![None; width as usize * height as usize];
+    let mut queue = VecDeque::from([start]);
+    came_from[index(start)] = Some(start);
+
+    while let Some(cell) = queue.pop_front() {
+        if cell == goal {
+            // Remonter le chemin jusqu'à la case qui suit le départ
+            let mut step = cell;
+            while let Some(previous) = came_from[index(step)] {
+                if previous == start {
+                    return Some(step);
+                }
+                step = previous;
+            }
+            return None;
+        }
+
+        for next in grid_neighbours(width, height, cell) {
+            if came_from[index(next)].is_none() && !blocked(next) {
+                came_from[index(next)] = Some(cell);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+/// Jeu à un joueur où le hasard intervient après chaque coup, exploré par
+/// [`expectimax`]
+pub trait ChanceGame: Sized {
+    type Move: Copy;
+
+    /// Coups qui changent la position, avec la position obtenue
+    fn moves(&self) -> Vec<(Self::Move, Self)>;
+    /// Tirages possibles du hasard après un coup, avec leur probabilité ;
+    /// vide si le hasard ne change rien
+    fn outcomes(&self) -> Vec<(f32, Self)>;
+    /// Valeur heuristique positive de la position, plus haute si elle est
+    /// meilleure
+    fn evaluate(&self) -> f32;
+}
+
+/// Valeur espérée de chaque coup jouable depuis `state`, en explorant
+/// `depth` coups. Une position sans coup jouable vaut 0
+pub fn expectimax<G: ChanceGame>(state: &G, depth: u32) -> Vec<(G::Move, f32)> {
+    state
+        .moves()
+        .into_iter()
+        .map(|(action, next)| (action, chance_value(&next, depth.max(1))))
+        .collect()
+}
+
+/// Meilleur coup de `state` et valeur du nœud joueur
+fn max_value<G: ChanceGame>(state: &G, depth: u32) -> f32 {
+    if depth == 0 {
+        return state.evaluate();
+    }
+    state
+        .moves()
+        .iter()
+        .map(|(_, next)| chance_value(next, depth))
+        .fold(0.0, f32::max)
+}
+
+/// Moyenne des nœuds joueur sur les tirages du hasard
+fn chance_value<G: ChanceGame>(state: &G, depth: u32) -> f32 {
+    let outcomes = state.outcomes();
+    if outcomes.is_empty() {
+        return max_value(state, depth - 1);
+    }
+    outcomes
+        .iter()
+        .map(|(probability, next)| probability * max_value(next, depth - 1))
+        .sum()
+}
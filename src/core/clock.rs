@@ -0,0 +1,123 @@
+//! Chronomètre de partie partagé par les jeux.
+//!
+//! Le temps passé en pause n'est pas compté : c'est ce temps de jeu effectif
+//! qui est affiché et enregistré dans les high scores.
+
+use std::time::Duration;
+
+#[cfg(not(feature = "web"))]
+pub use std::time::Instant;
+/// Horloge monotone des jeux et de l'interface : celle de la bibliothèque
+/// standard, ou celle de `web-time` dans un navigateur, où
+/// `std::time::Instant::now` panique
+#[cfg(feature = "web")]
+pub use web_time::Instant;
+
+#[derive(Debug, Clone)]
+pub struct GameClock {
+    /// Temps cumulé avant la dernière reprise
+    accumulated: Duration,
+    /// Instant de la dernière reprise, `None` si le chronomètre est arrêté
+    resumed_at: Option<Instant>,
+    /// Arrêté par `suspend` alors qu'il tournait
+    suspended: bool,
+}
+
+impl GameClock {
+    /// Chronomètre démarré immédiatement
+    pub fn new() -> Self {
+        Self {
+            accumulated: Duration::ZERO,
+            resumed_at: Some(Instant::now()),
+            suspended: false,
+        }
+    }
+
+    /// Chronomètre à zéro, en attente de `start`
+    pub fn stopped() -> Self {
+        Self {
+            accumulated: Duration::ZERO,
+            resumed_at: None,
+            suspended: false,
+        }
+    }
+
+    /// Chronomètre démarré qui a déjà compté `elapsed`, pour une partie
+    /// reprise
+    pub fn resumed_from(elapsed: Duration) -> Self {
+        Self {
+            accumulated: elapsed,
+            ..Self::new()
+        }
+    }
+
+    /// Remet à zéro et démarre
+    pub fn start(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Remet à zéro sans démarrer
+    pub fn reset(&mut self) {
+        *self = Self::stopped();
+    }
+
+    /// Suspend le décompte (sans effet si déjà en pause)
+    pub fn pause(&mut self) {
+        if let Some(resumed_at) = self.resumed_at.take() {
+            self.accumulated += resumed_at.elapsed();
+        }
+    }
+
+    /// Reprend le décompte (sans effet s'il tourne déjà)
+    pub fn resume(&mut self) {
+        if self.resumed_at.is_none() {
+            self.resumed_at = Some(Instant::now());
+        }
+    }
+
+    /// Suspension venue de l'extérieur du jeu (menu de pause, perte du
+    /// focus) : seul un chronomètre qui tournait sera relancé par `unsuspend`
+    pub fn suspend(&mut self) {
+        if self.resumed_at.is_some() {
+            self.pause();
+            self.suspended = true;
+        }
+    }
+
+    /// Annule `suspend`
+    pub fn unsuspend(&mut self) {
+        if std::mem::take(&mut self.suspended) {
+            self.resume();
+        }
+    }
+
+    /// Arrêté par `suspend` et pas encore relancé
+    pub fn is_suspended(&self) -> bool {
+        self.suspended
+    }
+
+    /// Temps écoulé hors pauses
+    pub fn elapsed(&self) -> Duration {
+        self.accumulated
+            + self
+                .resumed_at
+                .map_or(Duration::ZERO, |resumed_at| resumed_at.elapsed())
+    }
+
+    /// Temps écoulé en secondes entières, pour les high scores
+    pub fn elapsed_secs(&self) -> u64 {
+        self.elapsed().as_secs()
+    }
+
+    /// Temps écoulé au format "m:ss"
+    pub fn format(&self) -> String {
+        let seconds = self.elapsed_secs();
+        format!("{}:{:02}", seconds / 60, seconds % 60)
+    }
+}
+
+impl Default for GameClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,186 @@
+//! Grille 2D générique allouée sur le tas, partagée par les jeux à plateau.
+//!
+//! Les cellules sont stockées à plat, ligne par ligne : la cellule `(x, y)` se
+//! trouve à l'index `y * width + x`. La grille n'occupe que la taille active,
+//! contrairement aux tableaux fixes dimensionnés pour le pire cas.
+
+use serde::{Deserialize, Serialize};
+use std::ops::{Index, IndexMut};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "RawGrid2D<T>", bound(deserialize = "T: Deserialize<'de>"))]
+pub struct Grid2D<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T: Clone> Grid2D<T> {
+    /// Crée une grille `width` x `height` remplie avec `value`
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Remplit toute la grille avec `value`
+    pub fn fill(&mut self, value: T) {
+        self.cells.fill(value);
+    }
+
+    /// Redimensionne la grille en conservant les cellules communes aux deux
+    /// tailles ; les nouvelles cellules reçoivent `value`
+    pub fn resize(&mut self, width: usize, height: usize, value: T) {
+        let mut cells = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(self.get(x, y).cloned().unwrap_or_else(|| value.clone()));
+            }
+        }
+
+        self.cells = cells;
+        self.width = width;
+        self.height = height;
+    }
+
+    /// Supprime la ligne `y` : les lignes au-dessus descendent d'un cran et une
+    /// ligne remplie de `value` apparaît en haut
+    pub fn remove_row(&mut self, y: usize, value: T) {
+        if y >= self.height {
+            return;
+        }
+
+        let end = (y + 1) * self.width;
+        self.cells[..end].rotate_right(self.width);
+        self.cells[..self.width].fill(value);
+    }
+}
+
+impl<T> Grid2D<T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Indique si `(x, y)` est dans la grille
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    /// Accès en lecture, `None` hors de la grille
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if self.contains(x, y) {
+            self.cells.get(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    /// Accès en écriture, `None` hors de la grille
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if self.contains(x, y) {
+            self.cells.get_mut(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    /// Modifie une cellule ; retourne `false` hors de la grille
+    pub fn set(&mut self, x: usize, y: usize, value: T) -> bool {
+        match self.get_mut(x, y) {
+            Some(cell) => {
+                *cell = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Coordonnées des 8 voisins de `(x, y)` situés dans la grille
+    pub fn neighbors(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        let (width, height) = (self.width, self.height);
+        (y.saturating_sub(1)..=(y + 1).min(height.saturating_sub(1))).flat_map(move |ny| {
+            (x.saturating_sub(1)..=(x + 1).min(width.saturating_sub(1)))
+                .filter(move |&nx| nx != x || ny != y)
+                .map(move |nx| (nx, ny))
+        })
+    }
+
+    /// Cellules à plat, ligne par ligne
+    pub fn as_slice(&self) -> &[T] {
+        &self.cells
+    }
+
+    /// Cellules à plat, ligne par ligne, en écriture
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.cells.iter_mut()
+    }
+
+    /// Lignes de la grille, de haut en bas
+    pub fn rows(&self) -> std::slice::Chunks<'_, T> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Lignes de la grille en écriture, de haut en bas
+    pub fn rows_mut(&mut self) -> std::slice::ChunksMut<'_, T> {
+        self.cells.chunks_mut(self.width.max(1))
+    }
+}
+
+/// Forme sérialisée, validée avant de reconstruire la grille
+#[derive(Deserialize)]
+struct RawGrid2D<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> TryFrom<RawGrid2D<T>> for Grid2D<T> {
+    type Error = String;
+
+    fn try_from(raw: RawGrid2D<T>) -> Result<Self, Self::Error> {
+        if raw.cells.len() != raw.width * raw.height {
+            return Err(format!(
+                "grille {}x{} incohérente : {} cellules",
+                raw.width,
+                raw.height,
+                raw.cells.len()
+            ));
+        }
+
+        Ok(Self {
+            cells: raw.cells,
+            width: raw.width,
+            height: raw.height,
+        })
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid2D<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(self.contains(x, y), "({x}, {y}) hors de la grille");
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid2D<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(self.contains(x, y), "({x}, {y}) hors de la grille");
+        &mut self.cells[y * self.width + x]
+    }
+}
@@ -0,0 +1,177 @@
+//! File des commandes reçues entre deux pas de jeu.
+//!
+//! La boucle de jeu transmet chaque touche dès son arrivée, mais un jeu comme
+//! Snake n'applique un virage qu'au pas suivant : deux virages tapés pendant
+//! le même pas (haut puis gauche) doivent être joués l'un après l'autre, sans
+//! que le second écrase le premier. [`InputQueue`] les garde dans l'ordre,
+//! et le jeu en consomme une par pas.
+//!
+//! [`HeldKey`] suit une touche tenue (le sprint de Snake) d'après ses
+//! répétitions automatiques, les terminaux n'envoyant en général pas le
+//! relâchement.
+
+use crate::core::clock::Instant;
+use std::collections::VecDeque;
+use std::time::Duration;
+
+/// Délai avant la première répétition automatique (660 ms par défaut sous
+/// X11) : la touche compte comme tenue pendant ce temps
+const FIRST_REPEAT_GAP: Duration = Duration::from_millis(700);
+/// Écart maximal entre deux répétitions, une fois qu'elles ont commencé ;
+/// au-delà, la touche est relâchée. Deux appuis séparés ne se suivent
+/// jamais d'aussi près
+const REPEAT_GAP: Duration = Duration::from_millis(100);
+
+/// File bornée de commandes, consommées une par pas de jeu
+#[derive(Debug, Clone)]
+pub struct InputQueue<T> {
+    items: VecDeque<T>,
+    capacity: usize,
+}
+
+impl<T: Copy + PartialEq> InputQueue<T> {
+    /// Au-delà de `capacity` commandes en attente, les suivantes sont
+    /// ignorées : le jeu ne prend pas trop de retard sur le joueur
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            items: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Ajoute une commande en fin de file ; renvoie `false` si elle est
+    /// ignorée (file pleine, ou répétition de la dernière commande)
+    pub fn push(&mut self, item: T) -> bool {
+        if self.items.len() >= self.capacity || self.items.back() == Some(&item) {
+            return false;
+        }
+        self.items.push_back(item);
+        true
+    }
+
+    /// Commande suivante à appliquer
+    pub fn pop(&mut self) -> Option<T> {
+        self.items.pop_front()
+    }
+
+    /// Dernière commande en attente, celle à laquelle la suivante succède
+    pub fn last(&self) -> Option<T> {
+        self.items.back().copied()
+    }
+
+    pub fn clear(&mut self) {
+        self.items.clear();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+/// Touche tenue, reconnue à ses répétitions
+#[derive(Debug, Clone, Default)]
+pub struct HeldKey {
+    first_press: Option<Instant>,
+    last_press: Option<Instant>,
+    repeats: u32,
+}
+
+impl HeldKey {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pression ou répétition de la touche
+    pub fn press(&mut self, now: Instant) {
+        if self.is_held(now) {
+            self.repeats += 1;
+        } else {
+            self.first_press = Some(now);
+            self.repeats = 0;
+        }
+        self.last_press = Some(now);
+    }
+
+    /// Touche relâchée ou autre touche pressée
+    pub fn release(&mut self) {
+        *self = Self::default();
+    }
+
+    /// La touche est-elle toujours tenue à `now` ?
+    pub fn is_held(&self, now: Instant) -> bool {
+        let gap = if self.repeats == 0 {
+            FIRST_REPEAT_GAP
+        } else {
+            REPEAT_GAP
+        };
+        self.last_press
+            .is_some_and(|last_press| now.duration_since(last_press) <= gap)
+    }
+
+    /// La touche se répète à la cadence automatique : au moins deux
+    /// répétitions d'affilée, ce qu'aucun double appui ne produit
+    pub fn is_repeating(&self, now: Instant) -> bool {
+        self.repeats >= 2 && self.is_held(now)
+    }
+
+    /// Durée depuis la première pression, `None` si la touche n'est plus tenue
+    pub fn held_for(&self, now: Instant) -> Option<Duration> {
+        self.first_press
+            .filter(|_| self.is_held(now))
+            .map(|first_press| now.duration_since(first_press))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_key_is_held_while_its_repeats_keep_coming() {
+        let start = Instant::now();
+        let at = |ms: u64| start + Duration::from_millis(ms);
+        let mut key = HeldKey::new();
+        assert!(!key.is_held(start));
+
+        // Une première pression attend la répétition automatique
+        key.press(at(0));
+        assert!(key.is_held(at(600)));
+        // Les répétitions rapprochées gardent la touche tenue...
+        key.press(at(660));
+        key.press(at(700));
+        assert!(key.is_held(at(800)));
+        assert!(key.is_repeating(at(800)));
+        assert_eq!(key.held_for(at(800)), Some(Duration::from_millis(800)));
+        // ...et leur arrêt la relâche vite
+        assert!(!key.is_held(at(900)));
+        assert_eq!(key.held_for(at(900)), None);
+    }
+
+    #[test]
+    fn two_taps_are_not_a_repeat_chain() {
+        let start = Instant::now();
+        let at = |ms: u64| start + Duration::from_millis(ms);
+        let mut key = HeldKey::new();
+
+        key.press(at(0));
+        key.press(at(600));
+        assert!(!key.is_repeating(at(600)));
+        // Un troisième appui trop tard repart de zéro
+        key.press(at(1200));
+        assert!(!key.is_repeating(at(1200)));
+        assert_eq!(key.held_for(at(1200)), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn commands_come_out_in_order_and_the_queue_is_bounded() {
+        let mut queue = InputQueue::new(2);
+        assert!(queue.push('u'));
+        assert!(!queue.push('u'));
+        assert!(queue.push('l'));
+        assert!(!queue.push('d'));
+        assert_eq!(queue.last(), Some('l'));
+        assert_eq!(queue.pop(), Some('u'));
+        assert_eq!(queue.pop(), Some('l'));
+        assert!(queue.is_empty());
+    }
+}
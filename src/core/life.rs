@@ -0,0 +1,233 @@
+//! Noyau de calcul du Jeu de la Vie, indépendant du rendu et de l'audio.
+//!
+//! Les grilles sont stockées à plat, ligne par ligne : la cellule `(x, y)` se
+//! trouve à l'index `y * stride + x`. Seules les `width` premières colonnes et
+//! les `height` premières lignes sont simulées.
+
+/// Nombre minimal de cellules actives à partir duquel le calcul est parallélisé.
+/// En dessous (grilles Small/Medium), le coût de répartition sur les threads
+/// dépasse le gain.
+#[cfg(feature = "parallel")]
+pub const PARALLEL_THRESHOLD: usize = 80 * 40;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CellState {
+    Dead,
+    Alive,
+}
+
+/// Compte les voisins vivants d'une cellule (bords non toriques)
+pub fn count_neighbors(
+    grid: &[CellState],
+    stride: usize,
+    width: usize,
+    height: usize,
+    x: usize,
+    y: usize,
+) -> u8 {
+    let mut count = 0;
+
+    for ny in y.saturating_sub(1)..=(y + 1).min(height - 1) {
+        for nx in x.saturating_sub(1)..=(x + 1).min(width - 1) {
+            if (nx != x || ny != y) && grid[ny * stride + nx] == CellState::Alive {
+                count += 1;
+            }
+        }
+    }
+
+    count
+}
+
+/// Applique les règles de Conway à une cellule
+pub fn next_state(current: CellState, neighbors: u8) -> CellState {
+    match (current, neighbors) {
+        // Règle 1: Une cellule vivante avec moins de 2 voisins meurt (sous-population)
+        (CellState::Alive, n) if n < 2 => CellState::Dead,
+        // Règle 2: Une cellule vivante avec 2 ou 3 voisins survit
+        (CellState::Alive, 2..=3) => CellState::Alive,
+        // Règle 3: Une cellule vivante avec plus de 3 voisins meurt (surpopulation)
+        (CellState::Alive, _) => CellState::Dead,
+        // Règle 4: Une cellule morte avec exactement 3 voisins devient vivante (reproduction)
+        (CellState::Dead, 3) => CellState::Alive,
+        // Toutes les autres cellules restent mortes
+        (CellState::Dead, _) => CellState::Dead,
+    }
+}
+
+/// Calcule une ligne de la génération suivante
+fn compute_row(
+    src: &[CellState],
+    out_row: &mut [CellState],
+    stride: usize,
+    width: usize,
+    height: usize,
+    y: usize,
+) {
+    for (x, cell) in out_row.iter_mut().enumerate().take(width) {
+        let neighbors = count_neighbors(src, stride, width, height, x, y);
+        *cell = next_state(src[y * stride + x], neighbors);
+    }
+}
+
+/// Calcule la génération suivante de `src` dans `dst` sur un seul thread
+pub fn next_generation_serial(
+    src: &[CellState],
+    dst: &mut [CellState],
+    stride: usize,
+    width: usize,
+    height: usize,
+) {
+    if width == 0 || height == 0 {
+        return;
+    }
+
+    for (y, row) in dst.chunks_mut(stride).take(height).enumerate() {
+        compute_row(src, row, stride, width, height, y);
+    }
+}
+
+/// Calcule la génération suivante en répartissant les lignes sur tous les cœurs
+#[cfg(feature = "parallel")]
+pub fn next_generation_parallel(
+    src: &[CellState],
+    dst: &mut [CellState],
+    stride: usize,
+    width: usize,
+    height: usize,
+) {
+    use rayon::prelude::*;
+
+    if width == 0 || height == 0 {
+        return;
+    }
+
+    // Regrouper les lignes par paquets pour limiter le coût de répartition
+    dst.par_chunks_mut(stride)
+        .take(height)
+        .enumerate()
+        .with_min_len(8)
+        .for_each(|(y, row)| compute_row(src, row, stride, width, height, y));
+}
+
+/// Calcule la génération suivante en choisissant la stratégie selon la taille
+/// de la grille et les fonctionnalités compilées
+pub fn next_generation(
+    src: &[CellState],
+    dst: &mut [CellState],
+    stride: usize,
+    width: usize,
+    height: usize,
+) {
+    #[cfg(feature = "parallel")]
+    if width * height >= PARALLEL_THRESHOLD {
+        next_generation_parallel(src, dst, stride, width, height);
+        return;
+    }
+
+    next_generation_serial(src, dst, stride, width, height);
+}
+
+/// Marge ajoutée autour du motif quand il s'approche d'un bord
+const GROW_MARGIN: usize = 16;
+
+/// Simulation sans affichage sur un plan illimité : la grille s'agrandit dès
+/// qu'une cellule vivante approche d'un bord, pour que les bords morts de
+/// `next_generation` n'influencent jamais le résultat
+pub struct Simulation {
+    cells: Vec<CellState>,
+    scratch: Vec<CellState>,
+    width: usize,
+    height: usize,
+    generation: u64,
+}
+
+impl Simulation {
+    /// Simulation démarrant avec les cellules vivantes données
+    pub fn from_cells(cells: &[(usize, usize)]) -> Self {
+        let width = cells.iter().map(|&(x, _)| x + 1).max().unwrap_or(0);
+        let height = cells.iter().map(|&(_, y)| y + 1).max().unwrap_or(0);
+
+        let mut grid = vec![CellState::Dead; width * height];
+        for &(x, y) in cells {
+            grid[y * width + x] = CellState::Alive;
+        }
+
+        let mut simulation = Self {
+            scratch: grid.clone(),
+            cells: grid,
+            width,
+            height,
+            generation: 0,
+        };
+        simulation.grow_if_needed();
+        simulation
+    }
+
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    pub fn population(&self) -> usize {
+        self.cells
+            .iter()
+            .filter(|&&cell| cell == CellState::Alive)
+            .count()
+    }
+
+    /// Avance d'une génération
+    pub fn step(&mut self) {
+        next_generation(
+            &self.cells,
+            &mut self.scratch,
+            self.width,
+            self.width,
+            self.height,
+        );
+        std::mem::swap(&mut self.cells, &mut self.scratch);
+        self.generation += 1;
+        self.grow_if_needed();
+    }
+
+    /// Cellules vivantes, ramenées en haut à gauche de leur boîte englobante
+    pub fn live_cells(&self) -> Vec<(usize, usize)> {
+        let alive: Vec<(usize, usize)> = (0..self.height)
+            .flat_map(|y| (0..self.width).map(move |x| (x, y)))
+            .filter(|&(x, y)| self.cells[y * self.width + x] == CellState::Alive)
+            .collect();
+
+        let min_x = alive.iter().map(|&(x, _)| x).min().unwrap_or(0);
+        let min_y = alive.iter().map(|&(_, y)| y).min().unwrap_or(0);
+        alive
+            .into_iter()
+            .map(|(x, y)| (x - min_x, y - min_y))
+            .collect()
+    }
+
+    /// Agrandit la grille de `GROW_MARGIN` de chaque côté si une cellule
+    /// vivante est à moins de deux cases d'un bord
+    fn grow_if_needed(&mut self) {
+        let near_edge = (0..self.height).any(|y| {
+            (0..self.width).any(|x| {
+                self.cells[y * self.width + x] == CellState::Alive
+                    && (x < 2 || y < 2 || x + 2 >= self.width || y + 2 >= self.height)
+            })
+        });
+        if !near_edge && self.width > 0 {
+            return;
+        }
+
+        let width = self.width + 2 * GROW_MARGIN;
+        let height = self.height + 2 * GROW_MARGIN;
+        let mut grid = vec![CellState::Dead; width * height];
+        for y in 0..self.height {
+            let src = &self.cells[y * self.width..(y + 1) * self.width];
+            let start = (y + GROW_MARGIN) * width + GROW_MARGIN;
+            grid[start..start + self.width].copy_from_slice(src);
+        }
+
+        self.scratch = grid.clone();
+        self.cells = grid;
+        self.width = width;
+        self.height = height;
+    }
+}
@@ -1,5 +1,16 @@
-use crossterm::event::KeyEvent;
-use ratatui::Frame;
+pub mod bot;
+pub mod clock;
+pub mod grid;
+pub mod input;
+pub mod life;
+pub mod rng;
+pub mod share;
+
+use crate::audio::AudioManager;
+use crate::event::{KeyEvent, MouseEvent};
+use crate::splits::SplitTimer;
+use ratatui::{layout::Rect, Frame};
+use share::ShareCode;
 use std::error::Error;
 
 pub type GameResult = Result<(), Box<dyn Error>>;
@@ -11,26 +22,198 @@ pub enum GameAction {
     GameOver,
 }
 
+/// État de la partie vu depuis l'extérieur du jeu (boucle de jeu, titre du
+/// terminal…), commun à tous les jeux
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameStatus {
+    Menu, // Écran de sélection propre au jeu (mode, difficulté…)
+    Running,
+    Paused,
+    GameOver,
+    Victory,
+}
+
+impl GameStatus {
+    /// Libellé affiché dans le titre du terminal, `None` en cours de partie
+    pub fn label(self) -> Option<&'static str> {
+        match self {
+            GameStatus::Running => None,
+            GameStatus::Menu => Some("Menu"),
+            GameStatus::Paused => Some("Paused"),
+            GameStatus::GameOver => Some("Game Over"),
+            GameStatus::Victory => Some("Victory"),
+        }
+    }
+}
+
+/// Danger de la partie en cours, rendu par le jeu en teintant son terrain
+/// (voir [`crate::ui::animation::danger_color`])
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DangerLevel {
+    #[default]
+    Calm,
+    /// La partie se corse : pile haute, peu de cases sûres, grande vitesse
+    Warning,
+    /// La partie est sur le point de se terminer
+    Critical,
+}
+
 pub trait Game {
+    /// Appelé pour chaque touche pressée, dans l'ordre d'arrivée, entre deux
+    /// appels à `update`. Un jeu qui n'applique une commande qu'à son pas
+    /// suivant la garde dans une [`input::InputQueue`] pour n'en perdre
+    /// aucune
     fn handle_key(&mut self, key: KeyEvent) -> GameAction;
     fn update(&mut self) -> GameAction;
-    fn draw(&mut self, frame: &mut Frame);
+    /// Dessine le jeu dans `area` sans rien toucher autour : tout l'écran
+    /// (`frame.area()`) pour une partie normale, un volet ou un aperçu sinon
+    fn draw(&mut self, frame: &mut Frame, area: Rect);
     fn tick_rate(&self) -> std::time::Duration {
         std::time::Duration::from_millis(250) // Valeur par défaut
     }
+    /// Hors de `Running`, la boucle de jeu passe à quelques images par
+    /// seconde et le titre du terminal affiche l'état
+    fn status(&self) -> GameStatus {
+        GameStatus::Running
+    }
+    /// Danger de la partie en cours. Au-dessus de `Calm`, la boucle de jeu
+    /// redessine assez souvent pour animer la pulsation de la teinte
+    fn danger(&self) -> DangerLevel {
+        DangerLevel::Calm
+    }
+    /// Statut court affiché dans le titre du terminal (score en général)
+    fn title_status(&self) -> Option<String> {
+        None
+    }
+    /// Progression d'une longue simulation, affichée dans le titre si activé
+    fn title_progress(&self) -> Option<String> {
+        None
+    }
+    /// Score de la partie en cours, enregistré dans l'historique à la fin de
+    /// la session
+    fn session_score(&self) -> Option<u32> {
+        None
+    }
+    /// Mode de jeu choisi dans le jeu lui-même, pour l'historique
+    fn session_mode(&self) -> Option<String> {
+        None
+    }
+    /// Avancement vers l'objectif d'une étape du Gauntlet : lignes pour
+    /// Tetris, cases ouvertes pour le Démineur
+    fn stage_progress(&self) -> Option<u32> {
+        None
+    }
+    /// Appelé avant un arrêt forcé (Ctrl+C, SIGTERM…) pour sauvegarder la partie
+    fn on_shutdown(&mut self) {}
+    /// État de la partie en cours, pour la reprendre après une interruption
+    /// (voir [`crate::saves`]). `None` pour les jeux qui ne savent pas
+    /// reprendre une partie, ou quand il n'y a rien à reprendre (partie
+    /// terminée, pas encore commencée). Le temps de jeu rangé sous
+    /// `elapsed_ms` est affiché par le menu de reprise
+    fn save_state(&self) -> Option<serde_json::Value> {
+        None
+    }
+    /// Reprend une partie décrite par `save_state` ; `false` si l'état est
+    /// illisible, la partie restant alors celle du lancement
+    fn restore_state(&mut self, _state: serde_json::Value) -> bool {
+        false
+    }
+    /// Appelé quand la partie est suspendue (menu de pause, perte du focus) :
+    /// `update` n'est plus appelé jusqu'à `on_resume`. Les jeux qui ont un
+    /// état de pause s'y placent pour afficher leur propre écran au retour
+    fn on_pause(&mut self) {}
+    /// Appelé quand la partie reprend après `on_pause`
+    fn on_resume(&mut self) {}
+    /// Indique que le jeu utilise lui-même Échap en ce moment (fenêtre de
+    /// saisie, sélection…) ; sinon Échap ouvre le menu de pause
+    fn captures_esc(&self) -> bool {
+        false
+    }
+    /// Indique que le jeu utilise lui-même '+' et '-' (vitesse…) ; sinon ces
+    /// touches règlent le volume général. F10 coupe le son dans tous les cas
+    fn captures_volume_keys(&self) -> bool {
+        false
+    }
+    /// R agit dès la pression, sans maintien pour confirmer : parties courtes
+    /// qu'on relance à la chaîne, ou jeux où R ne recommence pas la partie
+    fn instant_restart(&self) -> bool {
+        false
+    }
+    /// Audio du jeu, pour les options du menu de pause
+    fn audio(&self) -> Option<&AudioManager> {
+        None
+    }
+    /// Texte collé dans le terminal, reçu d'un bloc. Ignoré par défaut :
+    /// seuls les champs de saisie en font quelque chose, pour qu'un collage
+    /// ne soit jamais rejoué comme une suite de touches (q, r…)
+    fn handle_paste(&mut self, _text: &str) {}
+    /// Clic, survol ou molette dans le terminal. Ignoré par défaut : seuls
+    /// les jeux qui se jouent à la souris en font quelque chose
+    fn handle_mouse(&mut self, _mouse: MouseEvent) {}
+    /// Recommence sur la partie d'un code de partage de ce jeu (même graine,
+    /// même option). Ignoré par les jeux qui ne se partagent pas
+    fn play_share_code(&mut self, _code: &ShareCode) {}
+    /// Code de la partie en cours, que `play_share_code` rejoue à
+    /// l'identique ; `None` pour les jeux qui ne se partagent pas
+    fn shared_game(&self) -> Option<ShareCode> {
+        None
+    }
+    /// Splits de la partie et temps de jeu écoulé, pour le panneau affiché
+    /// quand l'option Split Timer est activée
+    fn splits(&self) -> Option<(&SplitTimer, std::time::Duration)> {
+        None
+    }
+    /// Plateau figé dessiné dans le panneau d'aperçu du menu Games (voir
+    /// [`crate::ui::preview`]). Rien par défaut : le menu affiche alors
+    /// l'icône du jeu
+    fn preview(&self, _frame: &mut Frame, _area: Rect) {}
 }
 
 #[derive(Debug, Clone)]
 pub struct GameInfo {
+    /// Identifiant stable, utilisé en ligne de commande et dans les fichiers
+    /// enregistrés (configuration, historique)
+    pub id: String,
+    /// Nom affiché dans les menus et le titre du terminal
     pub name: String,
     pub description: String,
+    /// Autres noms acceptés en ligne de commande
+    pub aliases: Vec<String>,
+    /// Icône affichée devant le nom dans les menus (un emoji, remplacé selon
+    /// le jeu de symboles par [`crate::ui::glyphs`])
+    pub icon: &'static str,
+    /// Présentation détaillée du jeu, affichée dans le panneau de détails du
+    /// menu Games et par `termplay list --verbose`
+    pub long_description: &'static str,
+    /// Touches propres au jeu et leur effet, reprises par la page Controls du
+    /// menu de pause ; les raccourcis communs sont dans
+    /// [`crate::ui::controls::CONTROL_HINTS`]
+    pub controls: &'static [(&'static str, &'static str)],
 }
 
 impl GameInfo {
-    pub fn new(name: impl Into<String>, description: impl Into<String>) -> Self {
+    pub fn new(
+        id: impl Into<String>,
+        name: impl Into<String>,
+        description: impl Into<String>,
+    ) -> Self {
         Self {
+            id: id.into(),
             name: name.into(),
             description: description.into(),
+            aliases: Vec::new(),
+            icon: "🎮",
+            long_description: "",
+            controls: &[],
+        }
+    }
+
+    /// Présentation détaillée, ou la description courte à défaut
+    pub fn details(&self) -> &str {
+        if self.long_description.is_empty() {
+            &self.description
+        } else {
+            self.long_description
         }
     }
 }
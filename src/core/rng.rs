@@ -0,0 +1,65 @@
+//! Générateur aléatoire des jeux.
+//!
+//! Par défaut, c'est le générateur du thread de `rand`. [`seed`] le remplace,
+//! pour le thread courant, par un générateur déterministe : les tests
+//! obtiennent ainsi toujours la même nourriture, les mêmes pièces et les
+//! mêmes tuiles d'une exécution à l'autre.
+
+use rand::rngs::{StdRng, ThreadRng};
+use rand::{RngCore, SeedableRng};
+use std::cell::RefCell;
+
+thread_local! {
+    static SEEDED: RefCell<Option<StdRng>> = const { RefCell::new(None) };
+}
+
+/// Rend les tirages du thread courant déterministes à partir de `seed`
+pub fn seed(seed: u64) {
+    SEEDED.with(|seeded| *seeded.borrow_mut() = Some(StdRng::seed_from_u64(seed)));
+}
+
+/// Revient aux tirages aléatoires habituels pour le thread courant
+pub fn unseed() {
+    SEEDED.with(|seeded| *seeded.borrow_mut() = None);
+}
+
+/// Générateur à utiliser par les jeux à la place de `rand::rng()`
+pub fn rng() -> GameRng {
+    if SEEDED.with(|seeded| seeded.borrow().is_some()) {
+        GameRng::Seeded
+    } else {
+        GameRng::Thread(rand::rng())
+    }
+}
+
+pub enum GameRng {
+    Thread(ThreadRng),
+    /// Tire dans le générateur déterministe du thread
+    Seeded,
+}
+
+impl GameRng {
+    fn with<R>(&mut self, f: impl FnOnce(&mut dyn RngCore) -> R) -> R {
+        match self {
+            GameRng::Thread(rng) => f(rng),
+            GameRng::Seeded => SEEDED.with(|seeded| match seeded.borrow_mut().as_mut() {
+                Some(rng) => f(rng),
+                None => f(&mut rand::rng()),
+            }),
+        }
+    }
+}
+
+impl RngCore for GameRng {
+    fn next_u32(&mut self) -> u32 {
+        self.with(|rng| rng.next_u32())
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.with(|rng| rng.next_u64())
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.with(|rng| rng.fill_bytes(dest))
+    }
+}
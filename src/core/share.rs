@@ -0,0 +1,152 @@
+//! Codes de partage des parties.
+//!
+//! Un code contient le jeu, la graine de ses tirages et une option propre au
+//! jeu (la première case ouverte du Démineur), pour qu'un ami rejoue
+//! exactement la même grille ou la même suite de tuiles. Il est écrit en
+//! base 32 de Crockford, par groupes de six caractères : `0`/`O` et
+//! `1`/`I`/`L` sont confondus à la saisie, et un CRC de six bits refuse
+//! tout code dont un seul caractère est faux.
+
+use crate::core::rng;
+use rand::Rng;
+
+const ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+/// Caractères d'un code : 84 bits de données et 6 bits de contrôle
+const CODE_LEN: usize = 18;
+const PAYLOAD_BITS: u32 = 84;
+const CHECK_BITS: u32 = 6;
+const GROUP_LEN: usize = 6;
+
+/// Jeux dont les parties peuvent être partagées
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SharedGame {
+    Minesweeper,
+    Game2048,
+}
+
+impl SharedGame {
+    const ALL: [SharedGame; 2] = [SharedGame::Minesweeper, SharedGame::Game2048];
+
+    /// Identifiant du jeu dans le registre
+    pub fn id(self) -> &'static str {
+        match self {
+            SharedGame::Minesweeper => "minesweeper",
+            SharedGame::Game2048 => "2048",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShareCode {
+    pub game: SharedGame,
+    pub seed: u64,
+    /// Option propre au jeu, 0 si le jeu n'en a pas
+    pub extra: u16,
+}
+
+impl ShareCode {
+    /// Code au format `XXXXXX-XXXXXX-XXXXXX`
+    pub fn encode(&self) -> String {
+        let payload = self.payload();
+        let value = (payload << CHECK_BITS) | u128::from(checksum(payload));
+        let chars: Vec<char> = (0..CODE_LEN)
+            .rev()
+            .map(|i| char::from(ALPHABET[((value >> (5 * i)) & 31) as usize]))
+            .collect();
+        chars
+            .chunks(GROUP_LEN)
+            .map(|group| group.iter().collect::<String>())
+            .collect::<Vec<_>>()
+            .join("-")
+    }
+
+    /// Lit un code saisi par le joueur ; `None` s'il est invalide
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut value: u128 = 0;
+        let mut len = 0;
+        for c in text.chars().filter(|c| !matches!(c, '-' | ' ')) {
+            let c = match c.to_ascii_uppercase() {
+                'O' => '0',
+                'I' | 'L' => '1',
+                c => c,
+            };
+            let digit = ALPHABET.iter().position(|&a| char::from(a) == c)?;
+            value = (value << 5) | digit as u128;
+            len += 1;
+        }
+        if len != CODE_LEN {
+            return None;
+        }
+
+        let payload = value >> CHECK_BITS;
+        if (value & ((1 << CHECK_BITS) - 1)) as u8 != checksum(payload) {
+            return None;
+        }
+        Some(Self {
+            game: *SharedGame::ALL.get((payload >> 80) as usize)?,
+            seed: (payload >> 16) as u64,
+            extra: payload as u16,
+        })
+    }
+
+    /// Jeu (4 bits), graine (64 bits) puis option (16 bits)
+    fn payload(&self) -> u128 {
+        let game = SharedGame::ALL
+            .iter()
+            .position(|&game| game == self.game)
+            .unwrap_or(0);
+        (game as u128) << 80 | u128::from(self.seed) << 16 | u128::from(self.extra)
+    }
+}
+
+/// CRC-6 (x⁶ + x + 1) des données. Un caractère change au plus cinq bits
+/// consécutifs, une erreur que ce polynôme détecte toujours
+fn checksum(payload: u128) -> u8 {
+    (0..PAYLOAD_BITS).rev().fold(0, |crc, i| {
+        let bit = (payload >> i) as u8 & 1;
+        let shifted = (crc << 1) & 0x3F;
+        if bit ^ (crc >> 5) == 1 {
+            shifted ^ 0x03
+        } else {
+            shifted
+        }
+    })
+}
+
+/// Graine d'une nouvelle partie
+pub fn new_seed() -> u64 {
+    rng::rng().random()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codes_round_trip_and_reject_typos() {
+        let code = ShareCode {
+            game: SharedGame::Minesweeper,
+            seed: 0xDEAD_BEEF_0123_4567,
+            extra: 0x0A03,
+        };
+        let text = code.encode();
+        assert_eq!(text.len(), CODE_LEN + 2);
+        assert_eq!(ShareCode::parse(&text), Some(code));
+        assert_eq!(
+            ShareCode::parse(&text.to_lowercase().replace('-', " ")),
+            Some(code)
+        );
+
+        // Toute faute de frappe sur un seul caractère est refusée, dans les
+        // données comme dans le contrôle
+        for (i, c) in text.char_indices().filter(|&(_, c)| c != '-') {
+            let digit = ALPHABET.iter().position(|&a| char::from(a) == c).unwrap();
+            for other in (0..32).filter(|&d| d != digit) {
+                let mut typo = text.clone();
+                typo.replace_range(i..=i, &char::from(ALPHABET[other]).to_string());
+                assert_eq!(ShareCode::parse(&typo), None, "{typo}");
+            }
+        }
+        assert_eq!(ShareCode::parse("ABC"), None);
+    }
+}
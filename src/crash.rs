@@ -0,0 +1,205 @@
+//! Récupération après un panic survenu pendant une partie.
+//!
+//! Pendant l'exécution d'un jeu via [`run_guarded`], le hook de panic se
+//! contente d'enregistrer le message et la backtrace (dans `crash.log`) sans
+//! toucher au terminal : l'application peut alors afficher un écran d'excuse et
+//! revenir au menu. Hors de cette zone, le hook précédent s'applique.
+
+use crate::storage;
+use crate::ui::style;
+use ratatui::{
+    layout::{Alignment, Constraint, Layout},
+    style::{Color, Style, Stylize},
+    text::Line,
+    widgets::{Block, Paragraph, Wrap},
+    Frame,
+};
+use std::backtrace::Backtrace;
+use std::cell::{Cell, RefCell};
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Informations sur un panic intercepté
+#[derive(Debug, Clone)]
+pub struct CrashReport {
+    pub message: String,
+    pub location: Option<String>,
+    pub log_path: Option<PathBuf>,
+}
+
+thread_local! {
+    static GUARDED: Cell<bool> = const { Cell::new(false) };
+    static LAST_REPORT: RefCell<Option<CrashReport>> = const { RefCell::new(None) };
+}
+
+/// Installe un hook qui intercepte les panics survenant dans [`run_guarded`]
+/// et délègue les autres au hook déjà en place
+pub fn install_hook() {
+    let previous_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |panic_info| {
+        if !GUARDED.with(Cell::get) {
+            previous_hook(panic_info);
+            return;
+        }
+
+        let message = if let Some(message) = panic_info.payload().downcast_ref::<&str>() {
+            (*message).to_string()
+        } else if let Some(message) = panic_info.payload().downcast_ref::<String>() {
+            message.clone()
+        } else {
+            "unknown panic".to_string()
+        };
+        let location = panic_info
+            .location()
+            .map(|location| format!("{}:{}", location.file(), location.line()));
+        let log_path = write_crash_log(&message, location.as_deref());
+
+        LAST_REPORT.with(|report| {
+            *report.borrow_mut() = Some(CrashReport {
+                message,
+                location,
+                log_path,
+            })
+        });
+    }));
+}
+
+/// Exécute `f` en interceptant un éventuel panic
+pub fn run_guarded<R>(f: impl FnOnce() -> R) -> Result<R, CrashReport> {
+    GUARDED.with(|guarded| guarded.set(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    GUARDED.with(|guarded| guarded.set(false));
+
+    result.map_err(|_| {
+        LAST_REPORT
+            .with(|report| report.borrow_mut().take())
+            .unwrap_or_else(|| CrashReport {
+                message: "unknown panic".to_string(),
+                location: None,
+                log_path: None,
+            })
+    })
+}
+
+/// Ajoute le panic et sa backtrace au fichier `crash.log` de la config
+fn write_crash_log(message: &str, location: Option<&str>) -> Option<PathBuf> {
+    append_to_log(&format!(
+        "panicked at {}: {message}\n{}",
+        location.unwrap_or("unknown location"),
+        Backtrace::force_capture()
+    ))
+}
+
+/// Note dans `crash.log` une partie qui ne répondait plus (voir
+/// [`crate::ui::watchdog`])
+pub fn log_stall(game_id: &str, slowest: Duration) -> Option<PathBuf> {
+    append_to_log(&format!(
+        "{game_id} stopped responding: ticks took up to {} ms",
+        slowest.as_millis()
+    ))
+}
+
+/// Ajoute une entrée datée au fichier `crash.log` de la config
+fn append_to_log(entry: &str) -> Option<PathBuf> {
+    let path = storage::data_dir()?.join("crash.log");
+    std::fs::create_dir_all(path.parent()?).ok()?;
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .ok()?;
+    writeln!(
+        file,
+        "[{}] v{} {entry}\n",
+        chrono::Utc::now().format("%Y-%m-%d %H:%M:%S"),
+        env!("CARGO_PKG_VERSION"),
+    )
+    .ok()?;
+
+    Some(path)
+}
+
+/// Écran affiché après un crash, avant de revenir au menu
+pub fn draw_crash_screen(frame: &mut Frame, game_name: &str, report: &CrashReport) {
+    let mut details = vec![
+        Line::from(""),
+        Line::from(report.message.clone().white().bold()),
+    ];
+    if let Some(location) = &report.location {
+        details.push(Line::from(format!("at {location}").gray()));
+    }
+    details.push(Line::from(""));
+    match &report.log_path {
+        Some(path) => details.push(Line::from(
+            format!("Backtrace saved to {}", path.display()).dark_gray(),
+        )),
+        None => details.push(Line::from("Backtrace could not be saved".dark_gray())),
+    }
+
+    draw_error_screen(frame, &format!("💥 Sorry, {game_name} crashed"), details);
+}
+
+/// Écran affiché quand un fichier de données illisible empêche de démarrer
+pub fn draw_load_error_screen(frame: &mut Frame, error: &str) {
+    let details = vec![
+        Line::from(""),
+        Line::from(error.to_string().white().bold()),
+        Line::from(""),
+        Line::from("Fix the file, or delete it to start again from the defaults.".gray()),
+    ];
+
+    draw_error_screen(frame, "⚠️ TermPlay could not read its data", details);
+}
+
+fn draw_error_screen(frame: &mut Frame, title: &str, details: Vec<Line>) {
+    let area = frame.area();
+
+    style::fill_background(frame, style::background());
+
+    let chunks = Layout::vertical([
+        Constraint::Length(4),
+        Constraint::Min(0),
+        Constraint::Length(3),
+    ])
+    .split(area);
+
+    let header = Paragraph::new(vec![
+        Line::from(""),
+        Line::from(title.to_string().red().bold()),
+    ])
+    .alignment(Alignment::Center)
+    .block(
+        Block::bordered()
+            .border_style(Style::new().red())
+            .style(Style::default().bg(Color::Rgb(25, 35, 45))),
+    );
+    frame.render_widget(header, chunks[0]);
+
+    let body = Paragraph::new(details)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true })
+        .block(
+            Block::bordered()
+                .title(" Error ".red().bold())
+                .border_style(Style::new().red())
+                .style(Style::default().bg(Color::Rgb(10, 15, 20))),
+        );
+    frame.render_widget(body, chunks[1]);
+
+    let footer = Paragraph::new(Line::from(vec![
+        "Any key".green().bold(),
+        " Continue".white(),
+    ]))
+    .alignment(Alignment::Center)
+    .block(
+        Block::bordered()
+            .title(" Controls ".white().bold())
+            .border_style(Style::new().blue())
+            .style(Style::default().bg(Color::Rgb(25, 35, 45))),
+    );
+    frame.render_widget(footer, chunks[2]);
+}
@@ -0,0 +1,150 @@
+//! Événements clavier, souris et terminal reçus par les jeux et les menus.
+//!
+//! Dans le terminal, ce sont ceux de crossterm. Sans la feature `native`
+//! (build WebAssembly), crossterm ne se compile pas : ce module reprend alors
+//! les types dont TermPlay se sert, avec les mêmes noms, les mêmes champs et
+//! les mêmes valeurs de modificateurs, pour que les jeux et les replays
+//! restent identiques d'un build à l'autre.
+
+#[cfg(feature = "native")]
+pub use crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers, MouseButton, MouseEvent,
+    MouseEventKind,
+};
+
+#[cfg(not(feature = "native"))]
+pub use portable::*;
+
+#[cfg(not(feature = "native"))]
+mod portable {
+    bitflags::bitflags! {
+        /// Touches de modification ; mêmes bits que crossterm
+        #[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
+        pub struct KeyModifiers: u8 {
+            const SHIFT = 0b0000_0001;
+            const CONTROL = 0b0000_0010;
+            const ALT = 0b0000_0100;
+            const SUPER = 0b0000_1000;
+            const HYPER = 0b0001_0000;
+            const META = 0b0010_0000;
+            const NONE = 0b0000_0000;
+        }
+    }
+
+    bitflags::bitflags! {
+        /// État du clavier au moment de l'appui
+        #[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
+        pub struct KeyEventState: u8 {
+            const KEYPAD = 0b0000_0001;
+            const CAPS_LOCK = 0b0000_0010;
+            const NUM_LOCK = 0b0000_0100;
+            const NONE = 0b0000_0000;
+        }
+    }
+
+    #[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Hash)]
+    pub enum KeyEventKind {
+        Press,
+        Repeat,
+        Release,
+    }
+
+    #[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
+    pub enum KeyCode {
+        Backspace,
+        Enter,
+        Left,
+        Right,
+        Up,
+        Down,
+        Home,
+        End,
+        PageUp,
+        PageDown,
+        Tab,
+        BackTab,
+        Delete,
+        Insert,
+        F(u8),
+        Char(char),
+        Null,
+        Esc,
+        CapsLock,
+        ScrollLock,
+        NumLock,
+        PrintScreen,
+        Pause,
+        Menu,
+        KeypadBegin,
+    }
+
+    #[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
+    pub struct KeyEvent {
+        pub code: KeyCode,
+        pub modifiers: KeyModifiers,
+        pub kind: KeyEventKind,
+        pub state: KeyEventState,
+    }
+
+    impl KeyEvent {
+        pub const fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+            Self::new_with_kind(code, modifiers, KeyEventKind::Press)
+        }
+
+        pub const fn new_with_kind(
+            code: KeyCode,
+            modifiers: KeyModifiers,
+            kind: KeyEventKind,
+        ) -> Self {
+            Self {
+                code,
+                modifiers,
+                kind,
+                state: KeyEventState::empty(),
+            }
+        }
+    }
+
+    impl From<KeyCode> for KeyEvent {
+        fn from(code: KeyCode) -> Self {
+            Self::new(code, KeyModifiers::empty())
+        }
+    }
+
+    #[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
+    pub enum MouseButton {
+        Left,
+        Right,
+        Middle,
+    }
+
+    #[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
+    pub enum MouseEventKind {
+        Down(MouseButton),
+        Up(MouseButton),
+        Drag(MouseButton),
+        Moved,
+        ScrollDown,
+        ScrollUp,
+        ScrollLeft,
+        ScrollRight,
+    }
+
+    #[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
+    pub struct MouseEvent {
+        pub kind: MouseEventKind,
+        pub column: u16,
+        pub row: u16,
+        pub modifiers: KeyModifiers,
+    }
+
+    #[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Hash)]
+    pub enum Event {
+        FocusGained,
+        FocusLost,
+        Key(KeyEvent),
+        Mouse(MouseEvent),
+        Paste(String),
+        Resize(u16, u16),
+    }
+}
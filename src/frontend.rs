@@ -0,0 +1,357 @@
+//! Frontends : d'où viennent les événements et où s'affichent les écrans.
+//!
+//! L'application ne connaît que le trait [`Frontend`] : un terminal ratatui
+//! sur lequel dessiner, une source d'événements et un titre de fenêtre. Les
+//! jeux et le menu dessinent toujours dans une `Frame`, quel que soit le
+//! frontend.
+//!
+//! - [`CrosstermFrontend`] prend le contrôle du vrai terminal (mode raw,
+//!   écran alternatif, souris) et le restaure avec [`CrosstermFrontend::restore`].
+//!   Ses couleurs passent par [`PaletteBackend`], qui les adapte aux
+//!   terminaux sans truecolor.
+//! - [`StreamFrontend`] dessine pour un terminal distant relié par un flux
+//!   d'octets, comme le canal d'une connexion SSH : une session par
+//!   connexion, celles du serveur de `termplay serve --ssh` (feature `ssh`).
+//! - [`TestFrontend`] dessine dans un tampon en mémoire et rejoue une liste
+//!   d'événements, pour piloter une partie sans terminal.
+
+use crate::event::Event;
+use crate::ui::palette;
+#[cfg(feature = "native")]
+use crate::{config::PlatformProfile, input, shutdown, ui::TerminalTitle};
+#[cfg(feature = "native")]
+use crossterm::{
+    event::{
+        self, DisableBracketedPaste, DisableFocusChange, DisableMouseCapture, EnableBracketedPaste,
+        EnableFocusChange, EnableMouseCapture,
+    },
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+#[cfg(feature = "native")]
+use ratatui::{backend::CrosstermBackend, layout::Rect, TerminalOptions, Viewport};
+use ratatui::{
+    backend::{Backend, ClearType, TestBackend, WindowSize},
+    buffer::Cell,
+    layout::{Position, Size},
+    Terminal,
+};
+use std::collections::VecDeque;
+#[cfg(feature = "native")]
+use std::io::Stdout;
+use std::io::{self, Write};
+#[cfg(feature = "native")]
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::time::Duration;
+
+/// Affichage et source d'événements de l'application
+pub trait Frontend {
+    type Backend: Backend;
+
+    /// Terminal sur lequel dessiner
+    fn terminal(&mut self) -> &mut Terminal<Self::Backend>;
+
+    /// Attend un événement pendant au plus `timeout` ; `None` si aucun n'est
+    /// arrivé
+    fn poll_event(&mut self, timeout: Duration) -> io::Result<Option<Event>>;
+
+    /// Change le titre de la fenêtre, pour les frontends qui en ont un
+    fn set_title(&mut self, _title: &str) {}
+}
+
+/// Le terminal de l'utilisateur, via crossterm
+#[cfg(feature = "native")]
+pub struct CrosstermFrontend {
+    terminal: Terminal<PaletteBackend<CrosstermBackend<Stdout>>>,
+    title: Option<TerminalTitle>,
+}
+
+#[cfg(feature = "native")]
+impl CrosstermFrontend {
+    /// Passe le terminal en mode raw sur l'écran alternatif, avec la souris,
+    /// le focus et le collage ; `title` active la mise à jour du titre
+    pub fn new(title: bool) -> Result<Self, Box<dyn std::error::Error>> {
+        enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        execute!(
+            stdout,
+            EnterAlternateScreen,
+            EnableMouseCapture,
+            EnableFocusChange,
+            EnableBracketedPaste
+        )?;
+        let terminal = Terminal::new(PaletteBackend(CrosstermBackend::new(stdout)))?;
+
+        // Installer un hook de panic pour nettoyer le terminal
+        let original_hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |panic_info| {
+            let _ = disable_raw_mode();
+            let _ = execute!(
+                io::stdout(),
+                LeaveAlternateScreen,
+                DisableMouseCapture,
+                DisableFocusChange,
+                DisableBracketedPaste
+            );
+            let _ = io::stdout().flush();
+            original_hook(panic_info);
+        }));
+
+        Ok(Self {
+            terminal,
+            title: Some(TerminalTitle::new(title)),
+        })
+    }
+
+    /// Rend le terminal dans l'état où il a été trouvé
+    pub fn restore(&mut self) -> io::Result<()> {
+        // Restaurer le titre et le hook de panic original
+        self.title = None;
+        let _ = std::panic::take_hook();
+
+        // Forcer l'affichage du curseur avant tout
+        let _ = self.terminal.show_cursor();
+
+        // Désactiver le mode raw
+        let _ = disable_raw_mode();
+
+        // Nettoyer l'écran et restaurer le terminal
+        let _ = execute!(
+            self.terminal.backend_mut().0,
+            crossterm::terminal::Clear(crossterm::terminal::ClearType::All),
+            LeaveAlternateScreen,
+            DisableMouseCapture,
+            DisableFocusChange,
+            DisableBracketedPaste
+        );
+
+        // Forcer un flush final
+        let _ = io::stdout().flush();
+
+        // Petite pause pour s'assurer que tout est nettoyé
+        std::thread::sleep(Duration::from_millis(50));
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "native")]
+impl Frontend for CrosstermFrontend {
+    type Backend = PaletteBackend<CrosstermBackend<Stdout>>;
+
+    fn terminal(&mut self) -> &mut Terminal<Self::Backend> {
+        &mut self.terminal
+    }
+
+    /// Les événements passent par [`input::normalize`] ; un événement ignoré
+    /// compte comme une attente sans événement
+    fn poll_event(&mut self, timeout: Duration) -> io::Result<Option<Event>> {
+        if !event::poll(timeout)? {
+            return Ok(None);
+        }
+        let profile = input::platform_profile();
+        let event = input::normalize(event::read()?, profile);
+        if matches!(event, Some(Event::Resize(..))) && profile == PlatformProfile::Windows {
+            // La console garde des restes de l'ancien écran
+            self.terminal.clear()?;
+        }
+        Ok(event)
+    }
+
+    fn set_title(&mut self, title: &str) {
+        if let Some(terminal_title) = &mut self.title {
+            terminal_title.set(title);
+        }
+    }
+}
+
+/// Terminal distant : l'écran est écrit en séquences ANSI sur `output`, les
+/// touches arrivent par le [`StreamInput`] rendu avec le frontend. Chaque
+/// connexion a son frontend et sa propre [`App`](crate::App), de préférence
+/// muette ([`GameRegistry::silent`](crate::GameRegistry::silent)) : le son
+/// sortirait sur la machine qui héberge le serveur.
+///
+/// Ctrl+C et la fermeture de la connexion ne terminent que cette session :
+/// `poll_event` rend alors une erreur `ConnectionAborted`, sans demander
+/// l'arrêt des autres sessions
+#[cfg(feature = "native")]
+pub struct StreamFrontend<W: Write> {
+    terminal: Terminal<CrosstermBackend<W>>,
+    events: Receiver<Event>,
+}
+
+/// Côté connexion d'un [`StreamFrontend`], à alimenter depuis le thread qui
+/// lit le client
+#[cfg(feature = "native")]
+#[derive(Clone)]
+pub struct StreamInput {
+    events: Sender<Event>,
+}
+
+#[cfg(feature = "native")]
+impl StreamInput {
+    /// Octets tapés par le client ; rend faux quand la session est terminée
+    pub fn data(&self, data: &str) -> bool {
+        input::decode_keys(data)
+            .into_iter()
+            .all(|key| self.events.send(Event::Key(key)).is_ok())
+    }
+
+    /// Nouvelle taille de la fenêtre du client
+    pub fn resize(&self, cols: u16, rows: u16) -> bool {
+        self.events.send(Event::Resize(cols, rows)).is_ok()
+    }
+}
+
+#[cfg(feature = "native")]
+impl<W: Write> StreamFrontend<W> {
+    /// Session de `cols`×`rows` sur l'écran alternatif du client
+    pub fn new(mut output: W, cols: u16, rows: u16) -> io::Result<(Self, StreamInput)> {
+        execute!(output, EnterAlternateScreen)?;
+        let terminal = Terminal::with_options(
+            CrosstermBackend::new(output),
+            TerminalOptions {
+                viewport: Viewport::Fixed(Rect::new(0, 0, cols, rows)),
+            },
+        )?;
+        let (sender, events) = mpsc::channel();
+        Ok((Self { terminal, events }, StreamInput { events: sender }))
+    }
+}
+
+#[cfg(feature = "native")]
+impl<W: Write> Drop for StreamFrontend<W> {
+    fn drop(&mut self) {
+        let _ = self.terminal.show_cursor();
+        let _ = execute!(self.terminal.backend_mut(), LeaveAlternateScreen);
+    }
+}
+
+#[cfg(feature = "native")]
+impl<W: Write> Frontend for StreamFrontend<W> {
+    type Backend = CrosstermBackend<W>;
+
+    fn terminal(&mut self) -> &mut Terminal<Self::Backend> {
+        &mut self.terminal
+    }
+
+    fn poll_event(&mut self, timeout: Duration) -> io::Result<Option<Event>> {
+        let closed = || io::Error::new(io::ErrorKind::ConnectionAborted, "session closed");
+        match self.events.recv_timeout(timeout) {
+            Ok(Event::Key(key)) if shutdown::is_interrupt(&key) => Err(closed()),
+            Ok(Event::Resize(cols, rows)) => {
+                self.terminal.resize(Rect::new(0, 0, cols, rows))?;
+                Ok(Some(Event::Resize(cols, rows)))
+            }
+            Ok(event) => Ok(Some(event)),
+            Err(RecvTimeoutError::Timeout) => Ok(None),
+            Err(RecvTimeoutError::Disconnected) => Err(closed()),
+        }
+    }
+}
+
+/// Backend qui ramène les couleurs RGB à la palette du terminal
+/// ([`palette::adapt`]) avant de les écrire
+pub struct PaletteBackend<B>(pub B);
+
+impl<B: Backend> Backend for PaletteBackend<B> {
+    fn draw<'a, I>(&mut self, content: I) -> io::Result<()>
+    where
+        I: Iterator<Item = (u16, u16, &'a Cell)>,
+    {
+        let cells: Vec<(u16, u16, Cell)> = content
+            .map(|(x, y, cell)| {
+                let mut cell = cell.clone();
+                cell.fg = palette::adapt(cell.fg);
+                cell.bg = palette::adapt(cell.bg);
+                (x, y, cell)
+            })
+            .collect();
+        self.0.draw(cells.iter().map(|(x, y, cell)| (*x, *y, cell)))
+    }
+
+    fn append_lines(&mut self, n: u16) -> io::Result<()> {
+        self.0.append_lines(n)
+    }
+
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        self.0.hide_cursor()
+    }
+
+    fn show_cursor(&mut self) -> io::Result<()> {
+        self.0.show_cursor()
+    }
+
+    fn get_cursor_position(&mut self) -> io::Result<Position> {
+        self.0.get_cursor_position()
+    }
+
+    fn set_cursor_position<P: Into<Position>>(&mut self, position: P) -> io::Result<()> {
+        self.0.set_cursor_position(position)
+    }
+
+    fn clear(&mut self) -> io::Result<()> {
+        self.0.clear()
+    }
+
+    fn clear_region(&mut self, clear_type: ClearType) -> io::Result<()> {
+        self.0.clear_region(clear_type)
+    }
+
+    fn size(&self) -> io::Result<Size> {
+        self.0.size()
+    }
+
+    fn window_size(&mut self) -> io::Result<WindowSize> {
+        self.0.window_size()
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.0.flush()
+    }
+}
+
+/// Terminal en mémoire qui rejoue des événements préparés à l'avance
+pub struct TestFrontend {
+    terminal: Terminal<TestBackend>,
+    events: VecDeque<Event>,
+}
+
+impl TestFrontend {
+    pub fn new(width: u16, height: u16) -> io::Result<Self> {
+        Ok(Self {
+            terminal: Terminal::new(TestBackend::new(width, height))?,
+            events: VecDeque::new(),
+        })
+    }
+
+    /// Ajoute des événements à rejouer, dans l'ordre
+    pub fn push_events(&mut self, events: impl IntoIterator<Item = Event>) {
+        self.events.extend(events);
+    }
+
+    /// Dernier écran dessiné
+    pub fn buffer(&self) -> &ratatui::buffer::Buffer {
+        self.terminal.backend().buffer()
+    }
+}
+
+impl Frontend for TestFrontend {
+    type Backend = TestBackend;
+
+    fn terminal(&mut self) -> &mut Terminal<Self::Backend> {
+        &mut self.terminal
+    }
+
+    /// Un événement préparé par appel ; une fois la liste épuisée, laisse
+    /// passer le temps comme un terminal où personne ne tape
+    fn poll_event(&mut self, timeout: Duration) -> io::Result<Option<Event>> {
+        match self.events.pop_front() {
+            Some(event) => Ok(Some(event)),
+            None => {
+                std::thread::sleep(timeout);
+                Ok(None)
+            }
+        }
+    }
+}
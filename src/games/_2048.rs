@@ -1,19 +1,40 @@
 use crate::audio::{AudioManager, SoundEffect};
-use crate::core::{Game, GameAction};
-use crate::highscores::{GameData, HighScoreManager, Score};
-use crossterm::event::{KeyCode, KeyEvent};
-use rand::Rng;
+use crate::core::bot::{expectimax, ChanceGame};
+use crate::core::clock::GameClock;
+use crate::core::grid::Grid2D;
+use crate::core::share::{self, ShareCode, SharedGame};
+use crate::core::{Game, GameAction, GameStatus};
+use crate::event::{KeyCode, KeyEvent};
+use crate::highscores::{current_player_name, GameData, HighScoreManager, Score};
+use crate::music::stingers;
+use crate::puzzles::{self, Puzzle2048};
+use crate::ui::{bigtext, glyphs, numbers, preview, style};
+use rand::{rngs::StdRng, Rng, SeedableRng};
 use ratatui::{
     layout::{Constraint, Layout, Margin, Rect},
     style::{Color, Style, Stylize},
     text::Line,
     widgets::{Block, Clear, Paragraph},
 };
+use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
 // Taille de la grille 2048
 const GRID_SIZE: usize = 4;
 
+/// Délais entre deux coups du bot en autoplay, du plus lent au plus rapide
+const AUTOPLAY_SPEEDS_MS: [u64; 5] = [1000, 500, 250, 120, 50];
+const AUTOPLAY_DEFAULT_SPEED: usize = 2;
+/// Pause sur l'écran de fin avant que le bot relance une partie
+const AUTOPLAY_RESTART_DELAY_MS: u64 = 3000;
+/// En dessous de ce nombre de cases vides, le bot cherche un coup plus loin
+const AUTOPLAY_DEEP_SEARCH_EMPTY: usize = 4;
+
+/// Exposant du poids de chaque case pour le bot : les grosses tuiles sont
+/// rangées en serpentin depuis le coin haut gauche
+const SNAKE_WEIGHTS: [[i32; GRID_SIZE]; GRID_SIZE] =
+    [[15, 14, 13, 12], [8, 9, 10, 11], [7, 6, 5, 4], [0, 1, 2, 3]];
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Direction {
     Up,
@@ -22,48 +43,286 @@ pub enum Direction {
     Right,
 }
 
+impl Direction {
+    /// Dans l'ordre des évaluations du bot
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    fn arrow(self) -> &'static str {
+        match self {
+            Direction::Up => "▲",
+            Direction::Down => "▼",
+            Direction::Left => "◀",
+            Direction::Right => "▶",
+        }
+    }
+}
+
+/// Résultat d'un coup sur une grille, sans effet sur la partie
+struct Slide {
+    grid: Grid2D<u32>,
+    /// Somme des tuiles créées par les fusions
+    points: u32,
+    moved: bool,
+    /// Une fusion a créé une tuile 2048
+    reached_2048: bool,
+}
+
+/// Fait glisser les tuiles de `grid` vers `direction`, chaque tuile ne
+/// fusionnant qu'une fois par coup
+fn slide(grid: &Grid2D<u32>, direction: Direction) -> Slide {
+    let mut result = Slide {
+        grid: grid.clone(),
+        points: 0,
+        moved: false,
+        reached_2048: false,
+    };
+
+    for line in 0..GRID_SIZE {
+        // Cases de la ligne, depuis le bord vers lequel les tuiles glissent
+        let cells: [(usize, usize); GRID_SIZE] = std::array::from_fn(|i| {
+            let back = GRID_SIZE - 1 - i;
+            match direction {
+                Direction::Left => (i, line),
+                Direction::Right => (back, line),
+                Direction::Up => (line, i),
+                Direction::Down => (line, back),
+            }
+        });
+        let tiles: Vec<u32> = cells
+            .iter()
+            .map(|&cell| grid[cell])
+            .filter(|&value| value != 0)
+            .collect();
+
+        // Fusionner les tuiles adjacentes identiques
+        let mut merged = Vec::with_capacity(GRID_SIZE);
+        let mut i = 0;
+        while i < tiles.len() {
+            if i + 1 < tiles.len() && tiles[i] == tiles[i + 1] {
+                let value = tiles[i] * 2;
+                result.points += value;
+                result.reached_2048 |= value == 2048;
+                merged.push(value);
+                i += 2;
+            } else {
+                merged.push(tiles[i]);
+                i += 1;
+            }
+        }
+        merged.resize(GRID_SIZE, 0);
+
+        for (&cell, value) in cells.iter().zip(merged) {
+            if result.grid[cell] != value {
+                result.moved = true;
+                result.grid[cell] = value;
+            }
+        }
+    }
+
+    result
+}
+
+/// Position explorée par le bot de l'autoplay
+struct BotBoard(Grid2D<u32>);
+
+impl ChanceGame for BotBoard {
+    type Move = Direction;
+
+    fn moves(&self) -> Vec<(Direction, BotBoard)> {
+        Direction::ALL
+            .into_iter()
+            .filter_map(|direction| {
+                let slide = slide(&self.0, direction);
+                slide.moved.then_some((direction, BotBoard(slide.grid)))
+            })
+            .collect()
+    }
+
+    fn outcomes(&self) -> Vec<(f32, BotBoard)> {
+        let empty: Vec<usize> = (0..GRID_SIZE * GRID_SIZE)
+            .filter(|&index| self.0.as_slice()[index] == 0)
+            .collect();
+        let share = 1.0 / empty.len() as f32;
+
+        // Même tirage que `add_random_tile` : 2 à 90 %, 4 à 10 %
+        let mut outcomes = Vec::with_capacity(empty.len() * 2);
+        for index in empty {
+            for (value, probability) in [(2, 0.9), (4, 0.1)] {
+                let mut grid = self.0.clone();
+                grid.as_mut_slice()[index] = value;
+                outcomes.push((probability * share, BotBoard(grid)));
+            }
+        }
+        outcomes
+    }
+
+    fn evaluate(&self) -> f32 {
+        self.0
+            .rows()
+            .zip(SNAKE_WEIGHTS)
+            .flat_map(|(row, weights)| row.iter().zip(weights))
+            .map(|(&value, weight)| value as f32 * 2f32.powi(weight))
+            .sum()
+    }
+}
+
+/// Valeur de chaque coup pour le bot, dans l'ordre de `Direction::ALL` ;
+/// `None` pour les coups qui ne changent rien
+fn evaluate_moves(grid: &Grid2D<u32>) -> [Option<f32>; 4] {
+    let empty = grid.iter().filter(|&&value| value == 0).count();
+    let depth = if empty <= AUTOPLAY_DEEP_SEARCH_EMPTY {
+        3
+    } else {
+        2
+    };
+
+    let mut evaluations = [None; 4];
+    for (direction, value) in expectimax(&BotBoard(grid.clone()), depth) {
+        evaluations[direction as usize] = Some(value);
+    }
+    evaluations
+}
+
+/// Mode autoplay : le bot expectimax joue seul, à la vitesse choisie
+struct Autoplay {
+    /// Index dans `AUTOPLAY_SPEEDS_MS`
+    speed: usize,
+    paused: bool,
+    show_heatmap: bool,
+    /// Évaluations du bot pour la grille affichée, jouées au prochain tick
+    evaluations: [Option<f32>; 4],
+    last_move: Option<Direction>,
+    /// Ticks passés sur l'écran de fin de partie
+    game_over_ticks: u64,
+}
+
+impl Autoplay {
+    fn new(grid: &Grid2D<u32>) -> Self {
+        Self {
+            speed: AUTOPLAY_DEFAULT_SPEED,
+            paused: false,
+            show_heatmap: true,
+            evaluations: evaluate_moves(grid),
+            last_move: None,
+            game_over_ticks: 0,
+        }
+    }
+
+    fn delay_ms(&self) -> u64 {
+        AUTOPLAY_SPEEDS_MS[self.speed]
+    }
+
+    /// Coup le mieux évalué
+    fn best_move(&self) -> Option<Direction> {
+        Direction::ALL
+            .into_iter()
+            .zip(self.evaluations)
+            .filter_map(|(direction, value)| Some((direction, value?)))
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(direction, _)| direction)
+    }
+
+    /// Évaluations ramenées entre 0.0 (pire coup jouable) et 1.0 (meilleur)
+    fn heat(&self) -> [Option<f32>; 4] {
+        let values = self.evaluations.iter().flatten();
+        let min = values.clone().copied().fold(f32::INFINITY, f32::min);
+        let max = values.copied().fold(0.0, f32::max);
+        self.evaluations.map(|value| {
+            value.map(|value| {
+                if max > min {
+                    (value - min) / (max - min)
+                } else {
+                    1.0
+                }
+            })
+        })
+    }
+}
+
 pub struct Game2048 {
-    grid: [[u32; GRID_SIZE]; GRID_SIZE],
+    grid: Grid2D<u32>,
     score: u32,
     best_score: u32,
     game_over: bool,
     won: bool,
-    moved: bool, // Pour savoir si le dernier mouvement a changé quelque chose
+    endless: bool, // Le joueur a choisi de continuer après 2048
+    moved: bool,   // Pour savoir si le dernier mouvement a changé quelque chose
+    moves: u32,
 
     // Audio
     audio: AudioManager,
-    music_started: bool,
 
     // High scores
     highscore_manager: HighScoreManager,
-    start_time: std::time::Instant,
+    clock: GameClock,
     score_saved: bool,
+
+    // Puzzle en cours, `None` en partie classique
+    puzzle: Option<PuzzleRun>,
+
+    // Le bot joue seul, `None` en partie normale
+    autoplay: Option<Autoplay>,
+
+    // Graine des nouvelles tuiles, pour le code de partage
+    seed: u64,
+    rng: StdRng,
+}
+
+struct PuzzleRun {
+    puzzle: Puzzle2048,
+    solved: bool,
+}
+
+/// Partie classique interrompue, pour la reprendre (voir [`crate::saves`])
+#[derive(Serialize, Deserialize)]
+struct SavedGame {
+    grid: Grid2D<u32>,
+    score: u32,
+    won: bool,
+    endless: bool,
+    moves: u32,
+    seed: u64,
+    elapsed_ms: u64,
 }
 
 impl Game2048 {
-    pub fn new() -> Self {
+    pub fn new(audio: AudioManager) -> Self {
         let highscore_manager = HighScoreManager::default();
 
         // Charger le meilleur score depuis le fichier de high scores
         let best_score = highscore_manager
-            .get_best_score("2048")
+            .get_best_score("2048", false)
             .map(|score| score.score)
             .unwrap_or(0);
 
+        let seed = share::new_seed();
         let mut game = Self {
-            grid: [[0; GRID_SIZE]; GRID_SIZE],
+            grid: Grid2D::new(GRID_SIZE, GRID_SIZE, 0),
             score: 0,
             best_score,
             game_over: false,
             won: false,
+            endless: false,
             moved: false,
+            moves: 0,
 
-            audio: AudioManager::default(),
-            music_started: false,
+            audio,
 
             highscore_manager,
-            start_time: std::time::Instant::now(),
+            clock: GameClock::new(),
             score_saved: false,
+
+            puzzle: None,
+
+            autoplay: None,
+
+            seed,
+            rng: StdRng::seed_from_u64(seed),
         };
 
         // Ajouter deux tuiles au début
@@ -73,46 +332,133 @@ impl Game2048 {
         game
     }
 
+    /// Partie de puzzle : grille imposée, sans nouvelles tuiles entre les coups
+    pub fn from_puzzle(puzzle: Puzzle2048, audio: AudioManager) -> Self {
+        let mut game = Self::new(audio);
+        game.puzzle = Some(PuzzleRun {
+            puzzle,
+            solved: false,
+        });
+        game.restart();
+        game
+    }
+
+    /// Autoplay : le bot expectimax joue seul et affiche l'évaluation de
+    /// chaque coup
+    pub fn autoplay(audio: AudioManager) -> Self {
+        let mut game = Self::new(audio);
+        game.autoplay = Some(Autoplay::new(&game.grid));
+        game
+    }
+
+    /// Un coup du bot, ou une nouvelle partie après la fin de la précédente
+    fn update_autoplay(&mut self) {
+        let Some(autoplay) = &mut self.autoplay else {
+            return;
+        };
+        if autoplay.paused {
+            return;
+        }
+
+        if self.game_over {
+            autoplay.game_over_ticks += 1;
+            if autoplay.game_over_ticks * autoplay.delay_ms() >= AUTOPLAY_RESTART_DELAY_MS {
+                self.audio.clear_effects();
+                self.audio.stop_music();
+                self.restart();
+            }
+            return;
+        }
+
+        let Some(direction) = autoplay.best_move() else {
+            return;
+        };
+        autoplay.last_move = Some(direction);
+        self.move_tiles(direction);
+        if self.won {
+            // Le bot continue au-delà de 2048 sans attendre le joueur
+            self.endless = true;
+            self.clock.resume();
+        }
+        if let Some(autoplay) = &mut self.autoplay {
+            autoplay.evaluations = evaluate_moves(&self.grid);
+        }
+    }
+
+    /// Charge la grille de départ du puzzle
+    fn load_puzzle_board(&mut self) {
+        let Some(run) = &self.puzzle else {
+            return;
+        };
+
+        self.grid = Grid2D::new(GRID_SIZE, GRID_SIZE, 0);
+        for (row, values) in run.puzzle.board.iter().take(GRID_SIZE).enumerate() {
+            for (col, &value) in values.iter().take(GRID_SIZE).enumerate() {
+                self.grid.set(col, row, value);
+            }
+        }
+    }
+
+    /// Vérifie l'objectif du puzzle après un coup
+    fn check_puzzle(&mut self) {
+        let highest_tile = self.grid.iter().copied().max().unwrap_or(0);
+        let stuck = !self.can_move();
+        let Some(run) = &mut self.puzzle else {
+            return;
+        };
+
+        if highest_tile >= run.puzzle.target_tile {
+            run.solved = true;
+            self.game_over = true;
+            self.clock.pause();
+            if let Err(e) = puzzles::mark_solved(&run.puzzle.id) {
+                eprintln!("Erreur lors de la sauvegarde de la progression des puzzles: {e}");
+            }
+        } else if self.moves >= run.puzzle.max_moves || stuck {
+            self.game_over = true;
+            self.clock.pause();
+            self.audio.play_stinger(
+                &stingers::GAME2048_GAME_OVER,
+                Some(SoundEffect::Game2048GameOver),
+            );
+        }
+    }
+
     fn add_random_tile(&mut self) {
         let empty_cells: Vec<(usize, usize)> = (0..GRID_SIZE)
             .flat_map(|row| (0..GRID_SIZE).map(move |col| (row, col)))
-            .filter(|&(r, c)| self.grid[r][c] == 0)
+            .filter(|&(row, col)| self.grid[(col, row)] == 0)
             .collect();
 
         if empty_cells.is_empty() {
             return;
         }
 
-        let mut rng = rand::rng();
-        let &(row, col) = empty_cells.choose(&mut rng).unwrap();
+        let &(row, col) = empty_cells.choose(&mut self.rng).unwrap();
 
         // 90% chance pour 2, 10% chance pour 4
-        let value = if rng.random_bool(0.9) { 2 } else { 4 };
-        self.grid[row][col] = value;
+        let value = if self.rng.random_bool(0.9) { 2 } else { 4 };
+        self.grid.set(col, row, value);
     }
 
     fn can_move(&self) -> bool {
         // Vérifier s'il y a des cellules vides
-        for row in 0..GRID_SIZE {
-            for col in 0..GRID_SIZE {
-                if self.grid[row][col] == 0 {
-                    return true;
-                }
-            }
+        if self.grid.iter().any(|&value| value == 0) {
+            return true;
         }
 
         // Vérifier s'il y a des fusions possibles
         for row in 0..GRID_SIZE {
             for col in 0..GRID_SIZE {
-                let current = self.grid[row][col];
+                let current = self.grid[(col, row)];
 
                 // Vérifier à droite
-                if col < GRID_SIZE - 1 && self.grid[row][col + 1] == current {
+                if col < GRID_SIZE - 1 && self.grid[(col + 1, row)] == current {
                     return true;
                 }
 
                 // Vérifier en bas
-                if row < GRID_SIZE - 1 && self.grid[row + 1][col] == current {
+                if row < GRID_SIZE - 1 && self.grid[(col, row + 1)] == current {
                     return true;
                 }
             }
@@ -122,250 +468,63 @@ impl Game2048 {
     }
 
     fn start_music_if_needed(&mut self) {
-        if !self.music_started && self.audio.is_music_enabled() && !self.game_over {
-            // Choisir la version selon le score actuel
-            if self.score >= 10000 {
-                self.audio.play_2048_music_fast(); // Version énergique pour scores élevés
-            } else {
-                self.audio.play_2048_music(); // Version zen normale
-            }
-            self.music_started = true;
-        }
-
-        // Relancer la musique si elle est finie
-        if self.music_started
-            && self.audio.is_music_enabled()
-            && !self.game_over
-            && self.audio.is_music_empty()
-        {
-            // Choisir la version appropriée selon le score actuel
-            if self.score >= 10000 {
-                self.audio.play_2048_music_fast();
-            } else {
-                self.audio.play_2048_music();
-            }
+        if !self.audio.is_music_enabled() {
+            return;
         }
-    }
-
-    fn move_tiles(&mut self, direction: Direction) {
-        self.moved = false;
-        let mut new_grid = self.grid;
-
-        match direction {
-            Direction::Left => {
-                for (_row, grid_row) in new_grid.iter_mut().enumerate().take(GRID_SIZE) {
-                    let mut line: Vec<u32> =
-                        grid_row.iter().filter(|&&x| x != 0).cloned().collect();
-
-                    // Fusionner les tuiles adjacentes identiques
-                    let mut merged_line = Vec::new();
-                    let mut i = 0;
-                    while i < line.len() {
-                        if i + 1 < line.len() && line[i] == line[i + 1] {
-                            let merged_value = line[i] * 2;
-                            merged_line.push(merged_value);
-                            self.score += merged_value;
-
-                            // Son de fusion
-                            self.audio.play_sound(SoundEffect::Game2048Merge);
-
-                            if merged_value == 2048 && !self.won {
-                                self.won = true;
-                                // Son de victoire spécial
-                                self.audio.play_sound(SoundEffect::Game2048Victory);
-                                self.audio.stop_music();
-                                self.audio.play_2048_music_celebration();
-                                self.music_started = false;
-
-                                // Sauvegarder le score si c'est un high score
-                                self.save_high_score_if_needed();
-                            }
-                            i += 2; // Skip both tiles
-                        } else {
-                            merged_line.push(line[i]);
-                            i += 1;
-                        }
-                    }
-                    line = merged_line;
-
-                    // Remplir avec des zéros
-                    line.resize(GRID_SIZE, 0);
-
-                    // Vérifier si quelque chose a changé
-                    let new_row: [u32; GRID_SIZE] = line.as_slice().try_into().unwrap();
-                    if *grid_row != new_row {
-                        self.moved = true;
-                    }
-
-                    *grid_row = new_row;
-                }
-            }
-            Direction::Right => {
-                for (_row, grid_row) in new_grid.iter_mut().enumerate().take(GRID_SIZE) {
-                    let mut line: Vec<u32> =
-                        grid_row.iter().filter(|&&x| x != 0).cloned().collect();
-                    line.reverse();
-
-                    // Fusionner les tuiles adjacentes identiques
-                    let mut merged_line = Vec::new();
-                    let mut i = 0;
-                    while i < line.len() {
-                        if i + 1 < line.len() && line[i] == line[i + 1] {
-                            let merged_value = line[i] * 2;
-                            merged_line.push(merged_value);
-                            self.score += merged_value;
-
-                            // Son de fusion
-                            self.audio.play_sound(SoundEffect::Game2048Merge);
-
-                            if merged_value == 2048 && !self.won {
-                                self.won = true;
-                                // Son de victoire spécial
-                                self.audio.play_sound(SoundEffect::Game2048Victory);
-                                self.audio.stop_music();
-                                self.audio.play_2048_music_celebration();
-                                self.music_started = false;
-
-                                // Sauvegarder le score si c'est un high score
-                                self.save_high_score_if_needed();
-                            }
-                            i += 2; // Skip both tiles
-                        } else {
-                            merged_line.push(line[i]);
-                            i += 1;
-                        }
-                    }
-                    line = merged_line;
-
-                    // Remplir avec des zéros et inverser
-                    line.resize(GRID_SIZE, 0);
-                    line.reverse();
-
-                    // Vérifier si quelque chose a changé
-                    let new_row: [u32; GRID_SIZE] = line.as_slice().try_into().unwrap();
-                    if *grid_row != new_row {
-                        self.moved = true;
-                    }
 
-                    *grid_row = new_row;
-                }
-            }
-            Direction::Up => {
-                #[allow(clippy::needless_range_loop)]
-                for col in 0..GRID_SIZE {
-                    let mut line: Vec<u32> = (0..GRID_SIZE)
-                        .map(|row| new_grid[row][col])
-                        .filter(|&x| x != 0)
-                        .collect();
-
-                    // Fusionner les tuiles adjacentes identiques
-                    let mut merged_line = Vec::new();
-                    let mut i = 0;
-                    while i < line.len() {
-                        if i + 1 < line.len() && line[i] == line[i + 1] {
-                            let merged_value = line[i] * 2;
-                            merged_line.push(merged_value);
-                            self.score += merged_value;
-
-                            // Son de fusion
-                            self.audio.play_sound(SoundEffect::Game2048Merge);
-
-                            if merged_value == 2048 && !self.won {
-                                self.won = true;
-                                // Son de victoire spécial
-                                self.audio.play_sound(SoundEffect::Game2048Victory);
-                                self.audio.stop_music();
-                                self.audio.play_2048_music_celebration();
-                                self.music_started = false;
-
-                                // Sauvegarder le score si c'est un high score
-                                self.save_high_score_if_needed();
-                            }
-                            i += 2; // Skip both tiles
-                        } else {
-                            merged_line.push(line[i]);
-                            i += 1;
-                        }
-                    }
-                    line = merged_line;
+        if self.game_over {
+            // La musique s'arrête à la fin de son tour
+            self.audio.finish_music_loop();
+            return;
+        }
 
-                    // Remplir avec des zéros
-                    line.resize(GRID_SIZE, 0);
+        // Choisir la version selon le score actuel ; après la fanfare de
+        // victoire, la musique reprend au début
+        if self.score >= 10000 {
+            self.audio.play_2048_music_fast(); // Version énergique pour scores élevés
+        } else {
+            self.audio.play_2048_music(); // Version zen normale
+        }
+    }
 
-                    // Vérifier si quelque chose a changé et appliquer
-                    for row in 0..GRID_SIZE {
-                        if new_grid[row][col] != line[row] {
-                            self.moved = true;
-                        }
-                        new_grid[row][col] = line[row];
-                    }
-                }
-            }
-            Direction::Down => {
-                #[allow(clippy::needless_range_loop)]
-                for col in 0..GRID_SIZE {
-                    let mut line: Vec<u32> = (0..GRID_SIZE)
-                        .map(|row| new_grid[row][col])
-                        .filter(|&x| x != 0)
-                        .collect();
-                    line.reverse();
-
-                    // Fusionner les tuiles adjacentes identiques
-                    let mut merged_line = Vec::new();
-                    let mut i = 0;
-                    while i < line.len() {
-                        if i + 1 < line.len() && line[i] == line[i + 1] {
-                            let merged_value = line[i] * 2;
-                            merged_line.push(merged_value);
-                            self.score += merged_value;
-
-                            // Son de fusion
-                            self.audio.play_sound(SoundEffect::Game2048Merge);
-
-                            if merged_value == 2048 && !self.won {
-                                self.won = true;
-                                // Son de victoire spécial
-                                self.audio.play_sound(SoundEffect::Game2048Victory);
-                                self.audio.stop_music();
-                                self.audio.play_2048_music_celebration();
-                                self.music_started = false;
-
-                                // Sauvegarder le score si c'est un high score
-                                self.save_high_score_if_needed();
-                            }
-                            i += 2; // Skip both tiles
-                        } else {
-                            merged_line.push(line[i]);
-                            i += 1;
-                        }
-                    }
-                    line = merged_line;
-
-                    // Remplir avec des zéros et inverser
-                    line.resize(GRID_SIZE, 0);
-                    line.reverse();
-
-                    // Vérifier si quelque chose a changé et appliquer
-                    for row in 0..GRID_SIZE {
-                        if new_grid[row][col] != line[row] {
-                            self.moved = true;
-                        }
-                        new_grid[row][col] = line[row];
-                    }
-                }
-            }
+    fn move_tiles(&mut self, direction: Direction) {
+        let slide = slide(&self.grid, direction);
+        self.moved = slide.moved;
+        self.grid = slide.grid;
+
+        if slide.points > 0 {
+            self.score += slide.points;
+            // Son de fusion
+            self.audio.play_sound(SoundEffect::Game2048Merge);
         }
 
-        self.grid = new_grid;
+        if slide.reached_2048 && !self.won {
+            self.won = true;
+            // Fanfare de victoire ; la musique reprend au début ensuite
+            self.audio.play_stinger(
+                &stingers::GAME2048_VICTORY,
+                Some(SoundEffect::Game2048Victory),
+            );
+            self.clock.pause();
+        }
 
+        // En puzzle, pas de hasard : seul l'objectif est vérifié
+        if self.moved && self.puzzle.is_some() {
+            self.moves += 1;
+            self.check_puzzle();
+        }
         // Ajouter une nouvelle tuile si quelque chose a bougé
-        if self.moved {
+        else if self.moved {
+            self.moves += 1;
             self.add_random_tile();
 
             // Vérifier la fin de jeu
             if !self.can_move() {
                 self.game_over = true;
-                self.audio.play_sound(SoundEffect::Game2048GameOver);
+                self.audio.play_stinger(
+                    &stingers::GAME2048_GAME_OVER,
+                    Some(SoundEffect::Game2048GameOver),
+                );
 
                 // Sauvegarder le score si c'est un high score et pas encore sauvé
                 self.save_high_score_if_needed();
@@ -373,56 +532,69 @@ impl Game2048 {
         }
 
         // Mettre à jour le meilleur score
-        if self.score > self.best_score {
+        if self.score > self.best_score && self.puzzle.is_none() && self.autoplay.is_none() {
             self.best_score = self.score;
         }
     }
 
     fn restart(&mut self) {
-        self.grid = [[0; GRID_SIZE]; GRID_SIZE];
+        self.restart_with_seed(share::new_seed());
+    }
+
+    /// Nouvelle partie dont les tuiles sont tirées avec `seed`
+    fn restart_with_seed(&mut self, seed: u64) {
+        self.seed = seed;
+        self.rng = StdRng::seed_from_u64(seed);
+        self.grid = Grid2D::new(GRID_SIZE, GRID_SIZE, 0);
         self.score = 0;
         self.game_over = false;
         self.won = false;
+        self.endless = false;
         self.moved = false;
+        self.moves = 0;
         self.score_saved = false;
-        self.start_time = std::time::Instant::now();
+        self.clock.start();
+
+        if let Some(run) = &mut self.puzzle {
+            run.solved = false;
+            self.load_puzzle_board();
+        } else {
+            self.add_random_tile();
+            self.add_random_tile();
+        }
 
-        self.add_random_tile();
-        self.add_random_tile();
+        if let Some(autoplay) = &mut self.autoplay {
+            autoplay.evaluations = evaluate_moves(&self.grid);
+            autoplay.last_move = None;
+            autoplay.game_over_ticks = 0;
+        }
     }
 
     fn save_high_score_if_needed(&mut self) {
-        // Ne sauvegarder qu'une seule fois
-        if self.score_saved {
+        // Ne sauvegarder qu'une seule fois, et jamais pour un puzzle ni pour
+        // une partie jouée par le bot
+        if self.score_saved || self.puzzle.is_some() || self.autoplay.is_some() {
             return;
         }
 
         // Vérifier si c'est un high score
         if self.highscore_manager.is_high_score("2048", self.score) {
-            let duration = self.start_time.elapsed().as_secs();
+            let duration = self.clock.elapsed_secs();
 
             // Trouver la plus haute tuile atteinte
-            let highest_tile = self
-                .grid
-                .iter()
-                .flat_map(|row| row.iter())
-                .cloned()
-                .max()
-                .unwrap_or(0);
-
-            // Estimer le nombre de mouvements basé sur le score et le niveau
-            let estimated_moves = (self.score / 10).max(10); // Estimation basée sur le score
+            let highest_tile = self.grid.iter().copied().max().unwrap_or(0);
 
             let game_data = GameData::Game2048 {
                 highest_tile,
-                moves: estimated_moves,
+                moves: self.moves,
                 duration_seconds: duration,
+                endless: self.endless,
             };
 
-            let score = Score::new("Anonymous".to_string(), self.score, game_data);
+            let score = Score::new(current_player_name(), self.score, game_data);
 
             // Sauvegarder le score
-            if let Ok(_is_top_10) = self.highscore_manager.add_score("2048", score) {
+            if let Ok(_new_score) = self.highscore_manager.add_score("2048", score) {
                 self.score_saved = true;
             }
         }
@@ -456,16 +628,54 @@ impl Game2048 {
 
 impl Game for Game2048 {
     fn handle_key(&mut self, key: KeyEvent) -> GameAction {
-        if self.game_over || self.won {
+        if let Some(autoplay) = &mut self.autoplay {
             match key.code {
+                KeyCode::Char('+' | '=') => {
+                    autoplay.speed = (autoplay.speed + 1).min(AUTOPLAY_SPEEDS_MS.len() - 1);
+                    return GameAction::Continue;
+                }
+                KeyCode::Char('-') => {
+                    autoplay.speed = autoplay.speed.saturating_sub(1);
+                    return GameAction::Continue;
+                }
+                KeyCode::Char('h') => {
+                    autoplay.show_heatmap = !autoplay.show_heatmap;
+                    return GameAction::Continue;
+                }
+                KeyCode::Char(' ') => {
+                    autoplay.paused = !autoplay.paused;
+                    return GameAction::Continue;
+                }
+                // Le bot joue seul
+                KeyCode::Up
+                | KeyCode::Down
+                | KeyCode::Left
+                | KeyCode::Right
+                | KeyCode::Char('w' | 'a' | 's' | 'd' | 'c') => return GameAction::Continue,
+                _ => {}
+            }
+        }
+
+        if self.game_over || (self.won && !self.endless) {
+            match key.code {
+                KeyCode::Char('c') if !self.game_over => {
+                    // Continuer la partie au-delà de 2048
+                    self.endless = true;
+                    self.clock.resume();
+                    GameAction::Continue
+                }
                 KeyCode::Char('r') => {
+                    self.save_high_score_if_needed();
                     // Nettoyer l'audio avant de redémarrer
                     self.audio.clear_effects();
                     self.audio.stop_music();
                     self.restart();
                     GameAction::Continue
                 }
-                KeyCode::Char('q') => GameAction::Quit,
+                KeyCode::Char('q') => {
+                    self.save_high_score_if_needed();
+                    GameAction::Quit
+                }
                 KeyCode::Char('m') => {
                     self.audio.toggle_music();
                     GameAction::Continue
@@ -507,13 +717,21 @@ impl Game for Game2048 {
                     GameAction::Continue
                 }
                 KeyCode::Char('r') => {
+                    if self.endless {
+                        self.save_high_score_if_needed();
+                    }
                     // Nettoyer l'audio avant de redémarrer
                     self.audio.clear_effects();
                     self.audio.stop_music();
                     self.restart();
                     GameAction::Continue
                 }
-                KeyCode::Char('q') => GameAction::Quit,
+                KeyCode::Char('q') => {
+                    if self.endless {
+                        self.save_high_score_if_needed();
+                    }
+                    GameAction::Quit
+                }
                 KeyCode::Char('m') => {
                     self.audio.toggle_music();
                     GameAction::Continue
@@ -528,22 +746,135 @@ impl Game for Game2048 {
     }
 
     fn update(&mut self) -> GameAction {
+        self.update_autoplay();
         self.start_music_if_needed();
         GameAction::Continue
     }
 
-    fn draw(&mut self, frame: &mut ratatui::Frame) {
-        draw_2048_game(frame, self);
+    fn preview(&self, frame: &mut ratatui::Frame, area: Rect) {
+        draw_preview(frame, area);
+    }
+
+    fn draw(&mut self, frame: &mut ratatui::Frame, area: Rect) {
+        draw_2048_game(frame, area, self);
     }
 
     fn tick_rate(&self) -> Duration {
+        if let Some(autoplay) = &self.autoplay {
+            return Duration::from_millis(autoplay.delay_ms());
+        }
         Duration::from_millis(100) // Pas besoin d'être très rapide pour 2048
     }
-}
 
-fn draw_2048_game(frame: &mut ratatui::Frame, game: &Game2048) {
-    let area = frame.area();
+    fn status(&self) -> GameStatus {
+        match &self.autoplay {
+            // Le bot relance une partie après un nombre de ticks fixe
+            Some(autoplay) if autoplay.paused => GameStatus::Paused,
+            Some(_) => GameStatus::Running,
+            None if self.puzzle.as_ref().is_some_and(|run| run.solved) => GameStatus::Victory,
+            None if self.game_over => GameStatus::GameOver,
+            None if self.won && !self.endless => GameStatus::Victory,
+            None if self.clock.is_suspended() => GameStatus::Paused,
+            None => GameStatus::Running,
+        }
+    }
+
+    fn title_status(&self) -> Option<String> {
+        if let Some(run) = &self.puzzle {
+            return Some(format!("{}/{} moves", self.moves, run.puzzle.max_moves));
+        }
+        Some(format!("{} pts", numbers::score(self.score)))
+    }
+
+    fn session_score(&self) -> Option<u32> {
+        Some(self.score)
+    }
+
+    fn session_mode(&self) -> Option<String> {
+        if self.autoplay.is_some() {
+            return Some("Autoplay".to_string());
+        }
+        self.endless.then(|| "Endless".to_string())
+    }
+
+    fn on_shutdown(&mut self) {
+        if self.won || self.game_over {
+            self.save_high_score_if_needed();
+        }
+    }
+
+    fn save_state(&self) -> Option<serde_json::Value> {
+        // Ni les puzzles ni le bot ne se reprennent
+        if self.puzzle.is_some() || self.autoplay.is_some() || self.game_over || self.moves == 0 {
+            return None;
+        }
+        serde_json::to_value(SavedGame {
+            grid: self.grid.clone(),
+            score: self.score,
+            won: self.won,
+            endless: self.endless,
+            moves: self.moves,
+            seed: self.seed,
+            elapsed_ms: self.clock.elapsed().as_millis() as u64,
+        })
+        .ok()
+    }
+
+    fn restore_state(&mut self, state: serde_json::Value) -> bool {
+        let Ok(saved) = serde_json::from_value::<SavedGame>(state) else {
+            return false;
+        };
+        if saved.grid.width() != GRID_SIZE || saved.grid.height() != GRID_SIZE {
+            return false;
+        }
+        self.grid = saved.grid;
+        self.score = saved.score;
+        self.best_score = self.best_score.max(saved.score);
+        self.won = saved.won;
+        self.endless = saved.endless;
+        self.moves = saved.moves;
+        self.seed = saved.seed;
+        // Les tirages reprennent sur une suite propre à ce coup
+        self.rng = StdRng::seed_from_u64(saved.seed.wrapping_add(u64::from(saved.moves)));
+        self.clock = GameClock::resumed_from(Duration::from_millis(saved.elapsed_ms));
+        self.game_over = false;
+        self.score_saved = false;
+        true
+    }
+
+    fn on_pause(&mut self) {
+        self.clock.suspend();
+    }
+
+    fn on_resume(&mut self) {
+        self.clock.unsuspend();
+    }
+
+    fn captures_volume_keys(&self) -> bool {
+        self.autoplay.is_some() // Vitesse du bot
+    }
+
+    fn play_share_code(&mut self, code: &ShareCode) {
+        if self.puzzle.is_none() && self.autoplay.is_none() {
+            self.restart_with_seed(code.seed);
+        }
+    }
+
+    /// Partie classique seulement
+    fn shared_game(&self) -> Option<ShareCode> {
+        (self.puzzle.is_none() && self.autoplay.is_none()).then_some(ShareCode {
+            game: SharedGame::Game2048,
+            seed: self.seed,
+            extra: 0,
+        })
+    }
+
+    fn audio(&self) -> Option<&AudioManager> {
+        Some(&self.audio)
+    }
+}
 
+fn draw_2048_game(frame: &mut ratatui::Frame, area: Rect, game: &Game2048) {
     // Layout principal
     let chunks = Layout::vertical([
         Constraint::Length(4), // Header avec score
@@ -553,22 +884,66 @@ fn draw_2048_game(frame: &mut ratatui::Frame, game: &Game2048) {
     .split(area);
 
     // Fond sombre élégant
-    let background = Block::new().style(Style::default().bg(Color::Rgb(15, 20, 25)));
-    frame.render_widget(background, area);
+    style::fill_area(frame, area, style::background());
 
     // === HEADER ===
-    let header_text = vec![
+    let status_line = if let Some(run) = &game.puzzle {
         Line::from(vec![
-            "🎮 ".yellow().bold(),
-            "2048 GAME".cyan().bold(),
-            " 🎮".yellow().bold(),
-        ]),
+            "Puzzle: ".yellow(),
+            run.puzzle.name.as_str().white().bold(),
+            " | Moves: ".gray(),
+            format!("{}/{}", game.moves, run.puzzle.max_moves)
+                .cyan()
+                .bold(),
+            " | Target: ".gray(),
+            format!("{}", run.puzzle.target_tile).green().bold(),
+        ])
+    } else if let Some(autoplay) = &game.autoplay {
+        Line::from(vec![
+            format!("{} Autoplay", glyphs::icon("🤖")).magenta().bold(),
+            " | Score: ".gray(),
+            numbers::score(game.score).white().bold(),
+            " | Moves: ".gray(),
+            format!("{}", game.moves).cyan().bold(),
+            " | Speed: ".gray(),
+            format!("{}ms", autoplay.delay_ms()).white().bold(),
+            " | Last: ".gray(),
+            autoplay
+                .last_move
+                .map_or("-", Direction::arrow)
+                .white()
+                .bold(),
+            if autoplay.paused {
+                format!(" | {} Paused", glyphs::icon("⏸")).yellow().bold()
+            } else {
+                "".into()
+            },
+        ])
+    } else {
         Line::from(vec![
             "Score: ".yellow(),
-            format!("{}", game.score).white().bold(),
+            numbers::score(game.score).white().bold(),
             " | Best: ".gray(),
-            format!("{}", game.best_score).green().bold(),
+            numbers::score(game.best_score).green().bold(),
+            " | Moves: ".gray(),
+            format!("{}", game.moves).cyan().bold(),
+            " | Time: ".gray(),
+            game.clock.format().white().bold(),
+            if game.endless {
+                format!(" | {} Endless", glyphs::icon("♾")).magenta().bold()
+            } else {
+                "".into()
+            },
+        ])
+    };
+
+    let header_text = vec![
+        Line::from(vec![
+            format!("{} ", glyphs::icon("🎮")).yellow().bold(),
+            "2048 GAME".cyan().bold(),
+            format!(" {}", glyphs::icon("🎮")).yellow().bold(),
         ]),
+        status_line,
     ];
 
     let header = Paragraph::new(header_text)
@@ -606,17 +981,22 @@ fn draw_2048_game(frame: &mut ratatui::Frame, game: &Game2048) {
     // Dessiner la grille
     for row in 0..GRID_SIZE {
         for col in 0..GRID_SIZE {
-            let value = game.grid[row][col];
+            let value = game.grid[(col, row)];
 
             let cell_x = start_x + (col as u16 * (cell_width + 1));
             let cell_y = start_y + (row as u16 * (cell_height + 1));
 
+            // Sur un petit terminal, la grille déborde : on coupe les cases
             let cell_area = Rect {
                 x: cell_x,
                 y: cell_y,
                 width: cell_width,
                 height: cell_height,
-            };
+            }
+            .intersection(area);
+            if cell_area.is_empty() {
+                continue;
+            }
 
             let cell_text = if value == 0 {
                 String::new()
@@ -640,8 +1020,43 @@ fn draw_2048_game(frame: &mut ratatui::Frame, game: &Game2048) {
         }
     }
 
+    if let Some(autoplay) = game
+        .autoplay
+        .as_ref()
+        .filter(|autoplay| autoplay.show_heatmap)
+    {
+        let grid_area = Rect {
+            x: start_x,
+            y: start_y,
+            width: grid_width,
+            height: grid_height,
+        };
+        draw_move_heatmap(frame, autoplay, grid_area, inner_area);
+    }
+
     // === FOOTER ===
-    let instructions = if game.game_over || game.won {
+    let instructions = if game.autoplay.is_some() {
+        vec![
+            Line::from(vec![
+                "+/-".cyan().bold(),
+                " Speed  ".white(),
+                "H".cyan().bold(),
+                " Heatmap  ".white(),
+                "Space".cyan().bold(),
+                " Pause  ".white(),
+                "R".green().bold(),
+                " Restart  ".white(),
+                "Q".red().bold(),
+                " Quit".white(),
+            ]),
+            Line::from(vec![
+                "M".yellow().bold(),
+                " Music  ".white(),
+                "N".yellow().bold(),
+                " Sound Effects".white(),
+            ]),
+        ]
+    } else if game.game_over || (game.won && !game.endless) {
         vec![
             Line::from(vec![
                 if game.won {
@@ -696,37 +1111,59 @@ fn draw_2048_game(frame: &mut ratatui::Frame, game: &Game2048) {
     // === GAME OVER POPUP ===
     if game.game_over {
         let popup_width = 50.min(area.width);
-        let popup_height = 10.min(area.height);
+
+        let (title_lines, stats) = match &game.puzzle {
+            Some(run) => (
+                vec![if run.solved {
+                    Line::from("🧩 PUZZLE SOLVED! 🧩".green().bold())
+                } else {
+                    Line::from("❌ PUZZLE FAILED ❌".red().bold())
+                }],
+                vec![
+                    Line::from(run.puzzle.objective.as_str().white()),
+                    Line::from(vec![
+                        "Moves Used: ".white(),
+                        format!("{}", game.moves).cyan().bold(),
+                    ]),
+                ],
+            ),
+            None => (
+                bigtext::headline(
+                    "GAME OVER",
+                    Style::new().red().bold(),
+                    popup_width.saturating_sub(2),
+                    Line::from("💀 GAME OVER 💀".red().bold()),
+                ),
+                vec![
+                    Line::from(vec![
+                        "Final Score: ".white(),
+                        numbers::score(game.score).yellow().bold(),
+                    ]),
+                    Line::from(vec![
+                        "Best Score: ".white(),
+                        numbers::score(game.best_score).green().bold(),
+                    ]),
+                ],
+            ),
+        };
+
+        // Le grand titre agrandit la fenêtre d'autant de lignes
+        let popup_height = (9 + title_lines.len() as u16).min(area.height);
         let popup_area = Rect {
-            x: if area.width >= popup_width {
-                (area.width - popup_width) / 2
-            } else {
-                0
-            },
-            y: if area.height >= popup_height {
-                (area.height - popup_height) / 2
-            } else {
-                0
-            },
+            x: area.x + (area.width - popup_width) / 2,
+            y: area.y + (area.height - popup_height) / 2,
             width: popup_width,
             height: popup_height,
         };
 
         frame.render_widget(Clear, popup_area);
 
-        let game_over_text = vec![
-            Line::from(""),
-            Line::from("💀 GAME OVER 💀".red().bold()),
-            Line::from(""),
-            Line::from(vec![
-                "Final Score: ".white(),
-                format!("{}", game.score).yellow().bold(),
-            ]),
-            Line::from(vec![
-                "Best Score: ".white(),
-                format!("{}", game.best_score).green().bold(),
-            ]),
-            Line::from(""),
+        let mut game_over_text = vec![Line::from("")];
+        game_over_text.extend(title_lines);
+        game_over_text.push(Line::from(""));
+        game_over_text.extend(stats);
+        game_over_text.extend([
+            share_line(game),
             Line::from(vec![
                 "Press ".gray(),
                 "R".green().bold(),
@@ -734,7 +1171,7 @@ fn draw_2048_game(frame: &mut ratatui::Frame, game: &Game2048) {
                 "Q".red().bold(),
                 " to quit".gray(),
             ]),
-        ];
+        ]);
 
         let popup = Paragraph::new(game_over_text)
             .alignment(ratatui::layout::Alignment::Center)
@@ -748,11 +1185,11 @@ fn draw_2048_game(frame: &mut ratatui::Frame, game: &Game2048) {
         frame.render_widget(popup, popup_area);
     }
     // === POPUP DE VICTOIRE ===
-    else if game.won {
+    else if game.won && !game.endless {
         let popup_width = 50.min(area.width);
         let popup_height = 10.min(area.height);
-        let popup_x = (area.width.saturating_sub(popup_width)) / 2;
-        let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+        let popup_x = area.x + (area.width.saturating_sub(popup_width)) / 2;
+        let popup_y = area.y + (area.height.saturating_sub(popup_height)) / 2;
 
         let popup_area = Rect {
             x: popup_x,
@@ -769,11 +1206,14 @@ fn draw_2048_game(frame: &mut ratatui::Frame, game: &Game2048) {
             Line::from("🎉 CONGRATULATIONS! 🎉".green().bold()),
             Line::from(""),
             Line::from("You reached 2048!".white()),
-            Line::from(""),
+            share_line(game),
             Line::from(vec![
-                "Continue playing or ".white(),
+                "C".cyan().bold(),
+                " Keep playing (endless)  ".white(),
                 "R".green().bold(),
-                "estart".white(),
+                " Restart  ".white(),
+                "Q".red().bold(),
+                " Quit".white(),
             ]),
         ];
 
@@ -790,6 +1230,72 @@ fn draw_2048_game(frame: &mut ratatui::Frame, game: &Game2048) {
     }
 }
 
+/// Code de partage de la partie, pour les popups de fin
+fn share_line(game: &Game2048) -> Line<'static> {
+    match game.shared_game() {
+        Some(code) => Line::from(vec!["Share code: ".gray(), code.encode().yellow().bold()]),
+        None => Line::from(""),
+    }
+}
+
+/// Évaluation de chaque coup par le bot, posée sur les bords de la grille :
+/// vert pour le meilleur coup, rouge pour le pire, gris s'il ne change rien
+fn draw_move_heatmap(frame: &mut ratatui::Frame, autoplay: &Autoplay, grid: Rect, bounds: Rect) {
+    const LABEL_WIDTH: u16 = 7;
+    let center_x = grid.x + grid.width.saturating_sub(LABEL_WIDTH) / 2;
+    let center_y = grid.y + grid.height / 2;
+
+    for (direction, heat) in Direction::ALL.into_iter().zip(autoplay.heat()) {
+        let (x, y) = match direction {
+            Direction::Up => (center_x, grid.y.saturating_sub(1)),
+            Direction::Down => (center_x, grid.bottom()),
+            Direction::Left => (grid.x.saturating_sub(LABEL_WIDTH + 1), center_y),
+            Direction::Right => (grid.right() + 1, center_y),
+        };
+        let label_area = Rect::new(x, y, LABEL_WIDTH, 1);
+        // Pas de place autour de la grille sur les petits terminaux
+        if label_area.intersection(bounds) != label_area {
+            continue;
+        }
+
+        let (text, style) = match heat {
+            Some(heat) => (
+                format!("{} {:>3}", direction.arrow(), (heat * 100.0).round() as u32),
+                Style::default()
+                    .bg(heat_color(heat))
+                    .fg(Color::Black)
+                    .bold(),
+            ),
+            None => (
+                format!("{}  --", direction.arrow()),
+                Style::default().fg(Color::DarkGray),
+            ),
+        };
+        frame.render_widget(
+            Paragraph::new(text)
+                .alignment(ratatui::layout::Alignment::Center)
+                .style(style),
+            label_area,
+        );
+    }
+}
+
+/// Dégradé rouge, jaune, vert pour `heat` entre 0.0 et 1.0
+fn heat_color(heat: f32) -> Color {
+    let lerp = |from: u8, to: u8, t: f32| (from as f32 + (to as f32 - from as f32) * t) as u8;
+    let heat = heat.clamp(0.0, 1.0);
+    let (from, to, t) = if heat < 0.5 {
+        ((220, 70, 60), (230, 200, 70), heat * 2.0)
+    } else {
+        ((230, 200, 70), (80, 200, 100), heat * 2.0 - 1.0)
+    };
+    Color::Rgb(
+        lerp(from.0, to.0, t),
+        lerp(from.1, to.1, t),
+        lerp(from.2, to.2, t),
+    )
+}
+
 // Trait extension pour Vec::choose (simulation)
 trait Choose<T> {
     fn choose<R: rand::Rng>(&self, rng: &mut R) -> Option<&T>;
@@ -805,3 +1311,155 @@ impl<T> Choose<T> for Vec<T> {
         }
     }
 }
+
+/// Aperçu du menu : une grille en cours de partie, chaque chiffre étant
+/// l'exposant de la tuile (a pour 1024)
+const PREVIEW: [&str; 7] = ["21..", "    ", "432.", "    ", "7531", "    ", "a986"];
+
+fn draw_preview(frame: &mut ratatui::Frame, area: Rect) {
+    preview::draw_board(frame, area, &PREVIEW, |cell| {
+        let value = match cell.to_digit(16) {
+            Some(exponent) => 1 << exponent,
+            None if cell == '.' => 0,
+            None => return "      ".into(),
+        };
+        let label = if value == 0 {
+            String::new()
+        } else {
+            value.to_string()
+        };
+        format!("{label:^6}")
+            .fg(Game2048::get_text_color(value))
+            .bg(Game2048::get_tile_color(value))
+            .bold()
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::rng;
+    use crate::test_support::isolate_data_dir;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    /// Case vide ou tuile de 2 à 1024
+    fn tile() -> impl Strategy<Value = u32> {
+        prop_oneof![Just(0), (1u32..=10).prop_map(|exponent| 1 << exponent)]
+    }
+
+    fn board() -> impl Strategy<Value = Vec<Vec<u32>>> {
+        vec(vec(tile(), GRID_SIZE), GRID_SIZE)
+    }
+
+    fn direction() -> impl Strategy<Value = Direction> {
+        prop_oneof![
+            Just(Direction::Up),
+            Just(Direction::Down),
+            Just(Direction::Left),
+            Just(Direction::Right),
+        ]
+    }
+
+    /// Partie sur la grille `board`, sans tuile aléatoire entre les coups
+    fn game_on(board: Vec<Vec<u32>>) -> Game2048 {
+        isolate_data_dir();
+        Game2048::from_puzzle(
+            Puzzle2048 {
+                id: "proptest".to_string(),
+                name: "Property test".to_string(),
+                objective: String::new(),
+                board,
+                target_tile: u32::MAX,
+                max_moves: u32::MAX,
+            },
+            AudioManager::silent(),
+        )
+    }
+
+    fn is_valid_tile(value: u32) -> bool {
+        value == 0 || (value >= 2 && value.is_power_of_two())
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn moves_conserve_the_tile_sum(board in board(), directions in vec(direction(), 1..20)) {
+            let mut game = game_on(board);
+            let sum: u32 = game.grid.iter().sum();
+
+            for direction in directions {
+                game.move_tiles(direction);
+                prop_assert_eq!(game.grid.iter().sum::<u32>(), sum);
+                prop_assert!(game.grid.iter().all(|&value| is_valid_tile(value)));
+            }
+        }
+
+        #[test]
+        fn merges_score_the_merged_tiles(board in board(), direction in direction()) {
+            let mut game = game_on(board);
+            let before: Vec<u32> = game.grid.iter().copied().filter(|&v| v != 0).collect();
+
+            game.move_tiles(direction);
+
+            // Chaque fusion fait disparaître une tuile et rapporte la valeur
+            // de la tuile créée, soit deux fois la valeur de chaque tuile fusionnée
+            let after: Vec<u32> = game.grid.iter().copied().filter(|&v| v != 0).collect();
+            let merges = before.len() - after.len();
+            prop_assert_eq!(game.score == 0, merges == 0);
+            prop_assert!(game.score >= 4 * merges as u32);
+        }
+
+        #[test]
+        fn the_bot_only_scores_moves_that_change_the_grid(board in board()) {
+            let game = game_on(board);
+            let evaluations = evaluate_moves(&game.grid);
+
+            for (direction, evaluation) in Direction::ALL.into_iter().zip(evaluations) {
+                prop_assert_eq!(evaluation.is_some(), slide(&game.grid, direction).moved);
+            }
+        }
+
+        #[test]
+        fn a_move_adds_exactly_one_new_tile(seed in any::<u64>(), directions in vec(direction(), 1..40)) {
+            isolate_data_dir();
+            rng::seed(seed);
+            let mut game = Game2048::new(AudioManager::silent());
+
+            for direction in directions {
+                if game.game_over {
+                    break;
+                }
+                let sum: u32 = game.grid.iter().sum();
+                game.move_tiles(direction);
+                let added = game.grid.iter().sum::<u32>() - sum;
+
+                if game.moved {
+                    prop_assert!(added == 2 || added == 4);
+                } else {
+                    prop_assert_eq!(added, 0);
+                }
+                prop_assert!(game.grid.iter().all(|&value| is_valid_tile(value)));
+            }
+        }
+    }
+
+    #[test]
+    fn a_saved_game_resumes_where_it_stopped() {
+        isolate_data_dir();
+        rng::seed(7);
+        let mut game = Game2048::new(AudioManager::silent());
+        assert_eq!(game.save_state(), None, "nothing to resume before a move");
+        for direction in [Direction::Left, Direction::Up, Direction::Right] {
+            game.move_tiles(direction);
+        }
+        let state = game.save_state().unwrap();
+
+        let mut resumed = Game2048::new(AudioManager::silent());
+        assert!(resumed.restore_state(state));
+        assert_eq!(resumed.grid, game.grid);
+        assert_eq!((resumed.score, resumed.moves), (game.score, game.moves));
+        assert!(!resumed.restore_state(serde_json::json!({ "grid": [] })));
+    }
+}
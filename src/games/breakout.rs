@@ -1,26 +1,68 @@
 use crate::audio::{AudioManager, SoundEffect};
-use crate::core::{Game, GameAction};
-use crate::highscores::{GameData, HighScoreManager, Score};
-use crossterm::event::{KeyCode, KeyEvent};
+use crate::config::ConfigManager;
+use crate::core::bot::{PaddleBot, Steer};
+use crate::core::clock::GameClock;
+use crate::core::{Game, GameAction, GameStatus};
+use crate::event::{KeyCode, KeyEvent};
+use crate::highscores::{current_player_name, GameData, HighScoreManager, Score};
+use crate::music::stingers;
+use crate::mutators;
+use crate::splits::SplitTimer;
+use crate::ui::animation::{self, Shake};
+use crate::ui::particles::Particles;
+use crate::ui::trail::Trail;
+use crate::ui::{glyphs, numbers, preview, style, SelectableList};
 use ratatui::{
     layout::{Constraint, Layout, Rect},
     style::{Color, Style, Stylize},
     text::Line,
-    widgets::{Block, Clear, Paragraph},
+    widgets::{Block, Clear, ListItem, Paragraph},
 };
 use std::time::Duration;
 
+/// Taille de référence du terrain : vitesses et raquette y sont calibrées,
+/// puis mises à l'échelle de la taille réelle
 const FIELD_WIDTH: u16 = 60;
 const FIELD_HEIGHT: u16 = 20;
+/// Plus petit terrain jouable : une colonne par brique plus l'espace entre elles
+const MIN_FIELD_WIDTH: u16 = 25;
+const MIN_FIELD_HEIGHT: u16 = 14;
+const MAX_FIELD_WIDTH: u16 = 120;
+/// En coopération, le terrain s'élargit pour laisser de la place aux deux raquettes
+const MAX_COOP_FIELD_WIDTH: u16 = 160;
+const MAX_FIELD_HEIGHT: u16 = 30;
 const PADDLE_WIDTH: u16 = 10;
 const PADDLE_HEIGHT: u16 = 1;
+const PADDLE_STEP: f32 = 2.0;
 const BRICK_ROWS: usize = 6;
 const BRICK_COLS: usize = 12;
-const BRICK_WIDTH: u16 = 4;
 const BRICK_HEIGHT: u16 = 1;
+/// Briques détruites par niveau, pour les high scores et les splits
+const BRICKS_PER_LEVEL: u32 = 12;
+/// Nombre de renvois assurés par la raquette automatique du mode practice
+const ASSIST_SAVES: u32 = 3;
+/// Images pendant lesquelles une brique détruite s'effrite, puis éclats
+/// lancés par sa destruction
+const CRUMBLE_FRAMES: u8 = 6;
+const BRICK_PARTICLES: usize = 6;
+/// Secousse du terrain quand une vie est perdue
+const SHAKE_DURATION: Duration = Duration::from_millis(300);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GameMode {
+    Solo,
+    Coop, // 2 joueurs, vies et score partagés
+}
+
+/// Modes proposés dans le menu de sélection, dans l'ordre d'affichage
+const MODES: [(GameMode, &str); 2] = [
+    (GameMode::Solo, "🧱 Single Player"),
+    (GameMode::Coop, "👥 Co-op (2 Players)"),
+];
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum GameState {
+    Menu,
     Playing,
     Paused,
     GameOver,
@@ -36,12 +78,13 @@ pub struct Ball {
 }
 
 impl Ball {
-    fn new(x: f32, y: f32) -> Self {
+    /// Vitesse de départ pour un terrain de `width` × `height`
+    fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
         Self {
             x,
             y,
-            dx: 0.8,
-            dy: -0.6,
+            dx: 0.8 * width / FIELD_WIDTH as f32,
+            dy: -0.6 * height / FIELD_HEIGHT as f32,
         }
     }
 
@@ -57,52 +100,141 @@ impl Ball {
     fn bounce_y(&mut self) {
         self.dy = -self.dy;
     }
-
-    fn reset(&mut self, paddle_x: f32) {
-        self.x = paddle_x + PADDLE_WIDTH as f32 / 2.0;
-        self.y = FIELD_HEIGHT as f32 - 4.0;
-        self.dx = 0.8;
-        self.dy = -0.6;
-    }
 }
 
 #[derive(Debug, Clone, Copy)]
 pub struct Paddle {
     x: f32,
     y: f32,
+    width: f32,
+    /// Bord droit du terrain, pour borner les déplacements
+    field_width: f32,
 }
 
 impl Paddle {
-    fn new() -> Self {
+    fn new(field_width: f32, field_height: f32) -> Self {
+        Self::at(0.5, field_width, field_height)
+    }
+
+    /// Raquette centrée à la fraction `position` de la largeur du terrain
+    fn at(position: f32, field_width: f32, field_height: f32) -> Self {
+        let width = paddle_width(field_width);
         Self {
-            x: (FIELD_WIDTH - PADDLE_WIDTH) as f32 / 2.0,
-            y: FIELD_HEIGHT as f32 - 2.0,
+            x: (field_width * position - width / 2.0).clamp(0.0, field_width - width),
+            y: field_height - 2.0,
+            width,
+            field_width,
         }
     }
 
+    fn step(&self) -> f32 {
+        PADDLE_STEP * self.field_width / FIELD_WIDTH as f32
+    }
+
+    fn center(&self) -> f32 {
+        self.x + self.width / 2.0
+    }
+
     fn move_left(&mut self) {
         if self.x > 0.0 {
-            self.x = (self.x - 2.0).max(0.0);
+            self.x = (self.x - self.step()).max(0.0);
         }
     }
 
     fn move_right(&mut self) {
-        if self.x < (FIELD_WIDTH - PADDLE_WIDTH) as f32 {
-            self.x = (self.x + 2.0).min((FIELD_WIDTH - PADDLE_WIDTH) as f32);
+        let max_x = self.field_width - self.width;
+        if self.x < max_x {
+            self.x = (self.x + self.step()).min(max_x);
+        }
+    }
+
+    /// Suit un changement de taille du terrain en gardant la même position relative
+    fn resize(&mut self, field_width: f32, field_height: f32) {
+        let center = self.center() * field_width / self.field_width;
+        self.width = paddle_width(field_width);
+        self.field_width = field_width;
+        self.x = (center - self.width / 2.0).clamp(0.0, field_width - self.width);
+        self.y = field_height - 2.0;
+    }
+}
+
+/// Largeur de la raquette : un sixième du terrain, comme sur le terrain de référence
+fn paddle_width(field_width: f32) -> f32 {
+    (field_width * PADDLE_WIDTH as f32 / FIELD_WIDTH as f32)
+        .round()
+        .max(3.0)
+}
+
+/// Disposition des briques pour une taille de terrain donnée
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct BrickLayout {
+    /// Largeur d'une brique, sans l'espace qui la suit
+    width: u16,
+    /// Écart horizontal entre le début de deux briques voisines
+    pitch_x: u16,
+    /// Écart vertical entre deux rangées : les terrains bas collent les rangées
+    pitch_y: u16,
+    /// Marge à gauche pour centrer le mur de briques
+    offset_x: u16,
+}
+
+impl BrickLayout {
+    fn new(field_width: u16, field_height: u16) -> Self {
+        let pitch_x = (field_width.saturating_sub(1) / BRICK_COLS as u16).max(2);
+        let used = pitch_x * BRICK_COLS as u16 - 1;
+        Self {
+            width: pitch_x - 1,
+            pitch_x,
+            pitch_y: if field_height >= FIELD_HEIGHT {
+                BRICK_HEIGHT + 1
+            } else {
+                BRICK_HEIGHT
+            },
+            offset_x: field_width.saturating_sub(used) / 2,
         }
     }
+
+    /// Case en haut à gauche de la brique (`row`, `col`)
+    fn origin(&self, row: usize, col: usize) -> (u16, u16) {
+        (
+            self.offset_x + col as u16 * self.pitch_x,
+            2 + row as u16 * self.pitch_y,
+        )
+    }
+
+    /// Bas du mur de briques, première ligne libre en dessous
+    fn bottom(&self) -> u16 {
+        2 + (BRICK_ROWS as u16 - 1) * self.pitch_y + BRICK_HEIGHT
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Brick {
-    x: u16,
-    y: u16,
     destroyed: bool,
     color: Color,
 }
 
+/// Brique détruite qui s'effrite encore à l'écran
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Crumble {
+    row: usize,
+    col: usize,
+    color: Color,
+    age: u8,
+}
+
+impl Crumble {
+    fn glyph(&self) -> &'static str {
+        match self.age * 3 / CRUMBLE_FRAMES {
+            0 => "▓",
+            1 => "▒",
+            _ => "░",
+        }
+    }
+}
+
 impl Brick {
-    fn new(x: u16, y: u16, row: usize) -> Self {
+    fn new(row: usize) -> Self {
         let color = match row {
             0 => Color::Red,
             1 => Color::Yellow,
@@ -113,8 +245,6 @@ impl Brick {
         };
 
         Self {
-            x,
-            y,
             destroyed: false,
             color,
         }
@@ -123,55 +253,206 @@ impl Brick {
 
 pub struct BreakoutGame {
     state: GameState,
+    mode: GameMode,
+    mode_list: SelectableList, // Pour le menu de sélection de mode
     ball: Ball,
+    trail: Trail,
     paddle: Paddle,
+    /// Raquette du joueur 2 en coopération, à droite de celle du joueur 1
+    partner: Option<Paddle>,
+    /// Raquette qui porte la balle avant le lancer : les joueurs servent à
+    /// tour de rôle après chaque vie perdue
+    server: Player,
     bricks: [[Brick; BRICK_COLS]; BRICK_ROWS],
+    // Terrain : suit la place disponible à l'écran, voir update_dimensions
+    width: u16,
+    height: u16,
+    layout: BrickLayout,
     score: u32,
     lives: u32,
     ball_stuck: bool,
 
+    // Practice : raquette automatique
+    assist: bool,
+    assist_saves: u32,
+    assist_used: bool,
+    assist_bot: PaddleBot,
+
     // Audio
     audio: AudioManager,
-    music_started: bool,
 
     // High scores
     highscore_manager: HighScoreManager,
-    start_time: std::time::Instant,
+    clock: GameClock,
     score_saved: bool,
+    /// Un split par niveau, sauf dans une partie modifiée
+    splits: Option<SplitTimer>,
+    /// Multiplicateur de vitesse des modificateurs
+    speed_factor: u32,
+
+    // Effets : briques qui s'effritent, éclats et secousse du terrain
+    crumbling: Vec<Crumble>,
+    particles: Particles,
+    shake: Option<Shake>,
 }
 
 impl BreakoutGame {
-    pub fn new() -> Self {
-        let paddle = Paddle::new();
-        let ball = Ball::new(paddle.x + PADDLE_WIDTH as f32 / 2.0, paddle.y - 1.0);
-
-        let mut bricks = [[Brick::new(0, 0, 0); BRICK_COLS]; BRICK_ROWS];
-        for (row, brick_row) in bricks.iter_mut().enumerate().take(BRICK_ROWS) {
-            for (col, brick) in brick_row.iter_mut().enumerate().take(BRICK_COLS) {
-                let x = 1 + col as u16 * (BRICK_WIDTH + 1);
-                let y = 2 + row as u16 * (BRICK_HEIGHT + 1);
-                *brick = Brick::new(x, y, row);
-            }
-        }
+    pub fn new(audio: AudioManager) -> Self {
+        let (width, height) = (FIELD_WIDTH, FIELD_HEIGHT);
+        let paddle = Paddle::new(width as f32, height as f32);
+        let ball = Ball::new(paddle.center(), paddle.y - 1.0, width as f32, height as f32);
 
         Self {
-            state: GameState::Playing,
+            state: GameState::Menu,
+            mode: GameMode::Solo,
+            mode_list: SelectableList::new(),
             ball,
+            trail: Trail::new(
+                ConfigManager::new()
+                    .map(|config| config.get_display_config().ball_trail.length())
+                    .unwrap_or(0),
+            ),
             paddle,
-            bricks,
+            partner: None,
+            server: Player::One,
+            bricks: new_bricks(),
+            width,
+            height,
+            layout: BrickLayout::new(width, height),
             score: 0,
             lives: 3,
             ball_stuck: true,
 
-            audio: AudioManager::default(),
-            music_started: false,
+            assist: false,
+            assist_saves: ASSIST_SAVES,
+            assist_used: false,
+            assist_bot: PaddleBot::new(1.0, 1, 1.0),
+
+            audio,
 
             highscore_manager: HighScoreManager::default(),
-            start_time: std::time::Instant::now(),
+            clock: GameClock::new(),
             score_saved: false,
+            splits: (!mutators::any_active()).then(|| SplitTimer::new("breakout")),
+            speed_factor: mutators::speed_factor(),
+
+            crumbling: Vec::new(),
+            particles: Particles::new(),
+            shake: None,
         }
     }
 
+    fn start_game(&mut self, mode: GameMode) {
+        self.mode = mode;
+        self.restart();
+    }
+
+    /// Raquette de `player`, `None` pour le joueur 2 hors coopération
+    fn paddle_of(&self, player: Player) -> Option<&Paddle> {
+        match player {
+            Player::One => Some(&self.paddle),
+            Player::Two => self.partner.as_ref(),
+        }
+    }
+
+    /// Déplace la raquette de `player` sans qu'elle chevauche celle de
+    /// l'autre joueur
+    fn move_paddle(&mut self, player: Player, left: bool) {
+        let (paddle, other) = match player {
+            Player::One => (&mut self.paddle, self.partner),
+            Player::Two => match self.partner.as_mut() {
+                Some(partner) => (partner, Some(self.paddle)),
+                None => return,
+            },
+        };
+        if left {
+            paddle.move_left();
+        } else {
+            paddle.move_right();
+        }
+        if let Some(other) = other {
+            match player {
+                Player::One => paddle.x = paddle.x.min(other.x - paddle.width),
+                Player::Two => paddle.x = paddle.x.max(other.x + other.width),
+            }
+        }
+    }
+
+    /// Active ou coupe la raquette automatique tant qu'il reste des renvois ;
+    /// pas d'aide en coopération
+    fn toggle_assist(&mut self) {
+        self.assist = !self.assist && self.assist_saves > 0 && self.mode == GameMode::Solo;
+    }
+
+    /// La raquette automatique suit la balle quand elle descend
+    fn update_assist(&mut self) {
+        if !self.assist || self.ball_stuck || self.ball.dy <= 0.0 {
+            return;
+        }
+
+        match self.assist_bot.steer(self.paddle.center(), self.ball.x) {
+            Steer::Backward => self.paddle.move_left(),
+            Steer::Forward => self.paddle.move_right(),
+            Steer::Stay => {}
+        }
+    }
+
+    /// Adapte le terrain à la place disponible : les positions et les vitesses
+    /// sont remises à l'échelle pour garder la partie en cours telle quelle
+    fn update_dimensions(&mut self, new_width: u16, new_height: u16) {
+        if self.width == new_width && self.height == new_height {
+            return;
+        }
+
+        let width_ratio = new_width as f32 / self.width as f32;
+        let height_ratio = new_height as f32 / self.height as f32;
+
+        self.width = new_width;
+        self.height = new_height;
+        self.layout = BrickLayout::new(new_width, new_height);
+
+        self.paddle.resize(new_width as f32, new_height as f32);
+        if let Some(partner) = self.partner.as_mut() {
+            partner.resize(new_width as f32, new_height as f32);
+            // L'arrondi des largeurs peut faire se chevaucher les raquettes
+            partner.x = partner
+                .x
+                .max(self.paddle.x + self.paddle.width)
+                .min(new_width as f32 - partner.width);
+            self.paddle.x = self.paddle.x.min(partner.x - self.paddle.width).max(0.0);
+        }
+
+        self.ball.dx *= width_ratio;
+        self.ball.dy *= height_ratio;
+        self.trail.clear();
+        if self.ball_stuck {
+            self.ball.x = self.server_paddle().center();
+            self.ball.y = self.paddle.y - 1.0;
+        } else {
+            // Jamais sous le mur de briques, pour ne pas en casser une rangée d'un coup
+            self.ball.x = (self.ball.x * width_ratio).clamp(0.0, new_width as f32 - 1.0);
+            self.ball.y = (self.ball.y * height_ratio)
+                .max(self.layout.bottom() as f32)
+                .min(self.paddle.y - 1.0);
+        }
+    }
+
+    /// Raquette qui porte la balle avant le lancer
+    fn server_paddle(&self) -> &Paddle {
+        self.paddle_of(self.server).unwrap_or(&self.paddle)
+    }
+
+    /// Replace la balle sur la raquette après une vie perdue
+    fn reset_ball(&mut self) {
+        self.ball = Ball::new(
+            self.server_paddle().center(),
+            self.height as f32 - 4.0,
+            self.width as f32,
+            self.height as f32,
+        );
+        self.ball_stuck = true;
+    }
+
     fn launch_ball(&mut self) {
         if self.ball_stuck {
             self.ball_stuck = false;
@@ -179,37 +460,20 @@ impl BreakoutGame {
     }
 
     fn start_music_if_needed(&mut self) {
-        if !self.music_started && self.audio.is_music_enabled() && self.state == GameState::Playing
-        {
-            // Compter les briques restantes pour choisir la musique
-            let remaining_bricks = self.count_remaining_bricks();
-            let total_bricks = (BRICK_ROWS * BRICK_COLS) as u32;
-            let completion_ratio = 1.0 - (remaining_bricks as f32 / total_bricks as f32);
-
-            if completion_ratio > 0.7 {
-                self.audio.play_breakout_music_fast(); // Version intense pour fin de partie
+        if self.audio.is_music_enabled() {
+            if self.state == GameState::Playing {
+                self.audio.play_breakout_music();
             } else {
-                self.audio.play_breakout_music(); // Version arcade normale
+                // La musique s'arrête à la fin de son tour
+                self.audio.finish_music_loop();
             }
-            self.music_started = true;
         }
 
-        // Relancer la musique si elle est finie
-        if self.music_started
-            && self.audio.is_music_enabled()
-            && self.state == GameState::Playing
-            && self.audio.is_music_empty()
-        {
-            let remaining_bricks = self.count_remaining_bricks();
-            let total_bricks = (BRICK_ROWS * BRICK_COLS) as u32;
-            let completion_ratio = 1.0 - (remaining_bricks as f32 / total_bricks as f32);
-
-            if completion_ratio > 0.7 {
-                self.audio.play_breakout_music_fast();
-            } else {
-                self.audio.play_breakout_music();
-            }
-        }
+        // Les percussions montent avec la part de briques cassées, au maximum à 70 %
+        let remaining_bricks = self.count_remaining_bricks();
+        let total_bricks = (BRICK_ROWS * BRICK_COLS) as u32;
+        let completion_ratio = 1.0 - (remaining_bricks as f32 / total_bricks as f32);
+        self.audio.set_music_intensity(completion_ratio / 0.7);
     }
 
     fn count_remaining_bricks(&self) -> u32 {
@@ -224,6 +488,10 @@ impl BreakoutGame {
         count
     }
 
+    fn bricks_broken(&self) -> u32 {
+        (BRICK_ROWS * BRICK_COLS) as u32 - self.count_remaining_bricks()
+    }
+
     fn check_collisions(&mut self) {
         // Collision avec les murs
         if self.ball.x <= 0.0 {
@@ -232,8 +500,9 @@ impl BreakoutGame {
             // Son de collision avec les murs (réutilise le son Pong)
             self.audio.play_sound(SoundEffect::PongWallHit);
         }
-        if self.ball.x >= FIELD_WIDTH as f32 - 1.0 {
-            self.ball.x = FIELD_WIDTH as f32 - 1.0;
+        let right_wall = self.width as f32 - 1.0;
+        if self.ball.x >= right_wall {
+            self.ball.x = right_wall;
             self.ball.bounce_x();
             self.audio.play_sound(SoundEffect::PongWallHit);
         }
@@ -243,41 +512,60 @@ impl BreakoutGame {
             self.audio.play_sound(SoundEffect::PongWallHit);
         }
 
-        // Collision avec la raquette
-        if self.ball.y >= self.paddle.y - 1.0
-            && self.ball.y <= self.paddle.y + PADDLE_HEIGHT as f32
-            && self.ball.x >= self.paddle.x
-            && self.ball.x <= self.paddle.x + PADDLE_WIDTH as f32
-        {
-            self.ball.y = self.paddle.y - 1.0;
+        // Collision avec une raquette : seulement quand la balle descend, pour
+        // qu'une balle renvoyée par un joueur traverse la raquette de l'autre
+        let ball = self.ball;
+        let hit = [Some(self.paddle), self.partner]
+            .into_iter()
+            .flatten()
+            .find(|paddle| {
+                ball.dy > 0.0
+                    && ball.y >= paddle.y - 1.0
+                    && ball.y <= paddle.y + PADDLE_HEIGHT as f32
+                    && ball.x >= paddle.x
+                    && ball.x <= paddle.x + paddle.width
+            });
+        if let Some(paddle) = hit {
+            self.ball.y = paddle.y - 1.0;
 
             // Ajuster la direction en fonction de la position sur la raquette
-            let hit_pos = (self.ball.x - self.paddle.x) / PADDLE_WIDTH as f32;
+            let hit_pos = (self.ball.x - paddle.x) / paddle.width;
             let angle_factor = (hit_pos - 0.5) * 2.0; // -1 à 1
-            self.ball.dx = angle_factor * 1.2;
+            self.ball.dx = angle_factor * 1.2 * self.width as f32 / FIELD_WIDTH as f32;
             self.ball.dy = -self.ball.dy.abs(); // Toujours vers le haut
 
             // Son de collision avec la raquette
             self.audio.play_sound(SoundEffect::BreakoutPaddleHit);
+
+            // Chaque renvoi en mode practice consomme une aide
+            if self.assist {
+                self.assist_used = true;
+                self.assist_saves -= 1;
+                self.assist = self.assist_saves > 0;
+            }
         }
 
         // Collision avec les briques
         let ball_x = self.ball.x as u16;
         let ball_y = self.ball.y as u16;
 
-        for row in &mut self.bricks {
-            for brick in row {
+        let layout = self.layout;
+        let mut brick_hit = None;
+        for (row_index, row) in self.bricks.iter_mut().enumerate() {
+            for (col, brick) in row.iter_mut().enumerate() {
                 if brick.destroyed {
                     continue;
                 }
 
                 // Vérifier collision avec la brique
-                if ball_x >= brick.x
-                    && ball_x < brick.x + BRICK_WIDTH
-                    && ball_y >= brick.y
-                    && ball_y < brick.y + BRICK_HEIGHT
+                let (brick_x, brick_y) = layout.origin(row_index, col);
+                if ball_x >= brick_x
+                    && ball_x < brick_x + layout.width
+                    && ball_y >= brick_y
+                    && ball_y < brick_y + BRICK_HEIGHT
                 {
                     brick.destroyed = true;
+                    brick_hit = Some((row_index, col, brick.color));
                     self.score += 10;
                     self.ball.bounce_y();
 
@@ -287,36 +575,84 @@ impl BreakoutGame {
                 }
             }
         }
+        if let Some((row, col, color)) = brick_hit {
+            self.break_apart(row, col, color);
+            if self.bricks_broken().is_multiple_of(BRICKS_PER_LEVEL) {
+                if let Some(splits) = &mut self.splits {
+                    splits.mark(self.clock.elapsed());
+                }
+            }
+        }
 
         // Vérifier si la balle tombe en bas
-        if self.ball.y >= FIELD_HEIGHT as f32 {
+        if self.ball.y >= self.height as f32 {
             self.lives -= 1;
+            self.shake = Shake::start(SHAKE_DURATION);
             if self.lives == 0 {
                 self.state = GameState::GameOver;
-                // Son de game over
-                self.audio.play_sound(SoundEffect::BreakoutGameOver);
+                // Jingle de game over à la place de la musique
+                self.audio.play_stinger(
+                    &stingers::BREAKOUT_GAME_OVER,
+                    Some(SoundEffect::BreakoutGameOver),
+                );
+                if let Some(splits) = &mut self.splits {
+                    splits.finish();
+                }
 
                 // Sauvegarder le score si c'est un high score et pas encore sauvé
                 self.save_high_score_if_needed();
             } else {
-                self.ball.reset(self.paddle.x);
-                self.ball_stuck = true;
+                if self.partner.is_some() {
+                    self.server = self.server.other();
+                }
+                self.reset_ball();
             }
         }
 
         // Vérifier la victoire
         if self.all_bricks_destroyed() {
             self.state = GameState::Victory;
-            // Musique de victoire
-            self.audio.stop_music();
-            self.audio.play_breakout_music_celebration();
-            self.music_started = false;
+            // Fanfare de victoire à la place de la musique
+            self.audio.play_stinger(&stingers::BREAKOUT_VICTORY, None);
+            if let Some(splits) = &mut self.splits {
+                splits.finish();
+            }
 
             // Sauvegarder le score si c'est un high score et pas encore sauvé
             self.save_high_score_if_needed();
         }
     }
 
+    /// Fait s'effriter la brique (`row`, `col`) en lançant des éclats
+    fn break_apart(&mut self, row: usize, col: usize, color: Color) {
+        if animation::reduce_motion() {
+            return;
+        }
+        self.crumbling.push(Crumble {
+            row,
+            col,
+            color,
+            age: 0,
+        });
+        let (x, y) = self.layout.origin(row, col);
+        let center = x as f32 + self.layout.width as f32 / 2.0;
+        self.particles
+            .burst(center, y as f32, color, BRICK_PARTICLES);
+    }
+
+    /// Avance les effets d'une image
+    fn update_effects(&mut self) {
+        for crumble in &mut self.crumbling {
+            crumble.age += 1;
+        }
+        self.crumbling
+            .retain(|crumble| crumble.age < CRUMBLE_FRAMES);
+        self.particles.step();
+        if self.shake.is_some_and(|shake| shake.is_finished()) {
+            self.shake = None;
+        }
+    }
+
     fn all_bricks_destroyed(&self) -> bool {
         for row in &self.bricks {
             for brick in row {
@@ -331,57 +667,68 @@ impl BreakoutGame {
     fn update_ball(&mut self) {
         if self.ball_stuck {
             // La balle suit la raquette
-            self.ball.x = self.paddle.x + PADDLE_WIDTH as f32 / 2.0;
+            self.ball.x = self.server_paddle().center();
+            self.trail.clear();
         } else {
             self.ball.update();
             self.check_collisions();
+            self.trail.push(self.ball.x, self.ball.y);
         }
     }
 
     fn restart(&mut self) {
-        let paddle = Paddle::new();
-        let ball = Ball::new(paddle.x + PADDLE_WIDTH as f32 / 2.0, paddle.y - 1.0);
-
-        let mut bricks = [[Brick::new(0, 0, 0); BRICK_COLS]; BRICK_ROWS];
-        for (row, brick_row) in bricks.iter_mut().enumerate().take(BRICK_ROWS) {
-            for (col, brick) in brick_row.iter_mut().enumerate().take(BRICK_COLS) {
-                let x = 1 + col as u16 * (BRICK_WIDTH + 1);
-                let y = 2 + row as u16 * (BRICK_HEIGHT + 1);
-                *brick = Brick::new(x, y, row);
-            }
-        }
+        // La partie suivante garde la taille de terrain actuelle
+        let (width, height) = (self.width as f32, self.height as f32);
+        let (paddle, partner) = match self.mode {
+            GameMode::Solo => (Paddle::new(width, height), None),
+            GameMode::Coop => (
+                Paddle::at(0.25, width, height),
+                Some(Paddle::at(0.75, width, height)),
+            ),
+        };
+        let ball = Ball::new(paddle.center(), paddle.y - 1.0, width, height);
 
         self.state = GameState::Playing;
         self.ball = ball;
         self.paddle = paddle;
-        self.bricks = bricks;
+        self.partner = partner;
+        self.server = Player::One;
+        self.bricks = new_bricks();
         self.score = 0;
         self.lives = 3;
         self.ball_stuck = true;
+        self.assist = false;
+        self.assist_saves = ASSIST_SAVES;
+        self.assist_used = false;
         self.score_saved = false;
-        self.start_time = std::time::Instant::now();
+        self.crumbling.clear();
+        self.particles.clear();
+        self.shake = None;
+        if self.splits.is_some() {
+            self.splits = Some(SplitTimer::new(match self.mode {
+                GameMode::Solo => "breakout",
+                GameMode::Coop => "breakout-coop",
+            }));
+        }
+        self.clock.start();
 
         self.audio.stop_music();
-        self.music_started = false;
     }
 
     fn save_high_score_if_needed(&mut self) {
-        // Ne sauvegarder qu'une seule fois
-        if self.score_saved {
+        // Ne sauvegarder qu'une seule fois, et jamais un score à deux, qui
+        // fausserait le classement solo
+        if self.score_saved || self.mode == GameMode::Coop {
             return;
         }
 
         // Vérifier si c'est un high score
         if self.highscore_manager.is_high_score("breakout", self.score) {
-            let duration = self.start_time.elapsed().as_secs();
-
-            // Calculer les briques détruites
-            let total_bricks = (BRICK_ROWS * BRICK_COLS) as u32;
-            let remaining_bricks = self.count_remaining_bricks();
-            let bricks_broken = total_bricks - remaining_bricks;
+            let duration = self.clock.elapsed_secs();
 
             // Calculer un niveau basé sur les briques détruites
-            let level = (bricks_broken / 12).max(1); // Niveau augmente tous les 12 briques
+            let bricks_broken = self.bricks_broken();
+            let level = (bricks_broken / BRICKS_PER_LEVEL).max(1);
 
             let game_data = GameData::Breakout {
                 level,
@@ -389,34 +736,88 @@ impl BreakoutGame {
                 duration_seconds: duration,
             };
 
-            let score = Score::new("Anonymous".to_string(), self.score, game_data);
+            let mut score = Score::new(current_player_name(), self.score, game_data);
+            score.assists.ai = self.assist_used;
 
             // Sauvegarder le score
-            if let Ok(_is_top_10) = self.highscore_manager.add_score("breakout", score) {
+            if let Ok(_new_score) = self.highscore_manager.add_score("breakout", score) {
                 self.score_saved = true;
             }
         }
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Player {
+    One,
+    Two,
+}
+
+impl Player {
+    fn other(self) -> Self {
+        match self {
+            Player::One => Player::Two,
+            Player::Two => Player::One,
+        }
+    }
+}
+
+/// Mur de briques complet, une couleur par rangée
+fn new_bricks() -> [[Brick; BRICK_COLS]; BRICK_ROWS] {
+    std::array::from_fn(|row| [Brick::new(row); BRICK_COLS])
+}
+
 impl Game for BreakoutGame {
     fn handle_key(&mut self, key: KeyEvent) -> GameAction {
+        // En coopération, le joueur 1 joue avec A/D et le joueur 2 avec les flèches
+        let arrows = match self.mode {
+            GameMode::Solo => Player::One,
+            GameMode::Coop => Player::Two,
+        };
         match self.state {
+            GameState::Menu => {
+                if self.mode_list.handle_key(key, MODES.len()) {
+                    return GameAction::Continue;
+                }
+
+                match key.code {
+                    KeyCode::Enter => {
+                        let (mode, _) = MODES[self.mode_list.selected()];
+                        self.start_game(mode);
+                        GameAction::Continue
+                    }
+                    KeyCode::Char('q') => GameAction::Quit,
+                    _ => GameAction::Continue,
+                }
+            }
             GameState::Playing => match key.code {
-                KeyCode::Left | KeyCode::Char('a') => {
-                    self.paddle.move_left();
+                KeyCode::Char('a') => {
+                    self.move_paddle(Player::One, true);
+                    GameAction::Continue
+                }
+                KeyCode::Char('d') => {
+                    self.move_paddle(Player::One, false);
+                    GameAction::Continue
+                }
+                KeyCode::Left => {
+                    self.move_paddle(arrows, true);
                     GameAction::Continue
                 }
-                KeyCode::Right | KeyCode::Char('d') => {
-                    self.paddle.move_right();
+                KeyCode::Right => {
+                    self.move_paddle(arrows, false);
                     GameAction::Continue
                 }
                 KeyCode::Char(' ') => {
                     self.launch_ball();
                     GameAction::Continue
                 }
+                KeyCode::Char('h') => {
+                    self.toggle_assist();
+                    GameAction::Continue
+                }
                 KeyCode::Char('p') => {
                     self.state = GameState::Paused;
+                    self.clock.pause();
                     GameAction::Continue
                 }
                 KeyCode::Char('r') => {
@@ -440,6 +841,7 @@ impl Game for BreakoutGame {
             GameState::Paused => match key.code {
                 KeyCode::Char('p') => {
                     self.state = GameState::Playing;
+                    self.clock.resume();
                     GameAction::Continue
                 }
                 KeyCode::Char('r') => {
@@ -468,6 +870,11 @@ impl Game for BreakoutGame {
                     self.restart();
                     GameAction::Continue
                 }
+                KeyCode::Char('b') => {
+                    self.audio.stop_music();
+                    self.state = GameState::Menu;
+                    GameAction::Continue
+                }
                 KeyCode::Char('q') => GameAction::Quit,
                 KeyCode::Char('m') => {
                     self.audio.toggle_music();
@@ -485,22 +892,80 @@ impl Game for BreakoutGame {
     fn update(&mut self) -> GameAction {
         if self.state == GameState::Playing {
             self.start_music_if_needed();
+            self.update_assist();
             self.update_ball();
         }
+        // Les effets se figent avec la pause, mais finissent après la partie
+        if !matches!(self.state, GameState::Menu | GameState::Paused) {
+            self.update_effects();
+        }
         GameAction::Continue
     }
 
-    fn draw(&mut self, frame: &mut ratatui::Frame) {
-        draw_breakout_game(frame, self);
+    fn preview(&self, frame: &mut ratatui::Frame, area: Rect) {
+        draw_preview(frame, area);
+    }
+
+    fn draw(&mut self, frame: &mut ratatui::Frame, area: Rect) {
+        draw_breakout_game(frame, area, self);
     }
 
     fn tick_rate(&self) -> Duration {
-        Duration::from_millis(50)
+        Duration::from_millis(50 / self.speed_factor as u64)
+    }
+
+    fn status(&self) -> GameStatus {
+        match self.state {
+            GameState::Menu => GameStatus::Menu,
+            GameState::Playing => GameStatus::Running,
+            GameState::Paused => GameStatus::Paused,
+            GameState::GameOver => GameStatus::GameOver,
+            GameState::Victory => GameStatus::Victory,
+        }
+    }
+
+    fn on_pause(&mut self) {
+        if self.state == GameState::Playing {
+            self.state = GameState::Paused;
+            self.clock.pause();
+        }
+    }
+
+    fn audio(&self) -> Option<&AudioManager> {
+        Some(&self.audio)
+    }
+
+    fn session_score(&self) -> Option<u32> {
+        (self.state != GameState::Menu).then_some(self.score)
+    }
+
+    fn session_mode(&self) -> Option<String> {
+        if self.state == GameState::Menu {
+            return None;
+        }
+        let mode = match self.mode {
+            GameMode::Solo => "Single Player",
+            GameMode::Coop => "Co-op",
+        };
+        Some(mode.to_string())
+    }
+
+    fn title_status(&self) -> Option<String> {
+        (self.state != GameState::Menu).then(|| format!("{} pts", numbers::score(self.score)))
+    }
+
+    fn splits(&self) -> Option<(&SplitTimer, Duration)> {
+        let elapsed = self.clock.elapsed();
+        self.splits.as_ref().map(|splits| (splits, elapsed))
     }
 }
 
-fn draw_breakout_game(frame: &mut ratatui::Frame, game: &BreakoutGame) {
-    let area = frame.area();
+fn draw_breakout_game(frame: &mut ratatui::Frame, area: Rect, game: &mut BreakoutGame) {
+    if game.state == GameState::Menu {
+        style::fill_area(frame, area, Color::Black);
+        draw_mode_selection(frame, area, game);
+        return;
+    }
 
     // Layout principal
     let chunks = Layout::vertical([
@@ -511,24 +976,35 @@ fn draw_breakout_game(frame: &mut ratatui::Frame, game: &BreakoutGame) {
     .split(area);
 
     // Fond sombre
-    let background = Block::new().style(Style::default().bg(Color::Black));
-    frame.render_widget(background, area);
+    style::fill_area(frame, area, Color::Black);
 
     // === HEADER ===
     let lives_hearts = "♥ ".repeat(game.lives as usize);
     let header_text = vec![
         Line::from(vec![
-            "🧱 ".yellow().bold(),
+            format!("{} ", glyphs::icon("🧱")).yellow().bold(),
             "BREAKOUT".cyan().bold(),
-            " 🧱".yellow().bold(),
+            format!(" {}", glyphs::icon("🧱")).yellow().bold(),
         ]),
         Line::from(vec![
             "Score: ".white(),
-            format!("{}", game.score).yellow().bold(),
+            numbers::score(game.score).yellow().bold(),
             "  Lives: ".white(),
             format!("{}", game.lives).red().bold(),
             " ".white(),
             lives_hearts.red().bold(),
+            if game.mode == GameMode::Coop {
+                "  Co-op".cyan().bold()
+            } else {
+                "".into()
+            },
+            if game.assist {
+                format!("  {} Assist ×{}", glyphs::icon("🤖"), game.assist_saves)
+                    .magenta()
+                    .bold()
+            } else {
+                "".into()
+            },
         ]),
     ];
 
@@ -555,29 +1031,43 @@ fn draw_breakout_game(frame: &mut ratatui::Frame, game: &BreakoutGame) {
         horizontal: 2,
     });
 
-    // Calculer l'offset pour centrer le terrain
-    let field_start_x = inner_area.x + (inner_area.width.saturating_sub(FIELD_WIDTH)) / 2;
-    let field_start_y = inner_area.y + (inner_area.height.saturating_sub(FIELD_HEIGHT)) / 2;
+    // Le terrain prend toute la place disponible, dans des limites jouables
+    let max_width = match game.mode {
+        GameMode::Solo => MAX_FIELD_WIDTH,
+        GameMode::Coop => MAX_COOP_FIELD_WIDTH,
+    };
+    let field_width = inner_area.width.clamp(MIN_FIELD_WIDTH, max_width);
+    let field_height = inner_area.height.clamp(MIN_FIELD_HEIGHT, MAX_FIELD_HEIGHT);
+    game.update_dimensions(field_width, field_height);
+
+    // Calculer l'offset pour centrer le terrain, décalé pendant une secousse
+    let shake = game.shake.map_or(0, |shake| shake.offset());
+    let field_start_x = (inner_area.x + (inner_area.width.saturating_sub(field_width)) / 2)
+        .saturating_add_signed(shake)
+        .max(inner_area.x);
+    let field_start_y = inner_area.y + (inner_area.height.saturating_sub(field_height)) / 2;
+    let layout = game.layout;
 
     // Dessiner les briques
-    for row in &game.bricks {
-        for brick in row {
+    for (row_index, row) in game.bricks.iter().enumerate() {
+        for (col, brick) in row.iter().enumerate() {
             if !brick.destroyed {
-                let brick_x = field_start_x + brick.x;
-                let brick_y = field_start_y + brick.y;
+                let (x, y) = layout.origin(row_index, col);
+                let brick_x = field_start_x + x;
+                let brick_y = field_start_y + y;
 
                 // Vérifier les limites avant de dessiner
-                if brick_x + BRICK_WIDTH <= inner_area.x + inner_area.width
+                if brick_x + layout.width <= inner_area.x + inner_area.width
                     && brick_y + BRICK_HEIGHT <= inner_area.y + inner_area.height
                 {
                     let brick_area = Rect {
                         x: brick_x,
                         y: brick_y,
-                        width: BRICK_WIDTH,
+                        width: layout.width,
                         height: BRICK_HEIGHT,
                     };
 
-                    let brick_widget = Paragraph::new("█".repeat(BRICK_WIDTH as usize))
+                    let brick_widget = Paragraph::new("█".repeat(layout.width as usize))
                         .style(Style::default().fg(brick.color).bold());
 
                     frame.render_widget(brick_widget, brick_area);
@@ -586,26 +1076,52 @@ fn draw_breakout_game(frame: &mut ratatui::Frame, game: &BreakoutGame) {
         }
     }
 
-    // Dessiner la raquette
-    let paddle_x = field_start_x + game.paddle.x as u16;
-    let paddle_y = field_start_y + game.paddle.y as u16;
+    // Briques détruites qui s'effritent encore
+    for crumble in &game.crumbling {
+        let (x, y) = layout.origin(crumble.row, crumble.col);
+        let crumble_area = Rect::new(field_start_x + x, field_start_y + y, layout.width, 1)
+            .intersection(inner_area);
+        let crumble_widget = Paragraph::new(crumble.glyph().repeat(layout.width as usize))
+            .style(Style::default().fg(crumble.color));
+        frame.render_widget(crumble_widget, crumble_area);
+    }
 
-    if paddle_x + PADDLE_WIDTH <= inner_area.x + inner_area.width
-        && paddle_y + PADDLE_HEIGHT <= inner_area.y + inner_area.height
-    {
-        let paddle_area = Rect {
-            x: paddle_x,
-            y: paddle_y,
-            width: PADDLE_WIDTH,
-            height: PADDLE_HEIGHT,
+    // Dessiner les raquettes, celle du joueur 2 en cyan
+    let paddles = [
+        (Some(game.paddle), Color::White),
+        (game.partner, Color::Cyan),
+    ];
+    for (paddle, color) in paddles {
+        let Some(paddle) = paddle else {
+            continue;
         };
+        let paddle_x = field_start_x + paddle.x as u16;
+        let paddle_y = field_start_y + paddle.y as u16;
+        let paddle_width = paddle.width as u16;
+
+        if paddle_x + paddle_width <= inner_area.x + inner_area.width
+            && paddle_y + PADDLE_HEIGHT <= inner_area.y + inner_area.height
+        {
+            let paddle_area = Rect {
+                x: paddle_x,
+                y: paddle_y,
+                width: paddle_width,
+                height: PADDLE_HEIGHT,
+            };
 
-        let paddle_widget = Paragraph::new("═".repeat(PADDLE_WIDTH as usize))
-            .style(Style::default().fg(Color::White).bold());
+            let paddle_widget = Paragraph::new("═".repeat(paddle_width as usize))
+                .style(Style::default().fg(color).bold());
 
-        frame.render_widget(paddle_widget, paddle_area);
+            frame.render_widget(paddle_widget, paddle_area);
+        }
     }
 
+    // Traînée de la balle, sous la balle
+    game.trail
+        .render(frame.buffer_mut(), inner_area, (230, 200, 0), |x, y| {
+            (field_start_x + x as u16, field_start_y + y as u16)
+        });
+
     // Dessiner la balle
     let ball_x = field_start_x + game.ball.x as u16;
     let ball_y = field_start_y + game.ball.y as u16;
@@ -623,8 +1139,36 @@ fn draw_breakout_game(frame: &mut ratatui::Frame, game: &BreakoutGame) {
         frame.render_widget(ball_widget, ball_area);
     }
 
+    game.particles.render(
+        frame.buffer_mut(),
+        (field_start_x, field_start_y),
+        inner_area,
+    );
+
     // === FOOTER ===
     let instructions = match game.state {
+        GameState::Playing if game.mode == GameMode::Coop => vec![
+            Line::from(vec![
+                "A/D".cyan().bold(),
+                " Player 1  ".white(),
+                "←→".cyan().bold(),
+                " Player 2  ".white(),
+                "SPACE".green().bold(),
+                " Launch  ".white(),
+                "P".yellow().bold(),
+                " Pause".white(),
+            ]),
+            Line::from(vec![
+                "R".green().bold(),
+                " Restart  ".white(),
+                "Q".red().bold(),
+                " Quit  ".white(),
+                "M".yellow().bold(),
+                " Music  ".white(),
+                "N".yellow().bold(),
+                " Sound Effects".white(),
+            ]),
+        ],
         GameState::Playing => {
             if game.ball_stuck {
                 vec![
@@ -641,6 +1185,8 @@ fn draw_breakout_game(frame: &mut ratatui::Frame, game: &BreakoutGame) {
                         " Quit".white(),
                     ]),
                     Line::from(vec![
+                        "H".magenta().bold(),
+                        " Assist  ".white(),
                         "M".yellow().bold(),
                         " Music  ".white(),
                         "N".yellow().bold(),
@@ -660,6 +1206,8 @@ fn draw_breakout_game(frame: &mut ratatui::Frame, game: &BreakoutGame) {
                         " Quit".white(),
                     ]),
                     Line::from(vec![
+                        "H".magenta().bold(),
+                        " Assist  ".white(),
                         "M".yellow().bold(),
                         " Music  ".white(),
                         "N".yellow().bold(),
@@ -696,6 +1244,8 @@ fn draw_breakout_game(frame: &mut ratatui::Frame, game: &BreakoutGame) {
                 "  ".white(),
                 "R".green().bold(),
                 " Restart  ".white(),
+                "B".cyan().bold(),
+                " Modes  ".white(),
                 "Q".red().bold(),
                 " Quit".white(),
             ]),
@@ -706,6 +1256,7 @@ fn draw_breakout_game(frame: &mut ratatui::Frame, game: &BreakoutGame) {
                 " Sound Effects".white(),
             ]),
         ],
+        GameState::Menu => Vec::new(),
     };
 
     let footer = Paragraph::new(instructions)
@@ -723,16 +1274,8 @@ fn draw_breakout_game(frame: &mut ratatui::Frame, game: &BreakoutGame) {
         let popup_width = 40.min(area.width);
         let popup_height = 8.min(area.height);
         let popup_area = Rect {
-            x: if area.width >= popup_width {
-                (area.width - popup_width) / 2
-            } else {
-                0
-            },
-            y: if area.height >= popup_height {
-                (area.height - popup_height) / 2
-            } else {
-                0
-            },
+            x: area.x + (area.width - popup_width) / 2,
+            y: area.y + (area.height - popup_height) / 2,
             width: popup_width,
             height: popup_height,
         };
@@ -745,7 +1288,7 @@ fn draw_breakout_game(frame: &mut ratatui::Frame, game: &BreakoutGame) {
             Line::from(""),
             Line::from(vec![
                 "Final Score: ".white(),
-                format!("{}", game.score).yellow().bold(),
+                numbers::score(game.score).yellow().bold(),
             ]),
             Line::from(""),
             Line::from(vec![
@@ -771,16 +1314,8 @@ fn draw_breakout_game(frame: &mut ratatui::Frame, game: &BreakoutGame) {
         let popup_width = 40.min(area.width);
         let popup_height = 8.min(area.height);
         let popup_area = Rect {
-            x: if area.width >= popup_width {
-                (area.width - popup_width) / 2
-            } else {
-                0
-            },
-            y: if area.height >= popup_height {
-                (area.height - popup_height) / 2
-            } else {
-                0
-            },
+            x: area.x + (area.width - popup_width) / 2,
+            y: area.y + (area.height - popup_height) / 2,
             width: popup_width,
             height: popup_height,
         };
@@ -793,7 +1328,7 @@ fn draw_breakout_game(frame: &mut ratatui::Frame, game: &BreakoutGame) {
             Line::from(""),
             Line::from(vec![
                 "Final Score: ".white(),
-                format!("{}", game.score).yellow().bold(),
+                numbers::score(game.score).yellow().bold(),
             ]),
             Line::from(""),
             Line::from(vec![
@@ -817,3 +1352,94 @@ fn draw_breakout_game(frame: &mut ratatui::Frame, game: &BreakoutGame) {
         frame.render_widget(popup, popup_area);
     }
 }
+
+fn draw_mode_selection(frame: &mut ratatui::Frame, area: Rect, game: &mut BreakoutGame) {
+    let chunks = Layout::vertical([
+        Constraint::Length(6), // Header
+        Constraint::Min(0),    // Menu
+        Constraint::Length(3), // Footer
+    ])
+    .split(area);
+
+    // Header
+    let header_text = vec![
+        Line::from(""),
+        Line::from(vec![
+            format!("{} ", glyphs::icon("🧱")).yellow().bold(),
+            "BREAKOUT".cyan().bold(),
+            format!(" {}", glyphs::icon("🧱")).yellow().bold(),
+        ]),
+        Line::from("Choose your game mode".magenta()),
+        Line::from(""),
+    ];
+
+    let header = Paragraph::new(header_text)
+        .alignment(ratatui::layout::Alignment::Center)
+        .block(
+            Block::bordered()
+                .title(" Game Selection ".white().bold())
+                .border_style(Style::new().cyan())
+                .style(Style::default().bg(Color::Rgb(25, 35, 45))),
+        );
+    frame.render_widget(header, chunks[0]);
+
+    // Menu options
+    let items: Vec<ListItem> = MODES
+        .iter()
+        .map(|(_, label)| ListItem::new(Line::from(glyphs::text(label)).centered()))
+        .collect();
+
+    let block = Block::bordered()
+        .title(" Select Mode ".green().bold())
+        .border_style(Style::new().green())
+        .style(Style::default().bg(Color::Rgb(5, 10, 15)));
+    let highlight = style::focus(Color::Yellow);
+
+    game.mode_list
+        .render(frame, chunks[1], items, block, highlight);
+
+    // Footer
+    let footer_text = vec![Line::from(vec![
+        "↑↓".cyan().bold(),
+        " Navigate  ".white(),
+        "Enter".green().bold(),
+        " Select  ".white(),
+        "Q".red().bold(),
+        " Quit".white(),
+    ])];
+
+    let footer = Paragraph::new(footer_text)
+        .alignment(ratatui::layout::Alignment::Center)
+        .block(
+            Block::bordered()
+                .title(" Controls ".white().bold())
+                .border_style(Style::new().blue())
+                .style(Style::default().bg(Color::Rgb(25, 35, 45))),
+        );
+    frame.render_widget(footer, chunks[2]);
+}
+
+/// Aperçu du menu : le mur entamé, la balle et la raquette. Le chiffre est
+/// la rangée de la brique
+const PREVIEW: [&str; 9] = [
+    "000000000000",
+    "111..1111111",
+    "2222...22222",
+    "333333.33333",
+    "............",
+    "........o...",
+    "............",
+    "............",
+    ".....===....",
+];
+
+fn draw_preview(frame: &mut ratatui::Frame, area: Rect) {
+    preview::draw_board(frame, area, &PREVIEW, |cell| match cell {
+        '=' => "▀▀".fg(Color::White),
+        'o' => "● ".fg(Color::Yellow).bold(),
+        _ => match cell.to_digit(10) {
+            Some(row) => "▆▆".fg(Brick::new(row as usize).color),
+            None => "  ".into(),
+        },
+    });
+}
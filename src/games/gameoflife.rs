@@ -1,14 +1,23 @@
 use crate::audio::{AudioManager, SoundEffect};
-use crate::core::{Game, GameAction};
-use crate::highscores::{GameData, HighScoreManager, Score};
-use crossterm::event::{KeyCode, KeyEvent};
+use crate::core::clock::GameClock;
+use crate::core::grid::Grid2D;
+use crate::core::life::{self, CellState};
+use crate::core::rng;
+use crate::core::{Game, GameAction, GameStatus};
+use crate::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use crate::highscores::{current_player_name, GameData, HighScoreManager, Score};
+use crate::jobs::{self, Job, JobPoll};
+use crate::stamps::{self, Stamp, STAMP_NAME_MAX_LEN};
+use crate::ui::split_screen::SplitScreen;
+use crate::ui::{glyphs, preview, style, SelectableList, TextInput};
 use rand::Rng;
 use ratatui::{
-    layout::{Constraint, Layout, Rect},
+    layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Style, Stylize},
-    text::Line,
-    widgets::{Block, Paragraph},
+    text::{Line, Span},
+    widgets::{Block, Clear, ListItem, Paragraph},
 };
+use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
 // Tailles de grille prédéfinies
@@ -24,11 +33,11 @@ const HUGE_HEIGHT: usize = 60;
 const MAX_GRID_WIDTH: usize = HUGE_WIDTH;
 const MAX_GRID_HEIGHT: usize = HUGE_HEIGHT;
 
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum CellState {
-    Dead,
-    Alive,
-}
+/// Largeur d'une cellule à l'écran (2 caractères pour un aspect carré)
+const CELL_WIDTH: usize = 2;
+
+/// Densité du remplissage aléatoire, en dixièmes
+const DEFAULT_FILL_DENSITY: u8 = 3;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum GameState {
@@ -37,6 +46,49 @@ pub enum GameState {
     Editing,
 }
 
+/// Calque des voisins vivants sur les cellules mortes, changé avec H
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NeighborOverlay {
+    #[default]
+    Off,
+    /// Fond coloré selon le nombre de voisins
+    Heatmap,
+    /// Fond coloré et nombre de voisins affiché
+    Digits,
+}
+
+impl NeighborOverlay {
+    fn next(self) -> Self {
+        match self {
+            NeighborOverlay::Off => NeighborOverlay::Heatmap,
+            NeighborOverlay::Heatmap => NeighborOverlay::Digits,
+            NeighborOverlay::Digits => NeighborOverlay::Off,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            NeighborOverlay::Off => "Off",
+            NeighborOverlay::Heatmap => "Heat",
+            NeighborOverlay::Digits => "Heat+#",
+        }
+    }
+}
+
+/// Fond d'une cellule morte selon ses voisins vivants : froid pour 1 ou 2,
+/// vert vif pour 3 (elle naît à la génération suivante), chaud au-delà
+fn heat_color(neighbors: u8) -> Option<Color> {
+    match neighbors {
+        0 => None,
+        1 => Some(Color::Rgb(25, 35, 60)),
+        2 => Some(Color::Rgb(35, 55, 95)),
+        3 => Some(Color::Rgb(30, 120, 60)),
+        4 => Some(Color::Rgb(110, 80, 30)),
+        5 => Some(Color::Rgb(140, 65, 30)),
+        _ => Some(Color::Rgb(160, 40, 40)),
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum Pattern {
     Glider,
@@ -48,8 +100,8 @@ pub enum Pattern {
 }
 
 pub struct GameOfLife {
-    grid: [[CellState; MAX_GRID_WIDTH]; MAX_GRID_HEIGHT],
-    next_grid: [[CellState; MAX_GRID_WIDTH]; MAX_GRID_HEIGHT],
+    grid: Grid2D<CellState>,
+    next_grid: Grid2D<CellState>,
     state: GameState,
     generation: u32,
     cursor_x: usize,
@@ -57,26 +109,172 @@ pub struct GameOfLife {
     camera_x: usize, // Position de la caméra pour la vue
     camera_y: usize,
     speed: u8, // 1-5, plus élevé = plus rapide
-    grid_width: usize,
-    grid_height: usize,
 
     // Audio
     audio: AudioManager,
-    music_started: bool,
 
     // High scores
     highscore_manager: HighScoreManager,
-    start_time: std::time::Instant,
+    clock: GameClock,
     score_saved: bool,
     max_generations_reached: u32,
     population_history: Vec<u32>,
+
+    // Tampons
+    selection_anchor: Option<(usize, usize)>, // Coin fixe de la sélection, l'autre suit le curseur
+    stamp_name: Option<TextInput>,            // Saisie du nom de la sélection à enregistrer
+    stamp_picker: Option<StampPicker>,
+    stamp_status: Option<String>,
+    /// Enregistrement en cours d'un tampon, avec son nom
+    stamp_save: Option<(String, Job<Result<(), String>>)>,
+
+    // Souris
+    viewport: Viewport,
+    /// Dernière cellule peinte pendant un glisser, pour relier les suivantes
+    last_painted: Option<(usize, usize)>,
+    /// Rectangle en cours de tracé (coin fixe, coin sous la souris)
+    fill_rect: Option<((usize, usize), (usize, usize))>,
+    /// Densité des remplissages aléatoires, en dixièmes
+    fill_density: u8,
+    neighbor_overlay: NeighborOverlay,
+
+    // Instantanés de la session et comparaison A/B
+    snapshots: Vec<Snapshot>,
+    snapshot_name: Option<TextInput>,
+    snapshot_picker: Option<SnapshotPicker>,
+    comparison: Option<Comparison>,
+}
+
+/// Partie de la grille visible à l'écran lors du dernier rendu
+#[derive(Debug, Clone, Copy, Default)]
+struct Viewport {
+    /// Zone du terminal où sont dessinées les cellules
+    screen: Rect,
+    /// Cellule de la grille affichée en haut à gauche
+    origin_x: usize,
+    origin_y: usize,
+}
+
+impl Viewport {
+    /// Cellule de la grille affichée en (`column`, `row`) dans le terminal
+    fn cell_at(&self, column: u16, row: u16) -> Option<(usize, usize)> {
+        if !self.screen.contains((column, row).into()) {
+            return None;
+        }
+        Some((
+            self.origin_x + (column - self.screen.x) as usize / CELL_WIDTH,
+            self.origin_y + (row - self.screen.y) as usize,
+        ))
+    }
+}
+
+/// Rectangle (x min, y min, x max, y max) entre deux coins, bornes incluses
+fn ordered_rect(a: (usize, usize), b: (usize, usize)) -> (usize, usize, usize, usize) {
+    (a.0.min(b.0), a.1.min(b.1), a.0.max(b.0), a.1.max(b.1))
+}
+
+/// Cellules du segment de `from` à `to`, pour qu'un glisser rapide de la
+/// souris ne laisse pas de trous
+fn line_cells(from: (usize, usize), to: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+    let steps = from.0.abs_diff(to.0).max(from.1.abs_diff(to.1)).max(1);
+    let lerp = move |a: usize, b: usize, i: usize| {
+        (a as f32 + (b as f32 - a as f32) * i as f32 / steps as f32).round() as usize
+    };
+    (0..=steps).map(move |i| (lerp(from.0, to.0, i), lerp(from.1, to.1, i)))
+}
+
+/// Sélecteur de tampons affiché par-dessus la grille
+struct StampPicker {
+    stamps: Vec<Stamp>,
+    list: SelectableList,
+}
+
+/// Expérience interrompue, pour la reprendre (voir [`crate::saves`]) ; les
+/// cellules vivantes sont gardées en RLE, comme les tampons
+#[derive(Serialize, Deserialize)]
+struct SavedGame {
+    width: usize,
+    height: usize,
+    cells: String,
+    generation: u32,
+    speed: u8,
+    cursor: (usize, usize),
+    camera: (usize, usize),
+    max_generations_reached: u32,
+    population_history: Vec<u32>,
+    elapsed_ms: u64,
+}
+
+/// Grille entière enregistrée sous un nom, le temps de la session
+#[derive(Debug, Clone)]
+struct Snapshot {
+    name: String,
+    grid: Grid2D<CellState>,
+    generation: u32,
+}
+
+impl Snapshot {
+    fn population(&self) -> usize {
+        self.grid
+            .iter()
+            .filter(|&&cell| cell == CellState::Alive)
+            .count()
+    }
+
+    /// Avance d'une génération
+    fn step(&mut self) {
+        let (width, height) = (self.grid.width(), self.grid.height());
+        let mut next = Grid2D::new(width, height, CellState::Dead);
+        life::next_generation(
+            self.grid.as_slice(),
+            next.as_mut_slice(),
+            width,
+            width,
+            height,
+        );
+        self.grid = next;
+        self.generation += 1;
+    }
+}
+
+/// Sélecteur d'instantanés ; C y choisit les deux côtés d'une comparaison
+struct SnapshotPicker {
+    list: SelectableList,
+    /// Premier instantané choisi pour la comparaison
+    compare_first: Option<usize>,
+}
+
+/// Deux instantanés qui avancent côte à côte, génération par génération ;
+/// le côté qui a le focus peut être gardé dans la grille principale
+struct Comparison {
+    sides: [Snapshot; 2],
+    running: bool,
+    split: SplitScreen,
+}
+
+impl Comparison {
+    fn step(&mut self) {
+        for side in &mut self.sides {
+            side.step();
+        }
+    }
+}
+
+/// Première cellule affichée pour centrer `camera` quand seules `visible`
+/// cellules sur `total` tiennent à l'écran
+fn view_start(camera: usize, visible: usize, total: usize) -> usize {
+    if total > visible {
+        camera.saturating_sub(visible / 2).min(total - visible)
+    } else {
+        0
+    }
 }
 
 impl GameOfLife {
-    pub fn new() -> Self {
+    pub fn new(audio: AudioManager) -> Self {
         let mut game = Self {
-            grid: [[CellState::Dead; MAX_GRID_WIDTH]; MAX_GRID_HEIGHT],
-            next_grid: [[CellState::Dead; MAX_GRID_WIDTH]; MAX_GRID_HEIGHT],
+            grid: Grid2D::new(MEDIUM_WIDTH, MEDIUM_HEIGHT, CellState::Dead),
+            next_grid: Grid2D::new(MEDIUM_WIDTH, MEDIUM_HEIGHT, CellState::Dead),
             state: GameState::Editing,
             generation: 0,
             cursor_x: MEDIUM_WIDTH / 2,
@@ -84,66 +282,66 @@ impl GameOfLife {
             camera_x: MEDIUM_WIDTH / 2,
             camera_y: MEDIUM_HEIGHT / 2,
             speed: 3,
-            grid_width: MEDIUM_WIDTH,
-            grid_height: MEDIUM_HEIGHT,
 
-            audio: AudioManager::default(),
-            music_started: false,
+            audio,
 
             highscore_manager: HighScoreManager::default(),
-            start_time: std::time::Instant::now(),
+            clock: GameClock::new(),
             score_saved: false,
             max_generations_reached: 0,
             population_history: Vec::new(),
+
+            selection_anchor: None,
+            stamp_name: None,
+            stamp_picker: None,
+            stamp_status: None,
+            stamp_save: None,
+
+            viewport: Viewport::default(),
+            last_painted: None,
+            fill_rect: None,
+            fill_density: DEFAULT_FILL_DENSITY,
+            neighbor_overlay: NeighborOverlay::Off,
+
+            snapshots: Vec::new(),
+            snapshot_name: None,
+            snapshot_picker: None,
+            comparison: None,
         };
 
         // Commencer avec un pattern initial
-        game.place_pattern(Pattern::Glider, game.grid_width / 2, game.grid_height / 2);
+        game.place_pattern(
+            Pattern::Glider,
+            game.grid.width() / 2,
+            game.grid.height() / 2,
+        );
         game.place_pattern(
             Pattern::Blinker,
-            game.grid_width / 2 - 10,
-            game.grid_height / 2 - 5,
+            game.grid.width() / 2 - 10,
+            game.grid.height() / 2 - 5,
         );
         game.place_pattern(
             Pattern::Block,
-            game.grid_width / 2 + 10,
-            game.grid_height / 2 + 5,
+            game.grid.width() / 2 + 10,
+            game.grid.height() / 2 + 5,
         );
 
         game
     }
 
     fn start_music_if_needed(&mut self) {
-        if !self.music_started && self.audio.is_music_enabled() {
-            // Choisir la musique selon l'état et la vitesse
-            match self.state {
-                GameState::Running => {
-                    if self.speed >= 4 {
-                        self.audio.play_gameoflife_music_fast(); // Version dynamique pour vitesse élevée
-                    } else {
-                        self.audio.play_gameoflife_music(); // Version contemplative normale
-                    }
-                }
-                GameState::Editing | GameState::Paused => {
-                    self.audio.play_gameoflife_music(); // Version contemplative pour édition/pause
-                }
-            }
-            self.music_started = true;
+        if !self.audio.is_music_enabled() {
+            return;
         }
 
-        // Relancer la musique si elle est finie
-        if self.music_started && self.audio.is_music_enabled() && self.audio.is_music_empty() {
-            match self.state {
-                GameState::Running => {
-                    if self.speed >= 4 {
-                        self.audio.play_gameoflife_music_fast();
-                    } else {
-                        self.audio.play_gameoflife_music();
-                    }
-                }
-                GameState::Editing | GameState::Paused => {
-                    self.audio.play_gameoflife_music();
-                }
+        // Choisir la musique selon l'état et la vitesse ; un changement prend
+        // effet à la fin du tour en cours
+        match self.state {
+            GameState::Running if self.speed >= 4 => {
+                self.audio.play_gameoflife_music_fast(); // Version dynamique pour vitesse élevée
+            }
+            _ => {
+                self.audio.play_gameoflife_music(); // Version contemplative
             }
         }
     }
@@ -153,23 +351,10 @@ impl GameOfLife {
         let new_width = width.min(MAX_GRID_WIDTH);
         let new_height = height.min(MAX_GRID_HEIGHT);
 
-        // Créer une nouvelle grille vide
-        let mut new_grid = [[CellState::Dead; MAX_GRID_WIDTH]; MAX_GRID_HEIGHT];
-
-        // Copier les cellules existantes si elles rentrent dans la nouvelle taille
-        for (y, new_row) in new_grid
-            .iter_mut()
-            .enumerate()
-            .take(new_height.min(self.grid_height))
-        {
-            let width = new_width.min(self.grid_width);
-            new_row[..width].copy_from_slice(&self.grid[y][..width]);
-        }
-
-        self.grid = new_grid;
-        self.next_grid = [[CellState::Dead; MAX_GRID_WIDTH]; MAX_GRID_HEIGHT];
-        self.grid_width = new_width;
-        self.grid_height = new_height;
+        // Conserver les cellules existantes qui rentrent dans la nouvelle taille
+        self.grid.resize(new_width, new_height, CellState::Dead);
+        self.selection_anchor = None;
+        self.next_grid = Grid2D::new(new_width, new_height, CellState::Dead);
 
         // Ajuster la position du curseur et de la caméra
         self.cursor_x = self.cursor_x.min(new_width.saturating_sub(1));
@@ -181,26 +366,50 @@ impl GameOfLife {
     }
 
     fn clear_grid(&mut self) {
-        for y in 0..self.grid_height {
-            for x in 0..self.grid_width {
-                self.grid[y][x] = CellState::Dead;
-            }
-        }
+        self.grid.fill(CellState::Dead);
         self.generation = 0;
     }
 
     fn randomize_grid(&mut self) {
-        let mut rng = rand::rng();
-        for row in 0..self.grid_height {
-            for col in 0..self.grid_width {
-                self.grid[row][col] = if rng.random_bool(0.3) {
+        let mut rng = rng::rng();
+        let density = self.fill_density as f64 / 10.0;
+        for cell in self.grid.iter_mut() {
+            *cell = if rng.random_bool(density) {
+                CellState::Alive
+            } else {
+                CellState::Dead
+            };
+        }
+        self.generation = 0;
+    }
+
+    /// Remplit au hasard le rectangle entre deux coins, à la densité choisie
+    fn fill_random(&mut self, a: (usize, usize), b: (usize, usize)) {
+        let mut rng = rng::rng();
+        let density = self.fill_density as f64 / 10.0;
+        let (x0, y0, x1, y1) = ordered_rect(a, b);
+        for y in y0..=y1 {
+            for x in x0..=x1 {
+                let state = if rng.random_bool(density) {
                     CellState::Alive
                 } else {
                     CellState::Dead
                 };
+                self.grid.set(x, y, state);
             }
         }
-        self.generation = 0;
+        self.audio.play_sound(SoundEffect::GameOfLifePatternPlace);
+    }
+
+    fn change_fill_density(&mut self, delta: i8) {
+        self.fill_density = (self.fill_density as i8 + delta).clamp(1, 9) as u8;
+    }
+
+    fn in_fill_rect(&self, x: usize, y: usize) -> bool {
+        self.fill_rect.is_some_and(|(a, b)| {
+            let (x0, y0, x1, y1) = ordered_rect(a, b);
+            (x0..=x1).contains(&x) && (y0..=y1).contains(&y)
+        })
     }
 
     fn place_pattern(&mut self, pattern: Pattern, start_x: usize, start_y: usize) {
@@ -272,11 +481,17 @@ impl GameOfLife {
             ],
         };
 
-        for (dx, dy) in pattern_cells {
+        self.place_cells(pattern_cells, start_x, start_y);
+    }
+
+    /// Fait naître des cellules relatives au point de départ ; celles qui
+    /// sortent de la grille sont ignorées
+    fn place_cells(&mut self, cells: Vec<(usize, usize)>, start_x: usize, start_y: usize) {
+        for (dx, dy) in cells {
             let x = start_x + dx;
             let y = start_y + dy;
-            if x < self.grid_width && y < self.grid_height {
-                self.grid[y][x] = CellState::Alive;
+            if let Some(cell) = self.grid.get_mut(x, y) {
+                *cell = CellState::Alive;
             }
         }
 
@@ -284,56 +499,276 @@ impl GameOfLife {
         self.audio.play_sound(SoundEffect::GameOfLifePatternPlace);
     }
 
-    fn count_neighbors(&self, x: usize, y: usize) -> u8 {
-        let mut count = 0;
+    /// Rectangle sélectionné (x min, y min, x max, y max), bornes incluses
+    fn selection_rect(&self) -> Option<(usize, usize, usize, usize)> {
+        let anchor = self.selection_anchor?;
+        Some(ordered_rect(anchor, (self.cursor_x, self.cursor_y)))
+    }
+
+    fn is_selected(&self, x: usize, y: usize) -> bool {
+        self.selection_rect()
+            .is_some_and(|(x0, y0, x1, y1)| (x0..=x1).contains(&x) && (y0..=y1).contains(&y))
+    }
+
+    /// Cellules vivantes de la sélection, en coordonnées de la grille
+    fn selected_cells(&self) -> Vec<(usize, usize)> {
+        let Some((x0, y0, x1, y1)) = self.selection_rect() else {
+            return Vec::new();
+        };
+        (y0..=y1)
+            .flat_map(|y| (x0..=x1).map(move |x| (x, y)))
+            .filter(|&(x, y)| self.grid.get(x, y) == Some(&CellState::Alive))
+            .collect()
+    }
+
+    /// Enregistre la sélection sous le nom saisi, en tâche de fond
+    fn save_selection_as_stamp(&mut self, name: &str) {
+        let status = match Stamp::from_cells(name, &self.selected_cells()) {
+            Some(stamp) => {
+                let job = jobs::spawn("Saving stamp", move || {
+                    stamps::save(stamp).map_err(|e| e.to_string())
+                });
+                self.stamp_save = Some((name.to_string(), job));
+                format!("Saving stamp \"{name}\"…")
+            }
+            None => "Selection is empty".to_string(),
+        };
+        self.stamp_status = Some(status);
+        self.selection_anchor = None;
+    }
 
-        for dy in -1..=1i32 {
-            for dx in -1..=1i32 {
-                if dx == 0 && dy == 0 {
-                    continue;
+    /// Message de fin d'enregistrement du tampon, une fois la tâche terminée
+    fn poll_stamp_save(&mut self) {
+        let Some((name, job)) = &self.stamp_save else {
+            return;
+        };
+        let status = match job.poll() {
+            JobPoll::Running => return,
+            JobPoll::Finished(Ok(())) => format!("Saved stamp \"{name}\""),
+            JobPoll::Finished(Err(e)) => format!("Could not save stamp: {e}"),
+            JobPoll::Lost => "Could not save stamp".to_string(),
+        };
+        self.stamp_status = Some(status);
+        self.stamp_save = None;
+    }
+
+    fn open_stamp_picker(&mut self) {
+        self.selection_anchor = None;
+        self.stamp_picker = Some(StampPicker {
+            stamps: stamps::load_all(),
+            list: SelectableList::new(),
+        });
+    }
+
+    fn handle_stamp_name_key(&mut self, key: KeyEvent) -> GameAction {
+        let Some(input) = &mut self.stamp_name else {
+            return GameAction::Continue;
+        };
+
+        match key.code {
+            KeyCode::Enter => {
+                let name = input.value().trim().to_string();
+                if !name.is_empty() {
+                    self.stamp_name = None;
+                    self.save_selection_as_stamp(&name);
                 }
+            }
+            KeyCode::Esc => self.stamp_name = None,
+            _ => {
+                input.handle_key(key);
+            }
+        }
+        GameAction::Continue
+    }
+
+    fn handle_stamp_picker_key(&mut self, key: KeyEvent) -> GameAction {
+        let Some(picker) = &mut self.stamp_picker else {
+            return GameAction::Continue;
+        };
 
-                let nx = x as i32 + dx;
-                let ny = y as i32 + dy;
+        if picker.list.handle_key(key, picker.stamps.len()) {
+            return GameAction::Continue;
+        }
 
-                if nx >= 0 && nx < self.grid_width as i32 && ny >= 0 && ny < self.grid_height as i32
-                {
-                    let nx = nx as usize;
-                    let ny = ny as usize;
-                    if self.grid[ny][nx] == CellState::Alive {
-                        count += 1;
-                    }
+        match key.code {
+            KeyCode::Enter | KeyCode::Char(' ') => {
+                if let Some(stamp) = picker.stamps.get(picker.list.selected()) {
+                    let cells = stamp.cells();
+                    self.stamp_status = Some(format!("Placed stamp \"{}\"", stamp.name));
+                    self.stamp_picker = None;
+                    self.place_cells(cells, self.cursor_x, self.cursor_y);
                 }
             }
+            KeyCode::Char('d') | KeyCode::Delete => {
+                if let Some(stamp) = picker.stamps.get(picker.list.selected()) {
+                    let name = stamp.name.clone();
+                    self.stamp_status = Some(match stamps::delete(&name) {
+                        Ok(()) => format!("Deleted stamp \"{name}\""),
+                        Err(e) => format!("Could not delete stamp: {e}"),
+                    });
+                    self.open_stamp_picker();
+                }
+            }
+            KeyCode::Esc | KeyCode::Char('t') | KeyCode::Char('q') => self.stamp_picker = None,
+            _ => {}
         }
+        GameAction::Continue
+    }
 
-        count
+    /// Enregistre la grille sous `name`, en remplaçant l'instantané du même nom
+    fn save_snapshot(&mut self, name: &str) {
+        let snapshot = Snapshot {
+            name: name.to_string(),
+            grid: self.grid.clone(),
+            generation: self.generation,
+        };
+        match self.snapshots.iter_mut().find(|s| s.name == name) {
+            Some(existing) => *existing = snapshot,
+            None => self.snapshots.push(snapshot),
+        }
+        self.stamp_status = Some(format!("Saved snapshot \"{name}\""));
     }
 
-    fn update_generation(&mut self) {
-        // Calculer la prochaine génération
-        for y in 0..self.grid_height {
-            for x in 0..self.grid_width {
-                let neighbors = self.count_neighbors(x, y);
-                let current_cell = self.grid[y][x];
-
-                self.next_grid[y][x] = match (current_cell, neighbors) {
-                    // Règle 1: Une cellule vivante avec moins de 2 voisins meurt (sous-population)
-                    (CellState::Alive, n) if n < 2 => CellState::Dead,
-                    // Règle 2: Une cellule vivante avec 2 ou 3 voisins survit
-                    (CellState::Alive, 2..=3) => CellState::Alive,
-                    // Règle 3: Une cellule vivante avec plus de 3 voisins meurt (surpopulation)
-                    (CellState::Alive, n) if n > 3 => CellState::Dead,
-                    // Règle 4: Une cellule morte avec exactement 3 voisins devient vivante (reproduction)
-                    (CellState::Dead, 3) => CellState::Alive,
-                    // Toutes les autres cellules restent dans leur état
-                    (state, _) => state,
-                };
+    /// Remet la grille dans l'état de l'instantané `index` ; une simulation
+    /// en cours se met en pause
+    fn restore_snapshot(&mut self, index: usize) {
+        if let Some(snapshot) = self.snapshots.get(index).cloned() {
+            self.load_snapshot(snapshot);
+        }
+    }
+
+    fn load_snapshot(&mut self, snapshot: Snapshot) {
+        let (width, height) = (snapshot.grid.width(), snapshot.grid.height());
+        self.grid = snapshot.grid;
+        self.next_grid = Grid2D::new(width, height, CellState::Dead);
+        self.generation = snapshot.generation;
+        self.stamp_status = Some(format!("Restored snapshot \"{}\"", snapshot.name));
+
+        self.selection_anchor = None;
+        self.cursor_x = self.cursor_x.min(width - 1);
+        self.cursor_y = self.cursor_y.min(height - 1);
+        self.camera_x = self.camera_x.min(width - 1);
+        self.camera_y = self.camera_y.min(height - 1);
+        if self.state == GameState::Running {
+            self.state = GameState::Paused;
+        }
+    }
+
+    /// Lance la comparaison des instantanés `a` et `b`, à l'arrêt
+    fn start_comparison(&mut self, a: usize, b: usize) {
+        if let (Some(a), Some(b)) = (self.snapshots.get(a), self.snapshots.get(b)) {
+            self.comparison = Some(Comparison {
+                sides: [a.clone(), b.clone()],
+                running: false,
+                split: SplitScreen::new(Direction::Horizontal),
+            });
+            if self.state == GameState::Running {
+                self.state = GameState::Paused;
             }
         }
+    }
 
-        // Copier la nouvelle génération
-        self.grid = self.next_grid;
+    fn handle_snapshot_name_key(&mut self, key: KeyEvent) -> GameAction {
+        let Some(input) = &mut self.snapshot_name else {
+            return GameAction::Continue;
+        };
+
+        match key.code {
+            KeyCode::Enter => {
+                let name = input.value().trim().to_string();
+                if !name.is_empty() {
+                    self.snapshot_name = None;
+                    self.save_snapshot(&name);
+                }
+            }
+            KeyCode::Esc => self.snapshot_name = None,
+            _ => {
+                input.handle_key(key);
+            }
+        }
+        GameAction::Continue
+    }
+
+    fn handle_snapshot_picker_key(&mut self, key: KeyEvent) -> GameAction {
+        let Some(picker) = &mut self.snapshot_picker else {
+            return GameAction::Continue;
+        };
+        if picker.list.handle_key(key, self.snapshots.len()) {
+            return GameAction::Continue;
+        }
+
+        let selected = picker.list.selected();
+        match key.code {
+            KeyCode::Enter if selected < self.snapshots.len() => {
+                self.snapshot_picker = None;
+                self.restore_snapshot(selected);
+            }
+            KeyCode::Char('c') if selected < self.snapshots.len() => match picker.compare_first {
+                Some(first) if first != selected => {
+                    self.snapshot_picker = None;
+                    self.start_comparison(first, selected);
+                }
+                Some(_) => picker.compare_first = None,
+                None => picker.compare_first = Some(selected),
+            },
+            KeyCode::Char('d') if selected < self.snapshots.len() => {
+                self.snapshots.remove(selected);
+                picker.compare_first = None;
+                picker.list.jump_to(selected, self.snapshots.len());
+            }
+            KeyCode::Esc | KeyCode::Char('l') | KeyCode::Char('q') => self.snapshot_picker = None,
+            _ => {}
+        }
+        GameAction::Continue
+    }
+
+    /// Touches de la comparaison : la grille principale ne bouge pas
+    fn handle_comparison_key(&mut self, key: KeyEvent) -> GameAction {
+        let Some(comparison) = &mut self.comparison else {
+            return GameAction::Continue;
+        };
+        let Some((focus, key)) = comparison.split.route_key(key) else {
+            return GameAction::Continue;
+        };
+
+        match key.code {
+            KeyCode::Enter => {
+                let mut kept = comparison.sides[focus].clone();
+                kept.name = format!("{} (gen {})", kept.name, kept.generation);
+                self.comparison = None;
+                self.load_snapshot(kept);
+            }
+            KeyCode::Char('p') | KeyCode::Char(' ') => comparison.running = !comparison.running,
+            KeyCode::Char('n') if !comparison.running => comparison.step(),
+            KeyCode::Up | KeyCode::Char('w') => self.camera_y = self.camera_y.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('s') => {
+                self.camera_y = (self.camera_y + 1).min(MAX_GRID_HEIGHT - 1)
+            }
+            KeyCode::Left | KeyCode::Char('a') => self.camera_x = self.camera_x.saturating_sub(1),
+            KeyCode::Right | KeyCode::Char('d') => {
+                self.camera_x = (self.camera_x + 1).min(MAX_GRID_WIDTH - 1)
+            }
+            KeyCode::Char('+') | KeyCode::Char('=') => self.change_speed(1),
+            KeyCode::Char('-') => self.change_speed(-1),
+            KeyCode::Esc | KeyCode::Char('q') => self.comparison = None,
+            _ => {}
+        }
+        GameAction::Continue
+    }
+
+    fn update_generation(&mut self) {
+        // Calculer la prochaine génération (en parallèle sur les grandes grilles)
+        let (width, height) = (self.grid.width(), self.grid.height());
+        life::next_generation(
+            self.grid.as_slice(),
+            self.next_grid.as_mut_slice(),
+            width,
+            width,
+            height,
+        );
+
+        // La nouvelle génération devient la grille courante
+        std::mem::swap(&mut self.grid, &mut self.next_grid);
         self.generation += 1;
 
         // Mettre à jour les statistiques pour les high scores
@@ -353,8 +788,8 @@ impl GameOfLife {
     }
 
     fn toggle_cell(&mut self, x: usize, y: usize) {
-        if x < self.grid_width && y < self.grid_height {
-            self.grid[y][x] = match self.grid[y][x] {
+        if let Some(cell) = self.grid.get_mut(x, y) {
+            *cell = match *cell {
                 CellState::Alive => CellState::Dead,
                 CellState::Dead => CellState::Alive,
             };
@@ -363,6 +798,70 @@ impl GameOfLife {
         }
     }
 
+    /// Clic pour basculer une cellule, glisser pour peindre (Maj pour
+    /// effacer), Ctrl ou Alt + glisser pour remplir un rectangle au hasard
+    fn handle_mouse_paint(&mut self, mouse: MouseEvent) {
+        let cell = self.viewport.cell_at(mouse.column, mouse.row);
+        let erase = mouse.modifiers.contains(KeyModifiers::SHIFT);
+        let fill = mouse
+            .modifiers
+            .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT);
+
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                let Some((x, y)) = cell else {
+                    return;
+                };
+                self.move_cursor_to(x, y);
+                if fill {
+                    self.fill_rect = Some(((x, y), (x, y)));
+                    return;
+                }
+                if erase {
+                    self.grid.set(x, y, CellState::Dead);
+                } else {
+                    self.toggle_cell(x, y);
+                }
+                self.last_painted = Some((x, y));
+            }
+            MouseEventKind::Drag(MouseButton::Left) => {
+                let Some((x, y)) = cell else {
+                    return;
+                };
+                self.move_cursor_to(x, y);
+                if let Some((anchor, _)) = self.fill_rect {
+                    self.fill_rect = Some((anchor, (x, y)));
+                    return;
+                }
+                let state = if erase {
+                    CellState::Dead
+                } else {
+                    CellState::Alive
+                };
+                let from = self.last_painted.unwrap_or((x, y));
+                for (px, py) in line_cells(from, (x, y)) {
+                    self.grid.set(px, py, state);
+                }
+                self.last_painted = Some((x, y));
+            }
+            MouseEventKind::Up(MouseButton::Left) => {
+                if let Some((a, b)) = self.fill_rect.take() {
+                    self.fill_random(a, b);
+                }
+                self.last_painted = None;
+            }
+            _ => {}
+        }
+    }
+
+    /// En édition, le curseur clavier suit la souris
+    fn move_cursor_to(&mut self, x: usize, y: usize) {
+        if self.state == GameState::Editing {
+            self.cursor_x = x;
+            self.cursor_y = y;
+        }
+    }
+
     fn step_forward(&mut self) {
         self.update_generation();
     }
@@ -383,15 +882,11 @@ impl GameOfLife {
     }
 
     fn count_population(&self) -> u32 {
-        let mut count = 0;
-        for y in 0..self.grid_height {
-            for x in 0..self.grid_width {
-                if self.grid[y][x] == CellState::Alive {
-                    count += 1;
-                }
-            }
-        }
-        count
+        self.grid
+            .as_slice()
+            .iter()
+            .filter(|&&cell| cell == CellState::Alive)
+            .count() as u32
     }
 
     fn save_high_score_if_needed(&mut self) {
@@ -401,7 +896,7 @@ impl GameOfLife {
         }
 
         // Calculer un score basé sur les générations atteintes et la stabilité
-        let duration = self.start_time.elapsed().as_secs();
+        let duration = self.clock.elapsed_secs();
         let _current_population = self.count_population();
 
         // Score de base sur les générations
@@ -425,17 +920,17 @@ impl GameOfLife {
         if final_score >= 50
             && self
                 .highscore_manager
-                .is_high_score("gameoflife", final_score)
+                .is_high_score("game-of-life", final_score)
         {
             let game_data = GameData::GameOfLife {
                 generations: self.max_generations_reached,
                 duration_seconds: duration,
             };
 
-            let score = Score::new("Anonymous".to_string(), final_score, game_data);
+            let score = Score::new(current_player_name(), final_score, game_data);
 
             // Sauvegarder le score
-            if let Ok(_is_top_10) = self.highscore_manager.add_score("gameoflife", score) {
+            if let Ok(_new_score) = self.highscore_manager.add_score("game-of-life", score) {
                 self.score_saved = true;
             }
         }
@@ -444,6 +939,23 @@ impl GameOfLife {
 
 impl Game for GameOfLife {
     fn handle_key(&mut self, key: KeyEvent) -> GameAction {
+        // Les fenêtres de tampons captent toutes les touches
+        if self.stamp_name.is_some() {
+            return self.handle_stamp_name_key(key);
+        }
+        if self.stamp_picker.is_some() {
+            return self.handle_stamp_picker_key(key);
+        }
+        if self.snapshot_name.is_some() {
+            return self.handle_snapshot_name_key(key);
+        }
+        if self.snapshot_picker.is_some() {
+            return self.handle_snapshot_picker_key(key);
+        }
+        if self.comparison.is_some() {
+            return self.handle_comparison_key(key);
+        }
+
         match key.code {
             // Contrôles de mouvement
             KeyCode::Up | KeyCode::Char('w') => {
@@ -466,13 +978,13 @@ impl Game for GameOfLife {
             KeyCode::Down | KeyCode::Char('s') => {
                 match self.state {
                     GameState::Editing => {
-                        if self.cursor_y < self.grid_height - 1 {
+                        if self.cursor_y < self.grid.height() - 1 {
                             self.cursor_y += 1;
                             self.camera_y = self.cursor_y;
                         }
                     }
                     _ => {
-                        if self.camera_y < self.grid_height - 1 {
+                        if self.camera_y < self.grid.height() - 1 {
                             self.camera_y += 1;
                         }
                     }
@@ -498,13 +1010,13 @@ impl Game for GameOfLife {
             KeyCode::Right | KeyCode::Char('d') => {
                 match self.state {
                     GameState::Editing => {
-                        if self.cursor_x < self.grid_width - 1 {
+                        if self.cursor_x < self.grid.width() - 1 {
                             self.cursor_x += 1;
                             self.camera_x = self.cursor_x;
                         }
                     }
                     _ => {
-                        if self.camera_x < self.grid_width - 1 {
+                        if self.camera_x < self.grid.width() - 1 {
                             self.camera_x += 1;
                         }
                     }
@@ -512,6 +1024,16 @@ impl Game for GameOfLife {
                 GameAction::Continue
             }
 
+            // Enregistrer la sélection en cours comme tampon
+            KeyCode::Enter | KeyCode::Char(' ') if self.selection_anchor.is_some() => {
+                self.stamp_name = Some(TextInput::new(STAMP_NAME_MAX_LEN));
+                GameAction::Continue
+            }
+            KeyCode::Esc if self.selection_anchor.is_some() => {
+                self.selection_anchor = None;
+                GameAction::Continue
+            }
+
             // Toggle cellule en mode édition
             KeyCode::Enter | KeyCode::Char(' ') => {
                 if self.state == GameState::Editing {
@@ -520,8 +1042,42 @@ impl Game for GameOfLife {
                 GameAction::Continue
             }
 
+            // Tampons
+            KeyCode::Char('v') => {
+                if self.state == GameState::Editing {
+                    self.selection_anchor = match self.selection_anchor {
+                        Some(_) => None,
+                        None => Some((self.cursor_x, self.cursor_y)),
+                    };
+                    self.stamp_status = None;
+                }
+                GameAction::Continue
+            }
+            KeyCode::Char('t') => {
+                if self.state == GameState::Editing {
+                    self.open_stamp_picker();
+                }
+                GameAction::Continue
+            }
+
+            // Instantanés
+            KeyCode::Char('k') => {
+                self.selection_anchor = None;
+                self.snapshot_name = Some(TextInput::new(STAMP_NAME_MAX_LEN));
+                GameAction::Continue
+            }
+            KeyCode::Char('l') => {
+                self.selection_anchor = None;
+                self.snapshot_picker = Some(SnapshotPicker {
+                    list: SelectableList::new(),
+                    compare_first: None,
+                });
+                GameAction::Continue
+            }
+
             // Contrôles de simulation
             KeyCode::Char('p') => {
+                self.selection_anchor = None;
                 let old_state = self.state;
                 self.state = match self.state {
                     GameState::Running => GameState::Paused,
@@ -531,17 +1087,15 @@ impl Game for GameOfLife {
                 // Son de changement d'état
                 if old_state != self.state {
                     self.audio.play_sound(SoundEffect::GameOfLifeStateChange);
-                    // Redémarrer la musique si on change d'état
-                    self.music_started = false;
                 }
                 GameAction::Continue
             }
             KeyCode::Char('e') => {
+                self.selection_anchor = None;
                 let old_state = self.state;
                 self.state = GameState::Editing;
                 if old_state != self.state {
                     self.audio.play_sound(SoundEffect::GameOfLifeStateChange);
-                    self.music_started = false;
                 }
                 GameAction::Continue
             }
@@ -551,6 +1105,10 @@ impl Game for GameOfLife {
                 }
                 GameAction::Continue
             }
+            KeyCode::Char('h') => {
+                self.neighbor_overlay = self.neighbor_overlay.next();
+                GameAction::Continue
+            }
 
             // Contrôles de vitesse
             KeyCode::Char('+') | KeyCode::Char('=') => {
@@ -558,10 +1116,6 @@ impl Game for GameOfLife {
                 self.change_speed(1);
                 if old_speed != self.speed {
                     self.audio.play_sound(SoundEffect::GameOfLifeStateChange);
-                    // Redémarrer la musique si on change de vitesse en mode running
-                    if self.state == GameState::Running {
-                        self.music_started = false;
-                    }
                 }
                 GameAction::Continue
             }
@@ -570,9 +1124,6 @@ impl Game for GameOfLife {
                 self.change_speed(-1);
                 if old_speed != self.speed {
                     self.audio.play_sound(SoundEffect::GameOfLifeStateChange);
-                    if self.state == GameState::Running {
-                        self.music_started = false;
-                    }
                 }
                 GameAction::Continue
             }
@@ -642,10 +1193,21 @@ impl Game for GameOfLife {
                 self.randomize_grid();
                 GameAction::Continue
             }
+            KeyCode::Char('[') => {
+                self.change_fill_density(-1);
+                GameAction::Continue
+            }
+            KeyCode::Char(']') => {
+                self.change_fill_density(1);
+                GameAction::Continue
+            }
 
             // Contrôles audio
             KeyCode::Char('m') => {
                 self.audio.toggle_music();
+                if self.audio.is_music_enabled() {
+                    self.start_music_if_needed();
+                }
                 GameAction::Continue
             }
             KeyCode::Char('x') => {
@@ -665,29 +1227,166 @@ impl Game for GameOfLife {
 
     fn update(&mut self) -> GameAction {
         self.start_music_if_needed();
+        self.poll_stamp_save();
 
-        if self.state == GameState::Running {
+        if let Some(comparison) = &mut self.comparison {
+            if comparison.running {
+                comparison.step();
+            }
+        } else if self.state == GameState::Running {
             self.update_generation();
         }
         GameAction::Continue
     }
 
-    fn draw(&mut self, frame: &mut ratatui::Frame) {
-        draw_game_of_life(frame, self);
+    fn preview(&self, frame: &mut ratatui::Frame, area: Rect) {
+        draw_preview(frame, area);
+    }
+
+    fn draw(&mut self, frame: &mut ratatui::Frame, area: Rect) {
+        draw_game_of_life(frame, area, self);
     }
 
     fn tick_rate(&self) -> Duration {
-        if self.state == GameState::Running {
+        let running = match &self.comparison {
+            Some(comparison) => comparison.running,
+            None => self.state == GameState::Running,
+        };
+        if running {
             self.get_tick_rate()
         } else {
             Duration::from_millis(100)
         }
     }
-}
 
-fn draw_game_of_life(frame: &mut ratatui::Frame, game: &GameOfLife) {
-    let area = frame.area();
+    fn status(&self) -> GameStatus {
+        if self.state == GameState::Paused {
+            GameStatus::Paused
+        } else {
+            GameStatus::Running
+        }
+    }
 
+    fn title_progress(&self) -> Option<String> {
+        (self.generation > 0).then(|| format!("gen {}", self.generation))
+    }
+
+    fn on_pause(&mut self) {
+        if self.state == GameState::Running {
+            self.state = GameState::Paused;
+        }
+    }
+
+    fn captures_esc(&self) -> bool {
+        self.stamp_name.is_some()
+            || self.stamp_picker.is_some()
+            || self.selection_anchor.is_some()
+            || self.snapshot_name.is_some()
+            || self.snapshot_picker.is_some()
+            || self.comparison.is_some()
+    }
+
+    fn instant_restart(&self) -> bool {
+        true // R remplit la grille au hasard
+    }
+
+    fn captures_volume_keys(&self) -> bool {
+        true // Vitesse de la simulation
+    }
+
+    fn audio(&self) -> Option<&AudioManager> {
+        Some(&self.audio)
+    }
+
+    fn handle_paste(&mut self, text: &str) {
+        if let Some(input) = self.stamp_name.as_mut().or(self.snapshot_name.as_mut()) {
+            input.insert_str(text);
+        }
+    }
+
+    fn handle_mouse(&mut self, mouse: MouseEvent) {
+        // Pendant une comparaison, un clic donne le focus à un côté
+        if let Some(comparison) = &mut self.comparison {
+            comparison.split.route_mouse(mouse);
+            return;
+        }
+        // Les fenêtres et la comparaison passent avant la grille
+        if self.stamp_name.is_none()
+            && self.stamp_picker.is_none()
+            && self.snapshot_name.is_none()
+            && self.snapshot_picker.is_none()
+        {
+            self.handle_mouse_paint(mouse);
+        }
+    }
+
+    fn on_shutdown(&mut self) {
+        // Même sauvegarde que lorsqu'on quitte avec Q
+        self.save_high_score_if_needed();
+    }
+
+    fn save_state(&self) -> Option<serde_json::Value> {
+        let (width, height) = (self.grid.width(), self.grid.height());
+        let alive: Vec<(usize, usize)> = self
+            .grid
+            .rows()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.iter()
+                    .enumerate()
+                    .filter(|(_, &cell)| cell == CellState::Alive)
+                    .map(move |(x, _)| (x, y))
+            })
+            .collect();
+        serde_json::to_value(SavedGame {
+            width,
+            height,
+            cells: stamps::encode_rle(&alive, width, height),
+            generation: self.generation,
+            speed: self.speed,
+            cursor: (self.cursor_x, self.cursor_y),
+            camera: (self.camera_x, self.camera_y),
+            max_generations_reached: self.max_generations_reached,
+            population_history: self.population_history.clone(),
+            elapsed_ms: self.clock.elapsed().as_millis() as u64,
+        })
+        .ok()
+    }
+
+    fn restore_state(&mut self, state: serde_json::Value) -> bool {
+        let Ok(saved) = serde_json::from_value::<SavedGame>(state) else {
+            return false;
+        };
+        let (width, height) = (saved.width, saved.height);
+        if width == 0 || height == 0 || width > MAX_GRID_WIDTH || height > MAX_GRID_HEIGHT {
+            return false;
+        }
+        self.grid = Grid2D::new(width, height, CellState::Dead);
+        for (x, y) in stamps::decode_rle(&saved.cells) {
+            self.grid.set(x, y, CellState::Alive);
+        }
+        self.next_grid = Grid2D::new(width, height, CellState::Dead);
+        self.generation = saved.generation;
+        self.speed = saved.speed.clamp(1, 5);
+        self.cursor_x = saved.cursor.0.min(width - 1);
+        self.cursor_y = saved.cursor.1.min(height - 1);
+        self.camera_x = saved.camera.0.min(width - 1);
+        self.camera_y = saved.camera.1.min(height - 1);
+        self.max_generations_reached = saved.max_generations_reached;
+        self.population_history = saved.population_history;
+        self.clock = GameClock::resumed_from(Duration::from_millis(saved.elapsed_ms));
+        self.score_saved = false;
+        // La simulation attend le joueur
+        self.state = if self.generation > 0 {
+            GameState::Paused
+        } else {
+            GameState::Editing
+        };
+        true
+    }
+}
+
+fn draw_game_of_life(frame: &mut ratatui::Frame, area: Rect, game: &mut GameOfLife) {
     // Layout principal
     let chunks = Layout::vertical([
         Constraint::Length(4), // Header avec infos
@@ -697,8 +1396,13 @@ fn draw_game_of_life(frame: &mut ratatui::Frame, game: &GameOfLife) {
     .split(area);
 
     // Fond sombre
-    let background = Block::new().style(Style::default().bg(Color::Black));
-    frame.render_widget(background, area);
+    style::fill_area(frame, area, Color::Black);
+
+    if let Some(comparison) = &mut game.comparison {
+        let camera = (game.camera_x, game.camera_y);
+        draw_comparison(frame, &chunks, comparison, game.speed, camera);
+        return;
+    }
 
     // === HEADER ===
     let state_text = match game.state {
@@ -706,12 +1410,17 @@ fn draw_game_of_life(frame: &mut ratatui::Frame, game: &GameOfLife) {
         GameState::Paused => "PAUSED".yellow().bold(),
         GameState::Editing => "EDITING".cyan().bold(),
     };
+    let audio_status = if game.audio.is_enabled() {
+        glyphs::icon("🔊")
+    } else {
+        glyphs::icon("🔇")
+    };
 
     let header_text = vec![
         Line::from(vec![
-            "🧬 ".green().bold(),
+            format!("{} ", glyphs::icon("🧬")).green().bold(),
             "GAME OF LIFE".cyan().bold(),
-            " 🧬".green().bold(),
+            format!(" {}", glyphs::icon("🧬")).green().bold(),
         ]),
         Line::from(vec![
             "Gen: ".white(),
@@ -721,9 +1430,17 @@ fn draw_game_of_life(frame: &mut ratatui::Frame, game: &GameOfLife) {
             "  Speed: ".white(),
             format!("{}/5", game.speed).green().bold(),
             "  Size: ".white(),
-            format!("{}x{}", game.grid_width, game.grid_height)
+            format!("{}x{}", game.grid.width(), game.grid.height())
                 .cyan()
                 .bold(),
+            "  Fill: ".white(),
+            format!("{}%", game.fill_density as u32 * 10)
+                .magenta()
+                .bold(),
+            "  Neighbors: ".white(),
+            game.neighbor_overlay.label().yellow().bold(),
+            "  Audio: ".white(),
+            audio_status.white(),
         ]),
     ];
 
@@ -751,29 +1468,16 @@ fn draw_game_of_life(frame: &mut ratatui::Frame, game: &GameOfLife) {
     });
 
     // Calculer les dimensions des cellules (comme Snake)
-    let cell_width = 2; // Largeur de chaque cellule (2 caractères pour un aspect carré)
+    let cell_width = CELL_WIDTH;
     let cell_height = 1; // Hauteur de chaque cellule
 
     // Calculer combien de cellules on peut afficher
-    let cells_per_row = (inner_area.width as usize / cell_width).min(game.grid_width);
-    let cells_per_col = (inner_area.height as usize / cell_height).min(game.grid_height);
+    let cells_per_row = (inner_area.width as usize / cell_width).min(game.grid.width());
+    let cells_per_col = (inner_area.height as usize / cell_height).min(game.grid.height());
 
     // Calculer l'offset pour centrer la vue sur la caméra
-    let start_x = if game.grid_width > cells_per_row {
-        game.camera_x
-            .saturating_sub(cells_per_row / 2)
-            .min(game.grid_width - cells_per_row)
-    } else {
-        0
-    };
-
-    let start_y = if game.grid_height > cells_per_col {
-        game.camera_y
-            .saturating_sub(cells_per_col / 2)
-            .min(game.grid_height - cells_per_col)
-    } else {
-        0
-    };
+    let start_x = view_start(game.camera_x, cells_per_row, game.grid.width());
+    let start_y = view_start(game.camera_y, cells_per_col, game.grid.height());
 
     // Calculer le centrage de la grille dans la zone disponible
     let total_grid_width = cells_per_row * cell_width;
@@ -782,6 +1486,16 @@ fn draw_game_of_life(frame: &mut ratatui::Frame, game: &GameOfLife) {
         inner_area.x + (inner_area.width as usize).saturating_sub(total_grid_width) as u16 / 2;
     let grid_start_y =
         inner_area.y + (inner_area.height as usize).saturating_sub(total_grid_height) as u16 / 2;
+    game.viewport = Viewport {
+        screen: Rect::new(
+            grid_start_x,
+            grid_start_y,
+            total_grid_width as u16,
+            total_grid_height as u16,
+        ),
+        origin_x: start_x,
+        origin_y: start_y,
+    };
 
     // Dessiner la grille cellule par cellule
     for display_y in 0..cells_per_col {
@@ -789,9 +1503,9 @@ fn draw_game_of_life(frame: &mut ratatui::Frame, game: &GameOfLife) {
             let grid_x = start_x + display_x;
             let grid_y = start_y + display_y;
 
-            if grid_x >= game.grid_width || grid_y >= game.grid_height {
+            let Some(&cell) = game.grid.get(grid_x, grid_y) else {
                 continue;
-            }
+            };
 
             let cell_x = grid_start_x + (display_x * cell_width) as u16;
             let cell_y = grid_start_y + display_y as u16;
@@ -809,18 +1523,49 @@ fn draw_game_of_life(frame: &mut ratatui::Frame, game: &GameOfLife) {
                 && grid_y == game.cursor_y
             {
                 // Curseur en mode édition
-                match game.grid[grid_y][grid_x] {
+                match cell {
                     CellState::Alive => (
-                        "██",
+                        "██".to_string(),
                         Style::default().bg(Color::Yellow).fg(Color::Green).bold(),
                     ),
-                    CellState::Dead => ("  ", Style::default().bg(Color::Yellow)),
+                    CellState::Dead => ("  ".to_string(), Style::default().bg(Color::Yellow)),
                 }
             } else {
                 // Cellule normale
-                match game.grid[grid_y][grid_x] {
-                    CellState::Alive => ("██", Style::default().fg(Color::Green).bold()),
-                    CellState::Dead => ("  ", Style::default().bg(Color::Rgb(20, 25, 30))),
+                let neighbors = match (cell, game.neighbor_overlay) {
+                    (CellState::Dead, NeighborOverlay::Heatmap | NeighborOverlay::Digits) => {
+                        life::count_neighbors(
+                            game.grid.as_slice(),
+                            game.grid.width(),
+                            game.grid.width(),
+                            game.grid.height(),
+                            grid_x,
+                            grid_y,
+                        )
+                    }
+                    _ => 0,
+                };
+                let background = if game.in_fill_rect(grid_x, grid_y) {
+                    Color::Rgb(90, 65, 20)
+                } else if game.is_selected(grid_x, grid_y) {
+                    Color::Rgb(40, 60, 100)
+                } else {
+                    heat_color(neighbors).unwrap_or(Color::Rgb(20, 25, 30))
+                };
+                match cell {
+                    CellState::Alive => (
+                        "██".into(),
+                        Style::default().fg(Color::Green).bg(background).bold(),
+                    ),
+                    CellState::Dead
+                        if neighbors > 0 && game.neighbor_overlay == NeighborOverlay::Digits =>
+                    {
+                        (
+                            format!("{neighbors:>2}"),
+                            Style::default().fg(Color::Gray).bg(background),
+                        )
+                    }
+                    CellState::Dead => ("  ".into(), Style::default().bg(background)),
                 }
             };
 
@@ -843,7 +1588,11 @@ fn draw_game_of_life(frame: &mut ratatui::Frame, game: &GameOfLife) {
                 "N".blue().bold(),
                 " Step  ".white(),
                 "1-6".magenta().bold(),
-                " Patterns".white(),
+                " Patterns  ".white(),
+                "V".cyan().bold(),
+                " Select  ".white(),
+                "T".magenta().bold(),
+                " Stamps".white(),
             ]),
             Line::from(vec![
                 "F1-F4".cyan().bold(),
@@ -861,7 +1610,13 @@ fn draw_game_of_life(frame: &mut ratatui::Frame, game: &GameOfLife) {
                 "M".yellow().bold(),
                 " Music  ".white(),
                 "X".yellow().bold(),
-                " Sound Effects".white(),
+                " Sound Effects  ".white(),
+                "H".magenta().bold(),
+                " Neighbors  ".white(),
+                "K".cyan().bold(),
+                " Snapshot  ".white(),
+                "L".cyan().bold(),
+                " Snapshots".white(),
             ]),
         ],
         GameState::Running => vec![
@@ -892,7 +1647,13 @@ fn draw_game_of_life(frame: &mut ratatui::Frame, game: &GameOfLife) {
                 "M".yellow().bold(),
                 " Music  ".white(),
                 "X".yellow().bold(),
-                " Sound Effects".white(),
+                " Sound Effects  ".white(),
+                "H".magenta().bold(),
+                " Neighbors  ".white(),
+                "K".cyan().bold(),
+                " Snapshot  ".white(),
+                "L".cyan().bold(),
+                " Snapshots".white(),
             ]),
         ],
         GameState::Paused => vec![
@@ -923,7 +1684,13 @@ fn draw_game_of_life(frame: &mut ratatui::Frame, game: &GameOfLife) {
                 "M".yellow().bold(),
                 " Music  ".white(),
                 "X".yellow().bold(),
-                " Sound Effects".white(),
+                " Sound Effects  ".white(),
+                "H".magenta().bold(),
+                " Neighbors  ".white(),
+                "K".cyan().bold(),
+                " Snapshot  ".white(),
+                "L".cyan().bold(),
+                " Snapshots".white(),
             ]),
         ],
     };
@@ -942,13 +1709,15 @@ fn draw_game_of_life(frame: &mut ratatui::Frame, game: &GameOfLife) {
     if game.state == GameState::Editing {
         // Afficher l'aide des patterns dans un coin
         let help_width = 32;
-        let help_height = 14;
+        let help_height = 22;
+        // Rognée sur les petits terminaux plutôt que dessinée hors de l'écran
         let help_area = Rect {
-            x: area.width.saturating_sub(help_width),
-            y: chunks[0].height,
+            x: area.right().saturating_sub(help_width),
+            y: chunks[0].bottom(),
             width: help_width,
             height: help_height,
-        };
+        }
+        .intersection(area);
 
         let help_text = vec![
             Line::from(" Patterns:".yellow().bold()),
@@ -964,6 +1733,13 @@ fn draw_game_of_life(frame: &mut ratatui::Frame, game: &GameOfLife) {
             Line::from(" F2 - Medium (60x30)".white()),
             Line::from(" F3 - Large (80x40)".white()),
             Line::from(" F4 - Huge (120x60)".white()),
+            Line::from(""),
+            Line::from(" Stamps:".magenta().bold()),
+            Line::from(" V - Select, Enter - Save".white()),
+            Line::from(" T - Stamp library".white()),
+            Line::from(""),
+            Line::from(" Snapshots:".cyan().bold()),
+            Line::from(" K - Save, L - Restore/Compare".white()),
         ];
 
         let help_popup = Paragraph::new(help_text).block(
@@ -974,5 +1750,462 @@ fn draw_game_of_life(frame: &mut ratatui::Frame, game: &GameOfLife) {
         );
 
         frame.render_widget(help_popup, help_area);
+
+        if let Some(status) = &game.stamp_status {
+            let status_area = Rect {
+                x: help_area.x,
+                y: help_area.bottom(),
+                width: help_width,
+                height: 3,
+            }
+            .intersection(area);
+            frame.render_widget(
+                Paragraph::new(status.as_str().white()).block(
+                    Block::bordered()
+                        .border_style(Style::new().magenta())
+                        .style(Style::default().bg(Color::Rgb(20, 20, 30))),
+                ),
+                status_area,
+            );
+        }
+    }
+
+    if let Some(input) = &game.stamp_name {
+        draw_name_prompt(frame, area, " Save Stamp ", input);
+    }
+    if let Some(picker) = &mut game.stamp_picker {
+        draw_stamp_picker(frame, area, picker);
+    }
+    if let Some(input) = &game.snapshot_name {
+        draw_name_prompt(frame, area, " Save Snapshot ", input);
+    }
+    if let Some(picker) = &mut game.snapshot_picker {
+        draw_snapshot_picker(frame, area, picker, &game.snapshots);
+    }
+}
+
+/// Comparaison A/B : les deux instantanés côte à côte, les cellules qui ne
+/// vivent que d'un côté en jaune
+fn draw_comparison(
+    frame: &mut ratatui::Frame,
+    chunks: &[Rect],
+    comparison: &mut Comparison,
+    speed: u8,
+    camera: (usize, usize),
+) {
+    let [a, b] = &comparison.sides;
+    let state_text = if comparison.running {
+        "RUNNING".green().bold()
+    } else {
+        "PAUSED".yellow().bold()
+    };
+    let header = Paragraph::new(vec![
+        Line::from(vec![
+            format!("{} ", glyphs::icon("🧬")).green().bold(),
+            "A/B COMPARISON".cyan().bold(),
+            format!(" {}", glyphs::icon("🧬")).green().bold(),
+        ]),
+        Line::from(vec![
+            "Gen: ".white(),
+            format!("{}", a.generation).yellow().bold(),
+            " / ".white(),
+            format!("{}", b.generation).yellow().bold(),
+            "  State: ".white(),
+            state_text,
+            "  Speed: ".white(),
+            format!("{}/5", speed).green().bold(),
+        ]),
+    ])
+    .alignment(ratatui::layout::Alignment::Center)
+    .block(
+        Block::bordered()
+            .title(" Conway's Game of Life ".white().bold())
+            .border_style(Style::new().cyan())
+            .style(Style::default().bg(Color::Rgb(25, 35, 45))),
+    );
+    frame.render_widget(header, chunks[0]);
+
+    let running = comparison.running;
+    let sides = &comparison.sides;
+    let focus = comparison.split.focus();
+    comparison.split.render(frame, chunks[1], |pane, frame| {
+        let (label, side, other) = match pane {
+            0 => ("A", &sides[0], &sides[1]),
+            _ => ("B", &sides[1], &sides[0]),
+        };
+        let area = frame.area();
+        draw_comparison_side(frame, area, label, side, other, pane == focus, camera);
+    });
+
+    let footer = Paragraph::new(vec![
+        Line::from(vec![
+            "P".yellow().bold(),
+            if running {
+                " Pause  ".white()
+            } else {
+                " Run  ".white()
+            },
+            "N".blue().bold(),
+            " Step  ".white(),
+            "↑↓←→".cyan().bold(),
+            " Pan  ".white(),
+            "±".cyan().bold(),
+            " Speed  ".white(),
+            "Tab".cyan().bold(),
+            " Switch Side  ".white(),
+            "Enter".green().bold(),
+            " Keep Side  ".white(),
+            "Esc".red().bold(),
+            " Leave".white(),
+        ]),
+        Line::from("Yellow cells are alive on one side only".gray()),
+    ])
+    .alignment(ratatui::layout::Alignment::Center)
+    .block(
+        Block::bordered()
+            .title(" Controls ".white().bold())
+            .border_style(Style::new().blue())
+            .style(Style::default().bg(Color::Rgb(25, 35, 45))),
+    );
+    frame.render_widget(footer, chunks[2]);
+}
+
+fn draw_comparison_side(
+    frame: &mut ratatui::Frame,
+    area: Rect,
+    label: &str,
+    side: &Snapshot,
+    other: &Snapshot,
+    focused: bool,
+    camera: (usize, usize),
+) {
+    let accent = if focused { Color::Yellow } else { Color::Green };
+    let block = Block::bordered()
+        .title(format!(" {label}: {} ", side.name).fg(accent).bold())
+        .title_bottom(
+            format!(" Gen {}  Pop {} ", side.generation, side.population())
+                .white()
+                .bold(),
+        )
+        .border_style(Style::new().fg(accent))
+        .style(Style::default().bg(Color::Rgb(5, 10, 15)));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let (width, height) = (side.grid.width(), side.grid.height());
+    let columns = (inner.width as usize / CELL_WIDTH).min(width);
+    let rows = (inner.height as usize).min(height);
+    let start_x = view_start(camera.0, columns, width);
+    let start_y = view_start(camera.1, rows, height);
+    let left = inner.x + (inner.width as usize - columns * CELL_WIDTH) as u16 / 2;
+    let top = inner.y + (inner.height as usize - rows) as u16 / 2;
+
+    let buffer = frame.buffer_mut();
+    for row in 0..rows {
+        for column in 0..columns {
+            let (x, y) = (start_x + column, start_y + row);
+            let alive = side.grid.get(x, y) == Some(&CellState::Alive);
+            let alive_there = other.grid.get(x, y) == Some(&CellState::Alive);
+            let style = match (alive, alive_there) {
+                (true, true) => Style::default().fg(Color::Green),
+                (true, false) => Style::default().fg(Color::Yellow),
+                (false, _) => Style::default(),
+            };
+            let symbol = if alive { "██" } else { "  " };
+            buffer.set_string(
+                left + (column * CELL_WIDTH) as u16,
+                top + row as u16,
+                symbol,
+                style.bg(Color::Rgb(20, 25, 30)),
+            );
+        }
+    }
+}
+
+/// Rectangle centré de `width` x `height`, réduit à la taille de l'écran
+fn centered_rect(area: Rect, width: u16, height: u16) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    }
+}
+
+fn draw_name_prompt(frame: &mut ratatui::Frame, area: Rect, title: &str, input: &TextInput) {
+    let popup = centered_rect(area, 40, 6);
+    frame.render_widget(Clear, popup);
+    frame.render_widget(
+        Block::bordered()
+            .title(title.magenta().bold())
+            .border_style(Style::new().magenta())
+            .style(Style::default().bg(Color::Rgb(20, 20, 30))),
+        popup,
+    );
+
+    let [field, hint] = Layout::vertical([Constraint::Length(3), Constraint::Length(1)]).areas(
+        popup.inner(ratatui::layout::Margin {
+            vertical: 1,
+            horizontal: 1,
+        }),
+    );
+    input.render(
+        frame,
+        field,
+        Block::bordered().title(" Name ".white()),
+        Style::default().fg(Color::White),
+        true,
+    );
+    frame.render_widget(
+        Paragraph::new("Enter Save  Esc Cancel".gray())
+            .alignment(ratatui::layout::Alignment::Center),
+        hint,
+    );
+}
+
+fn draw_stamp_picker(frame: &mut ratatui::Frame, area: Rect, picker: &mut StampPicker) {
+    let popup = centered_rect(area, 44, 16);
+    frame.render_widget(Clear, popup);
+
+    let [list_area, hint] =
+        Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(popup);
+    let block = Block::bordered()
+        .title(" Stamp Library ".magenta().bold())
+        .border_style(Style::new().magenta())
+        .style(Style::default().bg(Color::Rgb(20, 20, 30)));
+
+    if picker.stamps.is_empty() {
+        frame.render_widget(
+            Paragraph::new(vec![
+                Line::from(""),
+                Line::from("No stamps saved yet".gray()),
+                Line::from("Select cells with V, then press Enter".gray()),
+            ])
+            .alignment(ratatui::layout::Alignment::Center)
+            .block(block),
+            list_area,
+        );
+    } else {
+        let items: Vec<ListItem> = picker
+            .stamps
+            .iter()
+            .map(|stamp| {
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        stamp.name.as_str(),
+                        Style::default().fg(Color::White).bold(),
+                    ),
+                    Span::styled(
+                        format!(
+                            "  {}x{}, {} cells",
+                            stamp.width,
+                            stamp.height,
+                            stamp.population()
+                        ),
+                        Style::default().fg(Color::Gray),
+                    ),
+                ]))
+            })
+            .collect();
+
+        picker.list.render(
+            frame,
+            list_area,
+            items,
+            block,
+            style::focus(Color::Rgb(60, 40, 80)),
+        );
+    }
+
+    frame.render_widget(
+        Paragraph::new("Enter Place  D Delete  Esc Close".gray())
+            .alignment(ratatui::layout::Alignment::Center)
+            .style(Style::default().bg(Color::Rgb(20, 20, 30))),
+        hint,
+    );
+}
+
+fn draw_snapshot_picker(
+    frame: &mut ratatui::Frame,
+    area: Rect,
+    picker: &mut SnapshotPicker,
+    snapshots: &[Snapshot],
+) {
+    let popup = centered_rect(area, 48, 16);
+    frame.render_widget(Clear, popup);
+
+    let [list_area, hint] =
+        Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(popup);
+    let block = Block::bordered()
+        .title(" Snapshots ".cyan().bold())
+        .border_style(Style::new().cyan())
+        .style(Style::default().bg(Color::Rgb(20, 20, 30)));
+
+    if snapshots.is_empty() {
+        frame.render_widget(
+            Paragraph::new(vec![
+                Line::from(""),
+                Line::from("No snapshots yet".gray()),
+                Line::from("Press K to save the grid under a name".gray()),
+            ])
+            .alignment(ratatui::layout::Alignment::Center)
+            .block(block),
+            list_area,
+        );
+    } else {
+        let items: Vec<ListItem> = snapshots
+            .iter()
+            .enumerate()
+            .map(|(index, snapshot)| {
+                let marker = if picker.compare_first == Some(index) {
+                    "A ".yellow().bold()
+                } else {
+                    "  ".into()
+                };
+                ListItem::new(Line::from(vec![
+                    marker,
+                    Span::styled(
+                        snapshot.name.as_str(),
+                        Style::default().fg(Color::White).bold(),
+                    ),
+                    Span::styled(
+                        format!(
+                            "  gen {}, {} cells",
+                            snapshot.generation,
+                            snapshot.population()
+                        ),
+                        Style::default().fg(Color::Gray),
+                    ),
+                ]))
+            })
+            .collect();
+
+        picker.list.render(
+            frame,
+            list_area,
+            items,
+            block,
+            style::focus(Color::Rgb(30, 50, 70)),
+        );
+    }
+
+    let hint_text = if picker.compare_first.is_some() {
+        "C Compare with A  Esc Close"
+    } else {
+        "Enter Restore  C Compare  D Delete  Esc Close"
+    };
+    frame.render_widget(
+        Paragraph::new(hint_text.gray())
+            .alignment(ratatui::layout::Alignment::Center)
+            .style(Style::default().bg(Color::Rgb(20, 20, 30))),
+        hint,
+    );
+}
+
+/// Aperçu du menu : un planeur, un clignotant et un bloc
+const PREVIEW: [&str; 9] = [
+    "............",
+    "..#.........",
+    "...#.....#..",
+    ".###.....#..",
+    ".........#..",
+    "............",
+    "....##......",
+    "....##......",
+    "............",
+];
+
+fn draw_preview(frame: &mut ratatui::Frame, area: Rect) {
+    preview::draw_board(frame, area, &PREVIEW, |cell| match cell {
+        '#' => "██".fg(Color::Green).bg(Color::Rgb(20, 25, 30)).bold(),
+        _ => "  ".bg(Color::Rgb(20, 25, 30)),
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn neighbor_overlay_cycles_and_marks_births() {
+        let overlay = NeighborOverlay::default();
+        assert_eq!(overlay.next(), NeighborOverlay::Heatmap);
+        assert_eq!(overlay.next().next(), NeighborOverlay::Digits);
+        assert_eq!(overlay.next().next().next(), NeighborOverlay::Off);
+
+        assert_eq!(heat_color(0), None);
+        // Une naissance ressort des voisinages trop vides ou trop pleins
+        let birth = heat_color(3);
+        assert!(birth.is_some() && birth != heat_color(2) && birth != heat_color(4));
+    }
+
+    #[test]
+    fn snapshots_restore_the_grid_and_compare_side_by_side() {
+        crate::test_support::isolate_data_dir();
+        let mut game = GameOfLife::new(AudioManager::silent());
+        game.clear_grid();
+        game.place_pattern(Pattern::Blinker, 10, 10);
+        game.save_snapshot("blinker");
+        game.clear_grid();
+        game.place_pattern(Pattern::Block, 10, 10);
+        game.save_snapshot("block");
+
+        game.restore_snapshot(0);
+        assert_eq!(game.count_population(), 3);
+        // Enregistrer sous un nom existant remplace l'instantané
+        game.save_snapshot("block");
+        assert_eq!(game.snapshots.len(), 2);
+        assert_eq!(game.snapshots[1].population(), 3);
+
+        game.clear_grid();
+        game.place_pattern(Pattern::Block, 10, 10);
+        game.save_snapshot("block");
+        game.start_comparison(0, 1);
+        let before = game.comparison.as_ref().unwrap().sides.clone();
+        game.handle_key(KeyEvent::from(KeyCode::Char('n')));
+
+        let comparison = game.comparison.as_ref().unwrap();
+        assert_eq!(comparison.sides[0].generation, 1);
+        // Le clignotant tourne, le bloc ne bouge pas
+        assert_ne!(comparison.sides[0].grid, before[0].grid);
+        assert_eq!(comparison.sides[1].grid, before[1].grid);
+        // La grille principale attend la fin de la comparaison
+        assert_eq!(game.generation, 0);
+
+        // Tab passe au côté B, Entrée le garde dans la grille principale
+        game.handle_key(KeyEvent::from(KeyCode::Tab));
+        game.handle_key(KeyEvent::from(KeyCode::Enter));
+        assert!(game.comparison.is_none());
+        assert_eq!(game.grid, before[1].grid);
+        assert_eq!(game.generation, 1);
+    }
+
+    #[test]
+    fn viewport_maps_both_columns_of_a_cell() {
+        let viewport = Viewport {
+            screen: Rect::new(4, 2, 20, 10),
+            origin_x: 30,
+            origin_y: 5,
+        };
+
+        assert_eq!(viewport.cell_at(4, 2), Some((30, 5)));
+        assert_eq!(viewport.cell_at(5, 2), Some((30, 5)));
+        assert_eq!(viewport.cell_at(6, 3), Some((31, 6)));
+        assert_eq!(viewport.cell_at(23, 11), Some((39, 14)));
+        assert_eq!(viewport.cell_at(24, 2), None);
+        assert_eq!(viewport.cell_at(3, 2), None);
+    }
+
+    #[test]
+    fn drag_lines_have_no_gaps() {
+        let cells: Vec<_> = line_cells((2, 1), (6, 3)).collect();
+
+        assert_eq!(cells.first(), Some(&(2, 1)));
+        assert_eq!(cells.last(), Some(&(6, 3)));
+        for pair in cells.windows(2) {
+            assert!(pair[0].0.abs_diff(pair[1].0) <= 1 && pair[0].1.abs_diff(pair[1].1) <= 1);
+        }
     }
 }
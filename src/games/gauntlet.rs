@@ -0,0 +1,619 @@
+//! Gauntlet : une suite d'étapes courtes dans plusieurs jeux, enchaînées
+//! avec un score cumulé.
+//!
+//! Chaque étape est jouée par le vrai jeu, arrêté à la fin de son temps
+//! limite ou quand son objectif est atteint. Les scores des étapes ne vont pas
+//! dans les classements de leur jeu (voir [`stage_running`]) : seul le total
+//! est enregistré, dans le classement du Gauntlet.
+
+use crate::audio::AudioManager;
+use crate::core::clock::GameClock;
+use crate::core::{Game, GameAction, GameStatus};
+use crate::event::{KeyCode, KeyEvent};
+use crate::games::{minesweeper::MinesweeperGame, snake::SnakeGame, tetris::TetrisGame};
+use crate::highscores::{current_player_name, GameData, HighScoreManager, Score};
+use crate::ui::{glyphs, numbers, style};
+use ratatui::{
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Clear, Paragraph},
+    Frame,
+};
+use std::cell::Cell;
+use std::time::Duration;
+
+/// Identifiant du classement des totaux
+const LEADERBOARD: &str = "gauntlet";
+
+thread_local! {
+    /// Vrai pendant qu'une étape est jouée, dans le thread qui la joue
+    static STAGE_RUNNING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Indique qu'une étape du Gauntlet est en cours : les jeux n'enregistrent
+/// alors pas leurs high scores
+pub fn stage_running() -> bool {
+    STAGE_RUNNING.get()
+}
+
+/// Calcul des points d'une étape
+#[derive(Debug, Clone, Copy)]
+enum Scoring {
+    /// Score du jeu
+    GameScore,
+    /// 25 points par ligne, plus 10 par seconde restante si l'objectif est
+    /// atteint
+    Lines,
+    /// 500 points et 5 par seconde restante pour une grille terminée, sinon
+    /// 5 par case ouverte
+    Board,
+}
+
+pub struct Stage {
+    pub game_id: &'static str,
+    pub name: &'static str,
+    pub goal: &'static str,
+    pub time_limit: Duration,
+    /// Avancement (voir [`Game::stage_progress`]) qui termine l'étape avant
+    /// la fin du temps
+    target: Option<u32>,
+    create: fn(AudioManager) -> Box<dyn Game>,
+    scoring: Scoring,
+    /// R relance l'étape sur-le-champ, temps remis à zéro
+    instant_restart: bool,
+}
+
+/// Étapes du Gauntlet, dans l'ordre
+pub const STAGES: [Stage; 3] = [
+    Stage {
+        game_id: "snake",
+        name: "Snake Dash",
+        goal: "Eat as much as you can in 30 seconds",
+        time_limit: Duration::from_secs(30),
+        target: None,
+        create: |audio| Box::new(SnakeGame::new(audio)),
+        scoring: Scoring::GameScore,
+        instant_restart: false,
+    },
+    Stage {
+        game_id: "tetris",
+        name: "Tetris Sprint",
+        goal: "Clear 40 lines as fast as you can",
+        time_limit: Duration::from_secs(180),
+        target: Some(40),
+        create: |audio| Box::new(TetrisGame::new(audio)),
+        scoring: Scoring::Lines,
+        instant_restart: true,
+    },
+    Stage {
+        game_id: "minesweeper",
+        name: "Beginner Board",
+        goal: "Clear a 9×9 board with 10 mines",
+        time_limit: Duration::from_secs(120),
+        target: None,
+        create: |audio| Box::new(MinesweeperGame::beginner(audio)),
+        scoring: Scoring::Board,
+        instant_restart: false,
+    },
+];
+
+impl Stage {
+    /// L'étape s'arrête à la fin du temps, à la fin de la partie ou quand
+    /// l'objectif est atteint
+    fn is_over(&self, game: &dyn Game, elapsed: Duration) -> bool {
+        elapsed >= self.time_limit
+            || matches!(game.status(), GameStatus::GameOver | GameStatus::Victory)
+            || self
+                .target
+                .is_some_and(|target| game.stage_progress().unwrap_or(0) >= target)
+    }
+
+    fn result(&self, game: &dyn Game, elapsed: Duration) -> StageResult {
+        let elapsed = elapsed.min(self.time_limit);
+        let seconds_left = (self.time_limit - elapsed).as_secs() as u32;
+        let progress = game.stage_progress().unwrap_or(0);
+        let (points, summary) = match self.scoring {
+            Scoring::GameScore => {
+                let score = game.session_score().unwrap_or(0);
+                (score, format!("{} pts", numbers::score(score)))
+            }
+            Scoring::Lines => {
+                let target = self.target.unwrap_or(0);
+                if progress >= target {
+                    (
+                        progress * 25 + seconds_left * 10,
+                        format!("{target} lines in {}", format_time(elapsed)),
+                    )
+                } else {
+                    (progress * 25, format!("{progress}/{target} lines"))
+                }
+            }
+            Scoring::Board => {
+                if game.status() == GameStatus::Victory {
+                    (
+                        500 + seconds_left * 5,
+                        format!("Cleared in {}", format_time(elapsed)),
+                    )
+                } else {
+                    (progress * 5, format!("{progress} cells opened"))
+                }
+            }
+        };
+        StageResult {
+            points,
+            summary,
+            elapsed,
+        }
+    }
+}
+
+/// Résultat d'une étape terminée
+#[derive(Debug, Clone)]
+pub struct StageResult {
+    pub points: u32,
+    /// Résumé affiché dans le tableau des étapes
+    pub summary: String,
+    pub elapsed: Duration,
+}
+
+enum Phase {
+    /// Annonce de l'étape suivante, avec les résultats déjà obtenus
+    Briefing,
+    Playing {
+        game: Box<dyn Game>,
+        /// Temps de l'étape, hors pauses
+        clock: GameClock,
+    },
+    /// Toutes les étapes sont jouées
+    Summary,
+}
+
+pub struct GauntletGame {
+    phase: Phase,
+    results: Vec<StageResult>,
+    highscore_manager: HighScoreManager,
+    /// Rang du total dans le classement, s'il y est entré
+    rank: Option<usize>,
+    /// Sortie audio transmise au jeu de chaque étape
+    audio: AudioManager,
+}
+
+impl GauntletGame {
+    pub fn new(audio: AudioManager) -> Self {
+        Self {
+            phase: Phase::Briefing,
+            results: Vec::new(),
+            highscore_manager: HighScoreManager::default(),
+            rank: None,
+            audio,
+        }
+    }
+
+    /// Étape en cours ou annoncée
+    fn stage(&self) -> Option<&'static Stage> {
+        STAGES.get(self.results.len())
+    }
+
+    pub fn total(&self) -> u32 {
+        self.results.iter().map(|result| result.points).sum()
+    }
+
+    fn start_stage(&mut self) {
+        let Some(stage) = self.stage() else {
+            return;
+        };
+        STAGE_RUNNING.set(true);
+        self.phase = Phase::Playing {
+            game: (stage.create)(self.audio.sibling()),
+            clock: GameClock::new(),
+        };
+    }
+
+    fn finish_stage(&mut self) {
+        let (Some(stage), Phase::Playing { game, clock }) = (self.stage(), &self.phase) else {
+            return;
+        };
+        let result = stage.result(game.as_ref(), clock.elapsed());
+        // Libérer le jeu de l'étape avant la suite
+        self.phase = Phase::Briefing;
+        STAGE_RUNNING.set(false);
+        self.results.push(result);
+
+        if self.stage().is_none() {
+            self.phase = Phase::Summary;
+            self.save_total();
+        }
+    }
+
+    fn save_total(&mut self) {
+        let total = self.total();
+        if total == 0 || !self.highscore_manager.is_high_score(LEADERBOARD, total) {
+            return;
+        }
+        let game_data = GameData::Gauntlet {
+            stage_points: self.results.iter().map(|result| result.points).collect(),
+            duration_seconds: self
+                .results
+                .iter()
+                .map(|result| result.elapsed.as_secs())
+                .sum(),
+        };
+        let score = Score::new(current_player_name(), total, game_data);
+        if let Ok(Some(new_score)) = self.highscore_manager.add_score(LEADERBOARD, score) {
+            self.rank = Some(new_score.rank);
+        }
+    }
+
+    fn restart(&mut self) {
+        self.phase = Phase::Briefing;
+        self.results.clear();
+        self.rank = None;
+    }
+}
+
+impl Drop for GauntletGame {
+    fn drop(&mut self) {
+        // Gauntlet abandonné en pleine étape
+        if matches!(self.phase, Phase::Playing { .. }) {
+            STAGE_RUNNING.set(false);
+        }
+    }
+}
+
+impl Game for GauntletGame {
+    fn handle_key(&mut self, key: KeyEvent) -> GameAction {
+        if key.code == KeyCode::Char('r')
+            && matches!(self.phase, Phase::Playing { .. })
+            && self.stage().is_some_and(|stage| stage.instant_restart)
+        {
+            self.start_stage();
+            return GameAction::Continue;
+        }
+        match &mut self.phase {
+            // Quitter le jeu d'une étape abandonne le Gauntlet
+            Phase::Playing { game, .. } => match game.handle_key(key) {
+                GameAction::Continue => GameAction::Continue,
+                _ => GameAction::Quit,
+            },
+            Phase::Briefing => match key.code {
+                KeyCode::Enter | KeyCode::Char(' ') => {
+                    self.start_stage();
+                    GameAction::Continue
+                }
+                KeyCode::Char('q') => GameAction::Quit,
+                _ => GameAction::Continue,
+            },
+            Phase::Summary => match key.code {
+                KeyCode::Enter | KeyCode::Char('r') => {
+                    self.restart();
+                    GameAction::Continue
+                }
+                KeyCode::Char('q') => GameAction::Quit,
+                _ => GameAction::Continue,
+            },
+        }
+    }
+
+    fn update(&mut self) -> GameAction {
+        let stage = self.stage();
+        let Phase::Playing { game, clock } = &mut self.phase else {
+            return GameAction::Continue;
+        };
+        let action = game.update();
+        let over = stage.is_none_or(|stage| stage.is_over(game.as_ref(), clock.elapsed()));
+        if action != GameAction::Continue || over {
+            self.finish_stage();
+        }
+        GameAction::Continue
+    }
+
+    fn draw(&mut self, frame: &mut Frame, area: Rect) {
+        let stage = self.stage();
+        match &mut self.phase {
+            Phase::Playing { game, clock } => {
+                game.draw(frame, area);
+                if let Some(stage) = stage {
+                    draw_stage_panel(frame, area, stage, self.results.len(), game.as_ref(), clock);
+                }
+            }
+            Phase::Briefing | Phase::Summary => draw_stage_table(frame, area, self),
+        }
+    }
+
+    fn tick_rate(&self) -> Duration {
+        match &self.phase {
+            Phase::Playing { game, .. } => game.tick_rate(),
+            _ => Duration::from_millis(100),
+        }
+    }
+
+    fn status(&self) -> GameStatus {
+        match &self.phase {
+            Phase::Playing { game, .. } => game.status(),
+            Phase::Briefing => GameStatus::Menu,
+            Phase::Summary => GameStatus::GameOver,
+        }
+    }
+
+    fn title_status(&self) -> Option<String> {
+        match &self.phase {
+            Phase::Playing { clock, .. } => self.stage().map(|stage| {
+                format!(
+                    "Stage {}/{} • {} left",
+                    self.results.len() + 1,
+                    STAGES.len(),
+                    format_time(stage.time_limit.saturating_sub(clock.elapsed()))
+                )
+            }),
+            _ => Some(format!("{} pts", numbers::score(self.total()))),
+        }
+    }
+
+    fn session_score(&self) -> Option<u32> {
+        matches!(self.phase, Phase::Summary).then(|| self.total())
+    }
+
+    fn on_shutdown(&mut self) {
+        if let Phase::Playing { game, .. } = &mut self.phase {
+            game.on_shutdown();
+        }
+    }
+
+    fn on_pause(&mut self) {
+        if let Phase::Playing { game, clock } = &mut self.phase {
+            game.on_pause();
+            clock.suspend();
+        }
+    }
+
+    fn on_resume(&mut self) {
+        if let Phase::Playing { game, clock } = &mut self.phase {
+            game.on_resume();
+            clock.unsuspend();
+        }
+    }
+
+    fn captures_esc(&self) -> bool {
+        match &self.phase {
+            Phase::Playing { game, .. } => game.captures_esc(),
+            _ => false,
+        }
+    }
+
+    fn instant_restart(&self) -> bool {
+        match &self.phase {
+            Phase::Playing { game, .. } => {
+                self.stage().is_some_and(|stage| stage.instant_restart) || game.instant_restart()
+            }
+            _ => false,
+        }
+    }
+
+    fn audio(&self) -> Option<&AudioManager> {
+        match &self.phase {
+            Phase::Playing { game, .. } => game.audio(),
+            _ => None,
+        }
+    }
+}
+
+/// Temps au format "m:ss"
+fn format_time(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
+
+/// Petit panneau en haut à gauche pendant une étape : numéro, temps restant
+/// et points déjà gagnés
+fn draw_stage_panel(
+    frame: &mut Frame,
+    area: Rect,
+    stage: &Stage,
+    index: usize,
+    game: &dyn Game,
+    clock: &GameClock,
+) {
+    let left = stage.time_limit.saturating_sub(clock.elapsed());
+    let mut lines = vec![Line::from(vec![format!("{} left", format_time(left))
+        .yellow()
+        .bold()])];
+    if let (Some(target), Some(progress)) = (stage.target, game.stage_progress()) {
+        lines.push(Line::from(format!("{progress}/{target}").fg(style::text())));
+    }
+
+    let width = 22.min(area.width);
+    let height = (lines.len() as u16 + 2).min(area.height);
+    let panel = Rect {
+        x: area.x,
+        y: area.y,
+        width,
+        height,
+    };
+    frame.render_widget(Clear, panel);
+    frame.render_widget(
+        Paragraph::new(lines).alignment(Alignment::Center).block(
+            Block::bordered()
+                .title(
+                    format!(
+                        " {} Stage {}/{} ",
+                        glyphs::icon("🎯"),
+                        index + 1,
+                        STAGES.len()
+                    )
+                    .magenta()
+                    .bold(),
+                )
+                .border_style(Style::new().magenta())
+                .style(Style::default().bg(style::surface())),
+        ),
+        panel,
+    );
+}
+
+/// Tableau des étapes : points obtenus, étape suivante et total
+fn draw_stage_table(frame: &mut Frame, area: Rect, gauntlet: &GauntletGame) {
+    style::fill_area(frame, area, style::background());
+
+    let chunks = Layout::vertical([
+        Constraint::Length(4),
+        Constraint::Min(0),
+        Constraint::Length(3),
+    ])
+    .split(area);
+
+    // === HEADER ===
+    let header = Paragraph::new(vec![
+        Line::from(vec![
+            format!("{} ", glyphs::icon("🎯")).magenta().bold(),
+            "GAUNTLET".cyan().bold(),
+            format!(" {}", glyphs::icon("🎯")).magenta().bold(),
+        ]),
+        Line::from(vec![
+            "Total: ".gray(),
+            format!("{} pts", numbers::score(gauntlet.total()))
+                .green()
+                .bold(),
+        ]),
+    ])
+    .alignment(Alignment::Center)
+    .block(
+        Block::bordered()
+            .border_style(Style::new().magenta())
+            .style(Style::default().bg(style::surface())),
+    );
+    frame.render_widget(header, chunks[0]);
+
+    // === ÉTAPES ===
+    let mut lines = vec![Line::from("")];
+    for (index, stage) in STAGES.iter().enumerate() {
+        let (mark, result) = match gauntlet.results.get(index) {
+            Some(result) => (
+                "✓".green().bold(),
+                format!(
+                    "{:>6} pts  {}",
+                    numbers::score(result.points),
+                    result.summary
+                )
+                .green(),
+            ),
+            None if index == gauntlet.results.len() => {
+                ("▶".yellow().bold(), "Next".yellow().bold())
+            }
+            None => ("·".dark_gray(), "".into()),
+        };
+        lines.push(Line::from(vec![
+            Span::raw("  "),
+            mark,
+            Span::styled(
+                format!(" {}. {:<16}", index + 1, stage.name),
+                Style::default().fg(style::text()).bold(),
+            ),
+            Span::styled(
+                format!("{:<12}", format_time(stage.time_limit)),
+                Style::default().fg(Color::Gray),
+            ),
+            result,
+        ]));
+        lines.push(Line::from(Span::styled(
+            format!("       {}", stage.goal),
+            Style::default().fg(Color::Gray),
+        )));
+        lines.push(Line::from(""));
+    }
+
+    if matches!(gauntlet.phase, Phase::Summary) {
+        lines.push(Line::from(vec![
+            "  Final score: ".fg(style::text()).bold(),
+            format!("{} pts", numbers::score(gauntlet.total()))
+                .green()
+                .bold(),
+        ]));
+        if let Some(rank) = gauntlet.rank {
+            lines.push(Line::from(
+                format!("  #{rank} in the Gauntlet leaderboard!")
+                    .yellow()
+                    .bold(),
+            ));
+        }
+    }
+
+    let title = if matches!(gauntlet.phase, Phase::Summary) {
+        " Results "
+    } else {
+        " Stages "
+    };
+    let stages = Paragraph::new(lines).block(
+        Block::bordered()
+            .title(title.green().bold())
+            .border_style(Style::new().green())
+            .style(Style::default().bg(style::panel())),
+    );
+    frame.render_widget(stages, chunks[1]);
+
+    // === FOOTER ===
+    let instructions = if matches!(gauntlet.phase, Phase::Summary) {
+        Line::from(vec![
+            "Enter".green().bold(),
+            " Play Again  ".fg(style::text()),
+            "Q".red().bold(),
+            " Quit".fg(style::text()),
+        ])
+    } else {
+        Line::from(vec![
+            "Enter".green().bold(),
+            " Start Stage  ".fg(style::text()),
+            "Q".red().bold(),
+            " Give Up".fg(style::text()),
+        ])
+    };
+    let footer = Paragraph::new(instructions)
+        .alignment(Alignment::Center)
+        .block(
+            Block::bordered()
+                .title(" Controls ".fg(style::text()).bold())
+                .border_style(Style::new().blue())
+                .style(Style::default().bg(style::surface())),
+        );
+    frame.render_widget(footer, chunks[2]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::isolate_data_dir;
+
+    #[test]
+    fn a_sprint_scores_lines_and_the_time_left() {
+        isolate_data_dir();
+
+        let sprint = &STAGES[1];
+        let game = TetrisGame::new(AudioManager::silent());
+        let result = sprint.result(&game, Duration::from_secs(200));
+        assert_eq!(result.points, 0);
+        assert_eq!(result.summary, "0/40 lines");
+        assert_eq!(result.elapsed, sprint.time_limit);
+        assert!(sprint.is_over(&game, sprint.time_limit));
+        assert!(!sprint.is_over(&game, Duration::from_secs(10)));
+    }
+
+    #[test]
+    fn only_the_sprint_stage_restarts_on_a_single_r() {
+        isolate_data_dir();
+
+        let mut gauntlet = GauntletGame::new(AudioManager::silent());
+        gauntlet.start_stage();
+        assert!(!gauntlet.instant_restart());
+
+        gauntlet.finish_stage();
+        gauntlet.start_stage();
+        assert_eq!(
+            gauntlet.stage().map(|stage| stage.name),
+            Some("Tetris Sprint")
+        );
+        assert!(gauntlet.instant_restart());
+        gauntlet.handle_key(KeyEvent::from(KeyCode::Char('r')));
+        assert!(matches!(gauntlet.phase, Phase::Playing { .. }));
+        assert_eq!(gauntlet.results.len(), 1);
+    }
+}
@@ -0,0 +1,714 @@
+//! Hot seat : deux joueurs se passent le clavier pour jouer tour à tour la
+//! même partie de 2048 ou du Démineur, puis comparent leurs essais.
+//!
+//! Le second joueur reçoit exactement la partie du premier grâce à son code
+//! de partage ([`Game::shared_game`]) : même suite de tuiles, ou mêmes mines
+//! autour de la même première case. Les essais ne vont pas dans les
+//! classements (voir [`run_in_progress`]).
+
+use crate::audio::AudioManager;
+use crate::config::PLAYER_NAME_MAX_LEN;
+use crate::core::clock::GameClock;
+use crate::core::share::ShareCode;
+use crate::core::{Game, GameAction, GameStatus};
+use crate::event::{KeyCode, KeyEvent};
+use crate::games::{minesweeper::MinesweeperGame, _2048::Game2048};
+use crate::highscores::current_player_name;
+use crate::ui::{glyphs, numbers, style, SelectableList, TextInput};
+use ratatui::{
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, ListItem, Paragraph},
+    Frame,
+};
+use std::cell::Cell;
+use std::cmp::Ordering as Rank;
+use std::time::Duration;
+
+thread_local! {
+    /// Vrai pendant qu'un essai est joué, dans le thread qui le joue
+    static RUN_IN_PROGRESS: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Indique qu'un essai du hot seat est en cours : les jeux n'enregistrent
+/// alors pas leurs high scores
+pub fn run_in_progress() -> bool {
+    RUN_IN_PROGRESS.get()
+}
+
+/// Jeux proposés, dans l'ordre du menu
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Contest {
+    Game2048,
+    Minesweeper,
+}
+
+const CONTESTS: [Contest; 2] = [Contest::Game2048, Contest::Minesweeper];
+
+impl Contest {
+    pub fn name(self) -> &'static str {
+        match self {
+            Contest::Game2048 => "2048",
+            Contest::Minesweeper => "Minesweeper",
+        }
+    }
+
+    /// Règle de départage, rappelée avant chaque essai
+    pub fn rule(self) -> &'static str {
+        match self {
+            Contest::Game2048 => "Highest score wins",
+            Contest::Minesweeper => "Fastest clear wins, otherwise most cells opened",
+        }
+    }
+
+    fn create(self, audio: AudioManager) -> Box<dyn Game> {
+        match self {
+            Contest::Game2048 => Box::new(Game2048::new(audio)),
+            Contest::Minesweeper => Box::new(MinesweeperGame::new(audio)),
+        }
+    }
+
+    fn attempt(self, game: &dyn Game, elapsed: Duration) -> Attempt {
+        let cleared = self == Contest::Minesweeper && game.status() == GameStatus::Victory;
+        let (points, summary) = match self {
+            Contest::Game2048 => {
+                let score = game.session_score().unwrap_or(0);
+                (score, format!("{} pts", numbers::score(score)))
+            }
+            Contest::Minesweeper => {
+                let opened = game.stage_progress().unwrap_or(0);
+                let summary = if cleared {
+                    "Board cleared".to_string()
+                } else {
+                    format!("{opened} cells opened")
+                };
+                (opened, summary)
+            }
+        };
+        Attempt {
+            points,
+            cleared,
+            elapsed,
+            summary,
+        }
+    }
+
+    /// Compare deux essais : `Greater` si `a` l'emporte sur `b`
+    fn compare(self, a: &Attempt, b: &Attempt) -> Rank {
+        match self {
+            Contest::Game2048 => a.points.cmp(&b.points),
+            Contest::Minesweeper => a.cleared.cmp(&b.cleared).then_with(|| {
+                if a.cleared {
+                    b.elapsed.as_secs().cmp(&a.elapsed.as_secs())
+                } else {
+                    a.points.cmp(&b.points)
+                }
+            }),
+        }
+    }
+}
+
+/// Essai terminé d'un joueur
+#[derive(Debug, Clone, PartialEq)]
+pub struct Attempt {
+    /// Score de 2048, cases ouvertes au Démineur
+    pub points: u32,
+    /// Grille du Démineur terminée
+    pub cleared: bool,
+    pub elapsed: Duration,
+    /// Résumé affiché dans les résultats
+    pub summary: String,
+}
+
+enum Phase {
+    /// Choix du jeu
+    Choose,
+    /// Saisie du nom du joueur 1 ou 2
+    Names(usize),
+    /// Le clavier passe au joueur suivant
+    Handoff,
+    Playing {
+        game: Box<dyn Game>,
+        /// Temps de l'essai, hors pauses
+        clock: GameClock,
+    },
+    /// Les deux essais sont joués
+    Results,
+}
+
+pub struct HotSeat {
+    phase: Phase,
+    contest: Contest,
+    contest_list: SelectableList,
+    /// Joueurs dans l'ordre de passage
+    names: [String; 2],
+    name_input: TextInput,
+    attempts: Vec<Attempt>,
+    /// Partie du premier essai, rejouée par le second joueur
+    code: Option<ShareCode>,
+    /// Sortie audio transmise au jeu de chaque essai
+    audio: AudioManager,
+}
+
+impl HotSeat {
+    pub fn new(audio: AudioManager) -> Self {
+        Self {
+            phase: Phase::Choose,
+            contest: Contest::Game2048,
+            contest_list: SelectableList::new(),
+            names: [String::new(), String::new()],
+            name_input: TextInput::with_value(&current_player_name(), PLAYER_NAME_MAX_LEN),
+            attempts: Vec::new(),
+            code: None,
+            audio,
+        }
+    }
+
+    /// Joueur de l'essai en cours ou annoncé
+    fn player(&self) -> &str {
+        &self.names[self.attempts.len().min(1)]
+    }
+
+    fn submit_name(&mut self, index: usize) {
+        let name = self.name_input.value().trim();
+        self.names[index] = if name.is_empty() {
+            format!("Player {}", index + 1)
+        } else {
+            name.to_string()
+        };
+        if index == 0 {
+            self.name_input.clear();
+            self.phase = Phase::Names(1);
+        } else {
+            self.phase = Phase::Handoff;
+        }
+    }
+
+    fn start_run(&mut self) {
+        let mut game = self.contest.create(self.audio.sibling());
+        if let Some(code) = self.code.filter(|_| !self.attempts.is_empty()) {
+            game.play_share_code(&code);
+        }
+        RUN_IN_PROGRESS.set(true);
+        self.phase = Phase::Playing {
+            game,
+            clock: GameClock::new(),
+        };
+    }
+
+    fn finish_run(&mut self) {
+        let Phase::Playing { game, clock } = &self.phase else {
+            return;
+        };
+        let attempt = self.contest.attempt(game.as_ref(), clock.elapsed());
+        if self.attempts.is_empty() {
+            self.code = game.shared_game();
+        }
+        // Libérer le jeu de l'essai avant la suite
+        self.phase = Phase::Handoff;
+        RUN_IN_PROGRESS.set(false);
+        self.attempts.push(attempt);
+        if self.attempts.len() == 2 {
+            self.phase = Phase::Results;
+        }
+    }
+
+    /// Nouvelle manche sur une autre partie ; l'autre joueur commence
+    fn rematch(&mut self) {
+        self.names.swap(0, 1);
+        self.attempts.clear();
+        self.code = None;
+        self.phase = Phase::Handoff;
+    }
+
+    /// Index du vainqueur, `None` pour une égalité ou une manche en cours
+    pub fn winner(&self) -> Option<usize> {
+        let [first, second] = self.attempts.as_slice() else {
+            return None;
+        };
+        match self.contest.compare(first, second) {
+            Rank::Greater => Some(0),
+            Rank::Less => Some(1),
+            Rank::Equal => None,
+        }
+    }
+}
+
+impl Drop for HotSeat {
+    fn drop(&mut self) {
+        // Hot seat abandonné en plein essai
+        if matches!(self.phase, Phase::Playing { .. }) {
+            RUN_IN_PROGRESS.set(false);
+        }
+    }
+}
+
+impl Game for HotSeat {
+    fn handle_key(&mut self, key: KeyEvent) -> GameAction {
+        match &mut self.phase {
+            Phase::Choose => {
+                if self.contest_list.handle_key(key, CONTESTS.len()) {
+                    return GameAction::Continue;
+                }
+                match key.code {
+                    KeyCode::Enter => {
+                        self.contest = CONTESTS[self.contest_list.selected()];
+                        self.phase = Phase::Names(0);
+                        GameAction::Continue
+                    }
+                    KeyCode::Char('q') => GameAction::Quit,
+                    _ => GameAction::Continue,
+                }
+            }
+            Phase::Names(index) => {
+                let index = *index;
+                if key.code == KeyCode::Enter {
+                    self.submit_name(index);
+                } else {
+                    self.name_input.handle_key(key);
+                }
+                GameAction::Continue
+            }
+            // Recommencer changerait la partie : R est sans effet pendant un
+            // essai, et quitter le jeu termine l'essai
+            Phase::Playing { game, .. } => {
+                if key.code != KeyCode::Char('r') && game.handle_key(key) != GameAction::Continue {
+                    self.finish_run();
+                }
+                GameAction::Continue
+            }
+            Phase::Handoff => match key.code {
+                KeyCode::Enter | KeyCode::Char(' ') => {
+                    self.start_run();
+                    GameAction::Continue
+                }
+                KeyCode::Char('q') => GameAction::Quit,
+                _ => GameAction::Continue,
+            },
+            Phase::Results => match key.code {
+                KeyCode::Enter | KeyCode::Char('r') => {
+                    self.rematch();
+                    GameAction::Continue
+                }
+                KeyCode::Char('n') => {
+                    self.attempts.clear();
+                    self.code = None;
+                    self.phase = Phase::Choose;
+                    GameAction::Continue
+                }
+                KeyCode::Char('q') => GameAction::Quit,
+                _ => GameAction::Continue,
+            },
+        }
+    }
+
+    fn update(&mut self) -> GameAction {
+        let Phase::Playing { game, .. } = &mut self.phase else {
+            return GameAction::Continue;
+        };
+        let action = game.update();
+        if action != GameAction::Continue
+            || matches!(game.status(), GameStatus::GameOver | GameStatus::Victory)
+        {
+            self.finish_run();
+        }
+        GameAction::Continue
+    }
+
+    fn draw(&mut self, frame: &mut Frame, area: Rect) {
+        match &mut self.phase {
+            Phase::Playing { game, .. } => {
+                game.draw(frame, area);
+                draw_turn_badge(frame, area, self.player(), self.attempts.len());
+            }
+            Phase::Choose => draw_contest_choice(frame, area, self),
+            Phase::Names(index) => {
+                let index = *index;
+                draw_name_entry(frame, area, self, index)
+            }
+            Phase::Handoff | Phase::Results => draw_scoreboard(frame, area, self),
+        }
+    }
+
+    fn tick_rate(&self) -> Duration {
+        match &self.phase {
+            Phase::Playing { game, .. } => game.tick_rate(),
+            _ => Duration::from_millis(100),
+        }
+    }
+
+    fn status(&self) -> GameStatus {
+        match &self.phase {
+            Phase::Playing { game, .. } => game.status(),
+            Phase::Results => GameStatus::GameOver,
+            _ => GameStatus::Menu,
+        }
+    }
+
+    fn title_status(&self) -> Option<String> {
+        match &self.phase {
+            Phase::Playing { .. } => Some(format!("{}'s turn", self.player())),
+            Phase::Results => Some(match self.winner() {
+                Some(winner) => format!("{} wins", self.names[winner]),
+                None => "Draw".to_string(),
+            }),
+            _ => None,
+        }
+    }
+
+    fn session_mode(&self) -> Option<String> {
+        (!matches!(self.phase, Phase::Choose)).then(|| self.contest.name().to_string())
+    }
+
+    fn on_shutdown(&mut self) {
+        if let Phase::Playing { game, .. } = &mut self.phase {
+            game.on_shutdown();
+        }
+    }
+
+    fn on_pause(&mut self) {
+        if let Phase::Playing { game, clock } = &mut self.phase {
+            game.on_pause();
+            clock.suspend();
+        }
+    }
+
+    fn on_resume(&mut self) {
+        if let Phase::Playing { game, clock } = &mut self.phase {
+            game.on_resume();
+            clock.unsuspend();
+        }
+    }
+
+    fn captures_esc(&self) -> bool {
+        match &self.phase {
+            Phase::Playing { game, .. } => game.captures_esc(),
+            _ => false,
+        }
+    }
+
+    fn instant_restart(&self) -> bool {
+        match &self.phase {
+            Phase::Playing { game, .. } => game.instant_restart(),
+            _ => false,
+        }
+    }
+
+    fn captures_volume_keys(&self) -> bool {
+        match &self.phase {
+            // "+" et "-" s'écrivent dans les noms
+            Phase::Names(_) => true,
+            Phase::Playing { game, .. } => game.captures_volume_keys(),
+            _ => false,
+        }
+    }
+
+    fn audio(&self) -> Option<&AudioManager> {
+        match &self.phase {
+            Phase::Playing { game, .. } => game.audio(),
+            _ => None,
+        }
+    }
+
+    fn handle_paste(&mut self, text: &str) {
+        if matches!(self.phase, Phase::Names(_)) {
+            self.name_input.insert_str(text);
+        }
+    }
+}
+
+/// Temps au format "m:ss"
+fn format_time(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
+
+/// Écran du hot seat : bandeau, contenu et commandes
+fn draw_frame(frame: &mut Frame, area: Rect, status: Line, instructions: Line) -> Rect {
+    style::fill_area(frame, area, style::background());
+
+    let [header, body, footer] = Layout::vertical([
+        Constraint::Length(4),
+        Constraint::Min(0),
+        Constraint::Length(3),
+    ])
+    .areas(area);
+
+    let title = Paragraph::new(vec![
+        Line::from(vec![
+            format!("{} ", glyphs::icon("🪑")).magenta().bold(),
+            "HOT SEAT".cyan().bold(),
+            format!(" {}", glyphs::icon("🪑")).magenta().bold(),
+        ]),
+        status,
+    ])
+    .alignment(Alignment::Center)
+    .block(
+        Block::bordered()
+            .border_style(Style::new().magenta())
+            .style(Style::default().bg(style::surface())),
+    );
+    frame.render_widget(title, header);
+
+    let controls = Paragraph::new(instructions)
+        .alignment(Alignment::Center)
+        .block(
+            Block::bordered()
+                .title(" Controls ".fg(style::text()).bold())
+                .border_style(Style::new().blue())
+                .style(Style::default().bg(style::surface())),
+        );
+    frame.render_widget(controls, footer);
+
+    body
+}
+
+fn draw_contest_choice(frame: &mut Frame, area: Rect, hot_seat: &mut HotSeat) {
+    let body = draw_frame(
+        frame,
+        area,
+        Line::from("Two players, one board, one keyboard".magenta()),
+        Line::from(vec![
+            "↑↓".green().bold(),
+            " Select  ".fg(style::text()),
+            "Enter".green().bold(),
+            " Choose  ".fg(style::text()),
+            "Q".red().bold(),
+            " Quit".fg(style::text()),
+        ]),
+    );
+
+    let items: Vec<ListItem> = CONTESTS
+        .iter()
+        .map(|contest| {
+            ListItem::new(vec![
+                Line::from(Span::styled(
+                    format!("  {}", contest.name()),
+                    Style::default().fg(style::text()).bold(),
+                )),
+                Line::from(Span::styled(
+                    format!("    {}", contest.rule()),
+                    Style::default().fg(Color::Gray),
+                )),
+            ])
+        })
+        .collect();
+    let block = Block::bordered()
+        .title(" Game ".green().bold())
+        .border_style(Style::new().green())
+        .style(Style::default().bg(style::panel()));
+    hot_seat
+        .contest_list
+        .render(frame, body, items, block, style::focus(Color::Magenta));
+}
+
+fn draw_name_entry(frame: &mut Frame, area: Rect, hot_seat: &HotSeat, index: usize) {
+    let body = draw_frame(
+        frame,
+        area,
+        Line::from(format!("{} • {}", hot_seat.contest.name(), hot_seat.contest.rule()).magenta()),
+        Line::from(vec![
+            "Enter".green().bold(),
+            " Confirm  ".fg(style::text()),
+            "Esc".red().bold(),
+            " Menu".fg(style::text()),
+        ]),
+    );
+
+    let block = Block::bordered()
+        .title(" Players ".green().bold())
+        .border_style(Style::new().green())
+        .style(Style::default().bg(style::panel()));
+    let inner = block.inner(body);
+    frame.render_widget(block, body);
+
+    let [first, field, hint] = Layout::vertical([
+        Constraint::Length(2),
+        Constraint::Length(3),
+        Constraint::Length(1),
+    ])
+    .areas(inner);
+    if index == 1 {
+        frame.render_widget(
+            Paragraph::new(Line::from(vec![
+                "  Player 1: ".gray(),
+                hot_seat.names[0].clone().fg(style::text()).bold(),
+            ])),
+            first,
+        );
+    }
+    hot_seat.name_input.render(
+        frame,
+        field,
+        Block::bordered().title(format!(" Player {} ", index + 1).white()),
+        Style::default().fg(Color::White),
+        true,
+    );
+    frame.render_widget(
+        Paragraph::new("Player 1 goes first, player 2 replays the same game".gray())
+            .alignment(Alignment::Center),
+        hint,
+    );
+}
+
+/// Essais joués, joueur suivant ou vainqueur
+fn draw_scoreboard(frame: &mut Frame, area: Rect, hot_seat: &HotSeat) {
+    let results = matches!(hot_seat.phase, Phase::Results);
+    let status = if results {
+        match hot_seat.winner() {
+            Some(winner) => Line::from(vec![
+                format!("{} ", glyphs::icon("🏆")).yellow(),
+                hot_seat.names[winner].clone().yellow().bold(),
+                " wins!".yellow().bold(),
+            ]),
+            None => Line::from("It's a draw!".yellow().bold()),
+        }
+    } else {
+        Line::from(vec![
+            "Pass the keyboard to ".gray(),
+            hot_seat.player().to_string().green().bold(),
+        ])
+    };
+    let instructions = if results {
+        Line::from(vec![
+            "Enter".green().bold(),
+            " Rematch  ".fg(style::text()),
+            "N".yellow().bold(),
+            " Change Game  ".fg(style::text()),
+            "Q".red().bold(),
+            " Quit".fg(style::text()),
+        ])
+    } else {
+        Line::from(vec![
+            "Enter".green().bold(),
+            " Start Turn  ".fg(style::text()),
+            "Q".red().bold(),
+            " Quit".fg(style::text()),
+        ])
+    };
+    let body = draw_frame(frame, area, status, instructions);
+
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(vec![
+            format!("  {} • ", hot_seat.contest.name())
+                .fg(style::text())
+                .bold(),
+            hot_seat.contest.rule().gray(),
+        ]),
+        Line::from(""),
+    ];
+    for (index, name) in hot_seat.names.iter().enumerate() {
+        let (mark, result) = match hot_seat.attempts.get(index) {
+            Some(attempt) => {
+                let won = results && hot_seat.winner() == Some(index);
+                let mark = if won { "🏆" } else { "✓" };
+                (
+                    format!("{} ", glyphs::icon(mark)).green().bold(),
+                    format!("{}  in {}", attempt.summary, format_time(attempt.elapsed)).green(),
+                )
+            }
+            None if index == hot_seat.attempts.len() => {
+                ("▶ ".yellow().bold(), "Next".yellow().bold())
+            }
+            None => ("· ".dark_gray(), "".into()),
+        };
+        lines.push(Line::from(vec![
+            Span::raw("  "),
+            mark,
+            Span::styled(
+                format!("{name:<18}"),
+                Style::default().fg(style::text()).bold(),
+            ),
+            result,
+        ]));
+    }
+    if let Some(code) = hot_seat.code.filter(|_| !hot_seat.attempts.is_empty()) {
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            "  Game code: ".gray(),
+            code.encode().yellow().bold(),
+        ]));
+    }
+
+    let title = if results { " Results " } else { " Turns " };
+    frame.render_widget(
+        Paragraph::new(lines).block(
+            Block::bordered()
+                .title(title.green().bold())
+                .border_style(Style::new().green())
+                .style(Style::default().bg(style::panel())),
+        ),
+        body,
+    );
+}
+
+/// Rappel du joueur en cours, en haut à droite pendant un essai
+fn draw_turn_badge(frame: &mut Frame, area: Rect, player: &str, turn: usize) {
+    let text = format!(" {} {player} • Turn {}/2 ", glyphs::icon("🪑"), turn + 1);
+    let width = (text.chars().count() as u16 + 1).min(area.width);
+    let badge = Rect {
+        x: area.right().saturating_sub(width),
+        y: area.y,
+        width,
+        height: 1.min(area.height),
+    };
+    frame.render_widget(
+        Paragraph::new(text.black().on_magenta().bold()).alignment(Alignment::Right),
+        badge,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attempt(points: u32, cleared: bool, seconds: u64) -> Attempt {
+        Attempt {
+            points,
+            cleared,
+            elapsed: Duration::from_secs(seconds),
+            summary: String::new(),
+        }
+    }
+
+    #[test]
+    fn attempts_are_ranked_by_the_rule_of_the_game() {
+        let minesweeper = Contest::Minesweeper;
+        // Une grille terminée l'emporte, même avec moins de cases ouvertes
+        assert_eq!(
+            minesweeper.compare(&attempt(40, true, 90), &attempt(60, false, 30)),
+            Rank::Greater
+        );
+        // Deux grilles terminées : la plus rapide
+        assert_eq!(
+            minesweeper.compare(&attempt(71, true, 90), &attempt(71, true, 60)),
+            Rank::Less
+        );
+        assert_eq!(
+            minesweeper.compare(&attempt(12, false, 10), &attempt(30, false, 50)),
+            Rank::Less
+        );
+        assert_eq!(
+            Contest::Game2048.compare(&attempt(512, false, 10), &attempt(512, false, 99)),
+            Rank::Equal
+        );
+    }
+
+    #[test]
+    fn the_second_player_replays_the_first_players_game() {
+        crate::test_support::isolate_data_dir();
+        for contest in CONTESTS {
+            let mut first = contest.create(AudioManager::silent());
+            if contest == Contest::Minesweeper {
+                // Les mines sont posées au premier clic
+                first.handle_key(KeyEvent::from(KeyCode::Char(' ')));
+            }
+            let code = first.shared_game().unwrap();
+            let mut second = contest.create(AudioManager::silent());
+            second.play_share_code(&code);
+            assert_eq!(second.shared_game(), Some(code));
+        }
+    }
+}
@@ -0,0 +1,484 @@
+//! Tutoriel interactif des règles du Jeu de la Vie.
+//!
+//! Une leçon par règle sur une petite grille : chaque cellule affiche son
+//! nombre de voisins vivants, la cellule étudiée est encadrée, et le joueur
+//! avance d'une génération pour voir la règle s'appliquer. La dernière leçon
+//! laisse la grille libre. Les règles viennent de [`crate::core::life`],
+//! comme pour le jeu lui-même.
+
+use crate::core::grid::Grid2D;
+use crate::core::life::{self, CellState};
+use crate::core::{Game, GameAction, GameStatus};
+use crate::event::{KeyCode, KeyEvent};
+use crate::ui::{glyphs, style};
+use ratatui::{
+    layout::{Alignment, Constraint, Flex, Layout, Rect},
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Paragraph, Wrap},
+};
+
+/// Côté de la grille des leçons
+const SIZE: usize = 7;
+
+/// Taille d'une cellule à l'écran : le nombre de voisins tient sur la
+/// première ligne, la seconde garde la cellule à peu près carrée
+const CELL_WIDTH: usize = 5;
+const CELL_HEIGHT: usize = 2;
+
+const DEAD_BG: Color = Color::Rgb(20, 25, 30);
+const ALIVE_BG: Color = Color::Rgb(40, 160, 70);
+/// Teintes des indices : cellule qui va mourir, cellule qui va naître
+const DYING_BG: Color = Color::Rgb(170, 70, 50);
+const BORN_BG: Color = Color::Rgb(30, 80, 45);
+
+struct Lesson {
+    title: &'static str,
+    /// Explication affichée avant de passer à la génération suivante
+    intro: &'static [&'static str],
+    /// Explication de ce qui vient de se passer
+    outcome: &'static [&'static str],
+    cells: &'static [(usize, usize)],
+    /// Cellule étudiée, où le curseur commence
+    focus: (usize, usize),
+}
+
+const LESSONS: [Lesson; 6] = [
+    Lesson {
+        title: "Neighbours",
+        intro: &[
+            "Every cell has 8 neighbours: the cells around it, diagonals included.",
+            "The number in each cell counts its living neighbours. The framed cell has 2.",
+            "Each generation, every cell looks at that number to decide whether it lives.",
+        ],
+        outcome: &[
+            "All cells changed at the same time, each from its own count.",
+            "The next lessons show the rules one by one.",
+        ],
+        cells: &[(2, 2), (3, 3), (4, 3)],
+        focus: (3, 3),
+    },
+    Lesson {
+        title: "Underpopulation",
+        intro: &[
+            "A living cell with fewer than 2 living neighbours dies, as if of loneliness.",
+            "The framed cell has a single neighbour.",
+        ],
+        outcome: &["Both cells died: each of them had only 1 neighbour."],
+        cells: &[(3, 3), (4, 3)],
+        focus: (3, 3),
+    },
+    Lesson {
+        title: "Survival",
+        intro: &[
+            "A living cell with 2 or 3 living neighbours survives.",
+            "Each cell of this block has 3 neighbours.",
+        ],
+        outcome: &[
+            "Nothing changed, and nothing ever will: the block is a still life.",
+            "Step again to check.",
+        ],
+        cells: &[(3, 3), (4, 3), (3, 4), (4, 4)],
+        focus: (3, 3),
+    },
+    Lesson {
+        title: "Overpopulation",
+        intro: &[
+            "A living cell with more than 3 living neighbours dies, as if of overcrowding.",
+            "The framed cell in the middle of the cross has 4.",
+        ],
+        outcome: &[
+            "The middle cell died. The arms survived with 3 neighbours each,",
+            "and the corners between them were born: see the next lesson.",
+        ],
+        cells: &[(3, 2), (2, 3), (3, 3), (4, 3), (3, 4)],
+        focus: (3, 3),
+    },
+    Lesson {
+        title: "Reproduction",
+        intro: &[
+            "A dead cell with exactly 3 living neighbours comes to life.",
+            "The framed empty cell above the line has 3.",
+        ],
+        outcome: &[
+            "Cells were born above and below, and both ends died with 1 neighbour.",
+            "This blinker flips between two shapes forever: step again.",
+        ],
+        cells: &[(2, 3), (3, 3), (4, 3)],
+        focus: (3, 2),
+    },
+    Lesson {
+        title: "Your Turn",
+        intro: &[
+            "Draw your own pattern and predict what happens before each step.",
+            "This glider moves one cell diagonally every 4 generations.",
+        ],
+        outcome: &["Keep stepping, or toggle cells to try something else."],
+        cells: &[(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)],
+        focus: (3, 3),
+    },
+];
+
+/// Ce que la règle réserve à une cellule dans `state` avec `neighbors`
+/// voisins vivants
+fn rule_text(state: CellState, neighbors: u8) -> &'static str {
+    match (state, neighbors) {
+        (CellState::Alive, 0..=1) => "dies of underpopulation (fewer than 2 neighbours)",
+        (CellState::Alive, 2..=3) => "survives (2 or 3 neighbours)",
+        (CellState::Alive, _) => "dies of overpopulation (more than 3 neighbours)",
+        (CellState::Dead, 3) => "is born by reproduction (exactly 3 neighbours)",
+        (CellState::Dead, _) => "stays dead (a dead cell needs exactly 3 neighbours)",
+    }
+}
+
+pub struct LifeTutorial {
+    lesson: usize,
+    grid: Grid2D<CellState>,
+    next_grid: Grid2D<CellState>,
+    cursor: (usize, usize),
+    /// Générations jouées depuis le début de la leçon
+    generation: u32,
+    /// Teinte les cellules qui vont mourir ou naître
+    hints: bool,
+}
+
+impl LifeTutorial {
+    pub fn new() -> Self {
+        let mut tutorial = Self {
+            lesson: 0,
+            grid: Grid2D::new(SIZE, SIZE, CellState::Dead),
+            next_grid: Grid2D::new(SIZE, SIZE, CellState::Dead),
+            cursor: (0, 0),
+            generation: 0,
+            hints: false,
+        };
+        tutorial.start_lesson(0);
+        tutorial
+    }
+
+    fn current(&self) -> &'static Lesson {
+        &LESSONS[self.lesson]
+    }
+
+    fn start_lesson(&mut self, lesson: usize) {
+        self.lesson = lesson.min(LESSONS.len() - 1);
+        self.grid.fill(CellState::Dead);
+        for &(x, y) in self.current().cells {
+            self.grid.set(x, y, CellState::Alive);
+        }
+        self.cursor = self.current().focus;
+        self.generation = 0;
+    }
+
+    fn neighbors(&self, x: usize, y: usize) -> u8 {
+        life::count_neighbors(self.grid.as_slice(), SIZE, SIZE, SIZE, x, y)
+    }
+
+    fn cell(&self, x: usize, y: usize) -> CellState {
+        self.grid.get(x, y).copied().unwrap_or(CellState::Dead)
+    }
+
+    fn step(&mut self) {
+        life::next_generation_serial(
+            self.grid.as_slice(),
+            self.next_grid.as_mut_slice(),
+            SIZE,
+            SIZE,
+            SIZE,
+        );
+        std::mem::swap(&mut self.grid, &mut self.next_grid);
+        self.generation += 1;
+    }
+
+    fn toggle_cursor_cell(&mut self) {
+        let (x, y) = self.cursor;
+        if let Some(cell) = self.grid.get_mut(x, y) {
+            *cell = match *cell {
+                CellState::Alive => CellState::Dead,
+                CellState::Dead => CellState::Alive,
+            };
+        }
+    }
+
+    fn move_cursor(&mut self, dx: isize, dy: isize) {
+        let (x, y) = self.cursor;
+        self.cursor = (
+            x.saturating_add_signed(dx).min(SIZE - 1),
+            y.saturating_add_signed(dy).min(SIZE - 1),
+        );
+    }
+}
+
+impl Default for LifeTutorial {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Game for LifeTutorial {
+    fn handle_key(&mut self, key: KeyEvent) -> GameAction {
+        match key.code {
+            KeyCode::Up => self.move_cursor(0, -1),
+            KeyCode::Down => self.move_cursor(0, 1),
+            KeyCode::Left => self.move_cursor(-1, 0),
+            KeyCode::Right => self.move_cursor(1, 0),
+            KeyCode::Enter => self.toggle_cursor_cell(),
+            KeyCode::Char('s') | KeyCode::Char(' ') => self.step(),
+            KeyCode::Char('n') | KeyCode::Tab => self.start_lesson(self.lesson + 1),
+            KeyCode::Char('p') | KeyCode::BackTab => {
+                self.start_lesson(self.lesson.saturating_sub(1))
+            }
+            KeyCode::Char('r') => self.start_lesson(self.lesson),
+            KeyCode::Char('h') => self.hints = !self.hints,
+            KeyCode::Char('q') => return GameAction::Quit,
+            _ => {}
+        }
+        GameAction::Continue
+    }
+
+    fn update(&mut self) -> GameAction {
+        GameAction::Continue
+    }
+
+    fn draw(&mut self, frame: &mut ratatui::Frame, area: Rect) {
+        draw_tutorial(frame, area, self);
+    }
+
+    fn title_status(&self) -> Option<String> {
+        Some(format!("Lesson {}/{}", self.lesson + 1, LESSONS.len()))
+    }
+
+    /// La grille n'avance qu'au pas à pas : rien ne bouge entre deux touches
+    fn status(&self) -> GameStatus {
+        GameStatus::Paused
+    }
+
+    fn instant_restart(&self) -> bool {
+        true
+    }
+}
+
+fn draw_tutorial(frame: &mut ratatui::Frame, area: Rect, tutorial: &LifeTutorial) {
+    style::fill_area(frame, area, style::background());
+
+    let [header_area, body_area, footer_area] = Layout::vertical([
+        Constraint::Length(4),
+        Constraint::Min(0),
+        Constraint::Length(3),
+    ])
+    .areas(area);
+
+    // === HEADER ===
+    let lesson = tutorial.current();
+    let header = Paragraph::new(vec![
+        Line::from(vec![
+            format!("{} ", glyphs::icon("🎓")).yellow().bold(),
+            "GAME OF LIFE TUTORIAL".cyan().bold(),
+            format!(" {}", glyphs::icon("🎓")).yellow().bold(),
+        ]),
+        Line::from(vec![
+            format!("Lesson {}/{}: ", tutorial.lesson + 1, LESSONS.len()).gray(),
+            lesson.title.fg(style::text()).bold(),
+            format!("   Generation {}", tutorial.generation).gray(),
+        ]),
+    ])
+    .alignment(Alignment::Center)
+    .block(
+        Block::bordered()
+            .border_style(Style::new().cyan())
+            .style(Style::default().bg(style::surface())),
+    );
+    frame.render_widget(header, header_area);
+
+    // === GRILLE ET EXPLICATIONS ===
+    let grid_width = (SIZE * CELL_WIDTH + 2) as u16;
+    let [grid_area, text_area] =
+        Layout::horizontal([Constraint::Length(grid_width), Constraint::Min(0)])
+            .spacing(1)
+            .areas(body_area);
+    let [grid_area] = Layout::vertical([Constraint::Length((SIZE * CELL_HEIGHT + 2) as u16)])
+        .flex(Flex::Center)
+        .areas(grid_area);
+
+    let grid = Paragraph::new(grid_lines(tutorial)).block(
+        Block::bordered()
+            .title(" Grid ".green().bold())
+            .border_style(Style::new().green())
+            .style(Style::default().bg(style::panel())),
+    );
+    frame.render_widget(grid, grid_area);
+
+    let explanation = Paragraph::new(explanation_lines(tutorial))
+        .wrap(Wrap { trim: true })
+        .block(
+            Block::bordered()
+                .title(" Rules ".yellow().bold())
+                .border_style(Style::new().yellow())
+                .style(Style::default().bg(style::panel())),
+        );
+    frame.render_widget(explanation, text_area);
+
+    // === FOOTER ===
+    let controls = Line::from(vec![
+        "S".green().bold(),
+        " Step  ".fg(style::text()),
+        "Arrows".cyan().bold(),
+        " Move  ".fg(style::text()),
+        "Enter".cyan().bold(),
+        " Toggle  ".fg(style::text()),
+        "N/P".yellow().bold(),
+        " Next/Prev lesson  ".fg(style::text()),
+        "H".magenta().bold(),
+        " Hints  ".fg(style::text()),
+        "R".yellow().bold(),
+        " Reset  ".fg(style::text()),
+        "Q".red().bold(),
+        " Back".fg(style::text()),
+    ]);
+    let footer = Paragraph::new(controls).alignment(Alignment::Center).block(
+        Block::bordered()
+            .title(" Controls ".fg(style::text()).bold())
+            .border_style(Style::new().blue())
+            .style(Style::default().bg(style::surface())),
+    );
+    frame.render_widget(footer, footer_area);
+}
+
+/// Grille annotée : le nombre de voisins vivants dans chaque cellule, la
+/// cellule sous le curseur entre crochets
+fn grid_lines(tutorial: &LifeTutorial) -> Vec<Line<'static>> {
+    let mut lines = Vec::with_capacity(SIZE * CELL_HEIGHT);
+    for y in 0..SIZE {
+        let mut numbers = Vec::with_capacity(SIZE);
+        let mut padding = Vec::with_capacity(SIZE);
+        for x in 0..SIZE {
+            let state = tutorial.cell(x, y);
+            let neighbors = tutorial.neighbors(x, y);
+            let next = life::next_state(state, neighbors);
+
+            let bg = match (state, next) {
+                (CellState::Alive, CellState::Dead) if tutorial.hints => DYING_BG,
+                (CellState::Dead, CellState::Alive) if tutorial.hints => BORN_BG,
+                (CellState::Alive, _) => ALIVE_BG,
+                (CellState::Dead, _) => DEAD_BG,
+            };
+            let fg = match (state, neighbors) {
+                (CellState::Alive, _) => Color::Black,
+                (CellState::Dead, 0) => Color::DarkGray,
+                (CellState::Dead, _) => Color::Gray,
+            };
+            let label = if neighbors == 0 && state == CellState::Dead {
+                "·".to_string()
+            } else {
+                neighbors.to_string()
+            };
+            let text = if (x, y) == tutorial.cursor {
+                format!(" [{label}] ")
+            } else {
+                format!("  {label}  ")
+            };
+            let mut cell_style = Style::default().fg(fg).bg(bg);
+            if (x, y) == tutorial.cursor {
+                cell_style = cell_style.fg(Color::Yellow).bold();
+            }
+            numbers.push(Span::styled(text, cell_style));
+            padding.push(Span::styled(
+                " ".repeat(CELL_WIDTH),
+                Style::default().bg(bg),
+            ));
+        }
+        lines.push(Line::from(numbers));
+        lines.push(Line::from(padding));
+    }
+    lines
+}
+
+/// Texte de la leçon, puis ce qui attend la cellule sous le curseur
+fn explanation_lines(tutorial: &LifeTutorial) -> Vec<Line<'static>> {
+    let lesson = tutorial.current();
+    let paragraphs = if tutorial.generation == 0 {
+        lesson.intro
+    } else {
+        lesson.outcome
+    };
+
+    let mut lines: Vec<Line> = paragraphs
+        .iter()
+        .map(|text| Line::from(text.fg(style::text())))
+        .collect();
+
+    let (x, y) = tutorial.cursor;
+    let state = tutorial.cell(x, y);
+    let neighbors = tutorial.neighbors(x, y);
+    let state_text = match state {
+        CellState::Alive => "living",
+        CellState::Dead => "dead",
+    };
+    lines.push(Line::default());
+    lines.push(Line::from(vec![
+        "Framed cell: ".yellow().bold(),
+        format!(
+            "{state_text}, {neighbors} living neighbour{}",
+            if neighbors == 1 { "" } else { "s" }
+        )
+        .fg(style::text()),
+    ]));
+    lines.push(Line::from(vec![
+        "Next generation it ".gray(),
+        rule_text(state, neighbors).cyan(),
+    ]));
+
+    lines.push(Line::default());
+    let prompt = if tutorial.lesson + 1 < LESSONS.len() {
+        "Press S to step, N for the next lesson."
+    } else {
+        "Press S to step, Enter to toggle the framed cell."
+    };
+    lines.push(Line::from(prompt.green()));
+    if tutorial.hints {
+        lines.push(Line::from(vec![
+            "Hints: ".magenta().bold(),
+            "  ".bg(DYING_BG),
+            " will die  ".gray(),
+            "  ".bg(BORN_BG),
+            " will be born".gray(),
+        ]));
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn each_rule_lesson_shows_its_rule_on_the_framed_cell() {
+        let mut tutorial = LifeTutorial::new();
+        // Leçon, état de la cellule étudiée après une génération
+        let expected = [
+            (1, CellState::Dead),
+            (2, CellState::Alive),
+            (3, CellState::Dead),
+            (4, CellState::Alive),
+        ];
+        for (lesson, after) in expected {
+            tutorial.start_lesson(lesson);
+            let (x, y) = tutorial.cursor;
+            tutorial.step();
+            assert_eq!(tutorial.cell(x, y), after, "{}", LESSONS[lesson].title);
+        }
+
+        // Le bloc de la leçon Survival ne bouge plus
+        tutorial.start_lesson(2);
+        tutorial.step();
+        tutorial.step();
+        assert_eq!(
+            tutorial
+                .grid
+                .iter()
+                .filter(|&&cell| cell == CellState::Alive)
+                .count(),
+            4
+        );
+    }
+}
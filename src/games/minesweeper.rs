@@ -1,28 +1,58 @@
 use crate::audio::{AudioManager, SoundEffect};
-use crate::core::{Game, GameAction};
-use crate::highscores::{GameData, HighScoreManager, Score};
-use crossterm::event::{KeyCode, KeyEvent};
-use rand::Rng;
+use crate::config::ConfigManager;
+use crate::core::clock::GameClock;
+use crate::core::clock::Instant;
+use crate::core::grid::Grid2D;
+use crate::core::share::{self, ShareCode, SharedGame};
+use crate::core::{DangerLevel, Game, GameAction, GameStatus};
+use crate::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use crate::highscores::{current_player_name, GameData, HighScoreManager, Score};
+use crate::music::stingers;
+use crate::mutators::{self, Mutator};
+use crate::ui::{animation, glyphs, preview, style};
+use rand::{rngs::StdRng, Rng, SeedableRng};
 use ratatui::{
     layout::{Constraint, Layout, Margin, Rect},
     style::{Color, Style, Stylize},
     text::Line,
     widgets::{Block, Clear, Paragraph},
 };
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
 use std::time::Duration;
 
 const GRID_WIDTH: usize = 16;
 const GRID_HEIGHT: usize = 16;
 const MINE_COUNT: usize = 40;
-
-#[derive(Debug, Clone, Copy, PartialEq)]
+/// Grille débutant : 9×9 et 10 mines
+const BEGINNER_SIZE: usize = 9;
+const BEGINNER_MINES: usize = 10;
+/// Cases visibles autour du curseur avec le modificateur Fog of War
+const FOG_RADIUS: usize = 2;
+/// Taille d'une case à l'écran, en caractères
+const CELL_WIDTH: u16 = 3;
+const CELL_HEIGHT: u16 = 1;
+
+/// Délai entre deux anneaux de l'ouverture d'une zone vide
+const RIPPLE_STEP: Duration = Duration::from_millis(50);
+/// Un son tous les deux anneaux, pour que les sons ne s'empilent pas
+const RIPPLE_SOUND_EVERY: u16 = 2;
+/// Hausse de la hauteur du son à chaque anneau, et hauteur maximale
+const RIPPLE_PITCH_STEP: f32 = 0.06;
+const RIPPLE_MAX_PITCH: f32 = 2.0;
+/// Cases sûres restantes sous lesquelles l'en-tête rougit, puis clignote
+const WARNING_SAFE_CELLS: usize = 10;
+const CRITICAL_SAFE_CELLS: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum CellState {
     Hidden,
     Revealed,
     Flagged,
+    Questioned,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct Cell {
     is_mine: bool,
     adjacent_mines: u8,
@@ -39,133 +69,196 @@ impl Cell {
     }
 }
 
+/// Ouverture progressive d'une zone vide : les cases déjà ouvertes par la
+/// partie apparaissent anneau par anneau autour de la case cliquée
+struct Ripple {
+    started: Instant,
+    /// Anneau de chaque case de la zone, 0 pour la case cliquée
+    rings: HashMap<(usize, usize), u16>,
+    last_ring: u16,
+    /// Dernier anneau accompagné d'un son
+    sounded_ring: u16,
+}
+
+impl Ripple {
+    fn current_ring(&self) -> u16 {
+        (self.started.elapsed().as_millis() / RIPPLE_STEP.as_millis()) as u16
+    }
+
+    /// Indique que la case n'est pas encore atteinte par l'anneau courant
+    fn hides(&self, x: usize, y: usize) -> bool {
+        self.rings
+            .get(&(x, y))
+            .is_some_and(|&ring| ring > self.current_ring())
+    }
+
+    fn is_finished(&self) -> bool {
+        self.current_ring() > self.last_ring
+    }
+}
+
+/// Partie interrompue, pour la reprendre (voir [`crate::saves`])
+#[derive(Serialize, Deserialize)]
+struct SavedGame {
+    grid: Grid2D<Cell>,
+    cursor: (usize, usize),
+    flags_used: usize,
+    cells_revealed: usize,
+    mine_count: usize,
+    fog: bool,
+    seed: u64,
+    first_click: Option<(usize, usize)>,
+    elapsed_ms: u64,
+}
+
 pub struct MinesweeperGame {
-    grid: [[Cell; GRID_WIDTH]; GRID_HEIGHT],
+    grid: Grid2D<Cell>,
     cursor_x: usize,
     cursor_y: usize,
+    /// Case sous la souris
+    hovered: Option<(usize, usize)>,
+    /// Cases visibles à l'écran lors du dernier rendu, pour la souris
+    field: Rect,
     game_over: bool,
     won: bool,
     mines_generated: bool,
     flags_used: usize,
     cells_revealed: usize,
+    question_marks: bool,
+    /// `MINE_COUNT`, ou 10 % de plus avec le modificateur Extra Mines
+    mine_count: usize,
+    /// Modificateur Fog of War : les cases loin du curseur sont masquées
+    fog: bool,
+    ripple: Option<Ripple>,
+
+    // Graine de la grille et première case ouverte, pour le code de partage
+    seed: u64,
+    rng: StdRng,
+    first_click: Option<(usize, usize)>,
 
     // Audio
     audio: AudioManager,
-    music_started: bool,
 
     // High scores
     highscore_manager: HighScoreManager,
-    start_time: std::time::Instant,
+    clock: GameClock,
     score_saved: bool,
 }
 
 impl MinesweeperGame {
-    pub fn new() -> Self {
+    pub fn new(audio: AudioManager) -> Self {
+        let seed = share::new_seed();
         Self {
-            grid: [[Cell::new(); GRID_WIDTH]; GRID_HEIGHT],
+            grid: Grid2D::new(GRID_WIDTH, GRID_HEIGHT, Cell::new()),
             cursor_x: GRID_WIDTH / 2,
             cursor_y: GRID_HEIGHT / 2,
+            hovered: None,
+            field: Rect::default(),
             game_over: false,
             won: false,
             mines_generated: false,
             flags_used: 0,
             cells_revealed: 0,
+            question_marks: ConfigManager::new()
+                .map(|config| config.get_gameplay_config().minesweeper_question_marks)
+                .unwrap_or(true),
+            mine_count: if mutators::is_active(Mutator::ExtraMines) {
+                MINE_COUNT + MINE_COUNT / 10
+            } else {
+                MINE_COUNT
+            },
+            fog: mutators::is_active(Mutator::FogOfWar),
+            ripple: None,
+
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+            first_click: None,
 
-            audio: AudioManager::default(),
-            music_started: false,
+            audio,
 
             highscore_manager: HighScoreManager::default(),
-            start_time: std::time::Instant::now(),
+            clock: GameClock::stopped(),
             score_saved: false,
         }
     }
 
+    /// Petite grille débutant, jouée par le Gauntlet
+    pub fn beginner(audio: AudioManager) -> Self {
+        let mut game = Self::new(audio);
+        game.grid = Grid2D::new(BEGINNER_SIZE, BEGINNER_SIZE, Cell::new());
+        game.cursor_x = BEGINNER_SIZE / 2;
+        game.cursor_y = BEGINNER_SIZE / 2;
+        game.mine_count = BEGINNER_MINES;
+        game
+    }
+
     fn generate_mines(&mut self, first_click_x: usize, first_click_y: usize) {
         if self.mines_generated {
             return;
         }
 
-        let mut rng = rand::rng();
         let mut mines_placed = 0;
 
-        while mines_placed < MINE_COUNT {
-            let x = rng.random_range(0..GRID_WIDTH);
-            let y = rng.random_range(0..GRID_HEIGHT);
+        while mines_placed < self.mine_count {
+            let x = self.rng.random_range(0..self.grid.width());
+            let y = self.rng.random_range(0..self.grid.height());
 
             // Ne pas placer de mine sur le premier clic ou autour
             if (x.abs_diff(first_click_x) <= 1 && y.abs_diff(first_click_y) <= 1)
-                || self.grid[y][x].is_mine
+                || self.grid[(x, y)].is_mine
             {
                 continue;
             }
 
-            self.grid[y][x].is_mine = true;
+            self.grid[(x, y)].is_mine = true;
             mines_placed += 1;
         }
 
         // Calculer les nombres adjacents
-        for y in 0..GRID_HEIGHT {
-            for x in 0..GRID_WIDTH {
-                if !self.grid[y][x].is_mine {
-                    self.grid[y][x].adjacent_mines = self.count_adjacent_mines(x, y);
+        for y in 0..self.grid.height() {
+            for x in 0..self.grid.width() {
+                if !self.grid[(x, y)].is_mine {
+                    self.grid[(x, y)].adjacent_mines = self.count_adjacent_mines(x, y);
                 }
             }
         }
 
         self.mines_generated = true;
+        self.first_click = Some((first_click_x, first_click_y));
+    }
+
+    /// Les prochaines mines seront tirées avec `seed`
+    fn reseed(&mut self, seed: u64) {
+        self.seed = seed;
+        self.rng = StdRng::seed_from_u64(seed);
+        self.first_click = None;
     }
 
     fn start_music_if_needed(&mut self) {
-        if !self.music_started && self.audio.is_music_enabled() && !self.game_over && !self.won {
-            // Choisir la version selon le nombre de drapeaux utilisés (indicateur de progression)
-            let flag_ratio = self.flags_used as f32 / MINE_COUNT as f32;
-            if flag_ratio > 0.7 {
-                self.audio.play_minesweeper_music_fast(); // Version tendue pour fin de partie
-            } else {
-                self.audio.play_minesweeper_music(); // Version contemplative normale
-            }
-            self.music_started = true;
+        if !self.audio.is_music_enabled() {
+            return;
         }
 
-        // Relancer la musique si elle est finie
-        if self.music_started
-            && self.audio.is_music_enabled()
-            && !self.game_over
-            && !self.won
-            && self.audio.is_music_empty()
-        {
-            let flag_ratio = self.flags_used as f32 / MINE_COUNT as f32;
-            if flag_ratio > 0.7 {
-                self.audio.play_minesweeper_music_fast();
-            } else {
-                self.audio.play_minesweeper_music();
-            }
+        if self.game_over || self.won {
+            // La musique s'arrête à la fin de son tour
+            self.audio.finish_music_loop();
+            return;
         }
-    }
-
-    fn count_adjacent_mines(&self, x: usize, y: usize) -> u8 {
-        let mut count = 0;
-
-        for dy in -1..=1i32 {
-            for dx in -1..=1i32 {
-                if dx == 0 && dy == 0 {
-                    continue;
-                }
-
-                let nx = x as i32 + dx;
-                let ny = y as i32 + dy;
 
-                if nx >= 0 && nx < GRID_WIDTH as i32 && ny >= 0 && ny < GRID_HEIGHT as i32 {
-                    let nx = nx as usize;
-                    let ny = ny as usize;
-                    if self.grid[ny][nx].is_mine {
-                        count += 1;
-                    }
-                }
-            }
+        // Choisir la version selon le nombre de drapeaux utilisés (indicateur de progression)
+        let flag_ratio = self.flags_used as f32 / self.mine_count as f32;
+        if flag_ratio > 0.7 {
+            self.audio.play_minesweeper_music_fast(); // Version tendue pour fin de partie
+        } else {
+            self.audio.play_minesweeper_music(); // Version contemplative normale
         }
+    }
 
-        count
+    fn count_adjacent_mines(&self, x: usize, y: usize) -> u8 {
+        self.grid
+            .neighbors(x, y)
+            .filter(|&(nx, ny)| self.grid[(nx, ny)].is_mine)
+            .count() as u8
     }
 
     fn reveal_cell(&mut self, x: usize, y: usize) {
@@ -173,33 +266,39 @@ impl MinesweeperGame {
     }
 
     fn reveal_cell_internal(&mut self, x: usize, y: usize, play_sound: bool) {
-        if x >= GRID_WIDTH || y >= GRID_HEIGHT {
+        if !self.grid.contains(x, y) {
             return;
         }
 
-        if self.grid[y][x].state != CellState::Hidden {
+        if matches!(
+            self.grid[(x, y)].state,
+            CellState::Revealed | CellState::Flagged
+        ) {
             return;
         }
 
         if !self.mines_generated {
             self.generate_mines(x, y);
         }
+        // Le chronomètre démarre au premier clic
+        if self.cells_revealed == 0 {
+            self.clock.start();
+        }
 
-        self.grid[y][x].state = CellState::Revealed;
+        self.grid[(x, y)].state = CellState::Revealed;
         self.cells_revealed += 1;
 
-        let cell = &self.grid[y][x];
-
-        if cell.is_mine {
+        if self.grid[(x, y)].is_mine {
             self.game_over = true;
-            // Son d'explosion
+            self.clock.pause();
+            // Son d'explosion, suivi du jingle de game over
             self.audio.play_sound(SoundEffect::MinesweeperMineHit);
+            self.audio
+                .play_stinger(&stingers::MINESWEEPER_GAME_OVER, None);
             // Révéler toutes les mines
-            for row in &mut self.grid {
-                for cell in row {
-                    if cell.is_mine {
-                        cell.state = CellState::Revealed;
-                    }
+            for cell in self.grid.iter_mut() {
+                if cell.is_mine {
+                    cell.state = CellState::Revealed;
                 }
             }
 
@@ -213,77 +312,182 @@ impl MinesweeperGame {
             self.audio.play_sound(SoundEffect::MinesweeperReveal);
         }
 
-        // Si la case n'a pas de mines adjacentes, révéler les cases voisines
-        if cell.adjacent_mines == 0 {
-            for dy in -1..=1i32 {
-                for dx in -1..=1i32 {
-                    if dx == 0 && dy == 0 {
-                        continue;
-                    }
-
-                    let nx = x as i32 + dx;
-                    let ny = y as i32 + dy;
-
-                    if nx >= 0 && nx < GRID_WIDTH as i32 && ny >= 0 && ny < GRID_HEIGHT as i32 {
-                        self.reveal_cell_internal(nx as usize, ny as usize, false);
-                    }
+        // Autour d'une case sans mine voisine, ouvrir toute la zone en
+        // largeur : une file plutôt que la récursion, qui pouvait épuiser la
+        // pile sur les grandes zones vides
+        let mut rings = vec![((x, y), 0)];
+        let mut queue = VecDeque::from([((x, y), 0)]);
+        while let Some(((cx, cy), ring)) = queue.pop_front() {
+            if self.grid[(cx, cy)].adjacent_mines > 0 {
+                continue;
+            }
+            let neighbors: Vec<_> = self.grid.neighbors(cx, cy).collect();
+            for neighbor in neighbors {
+                let cell = &mut self.grid[neighbor];
+                if matches!(cell.state, CellState::Revealed | CellState::Flagged) {
+                    continue;
                 }
+                cell.state = CellState::Revealed;
+                self.cells_revealed += 1;
+                rings.push((neighbor, ring + 1));
+                queue.push_back((neighbor, ring + 1));
             }
         }
+        self.start_ripple(rings);
 
         // Vérifier la victoire
-        if self.cells_revealed == (GRID_WIDTH * GRID_HEIGHT - MINE_COUNT) {
+        if self.cells_revealed == self.grid.as_slice().len() - self.mine_count {
             self.won = true;
-            // Son de victoire
-            self.audio.play_sound(SoundEffect::MinesweeperVictory);
-            self.audio.stop_music();
-            self.audio.play_minesweeper_music_celebration();
-            self.music_started = false;
+            self.clock.pause();
+            // Fanfare de victoire à la place de la musique
+            self.audio.play_stinger(
+                &stingers::MINESWEEPER_VICTORY,
+                Some(SoundEffect::MinesweeperVictory),
+            );
 
             // Sauvegarder le score si c'est un high score et pas encore sauvé
             self.save_high_score_if_needed();
         }
     }
 
+    /// Fait apparaître progressivement les cases ouvertes d'un coup ; une
+    /// ouverture pendant une autre la rejoint, à partir de l'anneau courant
+    fn start_ripple(&mut self, rings: Vec<((usize, usize), u16)>) {
+        if rings.len() < 2 || animation::reduce_motion() {
+            return;
+        }
+
+        let ripple = self.ripple.get_or_insert_with(|| Ripple {
+            started: Instant::now(),
+            rings: HashMap::new(),
+            last_ring: 0,
+            sounded_ring: 0,
+        });
+        let offset = ripple.current_ring();
+        for (cell, ring) in rings {
+            ripple.rings.insert(cell, ring + offset);
+            ripple.last_ring = ripple.last_ring.max(ring + offset);
+        }
+    }
+
+    /// Fait avancer l'ouverture en cours, avec un son de plus en plus aigu
+    fn advance_ripple(&mut self) {
+        let Some(ripple) = &mut self.ripple else {
+            return;
+        };
+        if ripple.is_finished() {
+            self.ripple = None;
+            return;
+        }
+
+        let ring = ripple.current_ring();
+        if ring >= ripple.sounded_ring + RIPPLE_SOUND_EVERY {
+            ripple.sounded_ring = ring;
+            let pitch = (1.0 + ring as f32 * RIPPLE_PITCH_STEP).min(RIPPLE_MAX_PITCH);
+            self.audio
+                .play_sound_pitched(SoundEffect::MinesweeperReveal, pitch);
+        }
+    }
+
+    /// Case telle qu'elle est affichée : cachée tant que l'ouverture en cours
+    /// ne l'a pas atteinte
+    fn shown_cell(&self, x: usize, y: usize) -> Cell {
+        let mut cell = self.grid[(x, y)];
+        if self
+            .ripple
+            .as_ref()
+            .is_some_and(|ripple| ripple.hides(x, y))
+        {
+            cell.state = CellState::Hidden;
+        }
+        cell
+    }
+
+    /// Révèle les voisines d'un chiffre qui a autant de drapeaux autour de
+    /// lui que de mines
+    fn chord(&mut self, x: usize, y: usize) {
+        let cell = self.grid[(x, y)];
+        if cell.state != CellState::Revealed || cell.adjacent_mines == 0 {
+            return;
+        }
+
+        let neighbors: Vec<_> = self.grid.neighbors(x, y).collect();
+        let flags = neighbors
+            .iter()
+            .filter(|&&cell| self.grid[cell].state == CellState::Flagged)
+            .count();
+        if flags != cell.adjacent_mines as usize {
+            return;
+        }
+
+        self.audio.play_sound(SoundEffect::MinesweeperReveal);
+        for (nx, ny) in neighbors {
+            if self.game_over || self.won {
+                break;
+            }
+            self.reveal_cell_internal(nx, ny, false);
+        }
+    }
+
+    /// Case affichée en (`column`, `row`) dans le terminal : chaque case
+    /// occupe toute sa largeur de 3 colonnes
+    fn cell_at(&self, column: u16, row: u16) -> Option<(usize, usize)> {
+        if !self.field.contains((column, row).into()) {
+            return None;
+        }
+        let x = ((column - self.field.x) / CELL_WIDTH) as usize;
+        let y = ((row - self.field.y) / CELL_HEIGHT) as usize;
+        self.grid.contains(x, y).then_some((x, y))
+    }
+
     fn toggle_flag(&mut self, x: usize, y: usize) {
-        if x >= GRID_WIDTH || y >= GRID_HEIGHT {
+        if !self.grid.contains(x, y) {
             return;
         }
 
-        let cell = &mut self.grid[y][x];
+        let cell = &mut self.grid[(x, y)];
         match cell.state {
             CellState::Hidden => {
-                if self.flags_used < MINE_COUNT {
-                    cell.state = CellState::Flagged;
-                    self.flags_used += 1;
-                    // Son de placement de drapeau
-                    self.audio.play_sound(SoundEffect::MinesweeperFlag);
-                }
+                // Pas de limite : on peut poser plus de drapeaux que de mines
+                cell.state = CellState::Flagged;
+                self.flags_used += 1;
+                // Son de placement de drapeau
+                self.audio.play_sound(SoundEffect::MinesweeperFlag);
             }
             CellState::Flagged => {
-                cell.state = CellState::Hidden;
+                cell.state = if self.question_marks {
+                    CellState::Questioned
+                } else {
+                    CellState::Hidden
+                };
                 self.flags_used -= 1;
                 // Son de retrait de drapeau
                 self.audio.play_sound(SoundEffect::MinesweeperUnflag);
             }
+            CellState::Questioned => {
+                cell.state = CellState::Hidden;
+            }
             CellState::Revealed => {}
         }
     }
 
     fn restart(&mut self) {
-        self.grid = [[Cell::new(); GRID_WIDTH]; GRID_HEIGHT];
-        self.cursor_x = GRID_WIDTH / 2;
-        self.cursor_y = GRID_HEIGHT / 2;
+        let (width, height) = (self.grid.width(), self.grid.height());
+        self.grid = Grid2D::new(width, height, Cell::new());
+        self.cursor_x = width / 2;
+        self.cursor_y = height / 2;
+        self.hovered = None;
         self.game_over = false;
         self.won = false;
         self.mines_generated = false;
         self.flags_used = 0;
         self.cells_revealed = 0;
         self.score_saved = false;
-        self.start_time = std::time::Instant::now();
+        self.ripple = None;
+        self.clock.reset();
+        self.reseed(share::new_seed());
 
         self.audio.stop_music();
-        self.music_started = false;
     }
 
     fn save_high_score_if_needed(&mut self) {
@@ -293,7 +497,7 @@ impl MinesweeperGame {
         }
 
         // Calculer un score basé sur le temps et les performances
-        let duration = self.start_time.elapsed().as_secs();
+        let duration = self.clock.elapsed_secs();
         let base_score = if self.won {
             // Score de base élevé pour une victoire
             10000u32
@@ -317,15 +521,15 @@ impl MinesweeperGame {
             .is_high_score("minesweeper", final_score)
         {
             let game_data = GameData::Minesweeper {
-                grid_size: (GRID_WIDTH as u32, GRID_HEIGHT as u32),
-                mines_count: MINE_COUNT as u32,
+                grid_size: (self.grid.width() as u32, self.grid.height() as u32),
+                mines_count: self.mine_count as u32,
                 duration_seconds: duration,
             };
 
-            let score = Score::new("Anonymous".to_string(), final_score, game_data);
+            let score = Score::new(current_player_name(), final_score, game_data);
 
             // Sauvegarder le score
-            if let Ok(_is_top_10) = self.highscore_manager.add_score("minesweeper", score) {
+            if let Ok(_new_score) = self.highscore_manager.add_score("minesweeper", score) {
                 self.score_saved = true;
             }
         }
@@ -335,6 +539,7 @@ impl MinesweeperGame {
         match cell.state {
             CellState::Hidden => Color::Rgb(160, 160, 160),
             CellState::Flagged => Color::Rgb(255, 100, 100),
+            CellState::Questioned => Color::Rgb(230, 200, 90),
             CellState::Revealed => {
                 if cell.is_mine {
                     Color::Rgb(255, 50, 50)
@@ -345,6 +550,14 @@ impl MinesweeperGame {
         }
     }
 
+    /// Case masquée par le brouillard : trop loin du curseur pendant une
+    /// partie Fog of War en cours
+    fn in_fog(&self, x: usize, y: usize) -> bool {
+        self.fog
+            && !(self.game_over || self.won)
+            && (x.abs_diff(self.cursor_x) > FOG_RADIUS || y.abs_diff(self.cursor_y) > FOG_RADIUS)
+    }
+
     fn get_cell_text_color(cell: &Cell) -> Color {
         if cell.state == CellState::Revealed && !cell.is_mine {
             match cell.adjacent_mines {
@@ -367,6 +580,7 @@ impl MinesweeperGame {
         match cell.state {
             CellState::Hidden => " ".to_string(),
             CellState::Flagged => "F".to_string(),
+            CellState::Questioned => "?".to_string(),
             CellState::Revealed => {
                 if cell.is_mine {
                     "*".to_string()
@@ -411,7 +625,7 @@ impl Game for MinesweeperGame {
                     GameAction::Continue
                 }
                 KeyCode::Down | KeyCode::Char('s') => {
-                    if self.cursor_y < GRID_HEIGHT - 1 {
+                    if self.cursor_y + 1 < self.grid.height() {
                         self.cursor_y += 1;
                     }
                     GameAction::Continue
@@ -423,7 +637,7 @@ impl Game for MinesweeperGame {
                     GameAction::Continue
                 }
                 KeyCode::Right | KeyCode::Char('d') => {
-                    if self.cursor_x < GRID_WIDTH - 1 {
+                    if self.cursor_x + 1 < self.grid.width() {
                         self.cursor_x += 1;
                     }
                     GameAction::Continue
@@ -457,23 +671,176 @@ impl Game for MinesweeperGame {
         }
     }
 
+    fn play_share_code(&mut self, code: &ShareCode) {
+        self.restart();
+        self.reseed(code.seed);
+        // Mêmes mines : elles sont placées autour de la même première case
+        let (x, y) = (usize::from(code.extra >> 8), usize::from(code.extra & 0xFF));
+        if self.grid.contains(x, y) {
+            self.generate_mines(x, y);
+            self.cursor_x = x;
+            self.cursor_y = y;
+        }
+    }
+
+    /// Grille en cours, une fois les mines placées
+    fn shared_game(&self) -> Option<ShareCode> {
+        self.first_click.map(|(x, y)| ShareCode {
+            game: SharedGame::Minesweeper,
+            seed: self.seed,
+            extra: (x as u16) << 8 | y as u16,
+        })
+    }
+
+    fn handle_mouse(&mut self, mouse: MouseEvent) {
+        self.hovered = self.cell_at(mouse.column, mouse.row);
+        if self.game_over || self.won {
+            return;
+        }
+        let Some((x, y)) = self.hovered else {
+            return;
+        };
+
+        let MouseEventKind::Down(button) = mouse.kind else {
+            return;
+        };
+        // Le curseur clavier suit le dernier clic
+        self.cursor_x = x;
+        self.cursor_y = y;
+        match button {
+            MouseButton::Left => self.reveal_cell(x, y),
+            MouseButton::Right => self.toggle_flag(x, y),
+            MouseButton::Middle => self.chord(x, y),
+        }
+    }
+
     fn update(&mut self) -> GameAction {
         self.start_music_if_needed();
+        self.advance_ripple();
         GameAction::Continue
     }
 
-    fn draw(&mut self, frame: &mut ratatui::Frame) {
-        draw_minesweeper_game(frame, self);
+    fn preview(&self, frame: &mut ratatui::Frame, area: Rect) {
+        draw_preview(frame, area);
+    }
+
+    fn draw(&mut self, frame: &mut ratatui::Frame, area: Rect) {
+        draw_minesweeper_game(frame, area, self);
     }
 
     fn tick_rate(&self) -> Duration {
-        Duration::from_millis(100)
+        if self.ripple.is_some() {
+            animation::FRAME_INTERVAL
+        } else {
+            Duration::from_millis(100)
+        }
+    }
+
+    fn status(&self) -> GameStatus {
+        if self.won {
+            GameStatus::Victory
+        } else if self.game_over {
+            GameStatus::GameOver
+        } else if self.clock.is_suspended() {
+            GameStatus::Paused
+        } else {
+            GameStatus::Running
+        }
+    }
+
+    fn danger(&self) -> DangerLevel {
+        if self.status() != GameStatus::Running || !self.mines_generated {
+            return DangerLevel::Calm;
+        }
+        let safe_left = self.grid.as_slice().len() - self.mine_count - self.cells_revealed;
+        if safe_left <= CRITICAL_SAFE_CELLS {
+            DangerLevel::Critical
+        } else if safe_left <= WARNING_SAFE_CELLS {
+            DangerLevel::Warning
+        } else {
+            DangerLevel::Calm
+        }
+    }
+
+    fn stage_progress(&self) -> Option<u32> {
+        Some(self.cells_revealed as u32)
+    }
+
+    fn save_state(&self) -> Option<serde_json::Value> {
+        // Avant le premier clic, les mines ne sont pas encore posées
+        if !self.mines_generated || self.game_over || self.won {
+            return None;
+        }
+        serde_json::to_value(SavedGame {
+            grid: self.grid.clone(),
+            cursor: (self.cursor_x, self.cursor_y),
+            flags_used: self.flags_used,
+            cells_revealed: self.cells_revealed,
+            mine_count: self.mine_count,
+            fog: self.fog,
+            seed: self.seed,
+            first_click: self.first_click,
+            elapsed_ms: self.clock.elapsed().as_millis() as u64,
+        })
+        .ok()
+    }
+
+    fn restore_state(&mut self, state: serde_json::Value) -> bool {
+        let Ok(saved) = serde_json::from_value::<SavedGame>(state) else {
+            return false;
+        };
+        let (width, height) = (saved.grid.width(), saved.grid.height());
+        if width == 0 || height == 0 {
+            return false;
+        }
+        self.grid = saved.grid;
+        self.cursor_x = saved.cursor.0.min(width - 1);
+        self.cursor_y = saved.cursor.1.min(height - 1);
+        self.flags_used = saved.flags_used;
+        self.cells_revealed = saved.cells_revealed;
+        self.mine_count = saved.mine_count;
+        self.fog = saved.fog;
+        self.seed = saved.seed;
+        self.rng = StdRng::seed_from_u64(saved.seed);
+        self.first_click = saved.first_click;
+        self.mines_generated = true;
+        self.game_over = false;
+        self.won = false;
+        self.ripple = None;
+        self.clock = GameClock::resumed_from(Duration::from_millis(saved.elapsed_ms));
+        self.score_saved = false;
+        true
+    }
+
+    fn title_status(&self) -> Option<String> {
+        Some(format!(
+            "{} mines left",
+            self.mine_count.saturating_sub(self.flags_used)
+        ))
+    }
+
+    fn on_pause(&mut self) {
+        self.clock.suspend();
+    }
+
+    fn on_resume(&mut self) {
+        self.clock.unsuspend();
+    }
+
+    fn audio(&self) -> Option<&AudioManager> {
+        Some(&self.audio)
     }
 }
 
-fn draw_minesweeper_game(frame: &mut ratatui::Frame, game: &MinesweeperGame) {
-    let area = frame.area();
+/// Code de partage de la partie terminée, pour les popups de fin
+fn share_line(game: &MinesweeperGame) -> Line<'static> {
+    match game.shared_game() {
+        Some(code) => Line::from(vec!["Share code: ".gray(), code.encode().yellow().bold()]),
+        None => Line::from(""),
+    }
+}
 
+fn draw_minesweeper_game(frame: &mut ratatui::Frame, area: Rect, game: &mut MinesweeperGame) {
     // Layout principal
     let chunks = Layout::vertical([
         Constraint::Length(4), // Header avec infos
@@ -483,31 +850,38 @@ fn draw_minesweeper_game(frame: &mut ratatui::Frame, game: &MinesweeperGame) {
     .split(area);
 
     // Fond sombre élégant
-    let background = Block::new().style(Style::default().bg(Color::Rgb(15, 20, 25)));
-    frame.render_widget(background, area);
+    style::fill_area(frame, area, style::background());
 
     // === HEADER ===
-    let mines_left = MINE_COUNT.saturating_sub(game.flags_used);
+    // Négatif quand plus de drapeaux que de mines ont été posés
+    let mines_left = game.mine_count as i64 - game.flags_used as i64;
     let header_text = vec![
         Line::from(vec![
-            "💣 ".yellow().bold(),
+            format!("{} ", glyphs::icon("💣")).yellow().bold(),
             "MINESWEEPER".cyan().bold(),
-            " 💣".yellow().bold(),
+            format!(" {}", glyphs::icon("💣")).yellow().bold(),
         ]),
         Line::from(vec![
             "Mines Left: ".yellow(),
             format!("{mines_left}").white().bold(),
             " | Flags Used: ".gray(),
             format!("{}", game.flags_used).red().bold(),
+            " | Time: ".gray(),
+            game.clock.format().white().bold(),
+            match game.hovered {
+                Some((x, y)) => format!(" | Cell: {},{}", x + 1, y + 1).cyan(),
+                None => "".into(),
+            },
         ]),
     ];
 
+    // L'en-tête clignote en rouge quand il ne reste que quelques cases sûres
     let header = Paragraph::new(header_text)
         .alignment(ratatui::layout::Alignment::Center)
         .block(
             Block::bordered()
                 .title(" Game Status ".white().bold())
-                .border_style(Style::new().cyan())
+                .border_style(Style::new().fg(animation::danger_color(Color::Cyan, game.danger())))
                 .style(Style::default().bg(Color::Rgb(25, 35, 45))),
         );
     frame.render_widget(header, chunks[0]);
@@ -526,37 +900,55 @@ fn draw_minesweeper_game(frame: &mut ratatui::Frame, game: &MinesweeperGame) {
     });
 
     // Calculer les dimensions pour centrer la grille
-    let cell_width = 3;
-    let cell_height = 1;
-    let grid_width = GRID_WIDTH as u16 * cell_width;
-    let grid_height = GRID_HEIGHT as u16 * cell_height;
+    let cell_width = CELL_WIDTH;
+    let cell_height = CELL_HEIGHT;
+    let grid_width = game.grid.width() as u16 * cell_width;
+    let grid_height = game.grid.height() as u16 * cell_height;
 
     let start_x = inner_area.x + (inner_area.width.saturating_sub(grid_width)) / 2;
     let start_y = inner_area.y + (inner_area.height.saturating_sub(grid_height)) / 2;
+    game.field = Rect::new(start_x, start_y, grid_width, grid_height).intersection(inner_area);
 
     // Dessiner la grille
-    for row in 0..GRID_HEIGHT {
-        for col in 0..GRID_WIDTH {
-            let cell = &game.grid[row][col];
+    for row in 0..game.grid.height() {
+        for col in 0..game.grid.width() {
+            let cell = &game.shown_cell(col, row);
 
             let cell_x = start_x + (col as u16 * cell_width);
             let cell_y = start_y + (row as u16 * cell_height);
 
+            // Sur un petit terminal, les cases hors du cadre ne sont pas dessinées
             let cell_area = Rect {
                 x: cell_x,
                 y: cell_y,
                 width: cell_width,
                 height: cell_height,
-            };
+            }
+            .intersection(inner_area);
+            if cell_area.is_empty() {
+                continue;
+            }
 
-            let cell_text = MinesweeperGame::get_cell_text(cell);
-            let cell_color = MinesweeperGame::get_cell_color(cell);
-            let text_color = MinesweeperGame::get_cell_text_color(cell);
+            let (cell_text, cell_color, text_color) = if game.in_fog(col, row) {
+                (
+                    "░".to_string(),
+                    Color::Rgb(40, 40, 50),
+                    Color::Rgb(70, 70, 85),
+                )
+            } else {
+                (
+                    MinesweeperGame::get_cell_text(cell),
+                    MinesweeperGame::get_cell_color(cell),
+                    MinesweeperGame::get_cell_text_color(cell),
+                )
+            };
 
             // Mettre en surbrillance la case sous le curseur
             let mut style = Style::default().bg(cell_color);
             if col == game.cursor_x && row == game.cursor_y {
                 style = style.bg(Color::Yellow);
+            } else if game.hovered == Some((col, row)) {
+                style = style.bg(Color::Rgb(250, 225, 140));
             }
 
             let cell_widget = Paragraph::new(cell_text)
@@ -597,7 +989,11 @@ fn draw_minesweeper_game(frame: &mut ratatui::Frame, game: &MinesweeperGame) {
                 "SPACE".cyan().bold(),
                 " Reveal  ".white(),
                 "F".yellow().bold(),
-                " Flag  ".white(),
+                if game.question_marks {
+                    " Flag/?  ".white()
+                } else {
+                    " Flag  ".white()
+                },
                 "R".green().bold(),
                 " Restart  ".white(),
                 "Q".red().bold(),
@@ -627,16 +1023,8 @@ fn draw_minesweeper_game(frame: &mut ratatui::Frame, game: &MinesweeperGame) {
         let popup_width = 50.min(area.width);
         let popup_height = 8.min(area.height);
         let popup_area = Rect {
-            x: if area.width >= popup_width {
-                (area.width - popup_width) / 2
-            } else {
-                0
-            },
-            y: if area.height >= popup_height {
-                (area.height - popup_height) / 2
-            } else {
-                0
-            },
+            x: area.x + (area.width - popup_width) / 2,
+            y: area.y + (area.height - popup_height) / 2,
             width: popup_width,
             height: popup_height,
         };
@@ -648,7 +1036,7 @@ fn draw_minesweeper_game(frame: &mut ratatui::Frame, game: &MinesweeperGame) {
             Line::from("💥 GAME OVER 💥".red().bold()),
             Line::from(""),
             Line::from("You hit a mine!".white()),
-            Line::from(""),
+            share_line(game),
             Line::from(vec![
                 "Press ".gray(),
                 "R".green().bold(),
@@ -674,16 +1062,8 @@ fn draw_minesweeper_game(frame: &mut ratatui::Frame, game: &MinesweeperGame) {
         let popup_width = 50.min(area.width);
         let popup_height = 8.min(area.height);
         let popup_area = Rect {
-            x: if area.width >= popup_width {
-                (area.width - popup_width) / 2
-            } else {
-                0
-            },
-            y: if area.height >= popup_height {
-                (area.height - popup_height) / 2
-            } else {
-                0
-            },
+            x: area.x + (area.width - popup_width) / 2,
+            y: area.y + (area.height - popup_height) / 2,
             width: popup_width,
             height: popup_height,
         };
@@ -695,7 +1075,7 @@ fn draw_minesweeper_game(frame: &mut ratatui::Frame, game: &MinesweeperGame) {
             Line::from("🎉 VICTORY! 🎉".green().bold()),
             Line::from(""),
             Line::from("All mines found!".white()),
-            Line::from(""),
+            share_line(game),
             Line::from(vec![
                 "Press ".gray(),
                 "R".green().bold(),
@@ -717,3 +1097,116 @@ fn draw_minesweeper_game(frame: &mut ratatui::Frame, game: &MinesweeperGame) {
         frame.render_widget(popup, popup_area);
     }
 }
+
+/// Aperçu du menu : un champ à moitié déminé (# caché, F drapeau, chiffres
+/// et espaces découverts)
+const PREVIEW: [&str; 7] = [
+    "###1 1##", "##F1 1F#", "#21   1#", "11    12", "   112##", "1111F###", "########",
+];
+
+fn draw_preview(frame: &mut ratatui::Frame, area: Rect) {
+    preview::draw_board(frame, area, &PREVIEW, |cell| {
+        let mut shown = Cell::new();
+        match cell {
+            '#' => {}
+            'F' => shown.state = CellState::Flagged,
+            _ => {
+                shown.state = CellState::Revealed;
+                shown.adjacent_mines = cell.to_digit(10).unwrap_or(0) as u8;
+            }
+        }
+        format!("{:^3}", MinesweeperGame::get_cell_text(&shown))
+            .fg(MinesweeperGame::get_cell_text_color(&shown))
+            .bg(MinesweeperGame::get_cell_color(&shown))
+            .bold()
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::rng;
+    use crate::test_support::isolate_data_dir;
+    use proptest::prelude::*;
+
+    #[test]
+    fn clicks_anywhere_in_a_cell_hit_that_cell() {
+        isolate_data_dir();
+        let mut game = MinesweeperGame::new(AudioManager::silent());
+        game.field = Rect::new(10, 5, GRID_WIDTH as u16 * CELL_WIDTH, GRID_HEIGHT as u16);
+
+        assert_eq!(game.cell_at(10, 5), Some((0, 0)));
+        assert_eq!(game.cell_at(12, 5), Some((0, 0)));
+        assert_eq!(game.cell_at(13, 6), Some((1, 1)));
+        assert_eq!(game.cell_at(57, 20), Some((15, 15)));
+        assert_eq!(game.cell_at(58, 5), None);
+        assert_eq!(game.cell_at(9, 5), None);
+    }
+
+    #[test]
+    fn empty_areas_open_at_once_but_appear_ring_by_ring() {
+        isolate_data_dir();
+        let mut game = MinesweeperGame::new(AudioManager::silent());
+        // Une seule mine dans le coin opposé : presque toute la grille est vide
+        let mine = (GRID_WIDTH - 1, GRID_HEIGHT - 1);
+        game.grid[mine].is_mine = true;
+        for (x, y) in game.grid.neighbors(mine.0, mine.1).collect::<Vec<_>>() {
+            game.grid[(x, y)].adjacent_mines = 1;
+        }
+        game.mines_generated = true;
+
+        game.reveal_cell(0, 0);
+
+        assert_eq!(game.cells_revealed, GRID_WIDTH * GRID_HEIGHT - 1);
+        assert_eq!(
+            game.grid[(GRID_WIDTH - 2, GRID_HEIGHT - 2)].state,
+            CellState::Revealed
+        );
+        // Au premier instant, seules la case cliquée et ses voisines se voient
+        let ripple = game.ripple.as_ref().expect("ripple started");
+        assert_eq!(ripple.last_ring as usize, GRID_WIDTH.max(GRID_HEIGHT) - 1);
+        assert_eq!(game.shown_cell(0, 0).state, CellState::Revealed);
+        assert_eq!(game.shown_cell(5, 3).state, CellState::Hidden);
+        assert_eq!(game.tick_rate(), animation::FRAME_INTERVAL);
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn first_reveal_opens_a_safe_zero_area(
+            seed in any::<u64>(),
+            x in 0..GRID_WIDTH,
+            y in 0..GRID_HEIGHT,
+        ) {
+            isolate_data_dir();
+            rng::seed(seed);
+            let mut game = MinesweeperGame::new(AudioManager::silent());
+
+            game.reveal_cell(x, y);
+
+            prop_assert!(!game.game_over);
+            prop_assert_eq!(game.grid[(x, y)].adjacent_mines, 0);
+
+            let mut revealed = 0;
+            for cy in 0..GRID_HEIGHT {
+                for cx in 0..GRID_WIDTH {
+                    let cell = game.grid[(cx, cy)];
+                    if cell.state != CellState::Revealed {
+                        continue;
+                    }
+                    revealed += 1;
+                    prop_assert!(!cell.is_mine, "mine revealed at ({cx}, {cy})");
+                    // Le remplissage s'étend autour de chaque case sans mine voisine
+                    if cell.adjacent_mines == 0 {
+                        for (nx, ny) in game.grid.neighbors(cx, cy) {
+                            prop_assert_eq!(game.grid[(nx, ny)].state, CellState::Revealed);
+                        }
+                    }
+                }
+            }
+            prop_assert_eq!(revealed, game.cells_revealed);
+            prop_assert_eq!(game.grid.iter().filter(|cell| cell.is_mine).count(), MINE_COUNT);
+        }
+    }
+}
@@ -1,92 +1,537 @@
+#[cfg(feature = "native")]
+use crate::audio::DeviceSink;
+use crate::audio::{AudioManager, AudioSink, SilentSink};
+use crate::config::GameListConfig;
 use crate::core::{Game, GameInfo};
 use std::collections::HashMap;
+use std::sync::Arc;
 
 pub mod _2048;
 pub mod breakout;
 pub mod gameoflife;
+pub mod gauntlet;
+pub mod hotseat;
+pub mod life_tutorial;
 pub mod minesweeper;
 pub mod pong;
+pub mod puzzles;
+pub mod rhythm;
 pub mod snake;
 pub mod tetris;
+pub mod tetris_doubles;
+pub mod tournament;
 
-pub type GameConstructor = Box<dyn Fn() -> Box<dyn Game>>;
+/// Construit un jeu branché sur le gestionnaire audio reçu
+pub type GameConstructor = Box<dyn Fn(AudioManager) -> Box<dyn Game>>;
+
+/// Nombre maximal de noms proposés pour un jeu inconnu
+const MAX_SUGGESTIONS: usize = 3;
 
 pub struct GameRegistry {
     games: HashMap<String, GameConstructor>,
     info: HashMap<String, GameInfo>,
+    /// Sortie audio des jeux construits
+    sink: Arc<dyn AudioSink>,
 }
 
 impl GameRegistry {
+    /// Jeux branchés sur le périphérique audio
+    #[cfg(feature = "native")]
     pub fn new() -> Self {
+        Self::with_sink(Arc::new(DeviceSink))
+    }
+
+    /// Jeux muets, qui ne touchent jamais au périphérique audio : pour les
+    /// tests et les machines d'intégration continue
+    pub fn silent() -> Self {
+        Self::with_sink(Arc::new(SilentSink))
+    }
+
+    pub fn with_sink(sink: Arc<dyn AudioSink>) -> Self {
         let mut registry = Self {
             games: HashMap::new(),
             info: HashMap::new(),
+            sink,
         };
         registry.register_all_games();
         registry
     }
 
-    pub fn register<F>(&mut self, name: &str, description: &str, constructor: F)
+    /// Sortie audio des jeux, que le menu partage
+    pub fn audio_sink(&self) -> Arc<dyn AudioSink> {
+        Arc::clone(&self.sink)
+    }
+
+    pub fn register<F>(&mut self, id: &str, name: &str, description: &str, constructor: F)
     where
-        F: Fn() -> Box<dyn Game> + 'static,
+        F: Fn(AudioManager) -> Box<dyn Game> + 'static,
     {
-        self.games.insert(name.to_string(), Box::new(constructor));
+        self.games.insert(id.to_string(), Box::new(constructor));
         self.info
-            .insert(name.to_string(), GameInfo::new(name, description));
+            .insert(id.to_string(), GameInfo::new(id, name, description));
+    }
+
+    /// Ajoute des noms acceptés en ligne de commande pour un jeu enregistré
+    pub fn alias(&mut self, id: &str, aliases: &[&str]) {
+        if let Some(info) = self.info.get_mut(id) {
+            info.aliases
+                .extend(aliases.iter().map(|alias| alias.to_string()));
+        }
+    }
+
+    /// Change l'icône d'un jeu enregistré dans les menus
+    pub fn icon(&mut self, id: &str, icon: &'static str) {
+        if let Some(info) = self.info.get_mut(id) {
+            info.icon = icon;
+        }
+    }
+
+    /// Ajoute la présentation détaillée et les touches propres à un jeu
+    /// enregistré (voir [`GameInfo::long_description`])
+    pub fn help(
+        &mut self,
+        id: &str,
+        long_description: &'static str,
+        controls: &'static [(&'static str, &'static str)],
+    ) {
+        if let Some(info) = self.info.get_mut(id) {
+            info.long_description = long_description;
+            info.controls = controls;
+        }
     }
 
+    /// Construit le jeu désigné par `name` (voir [`Self::find_id`])
     pub fn get_game(&self, name: &str) -> Option<Box<dyn Game>> {
-        self.games.get(name).map(|constructor| constructor())
+        let id = self.find_id(name)?;
+        self.games
+            .get(id)
+            .map(|constructor| constructor(AudioManager::new(self.audio_sink())))
     }
 
+    pub fn get_info(&self, id: &str) -> Option<&GameInfo> {
+        self.info.get(id)
+    }
+
+    /// Jeux triés par nom affiché
     pub fn list_games(&self) -> Vec<&GameInfo> {
         let mut games: Vec<&GameInfo> = self.info.values().collect();
         games.sort_by(|a, b| a.name.cmp(&b.name));
         games
     }
 
+    /// Indique si `name` désigne un jeu (voir [`Self::find_id`])
     pub fn has_game(&self, name: &str) -> bool {
-        self.games.contains_key(name)
+        self.find_id(name).is_some()
+    }
+
+    /// Identifiant du jeu désigné par son identifiant, un de ses alias ou son
+    /// nom affiché, sans tenir compte de la casse ni des séparateurs
+    /// ("Game of Life", "game_of_life" et "game-of-life" sont équivalents)
+    pub fn find_id(&self, name: &str) -> Option<&str> {
+        let wanted = normalize_name(name);
+        self.info
+            .values()
+            .find(|info| {
+                normalize_name(&info.id) == wanted
+                    || normalize_name(&info.name) == wanted
+                    || info
+                        .aliases
+                        .iter()
+                        .any(|alias| normalize_name(alias) == wanted)
+            })
+            .map(|info| info.id.as_str())
+    }
+
+    /// Identifiants et alias proches de `name`, du plus proche au plus
+    /// éloigné, au plus un par jeu
+    pub fn suggest(&self, name: &str) -> Vec<&str> {
+        let wanted = normalize_name(name);
+        // Tolérance proportionnelle à la longueur : "snek" → "snake", mais
+        // pas "xyz" → "pong"
+        let max_distance = (wanted.chars().count() / 3).max(2);
+
+        let mut candidates: Vec<(usize, &str)> = self
+            .info
+            .values()
+            .filter_map(|info| {
+                std::iter::once(&info.id)
+                    .chain(&info.aliases)
+                    .map(|candidate| (levenshtein(&wanted, candidate), candidate.as_str()))
+                    .min()
+            })
+            .filter(|&(distance, _)| distance <= max_distance)
+            .collect();
+        candidates.sort();
+        candidates
+            .into_iter()
+            .take(MAX_SUGGESTIONS)
+            .map(|(_, candidate)| candidate)
+            .collect()
     }
 
     fn register_all_games(&mut self) {
         // Enregistrer les jeux avec des métadonnées statiques pour éviter l'initialisation audio
-        self.register("snake", "Classic Snake game", || {
-            Box::new(snake::SnakeGame::new())
+        self.register("snake", "Snake", "Classic Snake game", |audio| {
+            Box::new(snake::SnakeGame::new(audio))
         });
 
-        self.register("tetris", "Classic Tetris with line clearing", || {
-            Box::new(tetris::TetrisGame::new())
-        });
+        self.register(
+            "snake-race",
+            "Snake Race",
+            "Race an AI snake to the food",
+            |audio| Box::new(snake::SnakeGame::race(audio)),
+        );
 
-        self.register("pong", "Classic Pong with 1 or 2 players", || {
-            Box::new(pong::PongGame::new())
-        });
+        self.register(
+            "snake-sprint",
+            "Snake Sprint",
+            "Hold Space to sprint while your energy lasts",
+            |audio| Box::new(snake::SnakeGame::sprint(audio)),
+        );
+
+        self.register(
+            "tetris",
+            "Tetris",
+            "Classic Tetris with line clearing",
+            |audio| Box::new(tetris::TetrisGame::new(audio)),
+        );
+
+        self.register(
+            "tetris-dig",
+            "Tetris Dig",
+            "Dig through garbage rows as fast as you can",
+            |audio| Box::new(tetris::TetrisGame::dig(audio)),
+        );
+
+        self.register(
+            "tetris-doubles",
+            "Tetris Doubles",
+            "Two players share a wide board, one piece each",
+            |audio| Box::new(tetris_doubles::TetrisDoubles::new(audio)),
+        );
+
+        self.register(
+            "pong",
+            "Pong",
+            "Classic Pong with 1 or 2 players",
+            |audio| Box::new(pong::PongGame::new(audio)),
+        );
 
         self.register(
+            "2048",
             "2048",
             "Slide numbered tiles to combine them and reach 2048!",
-            || Box::new(_2048::Game2048::new()),
+            |audio| Box::new(_2048::Game2048::new(audio)),
         );
 
-        self.register("Minesweeper", "Classic mine detection game", || {
-            Box::new(minesweeper::MinesweeperGame::new())
-        });
+        self.register(
+            "2048-autoplay",
+            "2048 Autoplay",
+            "Watch an expectimax bot play 2048",
+            |audio| Box::new(_2048::Game2048::autoplay(audio)),
+        );
 
-        self.register("Breakout", "Brick breaking arcade game", || {
-            Box::new(breakout::BreakoutGame::new())
-        });
+        self.register(
+            "puzzles",
+            "Puzzles",
+            "Scripted 2048 and Tetris challenges",
+            |audio| Box::new(puzzles::PuzzleBrowser::new(audio)),
+        );
+
+        self.register(
+            "minesweeper",
+            "Minesweeper",
+            "Classic mine detection game",
+            |audio| Box::new(minesweeper::MinesweeperGame::new(audio)),
+        );
 
         self.register(
+            "breakout",
+            "Breakout",
+            "Brick breaking arcade game",
+            |audio| Box::new(breakout::BreakoutGame::new(audio)),
+        );
+
+        self.register(
+            "game-of-life",
             "Game of Life",
             "Conway's Game of Life - Cellular automaton visualization",
-            || Box::new(gameoflife::GameOfLife::new()),
+            |audio| Box::new(gameoflife::GameOfLife::new(audio)),
+        );
+
+        self.register(
+            "game-of-life-tutorial",
+            "Game of Life Tutorial",
+            "Learn the rules of life step by step on a tiny grid",
+            |_| Box::new(life_tutorial::LifeTutorial::new()),
+        );
+
+        self.register(
+            "rhythm",
+            "Rhythm",
+            "Hit the notes in time with the music",
+            |audio| Box::new(rhythm::RhythmGame::new(audio)),
+        );
+
+        self.register(
+            "gauntlet",
+            "Gauntlet",
+            "Short stages across three games for one combined score",
+            |audio| Box::new(gauntlet::GauntletGame::new(audio)),
+        );
+
+        self.register(
+            "tournament",
+            "Tournament",
+            "Knockout Pong bracket for 2 to 8 local players",
+            |audio| Box::new(tournament::TournamentGame::new(audio)),
+        );
+
+        self.register(
+            "hot-seat",
+            "Hot Seat",
+            "Two players take turns on the same 2048 or Minesweeper game",
+            |audio| Box::new(hotseat::HotSeat::new(audio)),
+        );
+
+        // Anciens noms du registre et raccourcis
+        self.alias("snake-race", &["race"]);
+        self.alias("tetris-dig", &["dig"]);
+        self.alias("tetris-doubles", &["doubles"]);
+        self.alias("2048-autoplay", &["2048-ai"]);
+        self.alias("minesweeper", &["mines"]);
+        self.alias("rhythm", &["metronome"]);
+        self.alias("hot-seat", &["hotseat"]);
+        self.alias("game-of-life", &["gameoflife", "life", "gol"]);
+        self.alias("game-of-life-tutorial", &["life-tutorial"]);
+
+        // Icônes des menus ; les autres jeux gardent 🎮
+        self.icon("snake", "🐍");
+        self.icon("snake-race", "🏁");
+        self.icon("snake-sprint", "⚡");
+        self.icon("tetris", "🧩");
+        self.icon("tetris-dig", "⛏");
+        self.icon("tetris-doubles", "👥");
+        self.icon("pong", "🏓");
+        self.icon("2048", "🔢");
+        self.icon("puzzles", "🧠");
+        self.icon("minesweeper", "💣");
+        self.icon("breakout", "🧱");
+        self.icon("game-of-life", "🧬");
+        self.icon("game-of-life-tutorial", "🎓");
+        self.icon("rhythm", "🥁");
+        self.icon("gauntlet", "🎯");
+        self.icon("tournament", "🏆");
+        self.icon("hot-seat", "🪑");
+
+        self.register_help();
+    }
+
+    /// Présentations détaillées et touches de chaque jeu, pour le panneau de
+    /// détails du menu Games et la page Controls du menu de pause
+    fn register_help(&mut self) {
+        self.help(
+            "snake",
+            "Steer the snake to the food. Every bite makes it longer and a little faster; \
+             hitting a wall or your own tail ends the game.",
+            &[("←↑↓→", "Steer the snake")],
+        );
+        self.help(
+            "snake-race",
+            "An AI snake chases the same food on the board. Eat more than it before one \
+             of you crashes to win the race.",
+            &[("←↑↓→", "Steer the snake")],
+        );
+        self.help(
+            "snake-sprint",
+            "Classic Snake with an energy bar: hold Space to move at double speed while \
+             energy lasts. Every bite refills part of the bar.",
+            &[("←↑↓→", "Steer the snake"), ("Hold Space", "Sprint")],
+        );
+        self.help(
+            "tetris",
+            "Rotate and drop the falling pieces to complete horizontal lines. Cleared lines \
+             score points and raise the level, which makes pieces fall faster.",
+            &[
+                ("←→", "Move the piece"),
+                ("↑", "Rotate"),
+                ("↓", "Soft drop"),
+                ("Space", "Hard drop"),
+            ],
+        );
+        self.help(
+            "tetris-dig",
+            "The board starts filled with garbage rows. Clear them all in as few pieces \
+             and as little time as you can.",
+            &[
+                ("←→", "Move the piece"),
+                ("↑", "Rotate"),
+                ("↓", "Soft drop"),
+                ("Space", "Hard drop"),
+            ],
+        );
+        self.help(
+            "tetris-doubles",
+            "Two players share one wide board, each with their own falling piece, and \
+             clear lines together.",
+            &[
+                ("A/D W S Space", "Player 1: move, rotate, soft/hard drop"),
+                ("←→ ↑ ↓ Enter", "Player 2: move, rotate, soft/hard drop"),
+            ],
+        );
+        self.help(
+            "pong",
+            "Keep the ball in play with your paddle and send it past your opponent. Play \
+             against the AI or a friend on the same keyboard.",
+            &[
+                ("W/S or A/D", "Move player 1"),
+                ("↑↓ or ←→", "Move player 2"),
+            ],
+        );
+        self.help(
+            "2048",
+            "Slide the tiles: two equal tiles merge into their sum. Reach the 2048 tile \
+             to win, then keep going for a higher score.",
+            &[
+                ("←↑↓→ / WASD", "Slide the tiles"),
+                ("C", "Keep playing after 2048"),
+            ],
+        );
+        self.help(
+            "2048-autoplay",
+            "An expectimax bot plays 2048 on its own. Adjust its speed and show the \
+             heatmap of the moves it considers.",
+            &[("+/-", "Speed"), ("H", "Heatmap"), ("Space", "Pause")],
+        );
+        self.help(
+            "puzzles",
+            "Hand-made 2048 and Tetris positions, each with an objective to reach in a \
+             limited number of moves or lines.",
+            &[("↑↓", "Choose a puzzle"), ("Enter", "Play")],
+        );
+        self.help(
+            "minesweeper",
+            "Reveal every safe cell without touching a mine. Numbers tell how many mines \
+             surround a cell; flag the cells you suspect.",
+            &[
+                ("←↑↓→ / WASD", "Move the cursor"),
+                ("Space", "Reveal"),
+                ("F", "Flag / question mark"),
+            ],
+        );
+        self.help(
+            "breakout",
+            "Bounce the ball off your paddle to break every brick. Power-ups fall from \
+             broken bricks; losing all your lives ends the game.",
+            &[
+                ("←→ / A D", "Move the paddle"),
+                ("Space", "Launch the ball"),
+                ("P", "Pause"),
+                ("H", "Assist"),
+                ("B", "Modes"),
+            ],
+        );
+        self.help(
+            "game-of-life",
+            "Conway's cellular automaton: cells live, die or are born from their \
+             neighbors. Draw patterns in the editor and watch them evolve.",
+            &[
+                ("P", "Pause / run"),
+                ("N", "Step"),
+                ("E", "Edit"),
+                ("±", "Speed"),
+                ("←↑↓→", "Pan"),
+                ("F1-F4", "Grid size"),
+                ("K / L", "Snapshot / snapshots"),
+            ],
+        );
+        self.help(
+            "game-of-life-tutorial",
+            "Short lessons on a tiny grid that introduce the rules of life one at a time.",
+            &[
+                ("←↑↓→", "Move"),
+                ("Enter", "Toggle a cell"),
+                ("S", "Step"),
+                ("N/P", "Next / previous lesson"),
+                ("H", "Hints"),
+            ],
+        );
+        self.help(
+            "rhythm",
+            "Notes scroll down four lanes in time with the music. Hit each one as it \
+             reaches the line to build your combo.",
+            &[("D F J K / ←↓↑→", "Hit a lane"), ("B", "Modes")],
+        );
+        self.help(
+            "gauntlet",
+            "Short stages of several games in a row; the scores of each stage add up to \
+             one combined score.",
+            &[("Enter", "Start the stage"), ("Q", "Give up")],
+        );
+        self.help(
+            "tournament",
+            "Enter 2 to 8 player names for a seeded knockout bracket of local Pong \
+             matches, played until one champion remains.",
+            &[("Enter", "Add a player / play the next match")],
+        );
+        self.help(
+            "hot-seat",
+            "Two players take turns on the same 2048 or Minesweeper game and compare \
+             their results.",
+            &[("↑↓", "Choose the game"), ("Enter", "Select / next turn")],
         );
     }
 }
 
+/// Forme canonique d'un nom de jeu pour la recherche : minuscules, espaces et
+/// soulignés remplacés par des tirets
+fn normalize_name(name: &str) -> String {
+    name.trim().to_lowercase().replace([' ', '_'], "-")
+}
+
+/// Jeux dans l'ordre choisi par le joueur : ceux qu'il a placés d'abord,
+/// puis les autres dans leur ordre par défaut. Les jeux masqués ne sont
+/// gardés qu'avec `include_hidden`
+pub fn arrange<'a>(
+    games: &[&'a GameInfo],
+    list: &GameListConfig,
+    include_hidden: bool,
+) -> Vec<&'a GameInfo> {
+    let placed = list
+        .order
+        .iter()
+        .filter_map(|id| games.iter().find(|game| &game.id == id));
+    let others = games.iter().filter(|game| !list.order.contains(&game.id));
+
+    placed
+        .chain(others)
+        .filter(|game| include_hidden || !list.hidden.contains(&game.id))
+        .copied()
+        .collect()
+}
+
+#[cfg(feature = "native")]
 impl Default for GameRegistry {
     fn default() -> Self {
         Self::new()
     }
 }
+
+/// Distance d'édition (insertions, suppressions, substitutions) entre deux
+/// chaînes, caractère par caractère
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    // Ligne précédente de la matrice des distances
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
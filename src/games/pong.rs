@@ -1,13 +1,19 @@
 use crate::audio::{AudioManager, SoundEffect};
-use crate::core::{Game, GameAction};
-use crate::highscores::{GameData, HighScoreManager, Score};
-use crossterm::event::{KeyCode, KeyEvent};
+use crate::config::{ConfigManager, PongDifficulty, PongLayout};
+use crate::core::bot::{PaddleBot, Steer};
+use crate::core::clock::GameClock;
+use crate::core::rng;
+use crate::core::{Game, GameAction, GameStatus};
+use crate::event::{KeyCode, KeyEvent};
+use crate::highscores::{current_player_name, GameData, HighScoreManager, Score};
+use crate::music::stingers;
+use crate::ui::{glyphs, preview, style, trail::Trail, SelectableList};
 use rand::Rng;
 use ratatui::{
     layout::{Constraint, Layout, Margin, Rect},
     style::{Color, Style, Stylize},
     text::Line,
-    widgets::{Block, Clear, Paragraph},
+    widgets::{Block, Clear, ListItem, Paragraph},
 };
 use std::time::Duration;
 
@@ -27,6 +33,128 @@ pub struct Velocity {
 pub enum GameMode {
     SinglePlayer, // Contre IA
     TwoPlayer,    // 2 joueurs
+    Demo,         // IA contre IA, sans joueur
+}
+
+/// Modes proposés dans le menu de sélection, dans l'ordre d'affichage
+const MODES: [(GameMode, &str); 3] = [
+    (GameMode::SinglePlayer, "🤖 Single Player (vs AI)"),
+    (GameMode::TwoPlayer, "👥 Two Players"),
+    (GameMode::Demo, "🎬 AI vs AI (Demo)"),
+];
+
+/// Frames (25ms) d'affichage du résultat avant qu'une démo ne reparte
+const DEMO_RESTART_FRAMES: u32 = 120;
+
+/// Réglages du bot adverse aux deux bouts de l'échelle de difficulté ;
+/// Normal est à mi-chemin
+const EASY_AI: AiParams = AiParams {
+    skill: 0.45,
+    reaction_frames: 5.0,
+    dead_zone: 2.5,
+};
+const HARD_AI: AiParams = AiParams {
+    skill: 0.95,
+    reaction_frames: 1.0,
+    dead_zone: 0.5,
+};
+
+/// Niveau ajouté ou retiré à l'IA dynamique par point d'écart
+const DYNAMIC_STEP: f32 = 0.2;
+
+const PADDLE_LENGTH: f32 = 4.0;
+const PADDLE_SPEED: f32 = 2.5;
+
+/// Paramètres d'un [`PaddleBot`]
+#[derive(Debug, Clone, Copy)]
+struct AiParams {
+    /// Précision, de 0.0 à 1.0
+    skill: f32,
+    /// Frames entre deux décisions
+    reaction_frames: f32,
+    /// Écart toléré avant de bouger
+    dead_zone: f32,
+}
+
+/// Niveau de l'IA entre 0.0 (Easy) et 1.0 (Hard) au début d'un match
+fn starting_level(difficulty: PongDifficulty) -> f32 {
+    match difficulty {
+        PongDifficulty::Easy => 0.0,
+        PongDifficulty::Normal | PongDifficulty::Dynamic => 0.5,
+        PongDifficulty::Hard => 1.0,
+    }
+}
+
+/// Bot réglé pour un niveau entre 0.0 (Easy) et 1.0 (Hard)
+fn ai_bot(level: f32) -> PaddleBot {
+    let lerp = |easy: f32, hard: f32| easy + (hard - easy) * level;
+    PaddleBot::new(
+        lerp(EASY_AI.skill, HARD_AI.skill),
+        lerp(EASY_AI.reaction_frames, HARD_AI.reaction_frames).round() as u32,
+        lerp(EASY_AI.dead_zone, HARD_AI.dead_zone),
+    )
+}
+
+/// Sens du terrain à l'écran. La physique travaille toujours en coordonnées
+/// de terrain : `x` va d'un joueur à l'autre, `y` suit les raquettes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Orientation {
+    /// Raquettes à gauche et à droite
+    Horizontal,
+    /// Raquettes en bas (joueur 1) et en haut
+    Vertical,
+}
+
+impl Orientation {
+    /// Choisit le sens du terrain pour une zone d'écran donnée
+    fn for_area(layout: PongLayout, area: Rect) -> Self {
+        match layout {
+            PongLayout::Horizontal => Orientation::Horizontal,
+            PongLayout::Vertical => Orientation::Vertical,
+            // Une ligne du terminal est environ deux fois plus haute qu'une
+            // colonne n'est large
+            PongLayout::Auto if area.width < area.height * 2 => Orientation::Vertical,
+            PongLayout::Auto => Orientation::Horizontal,
+        }
+    }
+
+    /// Échelle du déplacement le long du terrain, d'un joueur à l'autre
+    fn length_scale(self) -> f32 {
+        match self {
+            Orientation::Horizontal => 1.0,
+            Orientation::Vertical => 0.5,
+        }
+    }
+
+    /// Échelle en travers du terrain, pour les raquettes et la balle
+    fn breadth_scale(self) -> f32 {
+        match self {
+            Orientation::Horizontal => 1.0,
+            Orientation::Vertical => 2.0,
+        }
+    }
+
+    /// Longueur et largeur du terrain pour la place disponible
+    fn field_size(self, area: Rect) -> (f32, f32) {
+        match self {
+            Orientation::Horizontal => (
+                area.width.clamp(40, 120) as f32, // Largeur max 120, min 40
+                area.height.clamp(15, 30) as f32, // Hauteur max 30, min 15
+            ),
+            Orientation::Vertical => (
+                area.height.clamp(15, 40) as f32,
+                area.width.clamp(20, 60) as f32,
+            ),
+        }
+    }
+
+    /// Case d'écran (colonne, ligne) d'un point du terrain, relative au coin du terrain
+    fn to_screen(self, length: f32, x: f32, y: f32) -> (u16, u16) {
+        match self {
+            Orientation::Horizontal => (x as u16, y as u16),
+            Orientation::Vertical => (y as u16, (length - 1.0 - x).max(0.0) as u16),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -45,7 +173,7 @@ pub struct Ball {
 
 impl Ball {
     fn new(width: f32, height: f32) -> Self {
-        let mut rng = rand::rng();
+        let mut rng = rng::rng();
         let angle = rng.random_range(-std::f32::consts::PI / 4.0..std::f32::consts::PI / 4.0);
         let speed = 0.8;
         let direction = if rng.random_bool(0.5) { 1.0 } else { -1.0 };
@@ -78,11 +206,19 @@ impl Paddle {
     fn new(x: f32, y: f32) -> Self {
         Self {
             position: Position { x, y },
-            height: 4.0,
-            speed: 2.5,
+            height: PADDLE_LENGTH,
+            speed: PADDLE_SPEED,
         }
     }
 
+    /// Adapte la raquette au sens du terrain sans déplacer son centre
+    fn set_orientation(&mut self, orientation: Orientation, field_height: f32) {
+        let center = self.get_center();
+        self.height = PADDLE_LENGTH * orientation.breadth_scale();
+        self.speed = PADDLE_SPEED * orientation.breadth_scale();
+        self.position.y = (center - self.height / 2.0).clamp(0.0, field_height - self.height);
+    }
+
     fn move_up(&mut self, _field_height: f32) {
         self.position.y = (self.position.y - self.speed).max(0.0);
     }
@@ -99,14 +235,18 @@ impl Paddle {
 pub struct PongGame {
     state: PongState,
     mode: GameMode,
-    selected_mode: usize, // Pour le menu de sélection de mode
+    mode_list: SelectableList, // Pour le menu de sélection de mode
 
-    // Terrain
+    // Terrain, en coordonnées de terrain : `width` d'un joueur à l'autre,
+    // `height` le long des raquettes
     width: f32,
     height: f32,
+    layout: PongLayout,
+    orientation: Orientation,
 
     // Objets du jeu
     ball: Ball,
+    trail: Trail,
     player1: Paddle, // Joueur gauche
     player2: Paddle, // Joueur droite ou IA
 
@@ -114,90 +254,153 @@ pub struct PongGame {
     score_player1: u32,
     score_player2: u32,
     max_score: u32,
+    /// Couleurs des joueurs 1 et 2 : raquettes et scores
+    colors: [Color; 2],
 
     // IA
-    ai_difficulty: f32,     // Entre 0.0 et 1.0
-    ai_update_counter: u32, // Compteur pour ralentir l'IA
+    left_bot: PaddleBot,  // Joueur 1 en mode démo
+    right_bot: PaddleBot, // Joueur 2 hors mode 2 joueurs
+    demo_restart_frames: u32,
+    difficulty: PongDifficulty,
+    /// Niveau de l'IA solo au début du match puis après chaque point, pour
+    /// le résumé de fin de match
+    ai_levels: Vec<f32>,
 
     // Audio
     audio: AudioManager,
-    music_started: bool,
 
     // High scores
     highscore_manager: HighScoreManager,
-    start_time: std::time::Instant,
+    clock: GameClock,
     score_saved: bool,
+    /// Match de tournoi : aucun score enregistré
+    friendly: bool,
 }
 
 impl PongGame {
-    pub fn new() -> Self {
+    pub fn new(audio: AudioManager) -> Self {
         let width = 60.0;
         let height = 20.0;
 
         Self {
             state: PongState::Menu,
             mode: GameMode::SinglePlayer,
-            selected_mode: 0,
+            mode_list: SelectableList::new(),
 
             width,
             height,
+            layout: ConfigManager::new()
+                .map(|config| config.get_gameplay_config().pong_layout)
+                .unwrap_or_default(),
+            orientation: Orientation::Horizontal,
 
             ball: Ball::new(width, height),
+            trail: Trail::new(
+                ConfigManager::new()
+                    .map(|config| config.get_display_config().ball_trail.length())
+                    .unwrap_or(0),
+            ),
             player1: Paddle::new(2.0, height / 2.0 - 2.0),
             player2: Paddle::new(width - 4.0, height / 2.0 - 2.0),
 
             score_player1: 0,
             score_player2: 0,
             max_score: 5,
+            colors: style::player_colors(),
 
-            ai_difficulty: 0.7, // IA modérément difficile
-            ai_update_counter: 0,
+            // La démo oppose deux IA de niveau Normal
+            left_bot: ai_bot(0.5),
+            right_bot: ai_bot(0.5),
+            demo_restart_frames: 0,
+            difficulty: ConfigManager::new()
+                .map(|config| config.get_gameplay_config().pong_difficulty)
+                .unwrap_or_default(),
+            ai_levels: Vec::new(),
 
-            audio: AudioManager::default(),
-            music_started: false,
+            audio,
 
             highscore_manager: HighScoreManager::default(),
-            start_time: std::time::Instant::now(),
+            clock: GameClock::new(),
             score_saved: false,
+            friendly: false,
         }
     }
 
+    /// Match à deux joueurs lancé tout de suite, hors classements (matchs
+    /// du tournoi)
+    pub fn friendly_match(audio: AudioManager) -> Self {
+        let mut game = Self::new(audio);
+        game.friendly = true;
+        game.start_game(GameMode::TwoPlayer);
+        game
+    }
+
+    /// Démo IA contre IA lancée tout de suite (écran titre)
+    pub fn demo(audio: AudioManager) -> Self {
+        let mut game = Self::new(audio);
+        game.start_game(GameMode::Demo);
+        game
+    }
+
+    /// Points du joueur 1 et du joueur 2
+    pub fn match_score(&self) -> (u32, u32) {
+        (self.score_player1, self.score_player2)
+    }
+
     fn start_game(&mut self, mode: GameMode) {
         self.mode = mode;
         self.state = PongState::Playing;
         self.score_player1 = 0;
         self.score_player2 = 0;
         self.score_saved = false;
-        self.start_time = std::time::Instant::now();
+        self.demo_restart_frames = 0;
+        self.clock.start();
         self.reset_positions();
+
+        let level = match mode {
+            GameMode::SinglePlayer => starting_level(self.difficulty),
+            _ => 0.5,
+        };
+        self.right_bot = ai_bot(level);
+        self.ai_levels = vec![level];
+    }
+
+    /// L'IA dynamique se renforce quand le joueur mène et se relâche quand
+    /// il est mené
+    fn adapt_ai(&mut self) {
+        if self.mode != GameMode::SinglePlayer || self.difficulty != PongDifficulty::Dynamic {
+            return;
+        }
+        let lead = self.score_player1 as f32 - self.score_player2 as f32;
+        let level = (starting_level(self.difficulty) + lead * DYNAMIC_STEP).clamp(0.0, 1.0);
+        self.right_bot = ai_bot(level);
+        self.ai_levels.push(level);
     }
 
     fn reset_positions(&mut self) {
         self.ball.reset(self.width, self.height);
+        self.trail.clear();
         self.player1.position.y = self.height / 2.0 - self.player1.height / 2.0;
         self.player2.position.y = self.height / 2.0 - self.player2.height / 2.0;
     }
 
     fn start_music_if_needed(&mut self) {
-        if !self.music_started && self.audio.is_music_enabled() && self.state == PongState::Playing
-        {
-            self.audio.play_pong_music();
-            self.music_started = true;
+        if !self.audio.is_music_enabled() {
+            return;
         }
 
-        // Relancer la musique si elle est finie
-        if self.music_started
-            && self.audio.is_music_enabled()
-            && self.state == PongState::Playing
-            && self.audio.is_music_empty()
-        {
-            // Jouer version rapide si la balle va très vite
-            let ball_speed = (self.ball.velocity.dx.powi(2) + self.ball.velocity.dy.powi(2)).sqrt();
-            if ball_speed > 1.5 {
-                self.audio.play_pong_music_fast();
-            } else {
-                self.audio.play_pong_music();
-            }
+        if self.state != PongState::Playing {
+            // La musique s'arrête à la fin de son tour
+            self.audio.finish_music_loop();
+            return;
+        }
+
+        // Jouer version rapide si la balle va très vite
+        let ball_speed = (self.ball.velocity.dx.powi(2) + self.ball.velocity.dy.powi(2)).sqrt();
+        if ball_speed > 1.5 {
+            self.audio.play_pong_music_fast();
+        } else {
+            self.audio.play_pong_music();
         }
     }
 
@@ -206,8 +409,8 @@ impl PongGame {
         let old_y = self.ball.position.y;
 
         // Mettre à jour la position
-        self.ball.position.x += self.ball.velocity.dx;
-        self.ball.position.y += self.ball.velocity.dy;
+        self.ball.position.x += self.ball.velocity.dx * self.orientation.length_scale();
+        self.ball.position.y += self.ball.velocity.dy * self.orientation.breadth_scale();
 
         // Rebond sur les murs haut et bas
         if self.ball.position.y <= 0.0 || self.ball.position.y >= self.height - 1.0 {
@@ -222,36 +425,26 @@ impl PongGame {
     }
 
     fn update_ai(&mut self) {
-        if self.mode == GameMode::SinglePlayer {
-            // L'IA ne réagit que toutes les 3 frames pour éviter les mouvements épileptiques
-            self.ai_update_counter += 1;
-            if self.ai_update_counter < 3 {
-                return;
-            }
-            self.ai_update_counter = 0;
-
-            let ball_center_y = self.ball.position.y;
-            let paddle_center_y = self.player2.get_center();
-
-            let diff = ball_center_y - paddle_center_y;
-
-            // L'IA n'est pas parfaite, elle a une vitesse limitée et parfois rate
-            let mut rng = rand::rng();
-            let _reaction_speed = self.ai_difficulty * self.player2.speed;
-
-            // Zone morte élargie pour éviter les mouvements épileptiques
-            let dead_zone = 1.5; // Zone morte plus large
+        if self.mode != GameMode::TwoPlayer {
+            let steer = self
+                .right_bot
+                .steer(self.player2.get_center(), self.ball.position.y);
+            Self::apply_steer(&mut self.player2, steer, self.height);
+        }
 
-            // Ajouter un peu d'imprécision à l'IA
-            let error = rng.random_range(-0.3..0.3) * (1.0 - self.ai_difficulty);
-            let target_diff = diff + error;
+        if self.mode == GameMode::Demo {
+            let steer = self
+                .left_bot
+                .steer(self.player1.get_center(), self.ball.position.y);
+            Self::apply_steer(&mut self.player1, steer, self.height);
+        }
+    }
 
-            // Ne bouger que si on est vraiment loin du centre
-            if target_diff > dead_zone {
-                self.player2.move_down(self.height);
-            } else if target_diff < -dead_zone {
-                self.player2.move_up(self.height);
-            }
+    fn apply_steer(paddle: &mut Paddle, steer: Steer, field_height: f32) {
+        match steer {
+            Steer::Backward => paddle.move_up(field_height),
+            Steer::Forward => paddle.move_down(field_height),
+            Steer::Stay => {}
         }
     }
 
@@ -297,6 +490,7 @@ impl PongGame {
         if self.ball.position.x >= self.width {
             self.score_player1 += 1;
             self.audio.play_sound(SoundEffect::PongScore);
+            self.adapt_ai();
             self.check_game_over();
             if self.state == PongState::Playing {
                 self.reset_positions();
@@ -307,6 +501,7 @@ impl PongGame {
         if self.ball.position.x <= 0.0 {
             self.score_player2 += 1;
             self.audio.play_sound(SoundEffect::PongScore);
+            self.adapt_ai();
             self.check_game_over();
             if self.state == PongState::Playing {
                 self.reset_positions();
@@ -317,16 +512,30 @@ impl PongGame {
     fn check_game_over(&mut self) {
         if self.score_player1 >= self.max_score || self.score_player2 >= self.max_score {
             self.state = PongState::GameOver;
-            // Arrêter la musique normale et jouer la célébration
-            self.audio.stop_music();
-            self.audio.play_pong_music_celebration();
-            self.music_started = false;
+            // Jingle de fin de match, de défaite si l'IA a battu le joueur
+            let lost = self.mode == GameMode::SinglePlayer && self.score_player2 >= self.max_score;
+            let stinger = if lost {
+                &stingers::PONG_GAME_OVER
+            } else {
+                &stingers::PONG_VICTORY
+            };
+            self.audio.play_stinger(stinger, None);
 
             // Sauvegarder le score si c'est un high score et pas encore sauvé
             self.save_high_score_if_needed();
         }
     }
 
+    /// Passe d'un sens de terrain à l'autre ; les dimensions suivent avec
+    /// update_dimensions
+    fn set_orientation(&mut self, orientation: Orientation) {
+        if self.orientation != orientation {
+            self.orientation = orientation;
+            self.player1.set_orientation(orientation, self.height);
+            self.player2.set_orientation(orientation, self.height);
+        }
+    }
+
     fn update_dimensions(&mut self, new_width: f32, new_height: f32) {
         if self.width != new_width || self.height != new_height {
             let width_ratio = new_width / self.width;
@@ -339,46 +548,48 @@ impl PongGame {
             // Ajuster les positions proportionnellement
             self.ball.position.x *= width_ratio;
             self.ball.position.y *= height_ratio;
+            self.trail.clear();
 
-            self.player1.position.y *= height_ratio;
+            self.player1.position.y = (self.player1.position.y * height_ratio)
+                .clamp(0.0, new_height - self.player1.height);
             self.player2.position.x = new_width - 4.0; // Repositionner à droite
-            self.player2.position.y *= height_ratio;
+            self.player2.position.y = (self.player2.position.y * height_ratio)
+                .clamp(0.0, new_height - self.player2.height);
         }
     }
 
     fn save_high_score_if_needed(&mut self) {
         // Ne sauvegarder qu'une seule fois
-        if self.score_saved {
+        if self.score_saved || self.friendly {
             return;
         }
 
         // On sauvegarde seulement le score du joueur humain (joueur 1)
         // En mode single player, le score du joueur 1 est ce qui compte
         // En mode 2 joueurs, on peut sauvegarder le meilleur des deux scores
-        let player_score = match self.mode {
-            GameMode::SinglePlayer => self.score_player1, // Score contre l'IA
-            GameMode::TwoPlayer => self.score_player1.max(self.score_player2), // Meilleur score en 2 joueurs
+        // avec le plus bas comme score de l'adversaire
+        let (player_score, opponent_score) = match self.mode {
+            GameMode::SinglePlayer => (self.score_player1, self.score_player2),
+            GameMode::TwoPlayer => (
+                self.score_player1.max(self.score_player2),
+                self.score_player1.min(self.score_player2),
+            ),
+            GameMode::Demo => return, // Aucun joueur humain
         };
 
         // Vérifier si c'est un high score
         if self.highscore_manager.is_high_score("pong", player_score) {
-            let duration = self.start_time.elapsed().as_secs();
-
-            // Le score de l'adversaire (IA ou joueur 2)
-            let opponent_score = match self.mode {
-                GameMode::SinglePlayer => self.score_player2, // Score de l'IA
-                GameMode::TwoPlayer => self.score_player1.min(self.score_player2), // Score le plus bas
-            };
+            let duration = self.clock.elapsed_secs();
 
             let game_data = GameData::Pong {
                 opponent_score,
                 duration_seconds: duration,
             };
 
-            let score = Score::new("Anonymous".to_string(), player_score, game_data);
+            let score = Score::new(current_player_name(), player_score, game_data);
 
             // Sauvegarder le score
-            if let Ok(_is_top_10) = self.highscore_manager.add_score("pong", score) {
+            if let Ok(_new_score) = self.highscore_manager.add_score("pong", score) {
                 self.score_saved = true;
             }
         }
@@ -388,61 +599,47 @@ impl PongGame {
 impl Game for PongGame {
     fn handle_key(&mut self, key: KeyEvent) -> GameAction {
         match self.state {
-            PongState::Menu => match key.code {
-                KeyCode::Up => {
-                    self.selected_mode = if self.selected_mode == 0 { 1 } else { 0 };
-                    GameAction::Continue
+            PongState::Menu => {
+                if self.mode_list.handle_key(key, MODES.len()) {
+                    return GameAction::Continue;
                 }
-                KeyCode::Down => {
-                    self.selected_mode = if self.selected_mode == 1 { 0 } else { 1 };
-                    GameAction::Continue
-                }
-                KeyCode::Enter => {
-                    let mode = if self.selected_mode == 0 {
-                        GameMode::SinglePlayer
-                    } else {
-                        GameMode::TwoPlayer
-                    };
-                    self.start_game(mode);
-                    GameAction::Continue
+
+                match key.code {
+                    KeyCode::Enter => {
+                        let (mode, _) = MODES[self.mode_list.selected()];
+                        self.start_game(mode);
+                        GameAction::Continue
+                    }
+                    KeyCode::Char('q') => GameAction::Quit,
+                    _ => GameAction::Continue,
                 }
-                KeyCode::Char('q') => GameAction::Quit,
-                _ => GameAction::Continue,
-            },
+            }
             PongState::Playing => {
                 match key.code {
-                    // Contrôles joueur 1 (gauche)
-                    KeyCode::Char('w') => {
+                    // Contrôles joueur 1 (gauche, ou bas en terrain vertical)
+                    KeyCode::Char('w' | 'a') if self.mode != GameMode::Demo => {
                         self.player1.move_up(self.height);
                         GameAction::Continue
                     }
-                    KeyCode::Char('s') => {
+                    KeyCode::Char('s' | 'd') if self.mode != GameMode::Demo => {
                         self.player1.move_down(self.height);
                         GameAction::Continue
                     }
-                    // Contrôles joueur 2 (droite) - seulement en mode 2 joueurs
-                    KeyCode::Up if self.mode == GameMode::TwoPlayer => {
+                    // Contrôles joueur 2 (droite, ou haut) - seulement en mode 2 joueurs
+                    KeyCode::Up | KeyCode::Left if self.mode == GameMode::TwoPlayer => {
                         self.player2.move_up(self.height);
                         GameAction::Continue
                     }
-                    KeyCode::Down if self.mode == GameMode::TwoPlayer => {
+                    KeyCode::Down | KeyCode::Right if self.mode == GameMode::TwoPlayer => {
                         self.player2.move_down(self.height);
                         GameAction::Continue
                     }
                     KeyCode::Char('q') => GameAction::Quit,
-                    KeyCode::Esc => {
-                        self.state = PongState::Menu;
-                        self.audio.stop_music();
-                        self.music_started = false;
-                        GameAction::Continue
-                    }
                     // Contrôles audio/musique
                     KeyCode::Char('m') => {
                         self.audio.toggle_music();
                         if self.audio.is_music_enabled() {
                             self.start_music_if_needed();
-                        } else {
-                            self.music_started = false;
                         }
                         GameAction::Continue
                     }
@@ -480,25 +677,84 @@ impl Game for PongGame {
             self.update_ai();
             self.check_ball_collision();
             self.check_scoring();
+            self.trail.push(self.ball.position.x, self.ball.position.y);
+        } else if self.state == PongState::GameOver && self.mode == GameMode::Demo {
+            // La démo s'enchaîne toute seule
+            self.demo_restart_frames += 1;
+            if self.demo_restart_frames >= DEMO_RESTART_FRAMES {
+                self.audio.stop_music();
+                self.start_game(GameMode::Demo);
+            }
         }
         GameAction::Continue
     }
 
-    fn draw(&mut self, frame: &mut ratatui::Frame) {
-        draw_pong_game(frame, self);
+    fn preview(&self, frame: &mut ratatui::Frame, area: Rect) {
+        draw_preview(frame, area);
+    }
+
+    fn draw(&mut self, frame: &mut ratatui::Frame, area: Rect) {
+        draw_pong_game(frame, area, self);
     }
 
     fn tick_rate(&self) -> Duration {
         Duration::from_millis(25) // Très fluide et réactif
     }
-}
 
-fn draw_pong_game(frame: &mut ratatui::Frame, game: &mut PongGame) {
-    let area = frame.area();
+    fn status(&self) -> GameStatus {
+        match self.state {
+            PongState::Menu => GameStatus::Menu,
+            PongState::Playing if self.clock.is_suspended() => GameStatus::Paused,
+            PongState::Playing => GameStatus::Running,
+            // La démo relance une partie après un nombre de ticks fixe
+            PongState::GameOver if self.mode == GameMode::Demo => GameStatus::Running,
+            // Contre l'IA, seul le match gagné par le joueur est une victoire
+            PongState::GameOver
+                if self.mode == GameMode::SinglePlayer && self.score_player2 >= self.max_score =>
+            {
+                GameStatus::GameOver
+            }
+            PongState::GameOver => GameStatus::Victory,
+        }
+    }
+
+    fn title_status(&self) -> Option<String> {
+        (self.state != PongState::Menu)
+            .then(|| format!("{} - {}", self.score_player1, self.score_player2))
+    }
+
+    fn session_score(&self) -> Option<u32> {
+        (self.state != PongState::Menu).then_some(self.score_player1)
+    }
+
+    fn session_mode(&self) -> Option<String> {
+        if self.state == PongState::Menu {
+            return None;
+        }
+        let mode = match self.mode {
+            GameMode::SinglePlayer => "Single Player",
+            GameMode::TwoPlayer => "Two Players",
+            GameMode::Demo => "Demo",
+        };
+        Some(mode.to_string())
+    }
 
+    fn on_pause(&mut self) {
+        self.clock.suspend();
+    }
+
+    fn on_resume(&mut self) {
+        self.clock.unsuspend();
+    }
+
+    fn audio(&self) -> Option<&AudioManager> {
+        Some(&self.audio)
+    }
+}
+
+fn draw_pong_game(frame: &mut ratatui::Frame, area: Rect, game: &mut PongGame) {
     // Fond sombre élégant
-    let background = Block::new().style(Style::default().bg(Color::Rgb(15, 20, 25)));
-    frame.render_widget(background, area);
+    style::fill_area(frame, area, style::background());
 
     match game.state {
         PongState::Menu => draw_mode_selection(frame, area, game),
@@ -507,7 +763,7 @@ fn draw_pong_game(frame: &mut ratatui::Frame, game: &mut PongGame) {
     }
 }
 
-fn draw_mode_selection(frame: &mut ratatui::Frame, area: Rect, game: &PongGame) {
+fn draw_mode_selection(frame: &mut ratatui::Frame, area: Rect, game: &mut PongGame) {
     let chunks = Layout::vertical([
         Constraint::Length(6), // Header
         Constraint::Min(0),    // Menu
@@ -519,9 +775,9 @@ fn draw_mode_selection(frame: &mut ratatui::Frame, area: Rect, game: &PongGame)
     let header_text = vec![
         Line::from(""),
         Line::from(vec![
-            "🏓 ".yellow().bold(),
+            format!("{} ", glyphs::icon("🏓")).yellow().bold(),
             "PONG".cyan().bold(),
-            " 🏓".yellow().bold(),
+            format!(" {}", glyphs::icon("🏓")).yellow().bold(),
         ]),
         Line::from("Choose your game mode".magenta()),
         Line::from(""),
@@ -531,62 +787,44 @@ fn draw_mode_selection(frame: &mut ratatui::Frame, area: Rect, game: &PongGame)
         .alignment(ratatui::layout::Alignment::Center)
         .block(
             Block::bordered()
-                .title(" Game Selection ".white().bold())
+                .title(" Game Selection ".fg(style::text()).bold())
                 .border_style(Style::new().cyan())
-                .style(Style::default().bg(Color::Rgb(25, 35, 45))),
+                .style(Style::default().bg(style::surface())),
         );
     frame.render_widget(header, chunks[0]);
 
     // Menu options
-    let modes = ["🤖 Single Player (vs AI)", "👥 Two Players"];
-    let mut menu_text = vec![Line::from("")];
-
-    for (i, mode) in modes.iter().enumerate() {
-        let style = if i == game.selected_mode {
-            Style::default().fg(Color::Yellow).bold()
-        } else {
-            Style::default().fg(Color::White)
-        };
+    let items: Vec<ListItem> = MODES
+        .iter()
+        .map(|(_, label)| ListItem::new(Line::from(*label).centered()))
+        .collect();
 
-        let prefix = if i == game.selected_mode {
-            "▶ "
-        } else {
-            "  "
-        };
-        menu_text.push(Line::from(vec![
-            prefix.yellow().bold(),
-            (*mode).fg(style.fg.unwrap_or(Color::White)),
-        ]));
-        menu_text.push(Line::from(""));
-    }
+    let block = Block::bordered()
+        .title(" Select Mode ".green().bold())
+        .border_style(Style::new().green())
+        .style(Style::default().bg(style::panel()));
+    let highlight = style::focus(Color::Yellow);
 
-    let menu = Paragraph::new(menu_text)
-        .alignment(ratatui::layout::Alignment::Center)
-        .block(
-            Block::bordered()
-                .title(" Select Mode ".green().bold())
-                .border_style(Style::new().green())
-                .style(Style::default().bg(Color::Rgb(10, 15, 20))),
-        );
-    frame.render_widget(menu, chunks[1]);
+    game.mode_list
+        .render(frame, chunks[1], items, block, highlight);
 
     // Footer
     let footer_text = vec![Line::from(vec![
         "↑↓".cyan().bold(),
-        " Navigate  ".white(),
+        " Navigate  ".fg(style::text()),
         "Enter".green().bold(),
-        " Select  ".white(),
+        " Select  ".fg(style::text()),
         "Q".red().bold(),
-        " Quit".white(),
+        " Quit".fg(style::text()),
     ])];
 
     let footer = Paragraph::new(footer_text)
         .alignment(ratatui::layout::Alignment::Center)
         .block(
             Block::bordered()
-                .title(" Controls ".white().bold())
+                .title(" Controls ".fg(style::text()).bold())
                 .border_style(Style::new().blue())
-                .style(Style::default().bg(Color::Rgb(25, 35, 45))),
+                .style(Style::default().bg(style::surface())),
         );
     frame.render_widget(footer, chunks[2]);
 }
@@ -605,30 +843,38 @@ fn draw_game_field(frame: &mut ratatui::Frame, area: Rect, game: &mut PongGame)
         horizontal: 2,
     });
 
-    // Calculer les dimensions du terrain de jeu (utilise la taille disponible avec des limites)
-    let field_width = inner_area.width.clamp(40, 120) as f32; // Largeur max 120, min 40
-    let field_height = inner_area.height.clamp(15, 30) as f32; // Hauteur max 30, min 15
+    // Choisir le sens du terrain, puis ses dimensions (utilise la taille
+    // disponible avec des limites)
+    let orientation = Orientation::for_area(game.layout, inner_area);
+    let (field_length, field_breadth) = orientation.field_size(inner_area);
 
     // Mettre à jour les dimensions du jeu
-    game.update_dimensions(field_width, field_height);
+    game.set_orientation(orientation);
+    game.update_dimensions(field_length, field_breadth);
 
     // === HEADER AVEC SCORES ===
     let mode_text = match game.mode {
-        GameMode::SinglePlayer => "vs AI",
-        GameMode::TwoPlayer => "2 Players",
+        GameMode::SinglePlayer => match (game.difficulty, game.ai_levels.last()) {
+            (PongDifficulty::Dynamic, Some(level)) => {
+                format!("vs AI, Dynamic {:.0}%", level * 100.0)
+            }
+            (difficulty, _) => format!("vs AI, {}", difficulty.name()),
+        },
+        GameMode::TwoPlayer => "2 Players".to_string(),
+        GameMode::Demo => "AI vs AI".to_string(),
     };
 
     let header_text = vec![
         Line::from(vec![
-            "🏓 ".yellow().bold(),
+            format!("{} ", glyphs::icon("🏓")).yellow().bold(),
             "PONG ".cyan().bold(),
             format!("({mode_text})").gray(),
         ]),
         Line::from(vec![
-            "Player 1: ".blue().bold(),
+            "Player 1: ".fg(game.colors[0]).bold(),
             format!("{}", game.score_player1).white().bold(),
             "  vs  ".gray(),
-            "Player 2: ".red().bold(),
+            "Player 2: ".fg(game.colors[1]).bold(),
             format!("{}", game.score_player2).white().bold(),
             "  |  ".gray(),
             "First to ".yellow(),
@@ -654,8 +900,10 @@ fn draw_game_field(frame: &mut ratatui::Frame, area: Rect, game: &mut PongGame)
     frame.render_widget(game_block, game_area);
 
     // Créer une zone centrée pour le terrain de jeu
-    let game_width = field_width as u16;
-    let game_height = field_height as u16;
+    let (game_width, game_height) = match orientation {
+        Orientation::Horizontal => (field_length as u16, field_breadth as u16),
+        Orientation::Vertical => (field_breadth as u16, field_length as u16),
+    };
     let start_x = inner_area.x + (inner_area.width.saturating_sub(game_width)) / 2;
     let start_y = inner_area.y + (inner_area.height.saturating_sub(game_height)) / 2;
 
@@ -682,14 +930,19 @@ fn draw_game_field(frame: &mut ratatui::Frame, area: Rect, game: &mut PongGame)
                     height: 1,
                 };
 
-                // Ligne centrale en pointillés
-                let symbol = if x == (field_width as u16 / 2) && y % 3 == 0 {
-                    "┃"
-                } else {
-                    " "
+                // Ligne centrale en pointillés, en travers du terrain
+                let (along, across) = match orientation {
+                    Orientation::Horizontal => (x, y),
+                    Orientation::Vertical => (y, x),
+                };
+                let on_center_line = along == field_length as u16 / 2 && across % 3 == 0;
+                let symbol = match (on_center_line, orientation) {
+                    (false, _) => " ",
+                    (true, Orientation::Horizontal) => "┃",
+                    (true, Orientation::Vertical) => "━",
                 };
 
-                let color = if x == (field_width as u16 / 2) && y % 3 == 0 {
+                let color = if on_center_line {
                     Color::Rgb(100, 100, 100)
                 } else {
                     Color::Rgb(20, 25, 30)
@@ -701,10 +954,15 @@ fn draw_game_field(frame: &mut ratatui::Frame, area: Rect, game: &mut PongGame)
         }
     }
 
-    // Dessiner le paddle gauche (joueur 1)
+    // Dessiner le paddle du joueur 1 (gauche, ou bas)
     for i in 0..(game.player1.height as u16) {
-        let paddle_x = playing_area.x + game.player1.position.x as u16;
-        let paddle_y = playing_area.y + (game.player1.position.y as u16) + i;
+        let (dx, dy) = orientation.to_screen(
+            field_length,
+            game.player1.position.x,
+            game.player1.position.y.floor() + i as f32,
+        );
+        let paddle_x = playing_area.x + dx;
+        let paddle_y = playing_area.y + dy;
 
         if paddle_x < playing_area.x + playing_area.width
             && paddle_y < playing_area.y + playing_area.height
@@ -716,16 +974,20 @@ fn draw_game_field(frame: &mut ratatui::Frame, area: Rect, game: &mut PongGame)
                 height: 1,
             };
 
-            let paddle_cell =
-                Paragraph::new("█").style(Style::default().fg(Color::LightBlue).bold());
+            let paddle_cell = Paragraph::new("█").style(Style::default().fg(game.colors[0]).bold());
             frame.render_widget(paddle_cell, paddle_area);
         }
     }
 
-    // Dessiner le paddle droit (joueur 2 ou IA)
+    // Dessiner le paddle du joueur 2 ou de l'IA (droite, ou haut)
     for i in 0..(game.player2.height as u16) {
-        let paddle_x = playing_area.x + game.player2.position.x as u16;
-        let paddle_y = playing_area.y + (game.player2.position.y as u16) + i;
+        let (dx, dy) = orientation.to_screen(
+            field_length,
+            game.player2.position.x,
+            game.player2.position.y.floor() + i as f32,
+        );
+        let paddle_x = playing_area.x + dx;
+        let paddle_y = playing_area.y + dy;
 
         if paddle_x < playing_area.x + playing_area.width
             && paddle_y < playing_area.y + playing_area.height
@@ -737,15 +999,22 @@ fn draw_game_field(frame: &mut ratatui::Frame, area: Rect, game: &mut PongGame)
                 height: 1,
             };
 
-            let paddle_cell =
-                Paragraph::new("█").style(Style::default().fg(Color::LightRed).bold());
+            let paddle_cell = Paragraph::new("█").style(Style::default().fg(game.colors[1]).bold());
             frame.render_widget(paddle_cell, paddle_area);
         }
     }
 
+    // Traînée de la balle, sous la balle
+    game.trail
+        .render(frame.buffer_mut(), playing_area, (0, 200, 200), |x, y| {
+            let (dx, dy) = orientation.to_screen(field_length, x, y);
+            (playing_area.x + dx, playing_area.y + dy)
+        });
+
     // Dessiner la balle
-    let ball_x = playing_area.x + game.ball.position.x as u16;
-    let ball_y = playing_area.y + game.ball.position.y as u16;
+    let (dx, dy) = orientation.to_screen(field_length, game.ball.position.x, game.ball.position.y);
+    let ball_x = playing_area.x + dx;
+    let ball_y = playing_area.y + dy;
 
     if ball_x < playing_area.x + playing_area.width && ball_y < playing_area.y + playing_area.height
     {
@@ -761,11 +1030,20 @@ fn draw_game_field(frame: &mut ratatui::Frame, area: Rect, game: &mut PongGame)
     }
 
     // === FOOTER AVEC CONTRÔLES ===
-    let controls = match game.mode {
-        GameMode::SinglePlayer => {
-            "W/S Move Player 1  •  AI controls Player 2  •  Esc Menu  •  Q Quit"
+    let controls = match (game.mode, orientation) {
+        (GameMode::SinglePlayer, Orientation::Horizontal) => {
+            "W/S Move Player 1  •  AI controls Player 2  •  Esc Pause  •  Q Quit"
+        }
+        (GameMode::SinglePlayer, Orientation::Vertical) => {
+            "A/D Move Player 1  •  AI controls Player 2  •  Esc Pause  •  Q Quit"
+        }
+        (GameMode::TwoPlayer, Orientation::Horizontal) => {
+            "W/S Player 1  •  ↑↓ Player 2  •  Esc Pause  •  Q Quit"
         }
-        GameMode::TwoPlayer => "W/S Player 1  •  ↑↓ Player 2  •  Esc Menu  •  Q Quit",
+        (GameMode::TwoPlayer, Orientation::Vertical) => {
+            "A/D Player 1  •  ←→ Player 2  •  Esc Pause  •  Q Quit"
+        }
+        (GameMode::Demo, _) => "AI controls both paddles  •  Esc Pause  •  Q Quit",
     };
 
     let footer_text = vec![Line::from(controls.white())];
@@ -781,6 +1059,28 @@ fn draw_game_field(frame: &mut ratatui::Frame, area: Rect, game: &mut PongGame)
     frame.render_widget(footer, chunks[2]);
 }
 
+/// Évolution de l'IA dynamique au fil des points : un bloc par niveau,
+/// du plus facile (▁) au plus dur (█)
+fn ai_summary(game: &PongGame) -> Line<'static> {
+    if game.mode != GameMode::SinglePlayer || game.difficulty != PongDifficulty::Dynamic {
+        return Line::from("");
+    }
+    const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let curve: String = game
+        .ai_levels
+        .iter()
+        .map(|level| BLOCKS[(level * (BLOCKS.len() - 1) as f32).round() as usize])
+        .collect();
+    let first = game.ai_levels.first().copied().unwrap_or(0.0);
+    let last = game.ai_levels.last().copied().unwrap_or(0.0);
+
+    Line::from(vec![
+        "AI level: ".white(),
+        curve.magenta().bold(),
+        format!("  {:.0}% → {:.0}%", first * 100.0, last * 100.0).gray(),
+    ])
+}
+
 fn draw_game_over(frame: &mut ratatui::Frame, area: Rect, game: &mut PongGame) {
     // D'abord dessiner le terrain en arrière-plan
     draw_game_field(frame, area, game);
@@ -789,35 +1089,26 @@ fn draw_game_over(frame: &mut ratatui::Frame, area: Rect, game: &mut PongGame) {
     let popup_width = 50.min(area.width);
     let popup_height = 12.min(area.height);
     let popup_area = Rect {
-        x: if area.width >= popup_width {
-            (area.width - popup_width) / 2
-        } else {
-            0
-        },
-        y: if area.height >= popup_height {
-            (area.height - popup_height) / 2
-        } else {
-            0
-        },
+        x: area.x + (area.width - popup_width) / 2,
+        y: area.y + (area.height - popup_height) / 2,
         width: popup_width,
         height: popup_height,
     };
 
     frame.render_widget(Clear, popup_area);
 
-    let winner = if game.score_player1 >= game.max_score {
-        "Player 1 Wins!"
-    } else {
-        match game.mode {
-            GameMode::SinglePlayer => "AI Wins!",
-            GameMode::TwoPlayer => "Player 2 Wins!",
-        }
+    let winner = match (game.mode, game.score_player1 >= game.max_score) {
+        (GameMode::Demo, true) => "Left AI Wins!",
+        (GameMode::Demo, false) => "Right AI Wins!",
+        (_, true) => "Player 1 Wins!",
+        (GameMode::SinglePlayer, false) => "AI Wins!",
+        (GameMode::TwoPlayer, false) => "Player 2 Wins!",
     };
 
     let winner_color = if game.score_player1 >= game.max_score {
-        Color::Blue
+        game.colors[0]
     } else {
-        Color::Red
+        game.colors[1]
     };
 
     let game_over_text = vec![
@@ -828,12 +1119,12 @@ fn draw_game_over(frame: &mut ratatui::Frame, area: Rect, game: &mut PongGame) {
         Line::from(""),
         Line::from(vec![
             "Final Score: ".white(),
-            format!("{}", game.score_player1).blue().bold(),
+            format!("{}", game.score_player1).fg(game.colors[0]).bold(),
             " - ".gray(),
-            format!("{}", game.score_player2).red().bold(),
+            format!("{}", game.score_player2).fg(game.colors[1]).bold(),
         ]),
         Line::from(""),
-        Line::from(""),
+        ai_summary(game),
         Line::from(vec![
             "Press ".gray(),
             "R".green().bold(),
@@ -855,3 +1146,25 @@ fn draw_game_over(frame: &mut ratatui::Frame, area: Rect, game: &mut PongGame) {
         );
     frame.render_widget(popup, popup_area);
 }
+
+/// Aperçu du menu : les deux raquettes, le filet et la balle
+const PREVIEW: [&str; 9] = [
+    "            :           ",
+    "            :           ",
+    "|           :           ",
+    "|           :     o    |",
+    "|           :          |",
+    "            :          |",
+    "            :           ",
+    "            :           ",
+    "            :           ",
+];
+
+fn draw_preview(frame: &mut ratatui::Frame, area: Rect) {
+    preview::draw_board(frame, area, &PREVIEW, |cell| match cell {
+        '|' => "█".fg(Color::White),
+        'o' => "●".fg(Color::Yellow).bold(),
+        ':' => "┆".fg(Color::DarkGray),
+        _ => " ".into(),
+    });
+}
@@ -0,0 +1,274 @@
+//! Écran de sélection des puzzles de 2048 et de Tetris.
+//!
+//! Le puzzle choisi est joué par le vrai jeu, construit avec sa position de
+//! départ ; quitter le puzzle ramène à la liste plutôt qu'au menu principal.
+
+use crate::audio::AudioManager;
+use crate::core::{Game, GameAction, GameStatus};
+use crate::event::{KeyCode, KeyEvent};
+use crate::games::{tetris::TetrisGame, _2048::Game2048};
+use crate::puzzles::{self, Puzzle};
+use crate::ui::{glyphs, style, SelectableList};
+use ratatui::{
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, ListItem, Paragraph},
+};
+use std::collections::BTreeSet;
+use std::time::Duration;
+
+pub struct PuzzleBrowser {
+    puzzles: Vec<Puzzle>,
+    solved: BTreeSet<String>,
+    list: SelectableList,
+    /// Puzzle en cours de partie
+    current: Option<Box<dyn Game>>,
+    /// Sortie audio transmise au jeu de chaque puzzle
+    audio: AudioManager,
+}
+
+impl PuzzleBrowser {
+    pub fn new(audio: AudioManager) -> Self {
+        Self {
+            puzzles: puzzles::all(),
+            solved: puzzles::solved(),
+            list: SelectableList::new(),
+            current: None,
+            audio,
+        }
+    }
+
+    fn start_selected(&mut self) {
+        let Some(puzzle) = self.puzzles.get(self.list.selected()) else {
+            return;
+        };
+
+        self.current = Some(match puzzle.clone() {
+            Puzzle::Game2048(puzzle) => {
+                Box::new(Game2048::from_puzzle(puzzle, self.audio.sibling()))
+            }
+            Puzzle::Tetris(puzzle) => {
+                Box::new(TetrisGame::from_puzzle(puzzle, self.audio.sibling()))
+            }
+        });
+    }
+
+    fn solved_count(&self) -> usize {
+        self.puzzles
+            .iter()
+            .filter(|puzzle| self.solved.contains(puzzle.id()))
+            .count()
+    }
+
+    /// Retour à la liste, avec la progression à jour
+    fn close_current(&mut self) {
+        self.current = None;
+        self.solved = puzzles::solved();
+    }
+}
+
+impl Game for PuzzleBrowser {
+    fn handle_key(&mut self, key: KeyEvent) -> GameAction {
+        if let Some(game) = &mut self.current {
+            if game.handle_key(key) != GameAction::Continue {
+                self.close_current();
+            }
+            return GameAction::Continue;
+        }
+
+        if self.list.handle_key(key, self.puzzles.len()) {
+            return GameAction::Continue;
+        }
+
+        match key.code {
+            KeyCode::Enter | KeyCode::Char(' ') => {
+                self.start_selected();
+                GameAction::Continue
+            }
+            KeyCode::Char('q') => GameAction::Quit,
+            _ => GameAction::Continue,
+        }
+    }
+
+    fn update(&mut self) -> GameAction {
+        if let Some(game) = &mut self.current {
+            if game.update() != GameAction::Continue {
+                self.close_current();
+            }
+        }
+        GameAction::Continue
+    }
+
+    fn draw(&mut self, frame: &mut ratatui::Frame, area: Rect) {
+        match &mut self.current {
+            Some(game) => game.draw(frame, area),
+            None => draw_puzzle_list(frame, area, self),
+        }
+    }
+
+    fn tick_rate(&self) -> Duration {
+        self.current
+            .as_ref()
+            .map_or(Duration::from_millis(100), |game| game.tick_rate())
+    }
+
+    fn status(&self) -> GameStatus {
+        self.current
+            .as_ref()
+            .map_or(GameStatus::Menu, |game| game.status())
+    }
+
+    fn title_status(&self) -> Option<String> {
+        match &self.current {
+            Some(game) => game.title_status(),
+            None => Some(format!(
+                "{}/{} solved",
+                self.solved_count(),
+                self.puzzles.len()
+            )),
+        }
+    }
+
+    fn on_shutdown(&mut self) {
+        if let Some(game) = &mut self.current {
+            game.on_shutdown();
+        }
+    }
+
+    fn on_pause(&mut self) {
+        if let Some(game) = &mut self.current {
+            game.on_pause();
+        }
+    }
+
+    fn on_resume(&mut self) {
+        if let Some(game) = &mut self.current {
+            game.on_resume();
+        }
+    }
+
+    fn captures_esc(&self) -> bool {
+        self.current
+            .as_ref()
+            .is_some_and(|game| game.captures_esc())
+    }
+
+    fn instant_restart(&self) -> bool {
+        self.current
+            .as_ref()
+            .is_some_and(|game| game.instant_restart())
+    }
+
+    fn audio(&self) -> Option<&AudioManager> {
+        self.current.as_ref().and_then(|game| game.audio())
+    }
+
+    fn handle_paste(&mut self, text: &str) {
+        if let Some(game) = &mut self.current {
+            game.handle_paste(text);
+        }
+    }
+}
+
+fn draw_puzzle_list(frame: &mut ratatui::Frame, area: Rect, browser: &mut PuzzleBrowser) {
+    let chunks = Layout::vertical([
+        Constraint::Length(4), // Header avec progression
+        Constraint::Min(0),    // Liste des puzzles
+        Constraint::Length(3), // Footer avec instructions
+    ])
+    .split(area);
+
+    style::fill_area(frame, area, style::background());
+
+    // === HEADER ===
+    let header_text = vec![
+        Line::from(vec![
+            format!("{} ", glyphs::icon("🧠")).yellow().bold(),
+            "PUZZLES".cyan().bold(),
+            format!(" {}", glyphs::icon("🧠")).yellow().bold(),
+        ]),
+        Line::from(vec![
+            "Solved: ".gray(),
+            format!("{}/{}", browser.solved_count(), browser.puzzles.len())
+                .green()
+                .bold(),
+        ]),
+    ];
+    let header = Paragraph::new(header_text)
+        .alignment(Alignment::Center)
+        .block(
+            Block::bordered()
+                .title(" Progress ".fg(style::text()).bold())
+                .border_style(Style::new().cyan())
+                .style(Style::default().bg(style::surface())),
+        );
+    frame.render_widget(header, chunks[0]);
+
+    // === LISTE ===
+    let block = Block::bordered()
+        .title(" Choose a Puzzle ".green().bold())
+        .border_style(Style::new().green())
+        .style(Style::default().bg(style::panel()));
+
+    if browser.puzzles.is_empty() {
+        let message = Paragraph::new("No puzzles available".gray())
+            .alignment(Alignment::Center)
+            .block(block);
+        frame.render_widget(message, chunks[1]);
+    } else {
+        let items: Vec<ListItem> = browser
+            .puzzles
+            .iter()
+            .map(|puzzle| {
+                let (mark, mark_color) = if browser.solved.contains(puzzle.id()) {
+                    ("✓", Color::Green)
+                } else {
+                    ("·", Color::DarkGray)
+                };
+
+                ListItem::new(vec![
+                    Line::from(vec![
+                        Span::styled(format!("  {mark} "), Style::default().fg(mark_color).bold()),
+                        Span::styled(
+                            format!("[{}] ", puzzle.game_name()),
+                            Style::default().fg(Color::Yellow),
+                        ),
+                        Span::styled(puzzle.name(), Style::default().fg(style::text()).bold()),
+                    ]),
+                    Line::from(Span::styled(
+                        format!("      {}", puzzle.objective()),
+                        Style::default().fg(Color::Gray),
+                    )),
+                ])
+            })
+            .collect();
+
+        browser.list.render(
+            frame,
+            chunks[1],
+            items,
+            block,
+            style::focus(Color::Rgb(40, 60, 80)),
+        );
+    }
+
+    // === FOOTER ===
+    let instructions = Line::from(vec![
+        "↑↓".cyan().bold(),
+        " Select  ".fg(style::text()),
+        "Enter".green().bold(),
+        " Play  ".fg(style::text()),
+        "Q".red().bold(),
+        " Back".fg(style::text()),
+    ]);
+    let footer = Paragraph::new(instructions)
+        .alignment(Alignment::Center)
+        .block(
+            Block::bordered()
+                .title(" Controls ".fg(style::text()).bold())
+                .border_style(Style::new().blue())
+                .style(Style::default().bg(style::surface())),
+        );
+    frame.render_widget(footer, chunks[2]);
+}
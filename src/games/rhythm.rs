@@ -0,0 +1,776 @@
+//! Jeu de rythme : les notes du morceau descendent vers une ligne de frappe,
+//! et il faut presser la touche de leur colonne au moment où elles la
+//! croisent. Le temps de jeu suit la position de lecture de la musique
+//! quand elle joue, pour que les notes tombent en même temps que le son.
+
+use crate::audio::AudioManager;
+use crate::core::clock::GameClock;
+use crate::core::{Game, GameAction, GameStatus};
+use crate::event::{KeyCode, KeyEvent};
+use crate::highscores::{current_player_name, GameData, HighScoreManager, Score};
+use crate::music::rhythm::{chart, song_length, RHYTHM_MUSIC};
+use crate::music::{GameMusic, MusicVariant};
+use crate::ui::{glyphs, numbers, preview, style, SelectableList};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style, Stylize},
+    text::Line,
+    widgets::{Block, Clear, ListItem, Paragraph},
+};
+use std::time::Duration;
+
+const LANES: usize = 4;
+/// Touches des colonnes, de gauche à droite ; les flèches marchent aussi
+const LANE_KEYS: [char; LANES] = ['d', 'f', 'j', 'k'];
+const LANE_ARROWS: [KeyCode; LANES] = [KeyCode::Left, KeyCode::Down, KeyCode::Up, KeyCode::Right];
+const LANE_COLORS: [Color; LANES] = [Color::Magenta, Color::Cyan, Color::Green, Color::Yellow];
+const LANE_WIDTH: u16 = 7;
+/// Temps mis par une note pour descendre du haut du terrain à la ligne de frappe
+const LOOKAHEAD: Duration = Duration::from_secs(2);
+/// Écarts maximaux avec l'instant de la note pour chaque appréciation
+const PERFECT_WINDOW: Duration = Duration::from_millis(50);
+const GREAT_WINDOW: Duration = Duration::from_millis(100);
+const GOOD_WINDOW: Duration = Duration::from_millis(150);
+/// Durée d'affichage de la dernière appréciation
+const JUDGEMENT_DISPLAY: Duration = Duration::from_millis(500);
+/// Pause entre la dernière note et l'écran des résultats
+const RESULTS_DELAY: Duration = Duration::from_secs(1);
+/// Écart toléré entre l'horloge et la musique avant de se recaler dessus :
+/// la position de lecture avance par blocs d'échantillons
+const SYNC_TOLERANCE_MS: i64 = 30;
+/// Combo qui ajoute un multiplicateur de points, jusqu'à ×4
+const COMBO_STEP: u32 = 10;
+const MAX_MULTIPLIER: u32 = 4;
+
+/// Tempos proposés dans le menu de sélection, dans l'ordre d'affichage
+const MODES: [(MusicVariant, &str); 2] = [
+    (MusicVariant::Normal, "🥁 Normal (120 BPM)"),
+    (MusicVariant::Fast, "⚡ Fast (150 BPM)"),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GameState {
+    Menu,
+    Playing,
+    Results,
+}
+
+/// Appréciation d'une note, selon l'écart entre la frappe et la note
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Judgement {
+    Perfect,
+    Great,
+    Good,
+    Miss,
+}
+
+impl Judgement {
+    const ALL: [Judgement; 4] = [
+        Judgement::Perfect,
+        Judgement::Great,
+        Judgement::Good,
+        Judgement::Miss,
+    ];
+
+    /// Appréciation d'une frappe à `offset` de la note, `None` si elle est
+    /// trop loin pour compter
+    fn from_offset(offset: Duration) -> Option<Self> {
+        match offset {
+            o if o <= PERFECT_WINDOW => Some(Judgement::Perfect),
+            o if o <= GREAT_WINDOW => Some(Judgement::Great),
+            o if o <= GOOD_WINDOW => Some(Judgement::Good),
+            _ => None,
+        }
+    }
+
+    fn points(self) -> u32 {
+        match self {
+            Judgement::Perfect => 300,
+            Judgement::Great => 200,
+            Judgement::Good => 100,
+            Judgement::Miss => 0,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Judgement::Perfect => "PERFECT",
+            Judgement::Great => "GREAT",
+            Judgement::Good => "GOOD",
+            Judgement::Miss => "MISS",
+        }
+    }
+
+    fn color(self) -> Color {
+        match self {
+            Judgement::Perfect => Color::Yellow,
+            Judgement::Great => Color::Green,
+            Judgement::Good => Color::Cyan,
+            Judgement::Miss => Color::Red,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Note {
+    /// Instant de la note depuis le début du morceau
+    time: Duration,
+    lane: usize,
+    judgement: Option<Judgement>,
+}
+
+pub struct RhythmGame {
+    state: GameState,
+    variant: MusicVariant,
+    mode_list: SelectableList,
+    notes: Vec<Note>,
+    score: u32,
+    combo: u32,
+    max_combo: u32,
+    /// Dernière appréciation et l'instant du morceau où elle a été donnée
+    last_judgement: Option<(Judgement, Duration)>,
+
+    // Temps du morceau : horloge de la partie, recalée sur la musique
+    clock: GameClock,
+    offset_ms: i64,
+    /// La musique demandée a commencé ; elle ne sera pas rejouée en boucle
+    music_started: bool,
+
+    // Audio
+    audio: AudioManager,
+
+    // High scores
+    highscore_manager: HighScoreManager,
+    score_saved: bool,
+}
+
+impl RhythmGame {
+    pub fn new(audio: AudioManager) -> Self {
+        Self {
+            state: GameState::Menu,
+            variant: MusicVariant::Normal,
+            mode_list: SelectableList::new(),
+            notes: Vec::new(),
+            score: 0,
+            combo: 0,
+            max_combo: 0,
+            last_judgement: None,
+
+            clock: GameClock::stopped(),
+            offset_ms: 0,
+            music_started: false,
+
+            audio,
+
+            highscore_manager: HighScoreManager::default(),
+            score_saved: false,
+        }
+    }
+
+    fn start_game(&mut self, variant: MusicVariant) {
+        self.variant = variant;
+        self.restart();
+    }
+
+    fn restart(&mut self) {
+        self.state = GameState::Playing;
+        self.notes = chart(self.variant)
+            .into_iter()
+            .map(|(time, lane)| Note {
+                time,
+                lane,
+                judgement: None,
+            })
+            .collect();
+        self.score = 0;
+        self.combo = 0;
+        self.max_combo = 0;
+        self.last_judgement = None;
+        self.offset_ms = 0;
+        self.music_started = false;
+        self.score_saved = false;
+        self.clock.start();
+
+        self.audio.stop_music();
+    }
+
+    /// Position dans le morceau
+    fn song_time(&self) -> Duration {
+        let ms = self.clock.elapsed().as_millis() as i64 + self.offset_ms;
+        Duration::from_millis(ms.max(0) as u64)
+    }
+
+    /// Lance la variante une seule fois, puis recale le temps du morceau sur
+    /// sa position de lecture tant qu'elle joue
+    fn follow_music(&mut self, variant: MusicVariant) {
+        if !self.audio.is_music_enabled() {
+            return;
+        }
+        let position = self
+            .audio
+            .music_position()
+            .filter(|position| position.bpm == RHYTHM_MUSIC.bpm(variant));
+
+        if !self.music_started {
+            if position.is_some() {
+                self.audio.finish_music_loop();
+                self.music_started = true;
+            } else {
+                self.audio.play_rhythm_music(variant);
+            }
+        }
+
+        if let (Some(position), GameState::Playing) = (position, self.state) {
+            let music_ms = position.elapsed.as_millis() as i64;
+            let drift = music_ms - self.song_time().as_millis() as i64;
+            if drift.abs() > SYNC_TOLERANCE_MS {
+                self.offset_ms += drift;
+            }
+        }
+    }
+
+    /// Frappe dans une colonne : juge la note la plus proche, s'il y en a une
+    /// assez près. Une frappe dans le vide ne coûte rien
+    fn hit(&mut self, lane: usize) {
+        let now = self.song_time();
+        let closest = self
+            .notes
+            .iter_mut()
+            .filter(|note| note.lane == lane && note.judgement.is_none())
+            .map(|note| (note.time.abs_diff(now), note))
+            .min_by_key(|(offset, _)| *offset);
+
+        let Some((offset, note)) = closest else {
+            return;
+        };
+        if let Some(judgement) = Judgement::from_offset(offset) {
+            note.judgement = Some(judgement);
+            self.judge(judgement, now);
+        }
+    }
+
+    fn judge(&mut self, judgement: Judgement, now: Duration) {
+        if judgement == Judgement::Miss {
+            self.combo = 0;
+        } else {
+            self.combo += 1;
+            self.max_combo = self.max_combo.max(self.combo);
+            self.score += judgement.points() * self.multiplier();
+        }
+        self.last_judgement = Some((judgement, now));
+    }
+
+    /// Multiplicateur de points du combo en cours
+    fn multiplier(&self) -> u32 {
+        (1 + self.combo / COMBO_STEP).min(MAX_MULTIPLIER)
+    }
+
+    /// Notes passées sous la ligne sans avoir été frappées
+    fn mark_misses(&mut self) {
+        let now = self.song_time();
+        let missed: Vec<usize> = self
+            .notes
+            .iter()
+            .enumerate()
+            .filter(|(_, note)| note.judgement.is_none() && now > note.time + GOOD_WINDOW)
+            .map(|(index, _)| index)
+            .collect();
+        for index in missed {
+            self.notes[index].judgement = Some(Judgement::Miss);
+            self.judge(Judgement::Miss, now);
+        }
+    }
+
+    fn count(&self, judgement: Judgement) -> usize {
+        self.notes
+            .iter()
+            .filter(|note| note.judgement == Some(judgement))
+            .count()
+    }
+
+    /// Part des points obtenus sur le maximum, en pourcentage
+    fn accuracy_percent(&self) -> u32 {
+        if self.notes.is_empty() {
+            return 0;
+        }
+        let earned: u32 = self
+            .notes
+            .iter()
+            .filter_map(|note| note.judgement)
+            .map(Judgement::points)
+            .sum();
+        earned * 100 / (self.notes.len() as u32 * Judgement::Perfect.points())
+    }
+
+    fn full_combo(&self) -> bool {
+        self.count(Judgement::Miss) == 0
+    }
+
+    fn finish(&mut self) {
+        self.state = GameState::Results;
+        self.clock.pause();
+        self.save_high_score_if_needed();
+
+        if self.full_combo() {
+            self.audio.stop_music();
+            self.music_started = false;
+        }
+    }
+
+    fn save_high_score_if_needed(&mut self) {
+        if self.score_saved {
+            return;
+        }
+
+        if self.highscore_manager.is_high_score("rhythm", self.score) {
+            let game_data = GameData::Rhythm {
+                bpm: RHYTHM_MUSIC.bpm(self.variant),
+                accuracy_percent: self.accuracy_percent(),
+                max_combo: self.max_combo,
+                duration_seconds: self.clock.elapsed_secs(),
+            };
+            let score = Score::new(current_player_name(), self.score, game_data);
+
+            if let Ok(_new_score) = self.highscore_manager.add_score("rhythm", score) {
+                self.score_saved = true;
+            }
+        }
+    }
+}
+
+impl Game for RhythmGame {
+    fn handle_key(&mut self, key: KeyEvent) -> GameAction {
+        match self.state {
+            GameState::Menu => {
+                if self.mode_list.handle_key(key, MODES.len()) {
+                    return GameAction::Continue;
+                }
+
+                match key.code {
+                    KeyCode::Enter => {
+                        let (variant, _) = MODES[self.mode_list.selected()];
+                        self.start_game(variant);
+                        GameAction::Continue
+                    }
+                    KeyCode::Char('q') => GameAction::Quit,
+                    _ => GameAction::Continue,
+                }
+            }
+            GameState::Playing => {
+                let lane = LANE_KEYS
+                    .iter()
+                    .position(|&c| key.code == KeyCode::Char(c))
+                    .or_else(|| LANE_ARROWS.iter().position(|&code| key.code == code));
+                if let Some(lane) = lane {
+                    self.hit(lane);
+                    return GameAction::Continue;
+                }
+
+                match key.code {
+                    KeyCode::Char('r') => {
+                        self.restart();
+                        GameAction::Continue
+                    }
+                    KeyCode::Char('q') => GameAction::Quit,
+                    KeyCode::Char('m') => {
+                        self.audio.toggle_music();
+                        GameAction::Continue
+                    }
+                    _ => GameAction::Continue,
+                }
+            }
+            GameState::Results => match key.code {
+                KeyCode::Char('r') => {
+                    self.restart();
+                    GameAction::Continue
+                }
+                KeyCode::Char('b') => {
+                    self.audio.stop_music();
+                    self.state = GameState::Menu;
+                    GameAction::Continue
+                }
+                KeyCode::Char('q') => GameAction::Quit,
+                KeyCode::Char('m') => {
+                    self.audio.toggle_music();
+                    GameAction::Continue
+                }
+                _ => GameAction::Continue,
+            },
+        }
+    }
+
+    fn update(&mut self) -> GameAction {
+        match self.state {
+            GameState::Playing => {
+                self.follow_music(self.variant);
+                self.mark_misses();
+                if self.song_time() > song_length(self.variant) + RESULTS_DELAY {
+                    self.finish();
+                }
+            }
+            // Petite fanfare pour une partie sans note manquée
+            GameState::Results if self.full_combo() => {
+                self.follow_music(MusicVariant::Celebration);
+            }
+            _ => {}
+        }
+        GameAction::Continue
+    }
+
+    fn preview(&self, frame: &mut ratatui::Frame, area: Rect) {
+        draw_preview(frame, area);
+    }
+
+    fn draw(&mut self, frame: &mut ratatui::Frame, area: Rect) {
+        draw_rhythm_game(frame, area, self);
+    }
+
+    fn tick_rate(&self) -> Duration {
+        // Une image par rafraîchissement courant, pour des notes fluides
+        Duration::from_millis(16)
+    }
+
+    fn status(&self) -> GameStatus {
+        match self.state {
+            GameState::Menu => GameStatus::Menu,
+            GameState::Playing => GameStatus::Running,
+            GameState::Results if self.full_combo() => GameStatus::Victory,
+            GameState::Results => GameStatus::GameOver,
+        }
+    }
+
+    fn on_pause(&mut self) {
+        self.clock.suspend();
+    }
+
+    fn on_resume(&mut self) {
+        self.clock.unsuspend();
+    }
+
+    fn audio(&self) -> Option<&AudioManager> {
+        Some(&self.audio)
+    }
+
+    fn session_score(&self) -> Option<u32> {
+        (self.state != GameState::Menu).then_some(self.score)
+    }
+
+    fn session_mode(&self) -> Option<String> {
+        (self.state != GameState::Menu).then(|| format!("{} BPM", RHYTHM_MUSIC.bpm(self.variant)))
+    }
+
+    fn title_status(&self) -> Option<String> {
+        (self.state != GameState::Menu).then(|| format!("{} pts", numbers::score(self.score)))
+    }
+}
+
+fn draw_rhythm_game(frame: &mut ratatui::Frame, area: Rect, game: &mut RhythmGame) {
+    style::fill_area(frame, area, Color::Black);
+
+    if game.state == GameState::Menu {
+        draw_mode_selection(frame, area, game);
+        return;
+    }
+
+    let chunks = Layout::vertical([
+        Constraint::Length(4), // Header avec score et combo
+        Constraint::Min(0),    // Colonnes
+        Constraint::Length(3), // Footer avec instructions
+    ])
+    .split(area);
+
+    // === HEADER ===
+    let header_text = vec![
+        Line::from(vec![
+            format!("{} ", glyphs::icon("🥁")).yellow().bold(),
+            "RHYTHM".cyan().bold(),
+            format!(" {}", glyphs::icon("🥁")).yellow().bold(),
+        ]),
+        Line::from(vec![
+            "Score: ".white(),
+            numbers::score(game.score).yellow().bold(),
+            "  Combo: ".white(),
+            format!("{}", game.combo).green().bold(),
+            format!(" ×{}", game.multiplier()).magenta().bold(),
+            "  BPM: ".white(),
+            format!("{}", RHYTHM_MUSIC.bpm(game.variant)).cyan().bold(),
+        ]),
+    ];
+    let header = Paragraph::new(header_text).centered().block(
+        Block::bordered()
+            .title(" Game Status ".white().bold())
+            .border_style(Style::new().cyan())
+            .style(Style::default().bg(Color::Rgb(25, 35, 45))),
+    );
+    frame.render_widget(header, chunks[0]);
+
+    // === COLONNES ===
+    let field = Block::bordered()
+        .title(" Track ".green().bold())
+        .border_style(Style::new().green())
+        .style(Style::default().bg(Color::Rgb(5, 10, 15)));
+    let inner = field.inner(chunks[1]);
+    frame.render_widget(field, chunks[1]);
+    draw_lanes(frame, inner, game);
+
+    // === FOOTER ===
+    let footer = Paragraph::new(Line::from(vec![
+        "D F J K".green().bold(),
+        " or ".white(),
+        "←↓↑→".green().bold(),
+        " Hit  ".white(),
+        "R".green().bold(),
+        " Restart  ".white(),
+        "M".yellow().bold(),
+        " Music  ".white(),
+        "Q".red().bold(),
+        " Quit".white(),
+    ]))
+    .centered()
+    .block(
+        Block::bordered()
+            .title(" Controls ".white().bold())
+            .border_style(Style::new().blue())
+            .style(Style::default().bg(Color::Rgb(25, 35, 45))),
+    );
+    frame.render_widget(footer, chunks[2]);
+
+    if game.state == GameState::Results {
+        draw_results(frame, area, game);
+    }
+}
+
+/// Colonnes, notes qui descendent et ligne de frappe avec les touches
+fn draw_lanes(frame: &mut ratatui::Frame, area: Rect, game: &RhythmGame) {
+    // Ligne de frappe, puis la rangée des touches en dessous
+    if area.height < 4 {
+        return;
+    }
+    let width = (LANE_WIDTH * LANES as u16).min(area.width);
+    let lane_width = width / LANES as u16;
+    let left = area.x + (area.width - width) / 2;
+    let hit_y = area.y + area.height - 2;
+    let travel = (hit_y - area.y) as f64;
+    let now = game.song_time();
+
+    for lane in 0..LANES {
+        let x = left + lane as u16 * lane_width;
+        let color = LANE_COLORS[lane];
+        let column = Rect::new(x, area.y, lane_width, area.height);
+        let line = Rect::new(x, hit_y, lane_width, 1);
+        let key = Rect::new(x, hit_y + 1, lane_width, 1);
+
+        let guide = vec![Line::from("┆".dark_gray()); (hit_y - area.y) as usize];
+        frame.render_widget(
+            Paragraph::new(guide).centered(),
+            Rect::new(column.x, column.y, column.width, hit_y - area.y),
+        );
+        frame.render_widget(
+            Paragraph::new("═".repeat(lane_width as usize)).fg(color),
+            line,
+        );
+        frame.render_widget(
+            Paragraph::new(LANE_KEYS[lane].to_ascii_uppercase().to_string())
+                .centered()
+                .fg(color)
+                .bold(),
+            key,
+        );
+    }
+
+    for note in game.notes.iter().filter(|note| note.judgement.is_none()) {
+        // Notes à venir dans la fenêtre, ou juste passées mais encore jouables
+        if note.time > now + LOOKAHEAD || now > note.time + GOOD_WINDOW {
+            continue;
+        }
+        let ahead = note.time.as_secs_f64() - now.as_secs_f64();
+        let y = hit_y as f64 - ahead / LOOKAHEAD.as_secs_f64() * travel;
+        let y = (y.round() as u16).clamp(area.y, hit_y);
+        let x = left + note.lane as u16 * lane_width;
+        frame.render_widget(
+            Paragraph::new("█".repeat(lane_width.saturating_sub(2) as usize))
+                .centered()
+                .fg(LANE_COLORS[note.lane])
+                .bold(),
+            Rect::new(x, y, lane_width, 1),
+        );
+    }
+
+    // Dernière appréciation au-dessus de la ligne
+    if let Some((judgement, at)) = game.last_judgement {
+        if now.saturating_sub(at) < JUDGEMENT_DISPLAY && hit_y > area.y {
+            frame.render_widget(
+                Paragraph::new(judgement.label())
+                    .centered()
+                    .fg(judgement.color())
+                    .bold(),
+                Rect::new(area.x, area.y, area.width, 1),
+            );
+        }
+    }
+}
+
+fn draw_results(frame: &mut ratatui::Frame, area: Rect, game: &RhythmGame) {
+    let popup_width = 40.min(area.width);
+    let popup_height = 12.min(area.height);
+    let popup_area = Rect {
+        x: area.x + (area.width - popup_width) / 2,
+        y: area.y + (area.height - popup_height) / 2,
+        width: popup_width,
+        height: popup_height,
+    };
+    frame.render_widget(Clear, popup_area);
+
+    let (title, color) = if game.full_combo() {
+        ("🎉 FULL COMBO! 🎉", Color::Green)
+    } else {
+        ("🎶 SONG COMPLETE 🎶", Color::Cyan)
+    };
+    let mut text = vec![
+        Line::from(glyphs::text(title).into_owned().fg(color).bold()),
+        Line::from(vec![
+            "Score: ".white(),
+            numbers::score(game.score).yellow().bold(),
+            "  Accuracy: ".white(),
+            format!("{}%", game.accuracy_percent()).green().bold(),
+        ]),
+        Line::from(vec![
+            "Max Combo: ".white(),
+            format!("{}", game.max_combo).magenta().bold(),
+        ]),
+        Line::from(""),
+    ];
+    text.extend(Judgement::ALL.iter().map(|&judgement| {
+        Line::from(vec![
+            format!("{:<8}", judgement.label())
+                .fg(judgement.color())
+                .bold(),
+            format!("{:>4}", game.count(judgement)).white(),
+        ])
+    }));
+    text.push(Line::from(""));
+    text.push(Line::from(vec![
+        "R".green().bold(),
+        " Restart  ".gray(),
+        "B".cyan().bold(),
+        " Modes  ".gray(),
+        "Q".red().bold(),
+        " Quit".gray(),
+    ]));
+
+    let popup = Paragraph::new(text).centered().block(
+        Block::bordered()
+            .title(" Results ".yellow().bold())
+            .border_style(Style::new().yellow().bold())
+            .style(Style::default().bg(Color::Black)),
+    );
+    frame.render_widget(popup, popup_area);
+}
+
+fn draw_mode_selection(frame: &mut ratatui::Frame, area: Rect, game: &mut RhythmGame) {
+    let chunks = Layout::vertical([
+        Constraint::Length(6), // Header
+        Constraint::Min(0),    // Menu
+        Constraint::Length(3), // Footer
+    ])
+    .split(area);
+
+    let header_text = vec![
+        Line::from(""),
+        Line::from(vec![
+            format!("{} ", glyphs::icon("🥁")).yellow().bold(),
+            "RHYTHM".cyan().bold(),
+            format!(" {}", glyphs::icon("🥁")).yellow().bold(),
+        ]),
+        Line::from("Choose your tempo".magenta()),
+        Line::from(""),
+    ];
+    let header = Paragraph::new(header_text).centered().block(
+        Block::bordered()
+            .title(" Game Selection ".white().bold())
+            .border_style(Style::new().cyan())
+            .style(Style::default().bg(Color::Rgb(25, 35, 45))),
+    );
+    frame.render_widget(header, chunks[0]);
+
+    let items: Vec<ListItem> = MODES
+        .iter()
+        .map(|(_, label)| ListItem::new(Line::from(glyphs::text(label)).centered()))
+        .collect();
+    let block = Block::bordered()
+        .title(" Select Tempo ".green().bold())
+        .border_style(Style::new().green())
+        .style(Style::default().bg(Color::Rgb(5, 10, 15)));
+    let highlight = style::focus(Color::Yellow);
+
+    game.mode_list
+        .render(frame, chunks[1], items, block, highlight);
+
+    let footer = Paragraph::new(Line::from(vec![
+        "↑↓".cyan().bold(),
+        " Navigate  ".white(),
+        "Enter".green().bold(),
+        " Select  ".white(),
+        "Q".red().bold(),
+        " Quit".white(),
+    ]))
+    .centered()
+    .block(
+        Block::bordered()
+            .title(" Controls ".white().bold())
+            .border_style(Style::new().blue())
+            .style(Style::default().bg(Color::Rgb(25, 35, 45))),
+    );
+    frame.render_widget(footer, chunks[2]);
+}
+
+/// Aperçu du menu : des notes qui descendent vers la ligne de frappe ; la
+/// touche de la colonne pour une note, en majuscule pour la ligne
+const PREVIEW: [&str; 9] = [
+    "....", ".f..", "....", "d..k", "....", "..j.", ".f..", "....", "DFJK",
+];
+
+fn draw_preview(frame: &mut ratatui::Frame, area: Rect) {
+    preview::draw_board(frame, area, &PREVIEW, |cell| {
+        let key = cell.to_ascii_lowercase();
+        match LANE_KEYS.iter().position(|&lane_key| lane_key == key) {
+            Some(lane) if cell.is_uppercase() => "═════".fg(LANE_COLORS[lane]),
+            Some(lane) => " ███ ".fg(LANE_COLORS[lane]).bold(),
+            None => "  ┆  ".dark_gray(),
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::isolate_data_dir;
+
+    #[test]
+    fn hits_are_judged_by_their_distance_to_the_note() {
+        isolate_data_dir();
+
+        let mut game = RhythmGame::new(AudioManager::silent());
+        game.start_game(MusicVariant::Normal);
+        let first = game.notes[0];
+
+        // Frappe 40 ms avant la première note, puis trop tôt pour la suivante
+        game.offset_ms = first.time.as_millis() as i64 - 40;
+        game.clock.pause();
+        game.hit(first.lane);
+        assert_eq!(game.notes[0].judgement, Some(Judgement::Perfect));
+        assert_eq!(game.combo, 1);
+        game.hit(game.notes[1].lane);
+        assert_eq!(game.notes[1].judgement, None);
+
+        // Une note laissée passer casse le combo
+        game.offset_ms = game.notes[1].time.as_millis() as i64 + 200;
+        game.mark_misses();
+        assert_eq!(game.notes[1].judgement, Some(Judgement::Miss));
+        assert_eq!(game.combo, 0);
+        assert_eq!(
+            Judgement::from_offset(Duration::from_millis(120)),
+            Some(Judgement::Good)
+        );
+    }
+}
@@ -1,7 +1,17 @@
 use crate::audio::{AudioManager, SoundEffect};
-use crate::core::{Game, GameAction};
-use crate::highscores::{GameData, HighScoreManager, Score};
-use crossterm::event::{KeyCode, KeyEvent};
+use crate::core::bot::{bfs_first_step, grid_neighbours};
+use crate::core::clock::GameClock;
+use crate::core::clock::Instant;
+use crate::core::input::{HeldKey, InputQueue};
+use crate::core::rng;
+use crate::core::{DangerLevel, Game, GameAction, GameStatus};
+use crate::event::{KeyCode, KeyEvent};
+use crate::ghosts::{self, Ghost, GhostRecorder};
+use crate::highscores::{current_player_name, GameData, HighScoreManager, Score};
+use crate::music::stingers;
+use crate::mutators;
+use crate::splits::SplitTimer;
+use crate::ui::{animation, bigtext, glyphs, numbers, preview, style};
 use rand::Rng;
 use ratatui::{
     layout::{Constraint, Layout, Margin, Rect},
@@ -11,6 +21,34 @@ use ratatui::{
 };
 use std::time::Duration;
 
+/// Score à atteindre pour gagner une course contre le serpent IA
+const RACE_TARGET_SCORE: u32 = 100;
+
+/// Vitesse fixe en course, pour que le joueur et l'IA jouent à armes égales
+const RACE_TICK_MS: u64 = 140;
+
+/// Durées de tick (hors sprint) sous lesquelles le bord du terrain rougit,
+/// puis pulse vite
+const WARNING_TICK_MS: u64 = 120;
+const CRITICAL_TICK_MS: u64 = 80;
+
+/// Fantôme de la meilleure partie classique
+const GHOST_ID: &str = "snake";
+
+/// Pommes mangées entre deux splits
+const FOODS_PER_SPLIT: u32 = 10;
+
+/// Virages gardés en attente, joués un par case : deux suffisent pour un
+/// demi-tour rapide, au-delà le serpent jouerait des touches trop anciennes
+const QUEUED_TURNS: usize = 2;
+
+/// Réserve d'énergie du sprint, pleine au départ
+const SPRINT_MAX_ENERGY: u32 = 100;
+/// Énergie dépensée par case parcourue en sprint
+const SPRINT_COST: u32 = 4;
+/// Énergie rendue par chaque pomme
+const SPRINT_FOOD_ENERGY: u32 = 25;
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Position {
     x: u16,
@@ -25,23 +63,69 @@ pub enum SnakeDirection {
     Right,
 }
 
+impl SnakeDirection {
+    fn opposite(self) -> Self {
+        match self {
+            SnakeDirection::Up => SnakeDirection::Down,
+            SnakeDirection::Down => SnakeDirection::Up,
+            SnakeDirection::Left => SnakeDirection::Right,
+            SnakeDirection::Right => SnakeDirection::Left,
+        }
+    }
+}
+
+/// Mode sprint : Espace tenu double la vitesse tant qu'il reste de l'énergie
+struct SprintState {
+    key: HeldKey,
+    energy: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum RaceOutcome {
+    Won,
+    Lost,
+    Draw,
+}
+
+/// Mode course : un serpent IA dispute la même nourriture au joueur
+struct RaceState {
+    rival: Vec<Position>,
+    rival_direction: SnakeDirection,
+    rival_score: u32,
+    outcome: Option<RaceOutcome>,
+}
+
 pub struct SnakeGame {
     snake: Vec<Position>,
     direction: SnakeDirection,
+    /// Virages tapés depuis le dernier pas, joués un par case
+    turns: InputQueue<SnakeDirection>,
     food: Position,
     score: u32,
     game_over: bool,
     width: u16,
     height: u16,
     audio: AudioManager,
-    music_started: bool,
     highscore_manager: HighScoreManager,
-    start_time: std::time::Instant,
+    clock: GameClock,
     score_saved: bool,
+    /// `Some` en mode course contre l'IA
+    race: Option<RaceState>,
+    /// `Some` en mode sprint
+    sprint: Option<SprintState>,
+    /// Meilleure partie classique, affichée pour comparaison
+    ghost: Option<Ghost>,
+    recorder: GhostRecorder,
+    /// Un split toutes les `FOODS_PER_SPLIT` pommes, `None` en course
+    splits: Option<SplitTimer>,
+    /// Partie modifiée, sans fantôme ni splits
+    mutated: bool,
+    /// Multiplicateur de vitesse des modificateurs
+    speed_factor: u32,
 }
 
 impl SnakeGame {
-    pub fn new() -> Self {
+    pub fn new(audio: AudioManager) -> Self {
         // Dimensions par défaut, seront mises à jour lors du premier rendu
         let width = 40;
         let height = 20;
@@ -50,25 +134,287 @@ impl SnakeGame {
             y: height / 2,
         }];
         let food = Self::generate_food(&snake, width, height);
+        // Une partie modifiée ne se compare pas aux parties habituelles
+        let mutated = mutators::any_active();
 
         Self {
             snake,
             direction: SnakeDirection::Right,
+            turns: InputQueue::new(QUEUED_TURNS),
             food,
             score: 0,
             game_over: false,
             width,
             height,
-            audio: AudioManager::default(),
-            music_started: false,
+            audio,
             highscore_manager: HighScoreManager::default(),
-            start_time: std::time::Instant::now(),
+            clock: GameClock::new(),
             score_saved: false,
+            race: None,
+            sprint: None,
+            ghost: if mutated {
+                None
+            } else {
+                ghosts::load(GHOST_ID)
+            },
+            recorder: GhostRecorder::new(),
+            splits: (!mutated).then(|| SplitTimer::new("snake")),
+            mutated,
+            speed_factor: mutators::speed_factor(),
+        }
+    }
+
+    /// Course contre un serpent IA : le premier à `RACE_TARGET_SCORE` gagne
+    pub fn race(audio: AudioManager) -> Self {
+        let mut game = Self::new(audio);
+        let (width, height) = (game.width, game.height);
+
+        // Les deux serpents partent de côtés opposés, décalés d'une ligne
+        game.snake = vec![Position {
+            x: width / 4,
+            y: height / 2,
+        }];
+        let rival = vec![Position {
+            x: width * 3 / 4,
+            y: height / 2 - 1,
+        }];
+        game.food = Self::generate_food(&[game.snake[0], rival[0]], width, height);
+        game.race = Some(RaceState {
+            rival,
+            rival_direction: SnakeDirection::Left,
+            rival_score: 0,
+            outcome: None,
+        });
+        game.ghost = None;
+        game.splits = None;
+        game
+    }
+
+    /// Partie classique avec sprint ; sans fantôme ni splits, qui ne se
+    /// comparent qu'aux parties sans sprint
+    pub fn sprint(audio: AudioManager) -> Self {
+        let mut game = Self::new(audio);
+        game.sprint = Some(SprintState {
+            key: HeldKey::new(),
+            energy: SPRINT_MAX_ENERGY,
+        });
+        game.ghost = None;
+        game.splits = None;
+        game
+    }
+
+    /// Durée d'un tick hors course et hors sprint, en millisecondes
+    fn cruise_tick_ms(&self) -> u64 {
+        // Vitesse de base: 300ms
+        let base_speed: u64 = 300;
+
+        // Réduction de 15ms par segment du serpent (sans compter la tête)
+        let speed_increase = (self.snake.len().saturating_sub(1) * 15) as u64;
+
+        // Vitesse minimale: 80ms pour éviter que ce soit injouable
+        base_speed.saturating_sub(speed_increase).max(80) / self.speed_factor as u64
+    }
+
+    /// Espace tenu avec de l'énergie en réserve
+    fn sprinting(&self) -> bool {
+        self.sprint
+            .as_ref()
+            .is_some_and(|sprint| sprint.energy > 0 && sprint.key.is_held(Instant::now()))
+    }
+
+    /// Garde un virage pour un prochain pas. Il se juge par rapport au
+    /// virage précédent en attente : depuis la droite, haut puis gauche donne
+    /// deux virages, joués sur deux cases
+    fn queue_turn(&mut self, direction: SnakeDirection) -> GameAction {
+        let previous = self.turns.last().unwrap_or(self.direction);
+        if direction != previous && direction != previous.opposite() {
+            self.turns.push(direction);
+        }
+        GameAction::Continue
+    }
+
+    /// Applique le virage suivant, au début d'un pas
+    fn take_turn(&mut self) {
+        if let Some(direction) = self.turns.pop() {
+            self.direction = direction;
+        }
+    }
+
+    /// Nouvelle partie dans le même mode
+    fn restart(&mut self) {
+        let audio = self.audio.sibling();
+        *self = if self.race.is_some() {
+            Self::race(audio)
+        } else if self.sprint.is_some() {
+            Self::sprint(audio)
+        } else {
+            Self::new(audio)
+        };
+    }
+
+    /// Case suivante de la tête dans `direction`, `None` hors du plateau
+    fn next_head(&self, head: Position, direction: SnakeDirection) -> Option<Position> {
+        let (x, y) = match direction {
+            SnakeDirection::Up => (Some(head.x), head.y.checked_sub(1)),
+            SnakeDirection::Down => (Some(head.x), Some(head.y + 1)),
+            SnakeDirection::Left => (head.x.checked_sub(1), Some(head.y)),
+            SnakeDirection::Right => (Some(head.x + 1), Some(head.y)),
+        };
+        match (x, y) {
+            (Some(x), Some(y)) if x < self.width && y < self.height => Some(Position { x, y }),
+            _ => None,
+        }
+    }
+
+    /// Cases occupées par les deux serpents
+    fn occupied(&self) -> Vec<Position> {
+        let rival = self.race.iter().flat_map(|race| race.rival.iter());
+        self.snake.iter().chain(rival).copied().collect()
+    }
+
+    /// Direction du serpent IA : plus court chemin vers la nourriture, ou à
+    /// défaut n'importe quelle case libre pour survivre
+    fn rival_direction(&self, race: &RaceState) -> SnakeDirection {
+        let head = race.rival[0];
+        // La queue du serpent IA avance avec lui, elle ne bloque pas
+        let own_body = &race.rival[..race.rival.len() - 1];
+        let blocked = |(x, y): (u16, u16)| {
+            let cell = Position { x, y };
+            self.snake.contains(&cell) || own_body.contains(&cell)
+        };
+
+        let step = bfs_first_step(
+            self.width,
+            self.height,
+            (head.x, head.y),
+            (self.food.x, self.food.y),
+            blocked,
+        )
+        .or_else(|| {
+            grid_neighbours(self.width, self.height, (head.x, head.y)).find(|&cell| !blocked(cell))
+        });
+
+        match step {
+            Some((x, _)) if x > head.x => SnakeDirection::Right,
+            Some((x, _)) if x < head.x => SnakeDirection::Left,
+            Some((_, y)) if y > head.y => SnakeDirection::Down,
+            Some((_, y)) if y < head.y => SnakeDirection::Up,
+            _ => race.rival_direction,
+        }
+    }
+
+    /// Un tick de course : les deux serpents avancent en même temps
+    fn move_race(&mut self) {
+        if self.race.as_ref().is_none_or(|race| race.outcome.is_some()) {
+            return;
+        }
+        self.take_turn();
+        let Some(race) = &self.race else {
+            return;
+        };
+
+        let rival_direction = self.rival_direction(race);
+        let player_head = self.next_head(self.snake[0], self.direction);
+        let rival_head = self.next_head(race.rival[0], rival_direction);
+
+        // Les queues libèrent leur case, sauf pour un serpent qui mange
+        let body = |snake: &[Position], head: Option<Position>| {
+            if head == Some(self.food) {
+                snake.len()
+            } else {
+                snake.len() - 1
+            }
+        };
+        let player_body = &self.snake[..body(&self.snake, player_head)];
+        let rival_body = &race.rival[..body(&race.rival, rival_head)];
+        let crashes = |head: Option<Position>| {
+            head.is_none_or(|head| player_body.contains(&head) || rival_body.contains(&head))
+        };
+
+        let mut player_crashed = crashes(player_head);
+        let mut rival_crashed = crashes(rival_head);
+
+        // Choc frontal : le serpent le plus long l'emporte
+        if !player_crashed && player_head == rival_head {
+            match self.snake.len().cmp(&race.rival.len()) {
+                std::cmp::Ordering::Greater => rival_crashed = true,
+                std::cmp::Ordering::Less => player_crashed = true,
+                std::cmp::Ordering::Equal => {
+                    player_crashed = true;
+                    rival_crashed = true;
+                }
+            }
+        }
+
+        if player_crashed || rival_crashed {
+            let outcome = match (player_crashed, rival_crashed) {
+                (true, false) => RaceOutcome::Lost,
+                (false, true) => RaceOutcome::Won,
+                // Les deux s'écrasent : le score départage
+                _ => match self.score.cmp(&race.rival_score) {
+                    std::cmp::Ordering::Greater => RaceOutcome::Won,
+                    std::cmp::Ordering::Less => RaceOutcome::Lost,
+                    std::cmp::Ordering::Equal => RaceOutcome::Draw,
+                },
+            };
+            self.finish_race(outcome);
+            return;
+        }
+
+        let (Some(player_head), Some(rival_head)) = (player_head, rival_head) else {
+            return;
+        };
+        let food = self.food;
+        let Some(race) = &mut self.race else {
+            return;
+        };
+
+        self.snake.insert(0, player_head);
+        if player_head == food {
+            self.score += 10;
+            self.audio.play_sound(SoundEffect::SnakeEat);
+        } else {
+            self.snake.pop();
+        }
+
+        race.rival_direction = rival_direction;
+        race.rival.insert(0, rival_head);
+        if rival_head == food {
+            race.rival_score += 10;
+        } else {
+            race.rival.pop();
+        }
+        let rival_score = race.rival_score;
+
+        if player_head == food || rival_head == food {
+            self.food = Self::generate_food(&self.occupied(), self.width, self.height);
+        }
+
+        if self.score >= RACE_TARGET_SCORE {
+            self.finish_race(RaceOutcome::Won);
+        } else if rival_score >= RACE_TARGET_SCORE {
+            self.finish_race(RaceOutcome::Lost);
+        }
+    }
+
+    /// Fin de course ; pas de high score, la partie n'est pas comparable au
+    /// mode classique
+    fn finish_race(&mut self, outcome: RaceOutcome) {
+        if let Some(race) = &mut self.race {
+            race.outcome = Some(outcome);
+        }
+        self.game_over = true;
+        self.clock.pause();
+        if outcome == RaceOutcome::Won {
+            self.audio.play_stinger(&stingers::SNAKE_VICTORY, None);
+        } else {
+            self.audio
+                .play_stinger(&stingers::SNAKE_GAME_OVER, Some(SoundEffect::SnakeGameOver));
         }
     }
 
     fn generate_food(snake: &[Position], width: u16, height: u16) -> Position {
-        let mut rng = rand::rng();
+        let mut rng = rng::rng();
         loop {
             let food = Position {
                 x: rng.random_range(0..width),
@@ -84,6 +430,12 @@ impl SnakeGame {
         if self.game_over {
             return;
         }
+        self.take_turn();
+        if self.sprinting() {
+            if let Some(sprint) = &mut self.sprint {
+                sprint.energy = sprint.energy.saturating_sub(SPRINT_COST);
+            }
+        }
 
         let head = self.snake[0];
         let new_head = match self.direction {
@@ -107,13 +459,16 @@ impl SnakeGame {
 
         if new_head.x >= self.width || new_head.y >= self.height || self.snake.contains(&new_head) {
             self.game_over = true;
-            // Arrêter la musique et jouer le son de game over
-            self.audio.stop_music();
-            self.audio.play_sound(SoundEffect::SnakeGameOver);
+            // Jingle de game over à la place de la musique
+            self.audio
+                .play_stinger(&stingers::SNAKE_GAME_OVER, Some(SoundEffect::SnakeGameOver));
 
             // Sauvegarder le score si c'est un high score et pas encore sauvé
             self.save_high_score_if_needed();
-            self.music_started = false;
+            self.save_ghost_if_best();
+            if let Some(splits) = &mut self.splits {
+                splits.finish();
+            }
             return;
         }
 
@@ -122,7 +477,17 @@ impl SnakeGame {
         if new_head == self.food {
             self.score += 10;
             self.audio.play_sound(SoundEffect::SnakeEat);
+            if let Some(sprint) = &mut self.sprint {
+                sprint.energy = (sprint.energy + SPRINT_FOOD_ENERGY).min(SPRINT_MAX_ENERGY);
+            }
             self.food = Self::generate_food(&self.snake, self.width, self.height);
+            if let Some(splits) = self
+                .splits
+                .as_mut()
+                .filter(|_| (self.score / 10).is_multiple_of(FOODS_PER_SPLIT))
+            {
+                splits.mark(self.clock.elapsed());
+            }
         } else {
             self.snake.pop();
         }
@@ -134,8 +499,9 @@ impl SnakeGame {
             self.width = new_width;
             self.height = new_height;
 
-            // Assurer que le serpent reste dans les limites
-            for segment in &mut self.snake {
+            // Assurer que les serpents restent dans les limites
+            let rival = self.race.iter_mut().flat_map(|race| race.rival.iter_mut());
+            for segment in self.snake.iter_mut().chain(rival) {
                 if segment.x >= new_width {
                     segment.x = new_width - 1;
                 }
@@ -146,30 +512,41 @@ impl SnakeGame {
 
             // Repositionner la nourriture si nécessaire
             if self.food.x >= new_width || self.food.y >= new_height {
-                self.food = Self::generate_food(&self.snake, new_width, new_height);
+                self.food = Self::generate_food(&self.occupied(), new_width, new_height);
             }
         }
     }
 
     fn start_music_if_needed(&mut self) {
-        if !self.music_started && self.audio.is_music_enabled() {
-            // Choisir la version de la musique selon la longueur du serpent
-            if self.snake.len() >= 15 {
-                self.audio.play_snake_music_fast(); // Version rapide pour serpent long
-            } else {
-                self.audio.play_snake_music(); // Version normale
-            }
-            self.music_started = true;
+        // Sans effet si la musique boucle déjà
+        if self.audio.is_music_enabled() {
+            self.audio.play_snake_music();
         }
 
-        // Relancer la musique si elle est finie
-        if self.music_started && self.audio.is_music_enabled() && self.audio.is_music_empty() {
-            // Choisir la version appropriée selon la longueur actuelle
-            if self.snake.len() >= 15 {
-                self.audio.play_snake_music_fast();
-            } else {
-                self.audio.play_snake_music();
-            }
+        // Les percussions montent avec la longueur du serpent
+        let intensity = self.snake.len().saturating_sub(3) as f32 / 12.0;
+        self.audio.set_music_intensity(intensity);
+
+        // Et le tempo avec la vitesse du serpent, de 300 ms à 80 ms par case
+        let tick = self.tick_rate().as_millis() as f32;
+        self.audio.set_music_speed((300.0 - tick) / 220.0);
+    }
+
+    /// Garde le déroulé de la partie s'il bat le fantôme
+    fn save_ghost_if_best(&mut self) {
+        let ghost = self.recorder.finish(self.score, self.clock.elapsed());
+        if self.score == 0
+            || self.mutated
+            || self.sprint.is_some()
+            || self
+                .ghost
+                .as_ref()
+                .is_some_and(|best| best.score >= self.score)
+        {
+            return;
+        }
+        if let Err(e) = ghosts::save(GHOST_ID, ghost) {
+            eprintln!("Erreur lors de la sauvegarde du fantôme: {e}");
         }
     }
 
@@ -181,16 +558,17 @@ impl SnakeGame {
 
         // Vérifier si c'est un high score
         if self.highscore_manager.is_high_score("snake", self.score) {
-            let duration = self.start_time.elapsed().as_secs();
+            let duration = self.clock.elapsed_secs();
             let game_data = GameData::Snake {
                 length: self.snake.len(),
                 duration_seconds: duration,
+                sprint: self.sprint.is_some(),
             };
 
-            let score = Score::new("Anonymous".to_string(), self.score, game_data);
+            let score = Score::new(current_player_name(), self.score, game_data);
 
             // Sauvegarder le score
-            if let Ok(_is_top_10) = self.highscore_manager.add_score("snake", score) {
+            if let Ok(_new_score) = self.highscore_manager.add_score("snake", score) {
                 self.score_saved = true;
             }
         }
@@ -205,28 +583,32 @@ impl Game for SnakeGame {
                     // Nettoyer l'audio avant de redémarrer
                     self.audio.clear_effects();
                     self.audio.stop_music();
-                    *self = Self::new();
+                    self.restart();
                     GameAction::Continue
                 }
                 KeyCode::Char('q') => GameAction::Quit,
                 _ => GameAction::Continue,
             }
         } else {
+            if key.code == KeyCode::Char('r') && self.instant_restart() {
+                // Un sprint se relance à la chaîne
+                self.audio.clear_effects();
+                self.audio.stop_music();
+                self.restart();
+                return GameAction::Continue;
+            }
+            if self.race.is_none() {
+                self.recorder.record_key(key);
+            }
             match key.code {
-                KeyCode::Up if self.direction != SnakeDirection::Down => {
-                    self.direction = SnakeDirection::Up;
-                    GameAction::Continue
-                }
-                KeyCode::Down if self.direction != SnakeDirection::Up => {
-                    self.direction = SnakeDirection::Down;
-                    GameAction::Continue
-                }
-                KeyCode::Left if self.direction != SnakeDirection::Right => {
-                    self.direction = SnakeDirection::Left;
-                    GameAction::Continue
-                }
-                KeyCode::Right if self.direction != SnakeDirection::Left => {
-                    self.direction = SnakeDirection::Right;
+                KeyCode::Up => self.queue_turn(SnakeDirection::Up),
+                KeyCode::Down => self.queue_turn(SnakeDirection::Down),
+                KeyCode::Left => self.queue_turn(SnakeDirection::Left),
+                KeyCode::Right => self.queue_turn(SnakeDirection::Right),
+                KeyCode::Char(' ') => {
+                    if let Some(sprint) = &mut self.sprint {
+                        sprint.key.press(Instant::now());
+                    }
                     GameAction::Continue
                 }
                 KeyCode::Char('q') => GameAction::Quit,
@@ -235,8 +617,6 @@ impl Game for SnakeGame {
                     self.audio.toggle_music();
                     if self.audio.is_music_enabled() {
                         self.start_music_if_needed();
-                    } else {
-                        self.music_started = false;
                     }
                     GameAction::Continue
                 }
@@ -254,32 +634,105 @@ impl Game for SnakeGame {
             // Démarrer la musique si ce n'est pas encore fait
             self.start_music_if_needed();
 
-            self.move_snake();
+            if self.race.is_some() {
+                self.move_race();
+            } else {
+                self.move_snake();
+                self.recorder.tick();
+                self.recorder
+                    .record_progress(self.clock.elapsed(), self.score);
+            }
         }
         GameAction::Continue
     }
 
-    fn draw(&mut self, frame: &mut ratatui::Frame) {
-        draw_snake_game(frame, self);
+    fn preview(&self, frame: &mut ratatui::Frame, area: Rect) {
+        draw_preview(frame, area, self.race.is_some());
     }
 
-    fn tick_rate(&self) -> Duration {
-        // Vitesse de base: 300ms
-        let base_speed: u64 = 300;
+    fn draw(&mut self, frame: &mut ratatui::Frame, area: Rect) {
+        draw_snake_game(frame, area, self);
+    }
 
-        // Réduction de 15ms par segment du serpent (sans compter la tête)
-        let speed_increase = (self.snake.len().saturating_sub(1) * 15) as u64;
+    fn tick_rate(&self) -> Duration {
+        if self.race.is_some() {
+            return Duration::from_millis(RACE_TICK_MS);
+        }
 
-        // Vitesse minimale: 80ms pour éviter que ce soit injouable
-        let final_speed = base_speed.saturating_sub(speed_increase).max(80);
+        let mut final_speed = self.cruise_tick_ms();
+        if self.sprinting() {
+            final_speed /= 2;
+        }
 
         Duration::from_millis(final_speed)
     }
-}
 
-fn draw_snake_game(frame: &mut ratatui::Frame, app: &mut SnakeGame) {
-    let area = frame.area();
+    fn status(&self) -> GameStatus {
+        let won = self
+            .race
+            .as_ref()
+            .is_some_and(|race| race.outcome == Some(RaceOutcome::Won));
+        if won {
+            GameStatus::Victory
+        } else if self.game_over {
+            GameStatus::GameOver
+        } else if self.clock.is_suspended() {
+            GameStatus::Paused
+        } else {
+            GameStatus::Running
+        }
+    }
+
+    fn danger(&self) -> DangerLevel {
+        // Le sprint, bref et voulu, ne compte pas ; la course a une vitesse fixe
+        if self.game_over || self.race.is_some() {
+            return DangerLevel::Calm;
+        }
+        match self.cruise_tick_ms() {
+            ms if ms <= CRITICAL_TICK_MS => DangerLevel::Critical,
+            ms if ms <= WARNING_TICK_MS => DangerLevel::Warning,
+            _ => DangerLevel::Calm,
+        }
+    }
+
+    fn title_status(&self) -> Option<String> {
+        if let Some(race) = &self.race {
+            return Some(format!(
+                "{} vs {} pts",
+                numbers::score(self.score),
+                numbers::score(race.rival_score)
+            ));
+        }
+        Some(format!("{} pts", numbers::score(self.score)))
+    }
+
+    fn session_score(&self) -> Option<u32> {
+        Some(self.score)
+    }
+
+    fn splits(&self) -> Option<(&SplitTimer, Duration)> {
+        let elapsed = self.clock.elapsed();
+        self.splits.as_ref().map(|splits| (splits, elapsed))
+    }
 
+    fn on_pause(&mut self) {
+        self.clock.suspend();
+    }
+
+    fn on_resume(&mut self) {
+        self.clock.unsuspend();
+    }
+
+    fn instant_restart(&self) -> bool {
+        self.sprint.is_some()
+    }
+
+    fn audio(&self) -> Option<&AudioManager> {
+        Some(&self.audio)
+    }
+}
+
+fn draw_snake_game(frame: &mut ratatui::Frame, area: Rect, app: &mut SnakeGame) {
     // Layout principal d'abord pour connaître l'espace réel disponible
     let chunks = Layout::vertical([
         Constraint::Length(4), // Header avec score
@@ -302,34 +755,80 @@ fn draw_snake_game(frame: &mut ratatui::Frame, app: &mut SnakeGame) {
     app.update_dimensions(game_width, game_height);
 
     // Fond sombre élégant
-    let background = Block::new().style(Style::default().bg(Color::Rgb(15, 20, 25)));
-    frame.render_widget(background, area);
+    style::fill_area(frame, area, style::background());
 
     // === HEADER ===
     let current_speed = app.tick_rate().as_millis();
     let snake_length = app.snake.len();
     let audio_status = if app.audio.is_enabled() {
-        "🔊"
+        glyphs::icon("🔊")
     } else {
-        "🔇"
+        glyphs::icon("🔇")
     };
 
-    let header_text = vec![
-        Line::from(vec![
-            "🐍 ".green().bold(),
-            "SNAKE GAME".cyan().bold(),
-            " 🐍".green().bold(),
-        ]),
+    let status_line = if let Some(race) = &app.race {
         Line::from(vec![
+            "You: ".green(),
+            numbers::score(app.score).white().bold(),
+            " | AI: ".magenta(),
+            numbers::score(race.rival_score).white().bold(),
+            " | Target: ".gray(),
+            format!("{RACE_TARGET_SCORE}").yellow().bold(),
+            " | Audio: ".gray(),
+            audio_status.white(),
+        ])
+    } else {
+        let mut spans = vec![
             "Score: ".yellow(),
-            format!("{}", app.score).white().bold(),
+            numbers::score(app.score).white().bold(),
             " | Length: ".gray(),
             format!("{snake_length}").green().bold(),
             " | Speed: ".gray(),
             format!("{current_speed}ms").red().bold(),
-            " | Audio: ".gray(),
-            audio_status.white(),
+        ];
+        // Réserve d'énergie du sprint
+        if let Some(sprint) = &app.sprint {
+            let filled = (sprint.energy * 10 / SPRINT_MAX_ENERGY) as usize;
+            let bar = format!("{}{}", "█".repeat(filled), "░".repeat(10 - filled));
+            spans.push(" | Energy: ".gray());
+            spans.push(if app.sprinting() {
+                bar.magenta().bold()
+            } else {
+                bar.yellow().bold()
+            });
+        }
+        // Score du record au même temps de jeu
+        if let Some(ghost) = &app.ghost {
+            let ghost_score = ghost.value_at(app.clock.elapsed()).unwrap_or(0);
+            let lead = i64::from(app.score) - i64::from(ghost_score);
+            spans.push(" | Ghost: ".gray());
+            spans.push(format!("{} ", numbers::score(ghost_score)).white().bold());
+            spans.push(if lead >= 0 {
+                format!("(+{lead})").green().bold()
+            } else {
+                format!("({lead})").red().bold()
+            });
+        }
+        spans.push(" | Audio: ".gray());
+        spans.push(audio_status.white());
+        Line::from(spans)
+    };
+
+    let header_text = vec![
+        Line::from(vec![
+            format!("{} ", glyphs::icon("🐍")).green().bold(),
+            if app.race.is_some() {
+                "SNAKE RACE"
+            } else if app.sprint.is_some() {
+                "SNAKE SPRINT"
+            } else {
+                "SNAKE GAME"
+            }
+            .cyan()
+            .bold(),
+            format!(" {}", glyphs::icon("🐍")).green().bold(),
         ]),
+        status_line,
     ];
 
     let header = Paragraph::new(header_text)
@@ -344,9 +843,10 @@ fn draw_snake_game(frame: &mut ratatui::Frame, app: &mut SnakeGame) {
 
     // === ZONE DE JEU ===
     let game_area = chunks[1];
+    // Le bord du terrain rougit quand le serpent va très vite
     let game_block = Block::bordered()
         .title(" Playing Field ".green().bold())
-        .border_style(Style::new().green())
+        .border_style(Style::new().fg(animation::danger_color(Color::Green, app.danger())))
         .style(Style::default().bg(Color::Rgb(10, 15, 20)));
     frame.render_widget(game_block, game_area);
 
@@ -407,6 +907,30 @@ fn draw_snake_game(frame: &mut ratatui::Frame, app: &mut SnakeGame) {
         }
     }
 
+    // Dessiner le serpent IA en violet
+    if let Some(race) = &app.race {
+        for (i, segment) in race.rival.iter().enumerate() {
+            if segment.x < game_width && segment.y < game_height {
+                let cell_area = Rect {
+                    x: inner_area.x + (segment.x * 2),
+                    y: inner_area.y + segment.y,
+                    width: 2,
+                    height: 1,
+                };
+
+                let color = if i == 0 {
+                    Color::Rgb(230, 140, 255)
+                } else {
+                    let intensity = 200 - (i * 10).min(100) as u8;
+                    Color::Rgb(intensity, 60, intensity)
+                };
+
+                let rival_cell = Paragraph::new("██").style(Style::default().fg(color));
+                frame.render_widget(rival_cell, cell_area);
+            }
+        }
+    }
+
     // Dessiner la nourriture avec des cellules carrées
     if app.food.x < game_width && app.food.y < game_height {
         let food_x = inner_area.x + (app.food.x * 2); // 2 caractères par cellule
@@ -427,6 +951,17 @@ fn draw_snake_game(frame: &mut ratatui::Frame, app: &mut SnakeGame) {
     let instructions = vec![Line::from(vec![
         "Arrow Keys".cyan().bold(),
         " Move  ".white(),
+        if app.sprint.is_some() {
+            "Hold Space".magenta().bold()
+        } else {
+            "".white()
+        },
+        if app.sprint.is_some() {
+            " Sprint  "
+        } else {
+            ""
+        }
+        .white(),
         "M".yellow().bold(),
         " Music  ".white(),
         "N".blue().bold(),
@@ -454,18 +989,41 @@ fn draw_snake_game(frame: &mut ratatui::Frame, app: &mut SnakeGame) {
     // === GAME OVER POPUP ===
     if app.game_over {
         let popup_width = 40.min(area.width);
-        let popup_height = 8.min(area.height);
+
+        let (title_lines, score_line) = match &app.race {
+            Some(race) => (
+                vec![match race.outcome {
+                    Some(RaceOutcome::Won) => Line::from("🏆 YOU WIN! 🏆".green().bold()),
+                    Some(RaceOutcome::Draw) => Line::from("🤝 DRAW 🤝".yellow().bold()),
+                    _ => Line::from("🤖 AI WINS 🤖".red().bold()),
+                }],
+                Line::from(vec![
+                    "You ".white(),
+                    numbers::score(app.score).green().bold(),
+                    " - ".gray(),
+                    numbers::score(race.rival_score).magenta().bold(),
+                    " AI".white(),
+                ]),
+            ),
+            None => (
+                bigtext::headline(
+                    "GAME OVER",
+                    Style::new().red().bold(),
+                    popup_width.saturating_sub(2),
+                    Line::from("💀 GAME OVER 💀".red().bold()),
+                ),
+                Line::from(vec![
+                    "Final Score: ".white(),
+                    numbers::score(app.score).yellow().bold(),
+                ]),
+            ),
+        };
+
+        // Le grand titre agrandit la fenêtre d'autant de lignes
+        let popup_height = (7 + title_lines.len() as u16).min(area.height);
         let popup_area = Rect {
-            x: if area.width >= popup_width {
-                (area.width - popup_width) / 2
-            } else {
-                0
-            },
-            y: if area.height >= popup_height {
-                (area.height - popup_height) / 2
-            } else {
-                0
-            },
+            x: area.x + (area.width - popup_width) / 2,
+            y: area.y + (area.height - popup_height) / 2,
             width: popup_width,
             height: popup_height,
         };
@@ -473,14 +1031,11 @@ fn draw_snake_game(frame: &mut ratatui::Frame, app: &mut SnakeGame) {
         // Fond transparent
         frame.render_widget(Clear, popup_area);
 
-        let game_over_text = vec![
+        let mut game_over_text = vec![Line::from("")];
+        game_over_text.extend(title_lines);
+        game_over_text.extend([
             Line::from(""),
-            Line::from("💀 GAME OVER 💀".red().bold()),
-            Line::from(""),
-            Line::from(vec![
-                "Final Score: ".white(),
-                format!("{}", app.score).yellow().bold(),
-            ]),
+            score_line,
             Line::from(""),
             Line::from(vec![
                 "Press ".gray(),
@@ -489,7 +1044,7 @@ fn draw_snake_game(frame: &mut ratatui::Frame, app: &mut SnakeGame) {
                 "Q".red().bold(),
                 " to quit".gray(),
             ]),
-        ];
+        ]);
 
         let popup = Paragraph::new(game_over_text)
             .alignment(ratatui::layout::Alignment::Center)
@@ -502,3 +1057,165 @@ fn draw_snake_game(frame: &mut ratatui::Frame, app: &mut SnakeGame) {
         frame.render_widget(popup, popup_area);
     }
 }
+
+/// Aperçu du menu : un serpent vers la nourriture, et le rival en course
+const PREVIEW: [&str; 9] = [
+    "............",
+    "..F.........",
+    "............",
+    "............",
+    "...SSSSH....",
+    "...S........",
+    ".SSS........",
+    "............",
+    "............",
+];
+const RACE_PREVIEW: [&str; 9] = [
+    "............",
+    "..F.........",
+    "..........r.",
+    "........RRr.",
+    "...SSSSH....",
+    "...S........",
+    ".SSS........",
+    "............",
+    "............",
+];
+
+fn draw_preview(frame: &mut ratatui::Frame, area: Rect, race: bool) {
+    let rows = if race { &RACE_PREVIEW } else { &PREVIEW };
+    preview::draw_board(frame, area, rows, |cell| match cell {
+        'H' => "██".fg(Color::Rgb(120, 255, 120)),
+        'S' => "██".fg(Color::Rgb(50, 180, 50)),
+        'R' => "██".fg(Color::Rgb(230, 140, 255)),
+        'r' => "██".fg(Color::Rgb(180, 60, 180)),
+        'F' => "██".fg(Color::Red).bold(),
+        _ => "░░".fg(Color::Rgb(30, 35, 40)),
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::isolate_data_dir;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    /// Flèche pressée, ou `None` pour un tick de la boucle de jeu
+    fn step() -> impl Strategy<Value = Option<KeyCode>> {
+        prop_oneof![
+            Just(Some(KeyCode::Up)),
+            Just(Some(KeyCode::Down)),
+            Just(Some(KeyCode::Left)),
+            Just(Some(KeyCode::Right)),
+            Just(None),
+        ]
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn snake_never_overlaps_itself(seed in any::<u64>(), steps in vec(step(), 1..300)) {
+            isolate_data_dir();
+            rng::seed(seed);
+            let mut game = SnakeGame::new(AudioManager::silent());
+
+            for step in steps {
+                if game.game_over {
+                    break;
+                }
+                match step {
+                    Some(code) => {
+                        game.handle_key(KeyEvent::from(code));
+                    }
+                    None => game.move_snake(),
+                }
+
+                for (i, segment) in game.snake.iter().enumerate() {
+                    prop_assert!(segment.x < game.width && segment.y < game.height);
+                    prop_assert!(!game.snake[i + 1..].contains(segment), "{segment:?} twice");
+                }
+                prop_assert!(!game.snake.contains(&game.food));
+            }
+        }
+    }
+
+    #[test]
+    fn holding_space_doubles_the_speed_until_the_energy_runs_out() {
+        isolate_data_dir();
+        let mut game = SnakeGame::sprint(AudioManager::silent());
+        let walking = game.tick_rate();
+
+        game.handle_key(KeyEvent::from(KeyCode::Char(' ')));
+        assert_eq!(game.tick_rate(), walking / 2);
+        game.move_snake();
+        assert_eq!(
+            game.sprint.as_ref().map(|sprint| sprint.energy),
+            Some(SPRINT_MAX_ENERGY - SPRINT_COST)
+        );
+
+        // Réserve vide : Espace ne fait plus rien
+        game.sprint.as_mut().unwrap().energy = 0;
+        game.handle_key(KeyEvent::from(KeyCode::Char(' ')));
+        assert_eq!(game.tick_rate(), walking);
+
+        // Le mode classique ignore Espace
+        let mut classic = SnakeGame::new(AudioManager::silent());
+        classic.handle_key(KeyEvent::from(KeyCode::Char(' ')));
+        assert_eq!(classic.tick_rate(), walking);
+    }
+
+    #[test]
+    fn two_quick_turns_are_played_on_two_steps() {
+        isolate_data_dir();
+        let mut game = SnakeGame::new(AudioManager::silent());
+        let start = game.snake[0];
+
+        // Haut puis gauche avant le pas suivant : demi-tour en deux cases,
+        // sans que la gauche, opposée à la droite, ne soit refusée
+        game.handle_key(KeyEvent::from(KeyCode::Up));
+        game.handle_key(KeyEvent::from(KeyCode::Left));
+        game.move_snake();
+        assert_eq!(
+            game.snake[0],
+            Position {
+                x: start.x,
+                y: start.y - 1
+            }
+        );
+        game.move_snake();
+        assert_eq!(
+            game.snake[0],
+            Position {
+                x: start.x - 1,
+                y: start.y - 1
+            }
+        );
+        assert!(!game.game_over);
+        assert_eq!(game.direction, SnakeDirection::Left);
+    }
+
+    #[test]
+    fn only_two_turns_wait_for_the_next_steps() {
+        isolate_data_dir();
+        let mut game = SnakeGame::new(AudioManager::silent());
+        let start = game.snake[0];
+
+        for code in [KeyCode::Down, KeyCode::Left, KeyCode::Up] {
+            game.handle_key(KeyEvent::from(code));
+        }
+        game.move_snake();
+        game.move_snake();
+        // Le troisième virage, tapé trop tôt, est oublié
+        game.move_snake();
+        assert_eq!(
+            game.snake[0],
+            Position {
+                x: start.x - 2,
+                y: start.y + 1
+            }
+        );
+        assert_eq!(game.direction, SnakeDirection::Left);
+    }
+}
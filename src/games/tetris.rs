@@ -1,7 +1,16 @@
 use crate::audio::{AudioManager, SoundEffect};
-use crate::core::{Game, GameAction};
-use crate::highscores::{GameData, HighScoreManager, Score};
-use crossterm::event::{KeyCode, KeyEvent};
+use crate::core::clock::GameClock;
+use crate::core::grid::Grid2D;
+use crate::core::rng;
+use crate::core::{DangerLevel, Game, GameAction, GameStatus};
+use crate::event::{KeyCode, KeyEvent};
+use crate::ghosts::{self, Ghost, GhostRecorder};
+use crate::highscores::{current_player_name, GameData, HighScoreManager, Score};
+use crate::music::stingers;
+use crate::mutators::{self, Mutator};
+use crate::puzzles::{self, TetrisPuzzle};
+use crate::splits::SplitTimer;
+use crate::ui::{animation, bigtext, glyphs, numbers, preview, style};
 use rand::Rng;
 use ratatui::{
     layout::{Constraint, Layout, Margin, Rect},
@@ -9,16 +18,37 @@ use ratatui::{
     text::Line,
     widgets::{Block, Clear, Paragraph},
 };
+use std::collections::VecDeque;
 use std::time::Duration;
 
 // Taille de la grille standard Tetris
 const BOARD_WIDTH: usize = 10;
 const BOARD_HEIGHT: usize = 20;
+/// Lignes libres au-dessus de la pile sous lesquelles le cadre rougit, puis
+/// pulse vite
+const WARNING_FREE_ROWS: usize = 6;
+const CRITICAL_FREE_ROWS: usize = 3;
+/// Part de chaque temps pendant laquelle le cadre de jeu est mis en évidence
+const BEAT_PULSE: f32 = 0.2;
+/// Durée d'affichage d'un popup de score, en frames (50ms)
+const POPUP_FRAMES: u32 = 30;
+/// Lignes de déchets préremplies en mode Dig
+const DIG_ROWS: usize = 10;
+/// Fantôme de la partie Dig la plus rapide
+const DIG_GHOST_ID: &str = "tetris-dig";
+
+/// Texte de score flottant affiché quelques instants au niveau des lignes
+/// effacées, qui remonte doucement avant de disparaître
+struct ScorePopup {
+    lines: Vec<String>,
+    row: usize,
+    frames_left: u32,
+}
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Position {
-    x: i32,
-    y: i32,
+    pub(crate) x: i32,
+    pub(crate) y: i32,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -30,10 +60,12 @@ pub enum PieceType {
     Z, // Z
     J, // J
     L, // L
+    /// Bloc de déchets du mode Dig, jamais tiré au sort
+    Garbage,
 }
 
 impl PieceType {
-    fn get_shape(&self) -> &'static [&'static [bool]] {
+    pub(crate) fn get_shape(&self) -> &'static [&'static [bool]] {
         match self {
             PieceType::I => &[
                 &[false, false, false, false],
@@ -67,10 +99,11 @@ impl PieceType {
                 &[true, true, true],
                 &[false, false, false],
             ],
+            PieceType::Garbage => &[&[true]],
         }
     }
 
-    fn get_color(&self) -> Color {
+    pub(crate) fn get_color(&self) -> Color {
         match self {
             PieceType::I => Color::Cyan,
             PieceType::O => Color::Yellow,
@@ -79,11 +112,26 @@ impl PieceType {
             PieceType::Z => Color::Red,
             PieceType::J => Color::Blue,
             PieceType::L => Color::Rgb(255, 165, 0), // Orange
+            PieceType::Garbage => Color::Rgb(110, 110, 120),
+        }
+    }
+
+    /// Pièce correspondant à une lettre des scénarios de puzzle
+    fn from_char(c: char) -> Option<Self> {
+        match c.to_ascii_uppercase() {
+            'I' => Some(PieceType::I),
+            'O' => Some(PieceType::O),
+            'T' => Some(PieceType::T),
+            'S' => Some(PieceType::S),
+            'Z' => Some(PieceType::Z),
+            'J' => Some(PieceType::J),
+            'L' => Some(PieceType::L),
+            _ => None,
         }
     }
 
-    fn random() -> Self {
-        let mut rng = rand::rng();
+    pub(crate) fn random() -> Self {
+        let mut rng = rng::rng();
         match rng.random_range(0..7) {
             0 => PieceType::I,
             1 => PieceType::O,
@@ -98,21 +146,26 @@ impl PieceType {
 
 #[derive(Debug, Clone)]
 pub struct Piece {
-    piece_type: PieceType,
+    pub(crate) piece_type: PieceType,
     position: Position,
     rotation: usize,
 }
 
 impl Piece {
     fn new(piece_type: PieceType) -> Self {
+        Self::spawned_at(piece_type, 4) // Centre en haut
+    }
+
+    /// Pièce en haut du plateau, sa forme commençant à la colonne `x`
+    pub(crate) fn spawned_at(piece_type: PieceType, x: i32) -> Self {
         Self {
             piece_type,
-            position: Position { x: 4, y: 0 }, // Centre en haut
+            position: Position { x, y: 0 },
             rotation: 0,
         }
     }
 
-    fn get_blocks(&self) -> Vec<Position> {
+    pub(crate) fn get_blocks(&self) -> Vec<Position> {
         let shape = self.get_rotated_shape();
         let mut blocks = Vec::new();
 
@@ -152,22 +205,38 @@ impl Piece {
         rotated
     }
 
-    fn moved(&self, dx: i32, dy: i32) -> Self {
+    pub(crate) fn moved(&self, dx: i32, dy: i32) -> Self {
         let mut piece = self.clone();
         piece.position.x += dx;
         piece.position.y += dy;
         piece
     }
 
-    fn rotated(&self) -> Self {
+    pub(crate) fn rotated(&self) -> Self {
         let mut piece = self.clone();
         piece.rotation = (piece.rotation + 1) % 4;
         piece
     }
 }
 
+/// Cases d'une pièce tirée au hasard, dans une orientation au hasard, avec
+/// sa couleur (pièces qui tombent derrière le menu)
+pub(crate) fn random_piece_blocks() -> (Vec<(u16, u16)>, Color) {
+    let piece = Piece {
+        piece_type: PieceType::random(),
+        position: Position { x: 0, y: 0 },
+        rotation: rng::rng().random_range(0..4),
+    };
+    let blocks = piece
+        .get_blocks()
+        .iter()
+        .map(|block| (block.x as u16, block.y as u16))
+        .collect();
+    (blocks, piece.piece_type.get_color())
+}
+
 pub struct TetrisGame {
-    board: [[Option<PieceType>; BOARD_WIDTH]; BOARD_HEIGHT],
+    board: Grid2D<Option<PieceType>>,
     current_piece: Option<Piece>,
     next_piece: PieceType,
     score: u32,
@@ -176,17 +245,53 @@ pub struct TetrisGame {
     game_over: bool,
     drop_timer: u32,
     audio: AudioManager,
-    music_started: bool,
     tetris_celebration: u32, // Compteur pour afficher "TETRIS!" à l'écran
+    last_move_was_rotation: bool, // Pour détecter les T-Spins
+    back_to_back: bool,      // Dernier effacement "difficile" (Tetris ou T-Spin)
+    combo: u32,              // Pièces consécutives ayant effacé des lignes
+    popups: Vec<ScorePopup>,
     highscore_manager: HighScoreManager,
-    start_time: std::time::Instant,
+    clock: GameClock,
     score_saved: bool,
+    /// Un split par niveau (10 lignes) ; `None` en mode Dig et en puzzle,
+    /// qui ne se jouent pas au même rythme
+    splits: Option<SplitTimer>,
+    /// `Some` en mode Dig
+    dig: Option<DigState>,
+    /// `Some` pendant un puzzle
+    puzzle: Option<PuzzleRun>,
+    /// Modificateur : la pièce suivante n'est pas affichée
+    hide_next: bool,
+    /// Multiplicateur de vitesse des modificateurs
+    speed_factor: u32,
+}
+
+/// Puzzle en cours : les pièces viennent de la suite imposée
+struct PuzzleRun {
+    puzzle: TetrisPuzzle,
+    /// Pièces restantes après `next_piece`
+    queue: VecDeque<PieceType>,
+    /// `next_piece` a déjà été jouée et il n'y en a plus d'autre
+    out_of_pieces: bool,
+    solved: bool,
+}
+
+/// Mode Dig : creuser jusqu'au fond des lignes de déchets le plus vite
+/// possible, avec le moins de pièces possible
+struct DigState {
+    garbage_rows: usize,
+    pieces_used: u32,
+    cleared: bool,
+    /// Partie la plus rapide, affichée pour comparaison
+    ghost: Option<Ghost>,
+    recorder: GhostRecorder,
 }
 
 impl TetrisGame {
-    pub fn new() -> Self {
+    pub fn new(audio: AudioManager) -> Self {
+        let hide_next = mutators::is_active(Mutator::HiddenNext);
         let mut game = Self {
-            board: [[None; BOARD_WIDTH]; BOARD_HEIGHT],
+            board: Grid2D::new(BOARD_WIDTH, BOARD_HEIGHT, None),
             current_piece: None,
             next_piece: PieceType::random(),
             score: 0,
@@ -194,27 +299,192 @@ impl TetrisGame {
             level: 1,
             game_over: false,
             drop_timer: 0,
-            audio: AudioManager::default(),
-            music_started: false,
+            audio,
             tetris_celebration: 0,
+            last_move_was_rotation: false,
+            back_to_back: false,
+            combo: 0,
+            popups: Vec::new(),
             highscore_manager: HighScoreManager::default(),
-            start_time: std::time::Instant::now(),
+            clock: GameClock::new(),
             score_saved: false,
+            // Une partie modifiée ne se compare pas aux parties habituelles
+            splits: (!mutators::any_active()).then(|| SplitTimer::new("tetris")),
+            dig: None,
+            puzzle: None,
+            hide_next,
+            speed_factor: mutators::speed_factor(),
         };
         game.spawn_piece();
         game
     }
 
+    /// Partie de puzzle : bas du plateau et suite de pièces imposés
+    pub fn from_puzzle(puzzle: TetrisPuzzle, audio: AudioManager) -> Self {
+        let mut game = Self::new(audio);
+        game.current_piece = None;
+
+        // Les lignes du scénario sont alignées sur le bas du plateau
+        let top = BOARD_HEIGHT.saturating_sub(puzzle.board.len());
+        for (y, row) in puzzle.board.iter().take(BOARD_HEIGHT).enumerate() {
+            for (x, c) in row.chars().take(BOARD_WIDTH).enumerate() {
+                if c != '.' {
+                    game.board[(x, top + y)] = Some(PieceType::Garbage);
+                }
+            }
+        }
+
+        let mut queue: VecDeque<PieceType> = puzzle
+            .pieces
+            .chars()
+            .filter_map(PieceType::from_char)
+            .collect();
+        let first = queue.pop_front();
+        game.splits = None;
+        game.puzzle = Some(PuzzleRun {
+            puzzle,
+            queue,
+            out_of_pieces: first.is_none(),
+            solved: false,
+        });
+
+        match first {
+            Some(piece_type) => {
+                game.next_piece = piece_type;
+                game.spawn_piece();
+            }
+            None => game.check_puzzle(),
+        }
+        game
+    }
+
+    /// Partie Dig : le bas du plateau est rempli de `DIG_ROWS` lignes de
+    /// déchets percées d'un seul trou chacune
+    pub fn dig(audio: AudioManager) -> Self {
+        let mut game = Self::new(audio);
+        let mut rng = rng::rng();
+
+        for y in BOARD_HEIGHT - DIG_ROWS..BOARD_HEIGHT {
+            let hole = rng.random_range(0..BOARD_WIDTH);
+            for x in 0..BOARD_WIDTH {
+                if x != hole {
+                    game.board[(x, y)] = Some(PieceType::Garbage);
+                }
+            }
+        }
+
+        let mut recorder = GhostRecorder::new();
+        recorder.record_progress(Duration::ZERO, DIG_ROWS as u32);
+        game.splits = None;
+        game.dig = Some(DigState {
+            garbage_rows: DIG_ROWS,
+            pieces_used: 0,
+            cleared: false,
+            ghost: ghosts::load(DIG_GHOST_ID),
+            recorder,
+        });
+        game
+    }
+
+    /// Nouvelle partie dans le même mode
+    fn restart(&mut self) {
+        let audio = self.audio.sibling();
+        *self = if let Some(run) = &self.puzzle {
+            Self::from_puzzle(run.puzzle.clone(), audio)
+        } else if self.dig.is_some() {
+            Self::dig(audio)
+        } else {
+            Self::new(audio)
+        };
+    }
+
+    /// Termine le puzzle quand l'objectif est atteint, ou quand il ne reste
+    /// plus de pièce pour l'atteindre
+    fn check_puzzle(&mut self) {
+        let Some(run) = &mut self.puzzle else {
+            return;
+        };
+
+        if self.lines_cleared >= run.puzzle.target_lines {
+            run.solved = true;
+            if let Err(e) = puzzles::mark_solved(&run.puzzle.id) {
+                eprintln!("Erreur lors de la sauvegarde de la progression des puzzles: {e}");
+            }
+            self.audio
+                .play_stinger(&stingers::TETRIS_VICTORY, Some(SoundEffect::TetrisTetris));
+        } else if run.out_of_pieces {
+            self.audio.play_stinger(
+                &stingers::TETRIS_GAME_OVER,
+                Some(SoundEffect::TetrisGameOver),
+            );
+        } else {
+            return;
+        }
+
+        self.game_over = true;
+        self.current_piece = None;
+        self.clock.pause();
+    }
+
+    /// Met à jour le nombre de lignes de déchets restantes et termine la
+    /// partie quand il n'en reste plus
+    fn update_dig_progress(&mut self) {
+        let garbage_rows = self
+            .board
+            .rows()
+            .filter(|row| row.contains(&Some(PieceType::Garbage)))
+            .count();
+
+        let Some(dig) = &mut self.dig else {
+            return;
+        };
+        dig.pieces_used += 1;
+        dig.garbage_rows = garbage_rows;
+        let elapsed = self.clock.elapsed();
+        dig.recorder.record_progress(elapsed, garbage_rows as u32);
+
+        if garbage_rows == 0 {
+            dig.cleared = true;
+            // Garder le déroulé s'il bat le fantôme
+            let ghost = dig.recorder.finish(dig.pieces_used, elapsed);
+            if dig
+                .ghost
+                .as_ref()
+                .is_none_or(|best| ghost.elapsed_ms < best.elapsed_ms)
+            {
+                if let Err(e) = ghosts::save(DIG_GHOST_ID, ghost) {
+                    eprintln!("Erreur lors de la sauvegarde du fantôme: {e}");
+                }
+            }
+            self.game_over = true;
+            self.current_piece = None;
+            self.clock.pause();
+            self.audio
+                .play_stinger(&stingers::TETRIS_VICTORY, Some(SoundEffect::TetrisTetris));
+        }
+    }
+
     fn spawn_piece(&mut self) {
         let new_piece = Piece::new(self.next_piece);
-        self.next_piece = PieceType::random();
+        match &mut self.puzzle {
+            Some(run) => match run.queue.pop_front() {
+                Some(piece_type) => self.next_piece = piece_type,
+                None => run.out_of_pieces = true,
+            },
+            None => self.next_piece = PieceType::random(),
+        }
 
         if self.is_valid_position(&new_piece) {
             self.current_piece = Some(new_piece);
         } else {
             self.game_over = true;
-            self.audio.stop_music();
-            self.audio.play_sound(SoundEffect::TetrisGameOver);
+            self.audio.play_stinger(
+                &stingers::TETRIS_GAME_OVER,
+                Some(SoundEffect::TetrisGameOver),
+            );
+            if let Some(splits) = &mut self.splits {
+                splits.finish();
+            }
 
             // Sauvegarder le score si c'est un high score et pas encore sauvé
             self.save_high_score_if_needed();
@@ -226,7 +496,7 @@ impl TetrisGame {
             if block.x < 0
                 || block.x >= BOARD_WIDTH as i32
                 || block.y >= BOARD_HEIGHT as i32
-                || (block.y >= 0 && self.board[block.y as usize][block.x as usize].is_some())
+                || (block.y >= 0 && self.board[(block.x as usize, block.y as usize)].is_some())
             {
                 return false;
             }
@@ -235,10 +505,19 @@ impl TetrisGame {
     }
 
     fn place_piece(&mut self) {
+        let t_spin = self
+            .current_piece
+            .as_ref()
+            .is_some_and(|piece| self.is_t_spin(piece));
+        let piece_row = self
+            .current_piece
+            .as_ref()
+            .map_or(0, |piece| piece.position.y.max(0) as usize);
+
         if let Some(piece) = &self.current_piece {
             for block in piece.get_blocks() {
                 if block.y >= 0 {
-                    self.board[block.y as usize][block.x as usize] = Some(piece.piece_type);
+                    self.board[(block.x as usize, block.y as usize)] = Some(piece.piece_type);
                 }
             }
         }
@@ -247,19 +526,46 @@ impl TetrisGame {
         // Jouer le son de pièce posée
         self.audio.play_sound(SoundEffect::TetrisPieceDrop);
 
-        self.clear_lines();
-        self.spawn_piece();
+        self.clear_lines(t_spin, piece_row);
+        self.update_dig_progress();
+        self.check_puzzle();
+        if !self.game_over {
+            self.spawn_piece();
+        }
     }
 
-    fn clear_lines(&mut self) {
-        let mut lines_to_clear = Vec::new();
+    /// Règle des trois coins : un T verrouillé juste après une rotation, dont au
+    /// moins trois des coins autour du centre sont occupés (ou hors du plateau)
+    fn is_t_spin(&self, piece: &Piece) -> bool {
+        if piece.piece_type != PieceType::T || !self.last_move_was_rotation {
+            return false;
+        }
+
+        let center_x = piece.position.x + 1;
+        let center_y = piece.position.y + 1;
+        let occupied_corners = [(-1, -1), (1, -1), (-1, 1), (1, 1)]
+            .iter()
+            .filter(|(dx, dy)| {
+                let (x, y) = (center_x + dx, center_y + dy);
+                x < 0
+                    || x >= BOARD_WIDTH as i32
+                    || y >= BOARD_HEIGHT as i32
+                    || (y >= 0 && self.board[(x as usize, y as usize)].is_some())
+            })
+            .count();
+
+        occupied_corners >= 3
+    }
 
+    fn clear_lines(&mut self, t_spin: bool, piece_row: usize) {
         // Identifier les lignes complètes
-        for y in 0..BOARD_HEIGHT {
-            if self.board[y].iter().all(|cell| cell.is_some()) {
-                lines_to_clear.push(y);
-            }
-        }
+        let lines_to_clear: Vec<usize> = self
+            .board
+            .rows()
+            .enumerate()
+            .filter(|(_, row)| row.iter().all(|cell| cell.is_some()))
+            .map(|(y, _)| y)
+            .collect();
 
         // Jouer le son approprié selon le nombre de lignes
         if !lines_to_clear.is_empty() {
@@ -272,37 +578,77 @@ impl TetrisGame {
                     if self.audio.is_music_enabled() {
                         self.audio.stop_music();
                         self.audio.play_tetris_music_harmony();
-                        self.music_started = false; // Pour que la musique normale reprenne après
                     }
                 }
                 _ => {}
             }
         }
 
-        // Supprimer les lignes complètes et les remplacer
-        for &line in lines_to_clear.iter().rev() {
-            for y in (1..=line).rev() {
-                self.board[y] = self.board[y - 1];
-            }
-            self.board[0] = [None; BOARD_WIDTH];
+        // Supprimer les lignes complètes de haut en bas : les lignes situées
+        // en dessous ne bougent pas, les index restants restent donc valides
+        for &line in &lines_to_clear {
+            self.board.remove_row(line, None);
         }
 
         // Mettre à jour le score et le niveau
         let lines_count = lines_to_clear.len() as u32;
         if lines_count > 0 {
             self.lines_cleared += lines_count;
-            self.level = (self.lines_cleared / 10) + 1;
-
-            // Système de score Tetris classique
-            let line_score = match lines_count {
-                1 => 40,
-                2 => 100,
-                3 => 300,
-                4 => 1200, // Tetris!
-                _ => 0,
-            };
-            self.score += line_score * self.level;
+            let level = (self.lines_cleared / 10) + 1;
+            if let Some(splits) = self.splits.as_mut().filter(|_| level > self.level) {
+                splits.mark(self.clock.elapsed());
+            }
+            self.level = level;
+        }
+
+        // Système de score Tetris classique, avec bonus pour les T-Spins
+        let (label, line_score) = match (t_spin, lines_count) {
+            (false, 1) => ("Single", 40),
+            (false, 2) => ("Double", 100),
+            (false, 3) => ("Triple", 300),
+            (false, 4) => ("Tetris", 1200),
+            (true, 0) => ("T-Spin", 100),
+            (true, 1) => ("T-Spin Single", 300),
+            (true, 2) => ("T-Spin Double", 700),
+            (true, 3) => ("T-Spin Triple", 1200),
+            _ => {
+                // Aucune ligne : la série de combos s'arrête
+                self.combo = 0;
+                return;
+            }
+        };
+        let mut points = line_score * self.level;
+        let mut text = label.to_string();
+
+        // Back-to-back : deux effacements difficiles d'affilée rapportent 50 % de plus
+        if lines_count > 0 {
+            let difficult = t_spin || lines_count == 4;
+            if difficult && self.back_to_back {
+                points = points * 3 / 2;
+                text = format!("Back-to-Back {label} x1.5");
+            }
+            self.back_to_back = difficult;
         }
+
+        let mut popup_lines = vec![format!("{text} +{points}")];
+
+        // Combo : chaque pièce qui efface encore des lignes ajoute un bonus croissant
+        if lines_count > 0 {
+            self.combo += 1;
+            if self.combo >= 2 {
+                let bonus = 50 * (self.combo - 1) * self.level;
+                points += bonus;
+                popup_lines.push(format!("Combo x{} +{bonus}", self.combo));
+            }
+        }
+
+        self.score += points;
+
+        self.popups.push(ScorePopup {
+            lines: popup_lines,
+            row: lines_to_clear.first().copied().unwrap_or(piece_row),
+            frames_left: POPUP_FRAMES,
+        });
     }
 
     fn move_piece(&mut self, dx: i32, dy: i32) -> bool {
@@ -311,6 +657,8 @@ impl TetrisGame {
             if self.is_valid_position(&new_piece) {
                 self.current_piece = Some(new_piece);
 
+                self.last_move_was_rotation = false;
+
                 // Son subtil pour le déplacement horizontal
                 if dx != 0 {
                     self.audio.play_sound(SoundEffect::TetrisMove);
@@ -326,6 +674,7 @@ impl TetrisGame {
             let rotated_piece = piece.rotated();
             if self.is_valid_position(&rotated_piece) {
                 self.current_piece = Some(rotated_piece);
+                self.last_move_was_rotation = true;
                 self.audio.play_sound(SoundEffect::TetrisRotate);
                 return true;
             }
@@ -355,50 +704,45 @@ impl TetrisGame {
 
     fn get_drop_interval(&self) -> u32 {
         // Vitesse progressive basée sur le niveau
-        std::cmp::max(1, 21 - self.level)
+        std::cmp::max(1, (21 - self.level) / self.speed_factor)
     }
 
     fn start_music_if_needed(&mut self) {
-        if !self.music_started && self.audio.is_music_enabled() {
-            // Choisir la version de la musique selon le niveau
-            if self.level >= 7 {
-                self.audio.play_tetris_music_fast(); // Version rapide pour les niveaux élevés
-            } else {
-                self.audio.play_tetris_music(); // Version normale
-            }
-            self.music_started = true;
+        // Sans effet si la musique boucle déjà ; après l'harmonie, la musique
+        // normale reprend à la fin de son tour
+        if self.audio.is_music_enabled() {
+            self.audio.play_tetris_music();
         }
 
-        // Relancer la musique si elle est finie
-        if self.music_started && self.audio.is_music_enabled() && self.audio.is_music_empty() {
-            // Choisir la version appropriée selon le niveau actuel
-            if self.level >= 7 {
-                self.audio.play_tetris_music_fast();
-            } else {
-                self.audio.play_tetris_music();
-            }
-        }
+        // Les percussions montent avec le niveau, au maximum à partir du niveau 7
+        let intensity = self.level.saturating_sub(1) as f32 / 6.0;
+        self.audio.set_music_intensity(intensity);
+
+        // Le tempo accélère avec la chute des pièces, jusqu'au niveau 15
+        self.audio
+            .set_music_speed(self.level.saturating_sub(1) as f32 / 14.0);
     }
 
     fn save_high_score_if_needed(&mut self) {
-        // Ne sauvegarder qu'une seule fois
-        if self.score_saved {
+        // Ne sauvegarder qu'une seule fois ; le mode Dig se joue au temps et
+        // n'a pas de tableau des scores
+        if self.score_saved || self.dig.is_some() || self.puzzle.is_some() {
             return;
         }
 
         // Vérifier si c'est un high score
         if self.highscore_manager.is_high_score("tetris", self.score) {
-            let duration = self.start_time.elapsed().as_secs();
+            let duration = self.clock.elapsed_secs();
             let game_data = GameData::Tetris {
                 level: self.level,
                 lines_cleared: self.lines_cleared,
                 duration_seconds: duration,
             };
 
-            let score = Score::new("Anonymous".to_string(), self.score, game_data);
+            let score = Score::new(current_player_name(), self.score, game_data);
 
             // Sauvegarder le score
-            if let Ok(_is_top_10) = self.highscore_manager.add_score("tetris", score) {
+            if let Ok(_new_score) = self.highscore_manager.add_score("tetris", score) {
                 self.score_saved = true;
             }
         }
@@ -413,13 +757,23 @@ impl Game for TetrisGame {
                     // Nettoyer l'audio avant de redémarrer
                     self.audio.clear_effects();
                     self.audio.stop_music();
-                    *self = Self::new();
+                    self.restart();
                     GameAction::Continue
                 }
                 KeyCode::Char('q') => GameAction::Quit,
                 _ => GameAction::Continue,
             }
         } else {
+            if key.code == KeyCode::Char('r') && self.instant_restart() {
+                // Dig et puzzles se relancent à la chaîne
+                self.audio.clear_effects();
+                self.audio.stop_music();
+                self.restart();
+                return GameAction::Continue;
+            }
+            if let Some(dig) = &mut self.dig {
+                dig.recorder.record_key(key);
+            }
             match key.code {
                 KeyCode::Left => {
                     self.move_piece(-1, 0);
@@ -452,9 +806,6 @@ impl Game for TetrisGame {
                     self.audio.toggle_music();
                     if self.audio.is_music_enabled() {
                         self.audio.play_tetris_music();
-                        self.music_started = true;
-                    } else {
-                        self.music_started = false;
                     }
                     GameAction::Continue
                 }
@@ -476,9 +827,19 @@ impl Game for TetrisGame {
                 self.tetris_celebration -= 1;
             }
 
+            // Faire vieillir les popups de score
+            for popup in &mut self.popups {
+                popup.frames_left = popup.frames_left.saturating_sub(1);
+            }
+            self.popups.retain(|popup| popup.frames_left > 0);
+
             // Démarrer la musique si ce n'est pas encore fait
             self.start_music_if_needed();
 
+            if let Some(dig) = &mut self.dig {
+                dig.recorder.tick();
+            }
+
             self.drop_timer += 1;
             if self.drop_timer >= self.get_drop_interval() {
                 self.drop_piece();
@@ -488,18 +849,94 @@ impl Game for TetrisGame {
         GameAction::Continue
     }
 
-    fn draw(&mut self, frame: &mut ratatui::Frame) {
-        draw_tetris_game(frame, self);
+    fn preview(&self, frame: &mut ratatui::Frame, area: Rect) {
+        draw_preview(frame, area, self.dig.is_some());
+    }
+
+    fn draw(&mut self, frame: &mut ratatui::Frame, area: Rect) {
+        draw_tetris_game(frame, area, self);
     }
 
     fn tick_rate(&self) -> Duration {
         Duration::from_millis(50) // Plus rapide pour une meilleure réactivité
     }
-}
 
-fn draw_tetris_game(frame: &mut ratatui::Frame, game: &TetrisGame) {
-    let area = frame.area();
+    fn status(&self) -> GameStatus {
+        let won = self.puzzle.as_ref().is_some_and(|run| run.solved)
+            || self.dig.as_ref().is_some_and(|dig| dig.cleared);
+        if won {
+            GameStatus::Victory
+        } else if self.game_over {
+            GameStatus::GameOver
+        } else if self.clock.is_suspended() {
+            GameStatus::Paused
+        } else {
+            GameStatus::Running
+        }
+    }
+
+    fn danger(&self) -> DangerLevel {
+        if self.game_over {
+            return DangerLevel::Calm;
+        }
+        let free_rows = self
+            .board
+            .rows()
+            .take_while(|row| row.iter().all(Option::is_none))
+            .count();
+        if free_rows <= CRITICAL_FREE_ROWS {
+            DangerLevel::Critical
+        } else if free_rows <= WARNING_FREE_ROWS {
+            DangerLevel::Warning
+        } else {
+            DangerLevel::Calm
+        }
+    }
+
+    fn session_score(&self) -> Option<u32> {
+        Some(self.score)
+    }
+
+    fn stage_progress(&self) -> Option<u32> {
+        Some(self.lines_cleared)
+    }
+
+    fn title_status(&self) -> Option<String> {
+        if let Some(run) = &self.puzzle {
+            return Some(format!(
+                "{}/{} lines",
+                self.lines_cleared, run.puzzle.target_lines
+            ));
+        }
+        if let Some(dig) = &self.dig {
+            return Some(format!("{} rows left", dig.garbage_rows));
+        }
+        Some(format!("{} pts", numbers::score(self.score)))
+    }
+
+    fn on_pause(&mut self) {
+        self.clock.suspend();
+    }
+
+    fn on_resume(&mut self) {
+        self.clock.unsuspend();
+    }
 
+    fn instant_restart(&self) -> bool {
+        self.dig.is_some() || self.puzzle.is_some()
+    }
+
+    fn audio(&self) -> Option<&AudioManager> {
+        Some(&self.audio)
+    }
+
+    fn splits(&self) -> Option<(&SplitTimer, Duration)> {
+        let elapsed = self.clock.elapsed();
+        self.splits.as_ref().map(|splits| (splits, elapsed))
+    }
+}
+
+fn draw_tetris_game(frame: &mut ratatui::Frame, area: Rect, game: &TetrisGame) {
     // Vérification de taille minimale pour éviter les erreurs de rendu
     if area.width < 30 || area.height < 15 {
         // Afficher un message d'erreur si l'écran est trop petit
@@ -530,67 +967,109 @@ fn draw_tetris_game(frame: &mut ratatui::Frame, game: &TetrisGame) {
     .split(area);
 
     // Fond sombre
-    let background = Block::new().style(Style::default().bg(Color::Rgb(15, 20, 25)));
-    frame.render_widget(background, area);
+    style::fill_area(frame, area, style::background());
 
     // === HEADER ===
     let audio_status = if game.audio.is_enabled() {
-        "🔊"
+        glyphs::icon("🔊")
     } else {
-        "🔇"
+        glyphs::icon("🔇")
     };
     let music_status = if game.audio.is_music_enabled() {
-        "🎵"
+        glyphs::icon("🎵")
+    } else {
+        glyphs::icon("🔇")
+    };
+    let speed_indicator = glyphs::icon(if game.level >= 7 { "⚡" } else { "🐌" });
+    let title = if game.puzzle.is_some() {
+        "TETRIS PUZZLE"
+    } else if game.dig.is_some() {
+        "TETRIS DIG"
     } else {
-        "🔇"
+        "TETRIS"
+    };
+
+    let status_line = if let Some(run) = &game.puzzle {
+        let pieces_left = run.queue.len() + usize::from(!run.out_of_pieces);
+        Line::from(vec![
+            "Puzzle: ".yellow(),
+            run.puzzle.name.as_str().white().bold(),
+            " | Lines: ".gray(),
+            format!("{}/{}", game.lines_cleared, run.puzzle.target_lines)
+                .green()
+                .bold(),
+            " | Pieces Left: ".gray(),
+            format!("{pieces_left}").cyan().bold(),
+        ])
+    } else if let Some(dig) = &game.dig {
+        let mut spans = vec![
+            "Garbage: ".yellow(),
+            format!("{}", dig.garbage_rows).white().bold(),
+            " | Pieces: ".gray(),
+            format!("{}", dig.pieces_used).green().bold(),
+            " | Time: ".gray(),
+            game.clock.format().cyan().bold(),
+        ];
+        // Lignes restantes du record au même temps de jeu
+        if let Some(ghost_rows) = dig
+            .ghost
+            .as_ref()
+            .and_then(|ghost| ghost.value_at(game.clock.elapsed()))
+        {
+            let lead = i64::from(ghost_rows) - dig.garbage_rows as i64;
+            spans.push(" | Ghost: ".gray());
+            spans.push(format!("{ghost_rows} ").white().bold());
+            spans.push(if lead >= 0 {
+                format!("(+{lead})").green().bold()
+            } else {
+                format!("({lead})").red().bold()
+            });
+        }
+        spans.extend([
+            " | Audio: ".gray(),
+            audio_status.white(),
+            " | Music: ".gray(),
+            music_status.white(),
+        ]);
+        Line::from(spans)
+    } else {
+        Line::from(vec![
+            "Score: ".yellow(),
+            numbers::score(game.score).white().bold(),
+            " | Lines: ".gray(),
+            format!("{}", game.lines_cleared).green().bold(),
+            " | Level: ".gray(),
+            format!("{}", game.level).red().bold(),
+            " ".white(),
+            speed_indicator.white(),
+            " | Audio: ".gray(),
+            audio_status.white(),
+            " | Music: ".gray(),
+            music_status.white(),
+        ])
     };
-    let speed_indicator = if game.level >= 7 { "⚡" } else { "🐌" };
 
     let header_text = if game.tetris_celebration > 0 {
         vec![
             Line::from(vec![
-                "🧩 ".blue().bold(),
-                "TETRIS".cyan().bold(),
-                " 🧩  🎉 ".blue().bold(),
+                format!("{} ", glyphs::icon("🧩")).blue().bold(),
+                title.cyan().bold(),
+                format!(" {}  {} ", glyphs::icon("🧩"), glyphs::icon("🎉"))
+                    .blue()
+                    .bold(),
                 "TETRIS!".yellow().bold(),
-                " 🎉".blue().bold(),
-            ]),
-            Line::from(vec![
-                "Score: ".yellow(),
-                format!("{}", game.score).white().bold(),
-                " | Lines: ".gray(),
-                format!("{}", game.lines_cleared).green().bold(),
-                " | Level: ".gray(),
-                format!("{}", game.level).red().bold(),
-                " ".white(),
-                speed_indicator.white(),
-                " | Audio: ".gray(),
-                audio_status.white(),
-                " | Music: ".gray(),
-                music_status.white(),
+                format!(" {}", glyphs::icon("🎉")).blue().bold(),
             ]),
+            status_line,
         ]
     } else {
         vec![
             Line::from(vec![
-                "🧩 ".blue().bold(),
-                "TETRIS".cyan().bold(),
-                " 🧩".blue().bold(),
-            ]),
-            Line::from(vec![
-                "Score: ".yellow(),
-                format!("{}", game.score).white().bold(),
-                " | Lines: ".gray(),
-                format!("{}", game.lines_cleared).green().bold(),
-                " | Level: ".gray(),
-                format!("{}", game.level).red().bold(),
-                " ".white(),
-                speed_indicator.white(),
-                " | Audio: ".gray(),
-                audio_status.white(),
-                " | Music: ".gray(),
-                music_status.white(),
+                format!("{} ", glyphs::icon("🧩")).blue().bold(),
+                title.cyan().bold(),
+                format!(" {}", glyphs::icon("🧩")).blue().bold(),
             ]),
+            status_line,
         ]
     };
 
@@ -622,10 +1101,23 @@ fn draw_tetris_game(frame: &mut ratatui::Frame, game: &TetrisGame) {
         height: (board_height + 2).min(inner_area.height), // +2 pour les bordures, mais limité par l'écran
     };
 
-    // Dessiner le cadre de jeu
+    // Dessiner le cadre de jeu, qui pulse sur les temps de la musique et
+    // rougit quand la pile approche du haut
+    let on_beat = game
+        .audio
+        .music_position()
+        .is_some_and(|position| position.beat_phase() < BEAT_PULSE);
+    let danger = game.danger();
+    let border_style = if on_beat {
+        Style::new()
+            .fg(animation::danger_color(Color::LightGreen, danger))
+            .bold()
+    } else {
+        Style::new().fg(animation::danger_color(Color::Green, danger))
+    };
     let game_block = Block::bordered()
         .title(" Playing Field ".green().bold())
-        .border_style(Style::new().green())
+        .border_style(border_style)
         .style(Style::default().bg(Color::Rgb(10, 15, 20)));
     frame.render_widget(game_block, game_rect);
 
@@ -653,7 +1145,7 @@ fn draw_tetris_game(frame: &mut ratatui::Frame, game: &TetrisGame) {
                     height: 1,
                 };
 
-                let (symbol, color) = if let Some(piece_type) = game.board[y][x] {
+                let (symbol, color) = if let Some(piece_type) = game.board[(x, y)] {
                     ("██", piece_type.get_color())
                 } else {
                     ("░░", Color::Rgb(40, 40, 50))
@@ -694,15 +1186,47 @@ fn draw_tetris_game(frame: &mut ratatui::Frame, game: &TetrisGame) {
         }
     }
 
+    // Dessiner les popups de score par-dessus le plateau
+    for popup in &game.popups {
+        // Remonter d'une ligne toutes les 10 frames
+        let rise = ((POPUP_FRAMES - popup.frames_left) / 10) as usize;
+        let top = popup.row.saturating_sub(rise);
+
+        for (i, text) in popup.lines.iter().enumerate() {
+            let y = board_area.y + (top + i) as u16;
+            if y >= board_area.y + board_area.height {
+                break;
+            }
+
+            let popup_area = Rect {
+                x: board_area.x,
+                y,
+                width: board_area.width,
+                height: 1,
+            };
+            let label = Paragraph::new(text.as_str())
+                .alignment(ratatui::layout::Alignment::Center)
+                .style(
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .bg(Color::Rgb(10, 15, 20))
+                        .bold(),
+                );
+            frame.render_widget(label, popup_area);
+        }
+    }
+
     // Dessiner les infos à côté (prochaine pièce)
     let info_area = Rect {
         x: board_area.x + board_width + 2,
         y: board_area.y,
         width: game_rect.width.saturating_sub(board_width + 3),
         height: 8,
-    };
+    }
+    .intersection(area);
 
-    if info_area.width > 0 {
+    let has_next = game.puzzle.as_ref().is_none_or(|run| !run.out_of_pieces) && !game.hide_next;
+    if info_area.width > 0 && has_next {
         let next_text = vec![Line::from("Next:".yellow().bold()), Line::from("")];
 
         let next_info = Paragraph::new(next_text).block(
@@ -780,40 +1304,81 @@ fn draw_tetris_game(frame: &mut ratatui::Frame, game: &TetrisGame) {
     // === GAME OVER POPUP ===
     if game.game_over {
         let popup_width = 50.min(area.width);
-        let popup_height = 10.min(area.height);
+
+        let (title_lines, stats) = match (&game.puzzle, &game.dig) {
+            (Some(run), _) => (
+                vec![if run.solved {
+                    Line::from("🧩 PUZZLE SOLVED! 🧩".green().bold())
+                } else {
+                    Line::from("❌ PUZZLE FAILED ❌".red().bold())
+                }],
+                vec![
+                    Line::from(run.puzzle.objective.as_str().white()),
+                    Line::from(vec![
+                        "Lines Cleared: ".white(),
+                        format!("{}", game.lines_cleared).green().bold(),
+                    ]),
+                    Line::from(vec!["Time: ".white(), game.clock.format().cyan().bold()]),
+                ],
+            ),
+            (None, Some(dig)) => (
+                vec![if dig.cleared {
+                    Line::from("⛏ DUG OUT! ⛏".green().bold())
+                } else {
+                    Line::from("💀 GAME OVER 💀".red().bold())
+                }],
+                vec![
+                    Line::from(vec!["Time: ".white(), game.clock.format().cyan().bold()]),
+                    Line::from(vec![
+                        "Pieces Used: ".white(),
+                        format!("{}", dig.pieces_used).green().bold(),
+                    ]),
+                    Line::from(vec![
+                        "Garbage Left: ".white(),
+                        format!("{}", dig.garbage_rows).yellow().bold(),
+                    ]),
+                ],
+            ),
+            (None, None) => (
+                bigtext::headline(
+                    "GAME OVER",
+                    Style::new().red().bold(),
+                    popup_width.saturating_sub(2),
+                    Line::from("💀 GAME OVER 💀".red().bold()),
+                ),
+                vec![
+                    Line::from(vec![
+                        "Final Score: ".white(),
+                        numbers::score(game.score).yellow().bold(),
+                    ]),
+                    Line::from(vec![
+                        "Lines Cleared: ".white(),
+                        format!("{}", game.lines_cleared).green().bold(),
+                    ]),
+                    Line::from(vec![
+                        "Level Reached: ".white(),
+                        format!("{}", game.level).red().bold(),
+                    ]),
+                ],
+            ),
+        };
+
+        // Le grand titre agrandit la fenêtre d'autant de lignes
+        let popup_height = (9 + title_lines.len() as u16).min(area.height);
         let popup_area = Rect {
-            x: if area.width >= popup_width {
-                (area.width - popup_width) / 2
-            } else {
-                0
-            },
-            y: if area.height >= popup_height {
-                (area.height - popup_height) / 2
-            } else {
-                0
-            },
+            x: area.x + (area.width - popup_width) / 2,
+            y: area.y + (area.height - popup_height) / 2,
             width: popup_width,
             height: popup_height,
         };
 
         frame.render_widget(Clear, popup_area);
 
-        let game_over_text = vec![
-            Line::from(""),
-            Line::from("💀 GAME OVER 💀".red().bold()),
-            Line::from(""),
-            Line::from(vec![
-                "Final Score: ".white(),
-                format!("{}", game.score).yellow().bold(),
-            ]),
-            Line::from(vec![
-                "Lines Cleared: ".white(),
-                format!("{}", game.lines_cleared).green().bold(),
-            ]),
-            Line::from(vec![
-                "Level Reached: ".white(),
-                format!("{}", game.level).red().bold(),
-            ]),
+        let mut game_over_text = vec![Line::from("")];
+        game_over_text.extend(title_lines);
+        game_over_text.push(Line::from(""));
+        game_over_text.extend(stats);
+        game_over_text.extend([
             Line::from(""),
             Line::from(vec![
                 "Press ".gray(),
@@ -822,7 +1387,7 @@ fn draw_tetris_game(frame: &mut ratatui::Frame, game: &TetrisGame) {
                 "Q".red().bold(),
                 " to quit".gray(),
             ]),
-        ];
+        ]);
 
         let popup = Paragraph::new(game_over_text)
             .alignment(ratatui::layout::Alignment::Center)
@@ -835,3 +1400,162 @@ fn draw_tetris_game(frame: &mut ratatui::Frame, game: &TetrisGame) {
         frame.render_widget(popup, popup_area);
     }
 }
+
+/// Aperçu du menu : une pièce qui tombe sur une pile, ou sur les rangées
+/// de débris en mode Dig. Une lettre par type de pièce
+const PREVIEW: [&str; 10] = [
+    "...TTT....",
+    "....T.....",
+    "..........",
+    "..........",
+    "..........",
+    "I.........",
+    "I.....ZZ..",
+    "IJ...S.ZZ.",
+    "IJJJSS.LOO",
+    "LLL..S.LOO",
+];
+const DIG_PREVIEW: [&str; 10] = [
+    "...TTT....",
+    "....T.....",
+    "..........",
+    "..........",
+    "..........",
+    "I.........",
+    "I.....ZZ..",
+    "IJJJ...ZZ.",
+    "GGGG.GGGGG",
+    "GG.GGGGGGG",
+];
+
+fn draw_preview(frame: &mut ratatui::Frame, area: Rect, dig: bool) {
+    let rows = if dig { &DIG_PREVIEW } else { &PREVIEW };
+    preview::draw_board(frame, area, rows, |cell| {
+        let piece = match cell {
+            'I' => PieceType::I,
+            'O' => PieceType::O,
+            'T' => PieceType::T,
+            'S' => PieceType::S,
+            'Z' => PieceType::Z,
+            'J' => PieceType::J,
+            'L' => PieceType::L,
+            'G' => PieceType::Garbage,
+            _ => return "░░".fg(Color::Rgb(40, 40, 50)),
+        };
+        "██".fg(piece.get_color())
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::isolate_data_dir;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    /// Action du joueur, ou un tick de la boucle de jeu
+    #[derive(Debug, Clone, Copy)]
+    enum Action {
+        Key(KeyCode),
+        Tick,
+    }
+
+    fn action() -> impl Strategy<Value = Action> {
+        prop_oneof![
+            Just(Action::Key(KeyCode::Left)),
+            Just(Action::Key(KeyCode::Right)),
+            Just(Action::Key(KeyCode::Down)),
+            Just(Action::Key(KeyCode::Up)),
+            Just(Action::Key(KeyCode::Char(' '))),
+            Just(Action::Tick),
+        ]
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        /// Ni les déplacements ni les rotations (une rotation bloquée est
+        /// refusée, sans wall kick) ne sortent une pièce du plateau ou ne la
+        /// superposent à un bloc posé
+        #[test]
+        fn pieces_stay_on_the_board(seed in any::<u64>(), actions in vec(action(), 1..200)) {
+            isolate_data_dir();
+            rng::seed(seed);
+            let mut game = TetrisGame::new(AudioManager::silent());
+
+            for action in actions {
+                if game.game_over {
+                    break;
+                }
+                match action {
+                    Action::Key(code) => {
+                        game.handle_key(KeyEvent::from(code));
+                    }
+                    Action::Tick => {
+                        game.update();
+                    }
+                }
+
+                let Some(piece) = &game.current_piece else {
+                    continue;
+                };
+                for block in piece.get_blocks() {
+                    prop_assert!((0..BOARD_WIDTH as i32).contains(&block.x), "{block:?}");
+                    prop_assert!(block.y < BOARD_HEIGHT as i32, "{block:?}");
+                    if block.y >= 0 {
+                        prop_assert!(game.board[(block.x as usize, block.y as usize)].is_none());
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn four_rotations_give_back_the_same_blocks(seed in any::<u64>()) {
+            isolate_data_dir();
+            rng::seed(seed);
+            let piece = Piece::new(PieceType::random());
+
+            let turned = piece.rotated().rotated().rotated().rotated();
+            prop_assert_eq!(turned.get_blocks(), piece.get_blocks());
+        }
+    }
+
+    #[test]
+    fn danger_rises_as_the_stack_nears_the_top() {
+        isolate_data_dir();
+        let mut game = TetrisGame::new(AudioManager::silent());
+        assert_eq!(game.danger(), DangerLevel::Calm);
+
+        let mut stack_up_to = |top: usize| {
+            game.board.fill(None);
+            game.board[(0, top)] = Some(PieceType::I);
+            game.danger()
+        };
+        assert_eq!(stack_up_to(WARNING_FREE_ROWS + 1), DangerLevel::Calm);
+        assert_eq!(stack_up_to(WARNING_FREE_ROWS), DangerLevel::Warning);
+        assert_eq!(stack_up_to(CRITICAL_FREE_ROWS), DangerLevel::Critical);
+
+        game.game_over = true;
+        assert_eq!(game.danger(), DangerLevel::Calm);
+    }
+
+    #[test]
+    fn status_reports_pauses_and_cleared_digs() {
+        isolate_data_dir();
+        let mut game = TetrisGame::dig(AudioManager::silent());
+        assert_eq!(game.status(), GameStatus::Running);
+        game.on_pause();
+        assert_eq!(game.status(), GameStatus::Paused);
+        game.on_resume();
+        assert_eq!(game.status(), GameStatus::Running);
+
+        // Plus une ligne de déchets : la partie est gagnée
+        game.board.fill(None);
+        game.update_dig_progress();
+        assert_eq!(game.status(), GameStatus::Victory);
+
+        let mut game = TetrisGame::new(AudioManager::silent());
+        game.game_over = true;
+        assert_eq!(game.status(), GameStatus::GameOver);
+    }
+}
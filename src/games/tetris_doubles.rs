@@ -0,0 +1,696 @@
+//! Tetris à deux sur un plateau de double largeur.
+//!
+//! Chaque joueur pilote sa propre pièce, qui apparaît au-dessus de sa moitié
+//! du plateau puis peut aller partout. Les deux pièces actives ne se
+//! traversent pas : un déplacement ou une rotation qui chevaucherait la pièce
+//! de l'autre est refusé, et une pièce qui tombe sur l'autre attend qu'elle se
+//! dégage au lieu de se verrouiller. Lignes, score et niveau sont communs.
+
+use super::tetris::{Piece, PieceType};
+use crate::audio::{AudioManager, SoundEffect};
+use crate::core::clock::GameClock;
+use crate::core::grid::Grid2D;
+use crate::core::{Game, GameAction, GameStatus};
+use crate::event::{KeyCode, KeyEvent};
+use crate::music::stingers;
+use crate::ui::{glyphs, numbers, preview, style};
+use ratatui::{
+    layout::{Constraint, Layout, Margin, Rect},
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Clear, Paragraph},
+};
+use std::time::Duration;
+
+const BOARD_WIDTH: usize = 20;
+const BOARD_HEIGHT: usize = 20;
+/// Colonne d'apparition des pièces de chaque joueur, au milieu de sa moitié
+const SPAWN_X: [i32; 2] = [3, 13];
+/// Largeur des panneaux "Next" de part et d'autre du plateau
+const NEXT_PANEL_WIDTH: u16 = 12;
+
+/// Ce qui empêche une pièce de bouger
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Obstacle {
+    /// Bord, fond ou bloc posé : la pièce se verrouille en tombant dessus
+    Board,
+    /// Pièce active de l'autre joueur : la pièce attend
+    Partner,
+}
+
+struct Seat {
+    piece: Option<Piece>,
+    next: PieceType,
+}
+
+impl Seat {
+    fn new() -> Self {
+        Self {
+            piece: None,
+            next: PieceType::random(),
+        }
+    }
+}
+
+pub struct TetrisDoubles {
+    board: Grid2D<Option<PieceType>>,
+    /// Joueur 1 (WASD) puis joueur 2 (flèches)
+    seats: [Seat; 2],
+    score: u32,
+    lines_cleared: u32,
+    level: u32,
+    game_over: bool,
+    drop_timer: u32,
+    audio: AudioManager,
+    clock: GameClock,
+    /// Couleurs des joueurs 1 et 2 : cadres et moitiés du plateau
+    colors: [Color; 2],
+}
+
+impl TetrisDoubles {
+    pub fn new(audio: AudioManager) -> Self {
+        let mut game = Self {
+            board: Grid2D::new(BOARD_WIDTH, BOARD_HEIGHT, None),
+            seats: [Seat::new(), Seat::new()],
+            score: 0,
+            lines_cleared: 0,
+            level: 1,
+            game_over: false,
+            drop_timer: 0,
+            audio,
+            clock: GameClock::new(),
+            colors: style::player_colors(),
+        };
+        game.spawn_piece(0);
+        game.spawn_piece(1);
+        game
+    }
+
+    fn restart(&mut self) {
+        *self = Self::new(self.audio.sibling());
+    }
+
+    /// Obstacle qui empêche `piece`, pilotée par `seat`, d'être à sa place
+    fn obstacle(&self, seat: usize, piece: &Piece) -> Option<Obstacle> {
+        let blocks = piece.get_blocks();
+        let off_board = blocks.iter().any(|block| {
+            block.x < 0
+                || block.x >= BOARD_WIDTH as i32
+                || block.y >= BOARD_HEIGHT as i32
+                || (block.y >= 0 && self.board[(block.x as usize, block.y as usize)].is_some())
+        });
+        if off_board {
+            return Some(Obstacle::Board);
+        }
+
+        let partner = self.seats[1 - seat].piece.as_ref()?;
+        let partner_blocks = partner.get_blocks();
+        blocks
+            .iter()
+            .any(|block| partner_blocks.contains(block))
+            .then_some(Obstacle::Partner)
+    }
+
+    /// Fait apparaître la pièce suivante de `seat` ; si la pièce de l'autre
+    /// joueur est dans le passage, on réessaiera à la prochaine frame
+    fn spawn_piece(&mut self, seat: usize) {
+        if self.game_over || self.seats[seat].piece.is_some() {
+            return;
+        }
+
+        let piece = Piece::spawned_at(self.seats[seat].next, SPAWN_X[seat]);
+        match self.obstacle(seat, &piece) {
+            None => {
+                self.seats[seat].piece = Some(piece);
+                self.seats[seat].next = PieceType::random();
+            }
+            Some(Obstacle::Partner) => {}
+            Some(Obstacle::Board) => {
+                self.game_over = true;
+                self.clock.pause();
+                self.audio.play_stinger(
+                    &stingers::TETRIS_GAME_OVER,
+                    Some(SoundEffect::TetrisGameOver),
+                );
+            }
+        }
+    }
+
+    /// Remplace la pièce de `seat` par `candidate` si la place est libre
+    fn try_place(&mut self, seat: usize, candidate: Piece) -> Result<(), Obstacle> {
+        match self.obstacle(seat, &candidate) {
+            Some(obstacle) => Err(obstacle),
+            None => {
+                self.seats[seat].piece = Some(candidate);
+                Ok(())
+            }
+        }
+    }
+
+    fn move_piece(&mut self, seat: usize, dx: i32, dy: i32) -> Result<(), Obstacle> {
+        let Some(piece) = &self.seats[seat].piece else {
+            return Err(Obstacle::Partner);
+        };
+        let moved = piece.moved(dx, dy);
+        self.try_place(seat, moved)?;
+        if dx != 0 {
+            self.audio.play_sound(SoundEffect::TetrisMove);
+        }
+        Ok(())
+    }
+
+    fn rotate_piece(&mut self, seat: usize) {
+        if let Some(piece) = &self.seats[seat].piece {
+            let rotated = piece.rotated();
+            if self.try_place(seat, rotated).is_ok() {
+                self.audio.play_sound(SoundEffect::TetrisRotate);
+            }
+        }
+    }
+
+    /// Descente d'une case ; la pièce se verrouille si elle repose sur le
+    /// plateau, pas si elle repose sur celle de l'autre joueur
+    fn drop_piece(&mut self, seat: usize) -> bool {
+        match self.move_piece(seat, 0, 1) {
+            Ok(()) => true,
+            Err(Obstacle::Board) => {
+                self.lock_piece(seat);
+                false
+            }
+            Err(Obstacle::Partner) => false,
+        }
+    }
+
+    fn soft_drop(&mut self, seat: usize) {
+        if self.drop_piece(seat) {
+            self.score += 1;
+        }
+    }
+
+    fn hard_drop(&mut self, seat: usize) {
+        let mut dropped_lines = 0;
+        let obstacle = loop {
+            match self.move_piece(seat, 0, 1) {
+                Ok(()) => dropped_lines += 1,
+                Err(obstacle) => break obstacle,
+            }
+        };
+
+        if dropped_lines > 0 {
+            self.score += dropped_lines * 2;
+            self.audio.play_sound(SoundEffect::TetrisHardDrop);
+        }
+        if obstacle == Obstacle::Board {
+            self.lock_piece(seat);
+        }
+    }
+
+    fn lock_piece(&mut self, seat: usize) {
+        let Some(piece) = self.seats[seat].piece.take() else {
+            return;
+        };
+        for block in piece.get_blocks() {
+            if block.y >= 0 {
+                self.board[(block.x as usize, block.y as usize)] = Some(piece.piece_type);
+            }
+        }
+        self.audio.play_sound(SoundEffect::TetrisPieceDrop);
+
+        self.clear_lines();
+        self.unstick_partner(1 - seat);
+        self.spawn_piece(seat);
+    }
+
+    /// Les lignes effacées font descendre les blocs au-dessus : la pièce de
+    /// l'autre joueur remonte si elle se retrouve dedans
+    fn unstick_partner(&mut self, seat: usize) {
+        for _ in 0..BOARD_HEIGHT {
+            let Some(piece) = &self.seats[seat].piece else {
+                return;
+            };
+            if self.obstacle(seat, piece) != Some(Obstacle::Board) {
+                return;
+            }
+            let lifted = piece.moved(0, -1);
+            self.seats[seat].piece = Some(lifted);
+        }
+    }
+
+    fn clear_lines(&mut self) {
+        let lines_to_clear: Vec<usize> = self
+            .board
+            .rows()
+            .enumerate()
+            .filter(|(_, row)| row.iter().all(|cell| cell.is_some()))
+            .map(|(y, _)| y)
+            .collect();
+
+        for &line in &lines_to_clear {
+            self.board.remove_row(line, None);
+        }
+
+        let lines_count = lines_to_clear.len() as u32;
+        let line_score = match lines_count {
+            0 => return,
+            1 => 40,
+            2 => 100,
+            3 => 300,
+            _ => 1200,
+        };
+        self.audio.play_sound(if lines_count >= 4 {
+            SoundEffect::TetrisTetris
+        } else {
+            SoundEffect::TetrisLineClear
+        });
+
+        // Un plateau deux fois plus large : deux fois plus de lignes par niveau
+        self.score += line_score * self.level;
+        self.lines_cleared += lines_count;
+        self.level = self.lines_cleared / 20 + 1;
+    }
+
+    fn get_drop_interval(&self) -> u32 {
+        std::cmp::max(1, 21 - self.level)
+    }
+
+    fn start_music_if_needed(&mut self) {
+        if self.audio.is_music_enabled() {
+            self.audio.play_tetris_music();
+        }
+        let intensity = self.level.saturating_sub(1) as f32 / 6.0;
+        self.audio.set_music_intensity(intensity);
+    }
+}
+
+impl Game for TetrisDoubles {
+    fn handle_key(&mut self, key: KeyEvent) -> GameAction {
+        if self.game_over {
+            return match key.code {
+                KeyCode::Char('r') => {
+                    self.audio.clear_effects();
+                    self.audio.stop_music();
+                    self.restart();
+                    GameAction::Continue
+                }
+                KeyCode::Char('q') => GameAction::Quit,
+                _ => GameAction::Continue,
+            };
+        }
+
+        match key.code {
+            // Joueur 1
+            KeyCode::Char('a') => _ = self.move_piece(0, -1, 0),
+            KeyCode::Char('d') => _ = self.move_piece(0, 1, 0),
+            KeyCode::Char('w') => self.rotate_piece(0),
+            KeyCode::Char('s') => self.soft_drop(0),
+            KeyCode::Char(' ') => self.hard_drop(0),
+            // Joueur 2
+            KeyCode::Left => _ = self.move_piece(1, -1, 0),
+            KeyCode::Right => _ = self.move_piece(1, 1, 0),
+            KeyCode::Up => self.rotate_piece(1),
+            KeyCode::Down => self.soft_drop(1),
+            KeyCode::Enter => self.hard_drop(1),
+            KeyCode::Char('m') => {
+                self.audio.toggle_music();
+                if self.audio.is_music_enabled() {
+                    self.audio.play_tetris_music();
+                }
+            }
+            KeyCode::Char('n') => self.audio.toggle_enabled(),
+            KeyCode::Char('q') => return GameAction::Quit,
+            _ => {}
+        }
+        GameAction::Continue
+    }
+
+    fn update(&mut self) -> GameAction {
+        if !self.game_over {
+            self.start_music_if_needed();
+            self.spawn_piece(0);
+            self.spawn_piece(1);
+
+            self.drop_timer += 1;
+            if self.drop_timer >= self.get_drop_interval() {
+                self.drop_piece(0);
+                self.drop_piece(1);
+                self.drop_timer = 0;
+            }
+        }
+        GameAction::Continue
+    }
+
+    fn preview(&self, frame: &mut ratatui::Frame, area: Rect) {
+        draw_preview(frame, area);
+    }
+
+    fn draw(&mut self, frame: &mut ratatui::Frame, area: Rect) {
+        draw_doubles(frame, area, self);
+    }
+
+    fn tick_rate(&self) -> Duration {
+        Duration::from_millis(50)
+    }
+
+    fn status(&self) -> GameStatus {
+        if self.game_over {
+            GameStatus::GameOver
+        } else if self.clock.is_suspended() {
+            GameStatus::Paused
+        } else {
+            GameStatus::Running
+        }
+    }
+
+    fn session_score(&self) -> Option<u32> {
+        Some(self.score)
+    }
+
+    fn title_status(&self) -> Option<String> {
+        Some(format!("{} pts", numbers::score(self.score)))
+    }
+
+    fn on_pause(&mut self) {
+        self.clock.suspend();
+    }
+
+    fn on_resume(&mut self) {
+        self.clock.unsuspend();
+    }
+
+    fn audio(&self) -> Option<&AudioManager> {
+        Some(&self.audio)
+    }
+}
+
+fn draw_doubles(frame: &mut ratatui::Frame, area: Rect, game: &TetrisDoubles) {
+    let chunks = Layout::vertical([
+        Constraint::Length(4), // Header
+        Constraint::Min(0),    // Zone de jeu
+        Constraint::Length(4), // Footer
+    ])
+    .split(area);
+
+    style::fill_area(frame, area, style::background());
+
+    // === HEADER ===
+    let header_text = vec![
+        Line::from(vec![
+            format!("{} ", glyphs::icon("🧩")).blue().bold(),
+            "TETRIS DOUBLES".cyan().bold(),
+            format!(" {}", glyphs::icon("🧩")).blue().bold(),
+        ]),
+        Line::from(vec![
+            "Score: ".yellow(),
+            numbers::score(game.score).white().bold(),
+            " | Lines: ".gray(),
+            format!("{}", game.lines_cleared).green().bold(),
+            " | Level: ".gray(),
+            format!("{}", game.level).red().bold(),
+            " | Time: ".gray(),
+            game.clock.format().cyan().bold(),
+        ]),
+    ];
+
+    let header = Paragraph::new(header_text)
+        .alignment(ratatui::layout::Alignment::Center)
+        .block(
+            Block::bordered()
+                .title(" Game Status ".white().bold())
+                .border_style(Style::new().cyan())
+                .style(Style::default().bg(Color::Rgb(25, 35, 45))),
+        );
+    frame.render_widget(header, chunks[0]);
+
+    // === ZONE DE JEU ===
+    let inner_area = chunks[1].inner(Margin {
+        vertical: 0,
+        horizontal: 1,
+    });
+    let board_width = BOARD_WIDTH as u16 * 2 + 2; // 2 caractères par bloc, + bordures
+    let game_rect = Rect {
+        x: inner_area.x + inner_area.width.saturating_sub(board_width) / 2,
+        y: inner_area.y,
+        width: board_width,
+        height: (BOARD_HEIGHT as u16 + 2).min(inner_area.height),
+    }
+    .intersection(area);
+
+    let board_block = Block::bordered()
+        .title(" Shared Field ".green().bold())
+        .border_style(Style::new().green())
+        .style(Style::default().bg(Color::Rgb(10, 15, 20)));
+    frame.render_widget(board_block, game_rect);
+
+    // Case de chaque pièce active : ██ pour le joueur 1, ▓▓ pour le joueur 2
+    let active: Vec<(i32, i32, &str, Color)> = game
+        .seats
+        .iter()
+        .zip(["██", "▓▓"])
+        .filter_map(|(seat, symbol)| seat.piece.as_ref().map(|piece| (piece, symbol)))
+        .flat_map(|(piece, symbol)| {
+            let color = piece.piece_type.get_color();
+            piece
+                .get_blocks()
+                .into_iter()
+                .map(move |block| (block.x, block.y, symbol, color))
+        })
+        .collect();
+
+    let rows: Vec<Line> = (0..BOARD_HEIGHT)
+        .map(|y| {
+            let spans: Vec<Span> = (0..BOARD_WIDTH)
+                .map(|x| {
+                    if let Some(&(_, _, symbol, color)) = active
+                        .iter()
+                        .find(|&&(bx, by, _, _)| bx == x as i32 && by == y as i32)
+                    {
+                        Span::styled(symbol, Style::default().fg(color).bold())
+                    } else if let Some(piece_type) = game.board[(x, y)] {
+                        Span::styled("██", Style::default().fg(piece_type.get_color()))
+                    } else {
+                        // Moitiés teintées de la couleur du joueur pour
+                        // repérer son côté
+                        let side = usize::from(x >= BOARD_WIDTH / 2);
+                        Span::styled("░░", Style::default().fg(side_tint(game.colors[side])))
+                    }
+                })
+                .collect();
+            Line::from(spans)
+        })
+        .collect();
+    frame.render_widget(
+        Paragraph::new(rows),
+        game_rect.inner(Margin {
+            vertical: 1,
+            horizontal: 1,
+        }),
+    );
+
+    // Pièces suivantes de chaque joueur, s'il y a la place sur les côtés
+    if game_rect.x >= inner_area.x + NEXT_PANEL_WIDTH {
+        let left = Rect::new(
+            game_rect.x - NEXT_PANEL_WIDTH,
+            game_rect.y,
+            NEXT_PANEL_WIDTH,
+            7,
+        );
+        let right = Rect::new(game_rect.right(), game_rect.y, NEXT_PANEL_WIDTH, 7);
+        draw_next(
+            frame,
+            left.intersection(area),
+            " P1 Next ",
+            game.seats[0].next,
+            game.colors[0],
+        );
+        draw_next(
+            frame,
+            right.intersection(area),
+            " P2 Next ",
+            game.seats[1].next,
+            game.colors[1],
+        );
+    }
+
+    // === FOOTER ===
+    let instructions = vec![
+        Line::from(vec![
+            "P1 ".fg(game.colors[0]).bold(),
+            "A/D".cyan().bold(),
+            " Move  ".white(),
+            "W".yellow().bold(),
+            " Rotate  ".white(),
+            "S".green().bold(),
+            " Soft Drop  ".white(),
+            "Space".magenta().bold(),
+            " Hard Drop".white(),
+        ]),
+        Line::from(vec![
+            "P2 ".fg(game.colors[1]).bold(),
+            "←→".cyan().bold(),
+            " Move  ".white(),
+            "↑".yellow().bold(),
+            " Rotate  ".white(),
+            "↓".green().bold(),
+            " Soft Drop  ".white(),
+            "Enter".magenta().bold(),
+            " Hard Drop  ".white(),
+            "M".blue().bold(),
+            " Music  ".white(),
+            "N".blue().bold(),
+            " Audio  ".white(),
+            "Q".red().bold(),
+            " Quit".white(),
+        ]),
+    ];
+
+    let footer = Paragraph::new(instructions)
+        .alignment(ratatui::layout::Alignment::Center)
+        .block(
+            Block::bordered()
+                .title(" Controls ".white().bold())
+                .border_style(Style::new().blue())
+                .style(Style::default().bg(Color::Rgb(25, 35, 45))),
+        );
+    frame.render_widget(footer, chunks[2]);
+
+    // === GAME OVER POPUP ===
+    if game.game_over {
+        let popup_width = 44.min(area.width);
+        let popup_height = 9.min(area.height);
+        let popup_area = Rect {
+            x: area.x + (area.width - popup_width) / 2,
+            y: area.y + (area.height - popup_height) / 2,
+            width: popup_width,
+            height: popup_height,
+        };
+
+        frame.render_widget(Clear, popup_area);
+
+        let game_over_text = vec![
+            Line::from(""),
+            Line::from("💀 GAME OVER 💀".red().bold()),
+            Line::from(""),
+            Line::from(vec![
+                "Team Score: ".white(),
+                numbers::score(game.score).yellow().bold(),
+            ]),
+            Line::from(vec![
+                "Lines Cleared: ".white(),
+                format!("{}", game.lines_cleared).green().bold(),
+            ]),
+            Line::from(""),
+            Line::from(vec![
+                "Press ".gray(),
+                "R".green().bold(),
+                " to restart or ".gray(),
+                "Q".red().bold(),
+                " to quit".gray(),
+            ]),
+        ];
+
+        let popup = Paragraph::new(game_over_text)
+            .alignment(ratatui::layout::Alignment::Center)
+            .block(
+                Block::bordered()
+                    .title(" Game Over ".red().bold())
+                    .border_style(Style::new().red().bold())
+                    .style(Style::default().bg(Color::Black)),
+            );
+        frame.render_widget(popup, popup_area);
+    }
+}
+
+/// Teinte sombre de la couleur d'un joueur pour les cases vides de sa moitié
+fn side_tint(color: Color) -> Color {
+    match color {
+        Color::Rgb(r, g, b) => Color::Rgb(25 + r / 8, 25 + g / 8, 30 + b / 8),
+        other => other,
+    }
+}
+
+fn draw_next(
+    frame: &mut ratatui::Frame,
+    area: Rect,
+    title: &str,
+    piece_type: PieceType,
+    color: Color,
+) {
+    let shape: Vec<Line> = piece_type
+        .get_shape()
+        .iter()
+        .map(|row| {
+            let cells: String = row
+                .iter()
+                .map(|&filled| if filled { "██" } else { "  " })
+                .collect();
+            Line::from(cells.fg(piece_type.get_color()))
+        })
+        .collect();
+
+    let mut text = vec![Line::from("")];
+    text.extend(shape);
+    let next = Paragraph::new(text)
+        .alignment(ratatui::layout::Alignment::Center)
+        .block(
+            Block::bordered()
+                .title(title.fg(color).bold())
+                .border_style(Style::new().fg(color)),
+        );
+    frame.render_widget(next, area);
+}
+
+/// Aperçu du menu : une pièce par joueur au-dessus de la pile commune
+const PREVIEW: [&str; 9] = [
+    "..TTT.......L...",
+    "...T......LLL...",
+    "................",
+    "................",
+    "................",
+    "I...........OO..",
+    "I.....ZZ....OO.J",
+    "ISS....ZZ.LL.JJJ",
+    "SS..JJJ.OOL.IIII",
+];
+
+fn draw_preview(frame: &mut ratatui::Frame, area: Rect) {
+    preview::draw_board(frame, area, &PREVIEW, |cell| {
+        let piece = match cell {
+            'I' => PieceType::I,
+            'O' => PieceType::O,
+            'T' => PieceType::T,
+            'S' => PieceType::S,
+            'Z' => PieceType::Z,
+            'J' => PieceType::J,
+            'L' => PieceType::L,
+            _ => return "░░".fg(Color::Rgb(40, 40, 50)),
+        };
+        "██".fg(piece.get_color())
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::isolate_data_dir;
+
+    #[test]
+    fn a_piece_resting_on_the_partner_waits_instead_of_locking() {
+        isolate_data_dir();
+        let mut game = TetrisDoubles::new(AudioManager::silent());
+        game.seats[0].piece = Some(Piece::spawned_at(PieceType::O, 8));
+        game.seats[1].piece = Some(Piece::spawned_at(PieceType::O, 8).moved(0, 2));
+
+        // La pièce du joueur 1 bute sur celle du joueur 2 sans se poser
+        game.hard_drop(0);
+        assert!(game.seats[0].piece.is_some());
+        assert!(game.board.rows().all(|row| row.iter().all(Option::is_none)));
+
+        // Une fois la pièce du joueur 2 posée, elle peut descendre à son tour
+        game.hard_drop(1);
+        game.hard_drop(0);
+        assert_eq!(
+            game.board.rows().flatten().filter(|c| c.is_some()).count(),
+            8
+        );
+    }
+}
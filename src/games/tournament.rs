@@ -0,0 +1,695 @@
+//! Tournoi local : de 2 à 8 joueurs inscrits par leur nom s'affrontent à
+//! Pong à deux, dans un tableau à élimination directe.
+//!
+//! L'ordre d'inscription fait le classement des têtes de série, placées comme
+//! dans un tableau de tennis : les deux premières ne peuvent se rencontrer
+//! qu'en finale, et ce sont elles qui passent le premier tour quand le nombre
+//! de joueurs n'est pas une puissance de deux. Chaque match est un vrai match
+//! de Pong à deux, qui ne compte pas dans les classements.
+
+use super::pong::PongGame;
+use crate::audio::AudioManager;
+use crate::config::PLAYER_NAME_MAX_LEN;
+use crate::core::{Game, GameAction, GameStatus};
+use crate::event::{KeyCode, KeyEvent};
+use crate::ui::{glyphs, style, TextInput};
+use ratatui::{
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Clear, Paragraph},
+    Frame,
+};
+use std::time::Duration;
+
+pub const MIN_PLAYERS: usize = 2;
+pub const MAX_PLAYERS: usize = 8;
+
+/// Match du tableau. Une place vide est une exemption au premier tour, un
+/// vainqueur pas encore connu ensuite
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Match {
+    /// Têtes de série (index dans `Bracket::players`)
+    players: [Option<usize>; 2],
+    /// Score final, absent pour une exemption
+    score: Option<(u32, u32)>,
+    winner: Option<usize>,
+}
+
+/// Tableau à élimination directe
+#[derive(Debug, Clone)]
+pub struct Bracket {
+    /// Joueurs, du premier au dernier tête de série
+    players: Vec<String>,
+    /// Matchs de chaque tour, du premier tour à la finale
+    rounds: Vec<Vec<Match>>,
+}
+
+/// Ordre des têtes de série sur les lignes d'un tableau de `size` places,
+/// une puissance de deux : 1, 8, 4, 5, 2, 7, 3, 6 pour huit places
+fn seed_order(size: usize) -> Vec<usize> {
+    let mut order = vec![0];
+    while order.len() < size {
+        let places = order.len() * 2;
+        order = order
+            .iter()
+            .flat_map(|&seed| [seed, places - 1 - seed])
+            .collect();
+    }
+    order
+}
+
+impl Bracket {
+    pub fn new(players: Vec<String>) -> Self {
+        let size = players.len().max(MIN_PLAYERS).next_power_of_two();
+        let first_round: Vec<Match> = seed_order(size)
+            .chunks(2)
+            .map(|pair| Match {
+                players: [pair[0], pair[1]].map(|seed| (seed < players.len()).then_some(seed)),
+                ..Match::default()
+            })
+            .collect();
+
+        let mut rounds = vec![first_round];
+        while let Some(matches) = rounds.last().map(Vec::len).filter(|&len| len > 1) {
+            rounds.push(vec![Match::default(); matches / 2]);
+        }
+
+        let mut bracket = Self { players, rounds };
+        // Exemptions : la tête de série passe directement au tour suivant
+        for index in 0..bracket.rounds[0].len() {
+            if let [Some(player), None] | [None, Some(player)] = bracket.rounds[0][index].players {
+                bracket.advance(0, index, player);
+            }
+        }
+        bracket
+    }
+
+    fn advance(&mut self, round: usize, index: usize, winner: usize) {
+        self.rounds[round][index].winner = Some(winner);
+        if let Some(next) = self.rounds.get_mut(round + 1) {
+            next[index / 2].players[index % 2] = Some(winner);
+        }
+    }
+
+    /// Enregistre le score d'un match joué ; en cas d'égalité, la meilleure
+    /// tête de série passe
+    pub fn record(&mut self, round: usize, index: usize, score: (u32, u32)) {
+        let Some(played) = self.rounds.get_mut(round).and_then(|m| m.get_mut(index)) else {
+            return;
+        };
+        let [Some(first), Some(second)] = played.players else {
+            return;
+        };
+        played.score = Some(score);
+        let winner = if score.1 > score.0 { second } else { first };
+        self.advance(round, index, winner);
+    }
+
+    /// Prochain match à jouer (tour, index), tour par tour
+    pub fn next_match(&self) -> Option<(usize, usize)> {
+        self.rounds.iter().enumerate().find_map(|(round, matches)| {
+            matches
+                .iter()
+                .position(|m| m.winner.is_none() && m.players.iter().all(Option::is_some))
+                .map(|index| (round, index))
+        })
+    }
+
+    /// Noms des deux joueurs d'un match
+    pub fn opponents(&self, round: usize, index: usize) -> Option<(&str, &str)> {
+        match self.rounds.get(round)?.get(index)?.players {
+            [Some(first), Some(second)] => Some((&self.players[first], &self.players[second])),
+            _ => None,
+        }
+    }
+
+    pub fn champion(&self) -> Option<&str> {
+        let winner = self.rounds.last()?.first()?.winner?;
+        Some(&self.players[winner])
+    }
+
+    /// Nom d'un tour, compté depuis la finale
+    pub fn round_name(&self, round: usize) -> &'static str {
+        match self.rounds.len() - round {
+            1 => "Final",
+            2 => "Semifinals",
+            3 => "Quarterfinals",
+            _ => "First Round",
+        }
+    }
+}
+
+enum Phase {
+    /// Inscription des joueurs
+    Entry,
+    /// Tableau affiché entre deux matchs
+    Bracket,
+    Playing {
+        game: Box<PongGame>,
+        round: usize,
+        index: usize,
+    },
+    /// Le tableau est joué jusqu'au bout
+    Champion,
+}
+
+pub struct TournamentGame {
+    phase: Phase,
+    /// Joueurs inscrits, gardés pour un nouveau tournoi
+    names: Vec<String>,
+    name_input: TextInput,
+    /// Raison du refus de la dernière saisie
+    entry_error: Option<&'static str>,
+    bracket: Bracket,
+    /// Sortie audio transmise à chaque match
+    audio: AudioManager,
+}
+
+impl TournamentGame {
+    pub fn new(audio: AudioManager) -> Self {
+        Self {
+            phase: Phase::Entry,
+            names: Vec::new(),
+            name_input: TextInput::new(PLAYER_NAME_MAX_LEN),
+            entry_error: None,
+            bracket: Bracket::new(Vec::new()),
+            audio,
+        }
+    }
+
+    /// Entrée dans la saisie : ajoute le nom tapé, ou lance le tournoi si le
+    /// champ est vide
+    fn submit_name(&mut self) {
+        let name = self.name_input.value().trim().to_string();
+        self.entry_error = if name.is_empty() {
+            if self.names.len() >= MIN_PLAYERS {
+                self.start();
+                None
+            } else {
+                Some("Enter at least 2 players")
+            }
+        } else if self.names.len() >= MAX_PLAYERS {
+            Some("The bracket is full (8 players)")
+        } else if self.names.iter().any(|n| n.eq_ignore_ascii_case(&name)) {
+            Some("This player is already entered")
+        } else {
+            self.names.push(name);
+            self.name_input.clear();
+            None
+        };
+    }
+
+    fn start(&mut self) {
+        self.bracket = Bracket::new(self.names.clone());
+        self.phase = Phase::Bracket;
+    }
+
+    fn start_match(&mut self) {
+        if let Some((round, index)) = self.bracket.next_match() {
+            self.phase = Phase::Playing {
+                game: Box::new(PongGame::friendly_match(self.audio.sibling())),
+                round,
+                index,
+            };
+        }
+    }
+
+    fn finish_match(&mut self) {
+        let Phase::Playing { game, round, index } = &self.phase else {
+            return;
+        };
+        self.bracket.record(*round, *index, game.match_score());
+        self.phase = if self.bracket.champion().is_some() {
+            Phase::Champion
+        } else {
+            Phase::Bracket
+        };
+    }
+}
+
+impl Game for TournamentGame {
+    fn handle_key(&mut self, key: KeyEvent) -> GameAction {
+        match &mut self.phase {
+            Phase::Entry => {
+                match key.code {
+                    KeyCode::Enter => self.submit_name(),
+                    // Champ vide : retire le dernier inscrit
+                    KeyCode::Backspace if self.name_input.is_empty() => {
+                        self.names.pop();
+                        self.entry_error = None;
+                    }
+                    _ => {
+                        if self.name_input.handle_key(key) {
+                            self.entry_error = None;
+                        }
+                    }
+                }
+                GameAction::Continue
+            }
+            // Quitter un match le laisse à rejouer
+            Phase::Playing { game, .. } => {
+                if game.handle_key(key) != GameAction::Continue {
+                    self.phase = Phase::Bracket;
+                }
+                GameAction::Continue
+            }
+            Phase::Bracket => match key.code {
+                KeyCode::Enter | KeyCode::Char(' ') => {
+                    self.start_match();
+                    GameAction::Continue
+                }
+                KeyCode::Char('q') => GameAction::Quit,
+                _ => GameAction::Continue,
+            },
+            Phase::Champion => match key.code {
+                KeyCode::Enter | KeyCode::Char('r') => {
+                    self.start();
+                    GameAction::Continue
+                }
+                KeyCode::Char('n') => {
+                    self.phase = Phase::Entry;
+                    GameAction::Continue
+                }
+                KeyCode::Char('q') => GameAction::Quit,
+                _ => GameAction::Continue,
+            },
+        }
+    }
+
+    fn update(&mut self) -> GameAction {
+        if let Phase::Playing { game, .. } = &mut self.phase {
+            game.update();
+            if game.status() == GameStatus::GameOver {
+                self.finish_match();
+            }
+        }
+        GameAction::Continue
+    }
+
+    fn draw(&mut self, frame: &mut Frame, area: Rect) {
+        match &mut self.phase {
+            Phase::Entry => draw_entry(frame, area, self),
+            Phase::Playing { game, round, index } => {
+                game.draw(frame, area);
+                draw_match_panel(frame, area, &self.bracket, *round, *index);
+            }
+            Phase::Bracket | Phase::Champion => draw_bracket(frame, area, self),
+        }
+    }
+
+    fn tick_rate(&self) -> Duration {
+        match &self.phase {
+            Phase::Playing { game, .. } => game.tick_rate(),
+            _ => Duration::from_millis(100),
+        }
+    }
+
+    fn status(&self) -> GameStatus {
+        match &self.phase {
+            Phase::Playing { game, .. } => game.status(),
+            Phase::Entry | Phase::Bracket => GameStatus::Menu,
+            Phase::Champion => GameStatus::Victory,
+        }
+    }
+
+    fn title_status(&self) -> Option<String> {
+        match &self.phase {
+            Phase::Playing { game, round, .. } => {
+                let (left, right) = game.match_score();
+                Some(format!(
+                    "{} • {left} - {right}",
+                    self.bracket.round_name(*round)
+                ))
+            }
+            Phase::Champion => self
+                .bracket
+                .champion()
+                .map(|champion| format!("{champion} wins")),
+            _ => None,
+        }
+    }
+
+    fn session_mode(&self) -> Option<String> {
+        (!matches!(self.phase, Phase::Entry))
+            .then(|| format!("{} Players", self.bracket.players.len()))
+    }
+
+    fn on_shutdown(&mut self) {
+        if let Phase::Playing { game, .. } = &mut self.phase {
+            game.on_shutdown();
+        }
+    }
+
+    fn on_pause(&mut self) {
+        if let Phase::Playing { game, .. } = &mut self.phase {
+            game.on_pause();
+        }
+    }
+
+    fn on_resume(&mut self) {
+        if let Phase::Playing { game, .. } = &mut self.phase {
+            game.on_resume();
+        }
+    }
+
+    fn captures_volume_keys(&self) -> bool {
+        match &self.phase {
+            // "+" et "-" s'écrivent dans les noms
+            Phase::Entry => true,
+            Phase::Playing { game, .. } => game.captures_volume_keys(),
+            _ => false,
+        }
+    }
+
+    fn audio(&self) -> Option<&AudioManager> {
+        match &self.phase {
+            Phase::Playing { game, .. } => game.audio(),
+            _ => None,
+        }
+    }
+
+    fn handle_paste(&mut self, text: &str) {
+        if matches!(self.phase, Phase::Entry) {
+            self.name_input.insert_str(text);
+        }
+    }
+}
+
+/// Bandeau "TOURNAMENT" et sa ligne de statut
+fn draw_header(frame: &mut Frame, area: Rect, status: Line) {
+    let header = Paragraph::new(vec![
+        Line::from(vec![
+            format!("{} ", glyphs::icon("🏆")).yellow().bold(),
+            "TOURNAMENT".cyan().bold(),
+            format!(" {}", glyphs::icon("🏆")).yellow().bold(),
+        ]),
+        status,
+    ])
+    .alignment(Alignment::Center)
+    .block(
+        Block::bordered()
+            .border_style(Style::new().yellow())
+            .style(Style::default().bg(style::surface())),
+    );
+    frame.render_widget(header, area);
+}
+
+fn draw_footer(frame: &mut Frame, area: Rect, instructions: Line) {
+    let footer = Paragraph::new(instructions)
+        .alignment(Alignment::Center)
+        .block(
+            Block::bordered()
+                .title(" Controls ".fg(style::text()).bold())
+                .border_style(Style::new().blue())
+                .style(Style::default().bg(style::surface())),
+        );
+    frame.render_widget(footer, area);
+}
+
+/// Inscription : joueurs déjà inscrits, dans l'ordre des têtes de série, et
+/// champ du nom suivant
+fn draw_entry(frame: &mut Frame, area: Rect, tournament: &TournamentGame) {
+    style::fill_area(frame, area, style::background());
+
+    let [header, body, footer] = Layout::vertical([
+        Constraint::Length(4),
+        Constraint::Min(0),
+        Constraint::Length(3),
+    ])
+    .areas(area);
+
+    draw_header(
+        frame,
+        header,
+        Line::from(format!(
+            "{}/{MAX_PLAYERS} players • entry order sets the seeds",
+            tournament.names.len()
+        ))
+        .magenta(),
+    );
+
+    let block = Block::bordered()
+        .title(" Players ".green().bold())
+        .border_style(Style::new().green())
+        .style(Style::default().bg(style::panel()));
+    let inner = block.inner(body);
+    frame.render_widget(block, body);
+
+    let [list_area, field_area, message_area] = Layout::vertical([
+        Constraint::Min(0),
+        Constraint::Length(3),
+        Constraint::Length(1),
+    ])
+    .areas(inner);
+
+    let mut lines = vec![Line::from("")];
+    lines.extend(tournament.names.iter().enumerate().map(|(seed, name)| {
+        Line::from(vec![
+            Span::styled(
+                format!("  {:>2}. ", seed + 1),
+                Style::default().fg(Color::Gray),
+            ),
+            Span::styled(name.clone(), Style::default().fg(style::text()).bold()),
+        ])
+    }));
+    frame.render_widget(Paragraph::new(lines), list_area);
+
+    tournament.name_input.render(
+        frame,
+        field_area,
+        Block::bordered().title(format!(" Player {} ", tournament.names.len() + 1).white()),
+        Style::default().fg(Color::White),
+        true,
+    );
+
+    let message = match tournament.entry_error {
+        Some(error) => Line::from(error.red().bold()),
+        None if tournament.names.len() >= MIN_PLAYERS => {
+            Line::from("Leave the name empty and press Enter to draw the bracket".gray())
+        }
+        None => Line::from("Type a name and press Enter".gray()),
+    };
+    frame.render_widget(
+        Paragraph::new(message).alignment(Alignment::Center),
+        message_area,
+    );
+
+    draw_footer(
+        frame,
+        footer,
+        Line::from(vec![
+            "Enter".green().bold(),
+            " Add Player / Start  ".fg(style::text()),
+            "Backspace".yellow().bold(),
+            " Remove Last  ".fg(style::text()),
+            "Esc".red().bold(),
+            " Menu".fg(style::text()),
+        ]),
+    );
+}
+
+/// Tableau : une colonne par tour, puis le vainqueur
+fn draw_bracket(frame: &mut Frame, area: Rect, tournament: &TournamentGame) {
+    style::fill_area(frame, area, style::background());
+    let bracket = &tournament.bracket;
+
+    let [header, body, footer] = Layout::vertical([
+        Constraint::Length(4),
+        Constraint::Min(0),
+        Constraint::Length(3),
+    ])
+    .areas(area);
+
+    let next = bracket.next_match();
+    let status = match (bracket.champion(), next) {
+        (Some(champion), _) => Line::from(vec![
+            "Champion: ".gray(),
+            champion.to_string().yellow().bold(),
+        ]),
+        (None, Some((round, index))) => {
+            let (first, second) = bracket.opponents(round, index).unwrap_or_default();
+            Line::from(vec![
+                format!("{}: ", bracket.round_name(round)).gray(),
+                first.to_string().fg(style::text()).bold(),
+                " vs ".gray(),
+                second.to_string().fg(style::text()).bold(),
+            ])
+        }
+        (None, None) => Line::from(""),
+    };
+    draw_header(frame, header, status);
+
+    let block = Block::bordered()
+        .title(" Bracket ".green().bold())
+        .border_style(Style::new().green())
+        .style(Style::default().bg(style::panel()));
+    let inner = block.inner(body);
+    frame.render_widget(block, body);
+
+    let columns = Layout::horizontal(
+        (0..=bracket.rounds.len()).map(|_| Constraint::Ratio(1, bracket.rounds.len() as u32 + 1)),
+    )
+    .split(inner);
+
+    for (round, matches) in bracket.rounds.iter().enumerate() {
+        let column = columns[round];
+        frame.render_widget(
+            Paragraph::new(bracket.round_name(round).cyan().bold()).alignment(Alignment::Center),
+            column,
+        );
+        let slot_height = column.height.saturating_sub(1) / matches.len() as u16;
+        for (index, played) in matches.iter().enumerate() {
+            let y = column.y + 1 + slot_height * index as u16 + slot_height.saturating_sub(2) / 2;
+            if y + 2 > column.bottom() {
+                break;
+            }
+            let upcoming = next == Some((round, index));
+            let lines: Vec<Line> = (0..2)
+                .map(|side| bracket_line(bracket, played, side, round == 0, upcoming))
+                .collect();
+            frame.render_widget(
+                Paragraph::new(lines),
+                Rect::new(column.x, y, column.width, 2),
+            );
+        }
+    }
+
+    // Colonne du vainqueur
+    let column = columns[bracket.rounds.len()];
+    frame.render_widget(
+        Paragraph::new("Champion".yellow().bold()).alignment(Alignment::Center),
+        column,
+    );
+    if let Some(champion) = bracket.champion() {
+        let y = column.y + column.height.saturating_sub(1) / 2;
+        frame.render_widget(
+            Paragraph::new(Line::from(vec![
+                format!("{} ", glyphs::icon("🏆")).yellow(),
+                champion.to_string().yellow().bold(),
+            ]))
+            .alignment(Alignment::Center),
+            Rect::new(column.x, y, column.width, 1),
+        );
+    }
+
+    let instructions = if bracket.champion().is_some() {
+        Line::from(vec![
+            "Enter".green().bold(),
+            " Rematch  ".fg(style::text()),
+            "N".yellow().bold(),
+            " New Players  ".fg(style::text()),
+            "Q".red().bold(),
+            " Quit".fg(style::text()),
+        ])
+    } else {
+        Line::from(vec![
+            "Enter".green().bold(),
+            " Play Next Match  ".fg(style::text()),
+            "Q".red().bold(),
+            " Quit".fg(style::text()),
+        ])
+    };
+    draw_footer(frame, footer, instructions);
+}
+
+/// Ligne d'un joueur dans le tableau : tête de série, nom et points
+fn bracket_line(
+    bracket: &Bracket,
+    played: &Match,
+    side: usize,
+    first_round: bool,
+    upcoming: bool,
+) -> Line<'static> {
+    let Some(player) = played.players[side] else {
+        let empty = if first_round { "bye" } else { "·" };
+        return Line::from(format!("     {empty}").dark_gray());
+    };
+    let points = played
+        .score
+        .map(|(first, second)| if side == 0 { first } else { second })
+        .map(|points| format!(" {points}"))
+        .unwrap_or_default();
+    let style = match played.winner {
+        Some(winner) if winner == player => Style::default().fg(Color::Green).bold(),
+        Some(_) => Style::default().fg(Color::DarkGray),
+        None if upcoming => Style::default().fg(Color::Yellow).bold(),
+        None => Style::default().fg(style::text()),
+    };
+    Line::from(vec![
+        Span::styled(
+            format!(" {:>2} ", player + 1),
+            Style::default().fg(Color::Gray),
+        ),
+        Span::styled(bracket.players[player].clone(), style),
+        Span::styled(points, style),
+    ])
+}
+
+/// Petit panneau en haut à gauche pendant un match : tour, joueurs et leurs
+/// touches
+fn draw_match_panel(frame: &mut Frame, area: Rect, bracket: &Bracket, round: usize, index: usize) {
+    let Some((first, second)) = bracket.opponents(round, index) else {
+        return;
+    };
+    let lines = vec![
+        Line::from(vec![first.to_string().blue().bold(), " W/S".gray()]),
+        Line::from(vec![second.to_string().red().bold(), " ↑/↓".gray()]),
+    ];
+
+    let width = 26.min(area.width);
+    let height = 4.min(area.height);
+    let panel = Rect {
+        x: area.x,
+        y: area.y,
+        width,
+        height,
+    };
+    frame.render_widget(Clear, panel);
+    frame.render_widget(
+        Paragraph::new(lines).alignment(Alignment::Center).block(
+            Block::bordered()
+                .title(
+                    format!(" {} {} ", glyphs::icon("🏆"), bracket.round_name(round))
+                        .yellow()
+                        .bold(),
+                )
+                .border_style(Style::new().yellow())
+                .style(Style::default().bg(style::surface())),
+        ),
+        panel,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn players(count: usize) -> Vec<String> {
+        (1..=count).map(|seed| format!("P{seed}")).collect()
+    }
+
+    #[test]
+    fn top_seeds_meet_last_and_get_the_byes() {
+        assert_eq!(seed_order(8), [0, 7, 3, 4, 1, 6, 2, 5]);
+
+        // Cinq joueurs : les trois premières têtes de série sont exemptées
+        let mut bracket = Bracket::new(players(5));
+        assert_eq!(bracket.rounds.len(), 3);
+        assert_eq!(bracket.round_name(0), "Quarterfinals");
+        assert_eq!(bracket.rounds[1][0].players, [Some(0), None]);
+        assert_eq!(bracket.rounds[1][1].players, [Some(1), Some(2)]);
+        assert_eq!(bracket.next_match(), Some((0, 1)));
+        assert_eq!(bracket.opponents(0, 1), Some(("P4", "P5")));
+
+        bracket.record(0, 1, (2, 5));
+        assert_eq!(bracket.rounds[1][0].players, [Some(0), Some(4)]);
+        bracket.record(1, 0, (5, 3));
+        bracket.record(1, 1, (4, 5));
+        assert_eq!(bracket.opponents(2, 0), Some(("P1", "P3")));
+        assert_eq!(bracket.champion(), None);
+        bracket.record(2, 0, (1, 5));
+        assert_eq!(bracket.champion(), Some("P3"));
+        assert_eq!(bracket.next_match(), None);
+    }
+}
@@ -0,0 +1,162 @@
+//! Fantômes d'entraînement.
+//!
+//! La meilleure partie de Snake et de Tetris Dig est enregistrée pendant le
+//! jeu : chaque touche avec le numéro du tick où elle a été jouée, et chaque
+//! changement de progression (score, lignes de déchets) avec le temps de jeu
+//! du [`GameClock`](crate::core::clock::GameClock). Les parties suivantes
+//! affichent où en était ce record au même moment.
+
+use crate::event::KeyEvent;
+use crate::storage;
+use crate::writer;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Fantômes enregistrés, par identifiant de jeu
+pub const GHOSTS_FILE: &str = "ghosts.json";
+
+/// Touche jouée pendant la partie
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GhostInput {
+    /// Nombre de ticks joués avant la touche
+    pub tick: u64,
+    /// Code de la touche, au format `Debug` de crossterm (`Left`, `Char(' ')`)
+    pub key: String,
+}
+
+/// Progression à un instant de la partie
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GhostSample {
+    /// Temps de jeu, sans les pauses
+    pub elapsed_ms: u64,
+    pub value: u32,
+}
+
+/// Déroulé complet de la meilleure partie
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Ghost {
+    /// Résultat final : score, ou pièces utilisées en Tetris Dig
+    pub score: u32,
+    /// Durée de la partie, sans les pauses
+    pub elapsed_ms: u64,
+    /// Nombre de ticks joués
+    pub ticks: u64,
+    pub inputs: Vec<GhostInput>,
+    /// Progression, une entrée par changement de valeur
+    pub progress: Vec<GhostSample>,
+}
+
+impl Ghost {
+    /// Progression du fantôme après `elapsed` de jeu, `None` si la partie
+    /// enregistrée n'avait encore rien noté
+    pub fn value_at(&self, elapsed: Duration) -> Option<u32> {
+        let elapsed_ms = elapsed.as_millis() as u64;
+        let seen = self
+            .progress
+            .partition_point(|sample| sample.elapsed_ms <= elapsed_ms);
+        seen.checked_sub(1).map(|index| self.progress[index].value)
+    }
+}
+
+/// Enregistrement de la partie en cours
+#[derive(Debug, Default)]
+pub struct GhostRecorder {
+    ticks: u64,
+    inputs: Vec<GhostInput>,
+    progress: Vec<GhostSample>,
+}
+
+impl GhostRecorder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record_key(&mut self, key: KeyEvent) {
+        self.inputs.push(GhostInput {
+            tick: self.ticks,
+            key: format!("{:?}", key.code),
+        });
+    }
+
+    /// À appeler après chaque tick de jeu
+    pub fn tick(&mut self) {
+        self.ticks += 1;
+    }
+
+    /// Note la progression, si elle a changé depuis la dernière fois
+    pub fn record_progress(&mut self, elapsed: Duration, value: u32) {
+        if self.progress.last().is_none_or(|last| last.value != value) {
+            self.progress.push(GhostSample {
+                elapsed_ms: elapsed.as_millis() as u64,
+                value,
+            });
+        }
+    }
+
+    /// Termine l'enregistrement
+    pub fn finish(&mut self, score: u32, elapsed: Duration) -> Ghost {
+        let recorder = std::mem::take(self);
+        Ghost {
+            score,
+            elapsed_ms: elapsed.as_millis() as u64,
+            ticks: recorder.ticks,
+            inputs: recorder.inputs,
+            progress: recorder.progress,
+        }
+    }
+}
+
+fn ghosts_path() -> Option<PathBuf> {
+    storage::data_dir().map(|dir| dir.join(GHOSTS_FILE))
+}
+
+fn load_all() -> BTreeMap<String, Ghost> {
+    ghosts_path()
+        .and_then(|path| writer::read_to_string(&path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Fantôme de la meilleure partie de `game`, s'il y en a une
+pub fn load(game: &str) -> Option<Ghost> {
+    load_all().remove(game)
+}
+
+/// Remplace le fantôme de `game`
+pub fn save(game: &str, ghost: Ghost) -> Result<(), Box<dyn std::error::Error>> {
+    let path = ghosts_path().ok_or("no config directory")?;
+    let mut ghosts = load_all();
+    ghosts.insert(game.to_string(), ghost);
+    writer::write(path, serde_json::to_string(&ghosts)?);
+    Ok(())
+}
+
+/// Efface tous les fantômes (utilisé par "Reset Everything")
+pub fn clear() {
+    if let Some(path) = ghosts_path() {
+        writer::delete(path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn value_at_follows_the_recorded_progress() {
+        let mut recorder = GhostRecorder::new();
+        recorder.record_progress(Duration::from_millis(100), 10);
+        recorder.record_progress(Duration::from_millis(200), 10);
+        recorder.record_progress(Duration::from_millis(300), 20);
+        let ghost = recorder.finish(20, Duration::from_millis(400));
+
+        // Une valeur inchangée n'ajoute pas d'entrée
+        assert_eq!(ghost.progress.len(), 2);
+        assert_eq!(ghost.value_at(Duration::from_millis(50)), None);
+        assert_eq!(ghost.value_at(Duration::from_millis(100)), Some(10));
+        assert_eq!(ghost.value_at(Duration::from_millis(299)), Some(10));
+        assert_eq!(ghost.value_at(Duration::from_secs(60)), Some(20));
+    }
+}
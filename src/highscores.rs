@@ -1,8 +1,27 @@
+use crate::challenge;
+use crate::config::{ConfigManager, DEFAULT_PLAYER_NAME};
+use crate::games::{gauntlet, hotseat};
+use crate::mutators::{self, Mutator};
+use crate::storage;
+use crate::time_format;
+use crate::writer;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
 use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Anciens identifiants de jeux du fichier de scores, avec l'identifiant
+/// actuel sous lequel leurs scores sont rangés au chargement
+const RENAMED_GAMES: [(&str, &str); 1] = [("gameoflife", "game-of-life")];
+
+thread_local! {
+    /// Scores ajoutés depuis le dernier passage du menu, voir
+    /// [`take_new_scores`] ; chaque session a les siens
+    static NEW_SCORES: RefCell<Vec<NewScore>> = const { RefCell::new(Vec::new()) };
+}
 
 /// Représente un score individuel
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -11,6 +30,42 @@ pub struct Score {
     pub score: u32,
     pub timestamp: DateTime<Utc>,
     pub game_data: GameData,
+    /// Aides utilisées pendant la partie (absent des anciens fichiers)
+    #[serde(default)]
+    pub assists: Assists,
+    /// Modificateurs de la partie
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mutators: Vec<Mutator>,
+}
+
+/// Aides utilisées pendant une partie, affichées en badges dans le classement
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Assists {
+    pub hints: bool,
+    pub undo: bool,
+    pub slow_motion: bool,
+    pub ai: bool,
+}
+
+impl Assists {
+    /// Indique si au moins une aide a été utilisée
+    pub fn any(&self) -> bool {
+        self.hints || self.undo || self.slow_motion || self.ai
+    }
+
+    /// Badges des aides utilisées
+    pub fn badges(&self) -> Vec<&'static str> {
+        [
+            (self.hints, "💡 hints"),
+            (self.undo, "↶ undo"),
+            (self.slow_motion, "🐢 practice"),
+            (self.ai, "🤖 AI"),
+        ]
+        .into_iter()
+        .filter_map(|(used, badge)| used.then_some(badge))
+        .collect()
+    }
 }
 
 /// Données spécifiques à chaque jeu
@@ -19,6 +74,9 @@ pub enum GameData {
     Snake {
         length: usize,
         duration_seconds: u64,
+        /// Partie jouée en mode sprint
+        #[serde(default)]
+        sprint: bool,
     },
     Tetris {
         level: u32,
@@ -33,6 +91,9 @@ pub enum GameData {
         highest_tile: u32,
         moves: u32,
         duration_seconds: u64,
+        /// Partie poursuivie en mode sans fin après 2048
+        #[serde(default)]
+        endless: bool,
     },
     Minesweeper {
         grid_size: (u32, u32),
@@ -48,6 +109,45 @@ pub enum GameData {
         generations: u32,
         duration_seconds: u64,
     },
+    Rhythm {
+        bpm: u32,
+        accuracy_percent: u32,
+        max_combo: u32,
+        duration_seconds: u64,
+    },
+    Gauntlet {
+        /// Points de chaque étape, dans l'ordre
+        stage_points: Vec<u32>,
+        duration_seconds: u64,
+    },
+}
+
+/// Score qui vient d'entrer dans un classement : de quoi le retrouver parmi
+/// les scores du jeu pour le signaler dans le menu
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewScore {
+    pub game: String,
+    pub player_name: String,
+    pub score: u32,
+    pub timestamp: DateTime<Utc>,
+    /// Place dans le classement, à partir de 1
+    pub rank: usize,
+    /// Meilleur score du joueur pour ce jeu jusqu'ici
+    pub personal_best: bool,
+}
+
+impl NewScore {
+    /// Indique si `score` est l'entrée ajoutée
+    pub fn matches(&self, score: &Score) -> bool {
+        score.timestamp == self.timestamp && score.player_name == self.player_name
+    }
+}
+
+/// Scores entrés dans un classement depuis le dernier appel, du plus ancien
+/// au plus récent. Les jeux enregistrent leurs scores eux-mêmes ; le menu
+/// récupère ainsi ceux de la partie qui vient de se terminer
+pub fn take_new_scores() -> Vec<NewScore> {
+    NEW_SCORES.take()
 }
 
 /// Gère les high scores pour tous les jeux
@@ -56,6 +156,25 @@ pub struct HighScores {
     pub games: HashMap<String, Vec<Score>>,
 }
 
+impl HighScores {
+    /// Range sous leur identifiant actuel les scores enregistrés sous un
+    /// ancien nom ; renvoie `true` si des scores ont été déplacés
+    fn migrate_renamed_games(&mut self) -> bool {
+        let mut migrated = false;
+        for (old_id, new_id) in RENAMED_GAMES {
+            let Some(old_scores) = self.games.remove(old_id) else {
+                continue;
+            };
+            let game_scores = self.games.entry(new_id.to_string()).or_default();
+            game_scores.extend(old_scores);
+            game_scores.sort_by_key(|s| std::cmp::Reverse(s.score));
+            game_scores.truncate(10);
+            migrated = true;
+        }
+        migrated
+    }
+}
+
 /// Manager principal pour les high scores
 pub struct HighScoreManager {
     scores: HighScores,
@@ -66,21 +185,14 @@ pub struct HighScoreManager {
 impl HighScoreManager {
     /// Crée un nouveau manager de high scores
     pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
-        let config_dir = dirs::config_dir()
-            .ok_or("Unable to find config directory")?
-            .join("termplay");
+        let config_dir = storage::data_dir().ok_or("Unable to find config directory")?;
 
         // Créer le répertoire de configuration s'il n'existe pas
         fs::create_dir_all(&config_dir)?;
 
         let scores_file = config_dir.join("highscores.json");
 
-        let scores = if scores_file.exists() {
-            let content = fs::read_to_string(&scores_file)?;
-            serde_json::from_str(&content).unwrap_or_default()
-        } else {
-            HighScores::default()
-        };
+        let scores = load_scores(&scores_file)?;
 
         Ok(Self {
             scores,
@@ -89,30 +201,57 @@ impl HighScoreManager {
         })
     }
 
-    /// Ajoute un nouveau score pour un jeu
+    /// Ajoute un nouveau score pour un jeu. Renvoie l'entrée ajoutée, `None`
+    /// si elle n'entre pas dans le top 10 ; une entrée ajoutée est aussi
+    /// signalée au menu (voir [`take_new_scores`])
     pub fn add_score(
         &mut self,
         game_name: &str,
         score: Score,
-    ) -> Result<bool, Box<dyn std::error::Error>> {
+    ) -> Result<Option<NewScore>, Box<dyn std::error::Error>> {
+        // Une étape du Gauntlet ne compte que pour le score du Gauntlet, un
+        // essai du hot seat que pour sa manche
+        if gauntlet::stage_running() || hotseat::run_in_progress() {
+            return Ok(None);
+        }
+        // Pendant le défi de la semaine, le score va dans son classement
+        let game_name = &*challenge::leaderboard_id(game_name);
         let game_scores = self.scores.games.entry(game_name.to_string()).or_default();
 
+        let personal_best = game_scores
+            .iter()
+            .filter(|s| s.player_name == score.player_name)
+            .all(|s| score.score > s.score);
+        let mut new_score = NewScore {
+            game: game_name.to_string(),
+            player_name: score.player_name.clone(),
+            score: score.score,
+            timestamp: score.timestamp,
+            rank: 0,
+            personal_best,
+        };
+
         // Ajouter le score
         game_scores.push(score);
 
         // Trier par score décroissant
-        game_scores.sort_by(|a, b| b.score.cmp(&a.score));
+        game_scores.sort_by_key(|s| std::cmp::Reverse(s.score));
 
         // Garder seulement les 10 meilleurs
-        let is_top_10 = game_scores.len() <= 10;
         if game_scores.len() > 10 {
             game_scores.truncate(10);
         }
+        let index = game_scores.iter().position(|s| new_score.matches(s));
 
         // Sauvegarder
         self.save()?;
 
-        Ok(is_top_10)
+        let Some(index) = index else {
+            return Ok(None);
+        };
+        new_score.rank = index + 1;
+        NEW_SCORES.with_borrow_mut(|scores| scores.push(new_score.clone()));
+        Ok(Some(new_score))
     }
 
     /// Récupère les high scores pour un jeu
@@ -124,13 +263,22 @@ impl HighScoreManager {
             .unwrap_or_default()
     }
 
-    /// Récupère le meilleur score pour un jeu
-    pub fn get_best_score(&self, game_name: &str) -> Option<&Score> {
-        self.scores.games.get(game_name)?.first()
+    /// Récupère le meilleur score pour un jeu, en ignorant éventuellement les
+    /// parties jouées avec des aides
+    pub fn get_best_score(&self, game_name: &str, exclude_assisted: bool) -> Option<&Score> {
+        self.scores
+            .games
+            .get(game_name)?
+            .iter()
+            .find(|score| !(exclude_assisted && score.assists.any()))
     }
 
     /// Vérifie si un score fait partie du top 10
     pub fn is_high_score(&self, game_name: &str, score: u32) -> bool {
+        if gauntlet::stage_running() || hotseat::run_in_progress() {
+            return false;
+        }
+        let game_name = &*challenge::leaderboard_id(game_name);
         let game_scores = match self.scores.games.get(game_name) {
             Some(scores) => scores,
             None => return true, // Premier score = high score
@@ -159,26 +307,32 @@ impl HighScoreManager {
 
     /// Récupère la liste de tous les jeux avec des scores (triée par ordre alphabétique)
     pub fn get_games_with_scores(&self) -> Vec<String> {
-        let mut games: Vec<String> = self.scores.games.keys().cloned().collect();
+        // Les classements du défi de la semaine sont affichés dans son écran
+        let mut games: Vec<String> = self
+            .scores
+            .games
+            .keys()
+            .filter(|key| !challenge::is_leaderboard(key))
+            .cloned()
+            .collect();
         games.sort();
         games
     }
 
-    /// Sauvegarde les scores sur disque
+    /// Sauvegarde les scores sur disque, en arrière-plan. Sans `native`, il
+    /// n'y a pas de dossier de données et rien n'est écrit
     fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
+        if cfg!(not(feature = "native")) {
+            return Ok(());
+        }
         let content = serde_json::to_string_pretty(&self.scores)?;
-        fs::write(&self.scores_file, content)?;
+        writer::write(self.scores_file.clone(), content);
         Ok(())
     }
 
     /// Recharge les scores depuis le disque
     pub fn reload(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        if self.scores_file.exists() {
-            let content = fs::read_to_string(&self.scores_file)?;
-            self.scores = serde_json::from_str(&content).unwrap_or_default();
-        } else {
-            self.scores = HighScores::default();
-        }
+        self.scores = load_scores(&self.scores_file)?;
         Ok(())
     }
 
@@ -205,6 +359,38 @@ impl Default for HighScoreManager {
     }
 }
 
+/// Lit le contenu de `highscores.json`
+pub fn parse_scores(contents: &str) -> Result<HighScores, serde_json::Error> {
+    serde_json::from_str(contents)
+}
+
+/// Scores enregistrés, y compris ceux encore en file d'écriture. Les scores
+/// d'un jeu renommé passent sous son nouvel identifiant, et le fichier est
+/// réécrit une fois
+fn load_scores(scores_file: &Path) -> Result<HighScores, Box<dyn std::error::Error>> {
+    match writer::read_to_string(scores_file) {
+        Ok(content) => {
+            let mut scores = parse_scores(&content).unwrap_or_default();
+            if scores.migrate_renamed_games() {
+                writer::write(
+                    scores_file.to_path_buf(),
+                    serde_json::to_string_pretty(&scores)?,
+                );
+            }
+            Ok(scores)
+        }
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(HighScores::default()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Nom du joueur choisi dans les Settings, à enregistrer avec ses scores
+pub fn current_player_name() -> String {
+    ConfigManager::new()
+        .map(|config| config.get_gameplay_config().player_name.clone())
+        .unwrap_or_else(|_| DEFAULT_PLAYER_NAME.to_string())
+}
+
 /// Helper pour créer des scores facilement
 impl Score {
     pub fn new(player_name: String, score: u32, game_data: GameData) -> Self {
@@ -213,6 +399,8 @@ impl Score {
             score,
             timestamp: Utc::now(),
             game_data,
+            assists: Assists::default(),
+            mutators: mutators::active(),
         }
     }
 
@@ -240,20 +428,81 @@ impl Score {
             GameData::GameOfLife {
                 duration_seconds, ..
             } => *duration_seconds,
+            GameData::Rhythm {
+                duration_seconds, ..
+            } => *duration_seconds,
+            GameData::Gauntlet {
+                duration_seconds, ..
+            } => *duration_seconds,
         };
 
-        let minutes = seconds / 60;
-        let seconds = seconds % 60;
+        time_format::format_duration(seconds)
+    }
 
-        if minutes > 0 {
-            format!("{minutes}m {seconds}s")
-        } else {
-            format!("{seconds}s")
+    /// Détail propre au jeu affiché dans le classement
+    pub fn details(&self) -> Option<String> {
+        match &self.game_data {
+            GameData::Game2048 {
+                highest_tile,
+                endless,
+                ..
+            } => Some(if *endless {
+                format!("tile {highest_tile} ♾")
+            } else {
+                format!("tile {highest_tile}")
+            }),
+            GameData::Snake { sprint: true, .. } => Some("sprint".to_string()),
+            GameData::Rhythm {
+                bpm,
+                accuracy_percent,
+                ..
+            } => Some(format!("{accuracy_percent}% @ {bpm} BPM")),
+            GameData::Gauntlet { stage_points, .. } => Some(
+                stage_points
+                    .iter()
+                    .map(u32::to_string)
+                    .collect::<Vec<_>>()
+                    .join(" + "),
+            ),
+            _ => None,
         }
     }
 
-    /// Formate la date en string lisible
+    /// Formate la date en string lisible (voir [`time_format`])
     pub fn format_date(&self) -> String {
-        self.timestamp.format("%Y-%m-%d %H:%M").to_string()
+        time_format::format_date_time(self.timestamp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scores_of_renamed_games_move_to_the_current_id() {
+        let score = |points| {
+            let game_data = GameData::GameOfLife {
+                generations: 0,
+                duration_seconds: 0,
+            };
+            Score::new("AAA".to_string(), points, game_data)
+        };
+        let mut scores = HighScores::default();
+        scores
+            .games
+            .insert("gameoflife".to_string(), vec![score(50), score(10)]);
+        scores
+            .games
+            .insert("game-of-life".to_string(), vec![score(30)]);
+
+        assert!(scores.migrate_renamed_games());
+        assert!(!scores.games.contains_key("gameoflife"));
+        let points: Vec<u32> = scores.games["game-of-life"]
+            .iter()
+            .map(|s| s.score)
+            .collect();
+        assert_eq!(points, [50, 30, 10]);
+        // Déjà migré : rien à faire
+        assert!(!scores.migrate_renamed_games());
     }
 }
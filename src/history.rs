@@ -0,0 +1,215 @@
+//! Historique des parties jouées.
+//!
+//! Chaque partie terminée est ajoutée sur une ligne JSON à la fin de
+//! `history.jsonl`, sans jamais réécrire les lignes précédentes : les
+//! statistiques sont recalculées à partir de ce journal à chaque affichage.
+//! Une ligne illisible (fichier tronqué, ancienne version) est ignorée.
+
+use crate::storage;
+use crate::time_format;
+use crate::writer;
+use chrono::{DateTime, Datelike, Duration as ChronoDuration, Local, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Journal des parties, dans le dossier de données
+pub const HISTORY_FILE: &str = "history.jsonl";
+
+/// Les parties plus courtes (lancée puis quittée aussitôt) ne sont pas
+/// enregistrées
+pub const MIN_SESSION_DURATION: Duration = Duration::from_secs(5);
+
+/// Une partie terminée
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionRecord {
+    /// Identifiant du jeu dans le registre
+    pub game: String,
+    /// Mode de jeu, pour les jeux qui en proposent plusieurs
+    #[serde(default)]
+    pub mode: Option<String>,
+    #[serde(default)]
+    pub score: Option<u32>,
+    /// Objectif de score personnel atteint pendant la partie
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_reached: Option<u32>,
+    /// Partie terminée sur une victoire (voir [`crate::core::GameStatus`])
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub won: bool,
+    /// Temps de jeu, sans les pauses
+    pub duration_seconds: u64,
+    /// Fin de la partie
+    pub timestamp: DateTime<Utc>,
+}
+
+impl SessionRecord {
+    /// Date de fin de la partie (voir [`time_format`])
+    pub fn format_date(&self) -> String {
+        time_format::format_date_time(self.timestamp)
+    }
+}
+
+fn history_path() -> Option<PathBuf> {
+    storage::data_dir().map(|dir| dir.join(HISTORY_FILE))
+}
+
+/// Ajoute une partie à l'historique
+pub fn record(record: &SessionRecord) -> Result<(), Box<dyn std::error::Error>> {
+    let path = history_path().ok_or("no config directory")?;
+    let mut line = serde_json::to_string(record)?;
+    line.push('\n');
+    writer::append(path, line);
+    Ok(())
+}
+
+/// Parties enregistrées, de la plus ancienne à la plus récente
+pub fn load() -> Vec<SessionRecord> {
+    let Some(content) = history_path().and_then(|path| writer::read_to_string(&path).ok()) else {
+        return Vec::new();
+    };
+
+    content
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect()
+}
+
+/// Efface l'historique (utilisé par "Reset Everything")
+pub fn clear() {
+    if let Some(path) = history_path() {
+        writer::delete(path);
+    }
+}
+
+/// Période affichée dans la vue History
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateRange {
+    AllTime,
+    Today,
+    LastWeek,
+    LastMonth,
+}
+
+impl DateRange {
+    pub const ALL: [DateRange; 4] = [
+        DateRange::AllTime,
+        DateRange::Today,
+        DateRange::LastWeek,
+        DateRange::LastMonth,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            DateRange::AllTime => "All time",
+            DateRange::Today => "Today",
+            DateRange::LastWeek => "Last 7 days",
+            DateRange::LastMonth => "Last 30 days",
+        }
+    }
+
+    /// Période suivante, en revenant au début après la dernière
+    pub fn next(self) -> Self {
+        let index = Self::ALL
+            .iter()
+            .position(|&range| range == self)
+            .unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    /// Indique si une partie terminée à `timestamp` tombe dans la période
+    pub fn contains(self, timestamp: DateTime<Utc>, now: DateTime<Local>) -> bool {
+        let timestamp = timestamp.with_timezone(&Local);
+        match self {
+            DateRange::AllTime => true,
+            DateRange::Today => timestamp.date_naive() == now.date_naive(),
+            DateRange::LastWeek => now - timestamp <= ChronoDuration::days(7),
+            DateRange::LastMonth => now - timestamp <= ChronoDuration::days(30),
+        }
+    }
+}
+
+/// Période des totaux de l'écran Statistics, alignée sur le calendrier
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatsPeriod {
+    ThisWeek,
+    ThisMonth,
+    AllTime,
+}
+
+impl StatsPeriod {
+    pub const ALL: [StatsPeriod; 3] = [
+        StatsPeriod::ThisWeek,
+        StatsPeriod::ThisMonth,
+        StatsPeriod::AllTime,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            StatsPeriod::ThisWeek => "This Week",
+            StatsPeriod::ThisMonth => "This Month",
+            StatsPeriod::AllTime => "All Time",
+        }
+    }
+
+    /// Période suivante, en revenant au début après la dernière
+    pub fn next(self) -> Self {
+        let index = Self::ALL
+            .iter()
+            .position(|&period| period == self)
+            .unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    /// Indique si une partie terminée à `timestamp` tombe dans la période ;
+    /// les semaines commencent le lundi
+    pub fn contains(self, timestamp: DateTime<Utc>, now: DateTime<Local>) -> bool {
+        let date = timestamp.with_timezone(&Local).date_naive();
+        let today = now.date_naive();
+        match self {
+            StatsPeriod::ThisWeek => date.iso_week() == today.iso_week(),
+            StatsPeriod::ThisMonth => date.year() == today.year() && date.month() == today.month(),
+            StatsPeriod::AllTime => true,
+        }
+    }
+}
+
+/// Totaux d'un jeu, recalculés depuis l'historique
+#[derive(Debug, Clone, Default)]
+pub struct GameSummary {
+    pub sessions: usize,
+    /// Parties terminées sur une victoire
+    pub wins: usize,
+    pub total_seconds: u64,
+    pub best_score: Option<u32>,
+    pub last_played: Option<DateTime<Utc>>,
+}
+
+/// Totaux par jeu, triés par nom de jeu
+pub fn summarize(records: &[SessionRecord]) -> BTreeMap<String, GameSummary> {
+    let mut summaries: BTreeMap<String, GameSummary> = BTreeMap::new();
+    for record in records {
+        let summary = summaries.entry(record.game.clone()).or_default();
+        summary.sessions += 1;
+        summary.wins += usize::from(record.won);
+        summary.total_seconds += record.duration_seconds;
+        summary.best_score = summary.best_score.max(record.score);
+        summary.last_played = summary.last_played.max(Some(record.timestamp));
+    }
+    summaries
+}
+
+/// Parties de `game` qui ont battu le meilleur score précédent, dans l'ordre
+/// chronologique : la progression du record personnel
+pub fn personal_bests(records: &[SessionRecord], game: &str) -> Vec<(DateTime<Utc>, u32)> {
+    let mut bests: Vec<(DateTime<Utc>, u32)> = Vec::new();
+    for record in records.iter().filter(|record| record.game == game) {
+        let Some(score) = record.score else {
+            continue;
+        };
+        if bests.last().is_none_or(|&(_, best)| score > best) {
+            bests.push((record.timestamp, score));
+        }
+    }
+    bests
+}
@@ -0,0 +1,358 @@
+//! Événements du terminal corrigés selon la plateforme.
+//!
+//! La console Windows envoie un relâchement après chaque pression (les
+//! boucles qui ne filtrent pas `KeyEventKind` verraient chaque touche deux
+//! fois), certaines consoles transmettent Entrée comme un caractère '\r' ou
+//! '\n', et un redimensionnement y laisse des restes de l'ancien écran.
+//! [`normalize`] efface ces différences avant que le menu ou les jeux ne
+//! voient les événements. Le profil est détecté, ou imposé dans les Settings.
+//!
+//! [`ChordRecognizer`] reconnaît les suites de touches comme `g` puis `s`, et
+//! [`decode_keys`] lit les touches d'un terminal distant qui n'envoie que des
+//! octets.
+
+use crate::config::{self, PlatformProfile};
+use crate::core::clock::Instant;
+use crate::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::Once;
+use std::time::Duration;
+
+/// Délai pour taper la touche suivante d'une suite avant qu'elle soit oubliée
+pub const CHORD_TIMEOUT: Duration = Duration::from_millis(1500);
+
+/// Profil résolu ; `UNSET` tant que la configuration n'a pas été lue
+static RESOLVED: AtomicU8 = AtomicU8::new(UNSET);
+const UNSET: u8 = u8::MAX;
+
+/// Profil effectivement utilisé
+pub fn platform_profile() -> PlatformProfile {
+    if RESOLVED.load(Ordering::Relaxed) == UNSET {
+        // Suivi ensuite à chaque modification des réglages
+        static WATCHING: Once = Once::new();
+        WATCHING.call_once(|| {
+            config::on_change(|config| apply_platform_profile(config.gameplay.platform_profile));
+        });
+    }
+    PlatformProfile::ALL
+        .get(RESOLVED.load(Ordering::Relaxed) as usize)
+        .copied()
+        .unwrap_or(PlatformProfile::Standard)
+}
+
+/// Prend en compte un profil modifié ; `Auto` est résolu ici
+pub fn apply_platform_profile(profile: PlatformProfile) {
+    let profile = match profile {
+        PlatformProfile::Auto => detect(),
+        profile => profile,
+    };
+    let index = PlatformProfile::ALL
+        .iter()
+        .position(|&p| p == profile)
+        .unwrap_or(0);
+    RESOLVED.store(index as u8, Ordering::Relaxed);
+}
+
+/// Profil de la plateforme de compilation
+pub fn detect() -> PlatformProfile {
+    if cfg!(windows) {
+        PlatformProfile::Windows
+    } else {
+        PlatformProfile::Standard
+    }
+}
+
+/// Console Windows historique (conhost), sans emoji ; Windows Terminal
+/// s'annonce avec `WT_SESSION`
+pub fn legacy_console() -> bool {
+    platform_profile() == PlatformProfile::Windows && std::env::var_os("WT_SESSION").is_none()
+}
+
+/// Événement tel que le menu et les jeux doivent le voir ; `None` s'il faut
+/// l'ignorer
+pub fn normalize(event: Event, profile: PlatformProfile) -> Option<Event> {
+    let Event::Key(mut key) = event else {
+        return Some(event);
+    };
+    if key.kind == KeyEventKind::Release && profile == PlatformProfile::Windows {
+        return None;
+    }
+    if matches!(key.code, KeyCode::Char('\r' | '\n'))
+        && key.modifiers.difference(KeyModifiers::SHIFT).is_empty()
+    {
+        key.code = KeyCode::Enter;
+    }
+    Some(Event::Key(key))
+}
+
+/// Résultat d'une touche passée à [`ChordRecognizer::feed`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChordStep<A> {
+    /// Début d'une suite : la touche est gardée en attendant la suivante
+    Pending,
+    /// Suite complète
+    Complete(A),
+    /// La touche ne continue aucune suite ; elle garde son rôle habituel
+    Unmatched,
+}
+
+/// Reconnaît des suites de touches parmi une liste de raccourcis
+pub struct ChordRecognizer<A> {
+    bindings: Vec<(Vec<char>, A)>,
+    pending: Vec<char>,
+    last_key: Option<Instant>,
+}
+
+impl<A: Copy> ChordRecognizer<A> {
+    /// Les suites vides sont ignorées
+    pub fn new(bindings: Vec<(Vec<char>, A)>) -> Self {
+        Self {
+            bindings: bindings
+                .into_iter()
+                .filter(|(keys, _)| !keys.is_empty())
+                .collect(),
+            pending: Vec::new(),
+            last_key: None,
+        }
+    }
+
+    pub fn feed(&mut self, c: char, now: Instant) -> ChordStep<A> {
+        self.expire(now);
+        let was_pending = !self.pending.is_empty();
+        self.pending.push(c);
+
+        if let Some(&(_, action)) = self.bindings.iter().find(|(keys, _)| *keys == self.pending) {
+            self.cancel();
+            return ChordStep::Complete(action);
+        }
+        if self
+            .bindings
+            .iter()
+            .any(|(keys, _)| keys.starts_with(&self.pending))
+        {
+            self.last_key = Some(now);
+            return ChordStep::Pending;
+        }
+
+        self.cancel();
+        // Une suite abandonnée laisse la touche en commencer une autre
+        if was_pending {
+            self.feed(c, now)
+        } else {
+            ChordStep::Unmatched
+        }
+    }
+
+    /// Touches déjà tapées de la suite en cours, vide s'il n'y en a pas
+    pub fn pending(&mut self, now: Instant) -> &[char] {
+        self.expire(now);
+        &self.pending
+    }
+
+    /// Touches qui terminent ou continuent la suite en cours, avec l'action
+    /// de chaque suite concernée
+    pub fn completions(&self) -> Vec<(&[char], A)> {
+        self.bindings
+            .iter()
+            .filter(|(keys, _)| !self.pending.is_empty() && keys.starts_with(&self.pending))
+            .map(|(keys, action)| (&keys[self.pending.len()..], *action))
+            .collect()
+    }
+
+    pub fn cancel(&mut self) {
+        self.pending.clear();
+        self.last_key = None;
+    }
+
+    fn expire(&mut self, now: Instant) {
+        if self
+            .last_key
+            .is_some_and(|last| now.duration_since(last) >= CHORD_TIMEOUT)
+        {
+            self.cancel();
+        }
+    }
+}
+
+/// Découpe les octets tapés dans un terminal distant (xterm.js, canal SSH)
+/// en touches
+pub fn decode_keys(data: &str) -> Vec<KeyEvent> {
+    let mut keys = Vec::new();
+    let mut rest = data;
+
+    while let Some(c) = rest.chars().next() {
+        let (key, len) = match c {
+            '\x1b' => decode_escape(rest),
+            '\r' | '\n' => (KeyEvent::from(KeyCode::Enter), 1),
+            '\t' => (KeyEvent::from(KeyCode::Tab), 1),
+            '\x7f' | '\x08' => (KeyEvent::from(KeyCode::Backspace), 1),
+            // Ctrl+lettre arrive comme caractère de contrôle
+            '\x01'..='\x1a' => {
+                let letter = (b'a' + c as u8 - 1) as char;
+                (
+                    KeyEvent::new(KeyCode::Char(letter), KeyModifiers::CONTROL),
+                    1,
+                )
+            }
+            c => (KeyEvent::from(KeyCode::Char(c)), c.len_utf8()),
+        };
+        keys.push(key);
+        rest = &rest[len..];
+    }
+
+    keys
+}
+
+/// Décode une séquence d'échappement en tête de `data` ; retourne la touche
+/// et le nombre d'octets consommés. Un Échap seul, ou suivi d'une séquence
+/// inconnue, vaut la touche Échap
+fn decode_escape(data: &str) -> (KeyEvent, usize) {
+    let escape = (KeyEvent::from(KeyCode::Esc), 1);
+    let bytes = data.as_bytes();
+
+    match bytes.get(1) {
+        // SS3 : F1-F4 et flèches en mode application
+        Some(b'O') => {
+            let code = match bytes.get(2) {
+                Some(b'P') => KeyCode::F(1),
+                Some(b'Q') => KeyCode::F(2),
+                Some(b'R') => KeyCode::F(3),
+                Some(b'S') => KeyCode::F(4),
+                Some(b'A') => KeyCode::Up,
+                Some(b'B') => KeyCode::Down,
+                Some(b'C') => KeyCode::Right,
+                Some(b'D') => KeyCode::Left,
+                Some(b'H') => KeyCode::Home,
+                Some(b'F') => KeyCode::End,
+                _ => return escape,
+            };
+            (KeyEvent::from(code), 3)
+        }
+        // CSI : paramètres numériques puis un octet final
+        Some(b'[') => {
+            let Some(end) = bytes[2..]
+                .iter()
+                .position(|byte| (0x40..=0x7e).contains(byte))
+            else {
+                return escape;
+            };
+            let params = &data[2..2 + end];
+            let len = 2 + end + 1;
+            let mut numbers = params.split(';').map(|n| n.parse::<u8>().unwrap_or(1));
+            let first = numbers.next().unwrap_or(1);
+            // Modificateurs xterm : 1 + (Maj=1, Alt=2, Ctrl=4)
+            let modifiers = numbers.next().map_or(KeyModifiers::NONE, |value| {
+                let bits = value.saturating_sub(1);
+                let mut modifiers = KeyModifiers::NONE;
+                if bits & 1 != 0 {
+                    modifiers |= KeyModifiers::SHIFT;
+                }
+                if bits & 2 != 0 {
+                    modifiers |= KeyModifiers::ALT;
+                }
+                if bits & 4 != 0 {
+                    modifiers |= KeyModifiers::CONTROL;
+                }
+                modifiers
+            });
+
+            let code = match bytes[2 + end] {
+                b'A' => KeyCode::Up,
+                b'B' => KeyCode::Down,
+                b'C' => KeyCode::Right,
+                b'D' => KeyCode::Left,
+                b'H' => KeyCode::Home,
+                b'F' => KeyCode::End,
+                b'Z' => return (KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT), len),
+                b'~' => match first {
+                    1 | 7 => KeyCode::Home,
+                    2 => KeyCode::Insert,
+                    3 => KeyCode::Delete,
+                    4 | 8 => KeyCode::End,
+                    5 => KeyCode::PageUp,
+                    6 => KeyCode::PageDown,
+                    15 => KeyCode::F(5),
+                    17..=21 => KeyCode::F(first - 11),
+                    23 | 24 => KeyCode::F(first - 12),
+                    _ => return escape,
+                },
+                _ => return escape,
+            };
+            (KeyEvent::new(code, modifiers), len)
+        }
+        // Alt+caractère
+        Some(_) => match data[1..].chars().next() {
+            Some(c) if !c.is_control() => (
+                KeyEvent::new(KeyCode::Char(c), KeyModifiers::ALT),
+                1 + c.len_utf8(),
+            ),
+            _ => escape,
+        },
+        None => escape,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::{KeyEvent, KeyEventState};
+
+    fn key(code: KeyCode, kind: KeyEventKind) -> Event {
+        Event::Key(KeyEvent {
+            code,
+            modifiers: KeyModifiers::NONE,
+            kind,
+            state: KeyEventState::NONE,
+        })
+    }
+
+    #[test]
+    fn windows_profile_drops_key_releases() {
+        let release = key(KeyCode::Char('a'), KeyEventKind::Release);
+        assert_eq!(normalize(release.clone(), PlatformProfile::Windows), None);
+        // Ailleurs, un relâchement est une vraie information (R tenu)
+        assert_eq!(
+            normalize(release.clone(), PlatformProfile::Standard),
+            Some(release)
+        );
+    }
+
+    #[test]
+    fn carriage_return_and_line_feed_become_enter() {
+        for profile in [PlatformProfile::Standard, PlatformProfile::Windows] {
+            for c in ['\r', '\n'] {
+                assert_eq!(
+                    normalize(key(KeyCode::Char(c), KeyEventKind::Press), profile),
+                    Some(key(KeyCode::Enter, KeyEventKind::Press))
+                );
+            }
+        }
+        let resize = Event::Resize(80, 24);
+        assert_eq!(
+            normalize(resize.clone(), PlatformProfile::Windows),
+            Some(resize)
+        );
+    }
+
+    #[test]
+    fn chords_complete_time_out_and_fall_through() {
+        let mut chords = ChordRecognizer::new(vec![(vec!['g', 's'], 1), (vec!['g', 'h'], 2)]);
+        let start = Instant::now();
+
+        assert_eq!(chords.feed('g', start), ChordStep::Pending);
+        assert_eq!(chords.completions(), vec![(&['s'][..], 1), (&['h'][..], 2)]);
+        assert_eq!(chords.feed('s', start), ChordStep::Complete(1));
+        assert!(chords.pending(start).is_empty());
+
+        // Une touche hors suite garde son rôle habituel
+        assert_eq!(chords.feed('j', start), ChordStep::Unmatched);
+        chords.feed('g', start);
+        assert_eq!(chords.feed('j', start), ChordStep::Unmatched);
+
+        // Trop tard : la suite est oubliée
+        chords.feed('g', start);
+        let late = start + CHORD_TIMEOUT;
+        assert!(chords.pending(late).is_empty());
+        assert_eq!(chords.feed('h', late), ChordStep::Unmatched);
+    }
+}
@@ -0,0 +1,175 @@
+//! Tâches d'arrière-plan : vérification des mises à jour, pré-rendu des
+//! musiques, enregistrement des tampons…
+//!
+//! Tout ce qui peut prendre plus d'une image passe par [`spawn`], qui lance
+//! la tâche sur son propre thread et rend un [`Job`] que l'interface
+//! interroge à chaque image sans jamais attendre. Les tâches en cours sont
+//! recensées sous un nom court, affiché dans le pied de page du menu par
+//! [`status`]. À la fermeture, [`shutdown`] demande leur arrêt : les tâches
+//! longues consultent [`cancelled`] entre deux étapes, et la fermeture ne
+//! les attend que quelques instants.
+
+use crate::core::clock::Instant;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::sync::{Condvar, Mutex};
+use std::thread;
+use std::time::Duration;
+
+/// Temps maximal d'attente des tâches en cours à la fermeture
+const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Tâches en cours, par numéro, avec leur nom
+static RUNNING: Mutex<Vec<(u64, &'static str)>> = Mutex::new(Vec::new());
+/// Signalé à chaque fin de tâche
+static FINISHED: Condvar = Condvar::new();
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+static CANCELLED: AtomicBool = AtomicBool::new(false);
+
+/// État d'une tâche vu depuis l'interface
+#[derive(Debug, PartialEq)]
+pub enum JobPoll<T> {
+    Running,
+    Finished(T),
+    /// Tâche interrompue par une panique, ou jamais démarrée faute de thread
+    Lost,
+}
+
+/// Résultat à venir d'une tâche lancée par [`spawn`]
+pub struct Job<T> {
+    receiver: Receiver<T>,
+}
+
+impl<T> Job<T> {
+    /// Résultat de la tâche s'il est prêt, sans attendre ; il n'est rendu
+    /// qu'une fois, `Lost` ensuite
+    pub fn poll(&self) -> JobPoll<T> {
+        match self.receiver.try_recv() {
+            Ok(result) => JobPoll::Finished(result),
+            Err(TryRecvError::Empty) => JobPoll::Running,
+            Err(TryRecvError::Disconnected) => JobPoll::Lost,
+        }
+    }
+}
+
+/// Retire la tâche de la liste quand son thread se termine, même sur une
+/// panique
+struct Registration(u64);
+
+impl Drop for Registration {
+    fn drop(&mut self) {
+        if let Ok(mut running) = RUNNING.lock() {
+            running.retain(|&(id, _)| id != self.0);
+        }
+        FINISHED.notify_all();
+    }
+}
+
+/// Lance `task` en arrière-plan sous le nom `label`
+pub fn spawn<T, F>(label: &'static str, task: F) -> Job<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let (sender, receiver) = mpsc::channel();
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+    if let Ok(mut running) = RUNNING.lock() {
+        running.push((id, label));
+    }
+    let registration = Registration(id);
+    let spawned = thread::Builder::new()
+        .name(format!("job-{id}"))
+        .spawn(move || {
+            let result = task();
+            // Retirée de la liste avant que l'interface ne voie le résultat
+            drop(registration);
+            let _ = sender.send(result);
+        });
+    // Sans thread, le `Registration` a été libéré avec la closure et la
+    // tâche apparaît aussitôt perdue
+    if let Err(e) = spawned {
+        eprintln!("Impossible de lancer la tâche \"{label}\": {e}");
+    }
+    Job { receiver }
+}
+
+/// Vrai une fois l'arrêt des tâches demandé : une tâche longue s'arrête à
+/// la prochaine étape
+pub fn cancelled() -> bool {
+    CANCELLED.load(Ordering::Relaxed)
+}
+
+/// Noms des tâches en cours, de la plus ancienne à la plus récente
+pub fn running() -> Vec<&'static str> {
+    RUNNING
+        .lock()
+        .map(|running| running.iter().map(|&(_, label)| label).collect())
+        .unwrap_or_default()
+}
+
+/// Résumé pour le pied de page : "Checking for updates", "Preparing music
+/// (+1)"… ou `None` si rien ne tourne
+pub fn status() -> Option<String> {
+    let running = running();
+    match running.as_slice() {
+        [] => None,
+        [label] => Some(label.to_string()),
+        [label, others @ ..] => Some(format!("{label} (+{})", others.len())),
+    }
+}
+
+/// Demande l'arrêt des tâches et attend leur fin, au plus quelques
+/// secondes ; celles qui tournent encore sont abandonnées avec le processus
+pub fn shutdown() {
+    CANCELLED.store(true, Ordering::Relaxed);
+    let deadline = Instant::now() + SHUTDOWN_TIMEOUT;
+    let Ok(mut running) = RUNNING.lock() else {
+        return;
+    };
+    while !running.is_empty() {
+        let left = deadline.saturating_duration_since(Instant::now());
+        if left.is_zero() {
+            return;
+        }
+        match FINISHED.wait_timeout(running, left) {
+            Ok((guard, _)) => running = guard,
+            Err(_) => return,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn jobs_report_their_result_and_leave_the_status() {
+        let (release, wait) = mpsc::channel::<()>();
+        let job = spawn("Test job", move || {
+            let _ = wait.recv();
+            42
+        });
+        assert_eq!(job.poll(), JobPoll::Running);
+        assert!(running().contains(&"Test job"));
+
+        release.send(()).unwrap();
+        let deadline = Instant::now() + Duration::from_secs(5);
+        let result = loop {
+            match job.poll() {
+                JobPoll::Running if Instant::now() < deadline => {
+                    thread::sleep(Duration::from_millis(1))
+                }
+                other => break other,
+            }
+        };
+        assert_eq!(result, JobPoll::Finished(42));
+        assert_eq!(job.poll(), JobPoll::Lost);
+
+        let panicked = spawn("Panicking job", || -> u8 { panic!("boom") });
+        while panicked.poll() == JobPoll::Running {
+            thread::sleep(Duration::from_millis(1));
+        }
+        assert!(!running().contains(&"Test job"));
+        assert!(!running().contains(&"Panicking job"));
+    }
+}
@@ -0,0 +1,125 @@
+//! TermPlay : collection de mini-jeux pour le terminal, utilisable comme
+//! bibliothèque.
+//!
+//! Le binaire `termplay` n'est qu'une fine couche de ligne de commande autour
+//! de cette bibliothèque. D'autres projets peuvent lancer un jeu dans leur
+//! propre terminal avec [`run_game`], construire des jeux depuis le
+//! [`GameRegistry`], ou écrire leurs propres jeux en implémentant [`Game`].
+//!
+//! ```no_run
+//! use termplay::GameRegistry;
+//!
+//! let registry = GameRegistry::new();
+//! for game in registry.list_games() {
+//!     println!("{} - {}", game.id, game.description);
+//! }
+//!
+//! // Lance Snake en plein écran et rend la main à la fin de la partie
+//! termplay::run_game("snake")?;
+//! termplay::flush_saves();
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! Les scores, l'historique et la progression sont enregistrés en
+//! arrière-plan : appeler [`flush_saves`] avant de quitter le programme.
+//!
+//! Sans la feature `native` (build WebAssembly de la feature `web`), seuls
+//! les jeux et leurs composants sont disponibles : pas de menu, de terminal
+//! ni de sortie audio.
+
+// Le menu et la boucle de l'application n'existent pas sans `native` : les
+// écrans et utilitaires qu'ils sont seuls à utiliser restent alors inutilisés
+#![cfg_attr(not(feature = "native"), allow(dead_code, unused_imports))]
+
+pub mod audio;
+pub mod backup;
+pub mod challenge;
+pub mod config;
+pub mod core;
+pub mod event;
+pub mod frontend;
+pub mod games;
+pub mod ghosts;
+pub mod highscores;
+pub mod history;
+pub mod metrics;
+pub mod music;
+pub mod mutators;
+pub mod puzzles;
+pub mod replay;
+pub mod saves;
+pub mod simulate;
+pub mod splits;
+pub mod stamps;
+pub mod storage;
+pub mod surprise;
+pub mod targets;
+pub mod time_format;
+#[cfg(feature = "web")]
+pub mod web;
+
+#[cfg(feature = "native")]
+mod app;
+#[cfg(feature = "native")]
+mod attract;
+#[cfg(feature = "native")]
+mod audio_recovery;
+mod crash;
+mod input;
+mod jobs;
+#[cfg(feature = "native")]
+mod menu;
+mod recording;
+#[cfg(feature = "ssh")]
+pub mod server;
+mod shutdown;
+#[cfg(feature = "native")]
+mod splash;
+#[cfg(test)]
+mod test_support;
+mod ui;
+#[cfg(feature = "native")]
+mod update;
+mod writer;
+
+#[cfg(feature = "native")]
+pub use app::App;
+pub use core::{Game, GameAction, GameInfo, GameResult};
+pub use games::GameRegistry;
+
+/// Lance le jeu désigné par son identifiant, un alias ou son nom affiché
+/// (voir [`GameRegistry::find_id`]) en prenant le contrôle du terminal
+/// jusqu'à la fin de la partie
+#[cfg(feature = "native")]
+pub fn run_game(name: &str) -> GameResult {
+    let mut app = App::new();
+    if !app.has_game(name) {
+        return Err(format!("Game '{name}' not found").into());
+    }
+    app.run_game(name)
+}
+
+/// Lance le menu principal de TermPlay
+#[cfg(feature = "native")]
+pub fn run_menu() -> GameResult {
+    App::new().run_menu()
+}
+
+/// Arrête les tâches d'arrière-plan puis attend la fin des sauvegardes
+/// encore en file (scores, historique, progression), au plus quelques
+/// secondes
+pub fn flush_saves() {
+    jobs::shutdown();
+    writer::flush();
+}
+
+/// Appelle [`flush_saves`] en étant détruite. Créée au début de `main`, elle
+/// couvre toutes les sorties : fin normale, erreur remontée par `?` ou panic
+#[must_use = "the saves are only flushed when the guard is dropped"]
+pub struct SavesGuard;
+
+impl Drop for SavesGuard {
+    fn drop(&mut self) {
+        flush_saves();
+    }
+}
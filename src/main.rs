@@ -1,30 +1,32 @@
-mod app;
-mod audio;
 mod cli;
-mod config;
-mod core;
-mod games;
-mod highscores;
-mod menu;
-mod music;
 
-use app::App;
 use clap::Parser;
-use cli::{Cli, Commands};
+use cli::{Cli, Commands, GolCommand, ReplayCommand, StatsCommand};
 use crossterm::{
-    event::DisableMouseCapture,
+    event::{DisableBracketedPaste, DisableFocusChange, DisableMouseCapture},
     execute,
     terminal::{disable_raw_mode, LeaveAlternateScreen},
 };
 use std::io::{self, Write};
+use termplay::{backup, metrics, replay, simulate, surprise, time_format, App};
 
 #[cfg(feature = "self-update")]
 use axoupdater::AxoUpdater;
 
+/// Code de sortie d'un nom de jeu inconnu, distinct du code 1 renvoyé quand
+/// une partie échoue, pour les scripts
+const EXIT_UNKNOWN_GAME: i32 = 2;
+
 /// Fonction de nettoyage d'urgence du terminal
 fn emergency_terminal_cleanup() {
     let _ = disable_raw_mode();
-    let _ = execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture);
+    let _ = execute!(
+        io::stdout(),
+        LeaveAlternateScreen,
+        DisableMouseCapture,
+        DisableFocusChange,
+        DisableBracketedPaste
+    );
     let _ = io::stdout().flush();
 }
 
@@ -70,27 +72,130 @@ fn handle_update(_check_only: bool) -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+/// Héberge TermPlay pour des joueurs connectés en SSH
+#[cfg(feature = "ssh")]
+fn handle_serve(address: &str) -> Result<(), Box<dyn std::error::Error>> {
+    termplay::server::serve(address)
+}
+
+#[cfg(not(feature = "ssh"))]
+fn handle_serve(_address: &str) -> Result<(), Box<dyn std::error::Error>> {
+    println!("⚠️  The SSH server is not available in this build.");
+    println!("Build TermPlay with it using:");
+    println!("cargo install termplay --features ssh");
+    Ok(())
+}
+
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Installer un hook de panic global pour nettoyer le terminal
     std::panic::set_hook(Box::new(|panic_info| {
         emergency_terminal_cleanup();
         eprintln!("Application panic: {panic_info}");
+        // Ne pas perdre les scores encore en file d'écriture
+        termplay::flush_saves();
     }));
     let cli = Cli::parse();
+    // Les scores, l'historique et les parties en file sont écrits même quand
+    // une commande échoue
+    let saves = termplay::SavesGuard;
     let mut app = App::new();
 
     match cli.command {
+        Some(Commands::Game { name }) if name == surprise::RANDOM_GAME => match app.random_game() {
+            Some(game_id) => app.run_game(&game_id)?,
+            None => {
+                eprintln!("Every game is hidden, there is nothing to pick from.");
+                eprintln!("Show games again from the Games menu (V, then H).");
+                drop(saves);
+                std::process::exit(EXIT_UNKNOWN_GAME);
+            }
+        },
         Some(Commands::Game { name }) => {
             if app.has_game(&name) {
                 app.run_game(&name)?;
             } else {
                 eprintln!("Game '{name}' not found!");
+                match app.suggest_games(&name).as_slice() {
+                    [] => {}
+                    [only] => eprintln!("Did you mean '{only}'?"),
+                    suggestions => {
+                        let quoted: Vec<String> =
+                            suggestions.iter().map(|name| format!("'{name}'")).collect();
+                        eprintln!("Did you mean one of {}?", quoted.join(", "));
+                    }
+                }
                 eprintln!("Use 'termplay list' to see available games.");
-                std::process::exit(1);
+                drop(saves);
+                std::process::exit(EXIT_UNKNOWN_GAME);
             }
         }
-        Some(Commands::List) => {
-            app.list_games();
+        Some(Commands::List { all, verbose }) => {
+            app.list_games(all, verbose);
+        }
+        Some(Commands::Gol { command }) => match command {
+            GolCommand::Run {
+                pattern,
+                generations,
+                out,
+                stats,
+            } => simulate::run(&pattern, generations, out.as_deref(), stats)?,
+        },
+        Some(Commands::Stats { command }) => match command {
+            StatsCommand::Export { out, yes } => metrics::export(out.as_deref(), yes)?,
+        },
+        Some(Commands::Replay { command }) => match command {
+            ReplayCommand::Verify { file } => {
+                let replay = replay::read(&file)?;
+                let header = &replay.header;
+                let seconds = replay.duration().as_secs();
+                println!(
+                    "{} - recorded with TermPlay {}, seed {}",
+                    header.game_id, header.version, header.seed
+                );
+                for (key, value) in &header.options {
+                    println!("  {key}: {value}");
+                }
+                if replay.complete {
+                    println!(
+                        "✅ {} keys over {}m {:02}s, checksums OK",
+                        replay.keys.len(),
+                        seconds / 60,
+                        seconds % 60
+                    );
+                } else {
+                    println!(
+                        "⚠️  Interrupted recording: the first {} keys ({}m {:02}s) are intact",
+                        replay.keys.len(),
+                        seconds / 60,
+                        seconds % 60
+                    );
+                }
+            }
+        },
+        Some(Commands::Backup { file }) => {
+            let manifest = backup::backup(&file)?;
+            println!(
+                "✅ Backed up {} files to {}",
+                manifest.files.len(),
+                file.display()
+            );
+        }
+        Some(Commands::Restore { file, force }) => {
+            let manifest = backup::restore(&file, force)?;
+            println!(
+                "✅ Restored {} files from a TermPlay {} backup of {}",
+                manifest.files.len(),
+                manifest.version,
+                time_format::format_date_time(manifest.created)
+            );
+        }
+        Some(Commands::Completions { shell }) => {
+            let mut names = app.game_names();
+            names.push(surprise::RANDOM_GAME.to_string());
+            cli::print_completions(shell, names);
+        }
+        Some(Commands::Serve { ssh }) => {
+            handle_serve(&ssh)?;
         }
         Some(Commands::Update { check_only }) => {
             handle_update(check_only)?;
@@ -102,6 +207,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Nettoyer le hook de panic à la sortie normale
     let _ = std::panic::take_hook();
+    drop(saves);
 
     Ok(())
 }
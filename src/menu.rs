@@ -1,33 +1,155 @@
-use crate::audio::AudioManager;
-use crate::config::ConfigManager;
-use crate::core::{GameAction, GameInfo};
-use crate::highscores::HighScoreManager;
+use crate::audio::{AudioManager, AudioSink, SoundEffect};
+use crate::backup;
+use crate::challenge::Challenge;
+use crate::config::{
+    ChordAction, ConfigHandle, ConfigManager, PlayerColor, DEFAULT_PLAYER_NAME, PLAYER_NAME_MAX_LEN,
+};
+use crate::core::clock::Instant;
+use crate::core::share::ShareCode;
+use crate::core::{Game, GameAction, GameInfo};
+use crate::event::{KeyCode, KeyEvent, KeyModifiers, MouseEvent};
+use crate::games::arrange;
+use crate::ghosts;
+use crate::highscores::{HighScoreManager, NewScore};
+use crate::history::{self, DateRange, GameSummary, SessionRecord, StatsPeriod};
+use crate::input::{ChordRecognizer, ChordStep};
+use crate::jobs;
 use crate::music::{
     breakout::BREAKOUT_MUSIC, gameoflife::GAMEOFLIFE_MUSIC, minesweeper::MINESWEEPER_MUSIC,
-    pong::PONG_MUSIC, snake::SNAKE_MUSIC, tetris::TETRIS_MUSIC, GameMusic, _2048::GAME2048_MUSIC,
+    pong::PONG_MUSIC, snake::SNAKE_MUSIC, tetris::TETRIS_MUSIC, GameMusic, MusicVariant,
+    _2048::GAME2048_MUSIC,
 };
-use crossterm::event::{KeyCode, KeyEvent};
+use crate::mutators::Mutator;
+use crate::puzzles;
+use crate::recording;
+use crate::saves;
+use crate::splits;
+use crate::storage::{self, STORED_FILES};
+use crate::surprise;
+use crate::targets;
+use crate::time_format;
+use crate::ui::animation::{Effect, Transition};
+use crate::ui::bigtext::{self, Font};
+use crate::ui::controls::CONTROL_HINTS;
+use crate::ui::wallpaper::Wallpaper;
+use crate::ui::{glyphs, numbers, style, SelectableList, TextInput};
+use crate::update::{UpdateChecker, UpdateStatus};
 use ratatui::{
-    layout::{Alignment, Constraint, Layout, Rect},
-    style::{Color, Modifier, Style, Stylize},
+    layout::{Alignment, Constraint, Flex, Layout, Rect},
+    style::{Color, Style, Stylize},
+    symbols,
     text::{Line, Span},
-    widgets::{Block, List, ListItem, ListState, Paragraph},
+    widgets::{
+        Axis, Block, Chart, Clear, Dataset, Gauge, GraphType, ListItem, Paragraph, Tabs, Wrap,
+    },
     Frame,
 };
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Ligne "Preview Theme" des Audio Settings
+const THEME_PREVIEW_INDEX: usize = 8;
+/// Ligne "Customize Effects" des Audio Settings
+const CUSTOMIZE_EFFECTS_INDEX: usize = 9;
+/// Ligne "Reconnect Audio" des Audio Settings
+const RECONNECT_AUDIO_INDEX: usize = 10;
+/// Largeur du panneau d'aperçu du menu Games, et largeur d'écran à partir
+/// de laquelle il est affiché
+const GAME_PREVIEW_WIDTH: u16 = 36;
+const GAME_PREVIEW_MIN_WIDTH: u16 = 76;
+/// Hauteur gardée pour l'aperçu au-dessus des détails du jeu
+const GAME_PREVIEW_MIN_BOARD: u16 = 8;
+/// Lignes d'actions de l'onglet Data, sous la liste des fichiers
+const DATA_OPEN_FOLDER_INDEX: usize = STORED_FILES.len();
+const DATA_BACKUP_INDEX: usize = STORED_FILES.len() + 1;
+const DATA_CLEAR_CACHES_INDEX: usize = STORED_FILES.len() + 2;
+const DATA_RESET_INDEX: usize = STORED_FILES.len() + 3;
+/// Hauteur de terminal à partir de laquelle le menu principal affiche son
+/// titre en grandes lettres
+const BIG_TITLE_MIN_HEIGHT: u16 = 30;
+/// Ligne "Score Targets" de l'onglet Gameplay
+const SCORE_TARGETS_INDEX: usize = 10;
+/// Longueur maximale d'un objectif de score saisi ("1,500,000")
+const SCORE_TARGET_MAX_LEN: usize = 12;
+/// Longueur maximale de la recherche dans la liste des jeux
+const GAME_SEARCH_MAX_LEN: usize = 24;
+/// Longueur maximale d'un code de partage saisi, tirets et espaces compris
+const SHARE_CODE_MAX_LEN: usize = 24;
+/// Durée d'affichage de l'annonce d'un nouveau score au retour d'une partie
+const TOAST_DURATION: Duration = Duration::from_secs(4);
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum MenuState {
     Main,
+    Resume, // Parties interrompues par un arrêt imprévu, proposées au démarrage
     Games,
+    Modifiers(String), // Modificateurs à appliquer à la prochaine partie d'un jeu
     HighScores,
-    HighScoresDetail(String), // Pour afficher les scores d'un jeu spécifique
+    WeeklyChallenge,            // Défi de la semaine et son classement
+    HighScoresDetail(String),   // Pour afficher les scores d'un jeu spécifique
     ConfirmClearScores(String), // Confirmation pour effacer les scores d'un jeu
+    Statistics,                 // Totaux par jeu, recalculés depuis l'historique
+    History,                    // Parties jouées, filtrées par jeu et par période
     MusicPlayer,
-    Settings,
-    AudioSettings,
+    Settings(SettingsTab), // Onglet affiché
+    SoundEffects,          // Réglages effet par effet
+    ScoreTargets,          // Objectifs de score personnels, par jeu
     About,
 }
 
+/// Onglets de l'écran Settings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingsTab {
+    Audio,
+    Graphics,
+    Controls,
+    Gameplay,
+    Data,
+}
+
+impl SettingsTab {
+    pub const ALL: [SettingsTab; 5] = [
+        SettingsTab::Audio,
+        SettingsTab::Graphics,
+        SettingsTab::Controls,
+        SettingsTab::Gameplay,
+        SettingsTab::Data,
+    ];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            SettingsTab::Audio => "🔊 Audio",
+            SettingsTab::Graphics => "🎨 Graphics",
+            SettingsTab::Controls => "⌨️ Controls",
+            SettingsTab::Gameplay => "🎮 Gameplay",
+            SettingsTab::Data => "💾 Data",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            SettingsTab::Audio => "Adjust audio and music settings",
+            SettingsTab::Graphics => "Terminal title, colors and display options",
+            SettingsTab::Controls => "Keys shared by every game",
+            SettingsTab::Gameplay => "Player name and game rules",
+            SettingsTab::Data => "Saved files, caches and reset",
+        }
+    }
+
+    fn index(self) -> usize {
+        Self::ALL.iter().position(|&tab| tab == self).unwrap_or(0)
+    }
+
+    pub fn next(self) -> Self {
+        Self::ALL[(self.index() + 1) % Self::ALL.len()]
+    }
+
+    pub fn previous(self) -> Self {
+        Self::ALL[(self.index() + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct MenuOption {
     pub title: String,
@@ -45,39 +167,108 @@ pub struct MainMenu {
     current_menu: MenuState,
     menu_history: Vec<MenuState>, // Pile pour l'historique de navigation
     main_options: Vec<MenuOption>,
-    games_list: Vec<GameInfo>,
-    selected_index: usize,
-    list_state: ListState,
+    games_list: Vec<GameInfo>, // Tous les jeux, dans l'ordre par défaut
+    show_hidden_games: bool,   // Afficher aussi les jeux masqués dans Games
+    game_search: TextInput,
+    searching: bool,                 // Saisie de la recherche en cours dans Games
+    name_input: Option<TextInput>,   // Édition du nom du joueur dans Settings
+    code_input: Option<TextInput>,   // Saisie d'un code de partage dans Games
+    invalid_code: bool,              // Le dernier code validé n'a pas pu être lu
+    target_input: Option<TextInput>, // Saisie de l'objectif du jeu sélectionné dans Score Targets
+    invalid_target: bool,            // Le dernier objectif validé n'a pas pu être lu
+    share_code: Option<ShareCode>,   // Partie à rejouer, lancée par la boucle du menu
+    random_game: Option<String>,     // Jeu tiré par "Random Game", lancé par la boucle du menu
+    confirm_reset: bool,             // "Reset Everything" attend une seconde validation
+    data_status: Option<String>,     // Résultat de la dernière action de l'onglet Data
+    list: SelectableList,
     audio: AudioManager,
     config_manager: ConfigManager,
     highscore_manager: HighScoreManager,
+    history: Vec<SessionRecord>, // Rechargé à l'ouverture de Statistics et History
+    history_game: Option<String>, // Jeu affiché dans History, `None` pour tous
+    history_range: DateRange,
+    stats_period: StatsPeriod, // Période des totaux de Statistics
     music_tracks: Vec<MusicTrack>,
     current_playing: Option<usize>,
     current_variant: Vec<usize>, // Index de la variante sélectionnée pour chaque track
+    update_checker: Option<UpdateChecker>, // Lancé à la première ouverture de l'écran About
+    transition: Option<Transition>, // Glissement ou fondu du contenu après un changement d'écran
+    wallpaper: Wallpaper,        // Animation derrière les panneaux
+    new_scores: Vec<NewScore>,   // Scores de la dernière partie, marqués NEW dans High Scores
+    toast: Option<(String, Instant)>, // Annonce affichée quelques secondes en haut de l'écran
+    score_chart: bool,           // Classement d'un jeu affiché en graphique plutôt qu'en liste
+    chords: ChordRecognizer<ChordAction>, // Raccourcis à plusieurs touches (g puis s…)
+    previews: HashMap<String, Option<Box<dyn Game>>>, // Jeux créés pour le panneau d'aperçu de Games
+    weekly: Challenge, // Défi de la semaine, recalculé à l'ouverture de son écran
+    mutators: Vec<Mutator>, // Modificateurs cochés sur l'écran Modifiers
+    interrupted: Vec<(String, saves::SavedGame)>, // Parties affichées par l'écran Resume
+    guest: bool,       // Session invitée : ni actions Data ni reprise des parties
+}
+
+/// Données lues au démarrage du menu, que l'écran d'accueil charge en
+/// parallèle pendant qu'il s'affiche
+pub struct StartupData {
+    pub highscore_manager: HighScoreManager,
+    pub history: Vec<SessionRecord>,
+    pub music_tracks: Vec<MusicTrack>,
 }
 
 #[derive(Debug, Clone)]
 pub struct MusicTrack {
     pub name: String,
-    pub variants: Vec<String>, // normal, fast, celebration
+    pub variants: Vec<String>,    // normal, fast, celebration
+    pub durations: Vec<Duration>, // Durée de chaque variante
+}
+
+impl StartupData {
+    /// Charge tout dans le thread appelant
+    pub fn load() -> Self {
+        Self {
+            highscore_manager: load_highscores(),
+            history: history::load(),
+            music_tracks: music_tracks(),
+        }
+    }
 }
 
 impl MainMenu {
-    pub fn new(games: Vec<&GameInfo>) -> Result<Self, Box<dyn std::error::Error>> {
-        // Charger la configuration
-        let config_manager = ConfigManager::new()?;
+    /// Menu des jeux `games`, avec les données chargées au démarrage ; ses
+    /// sons sortent sur `sink`, comme ceux des jeux, et ses réglages sont
+    /// ceux de `config`, partagés avec l'application
+    pub fn new(
+        games: Vec<&GameInfo>,
+        startup: StartupData,
+        sink: Arc<dyn AudioSink>,
+        config: ConfigHandle,
+    ) -> Self {
+        let config_manager = ConfigManager::from_handle(config);
         let audio_config = config_manager.get_audio_config();
+        let weekly = Challenge::current();
+        let weekly_name = games
+            .iter()
+            .find(|game| game.id == weekly.game_id)
+            .map_or(weekly.game_id, |game| game.name.as_str());
         let main_options = vec![
             MenuOption {
                 title: "🎮 Games".to_string(),
                 description: "Play exciting terminal games".to_string(),
                 action: MenuAction::EnterSubMenu(MenuState::Games),
             },
+            MenuOption {
+                title: "📅 Weekly Challenge".to_string(),
+                description: format!("{weekly_name}: {}", weekly.modifier.name()),
+                action: MenuAction::EnterSubMenu(MenuState::WeeklyChallenge),
+            },
             MenuOption {
                 title: "🏆 High Scores".to_string(),
                 description: "View best scores and leaderboards".to_string(),
                 action: MenuAction::EnterSubMenu(MenuState::HighScores),
             },
+            MenuOption {
+                title: "📊 Statistics".to_string(),
+                description: "Play time and history of played games".to_string(),
+                action: MenuAction::EnterSubMenu(MenuState::Statistics),
+            },
             MenuOption {
                 title: "🎵 Music Player".to_string(),
                 description: "Listen to game soundtracks".to_string(),
@@ -86,7 +277,7 @@ impl MainMenu {
             MenuOption {
                 title: "⚙️ Settings".to_string(),
                 description: "Configure game preferences".to_string(),
-                action: MenuAction::EnterSubMenu(MenuState::Settings),
+                action: MenuAction::EnterSubMenu(MenuState::Settings(SettingsTab::Audio)),
             },
             MenuOption {
                 title: "ℹ️ About".to_string(),
@@ -100,90 +291,109 @@ impl MainMenu {
             },
         ];
 
-        let mut list_state = ListState::default();
-        list_state.select(Some(0));
-
-        let music_tracks = vec![
-            MusicTrack {
-                name: TETRIS_MUSIC.name().to_string(),
-                variants: vec![
-                    "Normal".to_string(),
-                    "Fast".to_string(),
-                    "Celebration".to_string(),
-                ],
-            },
-            MusicTrack {
-                name: SNAKE_MUSIC.name().to_string(),
-                variants: vec!["Normal".to_string(), "Fast".to_string()],
-            },
-            MusicTrack {
-                name: PONG_MUSIC.name().to_string(),
-                variants: vec![
-                    "Normal".to_string(),
-                    "Fast".to_string(),
-                    "Celebration".to_string(),
-                ],
-            },
-            MusicTrack {
-                name: GAME2048_MUSIC.name().to_string(),
-                variants: vec![
-                    "Normal".to_string(),
-                    "Fast".to_string(),
-                    "Celebration".to_string(),
-                ],
-            },
-            MusicTrack {
-                name: MINESWEEPER_MUSIC.name().to_string(),
-                variants: vec![
-                    "Normal".to_string(),
-                    "Intense".to_string(),
-                    "Victory".to_string(),
-                ],
-            },
-            MusicTrack {
-                name: BREAKOUT_MUSIC.name().to_string(),
-                variants: vec![
-                    "Normal".to_string(),
-                    "Intense".to_string(),
-                    "Victory".to_string(),
-                ],
-            },
-            MusicTrack {
-                name: GAMEOFLIFE_MUSIC.name().to_string(),
-                variants: vec![
-                    "Contemplative".to_string(),
-                    "Dynamic".to_string(),
-                    "Wonder".to_string(),
-                ],
-            },
-        ];
-
         // Créer l'AudioManager avec la configuration chargée
-        let audio = AudioManager::new_with_config(audio_config)?;
+        let audio = AudioManager::with_sink(&audio_config, sink);
 
-        // Créer le HighScoreManager
-        let highscore_manager = HighScoreManager::new().unwrap_or_default();
+        let StartupData {
+            highscore_manager,
+            history,
+            music_tracks,
+        } = startup;
 
         // Initialiser les variantes sélectionnées (index 0 = première variante pour chaque track)
         let current_variant = vec![0; music_tracks.len()];
 
-        Ok(Self {
+        let chords = ChordRecognizer::new(
+            config_manager
+                .get_keymap_config()
+                .chords
+                .iter()
+                .map(|binding| (binding.sequence(), binding.action))
+                .collect(),
+        );
+
+        Self {
             current_menu: MenuState::Main,
             menu_history: Vec::new(), // Initialiser la pile vide
             main_options,
             games_list: games.into_iter().cloned().collect(),
-            selected_index: 0,
-            list_state,
+            show_hidden_games: false,
+            game_search: TextInput::new(GAME_SEARCH_MAX_LEN),
+            searching: false,
+            name_input: None,
+            code_input: None,
+            invalid_code: false,
+            target_input: None,
+            invalid_target: false,
+            share_code: None,
+            random_game: None,
+            confirm_reset: false,
+            data_status: None,
+            list: SelectableList::new(),
             audio,
             config_manager,
             highscore_manager,
+            history,
+            history_game: None,
+            history_range: DateRange::AllTime,
+            stats_period: StatsPeriod::AllTime,
             music_tracks,
             current_playing: None,
             current_variant,
-        })
+            update_checker: None,
+            transition: None,
+            wallpaper: Wallpaper::new(),
+            new_scores: Vec::new(),
+            toast: None,
+            score_chart: false,
+            chords,
+            previews: HashMap::new(),
+            weekly,
+            mutators: Vec::new(),
+            interrupted: Vec::new(),
+            guest: false,
+        }
+    }
+
+    /// Menu d'une session invitée (voir `App::guest`) : les actions de
+    /// l'onglet Data et la reprise des parties interrompues ne sont pas
+    /// proposées, et les chemins de l'hôte ne sont pas affichés
+    pub fn with_guest_mode(mut self, guest: bool) -> Self {
+        self.guest = guest;
+        self
     }
 
     pub fn handle_key(&mut self, key: KeyEvent) -> GameAction {
+        // La confirmation du reset ne vaut que pour un Entrée immédiat
+        if key.code != KeyCode::Enter {
+            self.confirm_reset = false;
+        }
+
+        if self.code_input.is_some() {
+            return self.handle_code_entry(key);
+        }
+
+        if self.handle_text_entry(key) {
+            return GameAction::Continue;
+        }
+
+        if self.handle_chord(key) {
+            return GameAction::Continue;
+        }
+
+        if key.modifiers.contains(KeyModifiers::SHIFT)
+            && matches!(key.code, KeyCode::Up | KeyCode::Down)
+            && self.current_menu == MenuState::Games
+        {
+            self.move_selected_game(if key.code == KeyCode::Up { -1 } else { 1 });
+            return GameAction::Continue;
+        }
+
+        if self.list.handle_key(key, self.item_count()) {
+            self.audio.play_sound(crate::audio::SoundEffect::MenuSelect);
+            return GameAction::Continue;
+        }
+
         match key.code {
             KeyCode::Char('q') => {
                 if self.current_menu == MenuState::Main {
@@ -191,11 +401,6 @@ impl MainMenu {
                         .play_sound(crate::audio::SoundEffect::MenuConfirm);
                     GameAction::Quit
                 } else {
-                    // Arrêter la musique si on quitte le music player
-                    if self.current_menu == MenuState::MusicPlayer {
-                        self.audio.stop_music();
-                        self.current_playing = None;
-                    }
                     self.audio.play_sound(crate::audio::SoundEffect::MenuBack);
                     self.go_back();
                     GameAction::Continue
@@ -208,23 +413,21 @@ impl MainMenu {
                 }
                 GameAction::Continue
             }
-            KeyCode::Down => {
-                self.next_item();
-                self.audio.play_sound(crate::audio::SoundEffect::MenuSelect);
-                GameAction::Continue
-            }
-            KeyCode::Up => {
-                self.previous_item();
-                self.audio.play_sound(crate::audio::SoundEffect::MenuSelect);
-                GameAction::Continue
-            }
             KeyCode::Left => {
                 if self.current_menu == MenuState::MusicPlayer {
                     self.previous_variant();
                     self.audio.play_sound(crate::audio::SoundEffect::MenuSelect);
-                } else if self.current_menu == MenuState::AudioSettings {
+                } else if self.current_menu == MenuState::Settings(SettingsTab::Audio) {
                     self.decrease_audio_setting();
                     self.audio.play_sound(crate::audio::SoundEffect::MenuSelect);
+                } else if self.current_menu == MenuState::SoundEffects {
+                    self.adjust_selected_effect_volume(-0.1);
+                } else if self.current_menu == MenuState::History {
+                    self.cycle_history_game(false);
+                    self.audio.play_sound(crate::audio::SoundEffect::MenuSelect);
+                } else if let MenuState::Settings(tab) = self.current_menu {
+                    // Hors onglet Audio, ←→ changent d'onglet
+                    self.switch_settings_tab(tab.previous());
                 }
                 GameAction::Continue
             }
@@ -232,9 +435,36 @@ impl MainMenu {
                 if self.current_menu == MenuState::MusicPlayer {
                     self.next_variant();
                     self.audio.play_sound(crate::audio::SoundEffect::MenuSelect);
-                } else if self.current_menu == MenuState::AudioSettings {
+                } else if self.current_menu == MenuState::Settings(SettingsTab::Audio) {
                     self.increase_audio_setting();
                     self.audio.play_sound(crate::audio::SoundEffect::MenuSelect);
+                } else if self.current_menu == MenuState::SoundEffects {
+                    self.adjust_selected_effect_volume(0.1);
+                } else if self.current_menu == MenuState::History {
+                    self.cycle_history_game(true);
+                    self.audio.play_sound(crate::audio::SoundEffect::MenuSelect);
+                } else if let MenuState::Settings(tab) = self.current_menu {
+                    self.switch_settings_tab(tab.next());
+                }
+                GameAction::Continue
+            }
+            KeyCode::Tab => {
+                if let MenuState::Settings(tab) = self.current_menu {
+                    self.switch_settings_tab(tab.next());
+                } else if self.current_menu == MenuState::History {
+                    self.history_range = self.history_range.next();
+                    self.list.reset();
+                    self.audio.play_sound(crate::audio::SoundEffect::MenuSelect);
+                } else if self.current_menu == MenuState::Statistics {
+                    self.stats_period = self.stats_period.next();
+                    self.list.reset();
+                    self.audio.play_sound(crate::audio::SoundEffect::MenuSelect);
+                }
+                GameAction::Continue
+            }
+            KeyCode::BackTab => {
+                if let MenuState::Settings(tab) = self.current_menu {
+                    self.switch_settings_tab(tab.previous());
                 }
                 GameAction::Continue
             }
@@ -248,13 +478,67 @@ impl MainMenu {
                     self.audio
                         .play_sound(crate::audio::SoundEffect::MenuConfirm);
                     self.play_selected_music();
+                } else if self.current_menu == MenuState::SoundEffects {
+                    self.audition_selected_effect();
+                }
+                GameAction::Continue
+            }
+            KeyCode::Char('/') => {
+                if self.current_menu == MenuState::Games {
+                    self.searching = true;
+                    self.audio.play_sound(crate::audio::SoundEffect::MenuSelect);
+                }
+                GameAction::Continue
+            }
+            KeyCode::Char('v') => {
+                if matches!(self.current_menu, MenuState::HighScoresDetail(_)) {
+                    self.score_chart = !self.score_chart;
+                    self.audio.play_sound(crate::audio::SoundEffect::MenuSelect);
+                } else if self.current_menu == MenuState::Games {
+                    self.show_hidden_games = !self.show_hidden_games;
+                    self.list.reset();
+                    self.audio.play_sound(crate::audio::SoundEffect::MenuSelect);
+                }
+                GameAction::Continue
+            }
+            KeyCode::Char('m') => {
+                if self.current_menu == MenuState::Games {
+                    self.open_modifiers();
+                }
+                GameAction::Continue
+            }
+            KeyCode::Char('d') => {
+                if self.current_menu == MenuState::SoundEffects {
+                    self.toggle_selected_effect();
+                } else if self.current_menu == MenuState::ScoreTargets {
+                    self.set_selected_target(None);
+                    self.audio.play_sound(crate::audio::SoundEffect::MenuSelect);
+                } else if self.current_menu == MenuState::Resume {
+                    self.discard_selected_save();
                 }
                 GameAction::Continue
             }
             KeyCode::Char('s') => {
                 if self.current_menu == MenuState::MusicPlayer {
-                    self.audio.stop_music();
-                    self.current_playing = None;
+                    self.stop_music_player();
+                }
+                GameAction::Continue
+            }
+            KeyCode::Char('h') => {
+                if self.current_menu == MenuState::Games {
+                    self.toggle_selected_game_hidden();
+                    self.audio.play_sound(crate::audio::SoundEffect::MenuSelect);
+                } else if self.current_menu == MenuState::Statistics {
+                    self.history_game = None;
+                    self.navigate_to(MenuState::History);
+                    self.audio.play_sound(crate::audio::SoundEffect::MenuSelect);
+                }
+                GameAction::Continue
+            }
+            KeyCode::Char('a') => {
+                if self.current_menu == MenuState::HighScores {
+                    self.toggle_best_excludes_assisted();
+                    self.audio.play_sound(crate::audio::SoundEffect::MenuSelect);
                 }
                 GameAction::Continue
             }
@@ -263,6 +547,11 @@ impl MainMenu {
                 if let MenuState::HighScoresDetail(game_name) = &self.current_menu {
                     self.navigate_to(MenuState::ConfirmClearScores(game_name.clone()));
                     self.audio.play_sound(crate::audio::SoundEffect::MenuSelect);
+                } else if self.current_menu == MenuState::Games {
+                    // Jouer depuis un code de partage
+                    self.code_input = Some(TextInput::new(SHARE_CODE_MAX_LEN));
+                    self.invalid_code = false;
+                    self.audio.play_sound(crate::audio::SoundEffect::MenuSelect);
                 }
                 GameAction::Continue
             }
@@ -296,38 +585,333 @@ impl MainMenu {
         }
     }
 
-    fn next_item(&mut self) {
-        let max_items = match &self.current_menu {
-            MenuState::Main => self.main_options.len(),
-            MenuState::Games => self.games_list.len(),
-            MenuState::HighScores => {
-                let games_with_scores = self.highscore_manager.get_games_with_scores();
-                games_with_scores.len().max(1) // Au moins 1 pour "No scores yet"
+    /// Saisie d'un code de partage : Entrée lance la partie du code s'il est
+    /// valide
+    fn handle_code_entry(&mut self, key: KeyEvent) -> GameAction {
+        let Some(input) = &mut self.code_input else {
+            return GameAction::Continue;
+        };
+        match key.code {
+            KeyCode::Enter => match ShareCode::parse(input.value()) {
+                Some(code) => {
+                    self.code_input = None;
+                    self.share_code = Some(code);
+                    self.audio
+                        .play_sound(crate::audio::SoundEffect::MenuConfirm);
+                    return GameAction::GameOver;
+                }
+                None => {
+                    self.invalid_code = true;
+                    self.audio.play_sound(crate::audio::SoundEffect::MenuBack);
+                }
+            },
+            KeyCode::Esc => {
+                self.code_input = None;
+                self.audio.play_sound(crate::audio::SoundEffect::MenuBack);
             }
-            MenuState::HighScoresDetail(game_name) => {
-                // Récupérer le nombre réel de scores pour ce jeu
-                let scores = self.highscore_manager.get_scores(game_name);
-                scores.len().max(1) // Au moins 1 pour "No scores yet"
+            _ => {
+                if input.handle_key(key) {
+                    self.invalid_code = false;
+                }
             }
-            MenuState::ConfirmClearScores(_) => 2, // Yes/No
-            MenuState::MusicPlayer => self.music_tracks.len(),
-            MenuState::Settings => 3,
-            MenuState::AudioSettings => 5, // 5 paramètres audio
-            MenuState::About => 1,
+        }
+        GameAction::Continue
+    }
+
+    /// Code de partage validé dans Games, à rejouer par la partie lancée
+    pub fn take_share_code(&mut self) -> Option<ShareCode> {
+        self.share_code.take()
+    }
+
+    /// Jeu tiré par la ligne "Random Game" du menu Games
+    pub fn take_random_game(&mut self) -> Option<String> {
+        self.random_game.take()
+    }
+
+    /// Tire un jeu parmi ceux affichés dans Games (recherche comprise), en
+    /// évitant ceux joués dernièrement
+    fn pick_random_game(&self) -> Option<String> {
+        let games = self.filtered_games();
+        let candidates: Vec<&str> = games.iter().map(|game| game.id.as_str()).collect();
+        surprise::pick(&candidates, &history::load(), &mut rand::rng()).map(str::to_string)
+    }
+
+    /// Lignes du menu Games : les jeux puis "Random Game", s'il y a des jeux
+    fn games_row_count(&self) -> usize {
+        let len = self.filtered_games().len();
+        len + usize::from(len > 0)
+    }
+
+    /// Envoie la touche au champ de saisie actif (nom du joueur ou recherche) ;
+    /// retourne `true` si elle a été consommée
+    fn handle_text_entry(&mut self, key: KeyEvent) -> bool {
+        if let Some(input) = &mut self.target_input {
+            match key.code {
+                KeyCode::Enter => {
+                    let text = input.value().trim().to_string();
+                    match targets::parse(&text) {
+                        Some(target) => self.set_selected_target(Some(target)),
+                        None if text.is_empty() => self.set_selected_target(None),
+                        None => {
+                            self.invalid_target = true;
+                            self.audio.play_sound(crate::audio::SoundEffect::MenuBack);
+                            return true;
+                        }
+                    }
+                    self.target_input = None;
+                    self.audio
+                        .play_sound(crate::audio::SoundEffect::MenuConfirm);
+                }
+                KeyCode::Esc => {
+                    self.target_input = None;
+                    self.audio.play_sound(crate::audio::SoundEffect::MenuBack);
+                }
+                _ => {
+                    input.handle_key(key);
+                    self.invalid_target = false;
+                }
+            }
+            return true;
+        }
+
+        if let Some(input) = &mut self.name_input {
+            match key.code {
+                KeyCode::Enter => {
+                    let name = input.value().trim().to_string();
+                    self.name_input = None;
+                    self.save_player_name(name);
+                    self.audio
+                        .play_sound(crate::audio::SoundEffect::MenuConfirm);
+                }
+                KeyCode::Esc => {
+                    self.name_input = None;
+                    self.audio.play_sound(crate::audio::SoundEffect::MenuBack);
+                }
+                _ => {
+                    input.handle_key(key);
+                }
+            }
+            return true;
+        }
+
+        if self.current_menu == MenuState::Games && self.searching {
+            match key.code {
+                KeyCode::Esc => {
+                    self.searching = false;
+                    self.game_search.clear();
+                    self.list.reset();
+                }
+                // Laissées à la navigation dans la liste filtrée
+                KeyCode::Enter
+                | KeyCode::Up
+                | KeyCode::Down
+                | KeyCode::PageUp
+                | KeyCode::PageDown => return false,
+                _ => {
+                    if self.game_search.handle_key(key) {
+                        self.list.reset();
+                    }
+                }
+            }
+            return true;
+        }
+
+        false
+    }
+
+    /// Suites de touches du keymap ; `true` si la touche en fait partie
+    fn handle_chord(&mut self, key: KeyEvent) -> bool {
+        let KeyCode::Char(c) = key.code else {
+            // Échap abandonne une suite commencée sans quitter l'écran
+            let pending = !self.chords.pending(Instant::now()).is_empty();
+            self.chords.cancel();
+            return pending && key.code == KeyCode::Esc;
+        };
+        if key
+            .modifiers
+            .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+            || matches!(self.current_menu, MenuState::ConfirmClearScores(_))
+        {
+            self.chords.cancel();
+            return false;
+        }
+
+        match self.chords.feed(c, Instant::now()) {
+            ChordStep::Pending => true,
+            ChordStep::Complete(action) => {
+                self.run_chord(action);
+                true
+            }
+            ChordStep::Unmatched => false,
+        }
+    }
+
+    /// Va à l'écran d'un raccourci, sauf s'il est déjà affiché
+    fn run_chord(&mut self, action: ChordAction) {
+        let target = match action {
+            ChordAction::MainMenu => {
+                if self.current_menu != MenuState::Main {
+                    self.searching = false;
+                    self.game_search.clear();
+                    self.return_to_main();
+                    self.transition = Transition::start(Effect::SlideBack);
+                    self.audio.play_sound(SoundEffect::MenuBack);
+                }
+                return;
+            }
+            ChordAction::Games => MenuState::Games,
+            ChordAction::HighScores => MenuState::HighScores,
+            ChordAction::Statistics => MenuState::Statistics,
+            ChordAction::History => MenuState::History,
+            ChordAction::MusicPlayer => MenuState::MusicPlayer,
+            ChordAction::Settings => MenuState::Settings(SettingsTab::Audio),
+            ChordAction::About => MenuState::About,
+        };
+        let already_there = match (&self.current_menu, &target) {
+            (MenuState::Settings(_), MenuState::Settings(_)) => true,
+            (current, target) => current == target,
+        };
+        if already_there {
+            return;
+        }
+        if target == MenuState::History {
+            self.history_game = None;
+        }
+        self.navigate_to(target);
+        self.audio.play_sound(SoundEffect::MenuConfirm);
+    }
+
+    /// Texte collé dans le terminal : seul un champ de saisie actif le reçoit
+    pub fn handle_paste(&mut self, text: &str) {
+        if let Some(input) = &mut self.name_input {
+            input.insert_str(text);
+        } else if let Some(input) = &mut self.target_input {
+            input.insert_str(text.trim());
+            self.invalid_target = false;
+        } else if let Some(input) = &mut self.code_input {
+            input.insert_str(text.trim());
+            self.invalid_code = false;
+        } else if self.current_menu == MenuState::Games && self.searching {
+            self.game_search.insert_str(text);
+            self.list.reset();
+        }
+    }
+
+    /// Jeux correspondant à la recherche (nom ou description, sans casse)
+    fn filtered_games(&self) -> Vec<&GameInfo> {
+        let query = self.game_search.value().to_lowercase();
+        let games: Vec<&GameInfo> = self.games_list.iter().collect();
+        arrange(
+            &games,
+            &self.config_manager.get_game_list_config(),
+            self.show_hidden_games,
+        )
+        .into_iter()
+        .filter(|game| {
+            query.is_empty()
+                || game.name.to_lowercase().contains(&query)
+                || game.id.contains(&query)
+                || game.description.to_lowercase().contains(&query)
+        })
+        .collect()
+    }
+
+    /// Nom affiché d'un jeu à partir de son identifiant
+    fn game_info(&self, game_id: &str) -> Option<&GameInfo> {
+        self.games_list.iter().find(|game| game.id == game_id)
+    }
+
+    fn game_display_name<'a>(&'a self, game_id: &'a str) -> &'a str {
+        self.game_info(game_id)
+            .map_or(game_id, |game| game.name.as_str())
+    }
+
+    /// Déplace le jeu sélectionné d'un cran dans la liste Games et enregistre
+    /// le nouvel ordre. Désactivé pendant une recherche, où les voisins
+    /// affichés ne sont pas ceux de la liste complète
+    fn move_selected_game(&mut self, offset: isize) {
+        if !self.game_search.is_empty() {
+            return;
+        }
+
+        let shown = self.filtered_games();
+        let selected = self.list.selected();
+        // "Random Game" reste en bas de la liste
+        if selected >= shown.len() {
+            return;
+        }
+        let Some(target) = selected
+            .checked_add_signed(offset)
+            .filter(|&target| target < shown.len())
+        else {
+            return;
+        };
+        let (moved, neighbour) = (shown[selected].id.clone(), shown[target].id.clone());
+        let len = self.games_row_count();
+
+        // Échanger les deux jeux dans l'ordre complet, jeux masqués compris
+        let games: Vec<&GameInfo> = self.games_list.iter().collect();
+        let mut order: Vec<String> =
+            arrange(&games, &self.config_manager.get_game_list_config(), true)
+                .into_iter()
+                .map(|game| game.id.clone())
+                .collect();
+        let (Some(a), Some(b)) = (
+            order.iter().position(|id| *id == moved),
+            order.iter().position(|id| *id == neighbour),
+        ) else {
+            return;
         };
+        order.swap(a, b);
+
+        if let Err(e) = self
+            .config_manager
+            .update_game_list_config(|config| config.order = order)
+        {
+            eprintln!("Error saving game order: {e}");
+        }
+        if offset < 0 {
+            self.list.previous(len);
+        } else {
+            self.list.next(len);
+        }
+        self.audio.play_sound(crate::audio::SoundEffect::MenuSelect);
+    }
 
-        if max_items == 0 {
+    /// Masque le jeu sélectionné, ou le réaffiche s'il était masqué
+    fn toggle_selected_game_hidden(&mut self) {
+        let Some(game_id) = self.get_selected_game().map(str::to_string) else {
             return;
+        };
+
+        if let Err(e) = self.config_manager.update_game_list_config(|config| {
+            if config.hidden.contains(&game_id) {
+                config.hidden.retain(|id| *id != game_id);
+            } else {
+                config.hidden.push(game_id);
+            }
+        }) {
+            eprintln!("Error saving hidden games: {e}");
+        }
+
+        // Garder la sélection dans la liste qui a pu raccourcir
+        let len = self.games_row_count();
+        if self.list.selected() >= len {
+            self.list.last(len);
         }
+    }
 
-        self.selected_index = (self.selected_index + 1) % max_items;
-        self.list_state.select(Some(self.selected_index));
+    /// Gère la molette de la souris : un cran déplace la sélection d'un élément
+    pub fn handle_mouse(&mut self, mouse: MouseEvent) {
+        self.list.handle_mouse(mouse, self.item_count());
     }
 
-    fn previous_item(&mut self) {
-        let max_items = match &self.current_menu {
+    /// Nombre d'éléments sélectionnables dans le menu courant
+    fn item_count(&self) -> usize {
+        match &self.current_menu {
             MenuState::Main => self.main_options.len(),
-            MenuState::Games => self.games_list.len(),
+            MenuState::Resume => self.interrupted.len().max(1),
+            MenuState::Games => self.games_row_count(),
             MenuState::HighScores => {
                 let games_with_scores = self.highscore_manager.get_games_with_scores();
                 games_with_scores.len().max(1) // Au moins 1 pour "No scores yet"
@@ -337,29 +921,27 @@ impl MainMenu {
                 let scores = self.highscore_manager.get_scores(game_name);
                 scores.len().max(1) // Au moins 1 pour "No scores yet"
             }
+            MenuState::Modifiers(game_id) => Mutator::for_game(game_id).len() + 1, // + Start
+            MenuState::WeeklyChallenge => self
+                .highscore_manager
+                .get_scores(&self.weekly.leaderboard())
+                .len()
+                .max(1),
             MenuState::ConfirmClearScores(_) => 2, // Yes/No
+            MenuState::Statistics => self.statistics().len().max(1), // Au moins 1 pour "No games played"
+            MenuState::History => self.filtered_history().len().max(1),
             MenuState::MusicPlayer => self.music_tracks.len(),
-            MenuState::Settings => 3,
-            MenuState::AudioSettings => 5, // 5 paramètres audio
+            MenuState::Settings(tab) => Self::settings_row_count(*tab),
+            MenuState::SoundEffects => SoundEffect::ALL.len(),
+            MenuState::ScoreTargets => self.target_games().len().max(1),
             MenuState::About => 1,
-        };
-
-        if max_items == 0 {
-            return;
         }
-
-        self.selected_index = if self.selected_index == 0 {
-            max_items - 1
-        } else {
-            self.selected_index - 1
-        };
-        self.list_state.select(Some(self.selected_index));
     }
 
     fn select_current_item(&mut self) -> GameAction {
-        match self.current_menu {
+        match &self.current_menu {
             MenuState::Main => {
-                if let Some(option) = self.main_options.get(self.selected_index) {
+                if let Some(option) = self.main_options.get(self.list.selected()) {
                     match &option.action {
                         MenuAction::EnterSubMenu(menu_state) => {
                             self.navigate_to(menu_state.clone());
@@ -372,7 +954,10 @@ impl MainMenu {
                 }
             }
             MenuState::Games => {
-                if let Some(_game) = self.games_list.get(self.selected_index) {
+                if self.filtered_games().get(self.list.selected()).is_some() {
+                    GameAction::GameOver
+                } else if let Some(game_id) = self.pick_random_game() {
+                    self.random_game = Some(game_id);
                     GameAction::GameOver
                 } else {
                     GameAction::Continue
@@ -382,21 +967,55 @@ impl MainMenu {
                 self.play_selected_music();
                 GameAction::Continue
             }
-            MenuState::Settings => {
-                match self.selected_index {
+            MenuState::Settings(SettingsTab::Graphics) => {
+                self.toggle_graphics_setting();
+                GameAction::Continue
+            }
+            MenuState::Settings(SettingsTab::Gameplay) => {
+                match self.list.selected() {
                     0 => {
-                        // Audio Settings
-                        self.navigate_to(MenuState::AudioSettings);
+                        let name = &self.config_manager.get_gameplay_config().player_name;
+                        self.name_input = Some(TextInput::with_value(name, PLAYER_NAME_MAX_LEN));
+                    }
+                    1 => {
+                        self.toggle_question_marks();
+                    }
+                    2 => {
+                        self.toggle_pause_on_focus_loss();
+                    }
+                    3 => {
+                        self.cycle_pong_layout();
+                    }
+                    4 => {
+                        self.cycle_pong_difficulty();
+                    }
+                    5 => {
+                        self.toggle_split_timer();
+                    }
+                    6 => {
+                        self.cycle_platform_profile();
+                    }
+                    7 => {
+                        self.cycle_autosave();
+                    }
+                    8 | 9 => {
+                        self.cycle_player_color(self.list.selected() - 8);
                     }
                     _ => {
-                        self.go_back();
+                        self.navigate_to(MenuState::ScoreTargets);
                     }
                 }
                 GameAction::Continue
             }
+            MenuState::Settings(SettingsTab::Data) => {
+                self.select_data_action();
+                GameAction::Continue
+            }
+            // Onglet informatif
+            MenuState::Settings(SettingsTab::Controls) => GameAction::Continue,
             MenuState::HighScores => {
                 let games_with_scores = self.highscore_manager.get_games_with_scores();
-                if let Some(game_name) = games_with_scores.get(self.selected_index) {
+                if let Some(game_name) = games_with_scores.get(self.list.selected()) {
                     self.navigate_to(MenuState::HighScoresDetail(game_name.clone()));
                 }
                 GameAction::Continue
@@ -410,34 +1029,294 @@ impl MainMenu {
                 // Enter ne fait rien ici, utiliser Y/N
                 GameAction::Continue
             }
-            MenuState::AudioSettings | MenuState::About => {
+            // La boucle du menu reprend la partie sélectionnée
+            MenuState::Resume if !self.interrupted.is_empty() => GameAction::GameOver,
+            MenuState::Resume => {
+                self.go_back();
+                GameAction::Continue
+            }
+            MenuState::Statistics => {
+                // Historique du jeu sélectionné
+                if let Some((game_name, _)) = self.statistics().get(self.list.selected()) {
+                    self.history_game = Some(game_name.clone());
+                    self.navigate_to(MenuState::History);
+                }
+                GameAction::Continue
+            }
+            MenuState::History => GameAction::Continue,
+            MenuState::Settings(SettingsTab::Audio)
+                if self.list.selected() == THEME_PREVIEW_INDEX =>
+            {
+                self.audio.preview_sound_theme();
+                GameAction::Continue
+            }
+            MenuState::Settings(SettingsTab::Audio)
+                if self.list.selected() == CUSTOMIZE_EFFECTS_INDEX =>
+            {
+                self.navigate_to(MenuState::SoundEffects);
+                GameAction::Continue
+            }
+            MenuState::Settings(SettingsTab::Audio)
+                if self.list.selected() == RECONNECT_AUDIO_INDEX =>
+            {
+                self.reconnect_audio();
+                GameAction::Continue
+            }
+            MenuState::SoundEffects => {
+                self.audition_selected_effect();
+                GameAction::Continue
+            }
+            MenuState::ScoreTargets => {
+                if let Some((game_id, _)) = self.target_games().get(self.list.selected()) {
+                    let target = self
+                        .config_manager
+                        .get_gameplay_config()
+                        .score_targets
+                        .get(game_id)
+                        .map(u32::to_string)
+                        .unwrap_or_default();
+                    self.target_input = Some(TextInput::with_value(&target, SCORE_TARGET_MAX_LEN));
+                    self.invalid_target = false;
+                }
+                GameAction::Continue
+            }
+            // La boucle du menu lance le jeu du défi, voir `selected_challenge`
+            MenuState::WeeklyChallenge => GameAction::GameOver,
+            MenuState::Modifiers(game_id) => {
+                match Mutator::for_game(game_id).get(self.list.selected()) {
+                    Some(&mutator) => {
+                        self.toggle_mutator(mutator);
+                        GameAction::Continue
+                    }
+                    // Dernière ligne : lancer la partie, voir `selected_mutators`
+                    None => GameAction::GameOver,
+                }
+            }
+            MenuState::Settings(SettingsTab::Audio) | MenuState::About => {
                 self.go_back();
                 GameAction::Continue
             }
         }
     }
 
-    /// Navigue vers un nouveau menu en sauvegardant l'état actuel dans la pile
-    fn navigate_to(&mut self, new_menu: MenuState) {
-        // Recharger les scores si on entre dans le menu High Scores
-        if matches!(
-            new_menu,
-            MenuState::HighScores | MenuState::HighScoresDetail(_)
-        ) {
-            if let Err(e) = self.highscore_manager.reload() {
+    /// Totaux par jeu sur la période choisie, dans l'ordre alphabétique
+    fn statistics(&self) -> Vec<(String, GameSummary)> {
+        let now = chrono::Local::now();
+        let records: Vec<SessionRecord> = self
+            .history
+            .iter()
+            .filter(|record| self.stats_period.contains(record.timestamp, now))
+            .cloned()
+            .collect();
+        history::summarize(&records).into_iter().collect()
+    }
+
+    /// Parties correspondant aux filtres de History, de la plus récente à la
+    /// plus ancienne
+    fn filtered_history(&self) -> Vec<&SessionRecord> {
+        let now = chrono::Local::now();
+        self.history
+            .iter()
+            .rev()
+            .filter(|record| {
+                self.history_game
+                    .as_ref()
+                    .is_none_or(|game| &record.game == game)
+            })
+            .filter(|record| self.history_range.contains(record.timestamp, now))
+            .collect()
+    }
+
+    /// Passe au jeu suivant (ou précédent) du filtre de History, en passant
+    /// par "tous les jeux"
+    fn cycle_history_game(&mut self, forward: bool) {
+        let mut choices: Vec<Option<String>> = vec![None];
+        choices.extend(history::summarize(&self.history).into_keys().map(Some));
+
+        let current = choices
+            .iter()
+            .position(|choice| *choice == self.history_game)
+            .unwrap_or(0);
+        let next = if forward {
+            (current + 1) % choices.len()
+        } else {
+            (current + choices.len() - 1) % choices.len()
+        };
+        self.history_game = choices.swap_remove(next);
+        self.list.reset();
+    }
+
+    /// Navigue vers un nouveau menu en sauvegardant l'état actuel dans la pile
+    fn navigate_to(&mut self, new_menu: MenuState) {
+        // Le défi change chaque lundi
+        if new_menu == MenuState::WeeklyChallenge {
+            self.weekly = Challenge::current();
+        }
+        // Les modificateurs cochés pour un autre jeu ne s'appliquent pas ici
+        if let MenuState::Modifiers(game_id) = &new_menu {
+            self.mutators.retain(|mutator| mutator.applies_to(game_id));
+        }
+
+        // Recharger les scores si on entre dans le menu High Scores
+        if matches!(
+            new_menu,
+            MenuState::HighScores | MenuState::HighScoresDetail(_) | MenuState::WeeklyChallenge
+        ) {
+            if let Err(e) = self.highscore_manager.reload() {
                 eprintln!("Error reloading scores: {e}");
             }
         }
 
+        // Recalculer les statistiques depuis l'historique à chaque ouverture
+        if matches!(
+            new_menu,
+            MenuState::Statistics | MenuState::History | MenuState::HighScoresDetail(_)
+        ) {
+            self.history = history::load();
+        }
+
+        // Vérifier les mises à jour en arrière-plan la première fois qu'on ouvre About
+        if new_menu == MenuState::About && self.update_checker.is_none() {
+            self.update_checker = Some(UpdateChecker::spawn());
+        }
+
+        // Les popups de confirmation apparaissent en fondu, les sous-menus glissent
+        let effect = if matches!(
+            new_menu,
+            MenuState::ConfirmClearScores(_) | MenuState::Resume
+        ) {
+            Effect::FadeIn
+        } else {
+            Effect::SlideForward
+        };
+        self.transition = Transition::start(effect);
+        self.leave_music_player();
+
         // Sauvegarder le menu actuel dans la pile
         self.menu_history.push(self.current_menu.clone());
         // Passer au nouveau menu
         self.current_menu = new_menu;
-        self.selected_index = 0;
-        self.list_state.select(Some(0));
+        self.list.reset();
+        self.select_new_score();
+    }
+
+    /// Prend en compte les scores enregistrés pendant la partie qui vient de
+    /// se terminer : badges NEW dans High Scores, et annonce d'un record
+    /// Annonce quelques secondes en haut de l'écran
+    pub fn show_toast(&mut self, message: &str) {
+        self.toast = Some((message.to_string(), Instant::now()));
+    }
+
+    pub fn show_new_scores(&mut self, new_scores: Vec<NewScore>) {
+        // Le classement du défi est affiché au retour de sa partie
+        if self.current_menu == MenuState::WeeklyChallenge {
+            if let Err(e) = self.highscore_manager.reload() {
+                eprintln!("Error reloading scores: {e}");
+            }
+        }
+        if new_scores.is_empty() {
+            return;
+        }
+        self.toast = new_scores.last().map(|new_score| {
+            let message = if new_score.personal_best {
+                format!(
+                    "🏆 New personal best! {} pts",
+                    numbers::score(new_score.score)
+                )
+            } else {
+                format!(
+                    "🏅 New high score: #{} with {} pts",
+                    new_score.rank,
+                    numbers::score(new_score.score)
+                )
+            };
+            (message, Instant::now())
+        });
+        self.new_scores = new_scores;
+    }
+
+    /// Place la sélection sur un score tout juste ajouté en arrivant dans
+    /// High Scores, pour qu'il soit visible même en bas du classement
+    fn select_new_score(&mut self) {
+        let Some(new_score) = self.new_scores.last() else {
+            return;
+        };
+        let index = match &self.current_menu {
+            MenuState::HighScores => self
+                .highscore_manager
+                .get_games_with_scores()
+                .iter()
+                .position(|game| *game == new_score.game),
+            MenuState::HighScoresDetail(game) if *game == new_score.game => self
+                .highscore_manager
+                .get_scores(game)
+                .iter()
+                .position(|score| new_score.matches(score)),
+            MenuState::WeeklyChallenge => self
+                .highscore_manager
+                .get_scores(&self.weekly.leaderboard())
+                .iter()
+                .position(|score| new_score.matches(score)),
+            _ => None,
+        };
+        if let Some(index) = index {
+            self.list.jump_to(index, self.item_count());
+        }
+    }
+
+    /// Ouvre l'écran Resume s'il reste des parties interrompues : la
+    /// session précédente ne s'est pas terminée normalement
+    pub fn offer_resume(&mut self) {
+        if self.guest {
+            return;
+        }
+        self.interrupted = saves::interrupted();
+        if !self.interrupted.is_empty() {
+            self.navigate_to(MenuState::Resume);
+        }
+    }
+
+    /// Relit les parties interrompues au retour d'une partie ; l'écran
+    /// Resume se ferme quand il n'y a plus rien à reprendre
+    pub fn refresh_interrupted(&mut self) {
+        if self.current_menu != MenuState::Resume {
+            return;
+        }
+        self.interrupted = saves::interrupted();
+        if self.interrupted.is_empty() {
+            self.go_back();
+        } else {
+            let selected = self.list.selected();
+            self.list.jump_to(selected, self.interrupted.len());
+        }
+    }
+
+    /// Oublie la partie interrompue sélectionnée dans Resume
+    fn discard_selected_save(&mut self) {
+        let Some((game_id, _)) = self.interrupted.get(self.list.selected()) else {
+            return;
+        };
+        saves::discard(game_id);
+        self.audio.play_sound(crate::audio::SoundEffect::MenuBack);
+        self.refresh_interrupted();
+    }
+
+    /// Revient au menu principal en vidant l'historique de navigation
+    pub fn return_to_main(&mut self) {
+        self.leave_music_player();
+        self.menu_history.clear();
+        self.current_menu = MenuState::Main;
+        self.list.reset();
     }
 
     fn go_back(&mut self) {
+        if self.current_menu == MenuState::Games {
+            self.searching = false;
+            self.game_search.clear();
+        }
+
+        self.leave_music_player();
+
         // Remonter d'un niveau en utilisant la pile
         if let Some(previous_menu) = self.menu_history.pop() {
             self.current_menu = previous_menu;
@@ -445,23 +1324,47 @@ impl MainMenu {
             // Si la pile est vide, retourner au menu principal
             self.current_menu = MenuState::Main;
         }
-        self.selected_index = 0;
-        self.list_state.select(Some(0));
+        self.transition = Transition::start(Effect::SlideBack);
+        self.list.reset();
+    }
+
+    /// En quittant le Music Player, le morceau s'arrête sauf si la musique
+    /// de fond est activée dans les Audio Settings
+    fn leave_music_player(&mut self) {
+        if self.current_menu == MenuState::MusicPlayer && !self.audio.is_background_music() {
+            self.stop_music_player();
+        }
+    }
+
+    /// Arrête le morceau lancé depuis le Music Player, s'il y en a un
+    pub fn stop_music_player(&mut self) {
+        if self.current_playing.take().is_some() {
+            self.audio.stop_music();
+        }
+    }
+
+    /// Morceau du Music Player qui joue encore, avec sa variante
+    fn now_playing(&self) -> Option<String> {
+        let index = self.current_playing?;
+        self.audio.music_position()?;
+        let track = self.music_tracks.get(index)?;
+        let variant = track.variants.get(self.current_variant[index])?;
+        Some(format!("{} • {variant}", track.name))
     }
 
     fn next_variant(&mut self) {
-        if let Some(track) = self.music_tracks.get(self.selected_index) {
+        if let Some(track) = self.music_tracks.get(self.list.selected()) {
             if !track.variants.is_empty() {
-                let current = &mut self.current_variant[self.selected_index];
+                let current = &mut self.current_variant[self.list.selected()];
                 *current = (*current + 1) % track.variants.len();
             }
         }
     }
 
     fn previous_variant(&mut self) {
-        if let Some(track) = self.music_tracks.get(self.selected_index) {
+        if let Some(track) = self.music_tracks.get(self.list.selected()) {
             if !track.variants.is_empty() {
-                let current = &mut self.current_variant[self.selected_index];
+                let current = &mut self.current_variant[self.list.selected()];
                 *current = if *current == 0 {
                     track.variants.len() - 1
                 } else {
@@ -472,12 +1375,13 @@ impl MainMenu {
     }
 
     fn increase_audio_setting(&mut self) {
-        match self.selected_index {
+        match self.list.selected() {
             0 => {
-                // Master volume
+                // Master volume ; le régler rétablit le son coupé avec F10
                 let current = self.audio.get_master_volume();
                 let new_volume = (current + 0.1).min(1.0);
                 self.audio.set_master_volume(new_volume);
+                self.audio.set_muted(false);
             }
             1 => {
                 // Effects volume
@@ -499,6 +1403,22 @@ impl MainMenu {
                 // Music enabled - toggle on
                 self.audio.set_music_enabled(true);
             }
+            5 => {
+                // Background music - toggle on
+                self.audio.set_background_music(true);
+            }
+            6 => {
+                // Music ducking
+                // Arrondir au dixième pour retomber exactement sur OFF
+                let current = self.audio.get_music_ducking();
+                self.audio
+                    .set_music_ducking(((current + 0.1) * 10.0).round() / 10.0);
+            }
+            7 => {
+                // Sound theme - thème suivant
+                self.audio
+                    .set_sound_theme(self.audio.get_sound_theme().next());
+            }
             _ => {}
         }
         // Sauvegarder la configuration après modification
@@ -506,12 +1426,13 @@ impl MainMenu {
     }
 
     fn decrease_audio_setting(&mut self) {
-        match self.selected_index {
+        match self.list.selected() {
             0 => {
-                // Master volume
+                // Master volume ; le régler rétablit le son coupé avec F10
                 let current = self.audio.get_master_volume();
                 let new_volume = (current - 0.1).max(0.0);
                 self.audio.set_master_volume(new_volume);
+                self.audio.set_muted(false);
             }
             1 => {
                 // Effects volume
@@ -533,12 +1454,317 @@ impl MainMenu {
                 // Music enabled - toggle off
                 self.audio.set_music_enabled(false);
             }
+            5 => {
+                // Background music - toggle off
+                self.audio.set_background_music(false);
+            }
+            6 => {
+                // Music ducking
+                // Arrondir au dixième pour retomber exactement sur OFF
+                let current = self.audio.get_music_ducking();
+                self.audio
+                    .set_music_ducking(((current - 0.1) * 10.0).round() / 10.0);
+            }
+            7 => {
+                // Sound theme - thème précédent
+                self.audio
+                    .set_sound_theme(self.audio.get_sound_theme().previous());
+            }
             _ => {}
         }
         // Sauvegarder la configuration après modification
         self.save_audio_config();
     }
 
+    /// Effet sélectionné dans la page Sound Effects
+    fn selected_effect(&self) -> Option<SoundEffect> {
+        SoundEffect::ALL.get(self.list.selected()).copied()
+    }
+
+    fn audition_selected_effect(&mut self) {
+        if let Some(effect) = self.selected_effect() {
+            self.audio.audition_sound(effect);
+        }
+    }
+
+    fn toggle_selected_effect(&mut self) {
+        if let Some(effect) = self.selected_effect() {
+            let mut effect_override = self.audio.effect_override(effect);
+            effect_override.enabled = !effect_override.enabled;
+            self.audio.set_effect_override(effect, effect_override);
+            self.save_audio_config();
+        }
+    }
+
+    fn adjust_selected_effect_volume(&mut self, delta: f32) {
+        if let Some(effect) = self.selected_effect() {
+            let mut effect_override = self.audio.effect_override(effect);
+            // Arrondir au dixième pour retomber exactement sur 100 %
+            effect_override.volume = ((effect_override.volume + delta) * 10.0).round() / 10.0;
+            effect_override.volume = effect_override.volume.clamp(0.0, 2.0);
+            self.audio.set_effect_override(effect, effect_override);
+            self.save_audio_config();
+            self.audio.audition_sound(effect);
+        }
+    }
+
+    /// Inclut ou exclut les parties assistées du meilleur score des High Scores
+    fn toggle_best_excludes_assisted(&mut self) {
+        if let Err(e) = self.config_manager.update_display_config(|config| {
+            config.best_excludes_assisted = !config.best_excludes_assisted;
+        }) {
+            eprintln!("Erreur lors de la sauvegarde de la configuration d'affichage: {e}");
+        }
+    }
+
+    /// Nombre de lignes de chaque onglet des Settings
+    fn settings_row_count(tab: SettingsTab) -> usize {
+        match tab {
+            // 8 paramètres audio + aperçu du thème + effets + reconnexion
+            SettingsTab::Audio => 11,
+            SettingsTab::Graphics => 16,
+            SettingsTab::Controls => CONTROL_HINTS.len(),
+            SettingsTab::Gameplay => SCORE_TARGETS_INDEX + 1,
+            SettingsTab::Data => DATA_RESET_INDEX + 1,
+        }
+    }
+
+    /// Change d'onglet sans passer par la pile de navigation, pour qu'Échap
+    /// ramène directement au menu principal
+    fn switch_settings_tab(&mut self, tab: SettingsTab) {
+        self.current_menu = MenuState::Settings(tab);
+        self.name_input = None;
+        self.data_status = None;
+        self.list.reset();
+        self.audio.play_sound(crate::audio::SoundEffect::MenuSelect);
+    }
+
+    fn toggle_graphics_setting(&mut self) {
+        let row = self.list.selected();
+        if let Err(e) = self
+            .config_manager
+            .update_display_config(|config| match row {
+                0 => config.terminal_title = !config.terminal_title,
+                1 => config.title_progress = !config.title_progress,
+                2 => config.best_excludes_assisted = !config.best_excludes_assisted,
+                3 => config.color_scheme = config.color_scheme.next(),
+                4 => config.transparent_background = !config.transparent_background,
+                5 => config.glyph_set = config.glyph_set.next(),
+                6 => config.reduce_motion = !config.reduce_motion,
+                7 => config.splash_screen = !config.splash_screen,
+                8 => config.title_screen = !config.title_screen,
+                9 => config.menu_background = config.menu_background.next(),
+                10 => config.color_depth = config.color_depth.next(),
+                11 => config.ball_trail = config.ball_trail.next(),
+                12 => config.frame_skip = config.frame_skip.next(),
+                13 => config.clock_format = config.clock_format.next(),
+                14 => config.date_order = config.date_order.next(),
+                _ => config.timezone = config.timezone.next(),
+            })
+        {
+            eprintln!("Erreur lors de la sauvegarde de la configuration d'affichage: {e}");
+        }
+    }
+
+    fn select_data_action(&mut self) {
+        if self.guest && self.list.selected() >= DATA_OPEN_FOLDER_INDEX {
+            self.data_status = Some("Only the host can manage the data folder".to_string());
+            return;
+        }
+        let status = match self.list.selected() {
+            DATA_OPEN_FOLDER_INDEX => match storage::open_data_dir() {
+                Ok(()) => "Opened the data folder".to_string(),
+                Err(e) => format!("Could not open the data folder: {e}"),
+            },
+            DATA_BACKUP_INDEX => match backup::default_backup_path() {
+                Some(path) => match backup::backup(&path) {
+                    Ok(manifest) => format!(
+                        "Backed up {} files to {}",
+                        manifest.files.len(),
+                        path.display()
+                    ),
+                    Err(e) => format!("Backup failed: {e}"),
+                },
+                None => "Backup failed: no home folder".to_string(),
+            },
+            DATA_CLEAR_CACHES_INDEX => match storage::clear_caches() {
+                Ok(freed) => format!("Caches cleared, {} freed", storage::format_size(freed)),
+                Err(e) => format!("Could not clear caches: {e}"),
+            },
+            DATA_RESET_INDEX if !self.confirm_reset => {
+                self.confirm_reset = true;
+                "Press Enter again to reset all settings and scores".to_string()
+            }
+            DATA_RESET_INDEX => {
+                self.confirm_reset = false;
+                match self.reset_everything() {
+                    Ok(()) => "All settings and scores have been reset".to_string(),
+                    Err(e) => format!("Reset failed: {e}"),
+                }
+            }
+            // Lignes de fichiers : informatives
+            _ => return,
+        };
+        self.data_status = Some(status);
+    }
+
+    /// Remet les réglages par défaut et efface les scores et les caches
+    fn reset_everything(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        self.config_manager.reset()?;
+        self.audio
+            .apply_config(&self.config_manager.get_audio_config());
+        self.highscore_manager.clear_all_scores()?;
+        puzzles::clear_progress();
+        history::clear();
+        ghosts::clear();
+        splits::clear();
+        saves::clear();
+        recording::clear()?;
+        storage::clear_caches()?;
+        Ok(())
+    }
+
+    fn toggle_question_marks(&mut self) {
+        if let Err(e) = self.config_manager.update_gameplay_config(|config| {
+            config.minesweeper_question_marks = !config.minesweeper_question_marks;
+        }) {
+            eprintln!("Erreur lors de la sauvegarde de la configuration de jeu: {e}");
+        }
+    }
+
+    /// Jeux dont on peut viser un score : ceux qui ont déjà un classement
+    /// ou un objectif, dans l'ordre du menu Games, avec leur nom
+    fn target_games(&self) -> Vec<(String, String)> {
+        let scored = self.highscore_manager.get_games_with_scores();
+        let targets = &self.config_manager.get_gameplay_config().score_targets;
+        self.games_list
+            .iter()
+            .filter(|game| scored.contains(&game.id) || targets.contains_key(&game.id))
+            .map(|game| (game.id.clone(), game.name.clone()))
+            .collect()
+    }
+
+    /// Règle (ou efface avec `None`) l'objectif du jeu sélectionné dans Score
+    /// Targets
+    fn set_selected_target(&mut self, target: Option<u32>) {
+        let Some((game_id, _)) = self.target_games().into_iter().nth(self.list.selected()) else {
+            return;
+        };
+        if let Err(e) = self
+            .config_manager
+            .update_gameplay_config(|config| match target {
+                Some(target) => {
+                    config.score_targets.insert(game_id, target);
+                }
+                None => {
+                    config.score_targets.remove(&game_id);
+                }
+            })
+        {
+            eprintln!("Erreur lors de la sauvegarde de la configuration de jeu: {e}");
+        }
+    }
+
+    fn save_player_name(&mut self, name: String) {
+        let name = if name.is_empty() {
+            DEFAULT_PLAYER_NAME.to_string()
+        } else {
+            name
+        };
+        if let Err(e) = self
+            .config_manager
+            .update_gameplay_config(|config| config.player_name = name)
+        {
+            eprintln!("Erreur lors de la sauvegarde de la configuration de jeu: {e}");
+        }
+    }
+
+    fn toggle_pause_on_focus_loss(&mut self) {
+        if let Err(e) = self.config_manager.update_gameplay_config(|config| {
+            config.pause_on_focus_loss = !config.pause_on_focus_loss;
+        }) {
+            eprintln!("Erreur lors de la sauvegarde de la configuration de jeu: {e}");
+        }
+    }
+
+    fn cycle_pong_layout(&mut self) {
+        if let Err(e) = self.config_manager.update_gameplay_config(|config| {
+            config.pong_layout = config.pong_layout.next();
+        }) {
+            eprintln!("Erreur lors de la sauvegarde de la configuration de jeu: {e}");
+        }
+    }
+
+    fn cycle_pong_difficulty(&mut self) {
+        if let Err(e) = self.config_manager.update_gameplay_config(|config| {
+            config.pong_difficulty = config.pong_difficulty.next();
+        }) {
+            eprintln!("Erreur lors de la sauvegarde de la configuration de jeu: {e}");
+        }
+    }
+
+    fn toggle_split_timer(&mut self) {
+        if let Err(e) = self.config_manager.update_gameplay_config(|config| {
+            config.split_timer = !config.split_timer;
+        }) {
+            eprintln!("Erreur lors de la sauvegarde de la configuration de jeu: {e}");
+        }
+    }
+
+    fn cycle_platform_profile(&mut self) {
+        if let Err(e) = self.config_manager.update_gameplay_config(|config| {
+            config.platform_profile = config.platform_profile.next();
+        }) {
+            eprintln!("Erreur lors de la sauvegarde de la configuration de jeu: {e}");
+        }
+    }
+
+    fn cycle_autosave(&mut self) {
+        if let Err(e) = self.config_manager.update_gameplay_config(|config| {
+            config.autosave = config.autosave.next();
+        }) {
+            eprintln!("Erreur lors de la sauvegarde de la configuration de jeu: {e}");
+        }
+    }
+
+    /// Passe le joueur `player` (0 ou 1) à la couleur suivante que l'on
+    /// distingue de celle de l'autre joueur
+    fn cycle_player_color(&mut self, player: usize) {
+        if let Err(e) = self.config_manager.update_gameplay_config(|config| {
+            let mut colors = PlayerColor::pair(config.player_colors);
+            colors[player] = colors[player].next_distinct_from(colors[1 - player]);
+            config.player_colors = colors;
+        }) {
+            eprintln!("Erreur lors de la sauvegarde de la configuration de jeu: {e}");
+        }
+    }
+
+    /// Indique si la perte du focus doit suspendre la musique et les parties
+    pub fn pause_on_focus_loss(&self) -> bool {
+        self.config_manager
+            .get_gameplay_config()
+            .pause_on_focus_loss
+    }
+
+    /// Relit les réglages audio enregistrés pendant une partie (volume,
+    /// son coupé, options du menu de pause)
+    pub fn reload_audio_config(&mut self) {
+        self.audio
+            .apply_config(&self.config_manager.get_audio_config());
+    }
+
+    /// Reconstruit la sortie audio sur le périphérique par défaut actuel,
+    /// par exemple après avoir branché un casque
+    fn reconnect_audio(&mut self) {
+        if AudioManager::reconnect() {
+            // Sans périphérique au lancement, le son avait été coupé
+            self.reload_audio_config();
+            self.show_toast("🔊 Audio reconnected");
+        } else {
+            self.show_toast("🔇 No audio device found");
+        }
+    }
+
     fn save_audio_config(&mut self) {
         let current_audio_config = self.audio.get_current_config();
         if let Err(e) = self.config_manager.update_audio_config(|config| {
@@ -629,40 +1855,82 @@ impl MainMenu {
 
     /// Jouer la musique actuellement sélectionnée
     fn play_selected_music(&mut self) {
-        self.play_music_at_index(self.selected_index);
+        self.play_music_at_index(self.list.selected());
     }
 
-    /// Rejouer la musique qui est actuellement en cours de lecture
-    fn replay_current_music(&mut self) {
-        if let Some(playing_index) = self.current_playing {
-            self.play_music_at_index(playing_index);
+    pub fn get_selected_game(&self) -> Option<&str> {
+        match &self.current_menu {
+            MenuState::Games => self
+                .filtered_games()
+                .get(self.list.selected())
+                .map(|g| g.id.as_str()),
+            MenuState::WeeklyChallenge => Some(self.weekly.game_id),
+            MenuState::Modifiers(game_id) => Some(game_id.as_str()),
+            MenuState::Resume => self
+                .interrupted
+                .get(self.list.selected())
+                .map(|(game_id, _)| game_id.as_str()),
+            _ => None,
         }
     }
 
-    pub fn get_selected_game(&self) -> Option<&str> {
-        if self.current_menu == MenuState::Games {
-            self.games_list
-                .get(self.selected_index)
-                .map(|g| g.name.as_str())
+    /// Modificateurs à appliquer à la partie lancée depuis l'écran Modifiers
+    pub fn selected_mutators(&self) -> Vec<Mutator> {
+        if matches!(self.current_menu, MenuState::Modifiers(_)) {
+            self.mutators.clone()
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Ouvre l'écran Modifiers du jeu sélectionné, s'il en propose
+    fn open_modifiers(&mut self) {
+        let Some(game_id) = self.get_selected_game().map(str::to_string) else {
+            return;
+        };
+        if Mutator::for_game(&game_id).is_empty() {
+            return;
+        }
+        self.navigate_to(MenuState::Modifiers(game_id));
+        self.audio.play_sound(crate::audio::SoundEffect::MenuSelect);
+    }
+
+    fn toggle_mutator(&mut self, mutator: Mutator) {
+        if let Some(index) = self.mutators.iter().position(|&m| m == mutator) {
+            self.mutators.remove(index);
         } else {
-            None
+            self.mutators.push(mutator);
         }
     }
 
+    /// Défi à appliquer à la partie lancée depuis son écran
+    pub fn selected_challenge(&self) -> Option<Challenge> {
+        (self.current_menu == MenuState::WeeklyChallenge).then_some(self.weekly)
+    }
+
+    /// Jeu sélectionné dans Games dont l'aperçu n'a pas encore été créé
+    pub fn preview_needed(&self) -> Option<String> {
+        self.get_selected_game()
+            .filter(|id| !self.previews.contains_key(*id))
+            .map(str::to_string)
+    }
+
+    /// Garde le jeu créé pour dessiner son aperçu ; `None` si le jeu n'a pas
+    /// pu être créé, pour ne pas réessayer
+    pub fn add_preview(&mut self, game_id: String, game: Option<Box<dyn Game>>) {
+        self.previews.insert(game_id, game);
+    }
+
     pub fn draw(&mut self, frame: &mut Frame) {
+        if self.transition.is_some_and(|t| t.is_finished()) {
+            self.transition = None;
+        }
         draw_main_menu(frame, self);
     }
 
-    pub fn update(&mut self) {
-        // Gérer la boucle de musique si on est dans le music player
-        if self.current_menu == MenuState::MusicPlayer
-            && self.current_playing.is_some()
-            && self.audio.is_music_enabled()
-            && self.audio.is_music_empty()
-        {
-            // Relancer la musique qui était en cours de lecture (pas celle sélectionnée)
-            self.replay_current_music();
-        }
+    /// Indique si une transition est en cours, pour redessiner plus souvent
+    pub fn animating(&self) -> bool {
+        self.transition.is_some()
     }
 
     /// Nettoie les ressources audio avant fermeture
@@ -675,68 +1943,122 @@ fn draw_main_menu(frame: &mut Frame, app: &mut MainMenu) {
     let area = frame.area();
 
     // Fond sombre élégant
-    let background = Block::new().style(Style::default().bg(Color::Rgb(15, 20, 25)));
-    frame.render_widget(background, area);
-
-    // Layout simple et propre
-    let chunks = Layout::vertical([
-        Constraint::Length(4), // Header
-        Constraint::Min(0),    // Zone principale
-        Constraint::Length(3), // Footer
-    ])
-    .split(area);
+    style::fill_background(frame, style::background());
 
     // === HEADER ===
     let title = match &app.current_menu {
         MenuState::Main => "TERMPLAY",
+        MenuState::Resume => "RESUME",
         MenuState::Games => "GAMES",
+        MenuState::Modifiers(_) => "MODIFIERS",
+        MenuState::WeeklyChallenge => "WEEKLY CHALLENGE",
         MenuState::HighScores => "HIGH SCORES",
         MenuState::HighScoresDetail(_) => "LEADERBOARD",
         MenuState::ConfirmClearScores(_) => "CONFIRM DELETION",
+        MenuState::Statistics => "STATISTICS",
+        MenuState::History => "HISTORY",
         MenuState::MusicPlayer => "MUSIC PLAYER",
-        MenuState::Settings => "SETTINGS",
-        MenuState::AudioSettings => "AUDIO SETTINGS",
+        MenuState::Settings(_) => "SETTINGS",
+        MenuState::SoundEffects => "SOUND EFFECTS",
+        MenuState::ScoreTargets => "SCORE TARGETS",
         MenuState::About => "ABOUT",
     };
 
     let subtitle = match &app.current_menu {
         MenuState::Main => "Terminal Mini-Games Collection".to_string(),
+        MenuState::Resume => "The last session ended unexpectedly".to_string(),
         MenuState::Games => "Choose your adventure".to_string(),
+        MenuState::Modifiers(game_id) => {
+            format!(
+                "Change the rules of your next {} game",
+                app.game_display_name(game_id)
+            )
+        }
+        MenuState::WeeklyChallenge => {
+            let days_left = app.weekly.days_left(chrono::Local::now().date_naive());
+            format!(
+                "Week {} of {} • {days_left} day{} left",
+                app.weekly.week,
+                app.weekly.year,
+                if days_left == 1 { "" } else { "s" }
+            )
+        }
         MenuState::HighScores => "Best scores and achievements".to_string(),
         MenuState::HighScoresDetail(game_name) => format!("Top scores for {game_name}"),
         MenuState::ConfirmClearScores(game_name) => {
             format!("Are you sure you want to delete all scores for {game_name}?")
         }
+        MenuState::Statistics => format!(
+            "Play time and sessions per game, {}",
+            app.stats_period.label()
+        ),
+        MenuState::History => match &app.history_game {
+            Some(game_id) => format!(
+                "{} sessions, {}",
+                app.game_display_name(game_id),
+                app.history_range.label()
+            ),
+            None => format!("All sessions, {}", app.history_range.label()),
+        },
         MenuState::MusicPlayer => "Listen to game soundtracks".to_string(),
-        MenuState::Settings => "Configure your experience".to_string(),
-        MenuState::AudioSettings => "Adjust audio and music settings".to_string(),
+        MenuState::Settings(tab) => tab.description().to_string(),
+        MenuState::SoundEffects => "Fine-tune each sound effect".to_string(),
+        MenuState::ScoreTargets => "A personal score to aim for in each game".to_string(),
         MenuState::About => "Information about TermPlay".to_string(),
     };
 
-    let header_text = vec![
-        Line::from(vec![
-            "🎮 ".cyan().bold(),
+    // Titre du menu principal en grandes lettres quand la place le permet
+    let big_title = match app.current_menu {
+        MenuState::Main if area.height >= BIG_TITLE_MIN_HEIGHT => bigtext::banner(
+            title,
+            Font::Compact,
+            Style::new().yellow().bold(),
+            area.width.saturating_sub(2),
+        ),
+        _ => None,
+    };
+    let mut header_text = big_title.unwrap_or_else(|| {
+        vec![Line::from(vec![
+            format!("{} ", glyphs::icon("🎮")).cyan().bold(),
             title.yellow().bold(),
-            " 🎮".cyan().bold(),
-        ]),
-        Line::from(subtitle.as_str().magenta()),
-    ];
+            format!(" {}", glyphs::icon("🎮")).cyan().bold(),
+        ])]
+    });
+    header_text.push(Line::from(subtitle.as_str().magenta()));
+
+    // Layout simple et propre
+    let chunks = Layout::vertical([
+        Constraint::Length(header_text.len() as u16 + 2), // Header
+        Constraint::Min(0),                               // Zone principale
+        Constraint::Length(3),                            // Footer
+    ])
+    .split(area);
 
     let header = Paragraph::new(header_text)
         .alignment(Alignment::Center)
         .block(
             Block::bordered()
-                .title(" Game Status ".white().bold())
+                .title(" Game Status ".fg(style::text()).bold())
                 .border_style(Style::new().cyan())
-                .style(Style::default().bg(Color::Rgb(25, 35, 45))),
+                .style(Style::default().bg(style::surface())),
         );
     frame.render_widget(header, chunks[0]);
 
     // === ZONE PRINCIPALE ===
     match &app.current_menu {
         MenuState::Main => draw_main_options(frame, chunks[1], app),
+        MenuState::Resume => draw_resume_menu(frame, chunks[1], app),
         MenuState::Games => draw_games_menu(frame, chunks[1], app),
+        MenuState::Modifiers(game_id) => {
+            let game_id = game_id.clone();
+            draw_modifiers_menu(frame, chunks[1], app, &game_id)
+        }
+        MenuState::WeeklyChallenge => draw_weekly_challenge(frame, chunks[1], app),
         MenuState::HighScores => draw_highscores_menu(frame, chunks[1], app),
+        MenuState::HighScoresDetail(game_name) if app.score_chart => {
+            let game_name_clone = game_name.clone();
+            draw_highscores_chart(frame, chunks[1], app, &game_name_clone)
+        }
         MenuState::HighScoresDetail(game_name) => {
             let game_name_clone = game_name.clone();
             draw_highscores_detail(frame, chunks[1], app, &game_name_clone)
@@ -745,10 +2067,19 @@ fn draw_main_menu(frame: &mut Frame, app: &mut MainMenu) {
             let game_name_clone = game_name.clone();
             draw_confirm_clear_scores(frame, chunks[1], &game_name_clone)
         }
+        MenuState::Statistics => draw_statistics_menu(frame, chunks[1], app),
+        MenuState::History => draw_history_menu(frame, chunks[1], app),
         MenuState::MusicPlayer => draw_music_player(frame, chunks[1], app),
-        MenuState::Settings => draw_settings_menu(frame, chunks[1], app),
-        MenuState::AudioSettings => draw_audio_settings_menu(frame, chunks[1], app),
-        MenuState::About => draw_about_menu(frame, chunks[1]),
+        MenuState::Settings(tab) => {
+            let tab = *tab;
+            draw_settings_menu(frame, chunks[1], app, tab)
+        }
+        MenuState::SoundEffects => draw_sound_effects_menu(frame, chunks[1], app),
+        MenuState::ScoreTargets => draw_score_targets_menu(frame, chunks[1], app),
+        MenuState::About => draw_about_menu(frame, chunks[1], app),
+    }
+    if let Some(transition) = app.transition {
+        transition.apply(frame.buffer_mut(), chunks[1]);
     }
 
     // === FOOTER ===
@@ -757,148 +2088,640 @@ fn draw_main_menu(frame: &mut Frame, app: &mut MainMenu) {
         MenuState::MusicPlayer => {
             "↑↓ Select Track • ←→ Change Variant • Space/Enter Play • S Stop • Esc/Q Back"
         }
-        MenuState::AudioSettings => "↑↓ Select Setting • ←→ Adjust Value • Esc/Q Back",
-        MenuState::HighScoresDetail(_) => "C Clear Scores • Esc/Q Back",
+        MenuState::Settings(SettingsTab::Audio) => {
+            "Tab Next Tab • ↑↓ Select • ←→ Adjust Value • Enter Preview/Open/Back • Esc/Q Back"
+        }
+        MenuState::SoundEffects => {
+            "↑↓ Select Effect • Space/Enter Play • ←→ Volume • D Enable/Disable • Esc/Q Back"
+        }
+        MenuState::ScoreTargets if app.target_input.is_some() => {
+            "Type a Score (e.g. 50k) • Enter Save (empty clears) • Esc Cancel"
+        }
+        MenuState::ScoreTargets => "↑↓ Select Game • Enter Set Target • D Clear • Esc/Q Back",
+        MenuState::HighScores => {
+            "↑↓ Select Game • Enter View • A Include/Exclude Assisted Runs • Esc/Q Back"
+        }
+        MenuState::WeeklyChallenge => "Enter Play • ↑↓ Scroll • Esc/Q Back",
+        MenuState::Modifiers(_) => "↑↓ Move • Enter Toggle/Start • Esc/Q Back",
+        MenuState::HighScoresDetail(_) if app.score_chart => {
+            "V List View • C Clear Scores • Esc/Q Back"
+        }
+        MenuState::HighScoresDetail(_) => "↑↓ Scroll • V Graph View • C Clear Scores • Esc/Q Back",
+        MenuState::Statistics => {
+            "↑↓ Select Game • Tab Period • Enter Game History • H Full History • Esc/Q Back"
+        }
+        MenuState::History => "↑↓ Scroll • ←→ Filter Game • Tab Date Range • Esc/Q Back",
+        MenuState::Games if app.code_input.is_some() => {
+            "Type or Paste a Share Code • Enter Play • Esc Cancel"
+        }
+        MenuState::Games if app.searching => "Type to Filter • ↑↓ Move • Enter Play • Esc Clear",
+        MenuState::Games => {
+            "↑↓ Move • Enter Play • M Modifiers • / Search • C Play Code • Shift+↑↓ Reorder • H Hide • V Show Hidden • Esc/Q Back"
+        }
+        MenuState::Settings(_) if app.name_input.is_some() => {
+            "Type Your Name • Enter Save • Esc Cancel"
+        }
+        MenuState::Settings(_) => "←→/Tab Switch Tab • ↑↓ Select • Enter Toggle/Run • Esc/Q Back",
         MenuState::ConfirmClearScores(_) => "Y Yes • N No",
+        MenuState::Resume => "↑↓ Select Game • Enter Resume • D Discard • Esc/Q Later",
         _ => "Arrow Keys Move • Enter Select • Esc/Q Back",
     };
 
     let footer_text = vec![Line::from(vec![
         "Controls: ".gray(),
-        controls.white().bold(),
+        controls.fg(style::text()).bold(),
     ])];
 
+    let mut footer_block = Block::bordered()
+        .title(" Controls ".fg(style::text()).bold())
+        .border_style(Style::new().blue())
+        .style(Style::default().bg(style::surface()));
+    // Musique de fond : rappeler ce qui joue et d'où elle vient
+    if app.current_menu != MenuState::MusicPlayer {
+        if let Some(now_playing) = app.now_playing() {
+            footer_block = footer_block.title(
+                Line::from(vec![
+                    format!(" {} {now_playing} ", glyphs::icon("🎵")).magenta(),
+                    "from Music Player ".gray(),
+                ])
+                .right_aligned(),
+            );
+        }
+    }
+    // Tâches de fond en cours (mise à jour, musique…)
+    if let Some(status) = jobs::status() {
+        footer_block = footer_block.title_bottom(
+            format!(" {} {status}… ", glyphs::icon("⏳"))
+                .gray()
+                .into_right_aligned_line(),
+        );
+    }
     let footer = Paragraph::new(footer_text)
         .alignment(Alignment::Center)
-        .block(
-            Block::bordered()
-                .title(" Controls ".white().bold())
-                .border_style(Style::new().blue())
-                .style(Style::default().bg(Color::Rgb(25, 35, 45))),
-        );
+        .block(footer_block);
     frame.render_widget(footer, chunks[2]);
+
+    let background = app.config_manager.get_display_config().menu_background;
+    app.wallpaper.draw(frame.buffer_mut(), background);
+
+    draw_toast(frame, app);
+    draw_chord_hint(frame, app, chunks[2]);
 }
 
-fn draw_main_options(frame: &mut Frame, area: Rect, app: &mut MainMenu) {
-    let items: Vec<ListItem> = app
-        .main_options
-        .iter()
-        .map(|option| {
-            let content = vec![Line::from(vec![
-                Span::styled("  ", Style::default()),
-                Span::styled(&option.title, Style::default().fg(Color::White).bold()),
-                Span::styled("  -  ", Style::default().fg(Color::Gray)),
-                Span::styled(&option.description, Style::default().fg(Color::LightBlue)),
-            ])];
-            ListItem::new(content)
+/// Suites possibles pendant la saisie d'un raccourci, au-dessus du pied de page
+fn draw_chord_hint(frame: &mut Frame, app: &mut MainMenu, footer: Rect) {
+    let pending: String = app.chords.pending(Instant::now()).iter().collect();
+    if pending.is_empty() {
+        return;
+    }
+
+    let lines: Vec<Line> = app
+        .chords
+        .completions()
+        .into_iter()
+        .map(|(keys, action)| {
+            Line::from(vec![
+                format!(" {:<3}", keys.iter().collect::<String>())
+                    .yellow()
+                    .bold(),
+                action.name().fg(style::text()),
+            ])
         })
         .collect();
+    let area = frame.area();
+    let width = (lines.iter().map(Line::width).max().unwrap_or(0) as u16 + 3)
+        .max(pending.len() as u16 + 6)
+        .min(area.width);
+    let height = (lines.len() as u16 + 2).min(footer.y.saturating_sub(area.y));
+    let popup = Rect {
+        x: area.right().saturating_sub(width + 1).max(area.x),
+        y: footer.y.saturating_sub(height),
+        width,
+        height,
+    };
 
-    let list = List::new(items)
-        .block(
+    frame.render_widget(Clear, popup);
+    frame.render_widget(
+        Paragraph::new(lines).block(
             Block::bordered()
-                .title(" Main Menu ".white().bold())
-                .border_style(Style::new().green())
-                .style(Style::default().bg(Color::Rgb(10, 15, 20))),
-        )
-        .style(Style::default().fg(Color::White))
-        .highlight_style(
-            Style::default()
-                .bg(Color::Rgb(0, 100, 200))
-                .fg(Color::White)
-                .add_modifier(Modifier::BOLD),
-        )
-        .highlight_symbol("▶ ");
+                .title(format!(" {pending}… ").yellow().bold())
+                .border_style(Style::new().yellow())
+                .style(Style::default().bg(style::surface())),
+        ),
+        popup,
+    );
+}
+
+/// Annonce en haut de l'écran, par-dessus l'en-tête, jusqu'à son expiration
+fn draw_toast(frame: &mut Frame, app: &mut MainMenu) {
+    let Some((message, shown_at)) = &app.toast else {
+        return;
+    };
+    if shown_at.elapsed() >= TOAST_DURATION {
+        app.toast = None;
+        return;
+    }
+
+    let line = Line::from(glyphs::text(message).into_owned().yellow().bold());
+    let area = frame.area();
+    let width = (line.width() as u16 + 4).min(area.width);
+    let height = 3.min(area.height);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + 1.min(area.height - height),
+        width,
+        height,
+    };
 
-    frame.render_stateful_widget(list, area, &mut app.list_state);
+    frame.render_widget(Clear, popup);
+    frame.render_widget(
+        Paragraph::new(line).centered().block(
+            Block::bordered()
+                .border_style(Style::new().yellow())
+                .style(Style::default().bg(style::surface())),
+        ),
+        popup,
+    );
 }
 
-fn draw_games_menu(frame: &mut Frame, area: Rect, app: &mut MainMenu) {
+fn draw_main_options(frame: &mut Frame, area: Rect, app: &mut MainMenu) {
     let items: Vec<ListItem> = app
-        .games_list
+        .main_options
+        .iter()
+        .map(|option| {
+            let content = vec![Line::from(vec![
+                Span::styled("  ", Style::default()),
+                Span::styled(
+                    glyphs::text(&option.title),
+                    Style::default().fg(style::text()).bold(),
+                ),
+                Span::styled("  -  ", Style::default().fg(Color::Gray)),
+                Span::styled(&option.description, Style::default().fg(Color::LightBlue)),
+            ])];
+            ListItem::new(content)
+        })
+        .collect();
+
+    let block = Block::bordered()
+        .title(" Main Menu ".fg(style::text()).bold())
+        .border_style(Style::new().green())
+        .style(Style::default().bg(style::panel()));
+    let highlight = style::focus(Color::Rgb(0, 100, 200));
+
+    app.list.render(frame, area, items, block, highlight);
+}
+
+fn draw_games_menu(frame: &mut Frame, area: Rect, app: &mut MainMenu) {
+    // Champ du code de partage au-dessus de la liste pendant la saisie
+    let area = if let Some(input) = &app.code_input {
+        let [code_area, list_area] =
+            Layout::vertical([Constraint::Length(3), Constraint::Min(0)]).areas(area);
+        let (title, color) = if app.invalid_code {
+            (" Invalid Code ", Color::Red)
+        } else {
+            (" Play from Code ", Color::Cyan)
+        };
+        input.render(
+            frame,
+            code_area,
+            Block::bordered()
+                .title(title.fg(color).bold())
+                .border_style(Style::new().fg(color))
+                .style(Style::default().bg(style::panel())),
+            Style::default().fg(style::text()),
+            true,
+        );
+        list_area
+    } else {
+        area
+    };
+
+    let area = if app.searching || !app.game_search.is_empty() {
+        let [search_area, list_area] =
+            Layout::vertical([Constraint::Length(3), Constraint::Min(0)]).areas(area);
+        app.game_search.render(
+            frame,
+            search_area,
+            Block::bordered()
+                .title(format!(" {} Search ", glyphs::icon("🔍")).cyan().bold())
+                .border_style(Style::new().cyan())
+                .style(Style::default().bg(style::panel())),
+            Style::default().fg(style::text()),
+            app.searching,
+        );
+        list_area
+    } else {
+        area
+    };
+
+    let games: Vec<GameInfo> = app.filtered_games().into_iter().cloned().collect();
+    let hidden_games = &app.config_manager.get_game_list_config().hidden;
+    if games.is_empty() {
+        let message = if app.game_search.is_empty() {
+            "All games are hidden, press V to show them"
+        } else {
+            "No game matches your search"
+        };
+        let message = Paragraph::new(message)
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(Color::Gray))
+            .block(
+                Block::bordered()
+                    .title(" Available Games ".green().bold())
+                    .border_style(Style::new().green())
+                    .style(Style::default().bg(style::panel())),
+            );
+        frame.render_widget(message, area);
+        return;
+    }
+
+    // Aperçu du jeu sélectionné à droite, si la place le permet
+    let show_preview = area.width >= GAME_PREVIEW_MIN_WIDTH;
+    let [list_area, preview_area] = if show_preview {
+        Layout::horizontal([Constraint::Min(0), Constraint::Length(GAME_PREVIEW_WIDTH)]).areas(area)
+    } else {
+        [area, Rect::default()]
+    };
+
+    let items: Vec<ListItem> = games
         .iter()
         .map(|game| {
-            let icon = match game.name.as_str() {
-                "snake" => "🐍",
-                "tetris" => "🧩",
-                "pong" => "🏓",
-                "2048" => "🔢",
-                "Minesweeper" => "💣",
-                "Breakout" => "🧱",
-                "Game of Life" => "🧬",
-                _ => "🎮",
+            let icon = glyphs::icon(game.icon);
+            let hidden = hidden_games.contains(&game.id);
+            let name_color = if hidden {
+                Color::DarkGray
+            } else {
+                style::text()
             };
 
-            let content = vec![Line::from(vec![
+            let mut spans = vec![
                 Span::styled(
                     format!("  {icon} "),
                     Style::default().fg(Color::Green).bold(),
                 ),
                 Span::styled(
                     game.name.to_uppercase(),
-                    Style::default().fg(Color::White).bold(),
+                    Style::default().fg(name_color).bold(),
                 ),
-                Span::styled("  -  ", Style::default().fg(Color::Gray)),
-                Span::styled(&game.description, Style::default().fg(Color::LightBlue)),
-            ])];
-            ListItem::new(content)
+                Span::styled(
+                    if hidden { " (hidden)" } else { "" },
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ];
+            // La description passe dans le panneau d'aperçu quand il est affiché
+            if !show_preview {
+                spans.push(Span::styled("  -  ", Style::default().fg(Color::Gray)));
+                spans.push(Span::styled(
+                    &game.description,
+                    Style::default().fg(Color::LightBlue),
+                ));
+            }
+            ListItem::new(Line::from(spans))
         })
+        .chain(std::iter::once(ListItem::new(Line::from(vec![
+            Span::styled(
+                format!("  {} ", glyphs::icon("🎲")),
+                Style::default().fg(Color::Yellow).bold(),
+            ),
+            Span::styled("RANDOM GAME", Style::default().fg(Color::Yellow).bold()),
+            Span::styled(
+                if show_preview { "" } else { "  -  Surprise me" },
+                Style::default().fg(Color::Gray),
+            ),
+        ]))))
         .collect();
 
-    let list = List::new(items)
-        .block(
-            Block::bordered()
-                .title(" Available Games ".green().bold())
-                .border_style(Style::new().green())
-                .style(Style::default().bg(Color::Rgb(10, 15, 20))),
-        )
-        .style(Style::default().fg(Color::White))
-        .highlight_style(
-            Style::default()
-                .bg(Color::Rgb(0, 150, 50))
-                .fg(Color::White)
-                .add_modifier(Modifier::BOLD),
-        )
-        .highlight_symbol("▶ ");
+    let block = Block::bordered()
+        .title(" Available Games ".green().bold())
+        .border_style(Style::new().green())
+        .style(Style::default().bg(style::panel()));
+    let highlight = style::focus(Color::Rgb(0, 150, 50));
+
+    app.list.render(frame, list_area, items, block, highlight);
 
-    frame.render_stateful_widget(list, area, &mut app.list_state);
+    match games.get(app.list.selected()) {
+        Some(game) => draw_game_preview(frame, preview_area, app, game),
+        None => draw_random_game_preview(frame, preview_area),
+    }
 }
 
-fn draw_settings_menu(frame: &mut Frame, area: Rect, app: &mut MainMenu) {
-    let settings_options = [
-        "🔊 Audio Settings",
-        "🎨 Graphics Settings (Coming soon)",
-        "⌨️ Controls Settings (Coming soon)",
+/// Panneau de droite quand "Random Game" est sélectionné
+fn draw_random_game_preview(frame: &mut Frame, area: Rect) {
+    if area.is_empty() {
+        return;
+    }
+    let block = Block::bordered()
+        .title(" Random Game ".yellow().bold())
+        .border_style(Style::new().yellow())
+        .style(Style::default().bg(style::panel()));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let lines = vec![
+        Line::from(glyphs::icon("🎲").yellow().bold()),
+        Line::from(""),
+        Line::from(
+            "Press Enter to play a game picked at random from the list.".fg(Color::LightBlue),
+        ),
+        Line::from(""),
+        Line::from("The games you played last come up less often.".fg(style::text())),
     ];
+    let [text_area] = Layout::vertical([Constraint::Length(lines.len() as u16 + 4)])
+        .flex(Flex::Center)
+        .areas(inner);
+    frame.render_widget(
+        Paragraph::new(lines).centered().wrap(Wrap { trim: true }),
+        text_area,
+    );
+}
+
+/// Panneau de droite du menu Games : aperçu dessiné par le jeu sélectionné,
+/// puis sa présentation détaillée et ses touches quand la hauteur le permet
+fn draw_game_preview(frame: &mut Frame, area: Rect, app: &MainMenu, game: &GameInfo) {
+    if area.is_empty() {
+        return;
+    }
+    let block = Block::bordered()
+        .title(format!(" {} ", game.name).green().bold())
+        .border_style(Style::new().green())
+        .style(Style::default().bg(style::panel()));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    // Détails sous l'aperçu, qui garde au moins GAME_PREVIEW_MIN_BOARD lignes ;
+    // la hauteur du texte replié est estimée d'après sa longueur
+    let details = game.details();
+    let text_height = (details.chars().count() as u16).div_ceil(inner.width.max(1)) + 1;
+    let mut lines = vec![Line::from(details.fg(Color::LightBlue))];
+    if !game.controls.is_empty() {
+        lines.push(Line::from(""));
+        lines.extend(game.controls.iter().map(|(key, action)| {
+            Line::from(vec![
+                key.cyan().bold(),
+                " ".into(),
+                action.fg(style::text()),
+            ])
+        }));
+    }
+    let details_height = (text_height + lines.len() as u16 - 1)
+        .min(inner.height.saturating_sub(GAME_PREVIEW_MIN_BOARD))
+        .max(3);
+    let [board_area, description_area] =
+        Layout::vertical([Constraint::Min(0), Constraint::Length(details_height)]).areas(inner);
+    // L'icône du jeu, recouverte par l'aperçu des jeux qui en dessinent un
+    let icon = Paragraph::new(glyphs::icon(game.icon))
+        .centered()
+        .fg(Color::DarkGray);
+    let middle = board_area.y + board_area.height / 2;
+    frame.render_widget(
+        icon,
+        Rect::new(board_area.x, middle, board_area.width, 1).intersection(board_area),
+    );
+    if let Some(Some(preview)) = app.previews.get(&game.id) {
+        preview.preview(frame, board_area);
+    }
+    frame.render_widget(
+        Paragraph::new(lines).wrap(Wrap { trim: true }),
+        description_area,
+    );
+}
 
-    let items: Vec<ListItem> = settings_options
+fn draw_settings_menu(frame: &mut Frame, area: Rect, app: &mut MainMenu, tab: SettingsTab) {
+    let [tabs_area, content_area] =
+        Layout::vertical([Constraint::Length(3), Constraint::Min(0)]).areas(area);
+
+    let tabs = Tabs::new(SettingsTab::ALL.map(|tab| glyphs::text(tab.title())))
+        .select(tab.index())
+        .style(Style::default().fg(Color::Gray))
+        .highlight_style(style::focus(Color::Yellow))
+        .divider("│")
+        .block(
+            Block::bordered()
+                .title(" Settings ".yellow().bold())
+                .border_style(Style::new().yellow())
+                .style(Style::default().bg(style::surface())),
+        );
+    frame.render_widget(tabs, tabs_area);
+
+    match tab {
+        SettingsTab::Audio => draw_audio_settings_menu(frame, content_area, app),
+        SettingsTab::Graphics => draw_graphics_settings(frame, content_area, app),
+        SettingsTab::Controls => draw_controls_settings(frame, content_area, app),
+        SettingsTab::Gameplay => draw_gameplay_settings(frame, content_area, app),
+        SettingsTab::Data => draw_data_settings(frame, content_area, app),
+    }
+}
+
+/// Case à cocher des réglages booléens
+fn toggle_label(enabled: bool) -> String {
+    format!(
+        "[{}] {}",
+        if enabled { "✓" } else { "✗" },
+        if enabled { "ON" } else { "OFF" }
+    )
+}
+
+/// Liste de lignes de réglages dans le style de l'écran Settings
+fn draw_settings_rows(
+    frame: &mut Frame,
+    area: Rect,
+    app: &mut MainMenu,
+    title: &str,
+    rows: &[String],
+) {
+    let items: Vec<ListItem> = rows
         .iter()
-        .map(|option| {
+        .map(|row| {
             let content = vec![Line::from(vec![
                 Span::styled("  ", Style::default()),
-                Span::styled(*option, Style::default().fg(Color::White).bold()),
+                Span::styled(glyphs::text(row), Style::default().fg(style::text()).bold()),
             ])];
             ListItem::new(content)
         })
         .collect();
 
-    let list = List::new(items)
-        .block(
+    let block = Block::bordered()
+        .title(format!(" {title} ").yellow().bold())
+        .border_style(Style::new().yellow())
+        .style(Style::default().bg(style::panel()));
+    let highlight = style::focus(Color::Rgb(200, 150, 0));
+
+    app.list.render(frame, area, items, block, highlight);
+}
+
+fn draw_graphics_settings(frame: &mut Frame, area: Rect, app: &mut MainMenu) {
+    let display = app.config_manager.get_display_config();
+    let rows = [
+        format!(
+            "🏷️ Terminal Title        {}",
+            toggle_label(display.terminal_title)
+        ),
+        format!(
+            "📈 Title Progress        {}",
+            toggle_label(display.title_progress)
+        ),
+        format!(
+            "🏆 Best Excludes Assists {}",
+            toggle_label(display.best_excludes_assisted)
+        ),
+        format!("🎨 Color Scheme          {}", display.color_scheme.name()),
+        format!(
+            "🪟 Transparent Background {}",
+            toggle_label(display.transparent_background)
+        ),
+        format!("🔣 Glyphs                {}", display.glyph_set.name()),
+        format!(
+            "🌀 Reduce Motion         {}",
+            toggle_label(display.reduce_motion)
+        ),
+        format!(
+            "🚀 Splash Screen         {}",
+            toggle_label(display.splash_screen)
+        ),
+        format!(
+            "🕹️ Title Screen          {}",
+            toggle_label(display.title_screen)
+        ),
+        format!(
+            "🌌 Menu Background       {}",
+            display.menu_background.name()
+        ),
+        format!("🌈 Color Depth           {}", display.color_depth.name()),
+        format!("💫 Ball Trail            {}", display.ball_trail.name()),
+        format!("🐌 Frame Skip            {}", display.frame_skip.name()),
+        format!("🕒 Clock                 {}", display.clock_format.name()),
+        format!("📆 Date Format           {}", display.date_order.name()),
+        format!("🌐 Time Zone             {}", display.timezone.name()),
+    ];
+
+    draw_settings_rows(frame, area, app, "Graphics", &rows);
+}
+
+fn draw_controls_settings(frame: &mut Frame, area: Rect, app: &mut MainMenu) {
+    let rows: Vec<String> = CONTROL_HINTS
+        .iter()
+        .map(|(keys, action)| format!("{keys:<16} {action}"))
+        .collect();
+
+    draw_settings_rows(frame, area, app, "Key Bindings", &rows);
+}
+
+fn draw_gameplay_settings(frame: &mut Frame, area: Rect, app: &mut MainMenu) {
+    let gameplay = app.config_manager.get_gameplay_config();
+    let rows = [
+        format!("👤 Player Name          {}", gameplay.player_name),
+        format!(
+            "❓ Minesweeper ? Marks  {}",
+            toggle_label(gameplay.minesweeper_question_marks)
+        ),
+        format!(
+            "⏸️ Pause on Focus Loss  {}",
+            toggle_label(gameplay.pause_on_focus_loss)
+        ),
+        format!("🏓 Pong Layout          {}", gameplay.pong_layout.name()),
+        format!(
+            "🤖 Pong AI              {}",
+            gameplay.pong_difficulty.name()
+        ),
+        format!(
+            "⏱️ Split Timer          {}",
+            toggle_label(gameplay.split_timer)
+        ),
+        format!(
+            "⌨️ Platform Profile     {}",
+            gameplay.platform_profile.name()
+        ),
+        format!("💾 Autosave             {}", gameplay.autosave.name()),
+        format!(
+            "🎨 Player 1 Color       {}",
+            PlayerColor::pair(gameplay.player_colors)[0].name()
+        ),
+        format!(
+            "🎨 Player 2 Color       {}",
+            PlayerColor::pair(gameplay.player_colors)[1].name()
+        ),
+        "🎯 Score Targets…".to_string(),
+    ];
+
+    // Champ d'édition du nom sous la liste pendant la saisie
+    let area = if let Some(input) = &app.name_input {
+        let [list_area, input_area] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(3)]).areas(area);
+        input.render(
+            frame,
+            input_area,
             Block::bordered()
-                .title(" Settings Menu ".yellow().bold())
+                .title(
+                    format!(" {} Player Name ", glyphs::icon("👤"))
+                        .yellow()
+                        .bold(),
+                )
                 .border_style(Style::new().yellow())
-                .style(Style::default().bg(Color::Rgb(10, 15, 20))),
-        )
-        .style(Style::default().fg(Color::White))
-        .highlight_style(
-            Style::default()
-                .bg(Color::Rgb(200, 150, 0))
-                .fg(Color::White)
-                .add_modifier(Modifier::BOLD),
-        )
-        .highlight_symbol("▶ ");
+                .style(Style::default().bg(style::panel())),
+            Style::default().fg(style::text()),
+            true,
+        );
+        list_area
+    } else {
+        area
+    };
 
-    frame.render_stateful_widget(list, area, &mut app.list_state);
+    draw_settings_rows(frame, area, app, "Gameplay", &rows);
+}
+
+fn draw_data_settings(frame: &mut Frame, area: Rect, app: &mut MainMenu) {
+    let mut rows: Vec<String> = STORED_FILES
+        .iter()
+        .map(|file| {
+            let path = match file.path() {
+                _ if app.guest => "on the host".to_string(),
+                Some(path) => path.display().to_string(),
+                None => "unavailable".to_string(),
+            };
+            let size = file
+                .size()
+                .map_or_else(|| "—".to_string(), storage::format_size);
+            let icon = if file.directory { "📂" } else { "📄" };
+            format!("{icon} {:<12} {size:>9}  {path}", file.label)
+        })
+        .collect();
+    let actions = [
+        "📂 Open Data Folder",
+        "💾 Back Up Data to Home Folder",
+        "🧹 Clear Caches",
+    ];
+    if app.guest {
+        rows.extend(actions.map(|action| format!("{action} (host only)")));
+        rows.push("♻️ Reset Everything (host only)".to_string());
+    } else {
+        rows.extend(actions.map(str::to_string));
+        rows.push(if app.confirm_reset {
+            "⚠️ Reset Everything – press Enter again to confirm".to_string()
+        } else {
+            "♻️ Reset Everything".to_string()
+        });
+    }
+
+    // Résultat de la dernière action sous la liste
+    let area = if let Some(status) = &app.data_status {
+        let [list_area, status_area] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(area);
+        frame.render_widget(
+            Paragraph::new(status.as_str())
+                .alignment(Alignment::Center)
+                .style(Style::default().fg(Color::Cyan)),
+            status_area,
+        );
+        list_area
+    } else {
+        area
+    };
+
+    let title = format!(
+        "Data Files – {} total",
+        storage::format_size(storage::total_size())
+    );
+    draw_settings_rows(frame, area, app, &title, &rows);
 }
 
 fn draw_audio_settings_menu(frame: &mut Frame, area: Rect, app: &mut MainMenu) {
@@ -908,6 +2731,9 @@ fn draw_audio_settings_menu(frame: &mut Frame, area: Rect, app: &mut MainMenu) {
     let music_volume = app.audio.get_music_volume();
     let audio_enabled = app.audio.is_enabled();
     let music_enabled = app.audio.is_music_enabled();
+    let sound_theme = app.audio.get_sound_theme();
+    let music_ducking = app.audio.get_music_ducking();
+    let background_music = app.audio.is_background_music();
 
     // Helper pour créer une barre de volume visuelle
     let create_volume_bar = |value: f32| -> String {
@@ -922,7 +2748,14 @@ fn draw_audio_settings_menu(frame: &mut Frame, area: Rect, app: &mut MainMenu) {
     };
 
     let audio_settings = [
-        format!("🎚️ Master Volume     {}", create_volume_bar(master_volume)),
+        if app.audio.is_muted() {
+            format!(
+                "🎚️ Master Volume     {} (muted)",
+                create_volume_bar(master_volume)
+            )
+        } else {
+            format!("🎚️ Master Volume     {}", create_volume_bar(master_volume))
+        },
         format!("🔊 Effects Volume    {}", create_volume_bar(volume)),
         format!("🎵 Music Volume      {}", create_volume_bar(music_volume)),
         format!(
@@ -935,6 +2768,24 @@ fn draw_audio_settings_menu(frame: &mut Frame, area: Rect, app: &mut MainMenu) {
             if music_enabled { "✓" } else { "✗" },
             if music_enabled { "ON" } else { "OFF" }
         ),
+        format!(
+            "📻 Background Music  [{}] {}",
+            if background_music { "✓" } else { "✗" },
+            if background_music { "ON" } else { "OFF" }
+        ),
+        if music_ducking > 0.0 {
+            format!("🔉 Music Ducking     {}", create_volume_bar(music_ducking))
+        } else {
+            "🔉 Music Ducking     [░░░░░░░░░░] OFF".to_string()
+        },
+        format!("🎛️ Sound Theme       ◀ {} ▶", sound_theme.name()),
+        "▶️ Preview Theme".to_string(),
+        "🎧 Customize Effects…".to_string(),
+        if AudioManager::output_lost() {
+            "🔌 Reconnect Audio   (device lost)".to_string()
+        } else {
+            "🔌 Reconnect Audio".to_string()
+        },
     ];
 
     let items: Vec<ListItem> = audio_settings
@@ -942,39 +2793,177 @@ fn draw_audio_settings_menu(frame: &mut Frame, area: Rect, app: &mut MainMenu) {
         .map(|setting| {
             let content = vec![Line::from(vec![
                 Span::styled("  ", Style::default()),
-                Span::styled(setting, Style::default().fg(Color::White).bold()),
+                Span::styled(setting, Style::default().fg(style::text()).bold()),
             ])];
             ListItem::new(content)
         })
         .collect();
 
-    let list = List::new(items)
-        .block(
-            Block::bordered()
-                .title(" Audio Settings ".cyan().bold())
-                .border_style(Style::new().cyan())
-                .style(Style::default().bg(Color::Rgb(10, 15, 20))),
-        )
-        .style(Style::default().fg(Color::White))
-        .highlight_style(
-            Style::default()
-                .bg(Color::Rgb(0, 150, 200))
-                .fg(Color::White)
-                .add_modifier(Modifier::BOLD),
+    let block = Block::bordered()
+        .title(" Audio Settings ".cyan().bold())
+        .border_style(Style::new().cyan())
+        .style(Style::default().bg(style::panel()));
+    let highlight = style::focus(Color::Rgb(0, 150, 200));
+
+    app.list.render(frame, area, items, block, highlight);
+}
+
+fn draw_score_targets_menu(frame: &mut Frame, area: Rect, app: &mut MainMenu) {
+    let block = Block::bordered()
+        .title(" Score Targets ".yellow().bold())
+        .border_style(Style::new().yellow())
+        .style(Style::default().bg(style::panel()));
+
+    let games = app.target_games();
+    if games.is_empty() {
+        let paragraph = Paragraph::new(
+            "🎯 No games to aim for yet!\n\nPlay a game to set a score target for it.",
         )
-        .highlight_symbol("▶ ");
+        .block(block)
+        .style(Style::default().fg(style::text()))
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+
+        frame.render_widget(paragraph, area);
+        return;
+    }
+
+    // Champ de saisie sous la liste pendant l'édition
+    let area = if let Some(input) = &app.target_input {
+        let [list_area, input_area] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(3)]).areas(area);
+        let (title, color) = if app.invalid_target {
+            (" Not a score – try 50000 or 50k ".to_string(), Color::Red)
+        } else {
+            (
+                format!(" {} Score Target ", glyphs::icon("🎯")),
+                Color::Yellow,
+            )
+        };
+        input.render(
+            frame,
+            input_area,
+            Block::bordered()
+                .title(title.fg(color).bold())
+                .border_style(Style::new().fg(color))
+                .style(Style::default().bg(style::panel())),
+            Style::default().fg(style::text()),
+            true,
+        );
+        list_area
+    } else {
+        area
+    };
+
+    let targets = &app.config_manager.get_gameplay_config().score_targets;
+    let items: Vec<ListItem> = games
+        .iter()
+        .map(|(game_id, name)| {
+            let target = match targets.get(game_id) {
+                Some(&target) => format!("{} pts", numbers::score(target)).yellow().bold(),
+                None => "—".dark_gray(),
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("  {} {name:<18}", glyphs::icon("🎯")),
+                    Style::default().fg(style::text()).bold(),
+                ),
+                target,
+            ]))
+        })
+        .collect();
+
+    let highlight = style::focus(Color::Rgb(200, 150, 0));
+    app.list.render(frame, area, items, block, highlight);
+}
 
-    frame.render_stateful_widget(list, area, &mut app.list_state);
+fn draw_sound_effects_menu(frame: &mut Frame, area: Rect, app: &mut MainMenu) {
+    let items: Vec<ListItem> = SoundEffect::ALL
+        .iter()
+        .map(|&effect| {
+            let effect_override = app.audio.effect_override(effect);
+            // La barre couvre 0 à 200 %, 100 % au milieu
+            let filled = (effect_override.volume * 5.0).round() as usize;
+            let volume_bar = format!(
+                "[{}{}] {:>3}%",
+                "█".repeat(filled),
+                "░".repeat(10 - filled.min(10)),
+                (effect_override.volume * 100.0).round() as u32
+            );
+            let (check, name_color) = if effect_override.enabled {
+                ("[✓]".green(), style::text())
+            } else {
+                ("[✗]".red(), Color::DarkGray)
+            };
+
+            let content = vec![Line::from(vec![
+                Span::raw("  "),
+                check,
+                Span::styled(
+                    format!(" {:<30}", effect.label()),
+                    Style::default().fg(name_color).bold(),
+                ),
+                Span::styled(volume_bar, Style::default().fg(Color::Gray)),
+            ])];
+            ListItem::new(content)
+        })
+        .collect();
+
+    let block = Block::bordered()
+        .title(" Sound Effects ".cyan().bold())
+        .border_style(Style::new().cyan())
+        .style(Style::default().bg(style::panel()));
+    let highlight = style::focus(Color::Rgb(0, 150, 200));
+
+    app.list.render(frame, area, items, block, highlight);
 }
 
-fn draw_about_menu(frame: &mut Frame, area: Rect) {
+/// Principales dépendances embarquées et leurs licences
+const THIRD_PARTY_LICENSES: &[(&str, &str)] = &[
+    ("ratatui", "MIT"),
+    ("crossterm", "MIT"),
+    ("rodio", "MIT OR Apache-2.0"),
+    ("clap", "MIT OR Apache-2.0"),
+    ("serde / serde_json", "MIT OR Apache-2.0"),
+    ("chrono", "MIT OR Apache-2.0"),
+    ("rand", "MIT OR Apache-2.0"),
+    ("dirs", "MIT OR Apache-2.0"),
+    ("rayon", "MIT OR Apache-2.0"),
+    ("axoupdater", "MIT OR Apache-2.0"),
+    ("tokio", "MIT"),
+    ("russh", "Apache-2.0"),
+];
+
+fn draw_about_menu(frame: &mut Frame, area: Rect, app: &mut MainMenu) {
+    let [info_area, licenses_area] =
+        Layout::horizontal([Constraint::Percentage(60), Constraint::Percentage(40)]).areas(area);
+
     // Récupérer la version depuis Cargo.toml automatiquement
     let version = env!("CARGO_PKG_VERSION");
     let version_text = format!("🎮 TermPlay v{version}");
+    let build_text = format!(
+        "Build {} • {}",
+        env!("TERMPLAY_GIT_HASH"),
+        env!("TERMPLAY_BUILD_DATE")
+    );
+
+    let update_line = match app.update_checker.as_mut().map(|checker| checker.status()) {
+        Some(UpdateStatus::Checking) | None => Line::from("⏳ Checking for updates...".gray()),
+        Some(UpdateStatus::UpToDate) => Line::from("✅ You are using the latest version".green()),
+        Some(UpdateStatus::Available(new_version)) => Line::from(vec![
+            format!("⬆️ Update available: v{new_version}")
+                .yellow()
+                .bold(),
+            "  (run 'termplay update')".gray(),
+        ]),
+        Some(UpdateStatus::Unavailable) => Line::from("Update check unavailable".dark_gray()),
+    };
 
     let about_text = vec![
         Line::from(""),
         Line::from(version_text.cyan().bold()),
+        Line::from(build_text.dark_gray()),
+        update_line,
         Line::from(""),
         Line::from("A beautiful collection of terminal mini-games"),
         Line::from("built with Rust and Ratatui."),
@@ -985,6 +2974,7 @@ fn draw_about_menu(frame: &mut Frame, area: Rect) {
         Line::from("• Extensible architecture for adding new games"),
         Line::from(""),
         Line::from("Created with ❤️ by MedCy1 using Rust".red()),
+        Line::from(format!("Licensed under {}", env!("CARGO_PKG_LICENSE")).gray()),
     ];
 
     let about = Paragraph::new(about_text)
@@ -993,27 +2983,48 @@ fn draw_about_menu(frame: &mut Frame, area: Rect) {
             Block::bordered()
                 .title(" About TermPlay ".cyan().bold())
                 .border_style(Style::new().cyan())
-                .style(Style::default().bg(Color::Rgb(10, 15, 20))),
+                .style(Style::default().bg(style::panel())),
         );
-    frame.render_widget(about, area);
+    frame.render_widget(about, info_area);
+
+    let mut licenses_text = vec![Line::from("")];
+    licenses_text.extend(THIRD_PARTY_LICENSES.iter().map(|(name, license)| {
+        Line::from(vec![
+            format!("{name}  ").fg(style::text()).bold(),
+            (*license).gray(),
+        ])
+    }));
+
+    let licenses = Paragraph::new(licenses_text)
+        .alignment(Alignment::Center)
+        .block(
+            Block::bordered()
+                .title(" Third-party Licenses ".magenta().bold())
+                .border_style(Style::new().magenta())
+                .style(Style::default().bg(style::panel())),
+        );
+    frame.render_widget(licenses, licenses_area);
 }
 
 fn draw_music_player(frame: &mut Frame, area: Rect, app: &mut MainMenu) {
+    let [list_area, progress_area] =
+        Layout::vertical([Constraint::Min(0), Constraint::Length(3)]).areas(area);
+
     let items: Vec<ListItem> = app
         .music_tracks
         .iter()
         .enumerate()
         .map(|(i, track)| {
             let status = if app.current_playing == Some(i) {
-                "▶️ "
+                glyphs::icon("▶️")
             } else {
-                "🎵 "
+                glyphs::icon("🎵")
             };
 
             let playing_text = if app.current_playing == Some(i) {
                 " [PLAYING]".green().bold()
             } else {
-                "".white()
+                "".fg(style::text())
             };
 
             // Afficher la variante actuellement sélectionnée en surbrillance
@@ -1026,14 +3037,19 @@ fn draw_music_player(frame: &mut Frame, area: Rect, app: &mut MainMenu) {
                     variants_display.push(variant.clone());
                 }
             }
-            let variants_text = format!(" ({})", variants_display.join(", "));
+            let length = track
+                .durations
+                .get(current_variant_idx)
+                .map(|duration| format!(" {}", format_track_time(*duration)))
+                .unwrap_or_default();
+            let variants_text = format!(" ({}){length}", variants_display.join(", "));
 
             let content = vec![Line::from(vec![
                 Span::styled(
-                    format!("  {status} "),
+                    format!("  {status}  "),
                     Style::default().fg(Color::Green).bold(),
                 ),
-                Span::styled(&track.name, Style::default().fg(Color::White).bold()),
+                Span::styled(&track.name, Style::default().fg(style::text()).bold()),
                 Span::styled(variants_text, Style::default().fg(Color::Gray)),
                 playing_text,
             ])];
@@ -1041,23 +3057,142 @@ fn draw_music_player(frame: &mut Frame, area: Rect, app: &mut MainMenu) {
         })
         .collect();
 
-    let list = List::new(items)
-        .block(
-            Block::bordered()
-                .title(" Available Music Tracks ".magenta().bold())
-                .border_style(Style::new().magenta())
-                .style(Style::default().bg(Color::Rgb(10, 15, 20))),
-        )
-        .style(Style::default().fg(Color::White))
-        .highlight_style(
+    let block = Block::bordered()
+        .title(" Available Music Tracks ".magenta().bold())
+        .border_style(Style::new().magenta())
+        .style(Style::default().bg(style::panel()));
+    let highlight = style::focus(Color::Rgb(100, 0, 150));
+
+    app.list.render(frame, list_area, items, block, highlight);
+    draw_music_progress(frame, progress_area, app);
+}
+
+/// Barre de progression du morceau en cours dans le Music Player
+fn draw_music_progress(frame: &mut Frame, area: Rect, app: &MainMenu) {
+    let block = Block::bordered()
+        .border_style(Style::new().magenta())
+        .style(Style::default().bg(style::panel()));
+
+    let position = app.current_playing.and_then(|_| app.audio.music_position());
+    let Some(position) = position else {
+        let idle = Paragraph::new("No track playing")
+            .style(Style::default().fg(Color::Gray))
+            .alignment(Alignment::Center)
+            .block(block);
+        frame.render_widget(idle, area);
+        return;
+    };
+
+    let section = position
+        .section
+        .map(|name| format!(" • {name}"))
+        .unwrap_or_default();
+    let label = format!(
+        "{} / {} • {} BPM{section}",
+        format_track_time(position.elapsed),
+        format_track_time(position.duration),
+        position.bpm
+    );
+
+    let gauge = Gauge::default()
+        .block(block)
+        .gauge_style(
             Style::default()
-                .bg(Color::Rgb(100, 0, 150))
-                .fg(Color::White)
-                .add_modifier(Modifier::BOLD),
+                .fg(Color::Magenta)
+                .bg(Color::Rgb(30, 20, 40)),
         )
-        .highlight_symbol("▶ ");
+        .ratio(position.progress())
+        .label(label);
+    frame.render_widget(gauge, area);
+}
 
-    frame.render_stateful_widget(list, area, &mut app.list_state);
+/// Durée des `count` premières variantes d'une musique
+fn track_durations(music: &dyn GameMusic, count: usize) -> Vec<Duration> {
+    [
+        MusicVariant::Normal,
+        MusicVariant::Fast,
+        MusicVariant::Celebration,
+    ]
+    .into_iter()
+    .take(count)
+    .map(|variant| music.duration(variant))
+    .collect()
+}
+
+/// High scores enregistrés, ou une liste vide s'ils sont illisibles
+pub fn load_highscores() -> HighScoreManager {
+    HighScoreManager::new().unwrap_or_default()
+}
+
+/// Morceaux du Music Player ; mesurer leur durée rejoue toute la partition
+pub fn music_tracks() -> Vec<MusicTrack> {
+    vec![
+        MusicTrack {
+            name: TETRIS_MUSIC.name().to_string(),
+            variants: vec![
+                "Normal".to_string(),
+                "Fast".to_string(),
+                "Celebration".to_string(),
+            ],
+            durations: track_durations(&TETRIS_MUSIC, 3),
+        },
+        MusicTrack {
+            name: SNAKE_MUSIC.name().to_string(),
+            variants: vec!["Normal".to_string(), "Fast".to_string()],
+            durations: track_durations(&SNAKE_MUSIC, 2),
+        },
+        MusicTrack {
+            name: PONG_MUSIC.name().to_string(),
+            variants: vec![
+                "Normal".to_string(),
+                "Fast".to_string(),
+                "Celebration".to_string(),
+            ],
+            durations: track_durations(&PONG_MUSIC, 3),
+        },
+        MusicTrack {
+            name: GAME2048_MUSIC.name().to_string(),
+            variants: vec![
+                "Normal".to_string(),
+                "Fast".to_string(),
+                "Celebration".to_string(),
+            ],
+            durations: track_durations(&GAME2048_MUSIC, 3),
+        },
+        MusicTrack {
+            name: MINESWEEPER_MUSIC.name().to_string(),
+            variants: vec![
+                "Normal".to_string(),
+                "Intense".to_string(),
+                "Victory".to_string(),
+            ],
+            durations: track_durations(&MINESWEEPER_MUSIC, 3),
+        },
+        MusicTrack {
+            name: BREAKOUT_MUSIC.name().to_string(),
+            variants: vec![
+                "Normal".to_string(),
+                "Intense".to_string(),
+                "Victory".to_string(),
+            ],
+            durations: track_durations(&BREAKOUT_MUSIC, 3),
+        },
+        MusicTrack {
+            name: GAMEOFLIFE_MUSIC.name().to_string(),
+            variants: vec![
+                "Contemplative".to_string(),
+                "Dynamic".to_string(),
+                "Wonder".to_string(),
+            ],
+            durations: track_durations(&GAMEOFLIFE_MUSIC, 3),
+        },
+    ]
+}
+
+/// Formate une durée de morceau en "m:ss"
+fn format_track_time(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    format!("{}:{:02}", seconds / 60, seconds % 60)
 }
 
 fn draw_highscores_menu(frame: &mut Frame, area: Rect, app: &mut MainMenu) {
@@ -1071,9 +3206,9 @@ fn draw_highscores_menu(frame: &mut Frame, area: Rect, app: &mut MainMenu) {
                     Block::bordered()
                         .title(" High Scores ".yellow().bold())
                         .border_style(Style::new().yellow())
-                        .style(Style::default().bg(Color::Rgb(10, 15, 20))),
+                        .style(Style::default().bg(style::panel())),
                 )
-                .style(Style::default().fg(Color::White))
+                .style(Style::default().fg(style::text()))
                 .alignment(Alignment::Center)
                 .wrap(ratatui::widgets::Wrap { trim: true });
 
@@ -1081,58 +3216,331 @@ fn draw_highscores_menu(frame: &mut Frame, area: Rect, app: &mut MainMenu) {
         return;
     }
 
+    let exclude_assisted = app
+        .config_manager
+        .get_display_config()
+        .best_excludes_assisted;
     let items: Vec<ListItem> = games_with_scores
         .iter()
         .map(|game_name| {
-            let best_score = app.highscore_manager.get_best_score(game_name);
-            let score_text = if let Some(score) = best_score {
-                format!(" (Best: {})", score.score)
-            } else {
-                " (No scores)".to_string()
+            let best_score = app
+                .highscore_manager
+                .get_best_score(game_name, exclude_assisted);
+            let score_text = match best_score {
+                Some(score) if exclude_assisted => {
+                    format!(" (Best unassisted: {})", numbers::score(score.score))
+                }
+                Some(score) => format!(" (Best: {})", numbers::score(score.score)),
+                None if exclude_assisted => " (No unassisted scores)".to_string(),
+                None => " (No scores)".to_string(),
             };
 
             let content = vec![Line::from(vec![
-                Span::styled("  🎮 ", Style::default().fg(Color::Yellow)),
-                Span::styled(game_name, Style::default().fg(Color::White).bold()),
+                Span::styled(
+                    format!("  {} ", glyphs::icon("🎮")),
+                    Style::default().fg(Color::Yellow),
+                ),
+                Span::styled(game_name, Style::default().fg(style::text()).bold()),
                 Span::styled(score_text, Style::default().fg(Color::Gray)),
+                new_badge(
+                    app.new_scores
+                        .iter()
+                        .any(|new_score| &new_score.game == game_name),
+                ),
             ])];
             ListItem::new(content)
         })
         .collect();
 
-    let list = List::new(items)
-        .block(
-            Block::bordered()
-                .title(" Games with High Scores ".yellow().bold())
-                .border_style(Style::new().yellow())
-                .style(Style::default().bg(Color::Rgb(10, 15, 20))),
+    let block = Block::bordered()
+        .title(" Games with High Scores ".yellow().bold())
+        .border_style(Style::new().yellow())
+        .style(Style::default().bg(style::panel()));
+    let highlight = style::focus(Color::Rgb(200, 200, 0));
+
+    app.list.render(frame, area, items, block, highlight);
+}
+
+fn draw_statistics_menu(frame: &mut Frame, area: Rect, app: &mut MainMenu) {
+    let statistics = app.statistics();
+
+    if statistics.is_empty() {
+        let message = if app.history.is_empty() {
+            "📊 No games played yet!\n\nFinished games are recorded here.".to_string()
+        } else {
+            format!(
+                "📊 No games played {}.\n\nPress Tab to change the period.",
+                app.stats_period.label().to_lowercase()
+            )
+        };
+        let paragraph = Paragraph::new(message)
+            .block(
+                Block::bordered()
+                    .title(
+                        format!(" Statistics • {} ", app.stats_period.label())
+                            .yellow()
+                            .bold(),
+                    )
+                    .border_style(Style::new().yellow())
+                    .style(Style::default().bg(style::panel())),
+            )
+            .style(Style::default().fg(style::text()))
+            .alignment(Alignment::Center)
+            .wrap(ratatui::widgets::Wrap { trim: true });
+
+        frame.render_widget(paragraph, area);
+        return;
+    }
+
+    let total_sessions: usize = statistics.iter().map(|(_, summary)| summary.sessions).sum();
+    let total_seconds: u64 = statistics
+        .iter()
+        .map(|(_, summary)| summary.total_seconds)
+        .sum();
+
+    let items: Vec<ListItem> = statistics
+        .iter()
+        .map(|(game_id, summary)| {
+            let game_name = app.game_display_name(game_id);
+            let best = summary
+                .best_score
+                .map(|score| format!("  Best: {}", numbers::score(score)))
+                .unwrap_or_default();
+            let wins = if summary.wins > 0 {
+                format!("  Wins: {}", summary.wins)
+            } else {
+                String::new()
+            };
+            let last_played = summary
+                .last_played
+                .map(|timestamp| format!("  Last: {}", time_format::format_date(timestamp)))
+                .unwrap_or_default();
+
+            let content = vec![Line::from(vec![
+                Span::styled(
+                    format!("  {} ", glyphs::icon("📊")),
+                    Style::default().fg(Color::Yellow),
+                ),
+                Span::styled(
+                    format!("{game_name:<14} "),
+                    Style::default().fg(style::text()).bold(),
+                ),
+                Span::styled(
+                    format!("{:>4} sessions", summary.sessions),
+                    Style::default().fg(Color::Cyan),
+                ),
+                Span::styled(
+                    format!(
+                        "  {:>8}",
+                        time_format::format_duration(summary.total_seconds)
+                    ),
+                    Style::default().fg(Color::Gray),
+                ),
+                Span::styled(best, Style::default().fg(Color::Green).bold()),
+                Span::styled(wins, Style::default().fg(Color::Yellow)),
+                Span::styled(last_played, Style::default().fg(Color::DarkGray)),
+            ])];
+            ListItem::new(content)
+        })
+        .collect();
+
+    let chunks = Layout::vertical([
+        Constraint::Min(5),         // Totaux par jeu
+        Constraint::Percentage(50), // Progression du record du jeu sélectionné
+    ])
+    .split(area);
+
+    let block = Block::bordered()
+        .title(
+            format!(
+                " {} • {total_sessions} Sessions • {} Played ",
+                app.stats_period.label(),
+                time_format::format_duration(total_seconds)
+            )
+            .yellow()
+            .bold(),
         )
-        .style(Style::default().fg(Color::White))
-        .highlight_style(
-            Style::default()
-                .bg(Color::Rgb(200, 200, 0))
-                .fg(Color::Black)
-                .add_modifier(Modifier::BOLD),
+        .border_style(Style::new().yellow())
+        .style(Style::default().bg(style::panel()));
+    let highlight = style::focus(Color::Rgb(200, 200, 0));
+
+    app.list.render(frame, chunks[0], items, block, highlight);
+
+    if let Some((game_id, _)) = statistics.get(app.list.selected()) {
+        let bests = history::personal_bests(&app.history, game_id);
+        draw_personal_best_chart(frame, chunks[1], app.game_display_name(game_id), &bests);
+    }
+}
+
+/// Courbe des records personnels successifs d'un jeu, toutes périodes
+/// confondues ; l'axe horizontal est en jours depuis le premier record
+fn draw_personal_best_chart(
+    frame: &mut Frame,
+    area: Rect,
+    game_name: &str,
+    bests: &[(chrono::DateTime<chrono::Utc>, u32)],
+) {
+    let block = Block::bordered()
+        .title(format!(" {game_name} Personal Bests ").cyan().bold())
+        .border_style(Style::new().cyan())
+        .style(Style::default().bg(style::panel()));
+
+    let (Some(&(first_time, _)), Some(&(last_time, best))) = (bests.first(), bests.last()) else {
+        let paragraph = Paragraph::new("No scored sessions for this game yet.")
+            .block(block)
+            .style(Style::default().fg(Color::Gray))
+            .alignment(Alignment::Center);
+        frame.render_widget(paragraph, area);
+        return;
+    };
+
+    let days_since_first =
+        |time: chrono::DateTime<chrono::Utc>| (time - first_time).num_seconds() as f64 / 86_400.0;
+    let points: Vec<(f64, f64)> = bests
+        .iter()
+        .map(|&(time, score)| (days_since_first(time), score as f64))
+        .collect();
+
+    // Bornes minimales pour qu'un seul record reste visible
+    let max_x = days_since_first(last_time).max(1.0);
+    let max_y = (best as f64 * 1.1).max(1.0);
+
+    let dataset = Dataset::default()
+        .name(format!("Best: {best}"))
+        .marker(symbols::Marker::Braille)
+        .graph_type(GraphType::Line)
+        .style(Style::default().fg(Color::Yellow))
+        .data(&points);
+
+    let chart = Chart::new(vec![dataset])
+        .block(block)
+        .x_axis(
+            Axis::default()
+                .style(Style::default().fg(Color::Gray))
+                .bounds([0.0, max_x])
+                .labels([
+                    time_format::format_date(first_time),
+                    time_format::format_date(last_time),
+                ]),
         )
-        .highlight_symbol("▶ ");
+        .y_axis(
+            Axis::default()
+                .style(Style::default().fg(Color::Gray))
+                .bounds([0.0, max_y])
+                .labels(["0".to_string(), best.to_string()]),
+        );
+    frame.render_widget(chart, area);
+}
 
-    frame.render_stateful_widget(list, area, &mut app.list_state);
+fn draw_history_menu(frame: &mut Frame, area: Rect, app: &mut MainMenu) {
+    let title = format!(
+        " {} • {} ",
+        app.history_game
+            .as_deref()
+            .map_or("All Games", |game_id| app.game_display_name(game_id)),
+        app.history_range.label()
+    );
+    let block = Block::bordered()
+        .title(title.yellow().bold())
+        .border_style(Style::new().yellow())
+        .style(Style::default().bg(style::panel()));
+
+    let records = app.filtered_history();
+    if records.is_empty() {
+        let paragraph = Paragraph::new("📜 No sessions match these filters.")
+            .block(block)
+            .style(Style::default().fg(style::text()))
+            .alignment(Alignment::Center)
+            .wrap(ratatui::widgets::Wrap { trim: true });
+
+        frame.render_widget(paragraph, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = records
+        .iter()
+        .map(|record| {
+            let score = record
+                .score
+                .map(|score| format!("{:>8} pts", numbers::compact(score)))
+                .unwrap_or_else(|| format!("{:>12}", "—"));
+
+            let content = vec![Line::from(vec![
+                Span::styled(
+                    format!("  {}  ", record.format_date()),
+                    Style::default().fg(Color::DarkGray),
+                ),
+                Span::styled(
+                    format!("{:<14} ", app.game_display_name(&record.game)),
+                    Style::default().fg(style::text()).bold(),
+                ),
+                Span::styled(score, Style::default().fg(Color::Green).bold()),
+                Span::styled(
+                    if record.target_reached.is_some() {
+                        format!(" {}", glyphs::icon("🎯"))
+                    } else {
+                        "  ".to_string()
+                    },
+                    Style::default().fg(Color::Yellow),
+                ),
+                Span::styled(
+                    if record.won {
+                        format!(" {}", glyphs::icon("🏆"))
+                    } else {
+                        "  ".to_string()
+                    },
+                    Style::default().fg(Color::Yellow),
+                ),
+                Span::styled(
+                    format!(
+                        "  {:>8}",
+                        time_format::format_duration(record.duration_seconds)
+                    ),
+                    Style::default().fg(Color::Gray),
+                ),
+                Span::styled(
+                    record
+                        .mode
+                        .as_ref()
+                        .map(|mode| format!("  {mode}"))
+                        .unwrap_or_default(),
+                    Style::default().fg(Color::Cyan),
+                ),
+            ])];
+            ListItem::new(content)
+        })
+        .collect();
+
+    let highlight = style::focus(Color::Rgb(40, 60, 80));
+
+    app.list.render(frame, area, items, block, highlight);
 }
 
 fn draw_highscores_detail(frame: &mut Frame, area: Rect, app: &mut MainMenu, game_name: &str) {
-    let scores = app.highscore_manager.get_scores(game_name);
+    draw_leaderboard(frame, area, app, game_name, game_name);
+}
+
+/// Classement `leaderboard` des high scores, sous le nom `title`
+fn draw_leaderboard(
+    frame: &mut Frame,
+    area: Rect,
+    app: &mut MainMenu,
+    leaderboard: &str,
+    title: &str,
+) {
+    let scores = app.highscore_manager.get_scores(leaderboard);
 
     if scores.is_empty() {
         let paragraph = Paragraph::new(format!(
-            "🏆 No scores yet for {game_name}!\n\nPlay this game to set your first high score."
+            "🏆 No scores yet for {title}!\n\nPlay this game to set your first high score."
         ))
         .block(
             Block::bordered()
-                .title(format!(" {game_name} Leaderboard ").yellow().bold())
+                .title(format!(" {title} Leaderboard ").yellow().bold())
                 .border_style(Style::new().yellow())
-                .style(Style::default().bg(Color::Rgb(10, 15, 20))),
+                .style(Style::default().bg(style::panel())),
         )
-        .style(Style::default().fg(Color::White))
+        .style(Style::default().fg(style::text()))
         .alignment(Alignment::Center)
         .wrap(ratatui::widgets::Wrap { trim: true });
 
@@ -1151,61 +3559,320 @@ fn draw_highscores_detail(frame: &mut Frame, area: Rect, app: &mut MainMenu, gam
                 3 => "🥉",
                 _ => "🏅",
             };
+            let medal = glyphs::icon(medal);
 
             let player_name = if score.player_name.is_empty() {
-                "Anonymous"
+                DEFAULT_PLAYER_NAME
             } else {
                 &score.player_name
             };
 
-            let content = vec![Line::from(vec![
-                Span::styled(format!(" {medal}  "), Style::default()),
-                Span::styled(
-                    format!("#{rank:<2} "),
-                    Style::default().fg(Color::Yellow).bold(),
-                ),
-                Span::styled(
-                    format!("{player_name:<15} "),
-                    Style::default().fg(Color::White).bold(),
-                ),
-                Span::styled(
-                    format!("{:>8} pts", score.score),
-                    Style::default().fg(Color::Green).bold(),
-                ),
+            let content =
+                vec![Line::from(vec![
+                    Span::styled(format!(" {medal}  "), Style::default()),
+                    Span::styled(
+                        format!("#{rank:<2} "),
+                        Style::default().fg(Color::Yellow).bold(),
+                    ),
+                    Span::styled(
+                        format!("{player_name:<15} "),
+                        Style::default().fg(style::text()).bold(),
+                    ),
+                    Span::styled(
+                        format!("{:>8} pts", numbers::compact(score.score)),
+                        Style::default().fg(Color::Green).bold(),
+                    ),
+                    Span::styled(
+                        format!("  {}", score.format_duration()),
+                        Style::default().fg(Color::Gray),
+                    ),
+                    Span::styled(
+                        score
+                            .details()
+                            .map(|details| format!("  {details}"))
+                            .unwrap_or_default(),
+                        Style::default().fg(Color::Cyan),
+                    ),
+                    Span::styled(
+                        format!("  {}", score.format_date()),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                    Span::styled(
+                        score
+                            .assists
+                            .badges()
+                            .iter()
+                            .map(|badge| format!("  [{badge}]"))
+                            .collect::<String>(),
+                        Style::default().fg(Color::Magenta),
+                    ),
+                    Span::styled(
+                        score
+                            .mutators
+                            .iter()
+                            .map(|mutator| format!("  [{}]", mutator.badge()))
+                            .collect::<String>(),
+                        Style::default().fg(Color::LightRed),
+                    ),
+                    new_badge(app.new_scores.iter().any(|new_score| {
+                        new_score.game == leaderboard && new_score.matches(score)
+                    })),
+                ])];
+            ListItem::new(content)
+        })
+        .collect();
+
+    let block = Block::bordered()
+        .title(
+            format!(" {} - Top {} ", title, scores.len())
+                .yellow()
+                .bold(),
+        )
+        .border_style(Style::new().yellow())
+        .style(Style::default().bg(style::panel()));
+    let highlight = style::focus(Color::Rgb(200, 200, 0));
+
+    app.list.render(frame, area, items, block, highlight);
+}
+
+/// Modificateurs proposés pour `game_id`, cochés ou non, puis la ligne qui
+/// lance la partie
+fn draw_modifiers_menu(frame: &mut Frame, area: Rect, app: &mut MainMenu, game_id: &str) {
+    let mut items: Vec<ListItem> = Mutator::for_game(game_id)
+        .into_iter()
+        .map(|mutator| {
+            let (check, name_color) = if app.mutators.contains(&mutator) {
+                ("[✓]".green(), style::text())
+            } else {
+                ("[ ]".gray(), Color::Gray)
+            };
+            ListItem::new(Line::from(vec![
+                Span::raw("  "),
+                check,
                 Span::styled(
-                    format!("  {}", score.format_duration()),
-                    Style::default().fg(Color::Gray),
+                    format!(" {:<20}", mutator.name()),
+                    Style::default().fg(name_color).bold(),
                 ),
-                Span::styled(
-                    format!("  {}", score.format_date()),
-                    Style::default().fg(Color::DarkGray),
-                ),
-            ])];
-            ListItem::new(content)
+                Span::styled(mutator.description(), Style::default().fg(Color::Gray)),
+            ]))
         })
         .collect();
+    let start = if app.mutators.is_empty() {
+        "  ▶ Start Game".to_string()
+    } else {
+        format!(
+            "  ▶ Start Game with {} modifier{}",
+            app.mutators.len(),
+            if app.mutators.len() == 1 { "" } else { "s" }
+        )
+    };
+    items.push(ListItem::new(Line::from(start.green().bold())));
 
-    let list = List::new(items)
-        .block(
-            Block::bordered()
-                .title(
-                    format!(" {} - Top {} ", game_name, scores.len())
-                        .yellow()
-                        .bold(),
-                )
-                .border_style(Style::new().yellow())
-                .style(Style::default().bg(Color::Rgb(10, 15, 20))),
+    let block = Block::bordered()
+        .title(" Modifiers ".magenta().bold())
+        .title_bottom(" Scores remember the modifiers they were played with ".gray())
+        .border_style(Style::new().magenta())
+        .style(Style::default().bg(style::panel()));
+    let highlight = style::focus(Color::Rgb(170, 0, 170));
+
+    app.list.render(frame, area, items, block, highlight);
+}
+
+/// Écran du défi de la semaine : le jeu, sa règle modifiée et le classement
+/// de la semaine
+fn draw_weekly_challenge(frame: &mut Frame, area: Rect, app: &mut MainMenu) {
+    let [info_area, board_area] =
+        Layout::vertical([Constraint::Length(6), Constraint::Min(0)]).areas(area);
+    let weekly = app.weekly;
+    let game_name = app.game_display_name(weekly.game_id).to_string();
+    let icon = app.game_info(weekly.game_id).map_or("🎮", |game| game.icon);
+
+    let info = Paragraph::new(vec![
+        Line::from(vec![
+            format!("{} {game_name}", glyphs::icon(icon))
+                .fg(style::text())
+                .bold(),
+            "  •  ".gray(),
+            weekly.modifier.name().yellow().bold(),
+        ]),
+        Line::from(weekly.modifier.description().fg(Color::LightBlue)),
+        Line::from(
+            "Everyone plays the same draws this week. Scores go to the weekly leaderboard.".gray(),
+        ),
+        Line::from(vec!["Enter".green().bold(), " Play the challenge".gray()]),
+    ])
+    .alignment(Alignment::Center)
+    .block(
+        Block::bordered()
+            .title(
+                format!(" {} Challenge ", weekly.week_label())
+                    .yellow()
+                    .bold(),
+            )
+            .border_style(Style::new().yellow())
+            .style(Style::default().bg(style::panel())),
+    );
+    frame.render_widget(info, info_area);
+
+    let title = format!("{game_name} {}", weekly.week_label());
+    draw_leaderboard(frame, board_area, app, &weekly.leaderboard(), &title);
+}
+
+/// Classement d'un jeu en graphique : les scores du top 10 selon leur date,
+/// sur le nuage de toutes les parties de l'historique, qui montre à quelle
+/// fréquence le jeu a été essayé. L'axe horizontal est en jours depuis la
+/// première partie
+fn draw_highscores_chart(frame: &mut Frame, area: Rect, app: &mut MainMenu, game_name: &str) {
+    let block = Block::bordered()
+        .title(format!(" {game_name} - Scores over Time ").yellow().bold())
+        .border_style(Style::new().yellow())
+        .style(Style::default().bg(style::panel()));
+
+    let scores: Vec<(chrono::DateTime<chrono::Utc>, u32)> = app
+        .highscore_manager
+        .get_scores(game_name)
+        .iter()
+        .map(|score| (score.timestamp, score.score))
+        .collect();
+    // Les parties sans score (simulations, parties quittées) comptent pour 0
+    let sessions: Vec<(chrono::DateTime<chrono::Utc>, u32)> = app
+        .history
+        .iter()
+        .filter(|record| record.game == game_name)
+        .map(|record| (record.timestamp, record.score.unwrap_or(0)))
+        .collect();
+
+    let times = || scores.iter().chain(&sessions).map(|&(time, _)| time);
+    let (Some(first_time), Some(last_time)) = (times().min(), times().max()) else {
+        let paragraph = Paragraph::new(format!(
+            "🏆 No scores yet for {game_name}!\n\nPlay this game to see your progress here."
+        ))
+        .block(block)
+        .style(Style::default().fg(style::text()))
+        .alignment(Alignment::Center)
+        .wrap(ratatui::widgets::Wrap { trim: true });
+        frame.render_widget(paragraph, area);
+        return;
+    };
+
+    let days_since_first =
+        |time: chrono::DateTime<chrono::Utc>| (time - first_time).num_seconds() as f64 / 86_400.0;
+    let points = |entries: &[(chrono::DateTime<chrono::Utc>, u32)]| -> Vec<(f64, f64)> {
+        entries
+            .iter()
+            .map(|&(time, score)| (days_since_first(time), score as f64))
+            .collect()
+    };
+    let score_points = points(&scores);
+    let session_points = points(&sessions);
+
+    let best = scores
+        .iter()
+        .chain(&sessions)
+        .map(|&(_, score)| score)
+        .max()
+        .unwrap_or(0);
+    // Bornes minimales pour qu'un seul point reste visible
+    let max_x = days_since_first(last_time).max(1.0);
+    let max_y = (best as f64 * 1.1).max(1.0);
+
+    let datasets = vec![
+        Dataset::default()
+            .name(format!("Sessions: {}", sessions.len()))
+            .marker(symbols::Marker::Braille)
+            .graph_type(GraphType::Scatter)
+            .style(Style::default().fg(Color::DarkGray))
+            .data(&session_points),
+        Dataset::default()
+            .name(format!("Top {}", scores.len()))
+            .marker(symbols::Marker::Dot)
+            .graph_type(GraphType::Scatter)
+            .style(Style::default().fg(Color::Yellow))
+            .data(&score_points),
+    ];
+
+    let chart = Chart::new(datasets)
+        .block(block)
+        .x_axis(
+            Axis::default()
+                .style(Style::default().fg(Color::Gray))
+                .bounds([0.0, max_x])
+                .labels([
+                    time_format::format_date(first_time),
+                    time_format::format_date(last_time),
+                ]),
         )
-        .style(Style::default().fg(Color::White))
-        .highlight_style(
-            Style::default()
-                .bg(Color::Rgb(200, 200, 0))
-                .fg(Color::Black)
-                .add_modifier(Modifier::BOLD),
+        .y_axis(
+            Axis::default()
+                .style(Style::default().fg(Color::Gray))
+                .bounds([0.0, max_y])
+                .labels(["0".to_string(), best.to_string()]),
+        );
+    frame.render_widget(chart, area);
+}
+
+/// Badge des scores ajoutés par la dernière partie
+fn new_badge(new: bool) -> Span<'static> {
+    if new {
+        Span::styled(
+            "  NEW",
+            Style::default().fg(Color::Black).bg(Color::Yellow).bold(),
         )
-        .highlight_symbol("▶ ");
+    } else {
+        Span::raw("")
+    }
+}
+
+/// Parties interrompues, avec leur statut, leur temps de jeu et l'heure de
+/// leur dernière sauvegarde
+fn draw_resume_menu(frame: &mut Frame, area: Rect, app: &mut MainMenu) {
+    let items: Vec<ListItem> = if app.interrupted.is_empty() {
+        vec![ListItem::new(Line::from(
+            "  Nothing left to resume".fg(style::text()),
+        ))]
+    } else {
+        app.interrupted
+            .iter()
+            .map(|(game_id, saved)| {
+                let summary = saved
+                    .summary
+                    .as_deref()
+                    .map(|summary| format!("  {summary}"))
+                    .unwrap_or_default();
+                let elapsed = saved
+                    .elapsed()
+                    .map(|elapsed| format!("  {}", time_format::format_duration(elapsed.as_secs())))
+                    .unwrap_or_default();
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!("  {} ", glyphs::icon("💾")),
+                        Style::default().fg(Color::Cyan),
+                    ),
+                    Span::styled(
+                        format!("{:<14}", app.game_display_name(game_id)),
+                        Style::default().fg(style::text()).bold(),
+                    ),
+                    Span::styled(summary, Style::default().fg(Color::Yellow)),
+                    Span::styled(elapsed, Style::default().fg(Color::Gray)),
+                    Span::styled(
+                        format!(
+                            "  Saved {}",
+                            time_format::format_date_time(saved.saved_at.to_utc())
+                        ),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                ]))
+            })
+            .collect()
+    };
+
+    let block = Block::bordered()
+        .title(" Interrupted Games ".cyan().bold())
+        .border_style(Style::new().cyan())
+        .style(Style::default().bg(style::panel()));
+    let highlight = style::focus(Color::Cyan);
 
-    frame.render_stateful_widget(list, area, &mut app.list_state);
+    app.list.render(frame, area, items, block, highlight);
 }
 
 fn draw_confirm_clear_scores(frame: &mut Frame, area: Rect, game_name: &str) {
@@ -1215,7 +3882,7 @@ fn draw_confirm_clear_scores(frame: &mut Frame, area: Rect, game_name: &str) {
         Line::from("⚠️  WARNING  ⚠️".red().bold()),
         Line::from(""),
         Line::from(vec![
-            "You are about to delete ALL high scores for ".white(),
+            "You are about to delete ALL high scores for ".fg(style::text()),
             game_name.yellow().bold(),
         ]),
         Line::from(""),
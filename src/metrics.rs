@@ -0,0 +1,111 @@
+//! Commande `termplay stats export` : instantané anonyme des statistiques de
+//! jeu, à joindre à un rapport de bug.
+//!
+//! Rien n'est jamais envoyé : la commande écrit un fichier JSON local que le
+//! joueur relit et partage lui-même. Elle ne fait rien sans confirmation
+//! explicite (`--yes` ou réponse à la question posée). L'instantané ne
+//! contient ni noms de joueurs, ni chemins, ni horodatages : seulement des
+//! totaux par jeu calculés depuis l'historique des parties.
+
+use crate::history::{self, SessionRecord};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::path::Path;
+
+/// Version du format de l'instantané, à changer si ses champs changent
+pub const SNAPSHOT_FORMAT: u32 = 1;
+
+/// Totaux anonymes d'un jeu
+#[derive(Debug, Default, Serialize)]
+pub struct GameMetrics {
+    pub sessions: usize,
+    pub total_seconds: u64,
+    pub best_score: Option<u32>,
+    /// Nombre de parties par mode de jeu
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub modes: BTreeMap<String, usize>,
+}
+
+/// Instantané partageable des statistiques
+#[derive(Debug, Serialize)]
+pub struct MetricsSnapshot {
+    pub format: u32,
+    pub version: &'static str,
+    pub os: &'static str,
+    pub arch: &'static str,
+    pub total_sessions: usize,
+    /// Nombre de jours différents avec au moins une partie
+    pub days_played: usize,
+    /// Totaux par identifiant de jeu
+    pub games: BTreeMap<String, GameMetrics>,
+}
+
+/// Calcule l'instantané à partir de l'historique des parties
+pub fn snapshot(records: &[SessionRecord]) -> MetricsSnapshot {
+    let mut games: BTreeMap<String, GameMetrics> = BTreeMap::new();
+    for record in records {
+        let game = games.entry(record.game.clone()).or_default();
+        game.sessions += 1;
+        game.total_seconds += record.duration_seconds;
+        game.best_score = game.best_score.max(record.score);
+        if let Some(mode) = &record.mode {
+            *game.modes.entry(mode.clone()).or_default() += 1;
+        }
+    }
+
+    let days: BTreeSet<_> = records
+        .iter()
+        .map(|record| record.timestamp.date_naive())
+        .collect();
+
+    MetricsSnapshot {
+        format: SNAPSHOT_FORMAT,
+        version: env!("CARGO_PKG_VERSION"),
+        os: std::env::consts::OS,
+        arch: std::env::consts::ARCH,
+        total_sessions: records.len(),
+        days_played: days.len(),
+        games,
+    }
+}
+
+/// Demande confirmation sur le terminal ; refuse si l'entrée n'est pas un
+/// terminal
+fn confirm() -> io::Result<bool> {
+    if !io::stdin().is_terminal() {
+        return Ok(false);
+    }
+
+    eprintln!("This exports a summary of your local play history:");
+    eprintln!("  per-game session counts, play time, best scores and modes,");
+    eprintln!("  plus the TermPlay version, OS and CPU architecture.");
+    eprintln!("No names, paths or dates are included, and nothing is sent anywhere.");
+    eprint!("Continue? [y/N] ");
+    io::stderr().flush()?;
+
+    let mut answer = String::new();
+    io::stdin().lock().read_line(&mut answer)?;
+    Ok(matches!(answer.trim(), "y" | "Y" | "yes" | "Yes"))
+}
+
+/// Écrit l'instantané dans `out`, ou sur la sortie standard sans fichier de
+/// sortie, après confirmation du joueur
+pub fn export(out: Option<&Path>, yes: bool) -> Result<(), Box<dyn std::error::Error>> {
+    if !yes && !confirm()? {
+        return Err("export cancelled (pass --yes to confirm non-interactively)".into());
+    }
+
+    let json = serde_json::to_string_pretty(&snapshot(&history::load()))?;
+    match out {
+        Some(path) => {
+            fs::write(path, json + "\n")
+                .map_err(|e| format!("cannot write {}: {e}", path.display()))?;
+            eprintln!("Statistics written to {}", path.display());
+        }
+        None => println!("{json}"),
+    }
+
+    Ok(())
+}
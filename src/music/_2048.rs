@@ -1,5 +1,4 @@
-use super::{create_note, GameMusic};
-use rodio::Sink;
+use super::{create_note, GameMusic, MusicVariant, NoteQueue};
 
 /// Musique relaxante et moderne pour 2048
 pub struct Game2048Music;
@@ -9,7 +8,27 @@ impl GameMusic for Game2048Music {
         "2048 Zen Mode"
     }
 
-    fn play_normal(&self, sink: &Sink, volume: f32) {
+    fn bpm(&self, variant: MusicVariant) -> u32 {
+        match variant {
+            MusicVariant::Normal => 100,
+            MusicVariant::Fast => 200,
+            MusicVariant::Celebration => 100,
+        }
+    }
+
+    fn sections(&self, variant: MusicVariant) -> &'static [(&'static str, usize)] {
+        match variant {
+            MusicVariant::Normal => &[
+                ("Intro", 0),
+                ("Theme", 4),
+                ("Variation", 14),
+                ("Conclusion", 20),
+            ],
+            _ => &[],
+        }
+    }
+
+    fn play_normal(&self, sink: &dyn NoteQueue, volume: f32) {
         // Mélodie zen et contemplative, inspirée de la musique de puzzle moderne
         // Utilise des progressions d'accords apaisantes
         let melody = vec![
@@ -49,7 +68,7 @@ impl GameMusic for Game2048Music {
         }
     }
 
-    fn play_fast(&self, sink: &Sink, volume: f32) {
+    fn play_fast(&self, sink: &dyn NoteQueue, volume: f32) {
         // Version plus énergique pour les moments de combo/points élevés
         let energetic_melody = vec![
             // Rythme plus soutenu, notes plus courtes
@@ -82,7 +101,7 @@ impl GameMusic for Game2048Music {
         }
     }
 
-    fn play_celebration(&self, sink: &Sink, volume: f32) {
+    fn play_celebration(&self, sink: &dyn NoteQueue, volume: f32) {
         // Mélodie de victoire pour atteindre 2048 ou plus
         let victory_sequence = vec![
             // Gamme ascendante triomphante
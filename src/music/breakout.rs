@@ -1,5 +1,4 @@
-use super::{create_note, GameMusic};
-use rodio::Sink;
+use super::{create_note, GameMusic, MusicVariant, NoteQueue};
 
 /// Musique arcade énergique pour Breakout
 pub struct BreakoutMusic;
@@ -9,7 +8,27 @@ impl GameMusic for BreakoutMusic {
         "Breakout Arcade"
     }
 
-    fn play_normal(&self, sink: &Sink, volume: f32) {
+    fn bpm(&self, variant: MusicVariant) -> u32 {
+        match variant {
+            MusicVariant::Normal => 200,
+            MusicVariant::Fast => 300,
+            MusicVariant::Celebration => 200,
+        }
+    }
+
+    fn sections(&self, variant: MusicVariant) -> &'static [(&'static str, usize)] {
+        match variant {
+            MusicVariant::Normal => &[
+                ("Intro", 0),
+                ("Theme", 5),
+                ("Variation", 16),
+                ("Conclusion", 23),
+            ],
+            _ => &[],
+        }
+    }
+
+    fn play_normal(&self, sink: &dyn NoteQueue, volume: f32) {
         // Mélodie arcade énergique et entraînante, style années 80
         let melody = vec![
             // Intro énergique - progression majeure
@@ -52,7 +71,7 @@ impl GameMusic for BreakoutMusic {
         }
     }
 
-    fn play_fast(&self, sink: &Sink, volume: f32) {
+    fn play_fast(&self, sink: &dyn NoteQueue, volume: f32) {
         // Version plus rapide et intense pour les moments critiques (peu de briques restantes)
         let intense_melody = vec![
             // Rythme accéléré, notes plus courtes
@@ -92,7 +111,7 @@ impl GameMusic for BreakoutMusic {
         }
     }
 
-    fn play_celebration(&self, sink: &Sink, volume: f32) {
+    fn play_celebration(&self, sink: &dyn NoteQueue, volume: f32) {
         // Musique de victoire arcade - fanfare triomphante
         let victory_sequence = vec![
             // Fanfare d'ouverture
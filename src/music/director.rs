@@ -0,0 +1,207 @@
+//! Pilotage de la musique en cours de lecture.
+//!
+//! Le `MusicDirector` garde une `LoopSource` unique dans le sink de musique :
+//! chaque musique demandée est rendue en mémoire (voir `prerender`) puis
+//! bouclée sans trou par cette source. La couche de percussions y est mixée
+//! avec un volume qui suit l'intensité de la partie, et le tempo suit la
+//! vitesse du jeu.
+
+use super::looper::{LoopControl, LoopRequest, LoopSource};
+use super::prerender::{render_stinger, PrerenderCache};
+use super::stingers::Stinger;
+use super::{GameMusic, MusicVariant};
+use crate::core::clock::Instant;
+use rodio::{mixer::Mixer, Sink};
+use std::cell::Cell;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Variation maximale de l'intensité par seconde, pour que la couche
+/// apparaisse et disparaisse en fondu
+const INTENSITY_RAMP_PER_SECOND: f32 = 0.5;
+/// Tempo à la vitesse de jeu maximale, par rapport au tempo d'origine
+const MAX_TEMPO: f32 = 1.3;
+/// Variation maximale du tempo par seconde : une accélération d'un coup (un
+/// niveau de Tetris) s'étale sur une seconde environ
+const TEMPO_RAMP_PER_SECOND: f32 = 0.05;
+
+/// Position de lecture de la musique en cours
+#[derive(Debug, Clone)]
+pub struct MusicPosition {
+    pub elapsed: Duration,
+    pub duration: Duration,
+    pub bpm: u32,
+    pub section: Option<&'static str>,
+}
+
+impl MusicPosition {
+    /// Avancement dans le morceau, entre 0.0 et 1.0
+    pub fn progress(&self) -> f64 {
+        if self.duration.is_zero() {
+            return 0.0;
+        }
+        (self.elapsed.as_secs_f64() / self.duration.as_secs_f64()).clamp(0.0, 1.0)
+    }
+
+    /// Position dans le temps en cours, entre 0.0 (sur le temps) et 1.0
+    pub fn beat_phase(&self) -> f32 {
+        let beat = 60.0 / self.bpm.max(1) as f64;
+        ((self.elapsed.as_secs_f64() % beat) / beat) as f32
+    }
+}
+
+pub struct MusicDirector {
+    sink: Sink,
+    control: Arc<LoopControl>,
+    prerendered: PrerenderCache,
+    intensity: Cell<f32>,
+    last_ramp: Cell<Instant>,
+    tempo: Cell<f32>,
+    last_tempo_ramp: Cell<Instant>,
+    /// Dernier jingle demandé et fin prévue de sa lecture
+    stinger: Cell<Option<(&'static str, Instant)>>,
+}
+
+impl MusicDirector {
+    pub fn new(mixer: &Mixer) -> Self {
+        let sink = Sink::connect_new(mixer);
+        let control = Arc::new(LoopControl::default());
+        let prerendered = PrerenderCache::default();
+        sink.append(LoopSource::new(Arc::clone(&control), prerendered.clone()));
+        // Forcer le démarrage de la lecture dans Rodio 0.21
+        sink.play();
+
+        Self {
+            sink,
+            control,
+            prerendered,
+            intensity: Cell::new(0.0),
+            last_ramp: Cell::new(Instant::now()),
+            tempo: Cell::new(1.0),
+            last_tempo_ramp: Cell::new(Instant::now()),
+            stinger: Cell::new(None),
+        }
+    }
+
+    /// Boucle la variante demandée. Si de la musique joue déjà, la variante
+    /// prend le relais à la fin du tour en cours ; la redemander alors
+    /// qu'elle boucle déjà est sans effet.
+    pub fn play(&self, music: &'static (dyn GameMusic + Sync), variant: MusicVariant, volume: f32) {
+        self.prerendered.request(music, variant);
+        self.control.set_volume(volume);
+        self.control
+            .set_looping(Some(LoopRequest { music, variant }));
+    }
+
+    /// Joue `stinger` une seule fois par-dessus la musique. La boucle en cours
+    /// est baissée jusqu'au silence puis abandonnée à la fin du jingle : la
+    /// musique demandée d'ici là avec [`Self::play`] prend le relais, sinon
+    /// tout s'arrête. Redemander le même jingle pendant qu'il joue est sans
+    /// effet.
+    pub fn play_stinger(&self, stinger: &'static Stinger, volume: f32) {
+        let now = Instant::now();
+        if self
+            .stinger
+            .get()
+            .is_some_and(|(name, end)| name == stinger.name && now < end)
+        {
+            return;
+        }
+        self.stinger
+            .set(Some((stinger.name, now + stinger.duration())));
+        self.control.set_looping(None);
+        self.control.play_stinger(render_stinger(stinger, volume));
+    }
+
+    /// Change le volume de la boucle en cours, sans attendre le prochain `play`
+    pub fn set_loop_volume(&self, volume: f32) {
+        self.control.set_volume(volume);
+    }
+
+    /// Baisse la musique à `level` (entre 0.0 et 1.0) pendant `duration`, avec
+    /// une rampe à la descente et à la remontée
+    pub fn duck(&self, level: f32, duration: Duration) {
+        self.control.duck(level, duration);
+    }
+
+    /// Laisse le tour en cours se terminer sans le relancer
+    pub fn finish_loop(&self) {
+        self.control.set_looping(None);
+    }
+
+    /// Arrête la musique et le jingle en cours, et remet l'intensité à zéro et le tempo d'origine
+    pub fn stop(&self) {
+        self.control.stop();
+        self.stinger.set(None);
+        // La prochaine musique repart d'un sink actif, même après une pause
+        self.resume();
+        self.intensity.set(0.0);
+        self.last_ramp.set(Instant::now());
+        self.control.set_intensity(0.0);
+        self.tempo.set(1.0);
+        self.last_tempo_ramp.set(Instant::now());
+        self.control.set_tempo(1.0);
+    }
+
+    /// Met la lecture en pause, au milieu du tour en cours
+    pub fn pause(&self) {
+        self.sink.pause();
+    }
+
+    /// Reprend la lecture là où elle a été mise en pause
+    pub fn resume(&self) {
+        self.sink.play();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.control.playing().is_none()
+    }
+
+    /// Position de lecture du morceau en cours
+    pub fn position(&self) -> Option<MusicPosition> {
+        let (track, elapsed) = self.control.playing()?;
+
+        Some(MusicPosition {
+            elapsed,
+            duration: track.timing.duration,
+            bpm: track.timing.bpm,
+            section: track.timing.section_at(elapsed),
+        })
+    }
+
+    /// Fixe l'intensité visée, entre 0.0 (piste seule) et 1.0 (couche au
+    /// maximum). La couche s'en rapproche progressivement à chaque appel.
+    pub fn set_intensity(&self, intensity: f32) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_ramp.replace(now));
+        let max_step = elapsed.as_secs_f32() * INTENSITY_RAMP_PER_SECOND;
+
+        let current = self.intensity.get();
+        let delta = (intensity.clamp(0.0, 1.0) - current).clamp(-max_step, max_step);
+        self.intensity.set(current + delta);
+        self.control.set_intensity(self.intensity.get());
+    }
+
+    /// Fixe la vitesse de jeu, entre 0.0 (tempo d'origine) et 1.0
+    /// ([`MAX_TEMPO`]). Le tempo s'en rapproche progressivement à chaque appel.
+    pub fn set_speed(&self, speed: f32) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_tempo_ramp.replace(now));
+        let max_step = elapsed.as_secs_f32() * TEMPO_RAMP_PER_SECOND;
+
+        let target = 1.0 + speed.clamp(0.0, 1.0) * (MAX_TEMPO - 1.0);
+        let current = self.tempo.get();
+        self.tempo
+            .set(current + (target - current).clamp(-max_step, max_step));
+        self.control.set_tempo(self.tempo.get());
+    }
+
+    /// Volume du sink de musique, utilisé pour les fondus
+    pub fn volume(&self) -> f32 {
+        self.sink.volume()
+    }
+
+    pub fn set_volume(&self, volume: f32) {
+        self.sink.set_volume(volume);
+    }
+}
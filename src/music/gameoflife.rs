@@ -1,5 +1,4 @@
-use super::{create_note, GameMusic};
-use rodio::Sink;
+use super::{create_note, GameMusic, MusicVariant, NoteQueue};
 
 /// Musique ambiante et évolutive pour Conway's Game of Life
 pub struct GameOfLifeMusic;
@@ -9,7 +8,27 @@ impl GameMusic for GameOfLifeMusic {
         "Game of Life Ambient"
     }
 
-    fn play_normal(&self, sink: &Sink, volume: f32) {
+    fn bpm(&self, variant: MusicVariant) -> u32 {
+        match variant {
+            MusicVariant::Normal => 150,
+            MusicVariant::Fast => 200,
+            MusicVariant::Celebration => 150,
+        }
+    }
+
+    fn sections(&self, variant: MusicVariant) -> &'static [(&'static str, usize)] {
+        match variant {
+            MusicVariant::Normal => &[
+                ("Intro", 0),
+                ("Theme", 5),
+                ("Variation", 17),
+                ("Conclusion", 23),
+            ],
+            _ => &[],
+        }
+    }
+
+    fn play_normal(&self, sink: &dyn NoteQueue, volume: f32) {
         // Musique ambiante contemplative et évolutive, inspirée de la science et l'émerveillement
         // Sons organiques et naturels, comme l'évolution de la vie
         let melody = vec![
@@ -52,7 +71,7 @@ impl GameMusic for GameOfLifeMusic {
         }
     }
 
-    fn play_fast(&self, sink: &Sink, volume: f32) {
+    fn play_fast(&self, sink: &dyn NoteQueue, volume: f32) {
         // Version plus dynamique pour les simulations rapides
         // Rythme accéléré mais toujours contemplatif
         let dynamic_melody = vec![
@@ -93,7 +112,7 @@ impl GameMusic for GameOfLifeMusic {
         }
     }
 
-    fn play_celebration(&self, sink: &Sink, volume: f32) {
+    fn play_celebration(&self, sink: &dyn NoteQueue, volume: f32) {
         // Musique d'émerveillement - pour les patterns complexes stables
         // Plus épique et émotionnelle, célébrant la beauté des automates cellulaires
         let wonder_sequence = vec![
@@ -0,0 +1,328 @@
+//! Source de musique bouclée sans trou.
+//!
+//! Une seule `LoopSource` reste dans le sink de musique pendant toute
+//! l'exécution. Elle rejoue la variante demandée depuis son rendu en mémoire
+//! et ne passe à une autre variante qu'à la fin d'un tour, directement sur le
+//! thread audio : les jeux n'ont plus à surveiller la fin de la musique pour
+//! ajouter la boucle suivante. La couche d'intensité est mixée dans la même
+//! source, ce qui la garde calée sur la piste. Le tempo est appliqué en
+//! relisant le rendu plus ou moins vite (interpolation linéaire), ce qui
+//! monte aussi un peu la hauteur des notes, comme une bande accélérée.
+//!
+//! Les jingles de fin de partie passent par la même source : mixés une seule
+//! fois par-dessus la boucle, qu'ils baissent jusqu'au silence, ils
+//! abandonnent en finissant le tour en cours. Le point de boucle suivant
+//! reprend alors la variante demandée entre-temps, ou rien.
+
+use super::prerender::{PrerenderCache, RenderedVariant, RENDER_CHANNELS, RENDER_SAMPLE_RATE};
+use super::{GameMusic, MusicVariant};
+use rodio::{source::Source, ChannelCount, SampleRate};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Nombre d'échantillons de silence entre deux vérifications quand aucune
+/// boucle n'est prête (10 ms)
+const IDLE_CHECK_SAMPLES: usize = RENDER_SAMPLE_RATE as usize / 100;
+
+/// Durée de la rampe de baisse de la musique (50 ms) et de sa remontée
+/// (400 ms), en échantillons
+const DUCK_ATTACK_SAMPLES: f32 = RENDER_SAMPLE_RATE as f32 * 0.05;
+const DUCK_RELEASE_SAMPLES: f32 = RENDER_SAMPLE_RATE as f32 * 0.4;
+
+/// Variante à boucler
+#[derive(Clone, Copy)]
+pub struct LoopRequest {
+    pub music: &'static (dyn GameMusic + Sync),
+    pub variant: MusicVariant,
+}
+
+/// État partagé entre le thread de l'interface et la source de musique
+#[derive(Default)]
+pub struct LoopControl {
+    /// Variante rejouée à chaque fin de tour, `None` pour s'arrêter à la fin
+    /// du tour en cours
+    looping: Mutex<Option<LoopRequest>>,
+    /// Tour en cours de lecture
+    playing: Mutex<Option<Arc<RenderedVariant>>>,
+    /// Échantillons déjà joués dans le tour en cours
+    played: AtomicUsize,
+    /// Abandonne le tour en cours au prochain échantillon
+    cut: AtomicBool,
+    /// Volume, intensité et tempo, stockés comme bits de `f32` ; un tempo à
+    /// zéro (valeur initiale) compte comme 1.0
+    volume: AtomicU32,
+    intensity: AtomicU32,
+    tempo: AtomicU32,
+    /// Niveau de la baisse demandée et sa durée en échantillons, consommée par
+    /// la source
+    duck_level: AtomicU32,
+    duck_hold: AtomicUsize,
+    /// Jingle rendu en attente de la source, signalé par `stinger_pending`
+    /// pour ne pas verrouiller à chaque échantillon
+    stinger: Mutex<Option<Arc<[f32]>>>,
+    stinger_pending: AtomicBool,
+}
+
+impl LoopControl {
+    /// Fixe la variante jouée à partir du prochain point de boucle
+    pub fn set_looping(&self, request: Option<LoopRequest>) {
+        *self.looping.lock().unwrap() = request;
+    }
+
+    /// Coupe le tour en cours et n'en relance aucun
+    pub fn stop(&self) {
+        *self.looping.lock().unwrap() = None;
+        *self.stinger.lock().unwrap() = None;
+        self.cut.store(true, Ordering::Relaxed);
+        *self.playing.lock().unwrap() = None;
+    }
+
+    /// Joue une fois `samples`, déjà rendus à leur volume, par-dessus la
+    /// boucle ; un jingle en cours est remplacé
+    pub fn play_stinger(&self, samples: Arc<[f32]>) {
+        *self.stinger.lock().unwrap() = Some(samples);
+        self.stinger_pending.store(true, Ordering::Relaxed);
+    }
+
+    /// Tour en cours et temps déjà joué
+    pub fn playing(&self) -> Option<(Arc<RenderedVariant>, Duration)> {
+        let playing = self.playing.lock().unwrap().clone()?;
+        let played = self.played.load(Ordering::Relaxed);
+        let elapsed = Duration::from_secs_f64(played as f64 / RENDER_SAMPLE_RATE as f64);
+        Some((playing, elapsed))
+    }
+
+    pub fn set_volume(&self, volume: f32) {
+        self.volume.store(volume.to_bits(), Ordering::Relaxed);
+    }
+
+    pub fn set_intensity(&self, intensity: f32) {
+        self.intensity.store(intensity.to_bits(), Ordering::Relaxed);
+    }
+
+    /// Vitesse de lecture du rendu, 1.0 pour le tempo d'origine
+    pub fn set_tempo(&self, tempo: f32) {
+        self.tempo.store(tempo.to_bits(), Ordering::Relaxed);
+    }
+
+    fn tempo(&self) -> f64 {
+        match f32::from_bits(self.tempo.load(Ordering::Relaxed)) {
+            tempo if tempo > 0.0 => tempo as f64,
+            _ => 1.0,
+        }
+    }
+
+    pub fn duck(&self, level: f32, duration: Duration) {
+        let samples = (duration.as_secs_f64() * RENDER_SAMPLE_RATE as f64) as usize;
+        self.duck_level
+            .store(level.clamp(0.0, 1.0).to_bits(), Ordering::Relaxed);
+        self.duck_hold.fetch_max(samples, Ordering::Relaxed);
+    }
+}
+
+/// Source infinie qui joue les boucles demandées à `LoopControl`
+pub struct LoopSource {
+    control: Arc<LoopControl>,
+    cache: PrerenderCache,
+    current: Option<Arc<RenderedVariant>>,
+    /// Position de lecture dans le rendu, fractionnaire quand le tempo
+    /// n'est pas 1.0
+    position: f64,
+    /// Échantillons de silence restants avant de chercher une boucle prête
+    idle: usize,
+    /// Gain appliqué par la baisse en cours, qui suit sa cible en rampe
+    duck_gain: f32,
+    /// Échantillons restants pendant lesquels la musique reste baissée
+    duck_hold: usize,
+    /// Jingle en cours et position de lecture
+    stinger: Option<Arc<[f32]>>,
+    stinger_position: usize,
+}
+
+impl LoopSource {
+    pub fn new(control: Arc<LoopControl>, cache: PrerenderCache) -> Self {
+        Self {
+            control,
+            cache,
+            current: None,
+            position: 0.0,
+            idle: 0,
+            duck_gain: 1.0,
+            duck_hold: 0,
+            stinger: None,
+            stinger_position: 0,
+        }
+    }
+
+    fn has_sample(&self) -> bool {
+        self.current
+            .as_ref()
+            .is_some_and(|current| (self.position as usize) < current.track.len())
+    }
+
+    /// Fait avancer la baisse de la musique d'un échantillon
+    fn next_duck_gain(&mut self) -> f32 {
+        let requested = self.control.duck_hold.swap(0, Ordering::Relaxed);
+        self.duck_hold = self.duck_hold.max(requested);
+
+        let target = if self.duck_hold > 0 {
+            self.duck_hold -= 1;
+            f32::from_bits(self.control.duck_level.load(Ordering::Relaxed))
+        } else {
+            1.0
+        };
+        // Un jingle couvre entièrement la boucle
+        let target = if self.stinger.is_some() { 0.0 } else { target };
+        let step = if target < self.duck_gain {
+            1.0 / DUCK_ATTACK_SAMPLES
+        } else {
+            1.0 / DUCK_RELEASE_SAMPLES
+        };
+        self.duck_gain += (target - self.duck_gain).clamp(-step, step);
+        self.duck_gain
+    }
+
+    /// Échantillon suivant du jingle en cours, 0.0 sans jingle. À la fin du
+    /// jingle, le tour baissé est abandonné : la boucle demandée entre-temps
+    /// reprend au début, ou la musique s'arrête
+    fn next_stinger_sample(&mut self) -> f32 {
+        if self.control.stinger_pending.swap(false, Ordering::Relaxed) {
+            self.stinger = self.control.stinger.lock().unwrap().take();
+            self.stinger_position = 0;
+        }
+
+        let Some(stinger) = &self.stinger else {
+            return 0.0;
+        };
+        match stinger.get(self.stinger_position) {
+            Some(&sample) => {
+                self.stinger_position += 1;
+                sample
+            }
+            None => {
+                self.stinger = None;
+                self.current = None;
+                self.idle = 0;
+                0.0
+            }
+        }
+    }
+
+    /// Échantillon suivant de la boucle, baissé de `duck_gain`
+    fn next_loop_sample(&mut self, duck_gain: f32) -> f32 {
+        if !self.has_sample() {
+            if self.idle > 0 {
+                self.idle -= 1;
+                return 0.0;
+            }
+            self.start_next_loop();
+            if !self.has_sample() {
+                self.idle = IDLE_CHECK_SAMPLES;
+                return 0.0;
+            }
+        }
+
+        let Some(current) = self.current.as_ref() else {
+            return 0.0;
+        };
+        let volume = f32::from_bits(self.control.volume.load(Ordering::Relaxed));
+        let intensity = f32::from_bits(self.control.intensity.load(Ordering::Relaxed));
+        let track = interpolate(&current.track, self.position);
+        let layer = interpolate(&current.layer, self.position);
+        let sample = (track + layer * intensity) * volume * duck_gain;
+
+        self.position += self.control.tempo();
+        self.control
+            .played
+            .store(self.position as usize, Ordering::Relaxed);
+        sample
+    }
+
+    /// Point de boucle : reprend la variante demandée, si son rendu est prêt
+    fn start_next_loop(&mut self) {
+        let request = *self.control.looping.lock().unwrap();
+        self.current = request.and_then(|request| self.cache.get(request.music, request.variant));
+        self.position = 0.0;
+
+        *self.control.playing.lock().unwrap() = self.current.clone();
+        self.control.played.store(0, Ordering::Relaxed);
+    }
+}
+
+impl Iterator for LoopSource {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        if self.control.cut.swap(false, Ordering::Relaxed) {
+            self.current = None;
+            self.idle = 0;
+            self.stinger = None;
+        }
+        let stinger = self.next_stinger_sample();
+        let duck_gain = self.next_duck_gain();
+        Some(self.next_loop_sample(duck_gain) + stinger)
+    }
+}
+
+/// Échantillon à une position fractionnaire, entre les deux voisins ; 0.0
+/// au-delà de la fin (couche absente ou plus courte)
+fn interpolate(samples: &[f32], position: f64) -> f32 {
+    let index = position as usize;
+    let Some(&before) = samples.get(index) else {
+        return 0.0;
+    };
+    let after = samples.get(index + 1).copied().unwrap_or(before);
+    let fraction = (position - index as f64) as f32;
+    before + (after - before) * fraction
+}
+
+impl Source for LoopSource {
+    fn current_span_len(&self) -> Option<usize> {
+        None
+    }
+
+    fn channels(&self) -> ChannelCount {
+        RENDER_CHANNELS
+    }
+
+    fn sample_rate(&self) -> SampleRate {
+        RENDER_SAMPLE_RATE
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interpolation_blends_neighbouring_samples() {
+        let samples = [0.0, 1.0, -1.0];
+        assert_eq!(interpolate(&samples, 1.0), 1.0);
+        assert_eq!(interpolate(&samples, 0.25), 0.25);
+        assert_eq!(interpolate(&samples, 1.5), 0.0);
+        // Dernier échantillon tenu, puis silence
+        assert_eq!(interpolate(&samples, 2.5), -1.0);
+        assert_eq!(interpolate(&samples, 3.0), 0.0);
+    }
+
+    #[test]
+    fn a_stinger_plays_once_then_hands_back_to_silence() {
+        let control = Arc::new(LoopControl::default());
+        let mut source = LoopSource::new(Arc::clone(&control), PrerenderCache::default());
+
+        control.play_stinger(Arc::from([0.5, -0.5, 0.25]));
+        let samples: Vec<f32> = source.by_ref().take(5).collect();
+        assert_eq!(samples, [0.5, -0.5, 0.25, 0.0, 0.0]);
+        assert!(source.stinger.is_none());
+
+        // Coupé par un arrêt de la musique avant d'avoir commencé
+        control.play_stinger(Arc::from([0.5]));
+        control.stop();
+        assert_eq!(source.next(), Some(0.0));
+    }
+}
@@ -1,5 +1,4 @@
-use super::{create_note, GameMusic};
-use rodio::Sink;
+use super::{create_note, GameMusic, MusicVariant, NoteQueue};
 
 /// Musique tendue et contemplative pour Minesweeper
 pub struct MinesweeperMusic;
@@ -9,7 +8,27 @@ impl GameMusic for MinesweeperMusic {
         "Minesweeper Tension"
     }
 
-    fn play_normal(&self, sink: &Sink, volume: f32) {
+    fn bpm(&self, variant: MusicVariant) -> u32 {
+        match variant {
+            MusicVariant::Normal => 150,
+            MusicVariant::Fast => 200,
+            MusicVariant::Celebration => 150,
+        }
+    }
+
+    fn sections(&self, variant: MusicVariant) -> &'static [(&'static str, usize)] {
+        match variant {
+            MusicVariant::Normal => &[
+                ("Intro", 0),
+                ("Theme", 5),
+                ("Variation", 15),
+                ("Return", 20),
+            ],
+            _ => &[],
+        }
+    }
+
+    fn play_normal(&self, sink: &dyn NoteQueue, volume: f32) {
         // Musique contemplative et tendue, mélodie mineure mystérieuse
         let melody = vec![
             // Intro mystérieuse - tons mineurs
@@ -48,7 +67,7 @@ impl GameMusic for MinesweeperMusic {
         }
     }
 
-    fn play_fast(&self, sink: &Sink, volume: f32) {
+    fn play_fast(&self, sink: &dyn NoteQueue, volume: f32) {
         // Version plus rapide et tendue pour les moments critiques
         let intense_melody = vec![
             // Rythme plus soutenu, notes plus courtes
@@ -85,7 +104,7 @@ impl GameMusic for MinesweeperMusic {
         }
     }
 
-    fn play_celebration(&self, sink: &Sink, volume: f32) {
+    fn play_celebration(&self, sink: &dyn NoteQueue, volume: f32) {
         // Musique de victoire - libération de la tension
         let victory_sequence = vec![
             // Gamme ascendante libératrice
@@ -1,30 +1,148 @@
 pub mod _2048;
 pub mod breakout;
+pub mod director;
 pub mod gameoflife;
+pub mod looper;
 pub mod minesweeper;
 pub mod pong;
+pub mod prerender;
+pub mod rhythm;
 pub mod snake;
+pub mod stingers;
 pub mod tetris;
 
-use rodio::{
-    source::{SineWave, Source},
-    Sink,
-};
+use rodio::source::{SineWave, Source, SquareWave};
+use std::cell::RefCell;
 use std::time::Duration;
 
+/// Variante d'une musique de jeu
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MusicVariant {
+    Normal,
+    Fast,
+    Celebration,
+}
+
+/// File de notes dans laquelle une musique est jouée
+pub trait NoteQueue {
+    fn append(&self, note: Box<dyn Source<Item = f32> + Send>);
+}
+
+/// File factice qui mesure la durée de chaque note sans rien jouer
+#[derive(Default)]
+struct DurationMeter {
+    notes: RefCell<Vec<Duration>>,
+}
+
+impl NoteQueue for DurationMeter {
+    fn append(&self, note: Box<dyn Source<Item = f32> + Send>) {
+        let duration = note.total_duration().unwrap_or_default();
+        self.notes.borrow_mut().push(duration);
+    }
+}
+
+/// Métadonnées temporelles d'une musique, utilisées pour enchaîner les boucles
+/// sans trou et synchroniser des effets sur le tempo
+#[derive(Debug, Clone)]
+pub struct TrackTiming {
+    pub duration: Duration,
+    pub bpm: u32,
+    /// Sections nommées et leur position depuis le début du morceau
+    pub sections: Vec<(&'static str, Duration)>,
+}
+
+impl TrackTiming {
+    /// Section en cours à la position `position`
+    pub fn section_at(&self, position: Duration) -> Option<&'static str> {
+        self.sections
+            .iter()
+            .rev()
+            .find(|(_, start)| *start <= position)
+            .map(|(name, _)| *name)
+    }
+}
+
 /// Trait pour définir les différentes musiques du jeu
 pub trait GameMusic {
     /// Joue la musique normale
-    fn play_normal(&self, sink: &Sink, volume: f32);
+    fn play_normal(&self, sink: &dyn NoteQueue, volume: f32);
 
     /// Joue la musique rapide (pour les niveaux élevés)
-    fn play_fast(&self, sink: &Sink, volume: f32);
+    fn play_fast(&self, sink: &dyn NoteQueue, volume: f32);
 
     /// Joue la musique de célébration
-    fn play_celebration(&self, sink: &Sink, volume: f32);
+    fn play_celebration(&self, sink: &dyn NoteQueue, volume: f32);
 
     /// Nom de la musique
     fn name(&self) -> &str;
+
+    /// Tempo de la variante, en battements par minute
+    fn bpm(&self, variant: MusicVariant) -> u32;
+
+    /// Sections de la variante : nom et index de la première note
+    fn sections(&self, _variant: MusicVariant) -> &'static [(&'static str, usize)] {
+        &[]
+    }
+
+    /// Joue la variante demandée
+    fn play(&self, variant: MusicVariant, sink: &dyn NoteQueue, volume: f32) {
+        match variant {
+            MusicVariant::Normal => self.play_normal(sink, volume),
+            MusicVariant::Fast => self.play_fast(sink, volume),
+            MusicVariant::Celebration => self.play_celebration(sink, volume),
+        }
+    }
+
+    /// Joue la couche d'intensité de la variante : des percussions calées sur
+    /// le tempo, de la même durée que la piste principale
+    fn play_layer(&self, variant: MusicVariant, sink: &dyn NoteQueue, volume: f32) {
+        let timing = self.timing(variant);
+        let beat = (60_000 / timing.bpm.max(1)) as u64;
+        let total = timing.duration.as_millis() as u64;
+
+        let mut elapsed = 0;
+        let mut beat_index = 0;
+        while elapsed + beat <= total {
+            // Grosse caisse sur les temps forts, caisse claire sur les autres
+            let (frequency, hit_ms) = if beat_index % 2 == 0 {
+                (80.0, 90)
+            } else {
+                (1800.0, 40)
+            };
+            sink.append(create_percussion(frequency, hit_ms, volume * 0.5));
+            sink.append(create_note(0.0, beat - hit_ms.min(beat), 0.0));
+            elapsed += beat;
+            beat_index += 1;
+        }
+
+        if total > elapsed {
+            sink.append(create_note(0.0, total - elapsed, 0.0));
+        }
+    }
+
+    /// Durée totale de la variante, mesurée note par note
+    fn duration(&self, variant: MusicVariant) -> Duration {
+        self.timing(variant).duration
+    }
+
+    /// Durée, tempo et position des sections de la variante
+    fn timing(&self, variant: MusicVariant) -> TrackTiming {
+        let meter = DurationMeter::default();
+        self.play(variant, &meter, 0.0);
+        let notes = meter.notes.into_inner();
+
+        let sections = self
+            .sections(variant)
+            .iter()
+            .map(|&(name, index)| (name, notes.iter().take(index).sum()))
+            .collect();
+
+        TrackTiming {
+            duration: notes.iter().sum(),
+            bpm: self.bpm(variant),
+            sections,
+        }
+    }
 }
 
 /// Helper pour créer des notes avec fade in/out - Compatible Rodio 0.21
@@ -46,6 +164,20 @@ pub fn create_note(
     )
 }
 
+/// Helper pour créer un coup de percussion bref et sec
+pub fn create_percussion(
+    frequency: f32,
+    duration_ms: u64,
+    volume: f32,
+) -> Box<dyn Source<Item = f32> + Send> {
+    Box::new(
+        SquareWave::new(frequency)
+            .take_duration(Duration::from_millis(duration_ms))
+            .fade_out(Duration::from_millis(duration_ms))
+            .amplify(volume),
+    )
+}
+
 /// Helper pour créer des accords (plusieurs notes simultanées) - Compatible Rodio 0.21
 pub fn create_chord(
     frequencies: &[f32],
@@ -1,5 +1,4 @@
-use super::{create_note, GameMusic};
-use rodio::Sink;
+use super::{create_note, GameMusic, MusicVariant, NoteQueue};
 
 /// Musique électronique rétro pour Pong
 pub struct PongMusic;
@@ -9,7 +8,22 @@ impl GameMusic for PongMusic {
         "Pong Retro Electronic"
     }
 
-    fn play_normal(&self, sink: &Sink, volume: f32) {
+    fn bpm(&self, variant: MusicVariant) -> u32 {
+        match variant {
+            MusicVariant::Normal => 150,
+            MusicVariant::Fast => 200,
+            MusicVariant::Celebration => 150,
+        }
+    }
+
+    fn sections(&self, variant: MusicVariant) -> &'static [(&'static str, usize)] {
+        match variant {
+            MusicVariant::Normal => &[("Intro", 0), ("Main", 4), ("Variation", 14), ("Return", 18)],
+            _ => &[],
+        }
+    }
+
+    fn play_normal(&self, sink: &dyn NoteQueue, volume: f32) {
         // Mélodie électronique minimaliste inspirée des années 70-80
         // Basée sur des gammes pentatoniques avec un rythme répétitif
         let melody = vec![
@@ -47,7 +61,7 @@ impl GameMusic for PongMusic {
         }
     }
 
-    fn play_fast(&self, sink: &Sink, volume: f32) {
+    fn play_fast(&self, sink: &dyn NoteQueue, volume: f32) {
         // Version accélérée pour les moments intenses (balles rapides)
         let fast_melody = vec![
             // Rythme plus rapide et plus intense
@@ -75,7 +89,7 @@ impl GameMusic for PongMusic {
         }
     }
 
-    fn play_celebration(&self, sink: &Sink, volume: f32) {
+    fn play_celebration(&self, sink: &dyn NoteQueue, volume: f32) {
         // Mélodie de victoire - montée triomphante
         let celebration = vec![
             // Gamme ascendante triomphante
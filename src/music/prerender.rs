@@ -0,0 +1,145 @@
+//! Pré-rendu des musiques en mémoire.
+//!
+//! Une boucle de musique est une suite de centaines de notes courtes : les
+//! construire et les ajouter une à une au sink depuis le thread de
+//! l'interface provoque des à-coups à chaque relance. Les variantes de la
+//! musique demandée sont donc rendues une fois pour toutes en échantillons
+//! PCM en tâche de fond, puis bouclés sans trou par `LoopSource`.
+//!
+//! Seule la dernière musique demandée est gardée en cache, pour borner la
+//! mémoire utilisée.
+
+use super::stingers::Stinger;
+use super::{GameMusic, MusicVariant, NoteQueue, TrackTiming};
+use crate::jobs;
+use rodio::{
+    source::{Source, UniformSourceIterator},
+    ChannelCount, SampleRate,
+};
+use std::cell::RefCell;
+use std::sync::{Arc, Mutex};
+
+/// Format des tampons : celui des générateurs de Rodio, mono à 48 kHz
+pub(super) const RENDER_CHANNELS: ChannelCount = 1;
+pub(super) const RENDER_SAMPLE_RATE: SampleRate = 48_000;
+
+const VARIANTS: [MusicVariant; 3] = [
+    MusicVariant::Normal,
+    MusicVariant::Fast,
+    MusicVariant::Celebration,
+];
+
+/// File qui rend chaque note en échantillons au lieu de la jouer
+#[derive(Default)]
+struct PcmRecorder {
+    samples: RefCell<Vec<f32>>,
+}
+
+impl NoteQueue for PcmRecorder {
+    fn append(&self, note: Box<dyn Source<Item = f32> + Send>) {
+        let note = UniformSourceIterator::new(note, RENDER_CHANNELS, RENDER_SAMPLE_RATE);
+        self.samples.borrow_mut().extend(note);
+    }
+}
+
+/// Variante rendue : piste principale, couche d'intensité et chronologie
+pub struct RenderedVariant {
+    pub timing: TrackTiming,
+    pub(super) track: Vec<f32>,
+    pub(super) layer: Vec<f32>,
+}
+
+impl RenderedVariant {
+    fn render(music: &dyn GameMusic, variant: MusicVariant) -> Self {
+        // Rendu à plein volume : le volume est appliqué à la lecture
+        let track = PcmRecorder::default();
+        music.play(variant, &track, 1.0);
+        let layer = PcmRecorder::default();
+        music.play_layer(variant, &layer, 1.0);
+
+        Self {
+            timing: music.timing(variant),
+            track: track.samples.into_inner(),
+            layer: layer.samples.into_inner(),
+        }
+    }
+}
+
+/// Jingle rendu au volume `volume`. Assez court pour être rendu sur le
+/// thread de l'interface, au moment de le jouer
+pub(super) fn render_stinger(stinger: &Stinger, volume: f32) -> Arc<[f32]> {
+    let recorder = PcmRecorder::default();
+    stinger.play(&recorder, volume);
+    recorder.samples.into_inner().into()
+}
+
+#[derive(Default)]
+struct CacheState {
+    /// Musique en cache ou en cours de rendu
+    music: Option<String>,
+    variants: Vec<(MusicVariant, Arc<RenderedVariant>)>,
+}
+
+/// Cache des variantes rendues, rempli par une tâche de fond
+#[derive(Clone, Default)]
+pub struct PrerenderCache {
+    state: Arc<Mutex<CacheState>>,
+}
+
+impl PrerenderCache {
+    /// Lance en arrière-plan le rendu de toutes les variantes de la musique,
+    /// en commençant par `first`, sauf si elles sont déjà en cache ou en cours
+    /// de rendu
+    pub fn request(&self, music: &'static (dyn GameMusic + Sync), first: MusicVariant) {
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        if state.music.as_deref() == Some(music.name()) {
+            return;
+        }
+        state.music = Some(music.name().to_string());
+        state.variants.clear();
+        drop(state);
+
+        let cache = self.clone();
+        // Le cache est rempli au fil du rendu : rien à attendre du résultat.
+        // Sans thread disponible, la musique ne sera simplement pas jouée
+        jobs::spawn("Preparing music", move || cache.render_all(music, first));
+    }
+
+    /// Variante rendue, si elle est prête
+    pub fn get(
+        &self,
+        music: &dyn GameMusic,
+        variant: MusicVariant,
+    ) -> Option<Arc<RenderedVariant>> {
+        let state = self.state.lock().ok()?;
+        if state.music.as_deref() != Some(music.name()) {
+            return None;
+        }
+        state
+            .variants
+            .iter()
+            .find(|(rendered, _)| *rendered == variant)
+            .map(|(_, rendered)| Arc::clone(rendered))
+    }
+
+    fn render_all(&self, music: &'static (dyn GameMusic + Sync), first: MusicVariant) {
+        let others = VARIANTS.into_iter().filter(|&variant| variant != first);
+        for variant in std::iter::once(first).chain(others) {
+            if jobs::cancelled() {
+                return;
+            }
+            let rendered = Arc::new(RenderedVariant::render(music, variant));
+
+            let Ok(mut state) = self.state.lock() else {
+                return;
+            };
+            // Une autre musique a été demandée entre-temps
+            if state.music.as_deref() != Some(music.name()) {
+                return;
+            }
+            state.variants.push((variant, rendered));
+        }
+    }
+}
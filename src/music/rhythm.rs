@@ -0,0 +1,160 @@
+use super::{create_note, create_percussion, GameMusic, MusicVariant, NoteQueue};
+use std::time::Duration;
+
+/// Temps de métronome joués avant la mélodie
+pub const COUNT_IN_BEATS: u32 = 4;
+
+/// Mélodie du jeu de rythme : fréquence (0.0 pour un silence) et durée en
+/// croches. Chaque note est aussi une note à jouer dans le jeu (voir
+/// [`chart`]).
+const MELODY: [(f32, u32); 39] = [
+    // Couplet
+    (262.0, 2), // C4
+    (330.0, 2), // E4
+    (392.0, 2), // G4
+    (330.0, 2), // E4
+    (440.0, 2), // A4
+    (392.0, 2), // G4
+    (330.0, 4), // E4
+    (294.0, 2), // D4
+    (330.0, 2), // E4
+    (392.0, 2), // G4
+    (440.0, 2), // A4
+    (392.0, 4), // G4
+    (0.0, 4),
+    // Refrain
+    (523.0, 2), // C5
+    (440.0, 2), // A4
+    (392.0, 2), // G4
+    (440.0, 2), // A4
+    (523.0, 1), // C5
+    (587.0, 1), // D5
+    (659.0, 2), // E5
+    (587.0, 2), // D5
+    (523.0, 2), // C5
+    (440.0, 2), // A4
+    (392.0, 1), // G4
+    (440.0, 1), // A4
+    (523.0, 2), // C5
+    (587.0, 2), // D5
+    (659.0, 4), // E5
+    (523.0, 4), // C5
+    // Fin
+    (262.0, 2), // C4
+    (330.0, 2), // E4
+    (392.0, 2), // G4
+    (523.0, 2), // C5
+    (294.0, 1), // D4
+    (392.0, 1), // G4
+    (587.0, 2), // D5
+    (659.0, 2), // E5
+    (523.0, 2), // C5
+    (262.0, 8), // C4
+];
+
+/// Index de la première note du refrain et de la fin dans `MELODY`
+const CHORUS_INDEX: usize = 13;
+const OUTRO_INDEX: usize = 29;
+
+/// Morceau métronomé du jeu de rythme
+pub struct RhythmMusic;
+
+/// Colonne du jeu pour une hauteur de note, des graves aux aigus
+pub fn lane(frequency: f32) -> usize {
+    match frequency {
+        f if f < 300.0 => 0,
+        f if f < 400.0 => 1,
+        f if f < 550.0 => 2,
+        _ => 3,
+    }
+}
+
+/// Partition de la variante : instant de chaque note depuis le début du
+/// morceau et sa colonne, silences exclus
+pub fn chart(variant: MusicVariant) -> Vec<(Duration, usize)> {
+    let eighth = eighth_ms(variant);
+    let mut time = COUNT_IN_BEATS as u64 * 2 * eighth;
+    let mut notes = Vec::new();
+    for (frequency, eighths) in MELODY {
+        if frequency > 0.0 {
+            notes.push((Duration::from_millis(time), lane(frequency)));
+        }
+        time += eighths as u64 * eighth;
+    }
+    notes
+}
+
+/// Durée du morceau, décompte compris
+pub fn song_length(variant: MusicVariant) -> Duration {
+    let eighths: u32 = COUNT_IN_BEATS * 2 + MELODY.iter().map(|&(_, eighths)| eighths).sum::<u32>();
+    Duration::from_millis(eighths as u64 * eighth_ms(variant))
+}
+
+fn eighth_ms(variant: MusicVariant) -> u64 {
+    30_000 / RHYTHM_MUSIC.bpm(variant) as u64
+}
+
+impl RhythmMusic {
+    /// Décompte puis mélodie, au tempo de la variante
+    fn play_song(&self, variant: MusicVariant, sink: &dyn NoteQueue, volume: f32) {
+        let beat = eighth_ms(variant) * 2;
+        for _ in 0..COUNT_IN_BEATS {
+            sink.append(create_percussion(1500.0, 30, volume * 0.4));
+            sink.append(create_note(0.0, beat - 30, 0.0));
+        }
+
+        for (frequency, eighths) in MELODY {
+            sink.append(create_note(
+                frequency,
+                eighths as u64 * eighth_ms(variant),
+                volume * 0.6,
+            ));
+        }
+    }
+}
+
+impl GameMusic for RhythmMusic {
+    fn name(&self) -> &str {
+        "Rhythm Beat"
+    }
+
+    fn bpm(&self, variant: MusicVariant) -> u32 {
+        match variant {
+            MusicVariant::Normal => 120,
+            MusicVariant::Fast => 150,
+            MusicVariant::Celebration => 160,
+        }
+    }
+
+    fn sections(&self, variant: MusicVariant) -> &'static [(&'static str, usize)] {
+        // Le décompte ajoute deux sons par temps avant la mélodie
+        const COUNT_IN: usize = COUNT_IN_BEATS as usize * 2;
+        match variant {
+            MusicVariant::Normal | MusicVariant::Fast => &[
+                ("Count-in", 0),
+                ("Verse", COUNT_IN),
+                ("Chorus", COUNT_IN + CHORUS_INDEX),
+                ("Outro", COUNT_IN + OUTRO_INDEX),
+            ],
+            MusicVariant::Celebration => &[],
+        }
+    }
+
+    fn play_normal(&self, sink: &dyn NoteQueue, volume: f32) {
+        self.play_song(MusicVariant::Normal, sink, volume);
+    }
+
+    fn play_fast(&self, sink: &dyn NoteQueue, volume: f32) {
+        self.play_song(MusicVariant::Fast, sink, volume);
+    }
+
+    fn play_celebration(&self, sink: &dyn NoteQueue, volume: f32) {
+        // Arpège montant pour un résultat parfait ou presque
+        for (frequency, duration_ms) in [(523.0, 120), (659.0, 120), (784.0, 120), (1046.0, 360)] {
+            sink.append(create_note(frequency, duration_ms, volume * 0.8));
+        }
+    }
+}
+
+/// Instance globale de la musique du jeu de rythme
+pub const RHYTHM_MUSIC: RhythmMusic = RhythmMusic;
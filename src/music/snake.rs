@@ -1,5 +1,4 @@
-use super::{create_note, GameMusic};
-use rodio::Sink;
+use super::{create_note, GameMusic, MusicVariant, NoteQueue};
 
 /// Musique simple et répétitive pour Snake
 pub struct SnakeMusic;
@@ -9,7 +8,22 @@ impl GameMusic for SnakeMusic {
         "Snake Ambient"
     }
 
-    fn play_normal(&self, sink: &Sink, volume: f32) {
+    fn bpm(&self, variant: MusicVariant) -> u32 {
+        match variant {
+            MusicVariant::Normal => 150,
+            MusicVariant::Fast => 300,
+            MusicVariant::Celebration => 200,
+        }
+    }
+
+    fn sections(&self, variant: MusicVariant) -> &'static [(&'static str, usize)] {
+        match variant {
+            MusicVariant::Normal => &[("Theme", 0), ("Variation", 7)],
+            _ => &[],
+        }
+    }
+
+    fn play_normal(&self, sink: &dyn NoteQueue, volume: f32) {
         // Mélodie simple et apaisante pour Snake
         // Basée sur une progression d'accords mineure
         let melody = vec![
@@ -36,7 +50,7 @@ impl GameMusic for SnakeMusic {
         }
     }
 
-    fn play_fast(&self, sink: &Sink, volume: f32) {
+    fn play_fast(&self, sink: &dyn NoteQueue, volume: f32) {
         // Version plus rapide avec des notes plus courtes
         let fast_melody = vec![
             (440.0, 300), // A4
@@ -61,7 +75,7 @@ impl GameMusic for SnakeMusic {
         }
     }
 
-    fn play_celebration(&self, sink: &Sink, volume: f32) {
+    fn play_celebration(&self, sink: &dyn NoteQueue, volume: f32) {
         // Petite mélodie de célébration quand le serpent mange
         let celebration = vec![
             (659.0, 150),  // E5
@@ -0,0 +1,126 @@
+//! Jingles de fin de partie : une courte fanfare de victoire et quelques
+//! notes de game over pour chaque jeu.
+//!
+//! Un jingle n'est pas une boucle : le `MusicDirector` le joue une seule fois
+//! par-dessus la musique, qu'il baisse jusqu'au silence, puis rend la main
+//! au silence ou à la musique demandée entre-temps (voir
+//! `MusicDirector::play_stinger`).
+
+use super::{create_note, NoteQueue};
+use std::time::Duration;
+
+/// Jingle joué une fois à la fin d'une partie
+pub struct Stinger {
+    pub name: &'static str,
+    /// Notes jouées l'une après l'autre : fréquence (0.0 pour un silence) et
+    /// durée en millisecondes
+    notes: &'static [(f32, u64)],
+}
+
+impl Stinger {
+    /// Joue le jingle dans `sink`
+    pub fn play(&self, sink: &dyn NoteQueue, volume: f32) {
+        for &(frequency, duration_ms) in self.notes {
+            sink.append(create_note(frequency, duration_ms, volume));
+        }
+    }
+
+    pub fn duration(&self) -> Duration {
+        Duration::from_millis(self.notes.iter().map(|&(_, duration_ms)| duration_ms).sum())
+    }
+}
+
+pub static SNAKE_GAME_OVER: Stinger = Stinger {
+    name: "Snake – Game Over",
+    notes: &[(440.0, 180), (392.0, 180), (330.0, 180), (220.0, 600)],
+};
+
+pub static SNAKE_VICTORY: Stinger = Stinger {
+    name: "Snake – Victory",
+    notes: &[(440.0, 120), (523.0, 120), (659.0, 120), (880.0, 500)],
+};
+
+pub static TETRIS_GAME_OVER: Stinger = Stinger {
+    name: "Tetris – Game Over",
+    // Fin de Korobeiniki, ralentie et descendante
+    notes: &[
+        (659.0, 250),
+        (523.0, 250),
+        (440.0, 250),
+        (0.0, 100),
+        (330.0, 250),
+        (220.0, 700),
+    ],
+};
+
+pub static TETRIS_VICTORY: Stinger = Stinger {
+    name: "Tetris – Victory",
+    notes: &[(659.0, 150), (784.0, 150), (988.0, 150), (1319.0, 600)],
+};
+
+pub static PONG_GAME_OVER: Stinger = Stinger {
+    name: "Pong – Game Over",
+    notes: &[(392.0, 200), (330.0, 200), (262.0, 600)],
+};
+
+pub static PONG_VICTORY: Stinger = Stinger {
+    name: "Pong – Victory",
+    notes: &[
+        (523.0, 120),
+        (523.0, 120),
+        (659.0, 120),
+        (784.0, 240),
+        (1047.0, 500),
+    ],
+};
+
+pub static BREAKOUT_GAME_OVER: Stinger = Stinger {
+    name: "Breakout – Game Over",
+    notes: &[
+        (523.0, 150),
+        (494.0, 150),
+        (466.0, 150),
+        (440.0, 150),
+        (0.0, 80),
+        (220.0, 600),
+    ],
+};
+
+pub static BREAKOUT_VICTORY: Stinger = Stinger {
+    name: "Breakout – Victory",
+    notes: &[
+        (523.0, 100),
+        (659.0, 100),
+        (784.0, 100),
+        (1047.0, 200),
+        (784.0, 100),
+        (1047.0, 600),
+    ],
+};
+
+pub static GAME2048_GAME_OVER: Stinger = Stinger {
+    name: "2048 – Game Over",
+    notes: &[(349.0, 300), (294.0, 300), (262.0, 800)],
+};
+
+pub static GAME2048_VICTORY: Stinger = Stinger {
+    name: "2048 – Victory",
+    notes: &[
+        (523.0, 150),
+        (659.0, 150),
+        (784.0, 150),
+        (1047.0, 300),
+        (1319.0, 600),
+    ],
+};
+
+pub static MINESWEEPER_GAME_OVER: Stinger = Stinger {
+    name: "Minesweeper – Game Over",
+    // Après l'explosion, deux notes graves en demi-ton
+    notes: &[(0.0, 250), (147.0, 400), (139.0, 800)],
+};
+
+pub static MINESWEEPER_VICTORY: Stinger = Stinger {
+    name: "Minesweeper – Victory",
+    notes: &[(784.0, 120), (988.0, 120), (1175.0, 120), (1568.0, 500)],
+};
@@ -1,5 +1,4 @@
-use super::{create_chord, create_note, GameMusic};
-use rodio::Sink;
+use super::{create_chord, create_note, GameMusic, MusicVariant, NoteQueue};
 
 /// Musique de Tetris (Korobeiniki)
 pub struct TetrisMusic;
@@ -9,7 +8,22 @@ impl GameMusic for TetrisMusic {
         "Tetris (Korobeiniki)"
     }
 
-    fn play_normal(&self, sink: &Sink, volume: f32) {
+    fn bpm(&self, variant: MusicVariant) -> u32 {
+        match variant {
+            MusicVariant::Normal => 150,
+            MusicVariant::Fast => 300,
+            MusicVariant::Celebration => 150,
+        }
+    }
+
+    fn sections(&self, variant: MusicVariant) -> &'static [(&'static str, usize)] {
+        match variant {
+            MusicVariant::Normal => &[("A", 0), ("B", 19), ("Bass", 37)],
+            _ => &[],
+        }
+    }
+
+    fn play_normal(&self, sink: &dyn NoteQueue, volume: f32) {
         // Mélodie principale de Korobeiniki
         // E B C D C B A A C E D C B C D E C A A
         let main_melody = vec![
@@ -78,7 +92,7 @@ impl GameMusic for TetrisMusic {
         }
     }
 
-    fn play_fast(&self, sink: &Sink, volume: f32) {
+    fn play_fast(&self, sink: &dyn NoteQueue, volume: f32) {
         // Version accélérée - notes plus courtes
         let fast_melody = vec![
             (659.0, 200), // E5
@@ -108,7 +122,7 @@ impl GameMusic for TetrisMusic {
         }
     }
 
-    fn play_celebration(&self, sink: &Sink, volume: f32) {
+    fn play_celebration(&self, sink: &dyn NoteQueue, volume: f32) {
         // Version avec harmonies pour célébrer un Tetris!
         let celebration_chords = vec![
             // Accord de victoire: E + G + C
@@ -0,0 +1,185 @@
+//! Modificateurs de partie.
+//!
+//! Un modificateur change une règle le temps d'une partie : vitesse doublée,
+//! commandes inversées, pièce suivante cachée… Le joueur les choisit sur
+//! l'écran Modifiers avant de lancer un jeu, et le défi de la semaine en
+//! impose un. Les jeux consultent les modificateurs actifs par des points
+//! d'accroche : [`is_active`] à leur création ou pendant le rendu,
+//! [`speed_factor`] pour leur vitesse, et [`map_key`] transforme les touches
+//! avant qu'elles ne leur parviennent. Les scores gardent la liste des
+//! modificateurs de leur partie.
+//!
+//! Les modificateurs actifs sont propres au thread qui joue : chaque session
+//! du serveur SSH a le sien, et ses parties ne modifient pas celles des
+//! autres.
+
+use crate::event::{KeyCode, KeyEvent};
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+
+/// Règle modifiée pendant une partie
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Mutator {
+    /// Le jeu avance deux fois plus vite
+    DoubleSpeed,
+    /// Gauche et droite, haut et bas sont échangés
+    InvertedControls,
+    /// Tetris : la pièce suivante n'est pas affichée
+    HiddenNext,
+    /// Démineur : seules les cases autour du curseur restent visibles
+    FogOfWar,
+    /// Démineur : 10 % de mines en plus
+    ExtraMines,
+}
+
+impl Mutator {
+    pub const ALL: [Mutator; 5] = [
+        Mutator::DoubleSpeed,
+        Mutator::InvertedControls,
+        Mutator::HiddenNext,
+        Mutator::FogOfWar,
+        Mutator::ExtraMines,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Mutator::DoubleSpeed => "Double Speed",
+            Mutator::InvertedControls => "Inverted Controls",
+            Mutator::HiddenNext => "Hidden Next Piece",
+            Mutator::FogOfWar => "Fog of War",
+            Mutator::ExtraMines => "Extra Mines",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            Mutator::DoubleSpeed => "Everything moves twice as fast",
+            Mutator::InvertedControls => "Left is right and up is down",
+            Mutator::HiddenNext => "You won't see the next piece coming",
+            Mutator::FogOfWar => "Only the cells around the cursor stay visible",
+            Mutator::ExtraMines => "The field hides 44 mines instead of 40",
+        }
+    }
+
+    /// Badge affiché dans le classement
+    pub fn badge(self) -> &'static str {
+        match self {
+            Mutator::DoubleSpeed => "⏩ 2x speed",
+            Mutator::InvertedControls => "🔀 inverted",
+            Mutator::HiddenNext => "🙈 no next",
+            Mutator::FogOfWar => "🌫 fog",
+            Mutator::ExtraMines => "💣 +10% mines",
+        }
+    }
+
+    /// Indique si le jeu `game_id` tient compte de ce modificateur
+    pub fn applies_to(self, game_id: &str) -> bool {
+        match self {
+            Mutator::DoubleSpeed => matches!(game_id, "snake" | "tetris" | "breakout"),
+            Mutator::InvertedControls => matches!(
+                game_id,
+                "snake" | "tetris" | "breakout" | "2048" | "minesweeper"
+            ),
+            Mutator::HiddenNext => game_id == "tetris",
+            Mutator::FogOfWar | Mutator::ExtraMines => game_id == "minesweeper",
+        }
+    }
+
+    /// Modificateurs proposés pour `game_id`
+    pub fn for_game(game_id: &str) -> Vec<Mutator> {
+        Self::ALL
+            .into_iter()
+            .filter(|mutator| mutator.applies_to(game_id))
+            .collect()
+    }
+}
+
+thread_local! {
+    /// Modificateurs de la partie en cours, vide en dehors d'une partie modifiée
+    static ACTIVE: RefCell<Vec<Mutator>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Applique `mutators` à la partie : à appeler avant de créer le jeu
+pub fn set(mutators: &[Mutator]) {
+    ACTIVE.with_borrow_mut(|active| *active = mutators.to_vec());
+}
+
+/// Rend aux jeux leurs règles habituelles
+pub fn clear() {
+    ACTIVE.with_borrow_mut(Vec::clear);
+}
+
+/// Modificateurs de la partie en cours
+pub fn active() -> Vec<Mutator> {
+    ACTIVE.with_borrow(Vec::clone)
+}
+
+pub fn is_active(mutator: Mutator) -> bool {
+    ACTIVE.with_borrow(|active| active.contains(&mutator))
+}
+
+/// Indique si la partie en cours est modifiée ; elle ne se compare alors pas
+/// aux parties habituelles (fantômes, temps intermédiaires)
+pub fn any_active() -> bool {
+    ACTIVE.with_borrow(|active| !active.is_empty())
+}
+
+/// Multiplicateur de vitesse de la partie en cours
+pub fn speed_factor() -> u32 {
+    if is_active(Mutator::DoubleSpeed) {
+        2
+    } else {
+        1
+    }
+}
+
+/// Touche à transmettre au jeu pour une touche pressée
+pub fn map_key(mut key: KeyEvent) -> KeyEvent {
+    if is_active(Mutator::InvertedControls) {
+        key.code = match key.code {
+            KeyCode::Left => KeyCode::Right,
+            KeyCode::Right => KeyCode::Left,
+            KeyCode::Up => KeyCode::Down,
+            KeyCode::Down => KeyCode::Up,
+            KeyCode::Char('a') => KeyCode::Char('d'),
+            KeyCode::Char('d') => KeyCode::Char('a'),
+            KeyCode::Char('w') => KeyCode::Char('s'),
+            KeyCode::Char('s') => KeyCode::Char('w'),
+            code => code,
+        };
+    }
+    key
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::KeyModifiers;
+
+    #[test]
+    fn inverted_controls_swap_directions_only_while_active() {
+        let left = KeyEvent::new(KeyCode::Left, KeyModifiers::NONE);
+        let quit = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE);
+        assert_eq!(map_key(left).code, KeyCode::Left);
+
+        set(&[Mutator::InvertedControls]);
+        assert_eq!(map_key(left).code, KeyCode::Right);
+        assert_eq!(map_key(quit).code, KeyCode::Char('q'));
+        assert_eq!(speed_factor(), 1);
+        clear();
+
+        assert_eq!(map_key(left).code, KeyCode::Left);
+        assert_eq!(Mutator::for_game("tetris").len(), 3);
+        assert!(Mutator::for_game("game-of-life").is_empty());
+    }
+
+    #[test]
+    fn each_session_thread_has_its_own_mutators() {
+        set(&[Mutator::DoubleSpeed]);
+        let other = std::thread::spawn(|| (any_active(), crate::challenge::active()));
+        assert_eq!(other.join().unwrap(), (false, None));
+        assert_eq!(speed_factor(), 2);
+        clear();
+    }
+}
@@ -0,0 +1,125 @@
+//! Puzzles de 2048 et de Tetris : une position de départ prédéfinie et un
+//! objectif à atteindre avec des coups ou des pièces limités.
+//!
+//! Les scénarios sont embarqués dans le binaire depuis `assets/puzzles/`. Les
+//! puzzles résolus sont enregistrés dans `puzzles.json`, à côté des scores.
+
+use crate::storage;
+use crate::writer;
+use serde::Deserialize;
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+const PUZZLES_2048: &str = include_str!("../assets/puzzles/2048.json");
+const PUZZLES_TETRIS: &str = include_str!("../assets/puzzles/tetris.json");
+
+/// Fichier de progression, dans le dossier de données
+pub const PROGRESS_FILE: &str = "puzzles.json";
+
+/// Atteindre une tuile en un nombre de coups limité. Aucune tuile n'apparaît
+/// entre les coups : la solution ne dépend pas du hasard.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Puzzle2048 {
+    pub id: String,
+    pub name: String,
+    pub objective: String,
+    /// Lignes de la grille, de haut en bas
+    pub board: Vec<Vec<u32>>,
+    pub target_tile: u32,
+    pub max_moves: u32,
+}
+
+/// Effacer un nombre de lignes avec une suite de pièces imposée
+#[derive(Debug, Clone, Deserialize)]
+pub struct TetrisPuzzle {
+    pub id: String,
+    pub name: String,
+    pub objective: String,
+    /// Lignes du bas du plateau, de haut en bas : '.' pour une case vide,
+    /// tout autre caractère pour un bloc
+    pub board: Vec<String>,
+    /// Pièces à jouer dans l'ordre ("IOTSZJL")
+    pub pieces: String,
+    pub target_lines: u32,
+}
+
+#[derive(Debug, Clone)]
+pub enum Puzzle {
+    Game2048(Puzzle2048),
+    Tetris(TetrisPuzzle),
+}
+
+impl Puzzle {
+    pub fn id(&self) -> &str {
+        match self {
+            Puzzle::Game2048(puzzle) => &puzzle.id,
+            Puzzle::Tetris(puzzle) => &puzzle.id,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            Puzzle::Game2048(puzzle) => &puzzle.name,
+            Puzzle::Tetris(puzzle) => &puzzle.name,
+        }
+    }
+
+    pub fn objective(&self) -> &str {
+        match self {
+            Puzzle::Game2048(puzzle) => &puzzle.objective,
+            Puzzle::Tetris(puzzle) => &puzzle.objective,
+        }
+    }
+
+    pub fn game_name(&self) -> &'static str {
+        match self {
+            Puzzle::Game2048(_) => "2048",
+            Puzzle::Tetris(_) => "Tetris",
+        }
+    }
+}
+
+/// Tous les puzzles embarqués, ceux de 2048 puis ceux de Tetris
+pub fn all() -> Vec<Puzzle> {
+    let puzzles_2048: Vec<Puzzle2048> = serde_json::from_str(PUZZLES_2048).unwrap_or_default();
+    let puzzles_tetris: Vec<TetrisPuzzle> =
+        serde_json::from_str(PUZZLES_TETRIS).unwrap_or_default();
+
+    puzzles_2048
+        .into_iter()
+        .map(Puzzle::Game2048)
+        .chain(puzzles_tetris.into_iter().map(Puzzle::Tetris))
+        .collect()
+}
+
+fn progress_path() -> Option<PathBuf> {
+    storage::data_dir().map(|dir| dir.join(PROGRESS_FILE))
+}
+
+/// Identifiants des puzzles déjà résolus
+pub fn solved() -> BTreeSet<String> {
+    progress_path()
+        .and_then(|path| writer::read_to_string(&path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Enregistre un puzzle comme résolu
+pub fn mark_solved(id: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let path = progress_path().ok_or("no config directory")?;
+
+    let mut solved = solved();
+    if !solved.insert(id.to_string()) {
+        return Ok(());
+    }
+
+    writer::write(path, serde_json::to_string_pretty(&solved)?);
+    Ok(())
+}
+
+/// Efface la progression (utilisé par "Reset Everything")
+pub fn clear_progress() {
+    if let Some(path) = progress_path() {
+        writer::delete(path);
+    }
+}
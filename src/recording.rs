@@ -0,0 +1,197 @@
+//! Enregistrement des parties au format asciicast v2 d'asciinema.
+//!
+//! F9 démarre ou arrête l'enregistrement pendant une partie. La boucle de jeu
+//! passe chaque image dessinée à [`CastRecorder::capture`] : seules les
+//! cellules modifiées depuis l'image précédente sont écrites, avec l'instant
+//! de l'image. `asciinema play` rejoue le fichier, et un convertisseur comme
+//! `agg` en fait un GIF. Les fichiers vont dans le dossier `recordings` du
+//! dossier de données, avec le journal des touches de la partie (voir
+//! [`crate::replay`]).
+
+use crate::core::clock::Instant;
+use crate::event::KeyEvent;
+use crate::replay::{self, ReplayHeader, ReplayWriter};
+use crate::storage;
+use crate::ui::ansi;
+use ratatui::{buffer::Buffer, layout::Rect};
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Sous-dossier des enregistrements, dans le dossier de données
+pub const RECORDINGS_DIR: &str = "recordings";
+
+/// Pauses raccourcies à la lecture (champ `idle_time_limit` de l'en-tête),
+/// en secondes : un menu de pause laissé ouvert ne fige pas la vidéo
+const IDLE_TIME_LIMIT: f64 = 2.0;
+
+/// Enregistrement en cours d'une partie
+pub struct CastRecorder {
+    writer: BufWriter<File>,
+    path: PathBuf,
+    started: Instant,
+    /// Écran tel qu'il a été écrit dans le fichier
+    shown: Buffer,
+    /// Journal des touches, abandonné s'il ne peut pas être écrit : la
+    /// vidéo continue sans lui
+    inputs: Option<ReplayWriter>,
+}
+
+impl CastRecorder {
+    /// Commence l'enregistrement de `game_id` dans un nouveau fichier du
+    /// dossier des enregistrements, pour un écran de la taille de `area`,
+    /// et le journal des touches décrit par `header` à côté
+    pub fn start(
+        game_id: &str,
+        title: &str,
+        area: Rect,
+        header: &ReplayHeader,
+    ) -> io::Result<Self> {
+        let dir = storage::data_dir()
+            .ok_or_else(|| io::Error::other("no config directory"))?
+            .join(RECORDINGS_DIR);
+        fs::create_dir_all(&dir)?;
+        let name = format!(
+            "{game_id}-{}.cast",
+            chrono::Local::now().format("%Y%m%d-%H%M%S")
+        );
+        let mut recorder = Self::create(&dir.join(name), title, area)?;
+        recorder.inputs =
+            ReplayWriter::create(&recorder.path.with_extension(replay::EXTENSION), header).ok();
+        Ok(recorder)
+    }
+
+    fn create(path: &Path, title: &str, area: Rect) -> io::Result<Self> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        let header = serde_json::json!({
+            "version": 2,
+            "width": area.width,
+            "height": area.height,
+            "timestamp": chrono::Utc::now().timestamp(),
+            "idle_time_limit": IDLE_TIME_LIMIT,
+            "title": title,
+            "env": { "TERM": "xterm-256color" },
+        });
+        writeln!(writer, "{header}")?;
+
+        let mut recorder = Self {
+            writer,
+            path: path.to_path_buf(),
+            started: Instant::now(),
+            shown: Buffer::empty(area),
+            inputs: None,
+        };
+        // Écran vidé et curseur masqué, comme dans le jeu
+        recorder.write_event("o", "\x1b[0m\x1b[2J\x1b[?25l")?;
+        Ok(recorder)
+    }
+
+    /// Ajoute une touche transmise au jeu au journal des touches
+    pub fn record_key(&mut self, key: KeyEvent) {
+        if let Some(inputs) = &mut self.inputs {
+            if inputs.record(key).is_err() {
+                self.inputs = None;
+            }
+        }
+    }
+
+    /// Ajoute l'image `buffer` à l'enregistrement
+    pub fn capture(&mut self, buffer: &Buffer) -> io::Result<()> {
+        if let Some(inputs) = &mut self.inputs {
+            if inputs.flush_due().is_err() {
+                self.inputs = None;
+            }
+        }
+
+        let mut out = String::new();
+        if buffer.area != self.shown.area {
+            let size = format!("{}x{}", buffer.area.width, buffer.area.height);
+            self.write_event("r", &size)?;
+            self.shown = Buffer::empty(buffer.area);
+            out.push_str("\x1b[0m\x1b[2J");
+        }
+
+        ansi::encode_diff(&self.shown, buffer, &mut out);
+        if !out.is_empty() {
+            self.write_event("o", &out)?;
+            self.shown = buffer.clone();
+        }
+        Ok(())
+    }
+
+    /// Temps écoulé depuis le début de l'enregistrement
+    pub fn elapsed(&self) -> Duration {
+        self.started.elapsed()
+    }
+
+    /// Termine l'enregistrement et retourne le chemin du fichier
+    pub fn finish(mut self) -> io::Result<PathBuf> {
+        self.writer.flush()?;
+        if let Some(inputs) = self.inputs.take() {
+            inputs.finish()?;
+        }
+        Ok(self.path)
+    }
+
+    fn write_event(&mut self, kind: &str, data: &str) -> io::Result<()> {
+        // Les lecteurs n'ont pas besoin de plus que la milliseconde
+        let time = (self.started.elapsed().as_secs_f64() * 1000.0).round() / 1000.0;
+        let event = serde_json::to_string(&(time, kind, data))?;
+        writeln!(self.writer, "{event}")
+    }
+}
+
+/// Supprime tous les enregistrements et leurs journaux de touches
+pub fn clear() -> io::Result<()> {
+    let Some(dir) = storage::data_dir() else {
+        return Ok(());
+    };
+    match fs::remove_dir_all(dir.join(RECORDINGS_DIR)) {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::style::{Color, Style};
+
+    #[test]
+    fn a_cast_has_a_header_and_only_writes_changed_cells() {
+        let path = std::env::temp_dir().join(format!("termplay-cast-{}.cast", std::process::id()));
+        let area = Rect::new(0, 0, 10, 2);
+        let mut recorder = CastRecorder::create(&path, "TermPlay – Snake", area).unwrap();
+
+        let mut screen = Buffer::empty(area);
+        screen.set_string(0, 0, "Score 10", Style::default().fg(Color::Yellow));
+        recorder.capture(&screen).unwrap();
+        // Image identique : rien à écrire
+        recorder.capture(&screen).unwrap();
+        screen.set_string(6, 0, "25", Style::default().fg(Color::Yellow));
+        recorder.capture(&screen).unwrap();
+        recorder
+            .capture(&Buffer::empty(Rect::new(0, 0, 12, 3)))
+            .unwrap();
+        let path = recorder.finish().unwrap();
+
+        let contents = fs::read_to_string(&path).unwrap();
+        let _ = fs::remove_file(&path);
+        let lines: Vec<serde_json::Value> = contents
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(lines[0]["version"], 2);
+        assert_eq!(lines[0]["width"], 10);
+        assert_eq!(lines[0]["title"], "TermPlay – Snake");
+        // Écran vidé, première image, seconde image, puis redimensionnement
+        assert_eq!(lines.len(), 6);
+        assert!(lines[2][2].as_str().unwrap().contains("Score 10"));
+        let second = lines[3][2].as_str().unwrap();
+        assert!(second.contains("25") && !second.contains("Score"));
+        assert_eq!(lines[4][1], "r");
+        assert_eq!(lines[4][2], "12x3");
+    }
+}
@@ -0,0 +1,498 @@
+//! Journal des touches d'une partie enregistrée (fichiers `.tpr`).
+//!
+//! Pendant un enregistrement F9, les touches transmises au jeu sont écrites
+//! à côté du fichier asciicast, avec leur délai, dans un format binaire
+//! compact (entiers petit-boutistes) :
+//!
+//! - en-tête : `TPRP`, version du format (u8), identifiant du jeu et version
+//!   de TermPlay (longueur u8 + UTF-8), graine des tirages (u64), options
+//!   (longueur u16 + lignes `clé=valeur`), CRC-32 de ce qui précède (u32) ;
+//! - blocs de touches `I` : nombre de touches (u16), longueur (u16), touches,
+//!   CRC-32 du bloc (u32) ;
+//! - bloc de fin `E` : nombre total de touches (u32), CRC-32 de toutes les
+//!   touches (u32).
+//!
+//! Une touche est son délai depuis la précédente en millisecondes (LEB128),
+//! son code et ses modificateurs. Un bloc est écrit dès qu'il est plein ou
+//! au plus tard une seconde après sa première touche : après un crash, le
+//! fichier reste un préfixe valide, que [`read`] reconnaît.
+
+use crate::core::clock::Instant;
+use crate::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::error::Error;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::Duration;
+
+/// Extension des journaux de touches
+pub const EXTENSION: &str = "tpr";
+
+const MAGIC: &[u8; 4] = b"TPRP";
+const FORMAT_VERSION: u8 = 1;
+const INPUT_BLOCK: u8 = b'I';
+const END_BLOCK: u8 = b'E';
+/// Touches par bloc au plus
+const BLOCK_KEYS: u16 = 64;
+/// Délai au bout duquel un bloc entamé est écrit
+const FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Ce qu'il faut pour rejouer la partie dans les mêmes conditions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplayHeader {
+    pub game_id: String,
+    /// Version de TermPlay qui a enregistré la partie
+    pub version: String,
+    /// Graine des tirages, 0 si le jeu ne la communique pas
+    pub seed: u64,
+    /// Options de la partie (code de partage, modificateurs…)
+    pub options: Vec<(String, String)>,
+}
+
+impl ReplayHeader {
+    fn encode(&self) -> Vec<u8> {
+        let mut out = MAGIC.to_vec();
+        out.push(FORMAT_VERSION);
+        for text in [&self.game_id, &self.version] {
+            let bytes = &text.as_bytes()[..text.len().min(u8::MAX as usize)];
+            out.push(bytes.len() as u8);
+            out.extend_from_slice(bytes);
+        }
+        out.extend_from_slice(&self.seed.to_le_bytes());
+        let options: String = self
+            .options
+            .iter()
+            .map(|(key, value)| format!("{key}={value}\n"))
+            .collect();
+        let options = &options.as_bytes()[..options.len().min(u16::MAX as usize)];
+        out.extend_from_slice(&(options.len() as u16).to_le_bytes());
+        out.extend_from_slice(options);
+        out.extend_from_slice(&crc32(&out).to_le_bytes());
+        out
+    }
+}
+
+/// Écriture d'un journal de touches pendant la partie
+pub struct ReplayWriter {
+    file: File,
+    /// Touches du bloc en cours
+    block: Vec<u8>,
+    block_keys: u16,
+    block_started: Instant,
+    last_key: Instant,
+    /// Touches écrites et CRC-32 de tout le flux des touches
+    total_keys: u32,
+    stream_crc: u32,
+}
+
+impl ReplayWriter {
+    pub fn create(path: &Path, header: &ReplayHeader) -> io::Result<Self> {
+        let mut file = File::create(path)?;
+        file.write_all(&header.encode())?;
+        let now = Instant::now();
+        Ok(Self {
+            file,
+            block: Vec::new(),
+            block_keys: 0,
+            block_started: now,
+            last_key: now,
+            total_keys: 0,
+            stream_crc: 0,
+        })
+    }
+
+    /// Ajoute une touche ; celles qui n'ont pas de code dans le format
+    /// (touches multimédia, modificateurs seuls) sont ignorées
+    pub fn record(&mut self, key: KeyEvent) -> io::Result<()> {
+        let now = Instant::now();
+        let mut encoded = Vec::new();
+        let delay = now.duration_since(self.last_key).as_millis() as u64;
+        write_varint(&mut encoded, delay);
+        if !encode_key(key, &mut encoded) {
+            return Ok(());
+        }
+        self.last_key = now;
+        if self.block_keys == 0 {
+            self.block_started = now;
+        }
+        self.block.extend_from_slice(&encoded);
+        self.block_keys += 1;
+        if self.block_keys >= BLOCK_KEYS {
+            self.write_block()?;
+        }
+        Ok(())
+    }
+
+    /// Écrit le bloc entamé s'il attend depuis plus d'une seconde ; à
+    /// appeler régulièrement (à chaque image)
+    pub fn flush_due(&mut self) -> io::Result<()> {
+        if self.block_keys > 0 && self.block_started.elapsed() >= FLUSH_INTERVAL {
+            self.write_block()?;
+        }
+        Ok(())
+    }
+
+    /// Écrit le dernier bloc et le bloc de fin
+    pub fn finish(mut self) -> io::Result<()> {
+        self.write_block()?;
+        let mut end = vec![END_BLOCK];
+        end.extend_from_slice(&self.total_keys.to_le_bytes());
+        end.extend_from_slice(&self.stream_crc.to_le_bytes());
+        self.file.write_all(&end)?;
+        self.file.sync_data()
+    }
+
+    fn write_block(&mut self) -> io::Result<()> {
+        if self.block_keys == 0 {
+            return Ok(());
+        }
+        let mut out = vec![INPUT_BLOCK];
+        out.extend_from_slice(&self.block_keys.to_le_bytes());
+        out.extend_from_slice(&(self.block.len() as u16).to_le_bytes());
+        out.extend_from_slice(&self.block);
+        out.extend_from_slice(&crc32(&self.block).to_le_bytes());
+        // Un seul write par bloc : un crash laisse au pire un bloc tronqué
+        self.file.write_all(&out)?;
+
+        self.stream_crc = crc32_update(self.stream_crc, &self.block);
+        self.total_keys += u32::from(self.block_keys);
+        self.block.clear();
+        self.block_keys = 0;
+        Ok(())
+    }
+}
+
+/// Journal relu et vérifié
+#[derive(Debug)]
+pub struct Replay {
+    pub header: ReplayHeader,
+    /// Touches avec leur délai depuis la précédente
+    pub keys: Vec<(Duration, KeyEvent)>,
+    /// `false` si le fichier s'arrête avant son bloc de fin (partie
+    /// interrompue) : les touches lues forment un préfixe valide
+    pub complete: bool,
+}
+
+impl Replay {
+    /// Durée couverte par les touches
+    pub fn duration(&self) -> Duration {
+        self.keys.iter().map(|(delay, _)| *delay).sum()
+    }
+}
+
+/// Lit le journal `path` en vérifiant ses sommes de contrôle. Un fichier
+/// tronqué rend les touches lues jusque-là ; un bloc complet mais altéré est
+/// une erreur
+pub fn read(path: &Path) -> Result<Replay, Box<dyn Error>> {
+    let bytes = fs::read(path)?;
+    let mut reader = Reader {
+        bytes: &bytes,
+        pos: 0,
+    };
+
+    let truncated = || "truncated replay header".to_string();
+    if reader.take(4).ok_or_else(truncated)? != MAGIC {
+        return Err("not a TermPlay replay".into());
+    }
+    let format = reader.u8().ok_or_else(truncated)?;
+    if format != FORMAT_VERSION {
+        return Err(format!("unsupported replay format version {format}").into());
+    }
+    let game_id = reader.text_u8().ok_or_else(truncated)?;
+    let version = reader.text_u8().ok_or_else(truncated)?;
+    let seed = u64::from_le_bytes(reader.array().ok_or_else(truncated)?);
+    let options_len = u16::from_le_bytes(reader.array().ok_or_else(truncated)?);
+    let options = reader.take(options_len as usize).ok_or_else(truncated)?;
+    let options = String::from_utf8(options.to_vec())?
+        .lines()
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect();
+    let header_end = reader.pos;
+    let crc = u32::from_le_bytes(reader.array().ok_or_else(truncated)?);
+    if crc != crc32(&bytes[..header_end]) {
+        return Err("corrupted replay header (checksum mismatch)".into());
+    }
+
+    let header = ReplayHeader {
+        game_id,
+        version,
+        seed,
+        options,
+    };
+    let mut keys = Vec::new();
+    let mut stream_crc = 0;
+    let mut block_index = 0;
+    loop {
+        let Some(tag) = reader.u8() else {
+            return Ok(Replay {
+                header,
+                keys,
+                complete: false,
+            });
+        };
+        match tag {
+            INPUT_BLOCK => {
+                let block = (|| {
+                    let count = u16::from_le_bytes(reader.array()?);
+                    let len = u16::from_le_bytes(reader.array()?);
+                    let data = reader.take(len as usize)?;
+                    let crc = u32::from_le_bytes(reader.array()?);
+                    Some((count, data, crc))
+                })();
+                // Bloc coupé par un crash : le préfixe lu reste valable
+                let Some((count, data, crc)) = block else {
+                    return Ok(Replay {
+                        header,
+                        keys,
+                        complete: false,
+                    });
+                };
+                block_index += 1;
+                if crc != crc32(data) {
+                    return Err(format!(
+                        "corrupted replay (block {block_index} checksum mismatch)"
+                    )
+                    .into());
+                }
+                let decoded = decode_block(data, count).ok_or_else(|| {
+                    format!("corrupted replay (block {block_index} cannot be decoded)")
+                })?;
+                keys.extend(decoded);
+                stream_crc = crc32_update(stream_crc, data);
+            }
+            END_BLOCK => {
+                let Some(total) = reader.array().map(u32::from_le_bytes) else {
+                    return Ok(Replay {
+                        header,
+                        keys,
+                        complete: false,
+                    });
+                };
+                let Some(crc) = reader.array().map(u32::from_le_bytes) else {
+                    return Ok(Replay {
+                        header,
+                        keys,
+                        complete: false,
+                    });
+                };
+                if total as usize != keys.len() || crc != stream_crc {
+                    return Err("corrupted replay (input stream checksum mismatch)".into());
+                }
+                if reader.pos != bytes.len() {
+                    return Err("unexpected data after the end of the replay".into());
+                }
+                return Ok(Replay {
+                    header,
+                    keys,
+                    complete: true,
+                });
+            }
+            _ => return Err(format!("corrupted replay (unknown block {tag:#04x})").into()),
+        }
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        let slice = self.bytes.get(self.pos..self.pos.checked_add(len)?)?;
+        self.pos += len;
+        Some(slice)
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        self.take(1).map(|bytes| bytes[0])
+    }
+
+    fn array<const N: usize>(&mut self) -> Option<[u8; N]> {
+        self.take(N)?.try_into().ok()
+    }
+
+    fn text_u8(&mut self) -> Option<String> {
+        let len = self.u8()?;
+        String::from_utf8(self.take(len as usize)?.to_vec()).ok()
+    }
+
+    fn varint(&mut self) -> Option<u64> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.u8()?;
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Some(value);
+            }
+        }
+        None
+    }
+}
+
+/// CRC-32 (polynôme de gzip et zip)
+fn crc32(data: &[u8]) -> u32 {
+    crc32_update(0, data)
+}
+
+/// CRC-32 de données lues par morceaux : `crc` est celui des morceaux
+/// précédents (0 au départ)
+fn crc32_update(crc: u32, data: &[u8]) -> u32 {
+    let mut crc = !crc;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn write_varint(out: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte);
+            return;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+/// Codes des touches sans caractère, dans l'ordre du format
+const NAMED_KEYS: [KeyCode; 15] = [
+    KeyCode::Enter,
+    KeyCode::Esc,
+    KeyCode::Backspace,
+    KeyCode::Tab,
+    KeyCode::BackTab,
+    KeyCode::Up,
+    KeyCode::Down,
+    KeyCode::Left,
+    KeyCode::Right,
+    KeyCode::Home,
+    KeyCode::End,
+    KeyCode::PageUp,
+    KeyCode::PageDown,
+    KeyCode::Delete,
+    KeyCode::Insert,
+];
+/// Code d'un caractère (suivi du caractère en LEB128) et d'une touche de
+/// fonction (suivi de son numéro)
+const CHAR_CODE: u8 = 0;
+const FUNCTION_CODE: u8 = 0x80;
+
+fn encode_key(key: KeyEvent, out: &mut Vec<u8>) -> bool {
+    match key.code {
+        KeyCode::Char(c) => {
+            out.push(CHAR_CODE);
+            write_varint(out, u64::from(u32::from(c)));
+        }
+        KeyCode::F(n) => out.extend_from_slice(&[FUNCTION_CODE, n]),
+        code => match NAMED_KEYS.iter().position(|&named| named == code) {
+            Some(index) => out.push(index as u8 + 1),
+            None => return false,
+        },
+    }
+    out.push(key.modifiers.bits());
+    true
+}
+
+fn decode_block(data: &[u8], count: u16) -> Option<Vec<(Duration, KeyEvent)>> {
+    let mut reader = Reader {
+        bytes: data,
+        pos: 0,
+    };
+    let mut keys = Vec::with_capacity(count as usize);
+    for _ in 0..count {
+        let delay = Duration::from_millis(reader.varint()?);
+        let code = match reader.u8()? {
+            CHAR_CODE => KeyCode::Char(char::from_u32(u32::try_from(reader.varint()?).ok()?)?),
+            FUNCTION_CODE => KeyCode::F(reader.u8()?),
+            index => *NAMED_KEYS.get(index as usize - 1)?,
+        };
+        let modifiers = KeyModifiers::from_bits(reader.u8()?)?;
+        keys.push((delay, KeyEvent::new(code, modifiers)));
+    }
+    (reader.pos == data.len()).then_some(keys)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("termplay-{name}-{}.tpr", std::process::id()))
+    }
+
+    fn header() -> ReplayHeader {
+        ReplayHeader {
+            game_id: "2048".to_string(),
+            version: "1.2.3".to_string(),
+            seed: 42,
+            options: vec![("code".to_string(), "ABC".to_string())],
+        }
+    }
+
+    fn write(path: &Path, keys: &[KeyEvent], finish: bool) {
+        let mut writer = ReplayWriter::create(path, &header()).unwrap();
+        for &key in keys {
+            writer.record(key).unwrap();
+        }
+        if finish {
+            writer.finish().unwrap();
+        } else {
+            writer.write_block().unwrap();
+        }
+    }
+
+    #[test]
+    fn a_finished_replay_reads_back_with_its_header_and_keys() {
+        let path = temp_path("replay");
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+        let keys: Vec<KeyEvent> = (0..100)
+            .map(|i| match i % 3 {
+                0 => KeyEvent::new(KeyCode::Char('é'), KeyModifiers::SHIFT),
+                1 => KeyEvent::from(KeyCode::Left),
+                _ => KeyEvent::from(KeyCode::F(9)),
+            })
+            .collect();
+        write(&path, &keys, true);
+
+        let replay = read(&path).unwrap();
+        let _ = fs::remove_file(&path);
+        assert!(replay.complete);
+        assert_eq!(replay.header, header());
+        let read_keys: Vec<KeyEvent> = replay.keys.iter().map(|(_, key)| *key).collect();
+        assert_eq!(read_keys, keys);
+    }
+
+    #[test]
+    fn an_interrupted_replay_keeps_its_valid_prefix_and_corruption_is_reported() {
+        let path = temp_path("replay-crash");
+        let keys = vec![KeyEvent::from(KeyCode::Up); 70];
+        write(&path, &keys, false);
+
+        // Crash au milieu d'un bloc suivant
+        let mut bytes = fs::read(&path).unwrap();
+        bytes.extend_from_slice(&[INPUT_BLOCK, 3, 0, 9]);
+        fs::write(&path, &bytes).unwrap();
+        let replay = read(&path).unwrap();
+        assert!(!replay.complete);
+        assert_eq!(replay.keys.len(), 70);
+
+        // Une touche altérée dans un bloc complet
+        let last = bytes.len() - 10;
+        bytes[last] ^= 0xff;
+        fs::write(&path, &bytes).unwrap();
+        let error = read(&path).unwrap_err().to_string();
+        let _ = fs::remove_file(&path);
+        assert!(error.contains("checksum"), "{error}");
+    }
+}
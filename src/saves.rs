@@ -0,0 +1,173 @@
+//! Sauvegardes des parties en cours.
+//!
+//! Les jeux qui savent reprendre une partie la décrivent en JSON
+//! ([`Game::save_state`](crate::core::Game::save_state)). La boucle de jeu
+//! l'enregistre ici toutes les quelques minutes (réglage Autosave), et à un
+//! arrêt forcé ; une partie terminée normalement efface sa sauvegarde. Une
+//! sauvegarde encore présente au lancement du jeu est donc celle d'une partie
+//! interrompue, que le jeu reprend ; le menu les propose au démarrage
+//! ([`interrupted`]).
+//!
+//! L'encodage se fait en tâche de fond : une sauvegarde préparée par
+//! [`prepare`] s'exécute sur n'importe quel thread, et seule la dernière
+//! demande pour un jeu compte, même si une sauvegarde plus ancienne finit
+//! après elle.
+
+use crate::storage;
+use crate::writer;
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Parties en cours, par identifiant de jeu
+pub const SAVES_FILE: &str = "saves.json";
+
+/// Partie interrompue
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedGame {
+    pub saved_at: DateTime<Local>,
+    /// Statut de la partie au moment de la sauvegarde ("1,024 pts", "gen
+    /// 42"…), affiché par le menu
+    #[serde(default)]
+    pub summary: Option<String>,
+    /// État propre au jeu, rendu à `Game::restore_state`
+    pub state: serde_json::Value,
+}
+
+impl SavedGame {
+    /// Temps de jeu de la partie, si le jeu l'enregistre sous `elapsed_ms`
+    pub fn elapsed(&self) -> Option<Duration> {
+        self.state
+            .get("elapsed_ms")
+            .and_then(serde_json::Value::as_u64)
+            .map(Duration::from_millis)
+    }
+}
+
+/// Numéro de la prochaine demande, croissant dans l'ordre des appels
+static NEXT_TICKET: AtomicU64 = AtomicU64::new(1);
+/// Numéro de la dernière demande appliquée, par jeu
+static APPLIED: Mutex<BTreeMap<String, u64>> = Mutex::new(BTreeMap::new());
+
+fn saves_path() -> Option<PathBuf> {
+    storage::data_dir().map(|dir| dir.join(SAVES_FILE))
+}
+
+fn load_all() -> BTreeMap<String, SavedGame> {
+    saves_path()
+        .and_then(|path| writer::read_to_string(&path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Partie interrompue de `game`, s'il y en a une
+pub fn load(game: &str) -> Option<SavedGame> {
+    load_all().remove(game)
+}
+
+/// Toutes les parties interrompues, de la plus récente à la plus ancienne
+pub fn interrupted() -> Vec<(String, SavedGame)> {
+    let mut saves: Vec<_> = load_all().into_iter().collect();
+    saves.sort_by_key(|(_, saved)| std::cmp::Reverse(saved.saved_at));
+    saves
+}
+
+/// Prépare l'enregistrement de `state` comme partie en cours de `game`, à
+/// exécuter plus tard sur n'importe quel thread. Sans effet si une demande
+/// plus récente pour ce jeu est passée avant
+pub fn prepare(
+    game: &str,
+    summary: Option<String>,
+    state: serde_json::Value,
+) -> impl FnOnce() + Send + 'static {
+    let ticket = NEXT_TICKET.fetch_add(1, Ordering::Relaxed);
+    let game = game.to_string();
+    let saved = SavedGame {
+        saved_at: Local::now(),
+        summary,
+        state,
+    };
+    move || apply(&game, ticket, Some(saved))
+}
+
+/// Enregistre tout de suite `state` comme partie en cours de `game`
+pub fn save(game: &str, summary: Option<String>, state: serde_json::Value) {
+    prepare(game, summary, state)();
+}
+
+/// Oublie la partie en cours de `game`
+pub fn discard(game: &str) {
+    let ticket = NEXT_TICKET.fetch_add(1, Ordering::Relaxed);
+    apply(game, ticket, None);
+}
+
+fn apply(game: &str, ticket: u64, saved: Option<SavedGame>) {
+    let Some(path) = saves_path() else {
+        return;
+    };
+    let mut applied = APPLIED.lock().unwrap_or_else(|e| e.into_inner());
+    if applied.get(game).is_some_and(|&last| last > ticket) {
+        return;
+    }
+    applied.insert(game.to_string(), ticket);
+
+    let mut saves = load_all();
+    let changed = match saved {
+        Some(saved) => {
+            saves.insert(game.to_string(), saved);
+            true
+        }
+        None => saves.remove(game).is_some(),
+    };
+    if !changed {
+        return;
+    }
+    if saves.is_empty() {
+        writer::delete(path);
+    } else {
+        match serde_json::to_string(&saves) {
+            Ok(content) => writer::write(path, content),
+            Err(e) => eprintln!("Erreur lors de la sauvegarde de la partie: {e}"),
+        }
+    }
+}
+
+/// Efface toutes les parties en cours (utilisé par "Reset Everything")
+pub fn clear() {
+    if let Some(path) = saves_path() {
+        writer::delete(path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::isolate_data_dir;
+
+    #[test]
+    #[cfg_attr(not(feature = "native"), ignore = "no data folder without `native`")]
+    fn the_latest_request_wins() {
+        isolate_data_dir();
+        let game = "saves-test";
+
+        save(game, None, serde_json::json!({ "score": 1 }));
+        assert_eq!(load(game).unwrap().state["score"], 1);
+
+        // Une sauvegarde préparée avant l'effacement ne la fait pas revenir
+        let late = prepare(game, None, serde_json::json!({ "score": 2 }));
+        discard(game);
+        late();
+        assert_eq!(load(game), None);
+
+        let older = prepare(game, None, serde_json::json!({ "score": 3 }));
+        let newer = prepare(game, None, serde_json::json!({ "score": 4 }));
+        newer();
+        older();
+        assert_eq!(load(game).unwrap().state["score"], 4);
+        discard(game);
+    }
+}
@@ -0,0 +1,390 @@
+//! Serveur SSH intégré, lancé par `termplay serve --ssh :2222`.
+//!
+//! Chaque connexion ouvre une session TermPlay dans le PTY demandé par le
+//! client : sa propre [`App`] sur des jeux muets et son propre
+//! [`StreamFrontend`], dans un thread à elle. Le runtime tokio relaie les
+//! octets tapés par le client vers la session, et les images de la session
+//! vers le client. Les joueurs sont des invités ([`App::guest`]) : ils
+//! partent des réglages du compte qui lance le serveur sans pouvoir les
+//! modifier, et partagent ses meilleurs scores.
+//!
+//! L'entrée est libre : tout nom d'utilisateur est accepté, sans mot de
+//! passe. La clé d'hôte est créée au premier lancement dans le dossier de
+//! données, pour que les clients la reconnaissent aux connexions suivantes.
+
+use crate::frontend::{StreamFrontend, StreamInput};
+use crate::games::GameRegistry;
+use crate::{shutdown, storage, App};
+use rand::Rng;
+use russh::keys::ssh_key::{private::Ed25519Keypair, LineEnding};
+use russh::keys::{HashAlg, PrivateKey, PublicKey};
+use russh::server::{Auth, Handle, Msg, Server as _, Session};
+use russh::{Channel, ChannelId, CryptoVec, Pty};
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, Write};
+use std::net::SocketAddr;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc::{self, UnboundedSender};
+
+/// Clé d'hôte du serveur, dans le dossier de données
+pub const HOST_KEY_FILE: &str = "ssh_host_ed25519_key";
+/// Taille d'écran d'un client qui ne demande pas de PTY ou n'en donne pas
+/// la taille
+const DEFAULT_SIZE: (u16, u16) = (80, 24);
+/// Plus grand écran accordé à un client : chaque session garde deux
+/// tampons de cette taille, qu'une demande démesurée ne doit pas gonfler
+const MAX_SIZE: (u16, u16) = (500, 200);
+/// Délai laissé aux sessions pour rendre leur terminal aux clients quand le
+/// serveur s'arrête
+const SHUTDOWN_GRACE: Duration = Duration::from_secs(2);
+/// Intervalle de consultation du drapeau d'arrêt
+const SHUTDOWN_POLL: Duration = Duration::from_millis(100);
+
+/// Écoute sur `address` (`:2222`, `127.0.0.1:2222`, `[::]:2222`…) jusqu'à
+/// Ctrl+C ou un signal d'arrêt, qui ferme aussi les sessions en cours
+pub fn serve(address: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let address = listen_address(address);
+    let key = host_key()?;
+    let fingerprint = key.public_key().fingerprint(HashAlg::Sha256);
+    let config = Arc::new(russh::server::Config {
+        keys: vec![key],
+        // Le client essaie d'abord la méthode "none", acceptée sans attendre
+        auth_rejection_time_initial: Some(Duration::ZERO),
+        nodelay: true,
+        ..Default::default()
+    });
+
+    App::install_hooks();
+    let sessions = Arc::new(AtomicUsize::new(0));
+    let mut server = Server {
+        sessions: Arc::clone(&sessions),
+    };
+    let runtime = tokio::runtime::Runtime::new()?;
+    runtime.block_on(async {
+        let listener = tokio::net::TcpListener::bind(&address).await?;
+        println!("Serving TermPlay over SSH on {}", listener.local_addr()?);
+        println!("Host key: {fingerprint}");
+        println!("Press Ctrl+C to stop the server.");
+
+        // Lâcher le serveur couperait toutes les connexions : il vit jusqu'à
+        // ce que les sessions aient rendu leur terminal
+        let mut running = server.run_on_socket(config, &listener);
+        tokio::select! {
+            result = &mut running => result?,
+            () = wait_for_shutdown() => {}
+        }
+
+        // Les sessions voient le même drapeau et se terminent d'elles-mêmes
+        let deadline = Instant::now() + SHUTDOWN_GRACE;
+        while sessions.load(Ordering::SeqCst) > 0 && Instant::now() < deadline {
+            tokio::time::sleep(SHUTDOWN_POLL).await;
+        }
+        Ok(())
+    })
+}
+
+/// `:2222` n'écoute que sur cette machine, comme `127.0.0.1:2222` : ouvrir
+/// le serveur au réseau demande de donner l'adresse (`0.0.0.0:2222`)
+fn listen_address(address: &str) -> String {
+    match address.strip_prefix(':') {
+        Some(port) => format!("127.0.0.1:{port}"),
+        None => address.to_string(),
+    }
+}
+
+async fn wait_for_shutdown() {
+    while !shutdown::requested() {
+        tokio::time::sleep(SHUTDOWN_POLL).await;
+    }
+}
+
+/// Clé d'hôte enregistrée, ou une nouvelle clé Ed25519 enregistrée pour les
+/// lancements suivants
+fn host_key() -> Result<PrivateKey, Box<dyn std::error::Error>> {
+    let dir = storage::data_dir().ok_or("no config directory")?;
+    let path = dir.join(HOST_KEY_FILE);
+    if path.exists() {
+        return Ok(PrivateKey::from_openssh(fs::read_to_string(&path)?)?);
+    }
+
+    let seed: [u8; 32] = rand::rng().random();
+    let key = PrivateKey::from(Ed25519Keypair::from_seed(&seed));
+    fs::create_dir_all(&dir)?;
+    write_private(&path, key.to_openssh(LineEnding::LF)?.as_bytes())?;
+    Ok(key)
+}
+
+/// Écrit un fichier lisible par son seul propriétaire
+fn write_private(path: &Path, contents: &[u8]) -> io::Result<()> {
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    options.open(path)?.write_all(contents)
+}
+
+#[derive(Clone)]
+struct Server {
+    /// Sessions dont le terminal n'a pas encore été rendu au client
+    sessions: Arc<AtomicUsize>,
+}
+
+impl russh::server::Server for Server {
+    type Handler = Connection;
+
+    fn new_client(&mut self, _peer: Option<SocketAddr>) -> Connection {
+        Connection {
+            sessions: Arc::clone(&self.sessions),
+            channels: HashMap::new(),
+        }
+    }
+}
+
+/// Connexion d'un client ; chacun de ses canaux a sa session
+struct Connection {
+    sessions: Arc<AtomicUsize>,
+    channels: HashMap<ChannelId, ChannelState>,
+}
+
+enum ChannelState {
+    /// Canal ouvert, en attente du shell ; taille du PTY demandé
+    Opening(u16, u16),
+    /// Session lancée. Fermer le canal lâche son entrée, ce qui termine la
+    /// session
+    Running(StreamInput),
+}
+
+impl russh::server::Handler for Connection {
+    type Error = russh::Error;
+
+    async fn auth_none(&mut self, _user: &str) -> Result<Auth, Self::Error> {
+        Ok(Auth::Accept)
+    }
+
+    async fn auth_password(&mut self, _user: &str, _password: &str) -> Result<Auth, Self::Error> {
+        Ok(Auth::Accept)
+    }
+
+    async fn auth_publickey(&mut self, _user: &str, _key: &PublicKey) -> Result<Auth, Self::Error> {
+        Ok(Auth::Accept)
+    }
+
+    async fn channel_open_session(
+        &mut self,
+        channel: Channel<Msg>,
+        _session: &mut Session,
+    ) -> Result<bool, Self::Error> {
+        let (cols, rows) = DEFAULT_SIZE;
+        self.channels
+            .insert(channel.id(), ChannelState::Opening(cols, rows));
+        Ok(true)
+    }
+
+    async fn pty_request(
+        &mut self,
+        channel: ChannelId,
+        _term: &str,
+        col_width: u32,
+        row_height: u32,
+        _pix_width: u32,
+        _pix_height: u32,
+        _modes: &[(Pty, u32)],
+        session: &mut Session,
+    ) -> Result<(), Self::Error> {
+        if let Some(state) = self.channels.get_mut(&channel) {
+            let (cols, rows) = screen_size(col_width, row_height);
+            *state = ChannelState::Opening(cols, rows);
+        }
+        session.channel_success(channel)
+    }
+
+    async fn shell_request(
+        &mut self,
+        channel: ChannelId,
+        session: &mut Session,
+    ) -> Result<(), Self::Error> {
+        let Some(ChannelState::Opening(cols, rows)) = self.channels.get(&channel) else {
+            return session.channel_failure(channel);
+        };
+        let input = start_session(
+            session.handle(),
+            channel,
+            (*cols, *rows),
+            Arc::clone(&self.sessions),
+        )?;
+        self.channels.insert(channel, ChannelState::Running(input));
+        session.channel_success(channel)
+    }
+
+    /// Seul le shell est proposé : `ssh hôte commande` est refusé
+    async fn exec_request(
+        &mut self,
+        channel: ChannelId,
+        _data: &[u8],
+        session: &mut Session,
+    ) -> Result<(), Self::Error> {
+        session.channel_failure(channel)
+    }
+
+    async fn data(
+        &mut self,
+        channel: ChannelId,
+        data: &[u8],
+        _session: &mut Session,
+    ) -> Result<(), Self::Error> {
+        if let Some(ChannelState::Running(input)) = self.channels.get(&channel) {
+            input.data(&String::from_utf8_lossy(data));
+        }
+        Ok(())
+    }
+
+    async fn window_change_request(
+        &mut self,
+        channel: ChannelId,
+        col_width: u32,
+        row_height: u32,
+        _pix_width: u32,
+        _pix_height: u32,
+        _session: &mut Session,
+    ) -> Result<(), Self::Error> {
+        if let Some(ChannelState::Running(input)) = self.channels.get(&channel) {
+            let (cols, rows) = screen_size(col_width, row_height);
+            input.resize(cols, rows);
+        }
+        Ok(())
+    }
+
+    async fn channel_eof(
+        &mut self,
+        channel: ChannelId,
+        _session: &mut Session,
+    ) -> Result<(), Self::Error> {
+        self.channels.remove(&channel);
+        Ok(())
+    }
+
+    async fn channel_close(
+        &mut self,
+        channel: ChannelId,
+        _session: &mut Session,
+    ) -> Result<(), Self::Error> {
+        self.channels.remove(&channel);
+        Ok(())
+    }
+}
+
+/// Taille annoncée par le client, bornée à [`MAX_SIZE`] ; 0 veut dire
+/// inconnue (RFC 4254)
+fn screen_size(cols: u32, rows: u32) -> (u16, u16) {
+    if cols == 0 || rows == 0 {
+        return DEFAULT_SIZE;
+    }
+    let (max_cols, max_rows) = MAX_SIZE;
+    (
+        cols.min(u32::from(max_cols)) as u16,
+        rows.min(u32::from(max_rows)) as u16,
+    )
+}
+
+/// Lance le menu de TermPlay pour un canal, dans son propre thread. Ses
+/// images partent vers le client au fil de l'eau ; le canal est fermé quand
+/// le joueur quitte ou que la connexion tombe
+fn start_session(
+    handle: Handle,
+    channel: ChannelId,
+    (cols, rows): (u16, u16),
+    sessions: Arc<AtomicUsize>,
+) -> io::Result<StreamInput> {
+    let (sender, mut screen) = mpsc::unbounded_channel();
+    let writer = ChannelWriter {
+        screen: sender,
+        buffer: Vec::new(),
+    };
+    let (mut frontend, input) = StreamFrontend::new(writer, cols, rows)?;
+
+    sessions.fetch_add(1, Ordering::SeqCst);
+    std::thread::spawn(move || {
+        let result = App::guest(GameRegistry::silent()).run_menu_on(&mut frontend);
+        if let Err(e) = result {
+            let closed = e
+                .downcast_ref::<io::Error>()
+                .is_some_and(|e| e.kind() == io::ErrorKind::ConnectionAborted);
+            if !closed {
+                eprintln!("SSH session ended with an error: {e}");
+            }
+        }
+        // Rend l'écran principal au client et ferme le flux des images
+        drop(frontend);
+    });
+
+    tokio::spawn(async move {
+        while let Some(bytes) = screen.recv().await {
+            if handle.data(channel, CryptoVec::from(bytes)).await.is_err() {
+                break;
+            }
+        }
+        let _ = handle.exit_status_request(channel, 0).await;
+        let _ = handle.eof(channel).await;
+        let _ = handle.close(channel).await;
+        sessions.fetch_sub(1, Ordering::SeqCst);
+    });
+
+    Ok(input)
+}
+
+/// Écran d'une session : les octets d'une image sont envoyés au client en
+/// un seul paquet, au `flush` de fin d'image
+struct ChannelWriter {
+    screen: UnboundedSender<Vec<u8>>,
+    buffer: Vec<u8>,
+}
+
+impl Write for ChannelWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buffer.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+        self.screen
+            .send(std::mem::take(&mut self.buffer))
+            .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "connection closed"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::isolate_data_dir;
+
+    #[test]
+    fn a_bare_port_listens_on_this_machine_only() {
+        assert_eq!(listen_address(":2222"), "127.0.0.1:2222");
+        assert_eq!(listen_address("0.0.0.0:2222"), "0.0.0.0:2222");
+        assert_eq!(listen_address("[::]:2222"), "[::]:2222");
+    }
+
+    #[test]
+    fn window_sizes_are_defaulted_and_bounded() {
+        assert_eq!(screen_size(0, 0), DEFAULT_SIZE);
+        assert_eq!(screen_size(120, 40), (120, 40));
+        assert_eq!(screen_size(100_000, 40), (500, 40));
+        assert_eq!(screen_size(65_535, 65_535), MAX_SIZE);
+    }
+
+    #[test]
+    fn the_host_key_is_kept_between_launches() {
+        isolate_data_dir();
+
+        let first = host_key().unwrap();
+        let second = host_key().unwrap();
+        assert_eq!(first.public_key(), second.public_key());
+    }
+}
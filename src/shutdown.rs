@@ -0,0 +1,41 @@
+//! Arrêt propre sur Ctrl+C et sur les signaux de terminaison.
+//!
+//! En mode raw, Ctrl+C arrive comme un événement clavier ; SIGINT, SIGTERM et
+//! SIGHUP (fermeture du terminal, `kill`) lèvent le même drapeau. Les boucles de
+//! l'application le consultent à chaque itération pour sauvegarder, couper le
+//! son et restaurer le terminal au lieu d'être tuées en plein rendu.
+
+use crate::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, LazyLock};
+
+static REQUESTED: LazyLock<Arc<AtomicBool>> = LazyLock::new(|| Arc::new(AtomicBool::new(false)));
+
+/// Redirige SIGINT, SIGTERM et SIGHUP vers le drapeau d'arrêt. À n'appeler
+/// que pour l'interface plein écran : les commandes en ligne gardent le
+/// comportement par défaut des signaux
+pub fn install_signal_handlers() {
+    #[cfg(all(unix, feature = "native"))]
+    for signal in [
+        signal_hook::consts::SIGINT,
+        signal_hook::consts::SIGTERM,
+        signal_hook::consts::SIGHUP,
+    ] {
+        let _ = signal_hook::flag::register(signal, Arc::clone(&REQUESTED));
+    }
+}
+
+/// Demande l'arrêt de l'application
+pub fn request() {
+    REQUESTED.store(true, Ordering::SeqCst);
+}
+
+/// Indique si un arrêt a été demandé
+pub fn requested() -> bool {
+    REQUESTED.load(Ordering::SeqCst)
+}
+
+/// Ctrl+C tel que reçu en mode raw
+pub fn is_interrupt(key: &KeyEvent) -> bool {
+    key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL)
+}
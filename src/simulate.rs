@@ -0,0 +1,94 @@
+//! Commande `termplay gol run` : simulation du Jeu de la Vie sans interface.
+//!
+//! Le motif est lu depuis un fichier RLE, simulé avec le même noyau que le
+//! jeu (`core::life`) sur un plan illimité, et le résultat est écrit au même
+//! format, pour les scripts et l'étude de motifs.
+
+use crate::core::life::Simulation;
+use crate::stamps;
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+
+/// Évolution de la population pendant la simulation
+struct PopulationStats {
+    initial: usize,
+    min: (usize, u64),
+    max: (usize, u64),
+}
+
+impl PopulationStats {
+    fn new(population: usize) -> Self {
+        Self {
+            initial: population,
+            min: (population, 0),
+            max: (population, 0),
+        }
+    }
+
+    fn record(&mut self, population: usize, generation: u64) {
+        if population < self.min.0 {
+            self.min = (population, generation);
+        }
+        if population > self.max.0 {
+            self.max = (population, generation);
+        }
+    }
+}
+
+/// Simule `generations` générations du motif `pattern` et écrit le résultat
+/// dans `out`, ou sur la sortie standard sans fichier de sortie
+pub fn run(
+    pattern: &Path,
+    generations: u64,
+    out: Option<&Path>,
+    show_stats: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let content = fs::read_to_string(pattern)
+        .map_err(|e| format!("cannot read {}: {e}", pattern.display()))?;
+    let cells = stamps::parse_rle_file(&content)
+        .map_err(|e| format!("invalid pattern {}: {e}", pattern.display()))?;
+
+    let mut simulation = Simulation::from_cells(&cells);
+    let mut stats = PopulationStats::new(simulation.population());
+    for _ in 0..generations {
+        simulation.step();
+        stats.record(simulation.population(), simulation.generation());
+    }
+
+    let result = simulation.live_cells();
+    let rle = stamps::format_rle_file(&result);
+    match out {
+        Some(path) => {
+            fs::write(path, rle).map_err(|e| format!("cannot write {}: {e}", path.display()))?
+        }
+        None => io::stdout().write_all(rle.as_bytes())?,
+    }
+
+    if show_stats {
+        // Sans fichier de sortie, la sortie standard est réservée au motif
+        let mut report: Box<dyn Write> = if out.is_some() {
+            Box::new(io::stdout())
+        } else {
+            Box::new(io::stderr())
+        };
+        let width = result.iter().map(|&(x, _)| x + 1).max().unwrap_or(0);
+        let height = result.iter().map(|&(_, y)| y + 1).max().unwrap_or(0);
+        writeln!(report, "generations: {}", simulation.generation())?;
+        writeln!(report, "initial population: {}", stats.initial)?;
+        writeln!(report, "final population: {}", result.len())?;
+        writeln!(
+            report,
+            "min population: {} (generation {})",
+            stats.min.0, stats.min.1
+        )?;
+        writeln!(
+            report,
+            "max population: {} (generation {})",
+            stats.max.0, stats.max.1
+        )?;
+        writeln!(report, "bounding box: {width}x{height}")?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,133 @@
+//! Écran d'accueil affiché au lancement du menu.
+//!
+//! Les high scores, l'historique des parties et la durée des morceaux du
+//! Music Player sont chargés chacun dans un thread pendant que le logo
+//! s'affiche avec l'avancement. Une touche écourte l'écran dès que tout est
+//! chargé ; l'audio, lié au thread principal, démarre ensuite avec le menu.
+
+use crate::core::clock::Instant;
+use crate::event::{Event, KeyEventKind};
+use crate::frontend::Frontend;
+use crate::history;
+use crate::menu::{self, StartupData};
+use crate::shutdown;
+use crate::ui::bigtext::{self, Font};
+use crate::ui::style;
+use ratatui::{
+    layout::{Alignment, Constraint, Flex, Layout},
+    style::{Color, Style, Stylize},
+    text::Line,
+    widgets::{Gauge, Paragraph},
+    Frame,
+};
+use std::io;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// Durée minimale d'affichage, pour que le logo ne fasse pas que clignoter
+const MIN_DISPLAY: Duration = Duration::from_millis(700);
+
+/// Intervalle entre deux images de l'écran
+const FRAME_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Étapes affichées, dans l'ordre où l'écran les attend
+const STEPS: [&str; 3] = ["high scores", "statistics", "music"];
+
+/// Charge les données du menu en affichant l'écran d'accueil
+pub fn load_startup<F: Frontend>(frontend: &mut F) -> io::Result<StartupData> {
+    let scores = thread::spawn(menu::load_highscores);
+    let history = thread::spawn(history::load);
+    let tracks = thread::spawn(menu::music_tracks);
+
+    let started = Instant::now();
+    let mut skipped = false;
+    loop {
+        let finished = [
+            scores.is_finished(),
+            history.is_finished(),
+            tracks.is_finished(),
+        ];
+        let done = finished.iter().filter(|&&f| f).count();
+        if done == STEPS.len() && (skipped || started.elapsed() >= MIN_DISPLAY) {
+            break;
+        }
+
+        let pending = finished.iter().position(|&f| !f).map(|i| STEPS[i]);
+        frontend
+            .terminal()
+            .draw(|f| draw_splash(f, done as f64 / STEPS.len() as f64, pending))?;
+
+        match frontend.poll_event(FRAME_INTERVAL)? {
+            Some(Event::Key(key)) if shutdown::is_interrupt(&key) => {
+                shutdown::request();
+                skipped = true;
+            }
+            Some(Event::Key(key)) if key.kind == KeyEventKind::Press => skipped = true,
+            _ => {}
+        }
+    }
+
+    Ok(StartupData {
+        highscore_manager: join_or(scores, menu::load_highscores),
+        history: join_or(history, history::load),
+        music_tracks: join_or(tracks, menu::music_tracks),
+    })
+}
+
+/// Résultat du thread, ou `load` relancé ici s'il a paniqué
+fn join_or<T>(handle: JoinHandle<T>, load: fn() -> T) -> T {
+    handle.join().unwrap_or_else(|_| load())
+}
+
+/// Logo sur 5 lignes dans un grand terminal, sur 3 sinon
+pub fn logo_font(frame: &Frame) -> Font {
+    if frame.area().height >= 30 {
+        Font::Block
+    } else {
+        Font::Compact
+    }
+}
+
+fn draw_splash(frame: &mut Frame, ratio: f64, pending: Option<&str>) {
+    style::fill_background(frame, style::background());
+
+    let [logo_area, gauge_area, _, hint_area] = Layout::vertical([
+        Constraint::Length(logo_font(frame).height() + 3),
+        Constraint::Length(1),
+        Constraint::Length(1),
+        Constraint::Length(1),
+    ])
+    .flex(Flex::Center)
+    .areas(frame.area());
+
+    let mut logo = bigtext::banner(
+        "TERMPLAY",
+        logo_font(frame),
+        Style::new().cyan().bold(),
+        frame.area().width,
+    )
+    .unwrap_or_else(|| vec![Line::from("TERMPLAY".cyan().bold())]);
+    logo.push(Line::from(""));
+    logo.push(Line::from("Terminal Mini-Games Collection".magenta()));
+    frame.render_widget(Paragraph::new(logo).alignment(Alignment::Center), logo_area);
+
+    let [gauge_area] = Layout::horizontal([Constraint::Length(40)])
+        .flex(Flex::Center)
+        .areas(gauge_area);
+    let label = match pending {
+        Some(step) => format!("Loading {step}…"),
+        None => "Ready".to_string(),
+    };
+    frame.render_widget(
+        Gauge::default()
+            .ratio(ratio)
+            .label(label)
+            .gauge_style(Style::default().fg(Color::Cyan).bg(style::surface())),
+        gauge_area,
+    );
+
+    frame.render_widget(
+        Paragraph::new("Press any key to skip".dark_gray()).alignment(Alignment::Center),
+        hint_area,
+    );
+}
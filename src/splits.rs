@@ -0,0 +1,149 @@
+//! Chronomètre à étapes (splits) pour le speedrun.
+//!
+//! Les jeux marquent une étape à chaque palier (10 lignes de Tetris, 10
+//! pommes de Snake, 12 briques de Breakout) avec le temps de jeu du
+//! [`GameClock`](crate::core::clock::GameClock). Chaque étape est comparée à
+//! celle de la meilleure partie, enregistrée par jeu dans `splits.json`.
+
+use crate::storage;
+use crate::writer;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Meilleures étapes, par identifiant de jeu, en millisecondes de jeu
+pub const SPLITS_FILE: &str = "splits.json";
+
+/// Étapes de la partie en cours et de la meilleure partie
+#[derive(Debug, Clone)]
+pub struct SplitTimer {
+    game: String,
+    best: Vec<u64>,
+    splits: Vec<u64>,
+    finished: bool,
+}
+
+impl SplitTimer {
+    /// Chronomètre vide, comparé à la meilleure partie enregistrée de `game`
+    pub fn new(game: &str) -> Self {
+        Self {
+            game: game.to_string(),
+            best: load_all().remove(game).unwrap_or_default(),
+            splits: Vec::new(),
+            finished: false,
+        }
+    }
+
+    /// Marque une étape après `elapsed` de jeu
+    pub fn mark(&mut self, elapsed: Duration) {
+        if !self.finished {
+            self.splits.push(elapsed.as_millis() as u64);
+        }
+    }
+
+    /// Temps des étapes franchies, en millisecondes
+    pub fn splits(&self) -> &[u64] {
+        &self.splits
+    }
+
+    /// Temps de la meilleure partie à l'étape `index`
+    pub fn best(&self, index: usize) -> Option<u64> {
+        self.best.get(index).copied()
+    }
+
+    /// Avance (négative) ou retard sur la meilleure partie à l'étape `index`
+    pub fn delta(&self, index: usize) -> Option<i64> {
+        let split = *self.splits.get(index)?;
+        Some(split as i64 - self.best(index)? as i64)
+    }
+
+    /// Termine la partie : ses étapes deviennent la référence si elle est
+    /// allée plus loin que la meilleure, ou aussi loin en moins de temps
+    pub fn finish(&mut self) {
+        if std::mem::replace(&mut self.finished, true) || !self.beats_best() {
+            return;
+        }
+        self.best = self.splits.clone();
+        if let Err(e) = save(&self.game, &self.splits) {
+            eprintln!("Erreur lors de la sauvegarde des splits: {e}");
+        }
+    }
+
+    fn beats_best(&self) -> bool {
+        match self.splits.len().cmp(&self.best.len()) {
+            std::cmp::Ordering::Greater => true,
+            std::cmp::Ordering::Less => false,
+            std::cmp::Ordering::Equal => self
+                .splits
+                .last()
+                .zip(self.best.last())
+                .is_some_and(|(split, best)| split < best),
+        }
+    }
+}
+
+/// Formate un temps de jeu en "m:ss.d"
+pub fn format_split(ms: u64) -> String {
+    format!("{}:{:02}.{}", ms / 60_000, ms / 1000 % 60, ms / 100 % 10)
+}
+
+/// Formate un écart en secondes signées ("+1.3", "-0.8")
+pub fn format_delta(delta_ms: i64) -> String {
+    let sign = if delta_ms < 0 { '-' } else { '+' };
+    let tenths = delta_ms.unsigned_abs() / 100;
+    format!("{sign}{}.{}", tenths / 10, tenths % 10)
+}
+
+fn splits_path() -> Option<PathBuf> {
+    storage::data_dir().map(|dir| dir.join(SPLITS_FILE))
+}
+
+fn load_all() -> BTreeMap<String, Vec<u64>> {
+    splits_path()
+        .and_then(|path| writer::read_to_string(&path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save(game: &str, splits: &[u64]) -> Result<(), Box<dyn std::error::Error>> {
+    let path = splits_path().ok_or("no config directory")?;
+    let mut all = load_all();
+    all.insert(game.to_string(), splits.to_vec());
+    writer::write(path, serde_json::to_string_pretty(&all)?);
+    Ok(())
+}
+
+/// Efface les meilleures étapes (utilisé par "Reset Everything")
+pub fn clear() {
+    if let Some(path) = splits_path() {
+        writer::delete(path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timer(best: &[u64], splits: &[u64]) -> SplitTimer {
+        SplitTimer {
+            game: "test".to_string(),
+            best: best.to_vec(),
+            splits: splits.to_vec(),
+            finished: false,
+        }
+    }
+
+    #[test]
+    fn best_run_goes_further_then_faster() {
+        assert!(timer(&[1000, 2000], &[900, 1800, 2700]).beats_best());
+        assert!(!timer(&[1000, 2000], &[900]).beats_best());
+        assert!(timer(&[1000, 2000], &[1100, 1900]).beats_best());
+        assert!(!timer(&[1000, 2000], &[900, 2100]).beats_best());
+
+        let run = timer(&[1000, 2000], &[900, 2100]);
+        assert_eq!(run.delta(0), Some(-100));
+        assert_eq!(run.delta(1), Some(100));
+        assert_eq!(format_delta(-100), "-0.1");
+        assert_eq!(format_split(83_450), "1:23.4");
+    }
+}
@@ -0,0 +1,268 @@
+//! Bibliothèque de tampons du Game of Life : des sélections nommées,
+//! enregistrées sur le disque et replaçables plus tard depuis le sélecteur.
+//!
+//! Les cellules sont stockées au format RLE habituel des outils du Jeu de la
+//! Vie (`b` cellule morte, `o` cellule vivante, `$` fin de ligne, `!` fin du
+//! motif), ce qui permet de copier un tampon depuis ou vers d'autres logiciels.
+
+use crate::storage;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+/// Fichier de la bibliothèque, dans le dossier de données
+pub const STAMPS_FILE: &str = "stamps.json";
+
+/// Longueur maximale du nom d'un tampon
+pub const STAMP_NAME_MAX_LEN: usize = 24;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Stamp {
+    pub name: String,
+    pub width: usize,
+    pub height: usize,
+    pub rle: String,
+}
+
+impl Stamp {
+    /// Tampon à partir des cellules vivantes d'une sélection, ramenées en
+    /// haut à gauche de leur boîte englobante
+    pub fn from_cells(name: &str, cells: &[(usize, usize)]) -> Option<Self> {
+        let min_x = cells.iter().map(|&(x, _)| x).min()?;
+        let min_y = cells.iter().map(|&(_, y)| y).min()?;
+        let cells: Vec<(usize, usize)> =
+            cells.iter().map(|&(x, y)| (x - min_x, y - min_y)).collect();
+
+        let width = cells.iter().map(|&(x, _)| x).max()? + 1;
+        let height = cells.iter().map(|&(_, y)| y).max()? + 1;
+
+        Some(Self {
+            name: name.to_string(),
+            width,
+            height,
+            rle: encode_rle(&cells, width, height),
+        })
+    }
+
+    /// Cellules vivantes, relatives au coin haut gauche
+    pub fn cells(&self) -> Vec<(usize, usize)> {
+        decode_rle(&self.rle)
+    }
+
+    pub fn population(&self) -> usize {
+        self.cells().len()
+    }
+}
+
+/// Encode des cellules vivantes en RLE
+pub fn encode_rle(cells: &[(usize, usize)], width: usize, height: usize) -> String {
+    fn push_run(out: &mut String, count: usize, tag: char) {
+        if count > 1 {
+            out.push_str(&count.to_string());
+        }
+        if count > 0 {
+            out.push(tag);
+        }
+    }
+
+    // Abscisses des cellules vivantes, ligne par ligne
+    let mut rows: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+    for &(x, y) in cells.iter().filter(|&&(x, y)| x < width && y < height) {
+        rows.entry(y).or_default().push(x);
+    }
+
+    let mut out = String::new();
+    // Les lignes vides sont fusionnées dans le `$` suivant ("3$")
+    let mut previous_row = 0;
+
+    for (y, mut xs) in rows {
+        xs.sort_unstable();
+        xs.dedup();
+
+        push_run(&mut out, y - previous_row, '$');
+        previous_row = y;
+
+        // Les cellules mortes en fin de ligne sont implicites
+        let mut next_x = 0;
+        let mut i = 0;
+        while i < xs.len() {
+            let start = xs[i];
+            while i + 1 < xs.len() && xs[i + 1] == xs[i] + 1 {
+                i += 1;
+            }
+            push_run(&mut out, start - next_x, 'b');
+            push_run(&mut out, xs[i] + 1 - start, 'o');
+            next_x = xs[i] + 1;
+            i += 1;
+        }
+    }
+
+    out.push('!');
+    out
+}
+
+/// Décode un motif RLE en cellules vivantes ; un motif qui dépasse
+/// [`MAX_PATTERN_EXTENT`] ou [`MAX_PATTERN_CELLS`] est refusé
+fn try_decode_rle(rle: &str) -> Result<Vec<(usize, usize)>, String> {
+    let too_large = || {
+        format!(
+            "pattern too large (at most {MAX_PATTERN_EXTENT}x{MAX_PATTERN_EXTENT} and {MAX_PATTERN_CELLS} live cells)"
+        )
+    };
+    let mut cells = Vec::new();
+    let (mut x, mut y) = (0usize, 0usize);
+    let mut count = 0usize;
+
+    for c in rle.chars() {
+        if let Some(digit) = c.to_digit(10) {
+            count = count.saturating_mul(10).saturating_add(digit as usize);
+            continue;
+        }
+
+        let run = count.max(1);
+        count = 0;
+        match c {
+            'b' => x = x.saturating_add(run),
+            'o' => {
+                if x.saturating_add(run) > MAX_PATTERN_EXTENT
+                    || cells.len() + run > MAX_PATTERN_CELLS
+                {
+                    return Err(too_large());
+                }
+                cells.extend((x..x + run).map(|cx| (cx, y)));
+                x += run;
+            }
+            '$' => {
+                y = y.saturating_add(run);
+                x = 0;
+            }
+            '!' => break,
+            _ => {}
+        }
+        if y >= MAX_PATTERN_EXTENT {
+            return Err(too_large());
+        }
+    }
+
+    Ok(cells)
+}
+
+/// Décode un motif RLE en cellules vivantes, relatives au coin haut gauche ;
+/// un motif démesuré donne un motif vide
+pub fn decode_rle(rle: &str) -> Vec<(usize, usize)> {
+    try_decode_rle(rle).unwrap_or_default()
+}
+
+/// Règle de Conway, la seule simulée par TermPlay
+const CONWAY_RULE: &str = "B3/S23";
+/// Longueur maximale des lignes d'un fichier RLE, comme le veut le format
+const RLE_LINE_LEN: usize = 70;
+/// Côté maximal d'un motif lu, bien au-delà des plus grandes grilles du jeu
+pub const MAX_PATTERN_EXTENT: usize = 10_000;
+/// Nombre maximal de cellules vivantes d'un motif lu
+pub const MAX_PATTERN_CELLS: usize = 1_000_000;
+
+/// Lit un fichier `.rle` complet : les lignes de commentaire `#` sont
+/// ignorées et l'en-tête `x = …, y = …, rule = …` n'est vérifié que pour la
+/// règle
+pub fn parse_rle_file(content: &str) -> Result<Vec<(usize, usize)>, String> {
+    let mut body = String::new();
+    for line in content.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if line.starts_with('x') {
+            let rule = line
+                .split(',')
+                .filter_map(|field| field.split_once('='))
+                .find(|(key, _)| key.trim() == "rule")
+                .map(|(_, value)| value.trim());
+            if let Some(rule) = rule {
+                if !rule.eq_ignore_ascii_case(CONWAY_RULE) && !rule.eq_ignore_ascii_case("23/3") {
+                    return Err(format!(
+                        "unsupported rule '{rule}', only {CONWAY_RULE} is simulated"
+                    ));
+                }
+            }
+            continue;
+        }
+        body.push_str(line);
+    }
+
+    if body.is_empty() {
+        return Err("no pattern found".to_string());
+    }
+    try_decode_rle(&body)
+}
+
+/// Écrit des cellules vivantes au format de fichier `.rle`, avec son en-tête
+pub fn format_rle_file(cells: &[(usize, usize)]) -> String {
+    let width = cells.iter().map(|&(x, _)| x + 1).max().unwrap_or(0);
+    let height = cells.iter().map(|&(_, y)| y + 1).max().unwrap_or(0);
+    let body = encode_rle(cells, width, height);
+
+    let mut out = format!("x = {width}, y = {height}, rule = {CONWAY_RULE}\n");
+    // Couper les lignes sans séparer un nombre de son symbole
+    let mut line_len = 0;
+    let mut token = String::new();
+    for c in body.chars() {
+        token.push(c);
+        if c.is_ascii_digit() {
+            continue;
+        }
+        if line_len + token.len() > RLE_LINE_LEN {
+            out.push('\n');
+            line_len = 0;
+        }
+        out.push_str(&token);
+        line_len += token.len();
+        token.clear();
+    }
+    out.push('\n');
+    out
+}
+
+fn stamps_path() -> Option<PathBuf> {
+    storage::data_dir().map(|dir| dir.join(STAMPS_FILE))
+}
+
+/// Tampons enregistrés, par ordre alphabétique
+pub fn load_all() -> Vec<Stamp> {
+    let mut stamps: Vec<Stamp> = stamps_path()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default();
+    stamps.sort_by_key(|stamp| stamp.name.to_lowercase());
+    stamps
+}
+
+fn save_all(stamps: &[Stamp]) -> Result<(), Box<dyn std::error::Error>> {
+    let path = stamps_path().ok_or("no config directory")?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, serde_json::to_string_pretty(stamps)?)?;
+    Ok(())
+}
+
+/// Sérialise les modifications du fichier : un enregistrement lancé en tâche
+/// de fond ne doit pas écraser celui d'à côté
+static EDITING: Mutex<()> = Mutex::new(());
+
+/// Enregistre un tampon, en remplaçant celui qui porte le même nom
+pub fn save(stamp: Stamp) -> Result<(), Box<dyn std::error::Error>> {
+    let _editing = EDITING.lock().unwrap_or_else(|e| e.into_inner());
+    let mut stamps = load_all();
+    stamps.retain(|existing| existing.name != stamp.name);
+    stamps.push(stamp);
+    save_all(&stamps)
+}
+
+pub fn delete(name: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let _editing = EDITING.lock().unwrap_or_else(|e| e.into_inner());
+    let mut stamps = load_all();
+    stamps.retain(|stamp| stamp.name != name);
+    save_all(&stamps)
+}
@@ -0,0 +1,214 @@
+//! Fichiers enregistrés par TermPlay dans son dossier de configuration.
+//!
+//! Utilisé par l'onglet Data des Settings pour afficher l'emplacement et la
+//! taille de chaque fichier, ouvrir le dossier et faire le ménage.
+
+use crate::ghosts;
+use crate::history;
+use crate::puzzles;
+use crate::recording;
+use crate::saves;
+use crate::splits;
+use crate::stamps;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+/// Fichier connu du dossier de données
+pub struct StoredFile {
+    pub label: &'static str,
+    pub file_name: &'static str,
+    /// Fichier jetable, supprimé par "Clear Caches"
+    pub cache: bool,
+    /// Dossier : sa taille est celle des fichiers qu'il contient
+    pub directory: bool,
+}
+
+pub const STORED_FILES: [StoredFile; 10] = [
+    StoredFile {
+        label: "Settings",
+        file_name: "config.json",
+        cache: false,
+        directory: false,
+    },
+    StoredFile {
+        label: "High Scores",
+        file_name: "highscores.json",
+        cache: false,
+        directory: false,
+    },
+    StoredFile {
+        label: "Puzzle Progress",
+        file_name: puzzles::PROGRESS_FILE,
+        cache: false,
+        directory: false,
+    },
+    StoredFile {
+        label: "Life Stamps",
+        file_name: stamps::STAMPS_FILE,
+        cache: false,
+        directory: false,
+    },
+    StoredFile {
+        label: "Game History",
+        file_name: history::HISTORY_FILE,
+        cache: false,
+        directory: false,
+    },
+    StoredFile {
+        label: "Ghosts",
+        file_name: ghosts::GHOSTS_FILE,
+        cache: false,
+        directory: false,
+    },
+    StoredFile {
+        label: "Split Times",
+        file_name: splits::SPLITS_FILE,
+        cache: false,
+        directory: false,
+    },
+    StoredFile {
+        label: "Saved Games",
+        file_name: saves::SAVES_FILE,
+        cache: false,
+        directory: false,
+    },
+    StoredFile {
+        label: "Recordings",
+        file_name: recording::RECORDINGS_DIR,
+        cache: false,
+        directory: true,
+    },
+    StoredFile {
+        label: "Crash Log",
+        file_name: "crash.log",
+        cache: true,
+        directory: false,
+    },
+];
+
+impl StoredFile {
+    pub fn path(&self) -> Option<PathBuf> {
+        data_dir().map(|dir| dir.join(self.file_name))
+    }
+
+    /// Taille sur le disque, `None` si le fichier n'existe pas
+    pub fn size(&self) -> Option<u64> {
+        let path = self.path()?;
+        if !self.directory {
+            return fs::metadata(path).ok().map(|metadata| metadata.len());
+        }
+        let size = fs::read_dir(path)
+            .ok()?
+            .filter_map(|entry| entry.ok()?.metadata().ok())
+            .filter(|metadata| metadata.is_file())
+            .map(|metadata| metadata.len())
+            .sum();
+        Some(size)
+    }
+}
+
+/// Remplace le dossier de données, pour les tests et les installations
+/// portables
+pub const DATA_DIR_VAR: &str = "TERMPLAY_DATA_DIR";
+
+/// Dossier où TermPlay enregistre sa configuration et ses scores :
+/// `$TERMPLAY_DATA_DIR` s'il est défini, sinon `termplay` dans le dossier de
+/// configuration du système
+#[cfg(feature = "native")]
+pub fn data_dir() -> Option<PathBuf> {
+    match std::env::var_os(DATA_DIR_VAR) {
+        Some(dir) if !dir.is_empty() => Some(PathBuf::from(dir)),
+        _ => dirs::config_dir().map(|dir| dir.join("termplay")),
+    }
+}
+
+/// Aucun dossier dans le navigateur : les réglages par défaut s'appliquent
+/// et rien n'est enregistré
+#[cfg(not(feature = "native"))]
+pub fn data_dir() -> Option<PathBuf> {
+    None
+}
+
+/// Taille totale des fichiers connus
+pub fn total_size() -> u64 {
+    STORED_FILES.iter().filter_map(StoredFile::size).sum()
+}
+
+/// Supprime les fichiers jetables et retourne le nombre d'octets libérés
+pub fn clear_caches() -> io::Result<u64> {
+    let mut freed = 0;
+    for file in STORED_FILES.iter().filter(|file| file.cache) {
+        if let (Some(path), Some(size)) = (file.path(), file.size()) {
+            fs::remove_file(path)?;
+            freed += size;
+        }
+    }
+    Ok(freed)
+}
+
+/// Ouvre le dossier de données dans le gestionnaire de fichiers du système
+pub fn open_data_dir() -> io::Result<()> {
+    let dir = data_dir().ok_or_else(|| io::Error::other("no config directory"))?;
+
+    let opener = if cfg!(target_os = "macos") {
+        "open"
+    } else if cfg!(windows) {
+        "explorer"
+    } else {
+        "xdg-open"
+    };
+
+    // Rediriger les sorties pour ne pas écrire par-dessus l'interface
+    Command::new(opener)
+        .arg(dir)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    Ok(())
+}
+
+/// Formate une taille en octets ("0 B", "12.3 KB", "1.5 MB")
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 3] = ["KB", "MB", "GB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::isolate_data_dir;
+
+    #[test]
+    #[cfg_attr(not(feature = "native"), ignore = "no data folder without `native`")]
+    fn the_recordings_folder_counts_its_files_and_is_cleared() {
+        isolate_data_dir();
+        let recordings = STORED_FILES
+            .iter()
+            .find(|file| file.file_name == recording::RECORDINGS_DIR)
+            .unwrap();
+        let dir = recordings.path().unwrap();
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("snake-1.cast"), [0; 100]).unwrap();
+        fs::write(dir.join("snake-1.tpr"), [0; 20]).unwrap();
+        assert_eq!(recordings.size(), Some(120));
+
+        recording::clear().unwrap();
+        assert_eq!(recordings.size(), None);
+        // Déjà supprimé : rien à faire
+        recording::clear().unwrap();
+    }
+}
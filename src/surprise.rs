@@ -0,0 +1,105 @@
+//! Jeu tiré au hasard : "🎲 Random Game" au bas du menu Games et
+//! `termplay game random`.
+//!
+//! Le tirage évite sans les exclure les jeux joués dernièrement : d'après
+//! l'historique, le dernier jeu joué a quatre fois moins de chances de
+//! sortir qu'un jeu délaissé.
+
+use crate::history::SessionRecord;
+use rand::Rng;
+
+/// Nom réservé de `termplay game` pour un jeu tiré au hasard
+pub const RANDOM_GAME: &str = "random";
+
+/// Parties récentes prises en compte pour le tirage
+const RECENT_SESSIONS: usize = 10;
+/// Poids d'un jeu absent des parties récentes ; le dernier jeu joué pèse 1,
+/// l'avant-dernier 2, etc.
+const MAX_WEIGHT: u32 = 4;
+
+/// Poids de chaque jeu de `candidates` dans le tirage
+fn weights(candidates: &[&str], history: &[SessionRecord]) -> Vec<u32> {
+    // Jeux des dernières parties, du plus récent au plus ancien, sans doublon
+    let mut recent: Vec<&str> = Vec::new();
+    for record in history.iter().rev().take(RECENT_SESSIONS) {
+        if !recent.contains(&record.game.as_str()) {
+            recent.push(&record.game);
+        }
+    }
+
+    candidates
+        .iter()
+        .map(|game| {
+            recent
+                .iter()
+                .position(|played| played == game)
+                .map_or(MAX_WEIGHT, |rank| (rank as u32 + 1).min(MAX_WEIGHT))
+        })
+        .collect()
+}
+
+/// Tire un jeu parmi `candidates`, identifiants du registre ; `None` s'il
+/// n'y en a aucun
+pub fn pick<'a>(
+    candidates: &[&'a str],
+    history: &[SessionRecord],
+    rng: &mut impl Rng,
+) -> Option<&'a str> {
+    let weights = weights(candidates, history);
+    let total: u32 = weights.iter().sum();
+    if total == 0 {
+        return None;
+    }
+
+    let mut roll = rng.random_range(0..total);
+    candidates
+        .iter()
+        .zip(weights)
+        .find(|&(_, weight)| {
+            let hit = roll < weight;
+            roll = roll.saturating_sub(weight);
+            hit
+        })
+        .map(|(game, _)| *game)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    fn played(game: &str) -> SessionRecord {
+        SessionRecord {
+            game: game.to_string(),
+            mode: None,
+            score: None,
+            target_reached: None,
+            won: false,
+            duration_seconds: 60,
+            timestamp: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn recently_played_games_are_less_likely_but_never_excluded() {
+        let history: Vec<SessionRecord> = ["snake", "pong", "tetris", "tetris"]
+            .into_iter()
+            .map(played)
+            .collect();
+        let candidates = ["tetris", "pong", "snake", "2048"];
+        assert_eq!(weights(&candidates, &history), vec![1, 2, 3, 4]);
+
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut counts = [0; 4];
+        for _ in 0..1_000 {
+            let game = pick(&candidates, &history, &mut rng).unwrap();
+            counts[candidates.iter().position(|&c| c == game).unwrap()] += 1;
+        }
+        assert!(counts[0] > 0, "{counts:?}");
+        assert!(counts[0] < counts[3], "{counts:?}");
+
+        assert_eq!(pick(&["tetris"], &history, &mut rng), Some("tetris"));
+        assert_eq!(pick(&[], &history, &mut rng), None);
+    }
+}
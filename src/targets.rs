@@ -0,0 +1,106 @@
+//! Objectifs de score personnels : un score à viser par jeu ("Tetris 50k"),
+//! réglé dans Settings → Gameplay.
+//!
+//! Pendant la partie, la boucle de jeu surveille le score avec un
+//! [`TargetWatch`] ; le franchissement de l'objectif est fêté une fois par
+//! partie et noté dans l'historique.
+
+use crate::config;
+
+/// Lit un objectif tapé par le joueur : "50000", "50,000", "50k", "1.5M".
+/// Rend `None` pour un texte illisible ou un objectif nul
+pub fn parse(text: &str) -> Option<u32> {
+    let text: String = text
+        .trim()
+        .chars()
+        .filter(|c| !matches!(c, ',' | '_' | '\'' | ' ' | '\u{a0}'))
+        .collect::<String>()
+        .to_lowercase();
+    let (number, multiplier) = match text.strip_suffix('k') {
+        Some(number) => (number, 1_000.0),
+        None => match text.strip_suffix('m') {
+            Some(number) => (number, 1_000_000.0),
+            None => (text.as_str(), 1.0),
+        },
+    };
+    let value = number.parse::<f64>().ok()? * multiplier;
+    (value.is_finite() && value >= 1.0 && value <= f64::from(u32::MAX))
+        .then(|| value.round() as u32)
+}
+
+/// Objectif réglé pour `game_id`
+pub fn target_for(game_id: &str) -> Option<u32> {
+    config::current()
+        .gameplay
+        .score_targets
+        .get(game_id)
+        .copied()
+}
+
+/// Surveille le score d'une partie jusqu'à l'objectif
+#[derive(Debug, Clone, Default)]
+pub struct TargetWatch {
+    target: Option<u32>,
+    reached: bool,
+}
+
+impl TargetWatch {
+    pub fn new(target: Option<u32>) -> Self {
+        Self {
+            target,
+            reached: false,
+        }
+    }
+
+    pub fn for_game(game_id: &str) -> Self {
+        Self::new(target_for(game_id))
+    }
+
+    /// Rend l'objectif la première fois que `score` l'atteint. Un score
+    /// retombé sous l'objectif est celui d'une nouvelle partie, qui doit
+    /// l'atteindre de nouveau
+    pub fn check(&mut self, score: Option<u32>) -> Option<u32> {
+        let target = self.target?;
+        let reached = score? >= target;
+        let crossed = reached && !self.reached;
+        self.reached = reached;
+        crossed.then_some(target)
+    }
+
+    /// Objectif atteint pendant la partie, pour l'historique
+    pub fn reached(&self) -> Option<u32> {
+        self.target.filter(|_| self.reached)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn targets_read_like_players_write_them() {
+        assert_eq!(parse("50000"), Some(50_000));
+        assert_eq!(parse(" 50,000 "), Some(50_000));
+        assert_eq!(parse("50k"), Some(50_000));
+        assert_eq!(parse("1.5M"), Some(1_500_000));
+        assert_eq!(parse("12 500"), Some(12_500));
+        assert_eq!(parse("0"), None);
+        assert_eq!(parse("lots"), None);
+        assert_eq!(parse(""), None);
+    }
+
+    #[test]
+    fn a_target_is_celebrated_once_per_game() {
+        let mut watch = TargetWatch::new(Some(100));
+        assert_eq!(watch.check(Some(90)), None);
+        assert_eq!(watch.check(Some(120)), Some(100));
+        assert_eq!(watch.check(Some(200)), None);
+        assert_eq!(watch.reached(), Some(100));
+
+        // Partie recommencée : le score repart de zéro
+        assert_eq!(watch.check(Some(0)), None);
+        assert_eq!(watch.reached(), None);
+        assert_eq!(watch.check(Some(100)), Some(100));
+        assert_eq!(TargetWatch::new(None).check(Some(1_000)), None);
+    }
+}
@@ -0,0 +1,18 @@
+//! Outils partagés par les tests unitaires.
+
+use crate::storage;
+use std::sync::Once;
+
+/// Dirige la configuration, les scores et l'historique vers un dossier
+/// temporaire propre au processus de test : une fin de partie provoquée par
+/// un test n'écrit jamais dans les données du développeur
+pub fn isolate_data_dir() {
+    static INIT: Once = Once::new();
+    INIT.call_once(|| {
+        let dir = std::env::temp_dir().join(format!("termplay-unit-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::env::set_var(storage::DATA_DIR_VAR, &dir);
+        // Locale fixe : nombres et symboles ne dépendent pas de la machine
+        std::env::set_var("LC_ALL", "C.UTF-8");
+    });
+}
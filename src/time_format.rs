@@ -0,0 +1,201 @@
+//! Dates, heures et durées affichées dans les classements, les statistiques
+//! et l'historique des parties.
+//!
+//! Les dates sont enregistrées en UTC. Leur affichage suit les réglages
+//! Clock, Date Format et Time Zone des Settings ; `Auto` y est résolu
+//! d'après la locale (`LC_ALL`, `LC_TIME` puis `LANG`).
+
+use crate::config::{ClockFormat, DateOrder, DisplayConfig, Timezone};
+use chrono::{DateTime, Local, Utc};
+use std::sync::{PoisonError, RwLock};
+
+/// Réglages résolus ; `None` tant que la configuration n'a pas été lue
+static CURRENT: RwLock<Option<TimeFormat>> = RwLock::new(None);
+
+/// Territoires dont la locale compte les heures sur 12 heures
+const TWELVE_HOUR_LOCALES: [&str; 6] = ["en_US", "en_CA", "en_AU", "en_NZ", "en_IN", "en_PH"];
+/// Territoires qui écrivent le mois avant le jour
+const MONTH_FIRST_LOCALES: [&str; 2] = ["en_US", "en_PH"];
+/// Territoires qui écrivent l'année en premier
+const YEAR_FIRST_TERRITORIES: [&str; 8] = ["CA", "CN", "HU", "JP", "KR", "LT", "SE", "TW"];
+
+/// Réglages d'affichage des dates, `Auto` résolu
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeFormat {
+    pub twelve_hour: bool,
+    /// Jamais `DateOrder::Auto`
+    pub order: DateOrder,
+    pub timezone: Timezone,
+}
+
+impl TimeFormat {
+    /// Réglages de `display`, `Auto` résolu d'après la locale de
+    /// l'environnement
+    pub fn from_config(display: &DisplayConfig) -> Self {
+        let locale = ["LC_ALL", "LC_TIME", "LANG"]
+            .iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|value| !value.is_empty());
+        Self::resolve(display, locale.as_deref())
+    }
+
+    fn resolve(display: &DisplayConfig, locale: Option<&str>) -> Self {
+        // "fr_FR.UTF-8@euro" -> "fr_FR" ; "C", "POSIX" ou rien : format ISO
+        let name = locale
+            .map(|locale| locale.split(['.', '@']).next().unwrap_or_default())
+            .filter(|name| !name.is_empty() && *name != "C" && *name != "POSIX");
+        let twelve_hour = match display.clock_format {
+            ClockFormat::Auto => name.is_some_and(|name| TWELVE_HOUR_LOCALES.contains(&name)),
+            ClockFormat::TwentyFourHour => false,
+            ClockFormat::TwelveHour => true,
+        };
+        let order = match (display.date_order, name) {
+            (DateOrder::Auto, None) => DateOrder::Iso,
+            (DateOrder::Auto, Some(name)) if MONTH_FIRST_LOCALES.contains(&name) => {
+                DateOrder::MonthDayYear
+            }
+            (DateOrder::Auto, Some(name)) => match name.split_once('_') {
+                Some((_, territory)) if YEAR_FIRST_TERRITORIES.contains(&territory) => {
+                    DateOrder::Iso
+                }
+                _ => DateOrder::DayMonthYear,
+            },
+            (order, _) => order,
+        };
+        Self {
+            twelve_hour,
+            order,
+            timezone: display.timezone,
+        }
+    }
+
+    /// Date seule ("2026-10-16", "16/10/2026"…)
+    pub fn date(&self, time: DateTime<Utc>) -> String {
+        self.format(time, self.date_pattern())
+    }
+
+    /// Heure seule ("14:05" ou "02:05 PM", de largeur fixe pour les colonnes)
+    pub fn time(&self, time: DateTime<Utc>) -> String {
+        self.format(time, self.time_pattern())
+    }
+
+    /// Date et heure
+    pub fn date_time(&self, time: DateTime<Utc>) -> String {
+        let pattern = format!("{} {}", self.date_pattern(), self.time_pattern());
+        self.format(time, &pattern)
+    }
+
+    fn date_pattern(&self) -> &'static str {
+        match self.order {
+            DateOrder::Auto | DateOrder::Iso => "%Y-%m-%d",
+            DateOrder::DayMonthYear => "%d/%m/%Y",
+            DateOrder::MonthDayYear => "%m/%d/%Y",
+        }
+    }
+
+    fn time_pattern(&self) -> &'static str {
+        if self.twelve_hour {
+            "%I:%M %p"
+        } else {
+            "%H:%M"
+        }
+    }
+
+    fn format(&self, time: DateTime<Utc>, pattern: &str) -> String {
+        match self.timezone {
+            Timezone::Local => time.with_timezone(&Local).format(pattern).to_string(),
+            Timezone::Utc => time.format(pattern).to_string(),
+        }
+    }
+}
+
+/// Réglages en vigueur, suivis depuis le premier appel
+pub fn current() -> TimeFormat {
+    if let Some(format) = *CURRENT.read().unwrap_or_else(PoisonError::into_inner) {
+        return format;
+    }
+    crate::ui::style::watch_config();
+    (*CURRENT.read().unwrap_or_else(PoisonError::into_inner))
+        .unwrap_or_else(|| TimeFormat::from_config(&DisplayConfig::default()))
+}
+
+/// Prend en compte des réglages d'affichage modifiés
+pub fn apply_display_config(display: &DisplayConfig) {
+    *CURRENT.write().unwrap_or_else(PoisonError::into_inner) =
+        Some(TimeFormat::from_config(display));
+}
+
+/// Date seule, selon les réglages en vigueur
+pub fn format_date(time: DateTime<Utc>) -> String {
+    current().date(time)
+}
+
+/// Heure seule, selon les réglages en vigueur
+pub fn format_time(time: DateTime<Utc>) -> String {
+    current().time(time)
+}
+
+/// Date et heure, selon les réglages en vigueur
+pub fn format_date_time(time: DateTime<Utc>) -> String {
+    current().date_time(time)
+}
+
+/// Formate une durée de jeu ("45s", "12m 05s", "3h 20m")
+pub fn format_duration(seconds: u64) -> String {
+    match seconds {
+        0..60 => format!("{seconds}s"),
+        60..3600 => format!("{}m {:02}s", seconds / 60, seconds % 60),
+        _ => format!("{}h {:02}m", seconds / 3600, seconds % 3600 / 60),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn resolve(clock_format: ClockFormat, date_order: DateOrder, locale: &str) -> TimeFormat {
+        let display = DisplayConfig {
+            clock_format,
+            date_order,
+            timezone: Timezone::Utc,
+            ..DisplayConfig::default()
+        };
+        TimeFormat::resolve(&display, Some(locale).filter(|locale| !locale.is_empty()))
+    }
+
+    #[test]
+    fn auto_follows_the_locale() {
+        let auto = |locale| resolve(ClockFormat::Auto, DateOrder::Auto, locale);
+        assert_eq!(auto("").order, DateOrder::Iso);
+        assert_eq!(auto("C.UTF-8").order, DateOrder::Iso);
+        assert_eq!(auto("en_US.UTF-8").order, DateOrder::MonthDayYear);
+        assert!(auto("en_US.UTF-8").twelve_hour);
+        assert_eq!(auto("fr_FR.UTF-8@euro").order, DateOrder::DayMonthYear);
+        assert!(!auto("fr_FR.UTF-8@euro").twelve_hour);
+        assert_eq!(auto("ja_JP.UTF-8").order, DateOrder::Iso);
+        // Un réglage explicite l'emporte sur la locale
+        let forced = resolve(ClockFormat::TwentyFourHour, DateOrder::Iso, "en_US.UTF-8");
+        assert_eq!(forced.order, DateOrder::Iso);
+        assert!(!forced.twelve_hour);
+    }
+
+    #[test]
+    fn formats_dates_and_times_in_utc() {
+        let time = Utc.with_ymd_and_hms(2026, 3, 7, 14, 5, 0).unwrap();
+        let us = resolve(ClockFormat::Auto, DateOrder::Auto, "en_US.UTF-8");
+        assert_eq!(us.date_time(time), "03/07/2026 02:05 PM");
+        let fr = resolve(ClockFormat::Auto, DateOrder::Auto, "fr_FR.UTF-8");
+        assert_eq!(fr.date_time(time), "07/03/2026 14:05");
+        assert_eq!(fr.time(time), "14:05");
+        let iso = resolve(ClockFormat::Auto, DateOrder::Auto, "");
+        assert_eq!(iso.date(time), "2026-03-07");
+    }
+
+    #[test]
+    fn durations_keep_two_digits_below_the_largest_unit() {
+        assert_eq!(format_duration(45), "45s");
+        assert_eq!(format_duration(725), "12m 05s");
+        assert_eq!(format_duration(12_000), "3h 20m");
+    }
+}
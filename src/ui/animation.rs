@@ -0,0 +1,268 @@
+//! Transitions des menus : quelques images de glissement à l'entrée et à la
+//! sortie des sous-menus, et de fondu à l'ouverture des popups.
+//!
+//! Une transition ne dessine rien elle-même : l'écran est dessiné
+//! normalement, puis [`Transition::apply`] décale ou assombrit les cellules
+//! de la zone concernée selon le temps écoulé. Les jeux secouent leur
+//! terrain avec [`Shake`] et le teintent de rouge en danger avec
+//! [`danger_color`]. Le réglage "Reduce Motion" des Settings les désactive
+//! toutes ; la teinte de danger reste, sans pulser.
+
+use crate::core::clock::Instant;
+use crate::core::DangerLevel;
+use crate::ui::palette;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier},
+};
+use std::f32::consts::TAU;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::OnceLock;
+use std::time::Duration;
+
+/// Durée d'une transition
+pub const TRANSITION_DURATION: Duration = Duration::from_millis(180);
+/// Intervalle entre deux images pendant une transition
+pub const FRAME_INTERVAL: Duration = Duration::from_millis(30);
+
+/// Fraction de la largeur parcourue par un glissement
+const SLIDE_FRACTION: f32 = 0.2;
+
+/// Intervalle entre deux images tant qu'une teinte de danger pulse
+pub const DANGER_FRAME_INTERVAL: Duration = Duration::from_millis(100);
+/// Période de la pulsation aux niveaux `Warning` et `Critical`
+const WARNING_PULSE: Duration = Duration::from_millis(1200);
+const CRITICAL_PULSE: Duration = Duration::from_millis(500);
+
+/// 0 ou 1 ; `UNSET` tant que la configuration n'a pas été lue
+static REDUCE_MOTION: AtomicU8 = AtomicU8::new(UNSET);
+const UNSET: u8 = u8::MAX;
+
+/// Indique si le joueur a désactivé les animations de l'interface
+pub fn reduce_motion() -> bool {
+    if REDUCE_MOTION.load(Ordering::Relaxed) == UNSET {
+        super::style::watch_config();
+    }
+    REDUCE_MOTION.load(Ordering::Relaxed) == 1
+}
+
+pub fn set_reduce_motion(reduce: bool) {
+    REDUCE_MOTION.store(u8::from(reduce), Ordering::Relaxed);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Effect {
+    /// Le contenu arrive par la droite (entrée dans un sous-menu)
+    SlideForward,
+    /// Le contenu arrive par la gauche (retour au menu précédent)
+    SlideBack,
+    /// Le contenu sort de la pénombre (ouverture d'un popup)
+    FadeIn,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Transition {
+    effect: Effect,
+    started: Instant,
+}
+
+impl Transition {
+    /// Démarre une transition, sauf si les animations sont désactivées
+    pub fn start(effect: Effect) -> Option<Self> {
+        (!reduce_motion()).then_some(Self {
+            effect,
+            started: Instant::now(),
+        })
+    }
+
+    /// Avancement de 0 à 1
+    pub fn progress(&self) -> f32 {
+        (self.started.elapsed().as_secs_f32() / TRANSITION_DURATION.as_secs_f32()).min(1.0)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.started.elapsed() >= TRANSITION_DURATION
+    }
+
+    /// Applique l'image courante de la transition à `area`, déjà dessinée
+    pub fn apply(&self, buffer: &mut Buffer, area: Rect) {
+        render_frame(self.effect, self.progress(), buffer, area);
+    }
+}
+
+/// Secousse d'un terrain de jeu : le jeu décale son terrain de
+/// [`Shake::offset`] colonnes le temps de la secousse
+#[derive(Debug, Clone, Copy)]
+pub struct Shake {
+    started: Instant,
+    duration: Duration,
+}
+
+impl Shake {
+    /// Démarre une secousse, sauf si les animations sont désactivées
+    pub fn start(duration: Duration) -> Option<Self> {
+        (!reduce_motion()).then_some(Self {
+            started: Instant::now(),
+            duration,
+        })
+    }
+
+    /// Décalage horizontal de l'image courante
+    pub fn offset(&self) -> i16 {
+        shake_offset(self.started.elapsed(), self.duration)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.started.elapsed() >= self.duration
+    }
+}
+
+/// Couleur `base` d'un élément du terrain (cadre, en-tête) tirée vers le
+/// rouge selon `danger`, en pulsant d'autant plus vite que le danger est
+/// grand. Avec Reduce Motion, la teinte reste à son maximum sans pulser
+pub fn danger_color(base: Color, danger: DangerLevel) -> Color {
+    static START: OnceLock<Instant> = OnceLock::new();
+    let elapsed = START.get_or_init(Instant::now).elapsed();
+    let steady = reduce_motion();
+    palette::blend(base, Color::Red, danger_tint(danger, elapsed, steady))
+}
+
+/// Part de rouge dans la teinte de danger, après `elapsed` de pulsation
+fn danger_tint(danger: DangerLevel, elapsed: Duration, steady: bool) -> f32 {
+    let (strength, period) = match danger {
+        DangerLevel::Calm => return 0.0,
+        DangerLevel::Warning => (0.5, WARNING_PULSE),
+        DangerLevel::Critical => (0.9, CRITICAL_PULSE),
+    };
+    if steady {
+        return strength;
+    }
+    // Entre un tiers et le maximum, sans jamais revenir à la couleur de base
+    let phase = (elapsed.as_secs_f32() / period.as_secs_f32()).fract();
+    let pulse = 0.5 - 0.5 * (phase * TAU).cos();
+    strength * (1.0 + 2.0 * pulse) / 3.0
+}
+
+/// Alterne à gauche et à droite à chaque image, de moins en moins fort
+fn shake_offset(elapsed: Duration, duration: Duration) -> i16 {
+    if elapsed >= duration {
+        return 0;
+    }
+    let frame = elapsed.as_millis() / FRAME_INTERVAL.as_millis();
+    let amplitude = if elapsed < duration / 2 { 2 } else { 1 };
+    if frame.is_multiple_of(2) {
+        amplitude
+    } else {
+        -amplitude
+    }
+}
+
+/// Image de `effect` à l'avancement `progress`
+fn render_frame(effect: Effect, progress: f32, buffer: &mut Buffer, area: Rect) {
+    let area = area.intersection(buffer.area);
+    // Ralentit en fin de course
+    let eased = 1.0 - (1.0 - progress).powi(3);
+    match effect {
+        Effect::SlideForward | Effect::SlideBack => {
+            let offset = ((1.0 - eased) * SLIDE_FRACTION * f32::from(area.width)).round() as u16;
+            if offset > 0 {
+                slide(buffer, area, offset, effect == Effect::SlideForward);
+            }
+        }
+        Effect::FadeIn => {
+            if eased < 1.0 {
+                fade(buffer, area, eased);
+            }
+        }
+    }
+}
+
+/// Décale le contenu de `area` de `offset` colonnes, vers la droite si
+/// `right`, et vide les colonnes libérées
+fn slide(buffer: &mut Buffer, area: Rect, offset: u16, right: bool) {
+    let offset = offset.min(area.width);
+    for y in area.top()..area.bottom() {
+        let row: Vec<_> = (area.left()..area.right())
+            .map(|x| buffer[(x, y)].clone())
+            .collect();
+        for (i, x) in (area.left()..area.right()).enumerate() {
+            let source = if right {
+                i.checked_sub(usize::from(offset))
+            } else {
+                Some(i + usize::from(offset)).filter(|&s| s < row.len())
+            };
+            match source {
+                Some(source) => buffer[(x, y)] = row[source].clone(),
+                None => {
+                    let style = buffer[(x, y)].style();
+                    buffer[(x, y)].reset();
+                    buffer[(x, y)].set_style(style.fg(Color::Reset));
+                }
+            }
+        }
+    }
+}
+
+/// Assombrit le texte de `area` : gris au début, atténué ensuite
+fn fade(buffer: &mut Buffer, area: Rect, eased: f32) {
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            let cell = &mut buffer[(x, y)];
+            if eased < 0.4 {
+                cell.set_fg(Color::DarkGray);
+            }
+            cell.modifier.insert(Modifier::DIM);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slide_moves_the_content_and_ends_in_place() {
+        let area = Rect::new(0, 0, 10, 1);
+        let mut buffer = Buffer::with_lines(["abcdefghij"]);
+        render_frame(Effect::SlideForward, 0.0, &mut buffer, area);
+        assert_eq!(buffer, Buffer::with_lines(["  abcdefgh"]));
+
+        let mut buffer = Buffer::with_lines(["abcdefghij"]);
+        render_frame(Effect::SlideBack, 0.0, &mut buffer, area);
+        assert_eq!(buffer, Buffer::with_lines(["cdefghij  "]));
+
+        let mut buffer = Buffer::with_lines(["abcdefghij"]);
+        render_frame(Effect::SlideForward, 1.0, &mut buffer, area);
+        assert_eq!(buffer, Buffer::with_lines(["abcdefghij"]));
+    }
+
+    #[test]
+    fn shakes_alternate_calm_down_and_stop() {
+        let duration = Duration::from_millis(300);
+        assert_eq!(shake_offset(Duration::ZERO, duration), 2);
+        assert_eq!(shake_offset(FRAME_INTERVAL, duration), -2);
+        assert_eq!(shake_offset(Duration::from_millis(240), duration), 1);
+        assert_eq!(shake_offset(duration, duration), 0);
+    }
+
+    #[test]
+    fn danger_tint_pulses_faster_and_stronger_when_critical() {
+        let warning = |ms| danger_tint(DangerLevel::Warning, Duration::from_millis(ms), false);
+        let critical = |ms| danger_tint(DangerLevel::Critical, Duration::from_millis(ms), false);
+        assert_eq!(
+            danger_tint(DangerLevel::Calm, Duration::from_millis(600), false),
+            0.0
+        );
+        // Creux au début de chaque période, maximum à mi-période
+        assert!((warning(0) - 0.5 / 3.0).abs() < 1e-4);
+        assert!((warning(600) - 0.5).abs() < 1e-4);
+        assert!((critical(250) - 0.9).abs() < 1e-4);
+        assert!((critical(500) - 0.3).abs() < 1e-4);
+        // Reduce Motion : teinte fixe, à son maximum
+        assert_eq!(
+            danger_tint(DangerLevel::Critical, Duration::from_millis(500), true),
+            0.9
+        );
+    }
+}
@@ -0,0 +1,82 @@
+//! Écrans ratatui traduits en séquences ANSI, pour les sorties qui ne
+//! passent pas par un backend : le terminal du navigateur (feature `web`) et
+//! les enregistrements de parties.
+
+use ratatui::{
+    buffer::{Buffer, Cell},
+    style::{Color, Modifier},
+};
+use std::fmt::Write;
+use unicode_width::UnicodeWidthStr;
+
+/// Séquences ANSI qui transforment l'écran `previous` en `next`
+pub fn encode_diff(previous: &Buffer, next: &Buffer, out: &mut String) {
+    // Position du curseur et style courants du navigateur
+    let mut cursor: Option<(u16, u16)> = None;
+    let mut style: Option<(Color, Color, Modifier)> = None;
+
+    for (x, y, cell) in previous.diff(next) {
+        if cursor != Some((x, y)) {
+            let _ = write!(out, "\x1b[{};{}H", y + 1, x + 1);
+        }
+        let cell_style = (cell.fg, cell.bg, cell.modifier);
+        if style != Some(cell_style) {
+            push_style(cell, out);
+            style = Some(cell_style);
+        }
+        out.push_str(cell.symbol());
+        let width = cell.symbol().width().max(1) as u16;
+        cursor = Some((x + width, y));
+    }
+
+    if style.is_some() {
+        out.push_str("\x1b[0m");
+    }
+}
+
+/// Séquence SGR complète du style d'une cellule
+fn push_style(cell: &Cell, out: &mut String) {
+    out.push_str("\x1b[0");
+    for (modifier, code) in [
+        (Modifier::BOLD, 1),
+        (Modifier::DIM, 2),
+        (Modifier::ITALIC, 3),
+        (Modifier::UNDERLINED, 4),
+        (Modifier::SLOW_BLINK, 5),
+        (Modifier::REVERSED, 7),
+        (Modifier::HIDDEN, 8),
+        (Modifier::CROSSED_OUT, 9),
+    ] {
+        if cell.modifier.contains(modifier) {
+            let _ = write!(out, ";{code}");
+        }
+    }
+    push_color(cell.fg, false, out);
+    push_color(cell.bg, true, out);
+    out.push('m');
+}
+
+fn push_color(color: Color, background: bool, out: &mut String) {
+    let base = if background { 40 } else { 30 };
+    let _ = match color {
+        Color::Reset => write!(out, ";{}", base + 9),
+        Color::Black => write!(out, ";{base}"),
+        Color::Red => write!(out, ";{}", base + 1),
+        Color::Green => write!(out, ";{}", base + 2),
+        Color::Yellow => write!(out, ";{}", base + 3),
+        Color::Blue => write!(out, ";{}", base + 4),
+        Color::Magenta => write!(out, ";{}", base + 5),
+        Color::Cyan => write!(out, ";{}", base + 6),
+        Color::Gray => write!(out, ";{}", base + 7),
+        Color::DarkGray => write!(out, ";{}", base + 60),
+        Color::LightRed => write!(out, ";{}", base + 61),
+        Color::LightGreen => write!(out, ";{}", base + 62),
+        Color::LightYellow => write!(out, ";{}", base + 63),
+        Color::LightBlue => write!(out, ";{}", base + 64),
+        Color::LightMagenta => write!(out, ";{}", base + 65),
+        Color::LightCyan => write!(out, ";{}", base + 66),
+        Color::White => write!(out, ";{}", base + 67),
+        Color::Indexed(index) => write!(out, ";{};5;{index}", base + 8),
+        Color::Rgb(r, g, b) => write!(out, ";{};2;{r};{g};{b}", base + 8),
+    };
+}
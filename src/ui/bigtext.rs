@@ -0,0 +1,199 @@
+//! Grandes lettres en blocs, à la manière de figlet : titre du menu, écran
+//! d'accueil et bandeaux de fin de partie.
+//!
+//! Chaque caractère est dessiné sur une grille de 5 pixels de haut. La police
+//! [`Font::Block`] affiche un pixel par case, [`Font::Compact`] en superpose
+//! deux par case avec des demi-blocs pour tenir sur 3 lignes. En symboles
+//! ASCII, les blocs deviennent des `#`.
+
+use crate::config::GlyphSet;
+use crate::ui::glyphs;
+use ratatui::{style::Style, text::Line};
+
+/// Hauteur d'un caractère en pixels
+const PIXEL_ROWS: usize = 5;
+
+/// Pixels de chaque caractère, ligne par ligne ; les minuscules utilisent les
+/// majuscules et un caractère absent est remplacé par `?`
+const GLYPHS: [(char, [&str; PIXEL_ROWS]); 47] = [
+    ('A', [" # ", "# #", "###", "# #", "# #"]),
+    ('B', ["## ", "# #", "## ", "# #", "## "]),
+    ('C', [" ##", "#  ", "#  ", "#  ", " ##"]),
+    ('D', ["## ", "# #", "# #", "# #", "## "]),
+    ('E', ["###", "#  ", "## ", "#  ", "###"]),
+    ('F', ["###", "#  ", "## ", "#  ", "#  "]),
+    ('G', [" ##", "#  ", "# #", "# #", " ##"]),
+    ('H', ["# #", "# #", "###", "# #", "# #"]),
+    ('I', ["###", " # ", " # ", " # ", "###"]),
+    ('J', ["  #", "  #", "  #", "# #", " # "]),
+    ('K', ["# #", "# #", "## ", "# #", "# #"]),
+    ('L', ["#  ", "#  ", "#  ", "#  ", "###"]),
+    ('M', ["# #", "###", "# #", "# #", "# #"]),
+    ('N', ["## ", "# #", "# #", "# #", "# #"]),
+    ('O', ["###", "# #", "# #", "# #", "###"]),
+    ('P', ["## ", "# #", "## ", "#  ", "#  "]),
+    ('Q', [" # ", "# #", "# #", "## ", " ##"]),
+    ('R', ["## ", "# #", "## ", "# #", "# #"]),
+    ('S', [" ##", "#  ", " # ", "  #", "## "]),
+    ('T', ["###", " # ", " # ", " # ", " # "]),
+    ('U', ["# #", "# #", "# #", "# #", "###"]),
+    ('V', ["# #", "# #", "# #", "# #", " # "]),
+    ('W', ["# #", "# #", "# #", "###", "# #"]),
+    ('X', ["# #", "# #", " # ", "# #", "# #"]),
+    ('Y', ["# #", "# #", " # ", " # ", " # "]),
+    ('Z', ["###", "  #", " # ", "#  ", "###"]),
+    ('0', ["###", "# #", "# #", "# #", "###"]),
+    ('1', [" # ", "## ", " # ", " # ", "###"]),
+    ('2', ["## ", "  #", " # ", "#  ", "###"]),
+    ('3', ["## ", "  #", " # ", "  #", "## "]),
+    ('4', ["# #", "# #", "###", "  #", "  #"]),
+    ('5', ["###", "#  ", "## ", "  #", "## "]),
+    ('6', [" ##", "#  ", "###", "# #", "###"]),
+    ('7', ["###", "  #", " # ", " # ", " # "]),
+    ('8', ["###", "# #", "###", "# #", "###"]),
+    ('9', ["###", "# #", "###", "  #", "## "]),
+    (' ', [" ", " ", " ", " ", " "]),
+    ('!', ["#", "#", "#", " ", "#"]),
+    ('?', ["## ", "  #", " # ", "   ", " # "]),
+    ('.', [" ", " ", " ", " ", "#"]),
+    (',', ["  ", "  ", "  ", " #", "# "]),
+    (':', [" ", "#", " ", "#", " "]),
+    ('\'', ["#", "#", " ", " ", " "]),
+    ('-', ["   ", "   ", "###", "   ", "   "]),
+    ('+', ["   ", " # ", "###", " # ", "   "]),
+    ('/', ["  #", "  #", " # ", "#  ", "#  "]),
+    ('%', ["# #", "  #", " # ", "#  ", "# #"]),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Font {
+    /// Un pixel par case, sur 5 lignes
+    Block,
+    /// Deux pixels superposés par case, sur 3 lignes
+    Compact,
+}
+
+impl Font {
+    /// Nombre de lignes de terminal d'un texte dans cette police
+    pub fn height(self) -> u16 {
+        match self {
+            Font::Block => PIXEL_ROWS as u16,
+            Font::Compact => PIXEL_ROWS.div_ceil(2) as u16,
+        }
+    }
+}
+
+fn glyph(c: char) -> &'static [&'static str; PIXEL_ROWS] {
+    let c = c.to_ascii_uppercase();
+    GLYPHS
+        .iter()
+        .find(|(known, _)| *known == c)
+        .or_else(|| GLYPHS.iter().find(|(known, _)| *known == '?'))
+        .map(|(_, rows)| rows)
+        .expect("la police contient '?'")
+}
+
+/// Lignes de pixels de `text`, les caractères séparés d'une colonne vide
+fn pixels(text: &str) -> [String; PIXEL_ROWS] {
+    let mut rows: [String; PIXEL_ROWS] = Default::default();
+    for (i, c) in text.chars().enumerate() {
+        for (row, pixels) in rows.iter_mut().zip(glyph(c)) {
+            if i > 0 {
+                row.push(' ');
+            }
+            row.push_str(pixels);
+        }
+    }
+    rows
+}
+
+/// Largeur de `text` en colonnes, la même dans toutes les polices
+pub fn width(text: &str) -> u16 {
+    pixels(text)[0].chars().count() as u16
+}
+
+/// Lignes de `text` dans la police `font`, de [`Font::height`] lignes
+pub fn render(text: &str, font: Font) -> Vec<String> {
+    render_in(text, font, glyphs::glyph_set() == GlyphSet::Ascii)
+}
+
+fn render_in(text: &str, font: Font, ascii: bool) -> Vec<String> {
+    let rows = pixels(text);
+    match font {
+        Font::Block => {
+            let full = if ascii { "#" } else { "█" };
+            rows.iter().map(|row| row.replace('#', full)).collect()
+        }
+        Font::Compact => rows
+            .chunks(2)
+            .map(|pair| {
+                let bottom: Vec<char> = pair.get(1).map_or(Vec::new(), |row| row.chars().collect());
+                pair[0]
+                    .chars()
+                    .enumerate()
+                    .map(
+                        |(x, top)| match (top == '#', bottom.get(x) == Some(&'#'), ascii) {
+                            (true, true, false) => '█',
+                            (true, false, false) => '▀',
+                            (false, true, false) => '▄',
+                            (true, true, true) => '#',
+                            (true, false, true) => '"',
+                            (false, true, true) => '_',
+                            (false, false, _) => ' ',
+                        },
+                    )
+                    .collect()
+            })
+            .collect(),
+    }
+}
+
+/// `text` en grandes lettres de style `style`, ou `None` s'il dépasse
+/// `max_width` colonnes : l'appelant garde alors son titre sur une ligne
+pub fn banner(text: &str, font: Font, style: Style, max_width: u16) -> Option<Vec<Line<'static>>> {
+    (width(text) <= max_width).then(|| {
+        render(text, font)
+            .into_iter()
+            .map(|row| Line::styled(row, style))
+            .collect()
+    })
+}
+
+/// Titre d'un bandeau de fin de partie : `text` en [`Font::Compact`] s'il
+/// tient dans `max_width` colonnes, sinon `fallback` sur une ligne
+pub fn headline(
+    text: &str,
+    style: Style,
+    max_width: u16,
+    fallback: Line<'static>,
+) -> Vec<Line<'static>> {
+    banner(text, Font::Compact, style, max_width).unwrap_or_else(|| vec![fallback])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_glyph_is_a_full_rectangle() {
+        for (c, rows) in GLYPHS {
+            let width = rows[0].chars().count();
+            assert!(
+                rows.iter().all(|row| row.chars().count() == width),
+                "{c:?} has rows of different widths"
+            );
+        }
+    }
+
+    #[test]
+    fn compact_letters_stack_two_pixels_per_cell() {
+        assert_eq!(
+            render_in("HI", Font::Compact, false),
+            ["█ █ ▀█▀", "█▀█  █ ", "▀ ▀ ▀▀▀"]
+        );
+        assert_eq!(render_in("HI", Font::Compact, true)[1], "#\"#  # ");
+        assert_eq!(render_in("hi", Font::Block, false).len(), 5);
+        assert_eq!(width("HI!"), 9);
+        assert!(banner("GAME OVER", Font::Compact, Style::new(), 20).is_none());
+    }
+}
@@ -0,0 +1,97 @@
+//! Bandeau de fête affiché par-dessus la partie quand le joueur atteint son
+//! objectif de score, quelques secondes, sans interrompre le jeu.
+
+use crate::core::clock::Instant;
+use crate::ui::{animation, glyphs, style};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style, Stylize},
+    text::Line,
+    widgets::{Block, Clear, Paragraph},
+    Frame,
+};
+use std::time::Duration;
+
+/// Durée d'affichage du bandeau
+const DURATION: Duration = Duration::from_secs(3);
+/// Couleurs du cadre, qui clignote tant que les animations sont permises
+const BORDER_COLORS: [Color; 3] = [
+    Color::Rgb(255, 215, 0),
+    Color::Rgb(255, 140, 0),
+    Color::Rgb(255, 255, 255),
+];
+/// Durée de chaque couleur du cadre
+const BLINK: Duration = Duration::from_millis(200);
+
+pub struct Celebration {
+    /// Message et instant où il a été affiché
+    shown: Option<(String, Instant)>,
+}
+
+impl Celebration {
+    pub fn new() -> Self {
+        Self { shown: None }
+    }
+
+    pub fn show(&mut self, message: String) {
+        self.shown = Some((message, Instant::now()));
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.shown
+            .as_ref()
+            .is_some_and(|(_, shown_at)| shown_at.elapsed() < DURATION)
+    }
+
+    /// Dessine le bandeau en haut au centre tant qu'il n'a pas expiré
+    pub fn draw(&mut self, frame: &mut Frame) {
+        if !self.is_visible() {
+            self.shown = None;
+            return;
+        }
+        let Some((message, shown_at)) = &self.shown else {
+            return;
+        };
+
+        let border = if animation::reduce_motion() {
+            BORDER_COLORS[0]
+        } else {
+            let step = shown_at.elapsed().as_millis() / BLINK.as_millis();
+            BORDER_COLORS[step as usize % BORDER_COLORS.len()]
+        };
+        let line = Line::from(vec![
+            format!("{} ", glyphs::icon("🎉")).into(),
+            glyphs::text(message)
+                .into_owned()
+                .fg(Color::Rgb(255, 215, 0))
+                .bold(),
+            format!(" {}", glyphs::icon("🎉")).into(),
+        ]);
+
+        let area = frame.area();
+        let width = (line.width() as u16 + 4).min(area.width);
+        let height = 3.min(area.height);
+        let banner = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + 1.min(area.height - height),
+            width,
+            height,
+        };
+
+        frame.render_widget(Clear, banner);
+        frame.render_widget(
+            Paragraph::new(line).centered().block(
+                Block::bordered()
+                    .border_style(Style::new().fg(border).bold())
+                    .style(Style::default().bg(style::surface())),
+            ),
+            banner,
+        );
+    }
+}
+
+impl Default for Celebration {
+    fn default() -> Self {
+        Self::new()
+    }
+}
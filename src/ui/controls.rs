@@ -0,0 +1,15 @@
+//! Raccourcis communs aux jeux et au menu, affichés dans l'onglet Controls
+//! des Settings et dans le menu de pause.
+
+pub const CONTROL_HINTS: [(&str, &str); 10] = [
+    ("↑↓←→", "Move / select"),
+    ("Space", "Main action (drop, launch, reveal…)"),
+    ("P", "Pause"),
+    ("R", "Restart"),
+    ("M", "Toggle music"),
+    ("N", "Toggle sound effects"),
+    ("Q", "Quit the game"),
+    ("Esc", "Pause menu in games, back in menus"),
+    ("/", "Search games"),
+    ("Tab / Shift+Tab", "Switch settings tab"),
+];
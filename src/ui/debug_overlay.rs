@@ -0,0 +1,139 @@
+//! Relevé de cadence affiché avec F12 pendant une partie : temps mis à
+//! dessiner et envoyer chaque image au terminal, images par seconde et réglage
+//! de Frame Skip. Sur une connexion SSH lente, l'envoi bloque tant que le
+//! terminal n'a pas suivi : ce temps donne la latence de l'affichage.
+//!
+//! Comme le voyant d'enregistrement, le relevé est dessiné après la capture
+//! de l'image et n'apparaît pas dans les enregistrements.
+
+use crate::config::FrameSkip;
+use crate::core::clock::Instant;
+use crate::ui::{glyphs, style};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style, Stylize},
+    text::Line,
+    widgets::{Block, Clear, Paragraph},
+    Frame,
+};
+use std::collections::VecDeque;
+use std::time::Duration;
+
+/// Période sur laquelle la cadence et les temps d'envoi sont mesurés
+const WINDOW: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Default)]
+pub struct DebugOverlay {
+    visible: bool,
+    /// Images dessinées pendant la dernière période : instant de fin et
+    /// durée du dessin et de l'envoi
+    frames: VecDeque<(Instant, Duration)>,
+}
+
+impl DebugOverlay {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn toggle(&mut self) {
+        self.visible = !self.visible;
+    }
+
+    /// Note une image envoyée au terminal en `took`, terminée à `at`
+    pub fn record_frame(&mut self, at: Instant, took: Duration) {
+        self.frames.push_back((at, took));
+        while self
+            .frames
+            .front()
+            .is_some_and(|&(shown_at, _)| at.duration_since(shown_at) >= WINDOW)
+        {
+            self.frames.pop_front();
+        }
+    }
+
+    /// Images envoyées pendant la dernière période
+    fn fps(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Temps d'envoi moyen et le plus long de la dernière période
+    fn latency(&self) -> (Duration, Duration) {
+        let total: Duration = self.frames.iter().map(|&(_, took)| took).sum();
+        let worst = self.frames.iter().map(|&(_, took)| took).max();
+        let average = total / self.frames.len().max(1) as u32;
+        (average, worst.unwrap_or_default())
+    }
+
+    /// Dessine le relevé en bas à gauche s'il a été demandé
+    pub fn draw(&self, frame: &mut Frame, frame_skip: FrameSkip) {
+        if !self.visible {
+            return;
+        }
+
+        let (average, worst) = self.latency();
+        let millis = |took: Duration| took.as_secs_f64() * 1000.0;
+        let line = Line::from(vec![
+            format!("{} ", glyphs::icon("⏱")).into(),
+            format!("{:.1} ms", millis(average)).fg(latency_color(average)),
+            format!(" (max {:.1})", millis(worst)).dark_gray(),
+            format!("  {} fps", self.fps()).fg(style::text()),
+            format!("  skip {}", frame_skip.name()).dark_gray(),
+        ]);
+
+        let area = frame.area();
+        let width = (line.width() as u16 + 4).min(area.width);
+        let height = 3.min(area.height);
+        let overlay = Rect {
+            x: area.x,
+            y: area.y + area.height - height,
+            width,
+            height,
+        };
+
+        frame.render_widget(Clear, overlay);
+        frame.render_widget(
+            Paragraph::new(line).block(
+                Block::bordered()
+                    .title(" F12 ")
+                    .border_style(Style::new().fg(Color::DarkGray))
+                    .style(Style::default().bg(style::surface())),
+            ),
+            overlay,
+        );
+    }
+}
+
+/// Vert tant que l'image part dans la durée d'un tick rapide, rouge quand le
+/// terminal ne suit plus
+fn latency_color(took: Duration) -> Color {
+    match took.as_millis() {
+        0..=15 => Color::Green,
+        16..=50 => Color::Yellow,
+        _ => Color::Red,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_the_last_second_of_frames_is_measured() {
+        let start = Instant::now();
+        let mut overlay = DebugOverlay::new();
+        for i in 0..30u64 {
+            let took = Duration::from_millis(if i == 25 { 40 } else { 10 });
+            overlay.record_frame(start + Duration::from_millis(i * 100), took);
+        }
+
+        // Images de 2,0 s à 2,9 s
+        assert_eq!(overlay.fps(), 10);
+        let (average, worst) = overlay.latency();
+        assert_eq!(average, Duration::from_millis(13));
+        assert_eq!(worst, Duration::from_millis(40));
+        assert_eq!(
+            DebugOverlay::new().latency(),
+            (Duration::ZERO, Duration::ZERO)
+        );
+    }
+}
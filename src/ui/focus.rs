@@ -0,0 +1,38 @@
+//! Bandeau de pause affiché quand le terminal perd le focus.
+
+use crate::ui::{glyphs, style};
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Style, Stylize},
+    text::Line,
+    widgets::{Block, Clear, Paragraph},
+    Frame,
+};
+
+/// Dessine le bandeau "PAUSED" centré par-dessus le jeu
+pub fn draw_focus_lost(frame: &mut Frame) {
+    let area = frame.area();
+    let width = 36.min(area.width);
+    let height = 4.min(area.height);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+
+    let text = vec![
+        Line::from(format!("{} PAUSED", glyphs::icon("⏸")).yellow().bold()),
+        Line::from("Terminal lost focus".gray()),
+    ];
+
+    frame.render_widget(Clear, popup);
+    frame.render_widget(
+        Paragraph::new(text).alignment(Alignment::Center).block(
+            Block::bordered()
+                .border_style(Style::new().yellow())
+                .style(Style::default().bg(style::surface())),
+        ),
+        popup,
+    );
+}
@@ -0,0 +1,210 @@
+//! Symboles des en-têtes et des menus.
+//!
+//! Les emoji s'affichent sur deux colonnes, ou en carrés vides avec certaines
+//! polices, ce qui décale les titres centrés. Le code garde les emoji comme
+//! référence et les fait passer par [`icon`] ou [`text`], qui les remplacent
+//! selon le jeu de symboles choisi dans les Settings.
+
+use crate::config::GlyphSet;
+use std::borrow::Cow;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Jeu de symboles résolu ; `UNSET` tant que la configuration n'a pas été lue
+static RESOLVED: AtomicU8 = AtomicU8::new(UNSET);
+const UNSET: u8 = u8::MAX;
+
+/// Chaque emoji avec son remplaçant Unicode (une colonne, présent dans les
+/// polices de terminal courantes) et son remplaçant ASCII
+const GLYPHS: [(&str, &str, &str); 73] = [
+    // Jeux
+    ("🐍", "∿", "S"),
+    ("🏁", "⚑", ">"),
+    ("🧩", "▦", "T"),
+    ("⛏", "⚒", "D"),
+    ("🏓", "●", "o"),
+    ("🔢", "№", "#"),
+    ("🧠", "✱", "?"),
+    ("💣", "✹", "*"),
+    ("🧱", "▤", "="),
+    ("🧬", "✣", "+"),
+    ("🎓", "✎", "L"),
+    ("🥁", "♩", "r"),
+    ("🎮", "►", ">"),
+    ("👥", "☻", "2"),
+    ("🎯", "◎", "x"),
+    ("🪑", "⇄", "H"),
+    // États des jeux
+    ("🤖", "⚙", "@"),
+    ("⏸️", "‖", "|"),
+    ("⏸", "‖", "|"),
+    ("⏱️", "◷", "~"),
+    ("⏱", "◷", "~"),
+    ("♾", "∞", "oo"),
+    ("🔊", "♪", "s"),
+    ("🔇", "×", "-"),
+    ("🎵", "♫", "m"),
+    ("⚡", "»", ">>"),
+    ("🐌", "›", ">"),
+    ("🎉", "★", "*"),
+    ("⏳", "◔", "~"),
+    // Menus
+    ("🏆", "♛", "#"),
+    ("📊", "▥", "#"),
+    ("⚙️", "⚙", "*"),
+    ("🚪", "←", "<"),
+    ("🔍", "⌕", "/"),
+    ("🎨", "◐", "%"),
+    ("🌈", "◑", "c"),
+    ("💫", "∴", "~"),
+    ("⌨️", "⌨", "k"),
+    ("💾", "▣", "d"),
+    ("🏷️", "¶", "t"),
+    ("📈", "↗", "^"),
+    ("📅", "▦", "w"),
+    ("🪟", "▢", "o"),
+    ("🔣", "Ω", "&"),
+    ("🌀", "≈", "~"),
+    ("🚀", "»", ">"),
+    ("🕹️", "◘", "a"),
+    ("🌌", "░", ":"),
+    ("🕒", "◴", "h"),
+    ("📆", "▤", "d"),
+    ("🌐", "⊕", "z"),
+    ("ℹ️", "i", "i"),
+    ("👤", "☺", "@"),
+    ("❓", "?", "?"),
+    ("📄", "▯", "-"),
+    ("📂", "▭", "+"),
+    ("🧹", "⌫", "x"),
+    ("⚠️", "⚠", "!"),
+    ("♻️", "↺", "!"),
+    ("🎚️", "♪", "v"),
+    ("📢", "♪", "a"),
+    ("🎶", "♫", "m"),
+    ("🔉", "♪", "d"),
+    ("🎛️", "♬", "t"),
+    ("🎧", "♪", "e"),
+    ("📻", "♫", "b"),
+    ("🔌", "⌁", "p"),
+    ("🥇", "①", "1"),
+    ("🥈", "②", "2"),
+    ("🥉", "③", "3"),
+    ("🏅", "•", "-"),
+    ("▶️", "▶", ">"),
+    ("🎲", "⚄", "?"),
+];
+
+/// Jeu de symboles effectivement utilisé
+pub fn glyph_set() -> GlyphSet {
+    if RESOLVED.load(Ordering::Relaxed) == UNSET {
+        super::style::watch_config();
+    }
+    GlyphSet::ALL
+        .get(RESOLVED.load(Ordering::Relaxed) as usize)
+        .copied()
+        .unwrap_or(GlyphSet::Emoji)
+}
+
+/// Prend en compte un jeu de symboles modifié ; `Auto` est résolu ici
+pub fn apply_glyph_set(set: GlyphSet) {
+    let set = match set {
+        GlyphSet::Auto => detect(),
+        set => set,
+    };
+    let index = GlyphSet::ALL.iter().position(|&s| s == set).unwrap_or(0);
+    RESOLVED.store(index as u8, Ordering::Relaxed);
+}
+
+/// Devine ce que le terminal sait afficher : la console Linux n'a pas
+/// d'emoji, et une locale à l'encodage explicite autre que UTF-8 n'a que
+/// l'ASCII. La console Windows historique n'a pas d'emoji non plus. Sans
+/// information (locale absente, "C"), on garde les emoji.
+pub fn detect() -> GlyphSet {
+    let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+        .iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .find(|value| !value.is_empty());
+    let term = std::env::var("TERM").unwrap_or_default();
+    detect_from(locale.as_deref(), &term, crate::input::legacy_console())
+}
+
+fn detect_from(locale: Option<&str>, term: &str, legacy_console: bool) -> GlyphSet {
+    let utf8 = locale
+        .and_then(|locale| locale.split_once('.'))
+        .is_none_or(|(_, codeset)| {
+            let codeset = codeset.split('@').next().unwrap_or_default();
+            codeset.eq_ignore_ascii_case("utf-8") || codeset.eq_ignore_ascii_case("utf8")
+        });
+    if !utf8 {
+        GlyphSet::Ascii
+    } else if term == "linux" || legacy_console {
+        GlyphSet::Unicode
+    } else {
+        GlyphSet::Emoji
+    }
+}
+
+fn replacement(entry: &(&'static str, &'static str, &'static str), set: GlyphSet) -> &'static str {
+    match set {
+        GlyphSet::Unicode => entry.1,
+        GlyphSet::Ascii => entry.2,
+        GlyphSet::Auto | GlyphSet::Emoji => entry.0,
+    }
+}
+
+/// Symbole à afficher à la place de l'emoji `emoji`
+pub fn icon(emoji: &'static str) -> &'static str {
+    let set = glyph_set();
+    GLYPHS
+        .iter()
+        .find(|entry| entry.0 == emoji)
+        .map_or(emoji, |entry| replacement(entry, set))
+}
+
+/// `text` avec ses emoji remplacés selon le jeu de symboles
+pub fn text(text: &str) -> Cow<'_, str> {
+    let set = glyph_set();
+    if set == GlyphSet::Emoji || text.is_ascii() {
+        return Cow::Borrowed(text);
+    }
+    let mut result = text.to_string();
+    // Les variantes avec sélecteur d'emoji (U+FE0F) passent avant leur base
+    for entry in &GLYPHS {
+        if result.contains(entry.0) {
+            result = result.replace(entry.0, replacement(entry, set));
+        }
+    }
+    Cow::Owned(result.replace('\u{FE0F}', ""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detection_follows_locale_and_terminal() {
+        assert_eq!(detect_from(None, "xterm-256color", false), GlyphSet::Emoji);
+        assert_eq!(detect_from(Some("C"), "xterm", false), GlyphSet::Emoji);
+        assert_eq!(
+            detect_from(Some("fr_FR.UTF-8"), "linux", false),
+            GlyphSet::Unicode
+        );
+        assert_eq!(
+            detect_from(Some("en_US.utf8@euro"), "xterm", false),
+            GlyphSet::Emoji
+        );
+        assert_eq!(
+            detect_from(Some("fr_FR.ISO-8859-15"), "xterm", false),
+            GlyphSet::Ascii
+        );
+        assert_eq!(detect_from(None, "", true), GlyphSet::Unicode);
+    }
+
+    #[test]
+    fn unicode_replacements_are_single_characters() {
+        for (emoji, unicode, ascii) in GLYPHS {
+            assert_eq!(unicode.chars().count(), 1, "{emoji}");
+            assert!(ascii.is_ascii() && !ascii.is_empty(), "{emoji}");
+        }
+    }
+}
@@ -0,0 +1,148 @@
+//! Confirmation par maintien de touche, pour les actions qui font perdre la
+//! partie en cours (R pour recommencer).
+//!
+//! Les terminaux n'envoient en général pas le relâchement des touches : une
+//! touche tenue se reconnaît à ses répétitions automatiques, suivies par
+//! [`HeldKey`]. Le maintien ne confirme qu'une fois les répétitions lancées
+//! à leur cadence : deux appuis rapprochés ne suffisent pas.
+
+use crate::config::GlyphSet;
+use crate::core::clock::Instant;
+use crate::core::input::HeldKey;
+use crate::ui::{glyphs, style};
+use ratatui::{
+    layout::Rect,
+    style::{Style, Stylize},
+    text::Line,
+    widgets::{Block, Clear, Paragraph},
+    Frame,
+};
+use std::time::Duration;
+
+/// Durée de maintien nécessaire
+pub const HOLD_DURATION: Duration = Duration::from_millis(500);
+/// Étapes de l'anneau de progression
+const RING: [&str; 5] = ["○", "◔", "◑", "◕", "●"];
+
+pub struct HoldToConfirm {
+    key: HeldKey,
+}
+
+impl HoldToConfirm {
+    pub fn new() -> Self {
+        Self {
+            key: HeldKey::new(),
+        }
+    }
+
+    /// Pression (ou répétition) de la touche ; renvoie `true` quand elle est
+    /// tenue depuis assez longtemps, et remet alors le maintien à zéro
+    pub fn press(&mut self, now: Instant) -> bool {
+        self.key.press(now);
+        let confirmed = self.key.is_repeating(now)
+            && self
+                .key
+                .held_for(now)
+                .is_some_and(|held| held >= HOLD_DURATION);
+        if confirmed {
+            self.key.release();
+        }
+        confirmed
+    }
+
+    /// Touche relâchée ou autre touche pressée
+    pub fn cancel(&mut self) {
+        self.key.release();
+    }
+
+    pub fn is_holding(&self) -> bool {
+        self.key.is_held(Instant::now())
+    }
+
+    /// Avancement du maintien entre 0 et 1, `None` hors maintien
+    pub fn progress(&mut self, now: Instant) -> Option<f32> {
+        self.key
+            .held_for(now)
+            .map(|held| (held.as_secs_f32() / HOLD_DURATION.as_secs_f32()).min(1.0))
+    }
+
+    /// Dessine l'anneau de progression au centre de l'écran pendant le maintien
+    pub fn draw(&mut self, frame: &mut Frame, label: &str) {
+        let Some(progress) = self.progress(Instant::now()) else {
+            return;
+        };
+
+        let indicator = if glyphs::glyph_set() == GlyphSet::Ascii {
+            let filled = (progress * 4.0) as usize;
+            format!("[{}{}]", "#".repeat(filled), "-".repeat(4 - filled))
+        } else {
+            RING[(progress * (RING.len() - 1) as f32) as usize].to_string()
+        };
+        let line = Line::from(vec![
+            format!("{indicator} ").yellow().bold(),
+            label.fg(style::text()).bold(),
+        ]);
+
+        let area = frame.area();
+        let width = (line.width() as u16 + 4).min(area.width);
+        let height = 3.min(area.height);
+        let popup = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + (area.height - height) / 2,
+            width,
+            height,
+        };
+
+        frame.render_widget(Clear, popup);
+        frame.render_widget(
+            Paragraph::new(line).centered().block(
+                Block::bordered()
+                    .border_style(Style::new().yellow())
+                    .style(Style::default().bg(style::surface())),
+            ),
+            popup,
+        );
+    }
+}
+
+impl Default for HoldToConfirm {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn confirms_only_after_the_key_is_held_long_enough() {
+        let start = Instant::now();
+        let at = |ms| start + Duration::from_millis(ms);
+        let mut hold = HoldToConfirm::new();
+
+        // Première pression, puis répétitions automatiques toutes les 40 ms
+        assert!(!hold.press(at(0)));
+        assert!(!hold.press(at(400)));
+        assert!(!hold.press(at(440)));
+        assert!(!hold.press(at(480)));
+        assert!(hold.press(at(520)));
+
+        // Un appui isolé expire sans confirmer, le suivant repart de zéro
+        assert!(!hold.press(at(1000)));
+        assert_eq!(hold.progress(at(2000)), None);
+        assert!(!hold.press(at(2100)));
+        assert!(!hold.press(at(2400)));
+    }
+
+    #[test]
+    fn two_taps_never_confirm() {
+        let start = Instant::now();
+        let at = |ms| start + Duration::from_millis(ms);
+        let mut hold = HoldToConfirm::new();
+
+        for tap in [0, 600, 1200, 1800] {
+            assert!(!hold.press(at(tap)));
+        }
+    }
+}
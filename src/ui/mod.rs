@@ -0,0 +1,38 @@
+//! Composants d'interface réutilisables par le menu et les jeux.
+
+pub mod animation;
+pub mod ansi;
+pub mod bigtext;
+pub mod celebration;
+pub mod controls;
+pub mod debug_overlay;
+pub mod focus;
+pub mod glyphs;
+pub mod hold_confirm;
+pub mod numbers;
+pub mod palette;
+pub mod particles;
+pub mod pause_menu;
+pub mod preview;
+pub mod recording_badge;
+pub mod selectable_list;
+pub mod split_panel;
+pub mod split_screen;
+pub mod style;
+pub mod text_input;
+#[cfg(feature = "native")]
+pub mod title;
+pub mod trail;
+pub mod volume_osd;
+pub mod wallpaper;
+pub mod watchdog;
+
+pub use hold_confirm::HoldToConfirm;
+pub use pause_menu::{PauseAction, PauseMenu};
+pub use recording_badge::RecordingBadge;
+pub use selectable_list::SelectableList;
+pub use text_input::TextInput;
+#[cfg(feature = "native")]
+pub use title::TerminalTitle;
+pub use volume_osd::VolumeOsd;
+pub use watchdog::Watchdog;
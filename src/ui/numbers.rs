@@ -0,0 +1,154 @@
+//! Affichage des scores et des grands nombres.
+//!
+//! Tous les en-têtes, popups et classements passent par [`score`], qui
+//! groupe les milliers, ou par [`compact`] là où la place manque : les
+//! scores énormes de 2048 ou des parties sans fin y deviennent "1.2M". Les
+//! séparateurs suivent la locale numérique du système (`LC_NUMERIC`), comme
+//! les symboles suivent `LC_CTYPE` : "12,345" en anglais, "12 345" en
+//! français, "12.345" en allemand.
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Séparateurs des milliers et des décimales
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumberStyle {
+    /// 12,345.6
+    Comma,
+    /// 12 345,6 (espace insécable)
+    Space,
+    /// 12.345,6
+    Dot,
+}
+
+impl NumberStyle {
+    pub const ALL: [NumberStyle; 3] = [NumberStyle::Comma, NumberStyle::Space, NumberStyle::Dot];
+
+    fn separators(self) -> (char, char) {
+        match self {
+            NumberStyle::Comma => (',', '.'),
+            NumberStyle::Space => ('\u{a0}', ','),
+            NumberStyle::Dot => ('.', ','),
+        }
+    }
+}
+
+/// Style résolu ; `UNSET` tant que la locale n'a pas été lue
+static RESOLVED: AtomicU8 = AtomicU8::new(UNSET);
+const UNSET: u8 = u8::MAX;
+
+/// Style effectivement utilisé
+pub fn number_style() -> NumberStyle {
+    if RESOLVED.load(Ordering::Relaxed) == UNSET {
+        apply_number_style(detect());
+    }
+    NumberStyle::ALL
+        .get(RESOLVED.load(Ordering::Relaxed) as usize)
+        .copied()
+        .unwrap_or(NumberStyle::Comma)
+}
+
+/// Impose un style, à la place de celui de la locale
+pub fn apply_number_style(style: NumberStyle) {
+    let index = NumberStyle::ALL
+        .iter()
+        .position(|&s| s == style)
+        .unwrap_or(0);
+    RESOLVED.store(index as u8, Ordering::Relaxed);
+}
+
+/// Style de la locale numérique du système ; l'anglais sans information
+pub fn detect() -> NumberStyle {
+    let locale = ["LC_ALL", "LC_NUMERIC", "LANG"]
+        .iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .find(|value| !value.is_empty());
+    detect_from(locale.as_deref())
+}
+
+fn detect_from(locale: Option<&str>) -> NumberStyle {
+    let language = locale
+        .and_then(|locale| locale.split(['_', '.', '@']).next())
+        .unwrap_or_default();
+    match language {
+        "fr" | "ru" | "uk" | "pl" | "cs" | "sk" | "sv" | "fi" | "nb" | "nn" | "no" | "hu"
+        | "bg" | "et" | "lv" | "lt" => NumberStyle::Space,
+        "de" | "it" | "es" | "nl" | "pt" | "da" | "tr" | "id" | "el" | "ro" | "hr" | "sl"
+        | "sr" => NumberStyle::Dot,
+        _ => NumberStyle::Comma,
+    }
+}
+
+/// Score complet, milliers groupés : "12,345"
+pub fn score(value: impl Into<u64>) -> String {
+    group(value.into(), number_style())
+}
+
+/// Score court pour les colonnes étroites : complet jusqu'à 99 999, puis
+/// "123K", "1.2M", "45.6B"
+pub fn compact(value: impl Into<u64>) -> String {
+    compact_with(value.into(), number_style())
+}
+
+fn group(value: u64, style: NumberStyle) -> String {
+    let (thousands, _) = style.separators();
+    let digits = value.to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3 * 2);
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(thousands);
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+fn compact_with(value: u64, style: NumberStyle) -> String {
+    const UNITS: [(u64, char); 3] = [(1_000_000_000, 'B'), (1_000_000, 'M'), (1_000, 'K')];
+    if value < 100_000 {
+        return group(value, style);
+    }
+    let (unit, suffix) = UNITS
+        .into_iter()
+        .find(|&(unit, _)| value >= unit)
+        .unwrap_or((1_000, 'K'));
+    // Tronqué plutôt qu'arrondi : 999 999 ne devient jamais "1000K"
+    let tenths = value / (unit / 10);
+    let (whole, tenth) = (tenths / 10, tenths % 10);
+    if whole >= 100 || tenth == 0 {
+        format!("{}{suffix}", group(whole, style))
+    } else {
+        let (_, decimal) = style.separators();
+        format!("{whole}{decimal}{tenth}{suffix}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scores_group_thousands_and_shorten_huge_values() {
+        assert_eq!(group(0, NumberStyle::Comma), "0");
+        assert_eq!(group(999, NumberStyle::Comma), "999");
+        assert_eq!(group(1_234_567, NumberStyle::Comma), "1,234,567");
+        assert_eq!(group(12_345, NumberStyle::Space), "12\u{a0}345");
+        assert_eq!(group(12_345, NumberStyle::Dot), "12.345");
+
+        assert_eq!(compact_with(99_999, NumberStyle::Comma), "99,999");
+        assert_eq!(compact_with(123_456, NumberStyle::Comma), "123K");
+        assert_eq!(compact_with(999_999, NumberStyle::Comma), "999K");
+        assert_eq!(compact_with(1_234_567, NumberStyle::Comma), "1.2M");
+        assert_eq!(compact_with(1_234_567, NumberStyle::Space), "1,2M");
+        assert_eq!(compact_with(3_000_000, NumberStyle::Comma), "3M");
+        assert_eq!(compact_with(45_600_000_000, NumberStyle::Comma), "45.6B");
+    }
+
+    #[test]
+    fn the_numeric_locale_picks_the_separators() {
+        assert_eq!(detect_from(Some("fr_FR.UTF-8")), NumberStyle::Space);
+        assert_eq!(detect_from(Some("de_DE")), NumberStyle::Dot);
+        assert_eq!(detect_from(Some("en_US.UTF-8")), NumberStyle::Comma);
+        assert_eq!(detect_from(Some("C")), NumberStyle::Comma);
+        assert_eq!(detect_from(None), NumberStyle::Comma);
+    }
+}
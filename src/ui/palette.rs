@@ -0,0 +1,231 @@
+//! Couleurs ramenées à ce que le terminal sait afficher.
+//!
+//! Le menu et les jeux dessinent en couleurs 24 bits (`Color::Rgb`), que les
+//! terminaux sans « truecolor » affichent mal. Au moment d'écrire à l'écran,
+//! [`adapt`] remplace ces couleurs par la plus proche de la palette de 256
+//! ou de 16 couleurs, selon la profondeur détectée ou choisie dans les
+//! Settings.
+
+use crate::config::ColorDepth;
+use ratatui::style::Color;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Profondeur résolue ; `UNSET` tant que la configuration n'a pas été lue
+static RESOLVED: AtomicU8 = AtomicU8::new(UNSET);
+const UNSET: u8 = u8::MAX;
+
+/// Niveaux de chaque composante dans le cube 6×6×6 de la palette 256 couleurs
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Les 16 couleurs de base avec leurs valeurs xterm habituelles
+const ANSI: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (205, 0, 0)),
+    (Color::Green, (0, 205, 0)),
+    (Color::Yellow, (205, 205, 0)),
+    (Color::Blue, (0, 0, 238)),
+    (Color::Magenta, (205, 0, 205)),
+    (Color::Cyan, (0, 205, 205)),
+    (Color::Gray, (229, 229, 229)),
+    (Color::DarkGray, (127, 127, 127)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (92, 92, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+/// Profondeur de couleur effectivement utilisée
+pub fn color_depth() -> ColorDepth {
+    if RESOLVED.load(Ordering::Relaxed) == UNSET {
+        super::style::watch_config();
+    }
+    ColorDepth::ALL
+        .get(RESOLVED.load(Ordering::Relaxed) as usize)
+        .copied()
+        .unwrap_or(ColorDepth::TrueColor)
+}
+
+/// Prend en compte une profondeur modifiée ; `Auto` est résolu ici
+pub fn apply_color_depth(depth: ColorDepth) {
+    let depth = match depth {
+        ColorDepth::Auto => detect(),
+        depth => depth,
+    };
+    let index = ColorDepth::ALL
+        .iter()
+        .position(|&d| d == depth)
+        .unwrap_or(0);
+    RESOLVED.store(index as u8, Ordering::Relaxed);
+}
+
+/// Devine la profondeur du terminal : `COLORTERM` annonce le truecolor,
+/// `TERM` les 256 couleurs. Terminal.app ne gère pas le truecolor, et la
+/// console Linux n'a que 16 couleurs.
+pub fn detect() -> ColorDepth {
+    let var = |name| std::env::var(name).unwrap_or_default();
+    detect_from(&var("COLORTERM"), &var("TERM"), &var("TERM_PROGRAM"))
+}
+
+fn detect_from(colorterm: &str, term: &str, term_program: &str) -> ColorDepth {
+    if matches!(colorterm, "truecolor" | "24bit") || term.ends_with("-direct") {
+        ColorDepth::TrueColor
+    } else if term_program == "Apple_Terminal" || term.contains("256color") {
+        ColorDepth::Palette256
+    } else if term == "linux" || term.is_empty() || term == "ansi" || term.starts_with("vt") {
+        ColorDepth::Palette16
+    } else {
+        // xterm, screen… sans autre indication : la palette 256 est sûre
+        ColorDepth::Palette256
+    }
+}
+
+/// Couleur à écrire dans le terminal pour la profondeur courante
+pub fn adapt(color: Color) -> Color {
+    quantize(color, color_depth())
+}
+
+fn quantize(color: Color, depth: ColorDepth) -> Color {
+    match (depth, color) {
+        (ColorDepth::Auto | ColorDepth::TrueColor, color) => color,
+        (ColorDepth::Palette256, Color::Rgb(r, g, b)) => Color::Indexed(to_256(r, g, b)),
+        (ColorDepth::Palette16, Color::Rgb(r, g, b)) => to_16(r, g, b),
+        (ColorDepth::Palette16, Color::Indexed(index)) => match ANSI.get(index as usize) {
+            Some(&(ansi, _)) => ansi,
+            None => {
+                let (r, g, b) = indexed_rgb(index);
+                to_16(r, g, b)
+            }
+        },
+        (_, color) => color,
+    }
+}
+
+/// Entrée la plus proche entre le cube de couleurs et la rampe de gris
+fn to_256(r: u8, g: u8, b: u8) -> u8 {
+    let level = |c: u8| {
+        (0..CUBE_LEVELS.len())
+            .min_by_key(|&i| CUBE_LEVELS[i].abs_diff(c))
+            .unwrap_or(0)
+    };
+    let (ri, gi, bi) = (level(r), level(g), level(b));
+    let cube = (CUBE_LEVELS[ri], CUBE_LEVELS[gi], CUBE_LEVELS[bi]);
+
+    // Gris 232 à 255 : 8, 18… 238
+    let average = (r as u32 + g as u32 + b as u32) / 3;
+    let step = (average.saturating_sub(3) / 10).min(23) as u8;
+    let gray = 8 + 10 * step;
+
+    if distance((r, g, b), (gray, gray, gray)) < distance((r, g, b), cube) {
+        232 + step
+    } else {
+        16 + 36 * ri as u8 + 6 * gi as u8 + bi as u8
+    }
+}
+
+fn to_16(r: u8, g: u8, b: u8) -> Color {
+    ANSI.iter()
+        .min_by_key(|(_, rgb)| distance((r, g, b), *rgb))
+        .map_or(Color::Reset, |&(color, _)| color)
+}
+
+/// Valeurs RGB d'une entrée 16 à 255 de la palette 256 couleurs
+fn indexed_rgb(index: u8) -> (u8, u8, u8) {
+    if index >= 232 {
+        let gray = 8 + 10 * (index - 232);
+        (gray, gray, gray)
+    } else {
+        let cube = index.saturating_sub(16);
+        (
+            CUBE_LEVELS[(cube / 36) as usize],
+            CUBE_LEVELS[(cube / 6 % 6) as usize],
+            CUBE_LEVELS[(cube % 6) as usize],
+        )
+    }
+}
+
+/// Composantes RGB d'une couleur, `None` pour la couleur par défaut du
+/// terminal
+pub fn rgb(color: Color) -> Option<(u8, u8, u8)> {
+    match color {
+        Color::Reset => None,
+        Color::Rgb(r, g, b) => Some((r, g, b)),
+        Color::Indexed(index) => Some(match ANSI.get(index as usize) {
+            Some(&(_, rgb)) => rgb,
+            None => indexed_rgb(index),
+        }),
+        named => ANSI
+            .iter()
+            .find(|(ansi, _)| *ansi == named)
+            .map(|&(_, rgb)| rgb),
+    }
+}
+
+/// Couleur entre `from` (`amount` à 0.0) et `to` (à 1.0). Sans composantes
+/// connues, bascule de l'une à l'autre à mi-chemin
+pub fn blend(from: Color, to: Color, amount: f32) -> Color {
+    let amount = amount.clamp(0.0, 1.0);
+    let (Some(a), Some(b)) = (rgb(from), rgb(to)) else {
+        return if amount < 0.5 { from } else { to };
+    };
+    let mix = |x: u8, y: u8| (f32::from(x) + (f32::from(y) - f32::from(x)) * amount).round() as u8;
+    Color::Rgb(mix(a.0, b.0), mix(a.1, b.1), mix(a.2, b.2))
+}
+
+/// Distance au carré, pondérée comme la sensibilité de l'œil
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let d = |x: u8, y: u8| (x.abs_diff(y) as u32).pow(2);
+    3 * d(a.0, b.0) + 4 * d(a.1, b.1) + 2 * d(a.2, b.2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_depth_from_the_environment() {
+        assert_eq!(
+            detect_from("truecolor", "xterm-256color", ""),
+            ColorDepth::TrueColor
+        );
+        assert_eq!(
+            detect_from("", "xterm-256color", "Apple_Terminal"),
+            ColorDepth::Palette256
+        );
+        assert_eq!(detect_from("", "linux", ""), ColorDepth::Palette16);
+    }
+
+    #[test]
+    fn quantizes_rgb_to_the_nearest_palette_entry() {
+        let panel = Color::Rgb(10, 15, 20);
+        assert_eq!(quantize(panel, ColorDepth::TrueColor), panel);
+        assert_eq!(quantize(panel, ColorDepth::Palette256), Color::Indexed(233));
+        assert_eq!(
+            quantize(Color::Rgb(255, 0, 0), ColorDepth::Palette256),
+            Color::Indexed(196)
+        );
+        assert_eq!(quantize(panel, ColorDepth::Palette16), Color::Black);
+        assert_eq!(
+            quantize(Color::Rgb(0, 200, 210), ColorDepth::Palette16),
+            Color::Cyan
+        );
+        // Les couleurs nommées passent telles quelles
+        assert_eq!(
+            quantize(Color::Yellow, ColorDepth::Palette16),
+            Color::Yellow
+        );
+    }
+
+    #[test]
+    fn blends_named_and_rgb_colors() {
+        assert_eq!(blend(Color::Green, Color::Red, 0.0), Color::Rgb(0, 205, 0));
+        assert_eq!(
+            blend(Color::Rgb(0, 100, 200), Color::LightRed, 0.5),
+            Color::Rgb(128, 50, 100)
+        );
+        assert_eq!(blend(Color::Reset, Color::Red, 0.4), Color::Reset);
+        assert_eq!(blend(Color::Reset, Color::Red, 0.6), Color::Red);
+    }
+}
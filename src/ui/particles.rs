@@ -0,0 +1,121 @@
+//! Particules des jeux : de petits éclats qui jaillissent d'un point, tombent
+//! et s'éteignent en quelques images.
+//!
+//! Un jeu garde un [`Particles`], y ajoute des gerbes avec
+//! [`Particles::burst`], le fait avancer à chaque mise à jour et le dessine
+//! par-dessus son terrain. Les positions sont en cases du terrain : le jeu
+//! donne l'origine du terrain à l'écran au moment de dessiner. Comme les
+//! transitions, les particules disparaissent avec le réglage "Reduce Motion".
+
+use super::animation;
+use ratatui::{buffer::Buffer, layout::Rect, style::Color};
+
+/// Images avant qu'une particule ne s'éteigne
+const LIFETIME: u8 = 8;
+/// Accélération vers le bas à chaque image
+const GRAVITY: f32 = 0.12;
+/// Aspect des éclats, du plus vif au plus éteint
+const GLYPHS: [char; 4] = ['✦', '•', '·', '.'];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Particle {
+    x: f32,
+    y: f32,
+    dx: f32,
+    dy: f32,
+    color: Color,
+    age: u8,
+}
+
+#[derive(Debug, Default)]
+pub struct Particles {
+    particles: Vec<Particle>,
+}
+
+impl Particles {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fait jaillir `count` éclats de (`x`, `y`), répartis en cercle ; rien
+    /// si les animations sont désactivées
+    pub fn burst(&mut self, x: f32, y: f32, color: Color, count: usize) {
+        if animation::reduce_motion() {
+            return;
+        }
+        for i in 0..count {
+            let angle = i as f32 / count as f32 * std::f32::consts::TAU;
+            // Les cases sont deux fois plus hautes que larges
+            let speed = if i % 2 == 0 { 0.9 } else { 0.5 };
+            self.particles.push(Particle {
+                x,
+                y,
+                dx: angle.cos() * speed,
+                dy: angle.sin() * speed * 0.5 - 0.2,
+                color,
+                age: 0,
+            });
+        }
+    }
+
+    /// Avance d'une image et oublie les particules éteintes
+    pub fn step(&mut self) {
+        for particle in &mut self.particles {
+            particle.x += particle.dx;
+            particle.y += particle.dy;
+            particle.dy += GRAVITY;
+            particle.age += 1;
+        }
+        self.particles.retain(|particle| particle.age < LIFETIME);
+    }
+
+    pub fn clear(&mut self) {
+        self.particles.clear();
+    }
+
+    /// Dessine les particules d'un terrain dont la case (0, 0) est à
+    /// `origin`, sans sortir de `bounds`
+    pub fn render(&self, buffer: &mut Buffer, origin: (u16, u16), bounds: Rect) {
+        let bounds = bounds.intersection(buffer.area);
+        for particle in &self.particles {
+            if particle.x < 0.0 || particle.y < 0.0 {
+                continue;
+            }
+            let x = origin.0.saturating_add(particle.x as u16);
+            let y = origin.1.saturating_add(particle.y as u16);
+            if !bounds.contains((x, y).into()) {
+                continue;
+            }
+            let glyph = GLYPHS[usize::from(particle.age) * GLYPHS.len() / usize::from(LIFETIME)];
+            buffer[(x, y)].set_char(glyph).set_fg(particle.color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bursts_spread_fall_and_fade_out() {
+        animation::set_reduce_motion(false);
+        let mut particles = Particles::new();
+        particles.burst(5.0, 2.0, Color::Red, 6);
+        particles.step();
+
+        let area = Rect::new(0, 0, 12, 6);
+        let mut buffer = Buffer::empty(area);
+        particles.render(&mut buffer, (0, 0), area);
+        let lit = buffer
+            .content
+            .iter()
+            .filter(|cell| cell.symbol() != " ")
+            .count();
+        assert!(lit >= 3, "only {lit} particles drawn");
+
+        for _ in 0..LIFETIME {
+            particles.step();
+        }
+        assert!(particles.particles.is_empty());
+    }
+}
@@ -0,0 +1,235 @@
+//! Menu de pause commun à tous les jeux, ouvert avec Échap pendant une partie.
+//!
+//! Le menu ne fait que choisir une action : la boucle de jeu de l'application
+//! l'applique (reprise, redémarrage, retour au menu…), puisque c'est elle qui
+//! possède la partie.
+
+use crate::event::{KeyCode, KeyEvent};
+use crate::ui::animation::{Effect, Transition};
+use crate::ui::controls::CONTROL_HINTS;
+use crate::ui::{glyphs, style, SelectableList};
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Clear, ListItem, Paragraph},
+    Frame,
+};
+
+/// Action choisie dans le menu de pause
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PauseAction {
+    Resume,
+    Restart,
+    ToggleMusic,
+    ToggleEffects,
+    MainMenu,
+    Quit,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PausePage {
+    Main,
+    Options,
+    Controls,
+}
+
+const MAIN_ITEMS: [&str; 6] = [
+    "Resume",
+    "Restart",
+    "Options",
+    "Controls",
+    "Back to Main Menu",
+    "Quit TermPlay",
+];
+
+const OPTION_ITEMS: [&str; 3] = ["Music", "Sound Effects", "Back"];
+
+pub struct PauseMenu {
+    page: PausePage,
+    list: SelectableList,
+    opening: Option<Transition>,
+    /// Touches propres au jeu en cours, en tête de la page Controls
+    game_controls: &'static [(&'static str, &'static str)],
+}
+
+impl PauseMenu {
+    pub fn new() -> Self {
+        Self {
+            page: PausePage::Main,
+            list: SelectableList::new(),
+            opening: Transition::start(Effect::FadeIn),
+            game_controls: &[],
+        }
+    }
+
+    /// Menu dont la page Controls commence par les touches du jeu en cours
+    pub fn with_controls(mut self, controls: &'static [(&'static str, &'static str)]) -> Self {
+        self.game_controls = controls;
+        self
+    }
+
+    /// Lignes de la page Controls : les touches du jeu, puis les raccourcis
+    /// communs
+    fn control_lines(&self) -> Vec<Line<'static>> {
+        let line = |(key, action): &(&str, &'static str)| {
+            Line::from(vec![
+                format!(" {key:<16}").cyan().bold(),
+                action.fg(style::text()),
+            ])
+        };
+        let mut lines: Vec<Line> = self.game_controls.iter().map(line).collect();
+        if !lines.is_empty() {
+            lines.push(Line::from(""));
+        }
+        lines.extend(CONTROL_HINTS.iter().map(line));
+        lines
+    }
+
+    /// Indique si le fondu d'ouverture est en cours
+    pub fn animating(&self) -> bool {
+        self.opening.is_some_and(|t| !t.is_finished())
+    }
+
+    /// Applique le fondu d'ouverture au popup déjà dessiné
+    fn apply_opening(&mut self, frame: &mut Frame, popup: Rect) {
+        match self.opening {
+            Some(transition) if transition.is_finished() => self.opening = None,
+            Some(transition) => transition.apply(frame.buffer_mut(), popup),
+            None => {}
+        }
+    }
+
+    fn open_page(&mut self, page: PausePage) {
+        self.page = page;
+        self.list.reset();
+    }
+
+    fn len(&self) -> usize {
+        match self.page {
+            PausePage::Main => MAIN_ITEMS.len(),
+            PausePage::Options => OPTION_ITEMS.len(),
+            PausePage::Controls => 0,
+        }
+    }
+
+    /// Applique une touche ; retourne l'action à effectuer, s'il y en a une
+    pub fn handle_key(&mut self, key: KeyEvent) -> Option<PauseAction> {
+        if self.list.handle_key(key, self.len()) {
+            return None;
+        }
+
+        match (self.page, key.code) {
+            (PausePage::Main, KeyCode::Esc) => Some(PauseAction::Resume),
+            (PausePage::Main, KeyCode::Enter | KeyCode::Char(' ')) => match self.list.selected() {
+                0 => Some(PauseAction::Resume),
+                1 => Some(PauseAction::Restart),
+                2 => {
+                    self.open_page(PausePage::Options);
+                    None
+                }
+                3 => {
+                    self.open_page(PausePage::Controls);
+                    None
+                }
+                4 => Some(PauseAction::MainMenu),
+                _ => Some(PauseAction::Quit),
+            },
+            (PausePage::Options, KeyCode::Enter | KeyCode::Char(' ')) => {
+                match self.list.selected() {
+                    0 => Some(PauseAction::ToggleMusic),
+                    1 => Some(PauseAction::ToggleEffects),
+                    _ => {
+                        self.open_page(PausePage::Main);
+                        None
+                    }
+                }
+            }
+            (PausePage::Options | PausePage::Controls, KeyCode::Esc | KeyCode::Backspace)
+            | (PausePage::Controls, KeyCode::Enter) => {
+                self.open_page(PausePage::Main);
+                None
+            }
+            _ => None,
+        }
+    }
+
+    /// Dessine le menu par-dessus la partie ; `music` et `effects` donnent
+    /// l'état affiché dans les options
+    pub fn draw(&mut self, frame: &mut Frame, music: bool, effects: bool) {
+        let area = frame.area();
+        let (width, height) = match self.page {
+            PausePage::Controls => {
+                let lines = self.control_lines();
+                let widest = lines.iter().map(Line::width).max().unwrap_or(0) as u16;
+                ((widest + 3).max(44), lines.len() as u16 + 4)
+            }
+            _ => (44, self.len() as u16 + 4),
+        };
+        let width = width.min(area.width);
+        let height = height.min(area.height);
+        let popup = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + (area.height - height) / 2,
+            width,
+            height,
+        };
+
+        let title = match self.page {
+            PausePage::Main => format!(" {} Paused ", glyphs::icon("⏸")),
+            PausePage::Options => " Options ".to_string(),
+            PausePage::Controls => " Controls ".to_string(),
+        };
+        let block = Block::bordered()
+            .title(title.yellow().bold())
+            .title_bottom(Line::from(" Esc Back ".gray()).centered())
+            .border_style(Style::new().yellow())
+            .style(Style::default().bg(style::surface()));
+
+        frame.render_widget(Clear, popup);
+
+        let items: Vec<ListItem> = match self.page {
+            PausePage::Main => MAIN_ITEMS.iter().map(|item| ListItem::new(*item)).collect(),
+            PausePage::Options => OPTION_ITEMS
+                .iter()
+                .enumerate()
+                .map(|(i, item)| {
+                    let state = match i {
+                        0 => Some(music),
+                        1 => Some(effects),
+                        _ => None,
+                    };
+                    let mut spans = vec![Span::raw(format!("{item:<16}"))];
+                    if let Some(on) = state {
+                        spans.push(if on {
+                            "ON".green().bold()
+                        } else {
+                            "OFF".red().bold()
+                        });
+                    }
+                    ListItem::new(Line::from(spans))
+                })
+                .collect(),
+            PausePage::Controls => {
+                frame.render_widget(Paragraph::new(self.control_lines()).block(block), popup);
+                self.apply_opening(frame, popup);
+                return;
+            }
+        };
+
+        self.list.render(
+            frame,
+            popup,
+            items,
+            block.title_alignment(Alignment::Center),
+            style::focus(Color::Rgb(60, 80, 100)),
+        );
+        self.apply_opening(frame, popup);
+    }
+}
+
+impl Default for PauseMenu {
+    fn default() -> Self {
+        Self::new()
+    }
+}
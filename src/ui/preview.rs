@@ -0,0 +1,42 @@
+//! Miniatures des jeux pour le panneau d'aperçu du menu Games.
+//!
+//! Les jeux décrivent un petit plateau figé sous forme de lignes de
+//! caractères, un caractère par case, et [`draw_board`] le centre dans la
+//! zone du panneau (voir [`crate::core::Game::preview`]).
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Paragraph},
+    Frame,
+};
+
+/// Fond des plateaux, le même que dans les jeux
+pub const BOARD_BACKGROUND: Color = Color::Rgb(10, 15, 20);
+
+/// Dessine le plateau `rows` centré dans `area` ; `cell` donne le texte de
+/// chaque case, de la même largeur pour toutes les cases
+pub fn draw_board<F>(frame: &mut Frame, area: Rect, rows: &[&str], cell: F)
+where
+    F: Fn(char) -> Span<'static>,
+{
+    let lines: Vec<Line> = rows
+        .iter()
+        .map(|row| Line::from(row.chars().map(&cell).collect::<Vec<_>>()))
+        .collect();
+    let width = lines.iter().map(Line::width).max().unwrap_or(0) as u16;
+    let height = lines.len() as u16;
+    let board = Rect {
+        x: area.x + area.width.saturating_sub(width) / 2,
+        y: area.y + area.height.saturating_sub(height) / 2,
+        width: width.min(area.width),
+        height: height.min(area.height),
+    };
+
+    frame.render_widget(
+        Block::new().style(Style::default().bg(BOARD_BACKGROUND)),
+        area,
+    );
+    frame.render_widget(Paragraph::new(lines), board);
+}
@@ -0,0 +1,84 @@
+//! Voyant affiché pendant l'enregistrement d'une partie (F9), puis le nom du
+//! fichier enregistré quelques instants. Il est dessiné après la capture de
+//! l'image : il n'apparaît pas dans l'enregistrement.
+
+use crate::core::clock::Instant;
+use crate::ui::style;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style, Stylize},
+    text::Line,
+    widgets::{Block, Clear, Paragraph},
+    Frame,
+};
+use std::time::Duration;
+
+/// Durée d'affichage du message de fin d'enregistrement
+const NOTICE_DURATION: Duration = Duration::from_millis(3000);
+
+pub struct RecordingBadge {
+    /// Message de fin d'enregistrement et instant où il a été affiché
+    notice: Option<(String, Instant)>,
+}
+
+impl RecordingBadge {
+    pub fn new() -> Self {
+        Self { notice: None }
+    }
+
+    /// Affiche `text` quelques instants (fichier enregistré, erreur)
+    pub fn show_notice(&mut self, text: String) {
+        self.notice = Some((text, Instant::now()));
+    }
+
+    /// Dessine le voyant en haut à droite si `recording` donne la durée de
+    /// l'enregistrement en cours, sinon le dernier message s'il n'a pas expiré
+    pub fn draw(&mut self, frame: &mut Frame, recording: Option<Duration>) {
+        if self
+            .notice
+            .as_ref()
+            .is_some_and(|(_, shown_at)| shown_at.elapsed() >= NOTICE_DURATION)
+        {
+            self.notice = None;
+        }
+
+        let line = match (recording, &self.notice) {
+            (Some(elapsed), _) => {
+                let seconds = elapsed.as_secs();
+                Line::from(vec![
+                    "● REC ".red().bold(),
+                    format!("{}:{:02}", seconds / 60, seconds % 60).fg(style::text()),
+                    "  F9 stop".dark_gray(),
+                ])
+            }
+            (None, Some((text, _))) => Line::from(text.clone().fg(style::text())),
+            (None, None) => return,
+        };
+
+        let area = frame.area();
+        let width = (line.width() as u16 + 4).min(area.width);
+        let height = 3.min(area.height);
+        let badge = Rect {
+            x: area.x + area.width - width,
+            y: area.y,
+            width,
+            height,
+        };
+
+        frame.render_widget(Clear, badge);
+        frame.render_widget(
+            Paragraph::new(line).centered().block(
+                Block::bordered()
+                    .border_style(Style::new().fg(Color::Red))
+                    .style(Style::default().bg(style::surface())),
+            ),
+            badge,
+        );
+    }
+}
+
+impl Default for RecordingBadge {
+    fn default() -> Self {
+        Self::new()
+    }
+}
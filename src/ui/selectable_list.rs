@@ -0,0 +1,145 @@
+//! Liste verticale sélectionnable au clavier et à la souris.
+//!
+//! Le composant ne possède pas ses éléments : l'appelant fournit leur nombre
+//! pour la navigation et les `ListItem` au moment du rendu, ce qui permet de
+//! l'utiliser pour des listes qui changent d'un écran à l'autre.
+
+use crate::event::{KeyCode, KeyEvent, MouseEvent, MouseEventKind};
+use crate::ui::style;
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    widgets::{Block, List, ListItem, ListState},
+    Frame,
+};
+
+/// Nombre d'éléments sautés par PageUp/PageDown
+const PAGE_SIZE: usize = 5;
+
+/// Sélection dans une liste : déplacement élément par élément (avec bouclage),
+/// par page, et saut au début ou à la fin (sans bouclage)
+#[derive(Debug)]
+pub struct SelectableList {
+    selected: usize,
+    state: ListState,
+}
+
+impl SelectableList {
+    pub fn new() -> Self {
+        let mut state = ListState::default();
+        state.select(Some(0));
+        Self { selected: 0, state }
+    }
+
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    fn select(&mut self, index: usize) {
+        self.selected = index;
+        self.state.select(Some(index));
+    }
+
+    /// Revient au premier élément
+    pub fn reset(&mut self) {
+        self.select(0);
+    }
+
+    pub fn next(&mut self, len: usize) {
+        if len > 0 {
+            self.select((self.selected + 1) % len);
+        }
+    }
+
+    pub fn previous(&mut self, len: usize) {
+        if len > 0 {
+            self.select(if self.selected == 0 {
+                len - 1
+            } else {
+                self.selected - 1
+            });
+        }
+    }
+
+    pub fn page_down(&mut self, len: usize) {
+        if len > 0 {
+            self.select((self.selected + PAGE_SIZE).min(len - 1));
+        }
+    }
+
+    pub fn page_up(&mut self, len: usize) {
+        if len > 0 {
+            self.select(self.selected.saturating_sub(PAGE_SIZE).min(len - 1));
+        }
+    }
+
+    pub fn first(&mut self, len: usize) {
+        if len > 0 {
+            self.select(0);
+        }
+    }
+
+    pub fn last(&mut self, len: usize) {
+        if len > 0 {
+            self.select(len - 1);
+        }
+    }
+
+    /// Sélectionne l'élément `index`, ou le dernier si la liste est plus courte
+    pub fn jump_to(&mut self, index: usize, len: usize) {
+        if len > 0 {
+            self.select(index.min(len - 1));
+        }
+    }
+
+    /// Applique les touches de navigation ; retourne `true` si la touche a été
+    /// consommée
+    pub fn handle_key(&mut self, key: KeyEvent, len: usize) -> bool {
+        match key.code {
+            KeyCode::Down => self.next(len),
+            KeyCode::Up => self.previous(len),
+            KeyCode::PageDown => self.page_down(len),
+            KeyCode::PageUp => self.page_up(len),
+            KeyCode::Home => self.first(len),
+            KeyCode::End => self.last(len),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Gère la molette : un cran déplace la sélection d'un élément ; retourne
+    /// `true` si l'événement a été consommé
+    pub fn handle_mouse(&mut self, mouse: MouseEvent, len: usize) -> bool {
+        match mouse.kind {
+            MouseEventKind::ScrollDown => self.next(len),
+            MouseEventKind::ScrollUp => self.previous(len),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Dessine les éléments dans `block` en surlignant la sélection avec
+    /// `highlight`
+    pub fn render(
+        &mut self,
+        frame: &mut Frame,
+        area: Rect,
+        items: Vec<ListItem>,
+        block: Block,
+        highlight: Style,
+    ) {
+        let list = List::new(items)
+            .block(block)
+            .style(Style::default().fg(style::text()))
+            .highlight_style(highlight)
+            .highlight_symbol("▶ ");
+
+        frame.render_stateful_widget(list, area, &mut self.state);
+    }
+}
+
+impl Default for SelectableList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
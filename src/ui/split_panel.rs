@@ -0,0 +1,75 @@
+//! Panneau compact des splits, affiché dans le coin haut droit du jeu.
+
+use crate::splits::{format_delta, format_split, SplitTimer};
+use crate::ui::{glyphs, style};
+use ratatui::{
+    layout::Rect,
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Clear, Paragraph},
+    Frame,
+};
+use std::time::Duration;
+
+const PANEL_WIDTH: u16 = 24;
+/// Nombre de splits franchis gardés à l'écran, les plus récents
+const VISIBLE_SPLITS: usize = 5;
+
+/// Dessine les derniers splits, leur écart avec le meilleur temps et le
+/// split en cours
+pub fn draw_split_panel(frame: &mut Frame, timer: &SplitTimer, elapsed: Duration) {
+    let splits = timer.splits();
+    let first = splits.len().saturating_sub(VISIBLE_SPLITS);
+
+    let mut lines: Vec<Line> = splits[first..]
+        .iter()
+        .enumerate()
+        .map(|(offset, &split)| {
+            let index = first + offset;
+            let delta = match timer.delta(index) {
+                Some(delta) if delta < 0 => format_delta(delta).green(),
+                Some(delta) => format_delta(delta).red(),
+                None => "".into(),
+            };
+            Line::from(vec![
+                format!("{:>2} ", index + 1).dark_gray(),
+                Span::raw(format!("{:>8} ", format_split(split))),
+                delta,
+            ])
+        })
+        .collect();
+
+    // Split en cours : temps courant et temps à battre
+    let next = splits.len();
+    let target = timer
+        .best(next)
+        .map_or_else(|| "--".to_string(), format_split);
+    lines.push(Line::from(vec![
+        format!("{:>2} ", next + 1).yellow(),
+        format!("{:>8} ", format_split(elapsed.as_millis() as u64))
+            .yellow()
+            .bold(),
+        format!("PB {target}").dark_gray(),
+    ]));
+
+    let area = frame.area();
+    let width = PANEL_WIDTH.min(area.width);
+    let height = (lines.len() as u16 + 2).min(area.height);
+    let panel = Rect {
+        x: area.right() - width,
+        y: area.y,
+        width,
+        height,
+    };
+
+    frame.render_widget(Clear, panel);
+    frame.render_widget(
+        Paragraph::new(lines).block(
+            Block::bordered()
+                .title(format!(" {} Splits ", glyphs::icon("⏱")).yellow().bold())
+                .border_style(Style::new().dark_gray())
+                .style(Style::default().bg(style::surface())),
+        ),
+        panel,
+    );
+}
@@ -0,0 +1,216 @@
+//! Écran partagé en deux volets, pour les modes côte à côte.
+//!
+//! Chaque volet a son propre terminal virtuel de la taille de sa zone : ce
+//! qui s'y dessine voit un `frame.area()` qui commence en (0, 0), comme en
+//! plein écran, puis [`SplitScreen::render`] recopie le résultat à sa place.
+//! Un jeu, ou une vue écrite pour tout l'écran, se dessine ainsi dans
+//! n'importe quel volet sans changer son code. Un seul volet a le focus à la
+//! fois : Tab le fait passer de l'un à l'autre, les autres touches vont au
+//! volet actif, et les clics au volet sous la souris, en coordonnées locales.
+
+use crate::event::{KeyCode, KeyEvent, MouseEvent};
+use ratatui::{
+    backend::TestBackend,
+    buffer::Buffer,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    Frame, Terminal,
+};
+
+/// Volet de gauche (ou du haut) et volet de droite (ou du bas)
+pub const PANES: usize = 2;
+
+pub struct SplitScreen {
+    direction: Direction,
+    terminals: [Option<Terminal<TestBackend>>; PANES],
+    focus: usize,
+    /// Zones des volets au dernier rendu, pour la souris
+    areas: [Rect; PANES],
+}
+
+impl SplitScreen {
+    /// Volets côte à côte (`Direction::Horizontal`) ou l'un sur l'autre
+    pub fn new(direction: Direction) -> Self {
+        Self {
+            direction,
+            terminals: [None, None],
+            focus: 0,
+            areas: [Rect::default(); PANES],
+        }
+    }
+
+    pub fn focus(&self) -> usize {
+        self.focus
+    }
+
+    /// Zones des deux volets dans `area`, séparées par une ligne
+    pub fn areas(&self, area: Rect) -> [Rect; PANES] {
+        let [first, _, second] = Layout::new(
+            self.direction,
+            [
+                Constraint::Fill(1),
+                Constraint::Length(1),
+                Constraint::Fill(1),
+            ],
+        )
+        .areas(area);
+        [first, second]
+    }
+
+    /// Volet destinataire d'une touche ; `None` pour Tab, qui change le focus
+    pub fn route_key(&mut self, key: KeyEvent) -> Option<(usize, KeyEvent)> {
+        if key.code == KeyCode::Tab {
+            self.focus = (self.focus + 1) % PANES;
+            return None;
+        }
+        Some((self.focus, key))
+    }
+
+    /// Volet sous la souris, avec l'événement ramené à ses coordonnées ; un
+    /// clic donne le focus au volet
+    pub fn route_mouse(&mut self, mut mouse: MouseEvent) -> Option<(usize, MouseEvent)> {
+        let pane = self
+            .areas
+            .iter()
+            .position(|area| area.contains((mouse.column, mouse.row).into()))?;
+        if matches!(mouse.kind, crate::event::MouseEventKind::Down(_)) {
+            self.focus = pane;
+        }
+        mouse.column -= self.areas[pane].x;
+        mouse.row -= self.areas[pane].y;
+        Some((pane, mouse))
+    }
+
+    /// Dessine les deux volets dans `area` : `draw` est appelé pour chacun
+    /// avec son numéro et un frame de la taille du volet
+    pub fn render(
+        &mut self,
+        frame: &mut Frame,
+        area: Rect,
+        mut draw: impl FnMut(usize, &mut Frame),
+    ) {
+        let area = area.intersection(frame.area());
+        self.areas = self.areas(area);
+        for pane in 0..PANES {
+            let pane_area = self.areas[pane];
+            if pane_area.is_empty() {
+                continue;
+            }
+            if let Some(buffer) = self.draw_pane(pane, pane_area, &mut draw) {
+                copy_into(frame.buffer_mut(), &buffer, pane_area);
+            }
+        }
+        self.draw_divider(frame.buffer_mut(), area);
+    }
+
+    fn draw_pane(
+        &mut self,
+        pane: usize,
+        area: Rect,
+        draw: &mut impl FnMut(usize, &mut Frame),
+    ) -> Option<Buffer> {
+        let size = Rect::new(0, 0, area.width, area.height);
+        let terminal = match &mut self.terminals[pane] {
+            Some(terminal) if terminal.backend().buffer().area == size => terminal,
+            slot => slot.insert(Terminal::new(TestBackend::new(area.width, area.height)).ok()?),
+        };
+        terminal
+            .draw(|frame| draw(pane, frame))
+            .ok()
+            .map(|completed| completed.buffer.clone())
+    }
+
+    /// Ligne de séparation, avec une flèche vers le volet actif
+    fn draw_divider(&self, buffer: &mut Buffer, area: Rect) {
+        let (first, second) = (self.areas[0], self.areas[1]);
+        let divider = match self.direction {
+            Direction::Horizontal => Rect::new(first.right(), area.y, 1, area.height),
+            Direction::Vertical => Rect::new(area.x, first.bottom(), area.width, 1),
+        }
+        .intersection(buffer.area);
+        let (line, arrow) = match (self.direction, self.focus) {
+            (Direction::Horizontal, 0) => ("│", "◀"),
+            (Direction::Horizontal, _) => ("│", "▶"),
+            (Direction::Vertical, 0) => ("─", "▲"),
+            (Direction::Vertical, _) => ("─", "▼"),
+        };
+        for y in divider.top()..divider.bottom() {
+            for x in divider.left()..divider.right() {
+                buffer[(x, y)]
+                    .set_symbol(line)
+                    .set_style(Style::default().fg(Color::DarkGray));
+            }
+        }
+        if second.is_empty() || divider.is_empty() {
+            return;
+        }
+        let middle = match self.direction {
+            Direction::Horizontal => (divider.x, divider.y + divider.height / 2),
+            Direction::Vertical => (divider.x + divider.width / 2, divider.y),
+        };
+        buffer[middle]
+            .set_symbol(arrow)
+            .set_style(Style::default().fg(Color::Yellow));
+    }
+}
+
+/// Recopie `source`, dessiné à partir de (0, 0), dans la zone `area`
+fn copy_into(target: &mut Buffer, source: &Buffer, area: Rect) {
+    let area = area.intersection(target.area);
+    for y in 0..area.height {
+        for x in 0..area.width {
+            if let Some(cell) = source.cell((x, y)) {
+                target[(area.x + x, area.y + y)] = cell.clone();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::{KeyModifiers, MouseButton, MouseEventKind};
+    use ratatui::widgets::Paragraph;
+
+    #[test]
+    fn panes_draw_as_if_full_screen_and_route_input() {
+        let mut split = SplitScreen::new(Direction::Horizontal);
+        let mut terminal = Terminal::new(TestBackend::new(21, 2)).unwrap();
+        terminal
+            .draw(|frame| {
+                split.render(frame, frame.area(), |pane, frame| {
+                    // Chaque volet commence en (0, 0)
+                    assert_eq!(frame.area(), Rect::new(0, 0, 10, 2));
+                    let text = if pane == 0 { "left" } else { "right" };
+                    frame.render_widget(Paragraph::new(text), frame.area());
+                })
+            })
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let rows: Vec<String> = (0..2)
+            .map(|y| (0..21).map(|x| buffer[(x, y)].symbol()).collect())
+            .collect();
+        assert_eq!(rows, ["left      │right     ", "          ◀          "]);
+
+        let key = KeyEvent::from(KeyCode::Char('x'));
+        assert_eq!(split.route_key(key), Some((0, key)));
+        assert_eq!(split.route_key(KeyEvent::from(KeyCode::Tab)), None);
+        assert_eq!(split.route_key(key), Some((1, key)));
+
+        let click = MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column: 3,
+            row: 1,
+            modifiers: KeyModifiers::NONE,
+        };
+        let (pane, local) = split.route_mouse(click).unwrap();
+        assert_eq!((pane, local.column, local.row), (0, 3, 1));
+        assert_eq!(split.focus(), 0);
+        assert!(split
+            .route_mouse(MouseEvent {
+                column: 10,
+                ..click
+            })
+            .is_none());
+    }
+}
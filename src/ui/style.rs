@@ -0,0 +1,168 @@
+//! Couleurs partagées des menus : fonds des panneaux, texte et surlignage de
+//! l'élément sélectionné, selon le jeu de couleurs choisi dans les Settings.
+//!
+//! Les réglages d'affichage sont lus une fois dans la configuration, puis
+//! suivis par [`apply_display_config`] à chaque modification (voir
+//! [`config::on_change`]).
+
+use crate::config::{self, ColorScheme, DisplayConfig, GameConfig, PlayerColor};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    widgets::Block,
+    Frame,
+};
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+use std::sync::{Once, PoisonError, RwLock};
+
+/// Jeu de couleurs courant ; `UNSET` tant que la configuration n'a pas été lue
+static SCHEME: AtomicU8 = AtomicU8::new(UNSET);
+const UNSET: u8 = u8::MAX;
+static TRANSPARENT: AtomicBool = AtomicBool::new(false);
+static PLAYER_COLORS: RwLock<[PlayerColor; 2]> = RwLock::new(PlayerColor::DEFAULT_PAIR);
+
+/// Lit les réglages d'affichage au premier usage
+fn ensure_loaded() {
+    if SCHEME.load(Ordering::Relaxed) == UNSET {
+        watch_config();
+    }
+}
+
+/// Applique les réglages d'affichage en vigueur, puis chaque modification.
+/// Appelé au premier usage par chacun des réglages mis en cache (symboles,
+/// profondeur de couleur, animations, dates)
+pub(crate) fn watch_config() {
+    static WATCHING: Once = Once::new();
+    WATCHING.call_once(|| config::on_change(apply_config));
+}
+
+fn apply_config(config: &GameConfig) {
+    *PLAYER_COLORS
+        .write()
+        .unwrap_or_else(PoisonError::into_inner) = config.gameplay.player_colors;
+    apply_display_config(&config.display);
+}
+
+pub fn color_scheme() -> ColorScheme {
+    ensure_loaded();
+    ColorScheme::ALL
+        .get(SCHEME.load(Ordering::Relaxed) as usize)
+        .copied()
+        .unwrap_or_default()
+}
+
+/// Indique si le fond du terminal doit rester visible
+pub fn transparent_background() -> bool {
+    ensure_loaded();
+    TRANSPARENT.load(Ordering::Relaxed)
+}
+
+/// Prend en compte des réglages d'affichage modifiés
+pub fn apply_display_config(display: &DisplayConfig) {
+    let index = ColorScheme::ALL
+        .iter()
+        .position(|&s| s == display.color_scheme)
+        .unwrap_or(0);
+    TRANSPARENT.store(display.transparent_background, Ordering::Relaxed);
+    super::glyphs::apply_glyph_set(display.glyph_set);
+    super::palette::apply_color_depth(display.color_depth);
+    super::animation::set_reduce_motion(display.reduce_motion);
+    crate::time_format::apply_display_config(display);
+    SCHEME.store(index as u8, Ordering::Relaxed);
+}
+
+/// Peint `color` sur tout l'écran, sauf si le joueur garde le fond de son
+/// terminal
+pub fn fill_background(frame: &mut Frame, color: Color) {
+    fill_area(frame, frame.area(), color);
+}
+
+/// Comme [`fill_background`], limité à `area`
+pub fn fill_area(frame: &mut Frame, area: Rect, color: Color) {
+    if !transparent_background() {
+        frame.render_widget(Block::new().style(Style::default().bg(color)), area);
+    }
+}
+
+/// Style de l'élément sélectionné d'une liste ou d'une barre d'onglets.
+/// `accent` colore le fond, avec un texte noir ou blanc selon sa clarté ; en
+/// contraste élevé la sélection passe en vidéo inverse.
+pub fn focus(accent: Color) -> Style {
+    match color_scheme() {
+        ColorScheme::Dark | ColorScheme::Light => Style::default()
+            .bg(accent)
+            .fg(contrasting_text(accent))
+            .add_modifier(Modifier::BOLD),
+        ColorScheme::HighContrast => Style::default()
+            .fg(Color::Reset)
+            .bg(Color::Reset)
+            .add_modifier(Modifier::REVERSED | Modifier::BOLD),
+    }
+}
+
+/// Fond de l'écran derrière les panneaux
+pub fn background() -> Color {
+    match color_scheme() {
+        ColorScheme::Dark => Color::Rgb(15, 20, 25),
+        ColorScheme::Light | ColorScheme::HighContrast => Color::Reset,
+    }
+}
+
+/// Fond des listes et des zones de contenu
+pub fn panel() -> Color {
+    match color_scheme() {
+        ColorScheme::Dark => Color::Rgb(10, 15, 20),
+        ColorScheme::Light | ColorScheme::HighContrast => Color::Reset,
+    }
+}
+
+/// Fond des bandeaux (en-têtes, pieds de page, popups)
+pub fn surface() -> Color {
+    match color_scheme() {
+        ColorScheme::Dark => Color::Rgb(25, 35, 45),
+        ColorScheme::Light | ColorScheme::HighContrast => Color::Reset,
+    }
+}
+
+/// Couleurs des joueurs 1 et 2 des modes à deux, choisies dans Settings →
+/// Gameplay
+pub fn player_colors() -> [Color; 2] {
+    ensure_loaded();
+    let colors = *PLAYER_COLORS.read().unwrap_or_else(PoisonError::into_inner);
+    PlayerColor::pair(colors).map(|color| {
+        let (r, g, b) = color.rgb();
+        Color::Rgb(r, g, b)
+    })
+}
+
+/// Couleur du texte principal sur [`panel`] et [`surface`]
+pub fn text() -> Color {
+    match color_scheme() {
+        ColorScheme::Dark => Color::White,
+        ColorScheme::Light => Color::Black,
+        ColorScheme::HighContrast => Color::Reset,
+    }
+}
+
+/// Noir sur les fonds clairs, blanc sur les fonds sombres
+fn contrasting_text(background: Color) -> Color {
+    let light = match background {
+        Color::Rgb(r, g, b) => 299 * r as u32 + 587 * g as u32 + 114 * b as u32 > 140_000,
+        Color::Yellow
+        | Color::Cyan
+        | Color::Gray
+        | Color::White
+        | Color::LightRed
+        | Color::LightGreen
+        | Color::LightYellow
+        | Color::LightBlue
+        | Color::LightMagenta
+        | Color::LightCyan => true,
+        _ => false,
+    };
+    if light {
+        Color::Black
+    } else {
+        Color::White
+    }
+}
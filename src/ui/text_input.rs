@@ -0,0 +1,164 @@
+//! Champ de saisie de texte sur une ligne.
+//!
+//! Le curseur se déplace par graphème (un emoji ou une lettre accentuée
+//! composée comptent pour un seul caractère) et la longueur maximale est
+//! comptée de la même façon. Le rendu place le curseur du terminal en tenant
+//! compte de la largeur d'affichage des caractères larges.
+
+use crate::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Position, Rect},
+    style::Style,
+    widgets::{Block, Paragraph},
+    Frame,
+};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+#[derive(Debug, Clone)]
+pub struct TextInput {
+    value: String,
+    /// Position du curseur, en graphèmes
+    cursor: usize,
+    /// Nombre maximal de graphèmes
+    max_len: usize,
+}
+
+impl TextInput {
+    pub fn new(max_len: usize) -> Self {
+        Self {
+            value: String::new(),
+            cursor: 0,
+            max_len,
+        }
+    }
+
+    /// Champ prérempli, curseur en fin de texte
+    pub fn with_value(value: &str, max_len: usize) -> Self {
+        let mut input = Self::new(max_len);
+        input.insert_str(value);
+        input
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.value.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.value.clear();
+        self.cursor = 0;
+    }
+
+    fn len(&self) -> usize {
+        self.value.graphemes(true).count()
+    }
+
+    /// Index en octets du début du graphème `index`
+    fn byte_index(&self, index: usize) -> usize {
+        self.value
+            .grapheme_indices(true)
+            .nth(index)
+            .map_or(self.value.len(), |(byte, _)| byte)
+    }
+
+    /// Insère un caractère au curseur (ignoré si le champ est plein)
+    pub fn insert(&mut self, c: char) {
+        if c.is_control() {
+            return;
+        }
+
+        let before = self.len();
+        let at = self.byte_index(self.cursor);
+        self.value.insert(at, c);
+
+        let after = self.len();
+        if after > self.max_len {
+            self.value.remove(at);
+        } else if after > before {
+            self.cursor += 1;
+        }
+        // Sinon le caractère s'est combiné au graphème précédent
+    }
+
+    /// Insère un texte collé, sans ses retours à la ligne ni caractères de
+    /// contrôle, dans la limite de la longueur maximale
+    pub fn insert_str(&mut self, text: &str) {
+        for c in text.chars() {
+            self.insert(c);
+        }
+    }
+
+    /// Supprime le graphème avant le curseur
+    pub fn backspace(&mut self) {
+        if self.cursor > 0 {
+            let start = self.byte_index(self.cursor - 1);
+            let end = self.byte_index(self.cursor);
+            self.value.replace_range(start..end, "");
+            self.cursor -= 1;
+        }
+    }
+
+    /// Supprime le graphème sous le curseur
+    pub fn delete(&mut self) {
+        if self.cursor < self.len() {
+            let start = self.byte_index(self.cursor);
+            let end = self.byte_index(self.cursor + 1);
+            self.value.replace_range(start..end, "");
+        }
+    }
+
+    pub fn move_left(&mut self) {
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    pub fn move_right(&mut self) {
+        self.cursor = (self.cursor + 1).min(self.len());
+    }
+
+    pub fn move_home(&mut self) {
+        self.cursor = 0;
+    }
+
+    pub fn move_end(&mut self) {
+        self.cursor = self.len();
+    }
+
+    /// Applique les touches d'édition ; retourne `true` si la touche a été
+    /// consommée. Entrée et Échap sont laissées à l'appelant.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => self.clear(),
+            KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => self.insert(c),
+            KeyCode::Backspace => self.backspace(),
+            KeyCode::Delete => self.delete(),
+            KeyCode::Left => self.move_left(),
+            KeyCode::Right => self.move_right(),
+            KeyCode::Home => self.move_home(),
+            KeyCode::End => self.move_end(),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Dessine le champ dans `block` ; le curseur du terminal n'est affiché
+    /// que si `focused`
+    pub fn render(&self, frame: &mut Frame, area: Rect, block: Block, style: Style, focused: bool) {
+        let inner = block.inner(area);
+        frame.render_widget(
+            Paragraph::new(self.value.as_str())
+                .style(style)
+                .block(block),
+            area,
+        );
+
+        if focused && inner.width > 0 && inner.height > 0 {
+            let before_cursor = &self.value[..self.byte_index(self.cursor)];
+            let column = (before_cursor.width() as u16).min(inner.width - 1);
+            frame.set_cursor_position(Position::new(inner.x + column, inner.y));
+        }
+    }
+}
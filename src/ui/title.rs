@@ -0,0 +1,74 @@
+//! Titre de la fenêtre du terminal pendant une session TermPlay.
+//!
+//! Le titre d'origine est empilé (séquence xterm `CSI 22 t`) à l'activation et
+//! restauré (`CSI 23 t`) à la fermeture ; les terminaux qui ne gèrent pas la
+//! pile ignorent simplement ces séquences.
+
+use crossterm::{execute, terminal::SetTitle, Command};
+use std::fmt;
+use std::io;
+
+/// Sauvegarde le titre courant sur la pile du terminal
+struct PushTitle;
+
+impl Command for PushTitle {
+    fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        f.write_str("\x1B[22;0t")
+    }
+
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Restaure le dernier titre sauvegardé sur la pile du terminal
+struct PopTitle;
+
+impl Command for PopTitle {
+    fn write_ansi(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        f.write_str("\x1B[23;0t")
+    }
+
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Met à jour le titre du terminal si l'option est activée dans la config
+pub struct TerminalTitle {
+    enabled: bool,
+    current: Option<String>,
+}
+
+impl TerminalTitle {
+    pub fn new(enabled: bool) -> Self {
+        if enabled {
+            let _ = execute!(io::stdout(), PushTitle);
+        }
+
+        Self {
+            enabled,
+            current: None,
+        }
+    }
+
+    /// Change le titre ; ne réécrit rien si le titre n'a pas changé
+    pub fn set(&mut self, title: &str) {
+        if !self.enabled || self.current.as_deref() == Some(title) {
+            return;
+        }
+
+        let _ = execute!(io::stdout(), SetTitle(title));
+        self.current = Some(title.to_string());
+    }
+}
+
+impl Drop for TerminalTitle {
+    fn drop(&mut self) {
+        if self.enabled {
+            let _ = execute!(io::stdout(), PopTitle);
+        }
+    }
+}
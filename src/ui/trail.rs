@@ -0,0 +1,113 @@
+//! Traînée d'une balle rapide : ses dernières positions, de plus en plus
+//! pâles, pour la suivre des yeux malgré la faible cadence du terminal.
+//!
+//! Le jeu ajoute la position de la balle à chaque mise à jour avec
+//! [`Trail::push`] et dessine la traînée sous la balle. Les positions sont en
+//! coordonnées du terrain : le jeu fournit leur conversion en case d'écran au
+//! moment de dessiner, ce qui couvre aussi le terrain vertical de Pong.
+
+use ratatui::{buffer::Buffer, layout::Rect, style::Color};
+use std::collections::VecDeque;
+
+/// Aspect de la traînée, de la position la plus récente à la plus ancienne
+const GLYPHS: [char; 3] = ['•', '∙', '·'];
+
+#[derive(Debug, Default)]
+pub struct Trail {
+    /// Positions passées, la plus récente en tête
+    points: VecDeque<(f32, f32)>,
+    length: usize,
+}
+
+impl Trail {
+    /// Traînée des `length` dernières positions ; aucune si `length` vaut 0
+    pub fn new(length: usize) -> Self {
+        Self {
+            points: VecDeque::with_capacity(length + 1),
+            length,
+        }
+    }
+
+    /// Retient la position de la balle après une mise à jour
+    pub fn push(&mut self, x: f32, y: f32) {
+        if self.length == 0 {
+            return;
+        }
+        self.points.push_front((x, y));
+        self.points.truncate(self.length + 1);
+    }
+
+    /// Oublie la traînée, quand la balle est replacée au service
+    pub fn clear(&mut self) {
+        self.points.clear();
+    }
+
+    /// Dessine la traînée de la couleur `color`, qui s'éteint vers le fond ;
+    /// `to_screen` donne la case d'écran d'une position du terrain. La case
+    /// de la balle elle-même (la position la plus récente) reste libre
+    pub fn render(
+        &self,
+        buffer: &mut Buffer,
+        bounds: Rect,
+        (r, g, b): (u8, u8, u8),
+        to_screen: impl Fn(f32, f32) -> (u16, u16),
+    ) {
+        let bounds = bounds.intersection(buffer.area);
+        let Some(&(ball_x, ball_y)) = self.points.front() else {
+            return;
+        };
+        let ball = to_screen(ball_x, ball_y);
+        // Des plus anciennes aux plus récentes, pour que les plus vives
+        // recouvrent les autres dans une même case
+        for (age, &(x, y)) in self.points.iter().enumerate().skip(1).rev() {
+            if x < 0.0 || y < 0.0 {
+                continue;
+            }
+            let cell = to_screen(x, y);
+            if cell == ball || !bounds.contains(cell.into()) {
+                continue;
+            }
+            let intensity = 1.0 - age as f32 / (self.length + 1) as f32;
+            let fade = |channel: u8| (f32::from(channel) * intensity) as u8;
+            let glyph = GLYPHS[(age - 1) * GLYPHS.len() / self.length];
+            buffer[cell]
+                .set_char(glyph)
+                .set_fg(Color::Rgb(fade(r), fade(g), fade(b)));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_trail_keeps_the_last_positions_and_fades_behind_the_ball() {
+        let mut trail = Trail::new(3);
+        for x in 0..6 {
+            trail.push(x as f32, 1.0);
+        }
+
+        let area = Rect::new(0, 0, 8, 3);
+        let mut buffer = Buffer::empty(area);
+        trail.render(&mut buffer, area, (0, 200, 200), |x, y| {
+            (x as u16, y as u16)
+        });
+
+        // Balle en 5 : la traînée couvre 2, 3 et 4, de plus en plus vive
+        let lit: Vec<u16> = (0..8).filter(|&x| buffer[(x, 1)].symbol() != " ").collect();
+        assert_eq!(lit, vec![2, 3, 4]);
+        let green = |x: u16| match buffer[(x, 1)].fg {
+            Color::Rgb(_, g, _) => g,
+            other => panic!("unexpected color {other:?}"),
+        };
+        assert!(green(2) < green(3) && green(3) < green(4));
+
+        trail.clear();
+        let mut buffer = Buffer::empty(area);
+        trail.render(&mut buffer, area, (0, 200, 200), |x, y| {
+            (x as u16, y as u16)
+        });
+        assert!(buffer.content.iter().all(|cell| cell.symbol() == " "));
+    }
+}
@@ -0,0 +1,90 @@
+//! Barre de volume affichée quelques instants par-dessus le jeu quand le
+//! joueur règle le son avec '+', '-' ou F10.
+
+use crate::core::clock::Instant;
+use crate::ui::{glyphs, style};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style, Stylize},
+    text::Line,
+    widgets::{Block, Clear, Paragraph},
+    Frame,
+};
+use std::time::Duration;
+
+/// Durée d'affichage après le dernier appui
+const DISPLAY_DURATION: Duration = Duration::from_millis(1500);
+/// Cases de la barre, une par pas de volume
+const BAR_CELLS: usize = 10;
+
+pub struct VolumeOsd {
+    /// Volume, son coupé et instant du dernier réglage
+    shown: Option<(f32, bool, Instant)>,
+}
+
+impl VolumeOsd {
+    pub fn new() -> Self {
+        Self { shown: None }
+    }
+
+    /// Affiche (ou prolonge) la barre avec le nouveau réglage
+    pub fn show(&mut self, volume: f32, muted: bool) {
+        self.shown = Some((volume, muted, Instant::now()));
+    }
+
+    /// Dessine la barre en haut de l'écran tant qu'elle n'a pas expiré
+    pub fn draw(&mut self, frame: &mut Frame) {
+        let Some((volume, muted, shown_at)) = self.shown else {
+            return;
+        };
+        if shown_at.elapsed() >= DISPLAY_DURATION {
+            self.shown = None;
+            return;
+        }
+
+        let area = frame.area();
+        let width = 30.min(area.width);
+        let height = 3.min(area.height);
+        let popup = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + 1.min(area.height - height),
+            width,
+            height,
+        };
+
+        let line = if muted {
+            Line::from(vec![
+                format!("{} ", glyphs::icon("🔇")).red().bold(),
+                "Muted".red().bold(),
+                "  F10 to unmute".dark_gray(),
+            ])
+        } else {
+            let filled = ((volume * BAR_CELLS as f32).round() as usize).min(BAR_CELLS);
+            Line::from(vec![
+                format!("{} ", glyphs::icon("🔊")).cyan().bold(),
+                "█".repeat(filled).cyan(),
+                "░".repeat(BAR_CELLS - filled).fg(Color::DarkGray),
+                format!(" {:>3}%", (volume * 100.0).round() as u8)
+                    .fg(style::text())
+                    .bold(),
+            ])
+        };
+
+        frame.render_widget(Clear, popup);
+        frame.render_widget(
+            Paragraph::new(line).centered().block(
+                Block::bordered()
+                    .title(" Volume ".fg(style::text()).bold())
+                    .border_style(Style::new().cyan())
+                    .style(Style::default().bg(style::surface())),
+            ),
+            popup,
+        );
+    }
+}
+
+impl Default for VolumeOsd {
+    fn default() -> Self {
+        Self::new()
+    }
+}
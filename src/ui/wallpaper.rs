@@ -0,0 +1,242 @@
+//! Fond animé du menu : une soupe du Jeu de la Vie ou des pièces de Tetris
+//! qui tombent, dessinées en couleur sourde dans les cases vides des
+//! panneaux.
+//!
+//! L'animation avance lentement, au moment de dessiner, et s'arrête avec le
+//! réglage "Reduce Motion".
+
+use crate::config::MenuBackground;
+use crate::core::clock::Instant;
+use crate::core::life::{self, CellState};
+use crate::core::rng;
+use crate::games::tetris;
+use crate::ui::{animation, style};
+use rand::Rng;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier},
+};
+use std::time::Duration;
+
+/// Intervalle entre deux générations de la soupe
+const LIFE_STEP: Duration = Duration::from_millis(500);
+/// Intervalle entre deux descentes des pièces
+const PIECES_STEP: Duration = Duration::from_millis(250);
+/// Colonnes du terminal par case
+const CELL_WIDTH: u16 = 2;
+/// Proportion de cases vivantes d'une nouvelle soupe
+const SOUP_DENSITY: f64 = 0.3;
+/// Générations à population constante avant de relancer une soupe figée
+const STALE_GENERATIONS: u32 = 12;
+/// Chance, à chaque descente, qu'une pièce apparaisse, pour 80 colonnes
+const PIECE_SPAWN_CHANCE: f64 = 0.25;
+
+struct Soup {
+    cells: Vec<CellState>,
+    scratch: Vec<CellState>,
+    population: usize,
+    stale: u32,
+}
+
+struct FallingPiece {
+    blocks: Vec<(u16, u16)>,
+    color: Color,
+    x: u16,
+    y: i32,
+}
+
+enum Scene {
+    Life(Soup),
+    Pieces(Vec<FallingPiece>),
+}
+
+pub struct Wallpaper {
+    background: MenuBackground,
+    /// Taille de la grille en cases
+    size: (u16, u16),
+    scene: Option<Scene>,
+    last_step: Instant,
+}
+
+impl Wallpaper {
+    pub fn new() -> Self {
+        Self {
+            background: MenuBackground::Off,
+            size: (0, 0),
+            scene: None,
+            last_step: Instant::now(),
+        }
+    }
+
+    /// Avance l'animation si son heure est venue, puis la dessine dans les
+    /// cases vides de `buffer`, déjà rempli par le menu
+    pub fn draw(&mut self, buffer: &mut Buffer, background: MenuBackground) {
+        let area = buffer.area;
+        let size = (area.width / CELL_WIDTH, area.height);
+        if background != self.background || size != self.size || self.scene.is_none() {
+            self.background = background;
+            self.size = size;
+            self.scene = new_scene(background, size);
+            self.last_step = Instant::now();
+        }
+
+        let interval = match self.scene {
+            Some(Scene::Life(_)) => LIFE_STEP,
+            Some(Scene::Pieces(_)) => PIECES_STEP,
+            None => return,
+        };
+        if !animation::reduce_motion() && self.last_step.elapsed() >= interval {
+            self.last_step = Instant::now();
+            self.step();
+        }
+
+        match &self.scene {
+            Some(Scene::Life(soup)) => {
+                let width = usize::from(size.0);
+                for (index, _) in soup
+                    .cells
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, &cell)| cell == CellState::Alive)
+                {
+                    let (x, y) = (index % width, index / width);
+                    paint(buffer, area, x as u16, y as u16, "•", dim(Color::Green));
+                }
+            }
+            Some(Scene::Pieces(pieces)) => {
+                for piece in pieces {
+                    for &(dx, dy) in &piece.blocks {
+                        let y = piece.y + i32::from(dy);
+                        if let Ok(y) = u16::try_from(y) {
+                            paint(buffer, area, piece.x + dx, y, "▪", dim(piece.color));
+                        }
+                    }
+                }
+            }
+            None => {}
+        }
+    }
+
+    fn step(&mut self) {
+        let (width, height) = (usize::from(self.size.0), usize::from(self.size.1));
+        match &mut self.scene {
+            Some(Scene::Life(soup)) => {
+                life::next_generation_serial(&soup.cells, &mut soup.scratch, width, width, height);
+                std::mem::swap(&mut soup.cells, &mut soup.scratch);
+                let population = count_alive(&soup.cells);
+                soup.stale = if population == soup.population {
+                    soup.stale + 1
+                } else {
+                    0
+                };
+                soup.population = population;
+                // Soupe éteinte ou réduite à des motifs stables : on recommence
+                if population < width * height / 50 || soup.stale >= STALE_GENERATIONS {
+                    self.scene = new_scene(MenuBackground::Life, self.size);
+                }
+            }
+            Some(Scene::Pieces(pieces)) => {
+                for piece in pieces.iter_mut() {
+                    piece.y += 1;
+                }
+                pieces.retain(|piece| piece.y < height as i32);
+                let chance = (PIECE_SPAWN_CHANCE * width as f64 / 40.0).min(1.0);
+                if width >= 4 && rng::rng().random_bool(chance) {
+                    let (blocks, color) = tetris::random_piece_blocks();
+                    pieces.push(FallingPiece {
+                        blocks,
+                        color,
+                        x: rng::rng().random_range(0..self.size.0 - 3),
+                        y: -4,
+                    });
+                }
+            }
+            None => {}
+        }
+    }
+}
+
+impl Default for Wallpaper {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn new_scene(background: MenuBackground, (width, height): (u16, u16)) -> Option<Scene> {
+    match background {
+        MenuBackground::Off => None,
+        MenuBackground::Life => {
+            let mut rng = rng::rng();
+            let cells: Vec<CellState> = (0..usize::from(width) * usize::from(height))
+                .map(|_| {
+                    if rng.random_bool(SOUP_DENSITY) {
+                        CellState::Alive
+                    } else {
+                        CellState::Dead
+                    }
+                })
+                .collect();
+            Some(Scene::Life(Soup {
+                population: count_alive(&cells),
+                scratch: cells.clone(),
+                cells,
+                stale: 0,
+            }))
+        }
+        MenuBackground::Tetris => Some(Scene::Pieces(Vec::new())),
+    }
+}
+
+fn count_alive(cells: &[CellState]) -> usize {
+    cells
+        .iter()
+        .filter(|&&cell| cell == CellState::Alive)
+        .count()
+}
+
+/// Couleur assourdie : à peine visible sur le fond des panneaux
+fn dim(color: Color) -> Color {
+    if !matches!(style::background(), Color::Rgb(..)) {
+        return Color::DarkGray;
+    }
+    let (r, g, b) = match color {
+        Color::Rgb(r, g, b) => (r, g, b),
+        Color::Red => (200, 0, 0),
+        Color::Green => (0, 200, 0),
+        Color::Yellow => (200, 200, 0),
+        Color::Blue => (0, 0, 200),
+        Color::Magenta => (200, 0, 200),
+        Color::Cyan => (0, 200, 200),
+        _ => (120, 120, 120),
+    };
+    Color::Rgb(r / 5 + 15, g / 5 + 20, b / 5 + 25)
+}
+
+/// Dessine `symbol` dans la case `(x, y)` si ses deux colonnes sont vides et
+/// ne font pas partie d'une sélection
+fn paint(buffer: &mut Buffer, area: Rect, x: u16, y: u16, symbol: &str, color: Color) {
+    let column = area.x + x * CELL_WIDTH;
+    let row = area.y + y;
+    if column + 1 >= area.right() || row >= area.bottom() {
+        return;
+    }
+    let backgrounds = [
+        style::background(),
+        style::panel(),
+        style::surface(),
+        Color::Reset,
+    ];
+    let free = (column..column + CELL_WIDTH).all(|x| {
+        let cell = &buffer[(x, row)];
+        cell.symbol() == " "
+            && backgrounds.contains(&cell.bg)
+            && !cell.modifier.contains(Modifier::REVERSED)
+    });
+    if free {
+        let cell = &mut buffer[(column, row)];
+        cell.set_symbol(symbol);
+        cell.set_fg(color);
+        cell.modifier.remove(Modifier::BOLD);
+    }
+}
@@ -0,0 +1,153 @@
+//! Surveillance des parties qui ne répondent plus.
+//!
+//! La boucle de jeu mesure chaque tick (mise à jour et dessin). Quand
+//! plusieurs ticks d'affilée dépassent [`SLOW_TICK`], la partie est figée et
+//! un avertissement propose de revenir au menu. Tout se passe sur le fil de
+//! la boucle : un tick bloqué indéfiniment ne peut pas être interrompu, seul
+//! un état qui ralentit chaque tick est repéré.
+
+use crate::ui::{glyphs, style};
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Style, Stylize},
+    text::Line,
+    widgets::{Block, Clear, Paragraph},
+    Frame,
+};
+use std::time::Duration;
+
+/// Durée d'un tick au-delà de laquelle il compte comme lent
+pub const SLOW_TICK: Duration = Duration::from_millis(250);
+/// Ticks lents d'affilée avant l'avertissement
+const SLOW_STREAK: u32 = 3;
+
+pub struct Watchdog {
+    /// Ticks lents consécutifs
+    streak: u32,
+    /// Durée du tick le plus lent de la série en cours
+    slowest: Duration,
+    /// Avertissement affiché, avec la durée du tick le plus lent
+    warning: Option<Duration>,
+    /// Avertissement écarté : pas de nouvel avertissement avant qu'un tick
+    /// rapide montre que la partie s'est remise
+    dismissed: bool,
+}
+
+impl Watchdog {
+    pub fn new() -> Self {
+        Self {
+            streak: 0,
+            slowest: Duration::ZERO,
+            warning: None,
+            dismissed: false,
+        }
+    }
+
+    /// Durée d'un tick ; renvoie la durée du tick le plus lent quand la série
+    /// déclenche l'avertissement
+    pub fn record(&mut self, elapsed: Duration) -> Option<Duration> {
+        if elapsed < SLOW_TICK {
+            self.streak = 0;
+            self.slowest = Duration::ZERO;
+            self.dismissed = false;
+            return None;
+        }
+        self.streak += 1;
+        self.slowest = self.slowest.max(elapsed);
+        if self.streak < SLOW_STREAK || self.dismissed || self.warning.is_some() {
+            return None;
+        }
+        self.warning = Some(self.slowest);
+        self.warning
+    }
+
+    pub fn is_warning(&self) -> bool {
+        self.warning.is_some()
+    }
+
+    /// Le joueur continue la partie malgré l'avertissement
+    pub fn dismiss(&mut self) {
+        self.warning = None;
+        self.dismissed = true;
+        self.streak = 0;
+    }
+
+    /// Dessine l'avertissement au centre de l'écran
+    pub fn draw(&self, frame: &mut Frame) {
+        let Some(slowest) = self.warning else {
+            return;
+        };
+        let area = frame.area();
+        let width = 44.min(area.width);
+        let height = 6.min(area.height);
+        let popup = Rect {
+            x: area.x + (area.width - width) / 2,
+            y: area.y + (area.height - height) / 2,
+            width,
+            height,
+        };
+
+        let text = vec![
+            Line::from(
+                format!("{} NOT RESPONDING", glyphs::icon("⚠️"))
+                    .red()
+                    .bold(),
+            ),
+            Line::from(format!("Each tick takes up to {} ms", slowest.as_millis()).gray()),
+            Line::from(""),
+            Line::from(vec![
+                "Q".yellow().bold(),
+                " back to menu  ".gray(),
+                "Any key".yellow().bold(),
+                " keep playing".gray(),
+            ]),
+        ];
+
+        frame.render_widget(Clear, popup);
+        frame.render_widget(
+            Paragraph::new(text).alignment(Alignment::Center).block(
+                Block::bordered()
+                    .border_style(Style::new().red())
+                    .style(Style::default().bg(style::surface())),
+            ),
+            popup,
+        );
+    }
+}
+
+impl Default for Watchdog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn warns_after_a_streak_of_slow_ticks_until_the_game_recovers() {
+        let slow = |ms| SLOW_TICK + Duration::from_millis(ms);
+        let mut watchdog = Watchdog::new();
+
+        // Un tick rapide remet la série à zéro
+        assert_eq!(watchdog.record(slow(10)), None);
+        assert_eq!(watchdog.record(slow(20)), None);
+        assert_eq!(watchdog.record(Duration::from_millis(16)), None);
+        assert_eq!(watchdog.record(slow(10)), None);
+        assert_eq!(watchdog.record(slow(500)), None);
+        assert_eq!(watchdog.record(slow(20)), Some(slow(500)));
+        assert!(watchdog.is_warning());
+
+        // Écarté, il ne revient pas tant que la partie reste lente
+        watchdog.dismiss();
+        for _ in 0..5 {
+            assert_eq!(watchdog.record(slow(0)), None);
+        }
+        assert_eq!(watchdog.record(Duration::ZERO), None);
+        for _ in 0..2 {
+            assert_eq!(watchdog.record(slow(0)), None);
+        }
+        assert_eq!(watchdog.record(slow(0)), Some(slow(0)));
+    }
+}
@@ -0,0 +1,89 @@
+//! Vérification des mises à jour en arrière-plan pour l'écran About.
+
+use crate::jobs::{Job, JobPoll};
+
+/// Résultat de la vérification de mise à jour
+#[cfg_attr(not(feature = "self-update"), allow(dead_code))]
+#[derive(Debug, Clone, PartialEq)]
+pub enum UpdateStatus {
+    Checking,
+    UpToDate,
+    Available(String),
+    /// Build sans self-update, installation non gérée par l'updater ou
+    /// vérification impossible (hors ligne…)
+    Unavailable,
+}
+
+/// Lance la vérification en tâche de fond pour ne pas bloquer l'interface
+pub struct UpdateChecker {
+    job: Option<Job<UpdateStatus>>,
+    status: UpdateStatus,
+}
+
+impl UpdateChecker {
+    #[cfg(feature = "self-update")]
+    pub fn spawn() -> Self {
+        let job = crate::jobs::spawn("Checking for updates", || {
+            check_for_update().unwrap_or(UpdateStatus::Unavailable)
+        });
+
+        Self {
+            job: Some(job),
+            status: UpdateStatus::Checking,
+        }
+    }
+
+    #[cfg(not(feature = "self-update"))]
+    pub fn spawn() -> Self {
+        Self {
+            job: None,
+            status: UpdateStatus::Unavailable,
+        }
+    }
+
+    /// Dernier état connu, mis à jour dès que la tâche a répondu
+    pub fn status(&mut self) -> &UpdateStatus {
+        if let Some(job) = &self.job {
+            match job.poll() {
+                JobPoll::Running => {}
+                JobPoll::Finished(status) => {
+                    self.status = status;
+                    self.job = None;
+                }
+                JobPoll::Lost => {
+                    self.status = UpdateStatus::Unavailable;
+                    self.job = None;
+                }
+            }
+        }
+        &self.status
+    }
+}
+
+#[cfg(feature = "self-update")]
+fn check_for_update() -> Result<UpdateStatus, Box<dyn std::error::Error>> {
+    use axoupdater::AxoUpdater;
+    use tokio::runtime::Runtime;
+
+    let rt = Runtime::new()?;
+    let mut updater = AxoUpdater::new_for("termplay");
+    updater.load_receipt()?;
+
+    rt.block_on(query_update(&mut updater))
+}
+
+#[cfg(feature = "self-update")]
+async fn query_update(
+    updater: &mut axoupdater::AxoUpdater,
+) -> Result<UpdateStatus, Box<dyn std::error::Error>> {
+    if !updater.is_update_needed().await? {
+        return Ok(UpdateStatus::UpToDate);
+    }
+
+    let version = updater
+        .query_new_version()
+        .await?
+        .map(|version| version.to_string())
+        .unwrap_or_default();
+    Ok(UpdateStatus::Available(version))
+}
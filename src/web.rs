@@ -0,0 +1,126 @@
+//! Adaptateur pour terminal de navigateur (xterm.js), activé par la feature
+//! `web`.
+//!
+//! Une [`WebSession`] fait tourner un jeu sans terminal réel : elle reçoit
+//! les données tapées telles que les envoie `Terminal.onData` d'xterm.js,
+//! fait avancer le jeu au rythme des appels à [`WebSession::tick`] et
+//! retourne les séquences ANSI à passer à `Terminal.write`. Seules les
+//! cellules modifiées depuis le dernier envoi sont réécrites.
+//!
+//! La boucle d'événements et l'horloge appartiennent à la page : l'adaptateur
+//! ne lit ni stdin ni l'heure système. Avec `--no-default-features
+//! --features web`, la bibliothèque se compile pour `wasm32-unknown-unknown`,
+//! où les jeux sont muets (voir [`GameRegistry::silent`]).
+//!
+//! Pour la page, `wasm-bindgen` exporte la classe JavaScript `WebSession` :
+//! `new WebSession(name, cols, rows)` puis `input`, `tick(ms)`, `resize` et
+//! `render`.
+
+use crate::core::{Game, GameAction};
+use crate::games::GameRegistry;
+use crate::ui::ansi;
+use ratatui::{backend::TestBackend, buffer::Buffer, layout::Rect, Terminal};
+use std::time::Duration;
+use wasm_bindgen::prelude::*;
+
+pub use crate::input::decode_keys;
+
+/// Partie en cours dans un terminal de navigateur
+#[wasm_bindgen]
+pub struct WebSession {
+    game: Box<dyn Game>,
+    terminal: Terminal<TestBackend>,
+    /// Écran tel qu'il a été envoyé au navigateur
+    shown: Buffer,
+    /// Temps écoulé depuis le dernier `update` du jeu
+    since_update: Duration,
+    /// Tout l'écran doit être réécrit au prochain rendu
+    full_redraw: bool,
+}
+
+impl WebSession {
+    /// Démarre le jeu désigné par `name` dans un terminal de `cols`×`rows`
+    pub fn new(name: &str, cols: u16, rows: u16) -> Option<Self> {
+        let game = GameRegistry::silent().get_game(name)?;
+        let terminal = Terminal::new(TestBackend::new(cols, rows)).ok()?;
+
+        Some(Self {
+            game,
+            terminal,
+            shown: Buffer::empty(Rect::new(0, 0, cols, rows)),
+            since_update: Duration::ZERO,
+            full_redraw: true,
+        })
+    }
+
+    /// Fait avancer le jeu de `elapsed` ; retourne `false` quand la partie
+    /// est terminée
+    pub fn tick(&mut self, elapsed: Duration) -> bool {
+        self.since_update += elapsed;
+        let tick_rate = self.game.tick_rate();
+        if self.since_update < tick_rate {
+            return true;
+        }
+
+        self.since_update = Duration::ZERO;
+        self.game.update() == GameAction::Continue
+    }
+}
+
+/// Interface exportée vers JavaScript
+#[wasm_bindgen]
+impl WebSession {
+    /// `new WebSession(name, cols, rows)` ; lève une erreur si le jeu
+    /// n'existe pas
+    #[wasm_bindgen(constructor)]
+    pub fn start(name: &str, cols: u16, rows: u16) -> Result<WebSession, JsError> {
+        Self::new(name, cols, rows).ok_or_else(|| JsError::new(&format!("unknown game: {name}")))
+    }
+
+    /// Version de [`WebSession::tick`] pour `requestAnimationFrame`, qui
+    /// compte en millisecondes
+    #[wasm_bindgen(js_name = tick)]
+    pub fn tick_millis(&mut self, elapsed_ms: f64) -> bool {
+        self.tick(Duration::try_from_secs_f64(elapsed_ms / 1000.0).unwrap_or_default())
+    }
+
+    /// À appeler depuis `Terminal.onResize`
+    pub fn resize(&mut self, cols: u16, rows: u16) {
+        self.terminal.backend_mut().resize(cols, rows);
+        let _ = self.terminal.resize(Rect::new(0, 0, cols, rows));
+        self.shown = Buffer::empty(Rect::new(0, 0, cols, rows));
+        self.full_redraw = true;
+    }
+
+    /// Transmet au jeu les données reçues de `Terminal.onData` ; retourne
+    /// `false` quand la partie est terminée
+    pub fn input(&mut self, data: &str) -> bool {
+        decode_keys(data)
+            .into_iter()
+            .all(|key| self.game.handle_key(key) == GameAction::Continue)
+    }
+
+    /// Dessine le jeu et retourne les séquences ANSI qui mettent l'écran du
+    /// navigateur à jour
+    pub fn render(&mut self) -> String {
+        let game = &mut self.game;
+        if self
+            .terminal
+            .draw(|frame| game.draw(frame, frame.area()))
+            .is_err()
+        {
+            return String::new();
+        }
+
+        let current = self.terminal.backend().buffer().clone();
+        let mut out = String::new();
+        if self.full_redraw {
+            // Effacer l'écran et masquer le curseur
+            out.push_str("\x1b[0m\x1b[2J\x1b[?25l");
+            self.full_redraw = false;
+        }
+        ansi::encode_diff(&self.shown, &current, &mut out);
+        self.shown = current;
+        out
+    }
+}
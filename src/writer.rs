@@ -0,0 +1,259 @@
+//! File d'écriture en arrière-plan des fichiers de données.
+//!
+//! Les sauvegardes des high scores et de la progression sont confiées à un
+//! thread d'écriture pour ne jamais bloquer la boucle de rendu, même sur un
+//! disque lent ou un partage réseau. Seul le dernier contenu demandé pour un
+//! fichier est écrit, et il l'est d'abord dans un fichier temporaire renommé
+//! ensuite par-dessus l'ancien : une écriture interrompue ne laisse jamais un
+//! fichier à moitié écrit.
+//!
+//! Les fichiers journaux comme l'historique des parties sont complétés par
+//! [`append`] : les lignes ajoutées s'accumulent dans la file et sont écrites
+//! en fin de fichier d'un seul coup.
+//!
+//! Les lectures passent par [`read_to_string`], qui voit les écritures encore
+//! en file.
+
+use crate::core::clock::Instant;
+use std::collections::BTreeMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Condvar, Mutex, OnceLock};
+use std::thread;
+use std::time::Duration;
+
+/// Temps maximal d'attente des écritures en file à la fermeture
+const FLUSH_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Opération en attente sur un fichier
+#[derive(Clone)]
+enum Pending {
+    /// Remplace tout le contenu
+    Replace(String),
+    /// Ajoute en fin de fichier
+    Append(String),
+    Delete,
+}
+
+impl Pending {
+    /// Opération équivalente à `self` suivie de `next`
+    fn then(self, next: Pending) -> Pending {
+        match (self, next) {
+            (Pending::Replace(mut contents), Pending::Append(more)) => {
+                contents.push_str(&more);
+                Pending::Replace(contents)
+            }
+            (Pending::Append(mut contents), Pending::Append(more)) => {
+                contents.push_str(&more);
+                Pending::Append(contents)
+            }
+            (Pending::Delete, Pending::Append(more)) => Pending::Replace(more),
+            (_, next) => next,
+        }
+    }
+
+    /// Contenu obtenu en appliquant l'opération à `current`
+    fn apply(&self, current: io::Result<String>) -> io::Result<String> {
+        match self {
+            Pending::Replace(contents) => Ok(contents.clone()),
+            Pending::Append(more) => match current {
+                Ok(mut contents) => {
+                    contents.push_str(more);
+                    Ok(contents)
+                }
+                Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(more.clone()),
+                Err(e) => Err(e),
+            },
+            Pending::Delete => Err(io::Error::from(io::ErrorKind::NotFound)),
+        }
+    }
+}
+
+#[derive(Default)]
+struct State {
+    queued: BTreeMap<PathBuf, Pending>,
+    /// Fichier en cours d'écriture par le thread
+    in_flight: Option<PathBuf>,
+}
+
+#[derive(Default)]
+struct WriteQueue {
+    state: Mutex<State>,
+    changed: Condvar,
+}
+
+static QUEUE: OnceLock<&'static WriteQueue> = OnceLock::new();
+
+/// File d'écriture, avec son thread démarré au premier appel
+fn queue() -> &'static WriteQueue {
+    QUEUE.get_or_init(|| {
+        let queue: &'static WriteQueue = Box::leak(Box::default());
+        let spawned = thread::Builder::new()
+            .name("data-writer".to_string())
+            .spawn(move || run(queue));
+        if spawned.is_err() {
+            eprintln!("Impossible de démarrer le thread d'écriture, sauvegardes désactivées");
+        }
+        queue
+    })
+}
+
+fn run(queue: &WriteQueue) {
+    loop {
+        let mut state = queue.state.lock().unwrap();
+        while state.queued.is_empty() {
+            state = queue.changed.wait(state).unwrap();
+        }
+        let (path, pending) = state.queued.pop_first().unwrap();
+        state.in_flight = Some(path.clone());
+        drop(state);
+
+        let result = match &pending {
+            Pending::Replace(contents) => write_atomic(&path, contents),
+            Pending::Append(contents) => append_to(&path, contents),
+            Pending::Delete => remove(&path),
+        };
+        if let Err(e) = result {
+            eprintln!("Erreur lors de l'écriture de {}: {e}", path.display());
+        }
+
+        queue.state.lock().unwrap().in_flight = None;
+        queue.changed.notify_all();
+    }
+}
+
+/// Écrit dans un fichier temporaire, le synchronise puis le renomme
+fn write_atomic(path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let mut temp_name = path.file_name().unwrap_or_default().to_os_string();
+    temp_name.push(".tmp");
+    let temp_path = path.with_file_name(temp_name);
+
+    let mut file = File::create(&temp_path)?;
+    file.write_all(contents.as_bytes())?;
+    file.sync_all()?;
+    fs::rename(&temp_path, path)
+}
+
+/// Ajoute en fin de fichier, en le créant au besoin
+fn append_to(path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    file.write_all(contents.as_bytes())?;
+    file.sync_all()
+}
+
+fn remove(path: &Path) -> io::Result<()> {
+    match fs::remove_file(path) {
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+        result => result,
+    }
+}
+
+fn push(path: PathBuf, pending: Pending) {
+    let queue = queue();
+    let mut state = queue.state.lock().unwrap();
+    let pending = match state.queued.remove(&path) {
+        Some(queued) => queued.then(pending),
+        None => pending,
+    };
+    state.queued.insert(path, pending);
+    drop(state);
+    queue.changed.notify_all();
+}
+
+/// Programme l'écriture de `contents` dans `path`, en remplaçant une écriture
+/// du même fichier encore en file
+pub fn write(path: PathBuf, contents: String) {
+    push(path, Pending::Replace(contents));
+}
+
+/// Programme l'ajout de `contents` à la fin de `path`
+pub fn append(path: PathBuf, contents: String) {
+    push(path, Pending::Append(contents));
+}
+
+/// Programme la suppression de `path`
+pub fn delete(path: PathBuf) {
+    push(path, Pending::Delete);
+}
+
+/// Contenu de `path`, en tenant compte des écritures encore en file
+pub fn read_to_string(path: &Path) -> io::Result<String> {
+    let Some(queue) = QUEUE.get() else {
+        return fs::read_to_string(path);
+    };
+
+    // Un ajout en cours d'écriture peut être à moitié sur le disque : attendre
+    // qu'il soit terminé avant de lire
+    let mut state = queue.state.lock().unwrap();
+    while state.in_flight.as_deref() == Some(path) {
+        state = queue.changed.wait(state).unwrap();
+    }
+    match state.queued.get(path) {
+        Some(Pending::Replace(contents)) => Ok(contents.clone()),
+        Some(Pending::Delete) => Err(io::Error::from(io::ErrorKind::NotFound)),
+        Some(pending) => pending.apply(fs::read_to_string(path)),
+        None => fs::read_to_string(path),
+    }
+}
+
+/// Attend la fin des écritures en file, au plus `FLUSH_TIMEOUT`
+pub fn flush() {
+    let Some(queue) = QUEUE.get() else {
+        return;
+    };
+
+    // Aussi appelé depuis le hook de panic : ne jamais paniquer ici
+    let deadline = Instant::now() + FLUSH_TIMEOUT;
+    let Ok(mut state) = queue.state.lock() else {
+        return;
+    };
+    while !state.queued.is_empty() || state.in_flight.is_some() {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return;
+        }
+        let Ok((next, _)) = queue.changed.wait_timeout(state, remaining) else {
+            return;
+        };
+        state = next;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flush_leaves_every_queued_operation_on_disk() {
+        let dir = std::env::temp_dir().join(format!("termplay-writer-{}", std::process::id()));
+        let replaced = dir.join("scores.json");
+        let appended = dir.join("history.jsonl");
+        let deleted = dir.join("saves.json");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(&deleted, "{}").unwrap();
+
+        write(replaced.clone(), "old".to_string());
+        write(replaced.clone(), "new".to_string());
+        append(appended.clone(), "a\n".to_string());
+        append(appended.clone(), "b\n".to_string());
+        delete(deleted.clone());
+        flush();
+
+        let state = queue().state.lock().unwrap();
+        assert!(state.queued.is_empty() && state.in_flight.is_none());
+        drop(state);
+        assert_eq!(fs::read_to_string(&replaced).unwrap(), "new");
+        assert_eq!(fs::read_to_string(&appended).unwrap(), "a\nb\n");
+        assert!(!deleted.exists());
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
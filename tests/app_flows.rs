@@ -0,0 +1,508 @@
+//! Parcours complets de l'application dans un terminal virtuel : menu, jeux
+//! et fin de partie, vérifiés sur les écrans dessinés.
+
+mod common;
+
+use common::{
+    app, char_keys, contains, guest_app, key, selected_line, ScriptedFrontend, SCRIPT_END,
+};
+use crossterm::event::KeyCode;
+use std::io::{self, Write};
+use std::sync::{Arc, Mutex};
+use termplay::config::ConfigManager;
+use termplay::frontend::StreamFrontend;
+
+#[test]
+fn quitting_from_the_main_menu_ends_cleanly() {
+    let mut frontend = ScriptedFrontend::new(100, 40, [key(KeyCode::Char('q'))]);
+
+    app().run_menu_on(&mut frontend).unwrap();
+
+    assert!(contains(
+        frontend.last_screen(),
+        "Terminal Mini-Games Collection"
+    ));
+    assert!(contains(frontend.last_screen(), "Main Menu"));
+}
+
+#[test]
+fn a_guest_session_cannot_touch_the_data_folder() {
+    let mut frontend = ScriptedFrontend::new(
+        120,
+        40,
+        [
+            key(KeyCode::Char('g')),
+            key(KeyCode::Char('s')),
+            key(KeyCode::BackTab),
+            key(KeyCode::End),
+            key(KeyCode::Enter),
+            key(KeyCode::Enter),
+            key(KeyCode::Char('q')),
+            key(KeyCode::Char('q')),
+        ],
+    );
+
+    guest_app().run_menu_on(&mut frontend).unwrap();
+
+    let screens = frontend.screens();
+    let data = &screens[3];
+    assert!(contains(data, "Reset Everything (host only)"));
+    assert!(contains(data, "on the host"));
+    assert!(contains(
+        &screens[5],
+        "Only the host can manage the data folder"
+    ));
+    assert!(
+        ConfigManager::new()
+            .unwrap()
+            .get_display_config()
+            .reduce_motion
+    );
+}
+
+#[test]
+fn main_menu_selection_wraps_around() {
+    let mut frontend = ScriptedFrontend::new(
+        100,
+        40,
+        [
+            key(KeyCode::Up),
+            key(KeyCode::Down),
+            key(KeyCode::Char('q')),
+        ],
+    );
+
+    app().run_menu_on(&mut frontend).unwrap();
+
+    let screens = frontend.screens();
+    assert!(selected_line(&screens[0]).unwrap().contains("Games"));
+    // Flèche haut sur la première option : retour à la dernière
+    assert!(selected_line(&screens[1]).unwrap().contains("Quit"));
+    // Flèche bas sur la dernière : retour à la première
+    assert!(selected_line(&screens[2]).unwrap().contains("Games"));
+}
+
+#[test]
+fn g_then_s_jumps_to_settings_and_shows_the_pending_chord() {
+    let mut frontend = ScriptedFrontend::new(
+        100,
+        40,
+        [
+            key(KeyCode::Char('g')),
+            key(KeyCode::Char('s')),
+            key(KeyCode::Char('q')),
+            key(KeyCode::Char('q')),
+        ],
+    );
+
+    app().run_menu_on(&mut frontend).unwrap();
+
+    let screens = frontend.screens();
+    // Après G, les suites possibles sont affichées
+    assert!(screens
+        .iter()
+        .any(|screen| contains(screen, "g…") && contains(screen, "Music Player")));
+    assert!(screens.iter().any(|screen| contains(screen, "SETTINGS")));
+}
+
+#[test]
+fn menu_launches_a_game_and_comes_back_to_the_games_list() {
+    let mut frontend = ScriptedFrontend::new(
+        100,
+        40,
+        [
+            // Main Menu → Games → premier jeu de la liste (2048, par ordre alphabétique)
+            key(KeyCode::Enter),
+            key(KeyCode::Enter),
+            // Quitter la partie, puis le menu Games et le menu principal
+            key(KeyCode::Char('q')),
+            key(KeyCode::Char('q')),
+            key(KeyCode::Char('q')),
+        ],
+    );
+
+    app().run_menu_on(&mut frontend).unwrap();
+
+    let screens = frontend.screens();
+    assert!(contains(&screens[1], "Available Games"));
+    assert!(contains(&screens[2], "2048 GAME"));
+    assert!(contains(&screens[3], "Available Games"));
+    assert!(contains(&screens[4], "Main Menu"));
+}
+
+#[test]
+fn the_games_list_previews_the_selected_game() {
+    let mut frontend = ScriptedFrontend::new(
+        100,
+        40,
+        [
+            key(KeyCode::Enter),
+            key(KeyCode::Char('q')),
+            key(KeyCode::Char('q')),
+        ],
+    );
+
+    app().run_menu_on(&mut frontend).unwrap();
+
+    // 2048 est le premier jeu : sa grille, sa présentation et ses touches à
+    // droite
+    let games = &frontend.screens()[1];
+    assert!(contains(games, "┌ 2048 "));
+    assert!(contains(games, "1024  512"));
+    assert!(contains(games, "Slide the tiles"));
+    assert!(contains(games, "C Keep playing after 2048"));
+}
+
+#[test]
+fn random_game_picks_among_the_games_listed() {
+    let mut script = vec![key(KeyCode::Enter), key(KeyCode::Char('/'))];
+    script.extend(char_keys("breakout"));
+    // "Random Game" suit le seul jeu trouvé
+    script.extend([key(KeyCode::Down), key(KeyCode::Enter)]);
+    let mut frontend = ScriptedFrontend::new(100, 40, script);
+
+    // Le script s'épuise pendant la partie
+    let _ = app().run_menu_on(&mut frontend);
+
+    let screens = frontend.screens();
+    let random = &screens[screens.len() - 2];
+    assert!(contains(random, "RANDOM GAME"));
+    assert!(contains(random, "┌ Random Game "));
+    assert!(contains(screens.last().unwrap(), "BREAKOUT"));
+}
+
+#[test]
+fn modifiers_are_toggled_before_starting_a_game() {
+    let mut frontend = ScriptedFrontend::new(
+        100,
+        40,
+        [
+            key(KeyCode::Enter),
+            key(KeyCode::Char('m')),
+            key(KeyCode::Enter),
+            key(KeyCode::Char('q')),
+            key(KeyCode::Char('q')),
+            key(KeyCode::Char('q')),
+        ],
+    );
+
+    app().run_menu_on(&mut frontend).unwrap();
+
+    // 2048 ne propose que les commandes inversées
+    let screens = frontend.screens();
+    assert!(contains(&screens[2], "MODIFIERS"));
+    assert!(contains(&screens[2], "[ ] Inverted Controls"));
+    assert!(contains(&screens[3], "[✓] Inverted Controls"));
+    assert!(contains(&screens[3], "Start Game with 1 modifier"));
+}
+
+#[test]
+fn the_weekly_challenge_shows_its_modifier_and_leaderboard() {
+    let mut frontend = ScriptedFrontend::new(
+        100,
+        40,
+        [
+            key(KeyCode::Down),
+            key(KeyCode::Enter),
+            key(KeyCode::Char('q')),
+            key(KeyCode::Char('q')),
+        ],
+    );
+
+    app().run_menu_on(&mut frontend).unwrap();
+
+    let screen = &frontend.screens()[2];
+    assert!(contains(screen, "WEEKLY CHALLENGE"));
+    assert!(contains(screen, "Enter Play the challenge"));
+    assert!(contains(screen, "No scores yet"));
+}
+
+#[test]
+fn tetris_shows_the_game_over_popup() {
+    // Empiler les pièces au centre jusqu'à bloquer l'apparition de la suivante
+    let mut events = char_keys(&" ".repeat(100));
+    events.push(key(KeyCode::Char('q')));
+    let mut frontend = ScriptedFrontend::new(100, 40, events);
+
+    app().run_game_on("tetris", &mut frontend).unwrap();
+
+    // "GAME OVER" en grandes lettres : la ligne du haut de G, A, M et E
+    assert!(!contains(&frontend.screens()[0], "▄▀▀ ▄▀▄ █▄█ █▀▀"));
+    assert!(
+        contains(frontend.last_screen(), "▄▀▀ ▄▀▄ █▄█ █▀▀"),
+        "{}",
+        common::lines(frontend.last_screen()).join("\n")
+    );
+    assert!(contains(frontend.last_screen(), "Final Score"));
+}
+
+#[test]
+fn a_new_high_score_is_announced_back_in_the_menu() {
+    // Main Menu → Games → recherche de Tetris (après Puzzles, dont la
+    // description le cite), puis une partie perdue
+    let mut events = vec![key(KeyCode::Enter), key(KeyCode::Char('/'))];
+    events.extend(char_keys("tetris"));
+    events.extend([key(KeyCode::Down), key(KeyCode::Enter)]);
+    events.extend(char_keys(&" ".repeat(100)));
+    events.push(key(KeyCode::Char('q')));
+    let mut frontend = ScriptedFrontend::new(100, 40, events);
+
+    let _ = app().run_menu_on(&mut frontend);
+
+    let screen = frontend.last_screen();
+    assert!(contains(screen, "Available Games"));
+    assert!(contains(screen, "New personal best!") || contains(screen, "New high score"));
+}
+
+#[test]
+fn the_gauntlet_announces_its_stages_and_plays_them_in_the_real_game() {
+    let mut frontend =
+        ScriptedFrontend::new(100, 40, [key(KeyCode::Enter), key(KeyCode::Char('q'))]);
+
+    app().run_game_on("gauntlet", &mut frontend).unwrap();
+
+    let briefing = &frontend.screens()[0];
+    assert!(contains(briefing, "GAUNTLET"));
+    assert!(contains(briefing, "Snake Dash"));
+    assert!(contains(briefing, "Beginner Board"));
+    // Première étape : le vrai Snake, avec le panneau de l'étape
+    let stage = &frontend.screens()[1];
+    assert!(contains(stage, "Stage 1/3"));
+    assert!(contains(stage, "0:30 left") || contains(stage, "0:29 left"));
+}
+
+#[test]
+fn a_tournament_seeds_its_players_and_plays_the_matches_in_pong() {
+    let mut events = char_keys("Ann");
+    events.push(key(KeyCode::Enter));
+    events.extend(char_keys("Bob"));
+    events.push(key(KeyCode::Enter));
+    // Champ vide : tirage du tableau, puis premier match
+    events.push(key(KeyCode::Enter));
+    events.push(key(KeyCode::Enter));
+    let mut frontend = ScriptedFrontend::new(100, 40, events);
+
+    let error = app().run_game_on("tournament", &mut frontend).unwrap_err();
+    assert_eq!(error.to_string(), SCRIPT_END);
+
+    let screens = frontend.screens();
+    let entry = &screens[screens.len() - 3];
+    assert!(contains(entry, "TOURNAMENT"));
+    assert!(contains(entry, "2/8 players"));
+    let bracket = &screens[screens.len() - 2];
+    assert!(contains(bracket, "Final: Ann vs Bob"));
+    assert!(contains(bracket, "Champion"));
+    // Le vrai Pong à deux, avec le panneau du match
+    let game = frontend.last_screen();
+    assert!(contains(game, "Final"));
+    assert!(contains(game, "Ann W/S"));
+    assert!(contains(game, "Bob ↑/↓"));
+}
+
+#[test]
+fn hot_seat_players_take_turns_on_the_same_game() {
+    // 2048, nom du profil pour le joueur 1, "Bob" pour le joueur 2
+    let mut events = vec![key(KeyCode::Enter), key(KeyCode::Enter)];
+    events.extend(char_keys("Bob"));
+    events.push(key(KeyCode::Enter));
+    // Premier essai, abandonné aussitôt
+    events.push(key(KeyCode::Enter));
+    events.push(key(KeyCode::Char('q')));
+    let mut frontend = ScriptedFrontend::new(100, 40, events);
+
+    let error = app().run_game_on("hot-seat", &mut frontend).unwrap_err();
+    assert_eq!(error.to_string(), SCRIPT_END);
+
+    let screens = frontend.screens();
+    let handoff = &screens[screens.len() - 3];
+    assert!(contains(handoff, "HOT SEAT"));
+    assert!(contains(handoff, "Highest score wins"));
+    let turn = &screens[screens.len() - 2];
+    assert!(contains(turn, "Turn 1/2"));
+    // Au tour de Bob, sur la même partie
+    let next = frontend.last_screen();
+    assert!(contains(next, "Pass the keyboard to Bob"));
+    assert!(contains(next, "Game code:"));
+}
+
+#[test]
+fn the_life_tutorial_explains_the_rule_before_and_after_a_step() {
+    let mut frontend = ScriptedFrontend::new(
+        100,
+        40,
+        [
+            key(KeyCode::Char('n')),
+            key(KeyCode::Char('s')),
+            key(KeyCode::Char('q')),
+        ],
+    );
+
+    app().run_game_on("life-tutorial", &mut frontend).unwrap();
+
+    assert!(contains(&frontend.screens()[0], "Lesson 1/6: Neighbours"));
+    let lesson = &frontend.screens()[1];
+    assert!(contains(lesson, "Lesson 2/6: Underpopulation"));
+    assert!(contains(lesson, "dies of underpopulation"));
+    let stepped = &frontend.screens()[2];
+    assert!(contains(stepped, "Generation 1"));
+    assert!(contains(stepped, "Both cells died"));
+}
+
+#[test]
+fn escape_opens_the_pause_menu_and_resume_closes_it() {
+    let mut frontend = ScriptedFrontend::new(
+        100,
+        40,
+        [
+            key(KeyCode::Esc),
+            key(KeyCode::Enter),
+            key(KeyCode::Char('q')),
+        ],
+    );
+
+    app().run_game_on("2048", &mut frontend).unwrap();
+
+    let screens = frontend.screens();
+    assert!(!contains(&screens[0], "Resume"));
+    assert!(contains(&screens[1], "Resume"));
+    assert!(!contains(&screens[2], "Resume"));
+}
+
+#[test]
+fn volume_keys_show_the_volume_bar_in_game() {
+    let mut frontend = ScriptedFrontend::new(
+        100,
+        40,
+        [
+            key(KeyCode::F(10)),
+            key(KeyCode::Char('+')),
+            key(KeyCode::Char('-')),
+            key(KeyCode::Char('q')),
+        ],
+    );
+
+    app().run_game_on("2048", &mut frontend).unwrap();
+
+    let screens = frontend.screens();
+    assert!(!contains(&screens[0], "Volume"));
+    assert!(contains(&screens[1], "Muted"));
+    // '+' rétablit le son et monte le volume d'un cran, '-' le redescend
+    assert!(contains(&screens[2], "90%"));
+    assert!(contains(&screens[3], "80%"));
+}
+
+#[test]
+fn f9_records_the_game_to_an_asciicast_file() {
+    let mut frontend = ScriptedFrontend::new(
+        100,
+        40,
+        [
+            key(KeyCode::F(9)),
+            key(KeyCode::Left),
+            key(KeyCode::F(9)),
+            key(KeyCode::Char('q')),
+        ],
+    );
+
+    app().run_game_on("2048", &mut frontend).unwrap();
+
+    let screens = frontend.screens();
+    assert!(contains(&screens[1], "REC"));
+    assert!(contains(&screens[3], "Saved recordings/2048-"));
+
+    let dir = termplay::storage::data_dir().unwrap().join("recordings");
+    let cast = std::fs::read_dir(dir)
+        .unwrap()
+        .filter_map(Result::ok)
+        .find(|entry| {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            name.starts_with("2048-") && name.ends_with(".cast")
+        })
+        .expect("recording file");
+    let contents = std::fs::read_to_string(cast.path()).unwrap();
+    let header = contents.lines().next().unwrap();
+    assert!(header.contains("\"version\":2"));
+    // L'image enregistrée ne contient pas le voyant
+    assert!(contents.contains("2048") && !contents.contains("REC"));
+}
+
+#[test]
+fn a_single_r_press_does_not_restart_a_game_in_progress() {
+    let mut frontend = ScriptedFrontend::new(
+        100,
+        40,
+        [
+            key(KeyCode::Char('r')),
+            key(KeyCode::Left),
+            key(KeyCode::Char('q')),
+        ],
+    );
+
+    app().run_game_on("2048", &mut frontend).unwrap();
+
+    let screens = frontend.screens();
+    assert!(!contains(&screens[0], "Hold R"));
+    assert!(contains(&screens[1], "Hold R to restart"));
+    // Toute autre touche annule le maintien
+    assert!(!contains(&screens[2], "Hold R"));
+}
+
+#[test]
+fn music_toggled_in_a_game_is_remembered_for_that_game_only() {
+    let mut frontend =
+        ScriptedFrontend::new(100, 40, [key(KeyCode::Char('m')), key(KeyCode::Char('q'))]);
+
+    app().run_game_on("minesweeper", &mut frontend).unwrap();
+
+    let config = ConfigManager::new().unwrap();
+    let preference = config.get_game_audio("minesweeper");
+    assert!(preference.music_enabled.is_some());
+    assert_eq!(preference.effects_enabled, None);
+    assert_eq!(config.get_game_audio("snake"), Default::default());
+}
+
+#[test]
+fn running_out_of_script_stops_the_game_loop() {
+    let mut frontend = ScriptedFrontend::new(80, 30, []);
+
+    let error = app().run_game_on("minesweeper", &mut frontend).unwrap_err();
+
+    assert_eq!(error.to_string(), SCRIPT_END);
+}
+
+/// Flux d'une session distante, relu par le test
+#[derive(Clone, Default)]
+struct SharedOutput(Arc<Mutex<Vec<u8>>>);
+
+impl Write for SharedOutput {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn a_stream_session_draws_the_menu_and_ends_alone() {
+    common::isolate_data_dir();
+    let output = SharedOutput::default();
+    let (mut frontend, input) = StreamFrontend::new(output.clone(), 100, 40).unwrap();
+
+    // Une flèche puis Ctrl+C, tels que les envoie un terminal
+    assert!(input.data("\x1b[B\x03"));
+    let error = app().run_menu_on(&mut frontend).unwrap_err();
+    assert_eq!(error.to_string(), "session closed");
+    drop(frontend);
+
+    let screen = String::from_utf8_lossy(&output.0.lock().unwrap()).into_owned();
+    assert!(screen.contains("Main Menu"));
+    // Le terminal du client retrouve son écran
+    assert!(screen.ends_with("\x1b[?1049l"));
+
+    // Une connexion fermée termine aussi la session
+    let (mut frontend, input) = StreamFrontend::new(SharedOutput::default(), 100, 40).unwrap();
+    drop(input);
+    assert!(app().run_menu_on(&mut frontend).is_err());
+}
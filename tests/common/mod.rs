@@ -0,0 +1,133 @@
+//! Harnais de test : un terminal virtuel qui rejoue une suite de touches et
+//! garde chaque écran dessiné par l'application.
+
+// Chaque fichier de test n'utilise qu'une partie du harnais
+#![allow(dead_code)]
+
+use crossterm::event::{Event, KeyCode, KeyEvent};
+use ratatui::{backend::TestBackend, buffer::Buffer, Terminal};
+use std::collections::VecDeque;
+use std::io;
+use std::path::PathBuf;
+use std::sync::Once;
+use std::time::Duration;
+use termplay::config::ConfigManager;
+use termplay::frontend::Frontend;
+use termplay::games::GameRegistry;
+use termplay::storage;
+use termplay::App;
+
+/// Message de l'erreur renvoyée quand le script est épuisé
+pub const SCRIPT_END: &str = "end of script";
+
+/// Dirige la configuration, les scores et l'historique vers un dossier
+/// temporaire propre au processus de test, pour ne jamais toucher aux données
+/// du développeur. L'écran d'accueil et les transitions des menus y sont
+/// coupés : chaque écran capturé est l'écran final. La locale est fixée, pour
+/// que les nombres et les symboles s'affichent partout de la même façon.
+pub fn isolate_data_dir() {
+    static INIT: Once = Once::new();
+    INIT.call_once(|| {
+        let dir: PathBuf =
+            std::env::temp_dir().join(format!("termplay-tests-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::env::set_var(storage::DATA_DIR_VAR, &dir);
+        std::env::set_var("LC_ALL", "C.UTF-8");
+        ConfigManager::new()
+            .and_then(|mut config| {
+                config.update_display_config(|c| {
+                    c.reduce_motion = true;
+                    c.splash_screen = false;
+                })
+            })
+            .expect("test configuration");
+    });
+}
+
+/// Application sur des jeux muets : les parcours tournent sans carte son et
+/// sans jamais ouvrir le périphérique audio
+pub fn app() -> App {
+    App::with_registry(GameRegistry::silent())
+}
+
+/// Session invitée sur des jeux muets, comme celles du serveur SSH
+pub fn guest_app() -> App {
+    App::guest(GameRegistry::silent())
+}
+
+pub fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent::from(code))
+}
+
+pub fn char_keys(text: &str) -> Vec<Event> {
+    text.chars().map(|c| key(KeyCode::Char(c))).collect()
+}
+
+/// Terminal virtuel piloté par une liste d'événements.
+///
+/// Une fois la liste épuisée, `poll_event` renvoie une erreur qui fait sortir
+/// l'application de sa boucle : un test ne peut jamais tourner indéfiniment.
+pub struct ScriptedFrontend {
+    terminal: Terminal<TestBackend>,
+    events: VecDeque<Event>,
+    screens: Vec<Buffer>,
+}
+
+impl ScriptedFrontend {
+    pub fn new(width: u16, height: u16, events: impl IntoIterator<Item = Event>) -> Self {
+        isolate_data_dir();
+        Self {
+            terminal: Terminal::new(TestBackend::new(width, height)).unwrap(),
+            events: events.into_iter().collect(),
+            screens: Vec::new(),
+        }
+    }
+
+    /// Écrans dessinés, un par événement lu, dans l'ordre
+    pub fn screens(&self) -> &[Buffer] {
+        &self.screens
+    }
+
+    /// Dernier écran dessiné
+    pub fn last_screen(&self) -> &Buffer {
+        self.screens.last().expect("nothing was drawn")
+    }
+}
+
+impl Frontend for ScriptedFrontend {
+    type Backend = TestBackend;
+
+    fn terminal(&mut self) -> &mut Terminal<Self::Backend> {
+        &mut self.terminal
+    }
+
+    fn poll_event(&mut self, _timeout: Duration) -> io::Result<Option<Event>> {
+        self.screens.push(self.terminal.backend().buffer().clone());
+        match self.events.pop_front() {
+            Some(event) => Ok(Some(event)),
+            None => Err(io::Error::other(SCRIPT_END)),
+        }
+    }
+}
+
+/// Lignes de l'écran, une chaîne par ligne
+pub fn lines(buffer: &Buffer) -> Vec<String> {
+    let area = buffer.area;
+    (area.top()..area.bottom())
+        .map(|y| {
+            (area.left()..area.right())
+                .map(|x| buffer[(x, y)].symbol())
+                .collect()
+        })
+        .collect()
+}
+
+/// Indique si `text` apparaît sur une ligne de l'écran
+pub fn contains(buffer: &Buffer, text: &str) -> bool {
+    lines(buffer).iter().any(|line| line.contains(text))
+}
+
+/// Ligne marquée comme sélectionnée dans une liste
+pub fn selected_line(buffer: &Buffer) -> Option<String> {
+    lines(buffer).into_iter().find(|line| line.contains('▶'))
+}
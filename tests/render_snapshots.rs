@@ -0,0 +1,156 @@
+//! Instantanés du premier écran de chaque jeu à plusieurs tailles de
+//! terminal, pour repérer les widgets tronqués ou superposés quand une mise
+//! en page change.
+//!
+//! Après un changement voulu de l'affichage, relancer avec
+//! `INSTA_UPDATE=always cargo test --test render_snapshots` puis relire le
+//! diff des fichiers de `tests/snapshots/`.
+
+mod common;
+
+use common::{isolate_data_dir, lines};
+use ratatui::{backend::TestBackend, layout::Rect, Terminal};
+use termplay::core::rng;
+use termplay::GameRegistry;
+
+/// Tailles courantes, puis la plus petite documentée par un jeu (le message
+/// "Minimum size: 30x15" de Tetris)
+const SIZES: [(u16, u16); 4] = [(80, 24), (120, 40), (60, 18), (30, 15)];
+
+/// Graine des tirages : mêmes pièces, tuiles et nourriture à chaque exécution
+const SEED: u64 = 42;
+
+fn snapshot_game(id: &str) {
+    isolate_data_dir();
+
+    for (width, height) in SIZES {
+        rng::seed(SEED);
+        let mut game = GameRegistry::silent().get_game(id).unwrap();
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        terminal
+            .draw(|frame| game.draw(frame, frame.area()))
+            .unwrap();
+
+        let screen = lines(terminal.backend().buffer()).join("\n");
+        insta::assert_snapshot!(format!("{id}_{width}x{height}"), screen);
+    }
+}
+
+#[test]
+fn snake() {
+    snapshot_game("snake");
+}
+
+#[test]
+fn snake_race() {
+    snapshot_game("snake-race");
+}
+
+#[test]
+fn tetris() {
+    snapshot_game("tetris");
+}
+
+#[test]
+fn tetris_dig() {
+    snapshot_game("tetris-dig");
+}
+
+#[test]
+fn tetris_doubles() {
+    snapshot_game("tetris-doubles");
+}
+
+#[test]
+fn pong() {
+    snapshot_game("pong");
+}
+
+#[test]
+fn game_2048() {
+    snapshot_game("2048");
+}
+
+#[test]
+fn puzzles() {
+    snapshot_game("puzzles");
+}
+
+#[test]
+fn minesweeper() {
+    snapshot_game("minesweeper");
+}
+
+#[test]
+fn breakout() {
+    snapshot_game("breakout");
+}
+
+#[test]
+fn rhythm() {
+    snapshot_game("rhythm");
+}
+
+#[test]
+fn game_of_life() {
+    snapshot_game("game-of-life");
+}
+
+/// Dessiné dans une zone décalée, chaque jeu donne le même écran qu'en plein
+/// écran, sans toucher aux cases autour
+#[test]
+fn games_draw_inside_their_area() {
+    isolate_data_dir();
+    let registry = GameRegistry::silent();
+    let (width, height) = SIZES[0];
+    let area = Rect::new(7, 3, width, height);
+
+    for info in registry.list_games() {
+        rng::seed(SEED);
+        let mut game = registry.get_game(&info.id).unwrap();
+        let mut full = Terminal::new(TestBackend::new(width, height)).unwrap();
+        full.draw(|frame| game.draw(frame, frame.area())).unwrap();
+
+        rng::seed(SEED);
+        let mut game = registry.get_game(&info.id).unwrap();
+        let mut terminal = Terminal::new(TestBackend::new(width + 12, height + 6)).unwrap();
+        terminal
+            .draw(|frame| {
+                let buffer = frame.buffer_mut();
+                for y in 0..buffer.area.height {
+                    for x in 0..buffer.area.width {
+                        if !area.contains((x, y).into()) {
+                            buffer[(x, y)].set_symbol("#");
+                        }
+                    }
+                }
+                game.draw(frame, area);
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        for y in 0..buffer.area.height {
+            for x in 0..buffer.area.width {
+                if !area.contains((x, y).into()) {
+                    assert_eq!(
+                        buffer[(x, y)].symbol(),
+                        "#",
+                        "{} drew at ({x}, {y})",
+                        info.id
+                    );
+                }
+            }
+        }
+        let expected = full.backend().buffer();
+        for y in 0..height {
+            for x in 0..width {
+                assert_eq!(
+                    buffer[(area.x + x, area.y + y)].symbol(),
+                    expected[(x, y)].symbol(),
+                    "{} differs at ({x}, {y})",
+                    info.id
+                );
+            }
+        }
+    }
+}
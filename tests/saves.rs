@@ -0,0 +1,112 @@
+//! Reprise des parties interrompues. Dans leur propre binaire de test : une
+//! sauvegarde présente au démarrage du menu ouvre l'écran Resume, ce qui
+//! changerait le premier écran des autres parcours.
+
+mod common;
+
+use common::{app, contains, isolate_data_dir, key, ScriptedFrontend};
+use crossterm::event::KeyCode;
+use std::sync::Mutex;
+
+/// Les tests partagent la sauvegarde de Game of Life : un à la fois
+static SAVES: Mutex<()> = Mutex::new(());
+
+/// Partie laissée par un arrêt forcé : un planeur à la génération 42
+fn save_glider() {
+    termplay::saves::save(
+        "game-of-life",
+        Some("gen 42".to_string()),
+        serde_json::json!({
+            "width": 60,
+            "height": 30,
+            "cells": "bo$2bo$3o!",
+            "generation": 42,
+            "speed": 3,
+            "cursor": [30, 15],
+            "camera": [30, 15],
+            "max_generations_reached": 42,
+            "population_history": [5],
+            "elapsed_ms": 90_000,
+        }),
+    );
+}
+
+#[test]
+fn an_interrupted_game_resumes_and_is_forgotten_once_quit() {
+    let _lock = SAVES.lock().unwrap_or_else(|e| e.into_inner());
+    isolate_data_dir();
+    save_glider();
+    let mut frontend = ScriptedFrontend::new(100, 40, [key(KeyCode::Char('q'))]);
+
+    app().run_game_on("game-of-life", &mut frontend).unwrap();
+
+    let screen = &frontend.screens()[0];
+    assert!(contains(screen, "Gen: 42"));
+    assert!(contains(screen, "PAUSED"));
+    assert!(contains(screen, "Resumed game saved at"));
+    assert_eq!(termplay::saves::load("game-of-life"), None);
+}
+
+#[test]
+fn the_menu_offers_to_resume_an_interrupted_game_on_startup() {
+    let _lock = SAVES.lock().unwrap_or_else(|e| e.into_inner());
+    isolate_data_dir();
+    save_glider();
+    let mut frontend = ScriptedFrontend::new(
+        100,
+        40,
+        [
+            key(KeyCode::Enter),
+            key(KeyCode::Char('q')),
+            key(KeyCode::Char('q')),
+        ],
+    );
+
+    app().run_menu_on(&mut frontend).unwrap();
+
+    let screens = frontend.screens();
+    let offer = &screens[0];
+    assert!(contains(offer, "RESUME"));
+    assert!(contains(offer, "Game of Life"));
+    assert!(contains(offer, "gen 42"));
+    assert!(screens.iter().any(|screen| contains(screen, "Gen: 42")));
+    // Rien d'autre à reprendre : retour au menu principal
+    assert!(contains(frontend.last_screen(), "Main Menu"));
+    assert_eq!(termplay::saves::load("game-of-life"), None);
+}
+
+#[test]
+fn an_interrupted_game_can_be_discarded_from_the_menu() {
+    let _lock = SAVES.lock().unwrap_or_else(|e| e.into_inner());
+    isolate_data_dir();
+    save_glider();
+    let mut frontend =
+        ScriptedFrontend::new(100, 40, [key(KeyCode::Char('d')), key(KeyCode::Char('q'))]);
+
+    app().run_menu_on(&mut frontend).unwrap();
+
+    assert!(contains(&frontend.screens()[0], "RESUME"));
+    assert!(contains(frontend.last_screen(), "Main Menu"));
+    assert_eq!(termplay::saves::load("game-of-life"), None);
+}
+
+#[test]
+fn quitting_from_the_pause_menu_keeps_the_game_and_ends_only_this_app() {
+    let _lock = SAVES.lock().unwrap_or_else(|e| e.into_inner());
+    isolate_data_dir();
+    save_glider();
+    let mut frontend = ScriptedFrontend::new(
+        100,
+        40,
+        [key(KeyCode::Esc), key(KeyCode::End), key(KeyCode::Enter)],
+    );
+
+    app().run_game_on("game-of-life", &mut frontend).unwrap();
+
+    assert!(termplay::saves::load("game-of-life").is_some());
+    // Une autre application (une autre session) continue de tourner
+    let mut frontend = ScriptedFrontend::new(100, 40, [key(KeyCode::Char('q'))]);
+    app().run_game_on("game-of-life", &mut frontend).unwrap();
+    assert!(contains(&frontend.screens()[0], "Gen: 42"));
+    assert_eq!(termplay::saves::load("game-of-life"), None);
+}